@@ -7,7 +7,9 @@ use crate::context::{ContextId, ContextRegistry, LayerId};
 use crate::rules::{HeldStates, Rule, RuleRegistry};
 use crate::signals::MetricSignals;
 use crate::stability::{StabilityConfig, StabilityState};
-use crate::transitions::{ActiveTransition, TransitionRegistry};
+use crate::transitions::{
+    ActiveTransition, MusicalPosition, TransitionProfile, TransitionRegistry, TransitionSync,
+};
 use rtrb::{Consumer, Producer, RingBuffer};
 use std::sync::atomic::{AtomicU8, AtomicU32, Ordering};
 
@@ -107,6 +109,10 @@ pub struct AdaptiveLayerEngine {
     beat_position: f32,
     beat_duration_ms: f32,
     beats_per_bar: u8,
+    tempo_bpm: f32,
+    /// Playhead in samples (fractional: ticks are in ms)
+    playhead_samples: f64,
+    sample_rate: f64,
 }
 
 impl AdaptiveLayerEngine {
@@ -134,6 +140,9 @@ impl AdaptiveLayerEngine {
             beat_position: 0.0,
             beat_duration_ms: 500.0, // Default 120 BPM
             beats_per_bar: 4,
+            tempo_bpm: 120.0,
+            playhead_samples: 0.0,
+            sample_rate: 48000.0,
         }
     }
 
@@ -169,6 +178,28 @@ impl AdaptiveLayerEngine {
         self.stability.set_config(config);
     }
 
+    /// Set the musical playhead (samples at `sample_rate`)
+    ///
+    /// Ticks advance the playhead from here; beat position is re-derived so
+    /// both sync paths agree with the host timeline.
+    pub fn set_playhead(&mut self, sample: u64, sample_rate: f64) {
+        if sample_rate > 0.0 {
+            self.sample_rate = sample_rate;
+        }
+        self.playhead_samples = sample as f64;
+        self.beat_position = self.musical_position().beats() as f32;
+    }
+
+    /// Current musical position on the playhead timeline
+    pub fn musical_position(&self) -> MusicalPosition {
+        MusicalPosition {
+            sample: self.playhead_samples as u64,
+            sample_rate: self.sample_rate,
+            tempo_bpm: self.tempo_bpm as f64,
+            beats_per_bar: self.beats_per_bar,
+        }
+    }
+
     /// Switch to a context
     pub fn switch_context(&mut self, context_id: &str, trigger: Option<&str>) {
         if let Some(context) = self.contexts.get(context_id) {
@@ -184,6 +215,7 @@ impl AdaptiveLayerEngine {
             // Update timing from context
             self.beat_duration_ms = context.audio_character.beat_duration_ms();
             self.beats_per_bar = context.audio_character.time_sig_numerator;
+            self.tempo_bpm = context.audio_character.tempo_bpm;
 
             // Get transition profile
             let transition = trigger
@@ -200,22 +232,8 @@ impl AdaptiveLayerEngine {
                 .cloned()
                 .unwrap_or_default();
 
-            // Calculate sync delay
-            let sync_delay = transition.calculate_sync_delay(
-                self.beat_position,
-                self.beats_per_bar,
-                self.beat_duration_ms,
-            );
-
             // Start transition
-            self.target_level = Some(start_level);
-            self.active_transition = Some(ActiveTransition::new(
-                current_level,
-                start_level,
-                transition,
-                self.current_time_ms,
-                sync_delay,
-            ));
+            self.begin_transition(current_level, start_level, transition);
 
             // Clear stability state for new context
             self.stability.reset();
@@ -234,6 +252,7 @@ impl AdaptiveLayerEngine {
         // Update time
         self.current_time_ms += delta_ms as u64;
         self.beat_position += delta_ms as f32 / self.beat_duration_ms;
+        self.playhead_samples += delta_ms as f64 * self.sample_rate / 1000.0;
 
         // 2. Update derived signals
         self.signals.update_derived("winTier");
@@ -424,26 +443,36 @@ impl AdaptiveLayerEngine {
             .cloned()
             .unwrap_or_default();
 
-        let sync_delay = profile.calculate_sync_delay(
-            self.beat_position,
-            self.beats_per_bar,
-            self.beat_duration_ms,
-        );
+        self.begin_transition(from, to, profile);
+    }
+
+    /// Create the active transition, delayed to its sync point
+    fn begin_transition(&mut self, from: LayerId, to: LayerId, profile: TransitionProfile) {
+        let transition = if profile.sync != TransitionSync::Immediate {
+            ActiveTransition::synced(
+                from,
+                to,
+                profile,
+                self.current_time_ms,
+                self.musical_position(),
+            )
+        } else {
+            let sync_delay = profile.calculate_sync_delay(
+                self.beat_position,
+                self.beats_per_bar,
+                self.beat_duration_ms,
+            );
+            ActiveTransition::new(from, to, profile, self.current_time_ms, sync_delay)
+        };
 
         self.target_level = Some(to);
-        self.active_transition = Some(ActiveTransition::new(
-            from,
-            to,
-            profile,
-            self.current_time_ms,
-            sync_delay,
-        ));
+        self.active_transition = Some(transition);
     }
 
     /// Tick active transition
     fn tick_transition(&mut self) {
         if let Some(ref mut transition) = self.active_transition {
-            transition.update(self.current_time_ms);
+            transition.update_at_sample(self.current_time_ms, self.playhead_samples as u64);
 
             if transition.is_complete() {
                 self.current_level
@@ -534,6 +563,7 @@ impl AdaptiveLayerEngine {
         self.last_fired_rule = None;
         self.current_time_ms = 0;
         self.beat_position = 0.0;
+        self.playhead_samples = 0.0;
     }
 
     // Getters for atomic state
@@ -567,6 +597,7 @@ impl AdaptiveLayerEngine {
 mod tests {
     use super::*;
     use crate::context::{Context, Layer};
+    use crate::transitions::TransitionPhase;
     
 
     fn create_test_engine() -> AdaptiveLayerEngine {
@@ -614,4 +645,42 @@ mod tests {
         assert!((volumes.volumes[2] - 1.0).abs() < 0.01);
         assert!((volumes.volumes[0]).abs() < 0.01);
     }
+
+    #[test]
+    fn test_next_bar_sync_starts_on_bar_sample() {
+        let mut engine = create_test_engine();
+        let mut transitions = TransitionRegistry::new();
+        transitions.register(TransitionProfile {
+            sync: TransitionSync::NextBar,
+            ..TransitionProfile::new("bar_sync", "Bar Sync")
+        });
+        engine.set_transitions(transitions);
+
+        // 120 BPM 4/4 @ 48kHz: bars every 96000 samples, 48 samples per ms
+        engine.set_playhead(100_032, 48000.0);
+        engine.start_transition(1, 2, "bar_sync");
+
+        let transition = engine.active_transition.as_ref().unwrap();
+        assert_eq!(transition.sync_sample, Some(192_000));
+        assert_eq!(transition.phase, TransitionPhase::WaitingForSync);
+
+        // 91968 samples to the bar = 1916 ms
+        for _ in 0..1915 {
+            engine.tick(1);
+        }
+        let transition = engine.active_transition.as_ref().unwrap();
+        assert_eq!(transition.phase, TransitionPhase::WaitingForSync);
+        assert_eq!(engine.musical_position().sample, 191_952);
+
+        engine.tick(1);
+        let transition = engine.active_transition.as_ref().unwrap();
+        assert!(transition.started);
+        assert_eq!(transition.phase, TransitionPhase::FadeOut);
+        assert_eq!(transition.start_time_ms, engine.current_time_ms);
+
+        let position = transition.position.unwrap();
+        assert_eq!(position.sample, 192_000);
+        assert_eq!(position.bar(), 2);
+        assert!(position.beat_in_bar().abs() < 1e-9);
+    }
 }
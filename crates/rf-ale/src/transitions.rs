@@ -2,6 +2,7 @@
 //!
 //! Smooth, musical transitions between layers with:
 //! - Sync modes (immediate, beat, bar, phrase, next_downbeat, custom)
+//! - Sample-accurate beat/bar sync on the playhead timeline
 //! - Fade curves (10 types)
 //! - Crossfade overlap
 //! - Ducking integration
@@ -30,6 +31,96 @@ pub enum SyncMode {
     Custom,
 }
 
+/// Explicit musical boundary a transition waits for
+///
+/// Unlike `SyncMode`, the wait is not capped by `max_wait_ms` and the
+/// boundary is resolved to an exact sample on the playhead timeline.
+/// `Immediate` leaves the decision to `sync_mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[derive(Default)]
+pub enum TransitionSync {
+    /// No explicit sync (defer to `sync_mode`)
+    #[default]
+    Immediate,
+    /// Start on the next beat boundary
+    NextBeat,
+    /// Start on the next bar boundary
+    NextBar,
+}
+
+impl TransitionSync {
+    /// First beat/bar boundary at or after the current position
+    pub fn boundary_sample(&self, position: &MusicalPosition) -> u64 {
+        let grid = match self {
+            Self::Immediate => return position.sample,
+            Self::NextBeat => position.samples_per_beat(),
+            Self::NextBar => position.samples_per_bar(),
+        };
+        if grid <= 0.0 {
+            return position.sample;
+        }
+
+        // Half-sample bias: a playhead sitting on a rounded boundary starts there
+        let index = ((position.sample as f64 - 0.5) / grid).ceil().max(0.0);
+        (index * grid).round() as u64
+    }
+}
+
+/// Musical position on the playhead sample timeline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MusicalPosition {
+    /// Playhead in samples
+    pub sample: u64,
+    /// Sample rate (Hz)
+    pub sample_rate: f64,
+    /// Tempo (BPM)
+    pub tempo_bpm: f64,
+    /// Beats per bar (time signature numerator)
+    pub beats_per_bar: u8,
+}
+
+impl MusicalPosition {
+    /// Samples per beat
+    pub fn samples_per_beat(&self) -> f64 {
+        if self.tempo_bpm > 0.0 {
+            self.sample_rate * 60.0 / self.tempo_bpm
+        } else {
+            0.0
+        }
+    }
+
+    /// Samples per bar
+    pub fn samples_per_bar(&self) -> f64 {
+        self.samples_per_beat() * self.beats_per_bar.max(1) as f64
+    }
+
+    /// Beats elapsed since sample 0
+    pub fn beats(&self) -> f64 {
+        let spb = self.samples_per_beat();
+        if spb > 0.0 {
+            self.sample as f64 / spb
+        } else {
+            0.0
+        }
+    }
+
+    /// Bar index (0-based)
+    pub fn bar(&self) -> u64 {
+        (self.beats() / self.beats_per_bar.max(1) as f64).floor() as u64
+    }
+
+    /// Beat within the current bar (0-based, fractional)
+    pub fn beat_in_bar(&self) -> f64 {
+        self.beats() % self.beats_per_bar.max(1) as f64
+    }
+
+    /// Same tempo map at another sample
+    pub fn at_sample(&self, sample: u64) -> Self {
+        Self { sample, ..*self }
+    }
+}
+
 /// Fade curve type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -189,6 +280,9 @@ pub struct TransitionProfile {
     /// Sync mode
     #[serde(default)]
     pub sync_mode: SyncMode,
+    /// Explicit beat/bar sync (overrides `sync_mode` when not `Immediate`)
+    #[serde(default)]
+    pub sync: TransitionSync,
     /// Maximum wait time for sync (ms)
     #[serde(default = "default_max_wait")]
     pub max_wait_ms: u32,
@@ -222,6 +316,7 @@ impl Default for TransitionProfile {
             id: "default".to_string(),
             name: "Default Transition".to_string(),
             sync_mode: SyncMode::Immediate,
+            sync: TransitionSync::Immediate,
             max_wait_ms: 500,
             custom_grid_beats: None,
             fade_in: FadeConfig::default(),
@@ -248,6 +343,7 @@ impl TransitionProfile {
             id: "upshift_energetic".to_string(),
             name: "Upshift Energetic".to_string(),
             sync_mode: SyncMode::Beat,
+            sync: TransitionSync::Immediate,
             max_wait_ms: 500,
             custom_grid_beats: None,
             fade_in: FadeConfig::new(250, FadeCurve::EaseOutQuad),
@@ -268,6 +364,7 @@ impl TransitionProfile {
             id: "downshift_smooth".to_string(),
             name: "Downshift Smooth".to_string(),
             sync_mode: SyncMode::Bar,
+            sync: TransitionSync::Immediate,
             max_wait_ms: 1000,
             custom_grid_beats: None,
             fade_in: FadeConfig::new(500, FadeCurve::EaseInOutQuad),
@@ -283,6 +380,7 @@ impl TransitionProfile {
             id: "feature_enter".to_string(),
             name: "Feature Enter".to_string(),
             sync_mode: SyncMode::NextDownbeat,
+            sync: TransitionSync::Immediate,
             max_wait_ms: 2000,
             custom_grid_beats: None,
             fade_in: FadeConfig::new(400, FadeCurve::EaseOutExpo),
@@ -303,6 +401,7 @@ impl TransitionProfile {
             id: "feature_exit".to_string(),
             name: "Feature Exit".to_string(),
             sync_mode: SyncMode::Phrase,
+            sync: TransitionSync::Immediate,
             max_wait_ms: 4000,
             custom_grid_beats: None,
             fade_in: FadeConfig::new(1000, FadeCurve::SCurve),
@@ -383,6 +482,10 @@ pub struct ActiveTransition {
     pub progress: f32,
     /// Phase (0 = waiting for sync, 1 = fade out old, 2 = crossfade, 3 = fade in new)
     pub phase: TransitionPhase,
+    /// Boundary sample the fade starts on (explicit `TransitionSync` only)
+    pub sync_sample: Option<u64>,
+    /// Musical position where the fade started (or will start)
+    pub position: Option<MusicalPosition>,
 }
 
 /// Transition phase
@@ -424,7 +527,66 @@ impl ActiveTransition {
             sync_delay_ms,
             progress: 0.0,
             phase,
+            sync_sample: None,
+            position: None,
+        }
+    }
+
+    /// Create a transition that waits for the profile's `TransitionSync`
+    /// boundary on the sample timeline
+    pub fn synced(
+        from_level: LayerId,
+        to_level: LayerId,
+        profile: TransitionProfile,
+        current_time_ms: u64,
+        position: MusicalPosition,
+    ) -> Self {
+        let boundary = profile.sync.boundary_sample(&position);
+        let wait_samples = boundary - position.sample;
+        let sync_delay_ms = if position.sample_rate > 0.0 {
+            (wait_samples as f64 * 1000.0 / position.sample_rate).ceil() as u32
+        } else {
+            0
+        };
+
+        let mut transition = Self::new(
+            from_level,
+            to_level,
+            profile,
+            current_time_ms,
+            sync_delay_ms,
+        );
+        transition.sync_sample = Some(boundary);
+        transition.position = Some(position.at_sample(boundary));
+        if wait_samples == 0 {
+            transition.started = true;
+            transition.phase = TransitionPhase::FadeOut;
         }
+        transition
+    }
+
+    /// Update transition state against the playhead
+    ///
+    /// A sample-synced transition starts when the playhead crosses its
+    /// boundary; the fade clock is backdated by the overshoot so the fade
+    /// runs from the boundary itself, not from the tick that noticed it.
+    pub fn update_at_sample(&mut self, current_time_ms: u64, playhead_sample: u64) {
+        if let (false, Some(boundary), Some(position)) =
+            (self.started, self.sync_sample, self.position)
+        {
+            if playhead_sample < boundary {
+                return;
+            }
+            let overshoot_ms = if position.sample_rate > 0.0 {
+                ((playhead_sample - boundary) as f64 * 1000.0 / position.sample_rate) as u64
+            } else {
+                0
+            };
+            self.started = true;
+            self.start_time_ms = current_time_ms.saturating_sub(overshoot_ms);
+            self.phase = TransitionPhase::FadeOut;
+        }
+        self.update(current_time_ms);
     }
 
     /// Update transition state
@@ -627,6 +789,29 @@ mod tests {
         assert_eq!(delay, 1500); // 3 beats * 500ms
     }
 
+    #[test]
+    fn test_transition_sync_boundary() {
+        // 120 BPM, 4/4 @ 48kHz: beat = 24000, bar = 96000 samples
+        let position = MusicalPosition {
+            sample: 100_000,
+            sample_rate: 48000.0,
+            tempo_bpm: 120.0,
+            beats_per_bar: 4,
+        };
+        assert_eq!(
+            TransitionSync::Immediate.boundary_sample(&position),
+            100_000
+        );
+        assert_eq!(TransitionSync::NextBeat.boundary_sample(&position), 120_000);
+        assert_eq!(TransitionSync::NextBar.boundary_sample(&position), 192_000);
+
+        // Already on a boundary: start there
+        let on_bar = position.at_sample(96_000);
+        assert_eq!(TransitionSync::NextBar.boundary_sample(&on_bar), 96_000);
+        assert_eq!(on_bar.bar(), 1);
+        assert!(on_bar.beat_in_bar().abs() < 1e-9);
+    }
+
     #[test]
     fn test_active_transition_phases() {
         let profile = TransitionProfile {
@@ -317,6 +317,8 @@ fn compute_spectral_metrics(
         config,
     );
 
+    let diff_map = compute_diff_map(&ref_frames, &test_frames, sample_rate, config);
+
     Ok(SpectralMetrics {
         avg_spectral_diff_db,
         max_spectral_diff_db,
@@ -326,6 +328,7 @@ fn compute_spectral_metrics(
         spectral_correlation,
         band_diffs_db,
         band_centers,
+        diff_map,
     })
 }
 
@@ -359,6 +362,73 @@ fn compute_spectral_correlation(
     sum_corr / num_frames as f64
 }
 
+fn compute_diff_map(
+    ref_frames: &[crate::spectral::SpectralFrame],
+    test_frames: &[crate::spectral::SpectralFrame],
+    sample_rate: u32,
+    config: &DiffConfig,
+) -> SpectralDiffMap {
+    let num_frames = ref_frames.len().min(test_frames.len());
+    if num_frames == 0 || ref_frames[0].magnitude.is_empty() {
+        return SpectralDiffMap::default();
+    }
+
+    let freq_resolution = ref_frames[0].freq_resolution;
+    let num_bins = ref_frames[0].magnitude.len();
+    let num_bands = SpectralDiffMap::BANDS;
+
+    // Logarithmic band distribution
+    let min_freq = config.freq_range.0.max(20.0);
+    let max_freq = config.freq_range.1.min(sample_rate as f64 / 2.0);
+    let band_edges: Vec<f64> = (0..=num_bands)
+        .map(|i| min_freq * (max_freq / min_freq).powf(i as f64 / num_bands as f64))
+        .collect();
+    let band_bins: Vec<(usize, usize)> = band_edges
+        .windows(2)
+        .map(|edge| {
+            let low = ((edge[0] / freq_resolution).floor() as usize).min(num_bins - 1);
+            let high = ((edge[1] / freq_resolution).ceil() as usize).clamp(low, num_bins - 1);
+            (low, high)
+        })
+        .collect();
+    let freqs = band_edges
+        .windows(2)
+        .map(|e| (e[0] * e[1]).sqrt())
+        .collect();
+
+    // Group consecutive frames into columns
+    let frames_per_column = num_frames.div_ceil(SpectralDiffMap::MAX_COLUMNS);
+    let band_power = |frame: &crate::spectral::SpectralFrame, (low, high): (usize, usize)| {
+        frame.power[low..=high].iter().sum::<f64>()
+    };
+
+    let (times, diff_db) = (0..num_frames)
+        .step_by(frames_per_column)
+        .map(|start| {
+            let end = (start + frames_per_column).min(num_frames);
+            let column = band_bins
+                .iter()
+                .map(|&bins| {
+                    let (ref_power, test_power) = (start..end).fold((0.0, 0.0), |(r, t), i| {
+                        (
+                            r + band_power(&ref_frames[i], bins),
+                            t + band_power(&test_frames[i], bins),
+                        )
+                    });
+                    to_db(test_power.sqrt().max(1e-10)) - to_db(ref_power.sqrt().max(1e-10))
+                })
+                .collect();
+            (ref_frames[start].time, column)
+        })
+        .unzip();
+
+    SpectralDiffMap {
+        times,
+        freqs,
+        diff_db,
+    }
+}
+
 fn compute_band_diffs(
     ref_frames: &[crate::spectral::SpectralFrame],
     test_frames: &[crate::spectral::SpectralFrame],
@@ -50,6 +50,47 @@ pub struct SpectralMetrics {
 
     /// Band center frequencies (Hz)
     pub band_centers: Vec<f64>,
+
+    /// Time-frequency map of the level difference
+    #[serde(default)]
+    pub diff_map: SpectralDiffMap,
+}
+
+/// Time-frequency grid of spectral level differences (test - reference)
+///
+/// Frames are averaged into at most `MAX_COLUMNS` time columns and bins into
+/// `BANDS` log-spaced bands, so the map stays small enough to embed in reports.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SpectralDiffMap {
+    /// Column start times (seconds)
+    pub times: Vec<f64>,
+
+    /// Band center frequencies (Hz)
+    pub freqs: Vec<f64>,
+
+    /// Level difference in dB, indexed `[column][band]`
+    pub diff_db: Vec<Vec<f64>>,
+}
+
+impl SpectralDiffMap {
+    /// Maximum number of time columns
+    pub const MAX_COLUMNS: usize = 256;
+
+    /// Number of frequency bands
+    pub const BANDS: usize = 64;
+
+    /// True if the map holds no data
+    pub fn is_empty(&self) -> bool {
+        self.diff_db.is_empty()
+    }
+
+    /// Largest absolute difference in dB
+    pub fn max_abs_db(&self) -> f64 {
+        self.diff_db
+            .iter()
+            .flatten()
+            .fold(0.0, |acc: f64, d| acc.max(d.abs()))
+    }
 }
 
 /// Perceptual metrics
@@ -173,6 +214,7 @@ impl SpectralMetrics {
             spectral_correlation: 1.0,
             band_diffs_db: vec![0.0; num_bands],
             band_centers: vec![0.0; num_bands],
+            diff_map: SpectralDiffMap::default(),
         }
     }
 }
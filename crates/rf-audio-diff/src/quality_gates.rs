@@ -7,6 +7,7 @@
 //! - Frequency response
 //! - Silence detection
 //! - Clipping detection
+//! - Sample sanity (NaN / Inf)
 
 use crate::loader::AudioData;
 use crate::spectral::to_db;
@@ -42,6 +43,10 @@ pub struct QualityGateConfig {
 
     /// Stereo correlation checks
     pub stereo: Option<StereoGate>,
+
+    /// NaN / Inf checks
+    #[serde(default)]
+    pub sanity: Option<SanityGate>,
 }
 
 impl Default for QualityGateConfig {
@@ -56,6 +61,7 @@ impl Default for QualityGateConfig {
             frequency: None,
             dc_offset: Some(DcOffsetGate::default()),
             stereo: None,
+            sanity: Some(SanityGate::default()),
         }
     }
 }
@@ -84,6 +90,7 @@ impl QualityGateConfig {
             frequency: None,
             dc_offset: Some(DcOffsetGate::default()),
             stereo: None,
+            sanity: Some(SanityGate::default()),
         }
     }
 
@@ -107,6 +114,7 @@ impl QualityGateConfig {
             frequency: None,
             dc_offset: Some(DcOffsetGate::default()),
             stereo: None,
+            sanity: Some(SanityGate::default()),
         }
     }
 
@@ -133,6 +141,7 @@ impl QualityGateConfig {
             frequency: Some(FrequencyGate::default()),
             dc_offset: Some(DcOffsetGate::strict()),
             stereo: Some(StereoGate::default()),
+            sanity: Some(SanityGate::default()),
         }
     }
 
@@ -163,6 +172,7 @@ impl QualityGateConfig {
             frequency: None,
             dc_offset: Some(DcOffsetGate::default()),
             stereo: None,
+            sanity: Some(SanityGate::default()),
         }
     }
 }
@@ -321,12 +331,17 @@ impl Default for FrequencyGate {
 pub struct DcOffsetGate {
     /// Maximum allowed DC offset (absolute)
     pub max_dc_offset: f64,
+
+    /// Fail the gate when exceeded (otherwise only a warning)
+    #[serde(default)]
+    pub fail_on_exceed: bool,
 }
 
 impl Default for DcOffsetGate {
     fn default() -> Self {
         Self {
             max_dc_offset: 0.01,
+            fail_on_exceed: false,
         }
     }
 }
@@ -336,10 +351,18 @@ impl DcOffsetGate {
     pub fn strict() -> Self {
         Self {
             max_dc_offset: 0.001,
+            fail_on_exceed: true,
         }
     }
 }
 
+/// Sample sanity gate (NaN / Inf), independent of any reference
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SanityGate {
+    /// Maximum non-finite samples allowed
+    pub max_non_finite: usize,
+}
+
 /// Stereo correlation gate
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StereoGate {
@@ -435,6 +458,14 @@ pub struct QualityMetrics {
     /// Total clipped samples
     pub clipped_samples: usize,
 
+    /// Longest run of consecutive clipped samples in any channel
+    #[serde(default)]
+    pub max_consecutive_clips: usize,
+
+    /// NaN / Inf samples
+    #[serde(default)]
+    pub non_finite_samples: usize,
+
     /// Stereo correlation (if stereo)
     pub stereo_correlation: Option<f64>,
 }
@@ -482,6 +513,10 @@ impl QualityGateRunner {
             self.check_dc_offset(&metrics, dc_gate, &mut checks);
         }
 
+        if let Some(ref sanity_gate) = self.config.sanity {
+            self.check_sanity(&metrics, sanity_gate, &mut checks);
+        }
+
         if let Some(ref stereo_gate) = self.config.stereo
             && let Some(corr) = metrics.stereo_correlation {
                 self.check_stereo(corr, stereo_gate, &mut checks);
@@ -581,6 +616,33 @@ impl QualityGateRunner {
             .filter(|&&s| s.abs() >= clip_threshold)
             .count();
 
+        let max_consecutive_clips = audio
+            .channels
+            .iter()
+            .map(|ch| {
+                let mut run = 0;
+                let mut longest = 0;
+                for &s in ch {
+                    run = if s.abs() >= clip_threshold {
+                        run + 1
+                    } else {
+                        0
+                    };
+                    longest = longest.max(run);
+                }
+                longest
+            })
+            .max()
+            .unwrap_or(0);
+
+        // Sanity
+        let non_finite_samples = audio
+            .channels
+            .iter()
+            .flat_map(|ch| ch.iter())
+            .filter(|s| !s.is_finite())
+            .count();
+
         // Stereo correlation
         let stereo_correlation = if audio.num_channels >= 2 {
             Some(calculate_stereo_correlation(
@@ -601,6 +663,8 @@ impl QualityGateRunner {
             leading_silence_ms,
             trailing_silence_ms,
             clipped_samples,
+            max_consecutive_clips,
+            non_finite_samples,
             stereo_correlation,
         })
     }
@@ -769,6 +833,18 @@ impl QualityGateRunner {
             ),
             severity: CheckSeverity::Error,
         });
+
+        checks.push(QualityCheck {
+            name: "clipping_consecutive".into(),
+            passed: metrics.max_consecutive_clips <= gate.max_consecutive_clips,
+            measured: metrics.max_consecutive_clips as f64,
+            threshold: gate.max_consecutive_clips as f64,
+            description: format!(
+                "Consecutive clipped samples {} <= {}",
+                metrics.max_consecutive_clips, gate.max_consecutive_clips
+            ),
+            severity: CheckSeverity::Error,
+        });
     }
 
     fn check_dc_offset(
@@ -786,7 +862,30 @@ impl QualityGateRunner {
                 "DC offset {:.6} <= {:.6}",
                 metrics.dc_offset, gate.max_dc_offset
             ),
-            severity: CheckSeverity::Warning,
+            severity: if gate.fail_on_exceed {
+                CheckSeverity::Error
+            } else {
+                CheckSeverity::Warning
+            },
+        });
+    }
+
+    fn check_sanity(
+        &self,
+        metrics: &QualityMetrics,
+        gate: &SanityGate,
+        checks: &mut Vec<QualityCheck>,
+    ) {
+        checks.push(QualityCheck {
+            name: "non_finite".into(),
+            passed: metrics.non_finite_samples <= gate.max_non_finite,
+            measured: metrics.non_finite_samples as f64,
+            threshold: gate.max_non_finite as f64,
+            description: format!(
+                "NaN/Inf samples {} <= {}",
+                metrics.non_finite_samples, gate.max_non_finite
+            ),
+            severity: CheckSeverity::Error,
         });
     }
 
@@ -883,6 +982,10 @@ impl QualityGateResult {
             "| Clipped Samples | {} |\n",
             self.metrics.clipped_samples
         ));
+        output.push_str(&format!(
+            "| NaN/Inf Samples | {} |\n",
+            self.metrics.non_finite_samples
+        ));
         output.push('\n');
 
         output.push_str("## Checks\n\n");
@@ -966,6 +1069,73 @@ mod tests {
         assert!(result.metrics.clipped_samples > 0);
     }
 
+    #[test]
+    fn test_clipped_render_fails_clipping_gate() {
+        let mut audio = make_test_audio(0.5);
+        for s in audio.channels[0][1000..1010].iter_mut() {
+            *s = 1.0;
+        }
+
+        let config = QualityGateConfig {
+            clipping: Some(ClippingGate::strict()),
+            ..QualityGateConfig::default()
+        };
+        let result = QualityGateRunner::new(config).check(&audio).unwrap();
+
+        assert!(!result.passed);
+        assert_eq!(result.metrics.max_consecutive_clips, 10);
+        for name in ["clipping", "clipping_consecutive"] {
+            let check = result.checks.iter().find(|c| c.name == name).unwrap();
+            assert!(!check.passed, "{} should fail", name);
+        }
+    }
+
+    #[test]
+    fn test_nan_fails_sanity_gate() {
+        let mut audio = make_test_audio(0.5);
+        audio.channels[1][500] = f64::NAN;
+
+        let result = QualityGateRunner::new(QualityGateConfig::default())
+            .check(&audio)
+            .unwrap();
+
+        assert!(!result.passed);
+        assert_eq!(result.metrics.non_finite_samples, 1);
+        let check = result
+            .checks
+            .iter()
+            .find(|c| c.name == "non_finite")
+            .unwrap();
+        assert!(!check.passed);
+        assert_eq!(check.severity, CheckSeverity::Error);
+    }
+
+    #[test]
+    fn test_strict_dc_offset_fails() {
+        let mut audio = make_test_audio(0.5);
+        for s in audio.channels[0].iter_mut() {
+            *s += 0.05;
+        }
+
+        let lenient = QualityGateRunner::new(QualityGateConfig::default())
+            .check(&audio)
+            .unwrap();
+        let check = lenient
+            .checks
+            .iter()
+            .find(|c| c.name == "dc_offset")
+            .unwrap();
+        assert!(!check.passed);
+        assert_eq!(check.severity, CheckSeverity::Warning);
+
+        let config = QualityGateConfig {
+            dc_offset: Some(DcOffsetGate::strict()),
+            ..QualityGateConfig::default()
+        };
+        let strict = QualityGateRunner::new(config).check(&audio).unwrap();
+        assert!(!strict.passed);
+    }
+
     #[test]
     fn test_metrics_markdown() {
         let audio = make_test_audio(0.5);
@@ -1,6 +1,7 @@
 //! Report generation for audio diff results
 
 use crate::diff::DiffResult;
+use crate::metrics::SpectralDiffMap;
 use serde::{Deserialize, Serialize};
 use std::io::Write;
 use std::path::Path;
@@ -16,6 +17,8 @@ pub enum ReportFormat {
     Markdown,
     /// JUnit XML (for CI integration)
     JUnit,
+    /// Self-contained HTML with spectral difference heatmaps
+    Html,
 }
 
 /// Report generator
@@ -85,6 +88,7 @@ impl DiffReport {
             ReportFormat::Json => self.to_json(),
             ReportFormat::Markdown => self.to_markdown(),
             ReportFormat::JUnit => self.to_junit(),
+            ReportFormat::Html => self.to_html_string(),
         }
     }
 
@@ -95,6 +99,11 @@ impl DiffReport {
         file.write_all(content.as_bytes())
     }
 
+    /// Save a visual HTML report with a spectral difference heatmap per result
+    pub fn to_html<P: AsRef<Path>>(&self, path: P) -> std::io::Result<()> {
+        self.save(path, ReportFormat::Html)
+    }
+
     fn to_text(&self) -> String {
         let mut output = String::new();
 
@@ -258,6 +267,154 @@ impl DiffReport {
 
         output
     }
+
+    fn to_html_string(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str("<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n");
+        output.push_str(&format!("<title>{}</title>\n", xml_escape(&self.title)));
+        output.push_str(
+            "<style>\n\
+             body { font-family: sans-serif; margin: 2em; }\n\
+             table { border-collapse: collapse; margin-bottom: 1em; }\n\
+             th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: left; }\n\
+             .pass { color: #2a2; } .fail { color: #c22; }\n\
+             svg.heatmap { width: 100%; max-width: 1024px; background: #fff; }\n\
+             </style>\n",
+        );
+        output.push_str("</head>\n<body>\n");
+
+        output.push_str(&format!("<h1>{}</h1>\n", xml_escape(&self.title)));
+        output.push_str(&format!("<p>Timestamp: {}</p>\n", self.timestamp));
+
+        output.push_str("<table class=\"summary\">\n");
+        output.push_str("<tr><th>Metric</th><th>Value</th></tr>\n");
+        output.push_str(&format!("<tr><td>Total</td><td>{}</td></tr>\n", self.total));
+        output.push_str(&format!(
+            "<tr><td>Passed</td><td>{}</td></tr>\n",
+            self.passed
+        ));
+        output.push_str(&format!(
+            "<tr><td>Failed</td><td>{}</td></tr>\n",
+            self.failed
+        ));
+        output.push_str(&format!(
+            "<tr><td>Pass Rate</td><td>{:.1}%</td></tr>\n",
+            self.pass_rate() * 100.0
+        ));
+        output.push_str("</table>\n");
+
+        for result in &self.results {
+            let (class, status) = if result.passed {
+                ("pass", "PASS")
+            } else {
+                ("fail", "FAIL")
+            };
+            output.push_str(&format!(
+                "<h2><span class=\"{}\">{}</span> {} vs {}</h2>\n",
+                class,
+                status,
+                xml_escape(&result.reference_path),
+                xml_escape(&result.test_path)
+            ));
+
+            output.push_str("<table class=\"metrics\">\n");
+            output.push_str(
+                "<tr><th>Check</th><th>Status</th><th>Value</th><th>Tolerance</th><th>Description</th></tr>\n",
+            );
+            for check in &result.checks {
+                let (class, icon) = if check.passed {
+                    ("pass", "✓")
+                } else {
+                    ("fail", "✗")
+                };
+                output.push_str(&format!(
+                    "<tr><td>{}</td><td class=\"{}\">{}</td><td>{:.6}</td><td>{:.6}</td><td>{}</td></tr>\n",
+                    xml_escape(&check.name),
+                    class,
+                    icon,
+                    check.actual,
+                    check.tolerance,
+                    xml_escape(&check.description)
+                ));
+            }
+            output.push_str("</table>\n");
+
+            let map = &result.metrics.spectral.diff_map;
+            if map.is_empty() {
+                output.push_str("<p>No spectral difference data.</p>\n");
+            } else {
+                output.push_str("<h3>Spectral difference (test - reference, dB)</h3>\n");
+                output.push_str(&heatmap_svg(map));
+            }
+        }
+
+        output.push_str("</body>\n</html>\n");
+
+        output
+    }
+}
+
+/// Render a diff map as an inline SVG heatmap
+///
+/// Low frequencies at the bottom; red where the test is louder, blue where it
+/// is quieter. The raw map is embedded alongside as JSON.
+fn heatmap_svg(map: &SpectralDiffMap) -> String {
+    const CELL_W: usize = 4;
+    const CELL_H: usize = 6;
+
+    let columns = map.diff_db.len();
+    let bands = map.freqs.len();
+    let width = columns * CELL_W;
+    let height = bands * CELL_H;
+    // Color scale covers at least ±1 dB so tiny differences stay pale
+    let scale = map.max_abs_db().max(1.0);
+
+    let mut svg = String::new();
+    svg.push_str(&format!(
+        "<svg class=\"heatmap\" xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\" \
+         preserveAspectRatio=\"none\" data-scale-db=\"{:.2}\">\n",
+        width, height, scale
+    ));
+    for (col, column) in map.diff_db.iter().enumerate() {
+        for (band, &diff) in column.iter().enumerate() {
+            let t = (diff / scale).clamp(-1.0, 1.0);
+            let fade = (255.0 * (1.0 - t.abs())).round() as u8;
+            let (r, g, b) = if t >= 0.0 {
+                (255, fade, fade)
+            } else {
+                (fade, fade, 255)
+            };
+            svg.push_str(&format!(
+                "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{:02x}{:02x}{:02x}\"/>\n",
+                col * CELL_W,
+                (bands - 1 - band) * CELL_H,
+                CELL_W,
+                CELL_H,
+                r,
+                g,
+                b
+            ));
+        }
+    }
+    svg.push_str("</svg>\n");
+
+    let duration = map.times.last().copied().unwrap_or(0.0);
+    svg.push_str(&format!(
+        "<p>0.00 s to {:.2} s, {:.0} Hz to {:.0} Hz (log), scale ±{:.2} dB</p>\n",
+        duration,
+        map.freqs.first().copied().unwrap_or(0.0),
+        map.freqs.last().copied().unwrap_or(0.0),
+        scale
+    ));
+
+    let json = serde_json::to_string(map).unwrap_or_else(|_| "{}".into());
+    svg.push_str(&format!(
+        "<script type=\"application/json\" class=\"heatmap-data\">{}</script>\n",
+        json.replace("</", "<\\/")
+    ));
+
+    svg
 }
 
 /// Simple timestamp without full chrono dependency
@@ -399,4 +556,44 @@ mod tests {
         assert!(xml.contains("tests=\"2\""));
         assert!(xml.contains("failures=\"1\""));
     }
+
+    #[test]
+    fn test_html_report() {
+        let mut report = DiffReport::new("Heatmap <Report>");
+        report.add_result(make_test_result(true));
+        report.add_result(make_test_result(false));
+
+        let path = std::env::temp_dir().join(format!("rf_audio_diff_{}.html", std::process::id()));
+        report.to_html(&path).unwrap();
+        let html = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).ok();
+
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<title>Heatmap &lt;Report&gt;</title>"));
+
+        // Metric tables
+        assert!(html.contains("<table class=\"summary\">"));
+        assert!(html.contains("<td>Failed</td><td>1</td>"));
+        assert_eq!(html.matches("<table class=\"metrics\">").count(), 2);
+        assert!(html.contains("<td>peak_diff</td>"));
+
+        // One heatmap per result with embedded data
+        assert_eq!(html.matches("<svg class=\"heatmap\"").count(), 2);
+        assert_eq!(html.matches("class=\"heatmap-data\"").count(), 2);
+        assert!(html.contains("\"diff_db\":[["));
+
+        // Identical audio is neutral white; -6 dB gain saturates to blue
+        let map = &report.results[0].metrics.spectral.diff_map;
+        assert!(!map.is_empty());
+        assert!(map.max_abs_db() < 1e-9);
+        let map = &report.results[1].metrics.spectral.diff_map;
+        assert!(
+            map.diff_db
+                .iter()
+                .flatten()
+                .all(|d| (d + 6.02).abs() < 0.01)
+        );
+        assert!(html.contains("fill=\"#ffffff\""));
+        assert!(html.contains("fill=\"#0000ff\""));
+    }
 }
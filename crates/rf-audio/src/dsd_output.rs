@@ -8,6 +8,25 @@
 //! - Automatic capability detection
 //! - DSD64/128/256/512 rate selection
 
+use cpal::SupportedStreamConfigRange;
+use cpal::traits::{DeviceTrait, StreamTrait};
+use cpal::{Device, SampleFormat, Stream, StreamConfig, SupportedStreamConfig};
+use rtrb::{Consumer, Producer, RingBuffer};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+#[cfg(target_os = "windows")]
+use std::sync::atomic::AtomicBool;
+
+use crate::{AudioError, AudioResult};
+
+/// DoP marker for even frames
+pub const DOP_MARKER_A: u8 = 0x05;
+/// DoP marker for odd frames
+pub const DOP_MARKER_B: u8 = 0xFA;
+/// DSD idle pattern (digital silence)
+pub const DSD_SILENCE: u8 = 0x69;
+
 /// DSD output mode
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum DsdOutputMode {
@@ -41,10 +60,10 @@ impl DsdRate {
     pub fn dop_pcm_rate(&self) -> u32 {
         // DoP requires specific PCM rates
         match self {
-            DsdRate::Dsd64 => 176_400,  // DSD64 → 176.4kHz PCM
-            DsdRate::Dsd128 => 352_800, // DSD128 → 352.8kHz PCM
-            DsdRate::Dsd256 => 705_600, // DSD256 → 705.6kHz PCM (rare)
-            DsdRate::Dsd512 => 705_600, // DSD512 → requires native or special handling
+            DsdRate::Dsd64 => 176_400,    // DSD64 → 176.4kHz PCM
+            DsdRate::Dsd128 => 352_800,   // DSD128 → 352.8kHz PCM
+            DsdRate::Dsd256 => 705_600,   // DSD256 → 705.6kHz PCM (rare)
+            DsdRate::Dsd512 => 1_411_200, // DSD512 → 1411.2kHz PCM (very rare)
         }
     }
 
@@ -87,9 +106,8 @@ impl Default for DsdCapabilities {
 }
 
 /// DoP (DSD over PCM) encoder
-#[allow(dead_code)]
 pub struct DoPEncoder {
-    /// Current marker (alternates 0x05/0xFA)
+    /// Current marker (alternates 0x05/0xFA per frame)
     marker_state: bool,
     /// Channel count
     channels: usize,
@@ -99,24 +117,30 @@ impl DoPEncoder {
     pub fn new(channels: usize) -> Self {
         Self {
             marker_state: false,
-            channels,
+            channels: channels.max(1),
         }
     }
 
-    /// Encode DSD bytes to DoP PCM samples
+    /// Encode byte-interleaved DSD (one byte per channel, in channel order)
+    /// into interleaved DoP PCM frames.
     ///
-    /// Each 24-bit PCM sample contains:
+    /// Each frame consumes two DSD bytes per channel. Every channel of a
+    /// frame carries the same marker; markers alternate between frames.
+    /// Each 24-bit PCM sample (left-aligned in i32) contains:
     /// - Bits 23-16: DoP marker (0x05 or 0xFA, alternating)
-    /// - Bits 15-8: DSD data byte (MSB)
-    /// - Bits 7-0: DSD data byte (LSB)
-    pub fn encode(&mut self, dsd_data: &[u8], output: &mut [i32]) {
-        const DOP_MARKER_A: u8 = 0x05;
-        const DOP_MARKER_B: u8 = 0xFA;
-
-        let mut dsd_idx = 0;
-        let mut out_idx = 0;
-
-        while dsd_idx + 1 < dsd_data.len() && out_idx < output.len() {
+    /// - Bits 15-8: older DSD byte
+    /// - Bits 7-0: newer DSD byte
+    ///
+    /// Returns the number of frames written.
+    pub fn encode(&mut self, dsd_data: &[u8], output: &mut [i32]) -> usize {
+        let channels = self.channels;
+        let frames = (dsd_data.len() / (2 * channels)).min(output.len() / channels);
+
+        for (bytes, out) in dsd_data
+            .chunks_exact(2 * channels)
+            .zip(output.chunks_exact_mut(channels))
+            .take(frames)
+        {
             let marker = if self.marker_state {
                 DOP_MARKER_B
             } else {
@@ -124,19 +148,23 @@ impl DoPEncoder {
             };
             self.marker_state = !self.marker_state;
 
-            // Pack two DSD bytes into one 24-bit DoP sample
-            let dsd_msb = dsd_data[dsd_idx] as i32;
-            let dsd_lsb = dsd_data[dsd_idx + 1] as i32;
+            for (ch, sample) in out.iter_mut().enumerate() {
+                *sample = Self::pack(marker, bytes[ch], bytes[channels + ch]);
+            }
+        }
 
-            // DoP format: marker(8) | dsd_msb(8) | dsd_lsb(8)
-            let dop_sample = ((marker as i32) << 16) | (dsd_msb << 8) | dsd_lsb;
+        frames
+    }
 
-            // Sign extend to 32-bit (DoP is always positive in upper bits)
-            output[out_idx] = dop_sample << 8; // Shift to align as 32-bit
+    /// Pack one DoP sample: marker(8) | dsd_msb(8) | dsd_lsb(8), left-aligned
+    #[inline]
+    fn pack(marker: u8, msb: u8, lsb: u8) -> i32 {
+        (((marker as u32) << 24) | ((msb as u32) << 16) | ((lsb as u32) << 8)) as i32
+    }
 
-            dsd_idx += 2;
-            out_idx += 1;
-        }
+    /// Channel count
+    pub fn channels(&self) -> usize {
+        self.channels
     }
 
     /// Reset encoder state
@@ -166,9 +194,6 @@ impl DoPDecoder {
 
     /// Check if PCM data is actually DoP
     pub fn detect_dop(&mut self, pcm_samples: &[i32]) -> bool {
-        const DOP_MARKER_A: u8 = 0x05;
-        const DOP_MARKER_B: u8 = 0xFA;
-
         for &sample in pcm_samples {
             // Extract marker from bits 23-16
             let marker = ((sample >> 24) & 0xFF) as u8;
@@ -333,6 +358,253 @@ impl AsioDsdOutput {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// DoP DEVICE OUTPUT
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// DoP frames encoded per pass in the output callback
+const DOP_CALLBACK_FRAMES: usize = 4096;
+
+/// Bit-transparent DSD playback through a PCM device using DoP
+///
+/// DSD bytes written with [`DsdOutput::write`] are byte-interleaved by
+/// channel. The audio callback packs them into DoP frames at the carrier
+/// rate (DSD rate / 16); when the queue runs dry it sends DoP-framed DSD
+/// silence so the DAC stays locked in DSD mode.
+pub struct DsdOutput {
+    stream: Stream,
+    producer: Producer<u8>,
+    dsd_rate: DsdRate,
+    carrier_rate: u32,
+    channels: usize,
+    sample_format: SampleFormat,
+    underruns: Arc<AtomicU64>,
+}
+
+impl DsdOutput {
+    /// Open a stereo DoP stream on `device` for `dsd_rate`.
+    ///
+    /// Fails with [`AudioError::DopUnsupported`] if the device cannot run
+    /// the DoP carrier rate with a bit-transparent sample format.
+    pub fn open(device: &Device, dsd_rate: DsdRate) -> AudioResult<Self> {
+        let channels = 2;
+        let carrier_rate = dsd_rate.dop_pcm_rate();
+
+        let ranges = device
+            .supported_output_configs()
+            .map_err(|e| AudioError::ConfigError(e.to_string()))?;
+        let supported =
+            select_dop_config(ranges, carrier_rate, channels as u16).ok_or_else(|| {
+                AudioError::DopUnsupported {
+                    device: device
+                        .description()
+                        .map(|d| d.name().to_string())
+                        .unwrap_or_else(|_| "unknown device".to_string()),
+                    dsd_rate: dsd_rate.sample_rate(),
+                    carrier_rate,
+                }
+            })?;
+
+        // Half a second of DSD bytes
+        let (producer, consumer) = RingBuffer::<u8>::new(carrier_rate as usize * channels);
+        let underruns = Arc::new(AtomicU64::new(0));
+        let sample_format = supported.sample_format();
+
+        let stream = build_dop_stream(
+            device,
+            &supported,
+            channels,
+            consumer,
+            Arc::clone(&underruns),
+        )?;
+
+        log::info!(
+            "DoP output: {} Hz DSD over {} Hz {:?}",
+            dsd_rate.sample_rate(),
+            carrier_rate,
+            sample_format
+        );
+
+        Ok(Self {
+            stream,
+            producer,
+            dsd_rate,
+            carrier_rate,
+            channels,
+            sample_format,
+            underruns,
+        })
+    }
+
+    /// Start playback
+    pub fn start(&self) -> AudioResult<()> {
+        self.stream
+            .play()
+            .map_err(|e| AudioError::StreamError(e.to_string()))
+    }
+
+    /// Stop playback
+    pub fn stop(&self) -> AudioResult<()> {
+        self.stream
+            .pause()
+            .map_err(|e| AudioError::StreamError(e.to_string()))
+    }
+
+    /// Queue byte-interleaved DSD data.
+    ///
+    /// Only whole byte-frames (one byte per channel) are queued. Returns the
+    /// number of bytes accepted; the caller retries the rest later.
+    pub fn write(&mut self, dsd_data: &[u8]) -> usize {
+        let len = dsd_data.len().min(self.producer.slots());
+        let len = len - len % self.channels;
+
+        for &byte in &dsd_data[..len] {
+            // Capacity checked above
+            let _ = self.producer.push(byte);
+        }
+
+        len
+    }
+
+    /// DSD rate being played
+    pub fn dsd_rate(&self) -> DsdRate {
+        self.dsd_rate
+    }
+
+    /// PCM carrier rate of the DoP stream
+    pub fn carrier_rate(&self) -> u32 {
+        self.carrier_rate
+    }
+
+    /// Channel count
+    pub fn channels(&self) -> usize {
+        self.channels
+    }
+
+    /// PCM sample format negotiated with the device
+    pub fn sample_format(&self) -> SampleFormat {
+        self.sample_format
+    }
+
+    /// Callbacks that ran out of queued DSD data
+    pub fn underruns(&self) -> u64 {
+        self.underruns.load(Ordering::Relaxed)
+    }
+}
+
+/// Pick a device config that carries DoP bit-transparently.
+///
+/// 32-bit integer is preferred; 32-bit float also works because every
+/// 24-bit DoP word is exactly representable in an f32 mantissa.
+fn select_dop_config(
+    ranges: impl Iterator<Item = SupportedStreamConfigRange>,
+    carrier_rate: u32,
+    channels: u16,
+) -> Option<SupportedStreamConfig> {
+    let candidates: Vec<SupportedStreamConfigRange> = ranges
+        .filter(|r| {
+            r.channels() == channels
+                && r.min_sample_rate() <= carrier_rate
+                && r.max_sample_rate() >= carrier_rate
+        })
+        .collect();
+
+    [SampleFormat::I32, SampleFormat::F32]
+        .into_iter()
+        .find_map(|format| candidates.iter().find(|r| r.sample_format() == format))
+        .map(|r| r.with_sample_rate(carrier_rate))
+}
+
+/// Fill `output` with DoP frames from the queue, DSD silence where it is empty.
+///
+/// Returns true if the queue ran dry.
+fn fill_dop(
+    consumer: &mut Consumer<u8>,
+    encoder: &mut DoPEncoder,
+    scratch: &mut [u8],
+    output: &mut [i32],
+) -> bool {
+    let channels = encoder.channels();
+    let frames = output.len() / channels;
+    let bytes = &mut scratch[..frames * 2 * channels];
+
+    let available = consumer.slots().min(bytes.len());
+    let available = available - available % channels;
+    for byte in &mut bytes[..available] {
+        *byte = consumer.pop().unwrap_or(DSD_SILENCE);
+    }
+    bytes[available..].fill(DSD_SILENCE);
+
+    encoder.encode(bytes, output);
+    available < bytes.len()
+}
+
+fn build_dop_stream(
+    device: &Device,
+    supported: &SupportedStreamConfig,
+    channels: usize,
+    mut consumer: Consumer<u8>,
+    underruns: Arc<AtomicU64>,
+) -> AudioResult<Stream> {
+    let config = StreamConfig {
+        channels: supported.channels(),
+        sample_rate: supported.sample_rate(),
+        buffer_size: cpal::BufferSize::Default,
+    };
+
+    // PRE-ALLOCATE: no allocations in the callback
+    let mut encoder = DoPEncoder::new(channels);
+    let mut scratch = vec![0u8; DOP_CALLBACK_FRAMES * 2 * channels];
+    let mut words = vec![0i32; DOP_CALLBACK_FRAMES * channels];
+    let block = DOP_CALLBACK_FRAMES * channels;
+
+    let error_callback = |err: cpal::StreamError| log::error!("DoP output stream error: {}", err);
+
+    let stream = match supported.sample_format() {
+        SampleFormat::I32 => device.build_output_stream(
+            &config,
+            move |data: &mut [i32], _: &cpal::OutputCallbackInfo| {
+                let mut dry = false;
+                for chunk in data.chunks_mut(block) {
+                    dry |= fill_dop(&mut consumer, &mut encoder, &mut scratch, chunk);
+                }
+                if dry {
+                    underruns.fetch_add(1, Ordering::Relaxed);
+                }
+            },
+            error_callback,
+            None,
+        ),
+        SampleFormat::F32 => device.build_output_stream(
+            &config,
+            move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+                let mut dry = false;
+                for chunk in data.chunks_mut(block) {
+                    let words = &mut words[..chunk.len()];
+                    dry |= fill_dop(&mut consumer, &mut encoder, &mut scratch, words);
+                    // Exact: 24 significant bits fit the f32 mantissa
+                    for (out, &word) in chunk.iter_mut().zip(words.iter()) {
+                        *out = word as f32 / 2_147_483_648.0;
+                    }
+                }
+                if dry {
+                    underruns.fetch_add(1, Ordering::Relaxed);
+                }
+            },
+            error_callback,
+            None,
+        ),
+        other => {
+            return Err(AudioError::ConfigError(format!(
+                "DoP needs I32 or F32 output, got {:?}",
+                other
+            )));
+        }
+    };
+
+    stream.map_err(|e| AudioError::StreamBuildError(e.to_string()))
+}
+
 /// DSD capability detector
 pub struct DsdCapabilityDetector;
 
@@ -526,6 +798,84 @@ mod tests {
         assert!(marker1 == 0x05 || marker1 == 0xFA);
     }
 
+    #[test]
+    fn test_dop_packing_dsd64() {
+        // DSD64 rides a 176.4kHz carrier: 16 DSD bits per PCM frame
+        let rate = DsdRate::Dsd64;
+        assert_eq!(rate.dop_pcm_rate(), 176_400);
+        assert_eq!(rate.dop_pcm_rate() * 16, rate.sample_rate());
+
+        // Byte-interleaved stereo: L0 R0 L1 R1 ...
+        let dsd: Vec<u8> = (0..16u8).collect();
+        let mut encoder = DoPEncoder::new(2);
+        let mut output = [0i32; 8];
+        assert_eq!(encoder.encode(&dsd, &mut output), 4);
+
+        for (frame, samples) in output.chunks(2).enumerate() {
+            let expected = if frame % 2 == 0 {
+                DOP_MARKER_A
+            } else {
+                DOP_MARKER_B
+            };
+            for (ch, &sample) in samples.iter().enumerate() {
+                let word = sample as u32;
+                assert_eq!((word >> 24) as u8, expected);
+                assert_eq!((word >> 16) as u8, dsd[frame * 4 + ch]);
+                assert_eq!((word >> 8) as u8, dsd[frame * 4 + 2 + ch]);
+                assert_eq!(word & 0xFF, 0);
+            }
+        }
+
+        // Marker sequence continues across calls and round-trips the detector
+        let mut more = [0i32; 8];
+        encoder.encode(&dsd, &mut more);
+        assert_eq!((more[0] as u32 >> 24) as u8, DOP_MARKER_A);
+        let mut decoder = DoPDecoder::new();
+        assert!(
+            decoder.detect_dop(
+                &[output, more]
+                    .concat()
+                    .iter()
+                    .step_by(2)
+                    .copied()
+                    .collect::<Vec<_>>()
+            )
+        );
+    }
+
+    #[test]
+    fn test_dop_config_selection() {
+        use cpal::SupportedBufferSize;
+
+        let range = |rate_max: u32, format: SampleFormat| {
+            SupportedStreamConfigRange::new(
+                2,
+                44_100,
+                rate_max,
+                SupportedBufferSize::Unknown,
+                format,
+            )
+        };
+
+        // 48kHz-only device cannot carry DSD64
+        let ranges = vec![range(48_000, SampleFormat::I32)];
+        assert!(select_dop_config(ranges.into_iter(), 176_400, 2).is_none());
+
+        // Prefers bit-exact integer output over float
+        let ranges = vec![
+            range(192_000, SampleFormat::F32),
+            range(192_000, SampleFormat::I32),
+            range(384_000, SampleFormat::I16),
+        ];
+        let config = select_dop_config(ranges.into_iter(), 176_400, 2).unwrap();
+        assert_eq!(config.sample_rate(), 176_400);
+        assert_eq!(config.sample_format(), SampleFormat::I32);
+
+        // 16-bit cannot hold the 24-bit DoP word
+        let ranges = vec![range(384_000, SampleFormat::I16)];
+        assert!(select_dop_config(ranges.into_iter(), 352_800, 2).is_none());
+    }
+
     #[test]
     fn test_dop_detector() {
         let mut decoder = DoPDecoder::new();
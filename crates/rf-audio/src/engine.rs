@@ -3,13 +3,14 @@
 //! Provides:
 //! - Stream management with device selection
 //! - Lock-free metering for UI
+//! - Xrun detection (callback overruns, output discontinuities)
 //! - Transport control (play/pause/stop)
 //! - Integration with DualPathEngine
 
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, AtomicU64, Ordering};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, SystemTime};
 
 use parking_lot::{Mutex, RwLock};
 
@@ -19,7 +20,7 @@ use rf_dsp::eq::{EqFilterType, ParametricEq}; // For EQ processing
 use rf_file::recording::{AudioRecorder, RecordingConfig, RecordingState};
 
 use crate::{
-    AudioConfig, AudioResult, AudioStream, get_default_input_device, get_default_output_device,
+    AudioConfig, AudioResult, AudioStream, XrunStats, get_default_input_device, get_default_output_device,
     get_input_device_by_name, get_output_device_by_name,
 };
 
@@ -306,6 +307,8 @@ pub struct AudioEngine {
     stream: Mutex<Option<AudioStream>>,
     /// Metering data (lock-free)
     pub meters: Arc<MeterData>,
+    /// Xrun counters (lock-free, kept across stream restarts)
+    pub xruns: Arc<XrunStats>,
     /// Transport position (lock-free)
     pub transport: Arc<TransportPosition>,
     /// Audio recorder (shared with audio callback)
@@ -342,6 +345,7 @@ impl AudioEngine {
             buffer_size: AtomicU32::new(default.buffer_size.as_u32()),
             stream: Mutex::new(None),
             meters: Arc::new(MeterData::default()),
+            xruns: Arc::new(XrunStats::default()),
             transport: Arc::new(TransportPosition::default()),
             recorder: Arc::new(AudioRecorder::new(recorder_config)),
             processor: Mutex::new(Box::new(PassthroughProcessor)),
@@ -365,6 +369,7 @@ impl AudioEngine {
             buffer_size: AtomicU32::new(settings.buffer_size.as_u32()),
             stream: Mutex::new(None),
             meters: Arc::new(MeterData::default()),
+            xruns: Arc::new(XrunStats::default()),
             transport: Arc::new(TransportPosition::default()),
             recorder: Arc::new(AudioRecorder::new(recorder_config)),
             processor: Mutex::new(Box::new(PassthroughProcessor)),
@@ -536,7 +541,13 @@ impl AudioEngine {
         });
 
         // Create and start stream (with input device if available)
-        let stream = AudioStream::new(&output_device, input_device.as_ref(), config, callback)?;
+        let stream = AudioStream::with_xrun_stats(
+            &output_device,
+            input_device.as_ref(),
+            config,
+            callback,
+            Arc::clone(&self.xruns),
+        )?;
         stream.start()?;
 
         if input_device.is_some() {
@@ -781,6 +792,30 @@ impl AudioEngine {
     pub fn reset_clip(&self) {
         self.meters.reset_clip();
     }
+
+    // ═══════════════════════════════════════════════════════════════════════════
+    // XRUNS
+    // ═══════════════════════════════════════════════════════════════════════════
+
+    /// Get xrun counters reference (for UI)
+    pub fn xrun_stats(&self) -> Arc<XrunStats> {
+        Arc::clone(&self.xruns)
+    }
+
+    /// Total xruns since creation or last reset
+    pub fn xrun_count(&self) -> u64 {
+        self.xruns.count()
+    }
+
+    /// Wall-clock time of the last xrun
+    pub fn last_xrun(&self) -> Option<SystemTime> {
+        self.xruns.last_xrun()
+    }
+
+    /// Reset xrun counters
+    pub fn reset_xruns(&self) {
+        self.xruns.reset();
+    }
 }
 
 impl Default for AudioEngine {
@@ -802,6 +837,34 @@ impl Drop for AudioEngine {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::XrunDetector;
+
+    /// Processor that deliberately blows its deadline
+    struct SlowProcessor {
+        delay: Duration,
+    }
+
+    impl AudioProcessor for SlowProcessor {
+        fn process(
+            &mut self,
+            _input_l: &[Sample],
+            _input_r: &[Sample],
+            _output_l: &mut [Sample],
+            _output_r: &mut [Sample],
+        ) {
+            thread::sleep(self.delay);
+        }
+
+        fn reset(&mut self) {}
+        fn set_sample_rate(&mut self, _sample_rate: f64) {}
+    }
+
+    fn run_block(detector: &mut XrunDetector, processor: &mut dyn AudioProcessor, frames: usize) {
+        let input = vec![0.0; frames];
+        let mut out_l = vec![0.0; frames];
+        let mut out_r = vec![0.0; frames];
+        detector.run(frames, || processor.process(&input, &input, &mut out_l, &mut out_r));
+    }
 
     #[test]
     fn test_meter_data() {
@@ -844,5 +907,49 @@ mod tests {
         assert!(!engine.is_running());
         assert_eq!(engine.transport_state(), TransportState::Stopped);
         assert_eq!(engine.position_samples(), 0);
+        assert_eq!(engine.xrun_count(), 0);
+        assert!(engine.last_xrun().is_none());
+    }
+
+    #[test]
+    fn test_overrun_increments_xrun_count() {
+        let engine = AudioEngine::new();
+        let mut detector = XrunDetector::new(engine.xrun_stats(), 48000.0);
+
+        // 4096 frames @ 48kHz = 85ms budget - passthrough never overruns
+        run_block(&mut detector, &mut PassthroughProcessor, 4096);
+        assert_eq!(engine.xrun_count(), 0);
+
+        // 64 frames @ 48kHz = 1.3ms budget - sleeping 10ms must overrun
+        let mut slow = SlowProcessor {
+            delay: Duration::from_millis(10),
+        };
+        run_block(&mut detector, &mut slow, 64);
+
+        assert_eq!(engine.xrun_count(), 1);
+        assert_eq!(engine.xruns.overruns(), 1);
+        assert!(engine.last_xrun().is_some());
+
+        engine.reset_xruns();
+        assert_eq!(engine.xrun_count(), 0);
+        assert!(engine.last_xrun().is_none());
+    }
+
+    #[test]
+    fn test_timestamp_gap_counts_discontinuity() {
+        let stats = Arc::new(XrunStats::default());
+        let mut detector = XrunDetector::new(Arc::clone(&stats), 48000.0);
+        let buffer = detector.buffer_duration(480); // 10ms
+
+        detector.check_timestamp(Duration::ZERO, 480);
+        detector.check_timestamp(buffer, 480);
+        // Small jitter is tolerated
+        detector.check_timestamp(buffer * 2 + Duration::from_millis(2), 480);
+        assert_eq!(stats.discontinuities(), 0);
+
+        // Skipped a whole buffer
+        detector.check_timestamp(buffer * 5, 480);
+        assert_eq!(stats.discontinuities(), 1);
+        assert_eq!(stats.count(), 1);
     }
 }
@@ -25,6 +25,15 @@ pub enum AudioError {
     #[error("Unsupported buffer size: {0}")]
     UnsupportedBufferSize(u32),
 
+    #[error(
+        "Device '{device}' cannot play DSD {dsd_rate} Hz over DoP: {carrier_rate} Hz 24-bit output not supported"
+    )]
+    DopUnsupported {
+        device: String,
+        dsd_rate: u32,
+        carrier_rate: u32,
+    },
+
     #[error("Backend error: {0}")]
     BackendError(String),
 }
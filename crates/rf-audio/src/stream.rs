@@ -9,7 +9,8 @@ use cpal::{
 };
 use rtrb::{Consumer, Producer, RingBuffer};
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 use rf_core::{BufferSize, Sample};
 
@@ -42,11 +43,144 @@ struct StreamRunningState {
     running: AtomicBool,
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// XRUN DETECTION (lock-free)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Xrun counters shared between the audio callback and the UI
+///
+/// Written from the audio thread with relaxed atomics only - safe to poll
+/// from any thread without blocking the callback.
+#[derive(Debug, Default)]
+pub struct XrunStats {
+    /// Callbacks that took longer than the buffer duration
+    overruns: AtomicU64,
+    /// Gaps in the device timeline (missed callbacks)
+    discontinuities: AtomicU64,
+    /// Time of the last xrun in microseconds since UNIX epoch (0 = never)
+    last_xrun_us: AtomicU64,
+}
+
+impl XrunStats {
+    /// Total xruns (overruns + discontinuities)
+    pub fn count(&self) -> u64 {
+        self.overruns() + self.discontinuities()
+    }
+
+    /// Number of callback overruns
+    pub fn overruns(&self) -> u64 {
+        self.overruns.load(Ordering::Relaxed)
+    }
+
+    /// Number of output discontinuities
+    pub fn discontinuities(&self) -> u64 {
+        self.discontinuities.load(Ordering::Relaxed)
+    }
+
+    /// Wall-clock time of the last xrun, if any occurred
+    pub fn last_xrun(&self) -> Option<SystemTime> {
+        match self.last_xrun_us.load(Ordering::Relaxed) {
+            0 => None,
+            us => Some(UNIX_EPOCH + Duration::from_micros(us)),
+        }
+    }
+
+    /// Reset all counters
+    pub fn reset(&self) {
+        self.overruns.store(0, Ordering::Relaxed);
+        self.discontinuities.store(0, Ordering::Relaxed);
+        self.last_xrun_us.store(0, Ordering::Relaxed);
+    }
+
+    fn record_overrun(&self) {
+        self.overruns.fetch_add(1, Ordering::Relaxed);
+        self.stamp();
+    }
+
+    fn record_discontinuity(&self) {
+        self.discontinuities.fetch_add(1, Ordering::Relaxed);
+        self.stamp();
+    }
+
+    fn stamp(&self) {
+        let us = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_micros() as u64)
+            .unwrap_or(1)
+            .max(1);
+        self.last_xrun_us.store(us, Ordering::Relaxed);
+    }
+}
+
+/// Per-stream xrun detector (owned by the audio callback)
+///
+/// - Overrun: processing took longer than the buffer lasts
+/// - Discontinuity: device time jumped further than one buffer (+ jitter)
+pub struct XrunDetector {
+    stats: Arc<XrunStats>,
+    sample_rate: f64,
+    /// Device time at which the next buffer is expected
+    expected_next: Option<Duration>,
+}
+
+impl XrunDetector {
+    /// Create detector reporting into `stats`
+    pub fn new(stats: Arc<XrunStats>, sample_rate: f64) -> Self {
+        Self {
+            stats,
+            sample_rate,
+            expected_next: None,
+        }
+    }
+
+    /// Shared counters
+    pub fn stats(&self) -> &Arc<XrunStats> {
+        &self.stats
+    }
+
+    /// Duration of a buffer of `frames` at the stream sample rate
+    pub fn buffer_duration(&self, frames: usize) -> Duration {
+        Duration::from_secs_f64(frames as f64 / self.sample_rate.max(1.0))
+    }
+
+    /// Run one block of processing, counting an overrun if it exceeds the buffer duration
+    #[inline]
+    pub fn run<R>(&mut self, frames: usize, process: impl FnOnce() -> R) -> R {
+        let start = Instant::now();
+        let result = process();
+        if start.elapsed() > self.buffer_duration(frames) {
+            self.stats.record_overrun();
+        }
+        result
+    }
+
+    /// Check the device timestamp of a callback for a gap since the previous one
+    ///
+    /// `time` is measured from any fixed origin (e.g. the first callback).
+    /// Half a buffer of jitter is tolerated.
+    #[inline]
+    pub fn check_timestamp(&mut self, time: Duration, frames: usize) {
+        let buffer = self.buffer_duration(frames);
+        if let Some(expected) = self.expected_next
+            && time > expected + buffer / 2
+        {
+            self.stats.record_discontinuity();
+        }
+        self.expected_next = Some(time + buffer);
+    }
+
+    /// Forget timeline state (call when the stream restarts)
+    pub fn reset(&mut self) {
+        self.expected_next = None;
+    }
+}
+
 /// Audio stream wrapper
 pub struct AudioStream {
     _output_stream: Stream,
     _input_stream: Option<Stream>,
     running_state: Arc<StreamRunningState>,
+    xruns: Arc<XrunStats>,
     config: AudioConfig,
     /// Input buffer info for recording
     pub input_buffer: Option<Arc<SharedInputBuffer>>,
@@ -64,6 +198,25 @@ impl AudioStream {
         input_device: Option<&Device>,
         config: AudioConfig,
         callback: AudioCallback,
+    ) -> AudioResult<Self> {
+        Self::with_xrun_stats(
+            output_device,
+            input_device,
+            config,
+            callback,
+            Arc::new(XrunStats::default()),
+        )
+    }
+
+    /// Create a new audio stream reporting xruns into shared `xruns` counters
+    ///
+    /// Lets the owner keep the counters across stream restarts.
+    pub fn with_xrun_stats(
+        output_device: &Device,
+        input_device: Option<&Device>,
+        config: AudioConfig,
+        callback: AudioCallback,
+        xruns: Arc<XrunStats>,
     ) -> AudioResult<Self> {
         let running_state = Arc::new(StreamRunningState {
             running: AtomicBool::new(false),
@@ -91,12 +244,14 @@ impl AudioStream {
             config.buffer_size,
             callback,
             input_consumer,
+            XrunDetector::new(Arc::clone(&xruns), config.sample_rate.as_f64()),
         )?;
 
         Ok(Self {
             _output_stream: output_stream,
             _input_stream: input_stream,
             running_state,
+            xruns,
             config,
             input_buffer: input_info,
         })
@@ -143,6 +298,11 @@ impl AudioStream {
     pub fn config(&self) -> &AudioConfig {
         &self.config
     }
+
+    /// Get xrun counters (for UI)
+    pub fn xrun_stats(&self) -> Arc<XrunStats> {
+        Arc::clone(&self.xruns)
+    }
 }
 
 fn get_output_stream_config(
@@ -220,12 +380,14 @@ fn get_stream_config(
 /// - Input samples come from rtrb Consumer (lock-free)
 /// - All buffers pre-allocated before stream creation
 /// - Zero allocations in audio callback
+/// - Xruns reported through atomics (overruns + timestamp gaps)
 fn build_output_stream_lockfree(
     device: &Device,
     supported_config: &SupportedStreamConfig,
     buffer_size: BufferSize,
     mut callback: AudioCallback,
     input_consumer: Option<Consumer<f32>>,
+    mut xrun_detector: XrunDetector,
 ) -> AudioResult<Stream> {
     let channels = supported_config.channels() as usize;
     let sample_rate = supported_config.sample_rate();
@@ -254,10 +416,13 @@ fn build_output_stream_lockfree(
     // Track if denormals have been set (once per audio thread)
     let mut denormals_set = false;
 
+    // Playback time of the first callback - origin for discontinuity detection
+    let mut timeline_origin: Option<cpal::StreamInstant> = None;
+
     let stream = device
         .build_output_stream(
             &config,
-            move |data: &mut [f32], _: &cpal::OutputCallbackInfo| {
+            move |data: &mut [f32], info: &cpal::OutputCallbackInfo| {
                 // ZERO ALLOCATIONS IN THIS CLOSURE
                 // All buffers are pre-allocated and moved in
 
@@ -271,6 +436,13 @@ fn build_output_stream_lockfree(
                 let frames = data.len() / channels;
                 let stereo_samples = frames * 2;
 
+                // Detect output discontinuities from device timestamps
+                let playback = info.timestamp().playback;
+                let origin = *timeline_origin.get_or_insert(playback);
+                if let Some(time) = playback.duration_since(&origin) {
+                    xrun_detector.check_timestamp(time, frames);
+                }
+
                 // Read from input ring buffer if available (LOCK-FREE)
                 if let Some(ref mut consumer) = input_rx {
                     // Read available samples from ring buffer
@@ -307,10 +479,13 @@ fn build_output_stream_lockfree(
 
                 // Call user callback directly - NO MUTEX
                 // Callback was MOVED into this closure
-                callback(
-                    &input_buffer_f64[..stereo_samples],
-                    &mut output_buffer_f64[..stereo_samples],
-                );
+                // Timed against the buffer duration to detect overruns
+                xrun_detector.run(frames, || {
+                    callback(
+                        &input_buffer_f64[..stereo_samples],
+                        &mut output_buffer_f64[..stereo_samples],
+                    )
+                });
 
                 // Convert f64 to f32 and write to output
                 // Handle mono/stereo/multi-channel conversion
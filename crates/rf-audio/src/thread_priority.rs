@@ -6,55 +6,114 @@
 //!
 //! # Platform Support
 //!
-//! - **macOS**: pthread QoS class (USER_INTERACTIVE) + real-time scheduling
+//! - **macOS**: pthread QoS class (USER_INTERACTIVE) + real-time scheduling,
+//!   plus audio workgroup membership (macOS 11+) via [`join_audio_workgroup`]
 //! - **Windows**: MMCSS (Multimedia Class Scheduler Service) "Pro Audio" class
-//! - **Linux**: SCHED_FIFO with elevated priority (requires CAP_SYS_NICE or root)
+//! - **Linux**: SCHED_FIFO, falling back to SCHED_RR (requires an `rtprio`
+//!   limit, CAP_SYS_NICE or root)
 //!
 //! # Usage
 //!
 //! Call `set_realtime_priority()` at the start of your audio callback thread.
-//! This should be done once when the audio stream starts, not on every callback.
+//! The outcome is cached per thread, so calling it again is cheap and never
+//! retries a denied request.
+//!
+//! Elevation never fails hard: when the OS refuses, the thread keeps running
+//! at normal priority and a warning is logged once. Surface
+//! [`PriorityResult::recommended_action`] to the user so they can fix their
+//! system configuration.
 
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::cell::Cell;
 
-/// Track if priority has been set (avoid repeated calls)
-static PRIORITY_SET: AtomicBool = AtomicBool::new(false);
+thread_local! {
+    /// Outcome of the first elevation attempt on this thread
+    static PRIORITY_STATE: Cell<Option<PriorityResult>> = const { Cell::new(None) };
+}
 
 /// Result of priority elevation attempt
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum PriorityResult {
-    /// Successfully elevated to real-time priority
-    Success,
-    /// Already set (no action needed)
+    /// Thread now runs with real-time scheduling
+    Elevated,
+    /// Already elevated on this thread (no action needed)
     AlreadySet,
-    /// Failed to set priority (non-fatal, will use default)
-    Failed,
-    /// Platform not supported
+    /// OS refused real-time scheduling; the thread keeps running at normal
+    /// priority. Audio still plays but is more prone to dropouts under load.
+    ///
+    /// Recommended user action (see [`PriorityResult::recommended_action`]):
+    /// - **Linux**: add the user to the `audio` group with an `rtprio` limit,
+    ///   e.g. `@audio - rtprio 95` in `/etc/security/limits.d/audio.conf`,
+    ///   then log in again. Inside containers, grant `CAP_SYS_NICE`.
+    /// - **Windows**: make sure the "Multimedia Class Scheduler" service is
+    ///   running.
+    /// - **macOS**: quit apps that hold real-time threads, or raise the
+    ///   buffer size.
+    Denied,
+    /// Platform has no real-time scheduling API
     Unsupported,
 }
 
+impl PriorityResult {
+    /// Whether the thread runs with real-time scheduling
+    pub fn is_elevated(&self) -> bool {
+        matches!(self, Self::Elevated | Self::AlreadySet)
+    }
+
+    /// User-facing hint for fixing a denied request (None otherwise)
+    pub fn recommended_action(&self) -> Option<&'static str> {
+        if *self != Self::Denied {
+            return None;
+        }
+
+        if cfg!(target_os = "linux") {
+            Some(
+                "Real-time priority denied. Add your user to the 'audio' group with an \
+                 rtprio limit (e.g. '@audio - rtprio 95' in /etc/security/limits.d/audio.conf) \
+                 and log in again, or grant CAP_SYS_NICE.",
+            )
+        } else if cfg!(target_os = "windows") {
+            Some(
+                "Real-time priority denied. Make sure the Multimedia Class Scheduler \
+                 service (MMCSS) is running.",
+            )
+        } else {
+            Some(
+                "Real-time priority denied. Close other real-time audio apps or increase \
+                 the buffer size.",
+            )
+        }
+    }
+}
+
 /// Set real-time priority for the current thread.
 ///
-/// This function is safe to call multiple times - it will only
-/// attempt to set priority once per process.
+/// Safe to call repeatedly (e.g. from the first audio callback): only the
+/// first call per thread touches the scheduler, later calls return
+/// `AlreadySet` or the cached `Denied`/`Unsupported` outcome. Never panics.
 ///
 /// # Returns
 ///
 /// `PriorityResult` indicating the outcome.
 pub fn set_realtime_priority() -> PriorityResult {
-    if PRIORITY_SET.swap(true, Ordering::SeqCst) {
-        return PriorityResult::AlreadySet;
+    if let Some(previous) = PRIORITY_STATE.with(Cell::get) {
+        return match previous {
+            PriorityResult::Elevated => PriorityResult::AlreadySet,
+            other => other,
+        };
     }
 
     let result = platform_set_priority();
+    PRIORITY_STATE.with(|state| state.set(Some(result)));
 
     match result {
-        PriorityResult::Success => {
+        PriorityResult::Elevated => {
             log::info!("Audio thread elevated to real-time priority");
         }
-        PriorityResult::Failed => {
-            log::warn!("Failed to set real-time thread priority (non-fatal)");
-            PRIORITY_SET.store(false, Ordering::SeqCst); // Allow retry
+        PriorityResult::Denied => {
+            log::warn!(
+                "Real-time thread priority denied, audio thread running at normal priority. {}",
+                result.recommended_action().unwrap_or_default()
+            );
         }
         PriorityResult::Unsupported => {
             log::debug!("Real-time priority not supported on this platform");
@@ -65,10 +124,10 @@ pub fn set_realtime_priority() -> PriorityResult {
     result
 }
 
-/// Reset priority tracking (for testing)
+/// Reset priority tracking for the current thread (for testing)
 #[doc(hidden)]
 pub fn reset_priority_state() {
-    PRIORITY_SET.store(false, Ordering::SeqCst);
+    PRIORITY_STATE.with(|state| state.set(None));
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -159,18 +218,150 @@ fn platform_set_priority() -> PriorityResult {
     };
 
     if result == 0 {
-        PriorityResult::Success
+        PriorityResult::Elevated
     } else {
         log::debug!("thread_policy_set failed: {} (QoS still applied)", result);
         // QoS was set, so partial success
         if qos_result == 0 {
-            PriorityResult::Success
+            PriorityResult::Elevated
         } else {
-            PriorityResult::Failed
+            PriorityResult::Denied
         }
     }
 }
 
+/// Join the IO workgroup of a CoreAudio device (macOS 11+)
+///
+/// Worker threads that render audio for a device should join its workgroup
+/// so the scheduler accounts for them in the device deadline. The device's
+/// own IO thread is already a member. Returns `Unsupported` on macOS
+/// versions without the workgroup API. Membership lasts until the thread
+/// exits or calls [`leave_audio_workgroup`].
+#[cfg(target_os = "macos")]
+pub fn join_audio_workgroup(device_id: u32) -> PriorityResult {
+    use std::ffi::c_void;
+
+    // 'oswg'
+    const K_AUDIO_DEVICE_PROPERTY_IO_THREAD_OS_WORKGROUP: u32 = 0x6f737767;
+    // 'glob'
+    const K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL: u32 = 0x676c6f62;
+    const K_AUDIO_OBJECT_PROPERTY_ELEMENT_MAIN: u32 = 0;
+
+    #[repr(C)]
+    struct AudioObjectPropertyAddress {
+        selector: u32,
+        scope: u32,
+        element: u32,
+    }
+
+    unsafe extern "C" {
+        fn AudioObjectGetPropertyData(
+            object: u32,
+            address: *const AudioObjectPropertyAddress,
+            qualifier_size: u32,
+            qualifier: *const c_void,
+            data_size: *mut u32,
+            data: *mut c_void,
+        ) -> i32;
+    }
+
+    if WORKGROUP_TOKEN.with(|t| t.borrow().is_some()) {
+        return PriorityResult::AlreadySet;
+    }
+
+    // Resolved at runtime so older macOS versions fall back cleanly
+    let Some(join) = workgroup_fn::<WorkgroupJoinFn>(c"os_workgroup_join") else {
+        return PriorityResult::Unsupported;
+    };
+
+    let address = AudioObjectPropertyAddress {
+        selector: K_AUDIO_DEVICE_PROPERTY_IO_THREAD_OS_WORKGROUP,
+        scope: K_AUDIO_OBJECT_PROPERTY_SCOPE_GLOBAL,
+        element: K_AUDIO_OBJECT_PROPERTY_ELEMENT_MAIN,
+    };
+    let mut workgroup: *mut c_void = std::ptr::null_mut();
+    let mut size = std::mem::size_of::<*mut c_void>() as u32;
+    let status = unsafe {
+        AudioObjectGetPropertyData(
+            device_id,
+            &address,
+            0,
+            std::ptr::null(),
+            &mut size,
+            &mut workgroup as *mut _ as *mut c_void,
+        )
+    };
+    if status != 0 || workgroup.is_null() {
+        log::debug!("Device {} has no IO workgroup (status {})", device_id, status);
+        return PriorityResult::Unsupported;
+    }
+
+    let mut token = Box::new(WorkgroupToken {
+        workgroup,
+        opaque: [0; 40],
+    });
+    let result = unsafe { join(workgroup, token.opaque.as_mut_ptr()) };
+    if result == 0 {
+        WORKGROUP_TOKEN.with(|t| *t.borrow_mut() = Some(token));
+        PriorityResult::Elevated
+    } else {
+        log::warn!("os_workgroup_join failed: {}", result);
+        release_workgroup(workgroup);
+        PriorityResult::Denied
+    }
+}
+
+/// Leave the workgroup joined by [`join_audio_workgroup`] on this thread
+#[cfg(target_os = "macos")]
+pub fn leave_audio_workgroup() {
+    let Some(mut token) = WORKGROUP_TOKEN.with(|t| t.borrow_mut().take()) else {
+        return;
+    };
+    if let Some(leave) = workgroup_fn::<WorkgroupLeaveFn>(c"os_workgroup_leave") {
+        unsafe { leave(token.workgroup, token.opaque.as_mut_ptr()) };
+    }
+    release_workgroup(token.workgroup);
+}
+
+/// Join token (`os_workgroup_join_token_s`, 40 bytes) plus the retained workgroup
+#[cfg(target_os = "macos")]
+struct WorkgroupToken {
+    workgroup: *mut std::ffi::c_void,
+    opaque: [u8; 40],
+}
+
+#[cfg(target_os = "macos")]
+thread_local! {
+    static WORKGROUP_TOKEN: std::cell::RefCell<Option<Box<WorkgroupToken>>> =
+        const { std::cell::RefCell::new(None) };
+}
+
+#[cfg(target_os = "macos")]
+type WorkgroupJoinFn = unsafe extern "C" fn(*mut std::ffi::c_void, *mut u8) -> i32;
+#[cfg(target_os = "macos")]
+type WorkgroupLeaveFn = unsafe extern "C" fn(*mut std::ffi::c_void, *mut u8);
+
+/// Look up a libSystem workgroup function (absent before macOS 11)
+#[cfg(target_os = "macos")]
+fn workgroup_fn<F: Copy>(name: &std::ffi::CStr) -> Option<F> {
+    let ptr = unsafe { libc::dlsym(libc::RTLD_DEFAULT, name.as_ptr()) };
+    if ptr.is_null() {
+        None
+    } else {
+        // SAFETY: F is one of the fn pointer types above, matching the C signature
+        Some(unsafe { std::mem::transmute_copy::<*mut libc::c_void, F>(&ptr) })
+    }
+}
+
+/// Release the +1 reference returned by the workgroup property
+#[cfg(target_os = "macos")]
+fn release_workgroup(workgroup: *mut std::ffi::c_void) {
+    unsafe extern "C" {
+        fn os_release(object: *mut std::ffi::c_void);
+    }
+    unsafe { os_release(workgroup) };
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // Windows Implementation
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -197,7 +388,7 @@ fn platform_set_priority() -> PriorityResult {
             "MMCSS Pro Audio class registered (task index: {})",
             task_index
         );
-        return PriorityResult::Success;
+        return PriorityResult::Elevated;
     }
 
     log::debug!("MMCSS registration failed, falling back to thread priority");
@@ -207,9 +398,9 @@ fn platform_set_priority() -> PriorityResult {
     let result = unsafe { SetThreadPriority(current_thread, THREAD_PRIORITY_TIME_CRITICAL) };
 
     if result.as_bool() {
-        PriorityResult::Success
+        PriorityResult::Elevated
     } else {
-        PriorityResult::Failed
+        PriorityResult::Denied
     }
 }
 
@@ -220,42 +411,65 @@ fn platform_set_priority() -> PriorityResult {
 #[cfg(target_os = "linux")]
 fn platform_set_priority() -> PriorityResult {
     use libc::{
-        SCHED_FIFO, SCHED_RR, pthread_self, pthread_setschedparam, sched_param, sched_setscheduler,
+        EPERM, RLIMIT_RTPRIO, SCHED_FIFO, SCHED_RR, geteuid, getrlimit, pthread_getschedparam,
+        pthread_self, pthread_setschedparam, rlimit, sched_param,
     };
 
-    // Try SCHED_FIFO first (requires CAP_SYS_NICE or root)
-    // Priority 80 is high but leaves room for kernel threads
-    let mut param = sched_param { sched_priority: 80 };
+    // Priority 80 is high but leaves room for kernel threads. Unprivileged
+    // users may only go as high as their RLIMIT_RTPRIO (limits.conf rtprio).
+    const PREFERRED_PRIORITY: i32 = 80;
 
-    let result = unsafe { sched_setscheduler(0, SCHED_FIFO, &param) };
-
-    if result == 0 {
-        return PriorityResult::Success;
-    }
+    let mut limit = rlimit {
+        rlim_cur: 0,
+        rlim_max: 0,
+    };
+    let rtprio_limit = if unsafe { getrlimit(RLIMIT_RTPRIO, &mut limit) } == 0 {
+        limit.rlim_cur.min(i32::MAX as _) as i32
+    } else {
+        0
+    };
+    let priority = if unsafe { geteuid() } == 0 || rtprio_limit == 0 {
+        // Root, or no limit configured: CAP_SYS_NICE may still allow it
+        PREFERRED_PRIORITY
+    } else {
+        PREFERRED_PRIORITY.min(rtprio_limit)
+    };
 
-    log::debug!("SCHED_FIFO failed (need CAP_SYS_NICE), trying SCHED_RR");
+    let thread = unsafe { pthread_self() };
 
-    // Try SCHED_RR as fallback (slightly less strict)
-    param.sched_priority = 70;
-    let result = unsafe { sched_setscheduler(0, SCHED_RR, &param) };
+    // SCHED_FIFO first, SCHED_RR as fallback (slightly less strict)
+    for policy in [SCHED_FIFO, SCHED_RR] {
+        let param = sched_param {
+            sched_priority: priority,
+        };
+        let err = unsafe { pthread_setschedparam(thread, policy, &param) };
+
+        if err == 0 {
+            // Verify the kernel actually applied the policy
+            let mut applied_policy = 0;
+            let mut applied = sched_param { sched_priority: 0 };
+            let ok = unsafe { pthread_getschedparam(thread, &mut applied_policy, &mut applied) };
+            if ok == 0 && (applied_policy == SCHED_FIFO || applied_policy == SCHED_RR) {
+                log::debug!(
+                    "Linux RT scheduling: policy {} priority {}",
+                    applied_policy,
+                    applied.sched_priority
+                );
+                return PriorityResult::Elevated;
+            }
+        }
 
-    if result == 0 {
-        return PriorityResult::Success;
+        log::debug!(
+            "pthread_setschedparam(policy {}, priority {}) failed: {}{}",
+            policy,
+            priority,
+            err,
+            if err == EPERM { " (permission denied)" } else { "" }
+        );
     }
 
-    log::debug!("SCHED_RR failed, trying pthread_setschedparam");
-
-    // Last resort: pthread_setschedparam (might work without root in some configs)
-    param.sched_priority = 50;
-    let thread = unsafe { pthread_self() };
-    let result = unsafe { pthread_setschedparam(thread, SCHED_FIFO, &param) };
-
-    if result == 0 {
-        PriorityResult::Success
-    } else {
-        log::debug!("All Linux RT scheduling methods failed (errno: {})", result);
-        PriorityResult::Failed
-    }
+    // Thread stays on SCHED_OTHER at normal priority
+    PriorityResult::Denied
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -282,18 +496,44 @@ mod tests {
         let first = set_realtime_priority();
         let second = set_realtime_priority();
 
-        // Second call should return AlreadySet (if first succeeded)
-        // or allow retry (if first failed)
         assert!(
-            first == PriorityResult::Success
-                || first == PriorityResult::Failed
+            first == PriorityResult::Elevated
+                || first == PriorityResult::Denied
                 || first == PriorityResult::Unsupported
         );
 
-        if first == PriorityResult::Success {
+        // Second call never retries: AlreadySet after success,
+        // otherwise the cached outcome
+        if first == PriorityResult::Elevated {
             assert_eq!(second, PriorityResult::AlreadySet);
+        } else {
+            assert_eq!(second, first);
         }
 
         reset_priority_state();
     }
+
+    #[test]
+    fn test_priority_state_is_per_thread() {
+        reset_priority_state();
+        let main = set_realtime_priority();
+
+        // A fresh thread makes its own attempt instead of seeing AlreadySet
+        let other = std::thread::spawn(set_realtime_priority).join().unwrap();
+        assert_ne!(other, PriorityResult::AlreadySet);
+        if main != PriorityResult::Elevated {
+            assert_eq!(other, main);
+        }
+
+        reset_priority_state();
+    }
+
+    #[test]
+    fn test_recommended_action_only_when_denied() {
+        assert!(PriorityResult::Denied.recommended_action().is_some());
+        assert!(PriorityResult::Elevated.recommended_action().is_none());
+        assert!(PriorityResult::Unsupported.recommended_action().is_none());
+        assert!(PriorityResult::AlreadySet.is_elevated());
+        assert!(!PriorityResult::Denied.is_elevated());
+    }
 }
@@ -6,8 +6,10 @@
 //! - Crest Factor
 //! - Zwicker Loudness (ISO 532-1)
 //! - Sharpness, Roughness, Fluctuation
+//! - Scrolling history of LUFS-S, true peak and correlation for graphs
 
 use std::sync::LazyLock;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use parking_lot::RwLock;
 use rf_dsp::loudness_advanced::PsychoacousticMeter;
 use rf_dsp::metering_simd::{CrestFactorMeter, PsrMeter, TruePeak8x};
@@ -28,6 +30,149 @@ static CREST_METER: LazyLock<RwLock<Option<CrestFactorMeter>>> = LazyLock::new(|
 /// Global Psychoacoustic meter (Zwicker + Sharpness + Roughness + Fluctuation)
 static PSYCHOACOUSTIC: LazyLock<RwLock<Option<PsychoacousticMeter>>> = LazyLock::new(|| RwLock::new(None));
 
+/// Global meter history (lock-free reads from the UI thread)
+static METER_HISTORY: LazyLock<MeterHistory> =
+    LazyLock::new(|| MeterHistory::new(MAX_HISTORY_LENGTH));
+
+/// History recorder state (audio thread only)
+static HISTORY_RECORDER: LazyLock<RwLock<HistoryRecorder>> =
+    LazyLock::new(|| RwLock::new(HistoryRecorder::new(48000.0, DEFAULT_HISTORY_INTERVAL_MS)));
+
+/// Upper bound on history entries per series
+pub const MAX_HISTORY_LENGTH: usize = 6000;
+
+/// Default history: 300 entries at 100 ms = 30 seconds
+const DEFAULT_HISTORY_LENGTH: usize = 300;
+const DEFAULT_HISTORY_INTERVAL_MS: f64 = 100.0;
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// METER HISTORY
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Bounded ring of LUFS-S, true peak and correlation samples
+///
+/// Single writer (audio thread), any number of lock-free readers. Values are
+/// stored as f64 bits in atomics; a reader racing the writer may see the
+/// oldest entry replaced mid-copy, which is harmless for plotting.
+pub struct MeterHistory {
+    lufs_short: Box<[AtomicU64]>,
+    true_peak: Box<[AtomicU64]>,
+    correlation: Box<[AtomicU64]>,
+    /// Active ring length (<= capacity)
+    length: AtomicUsize,
+    /// Total entries written since the last clear
+    written: AtomicUsize,
+}
+
+impl MeterHistory {
+    /// Create history with fixed capacity; active length starts at the default
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        let series = || (0..capacity).map(|_| AtomicU64::new(0)).collect();
+        Self {
+            lufs_short: series(),
+            true_peak: series(),
+            correlation: series(),
+            length: AtomicUsize::new(DEFAULT_HISTORY_LENGTH.min(capacity)),
+            written: AtomicUsize::new(0),
+        }
+    }
+
+    /// Set ring length (clamped to 1..=capacity) and clear
+    pub fn set_length(&self, length: usize) {
+        self.length
+            .store(length.clamp(1, self.lufs_short.len()), Ordering::Release);
+        self.clear();
+    }
+
+    /// Active ring length
+    pub fn length(&self) -> usize {
+        self.length.load(Ordering::Acquire)
+    }
+
+    /// Drop all recorded entries
+    pub fn clear(&self) {
+        self.written.store(0, Ordering::Release);
+    }
+
+    /// Append one entry, overwriting the oldest once full
+    pub fn push(&self, lufs_short: f64, true_peak_dbtp: f64, correlation: f64) {
+        let n = self.written.load(Ordering::Relaxed);
+        let i = n % self.length();
+        self.lufs_short[i].store(lufs_short.to_bits(), Ordering::Relaxed);
+        self.true_peak[i].store(true_peak_dbtp.to_bits(), Ordering::Relaxed);
+        self.correlation[i].store(correlation.to_bits(), Ordering::Relaxed);
+        self.written.store(n + 1, Ordering::Release);
+    }
+
+    /// Copy the recorded entries, oldest first
+    pub fn snapshot(&self, interval_ms: f64) -> MeterHistoryData {
+        let n = self.written.load(Ordering::Acquire);
+        let length = self.length();
+        let count = n.min(length);
+        let read = |series: &[AtomicU64]| -> Vec<f64> {
+            (n - count..n)
+                .map(|k| f64::from_bits(series[k % length].load(Ordering::Relaxed)))
+                .collect()
+        };
+        MeterHistoryData {
+            lufs_short: read(&self.lufs_short),
+            true_peak_dbtp: read(&self.true_peak),
+            correlation: read(&self.correlation),
+            interval_ms,
+        }
+    }
+}
+
+/// Accumulates correlation and paces history writes (audio thread)
+struct HistoryRecorder {
+    sample_rate: f64,
+    interval_samples: usize,
+    interval_ms: f64,
+    elapsed: usize,
+    sum_lr: f64,
+    sum_ll: f64,
+    sum_rr: f64,
+}
+
+impl HistoryRecorder {
+    fn new(sample_rate: f64, interval_ms: f64) -> Self {
+        Self {
+            sample_rate,
+            interval_samples: ((sample_rate * interval_ms / 1000.0) as usize).max(1),
+            interval_ms,
+            elapsed: 0,
+            sum_lr: 0.0,
+            sum_ll: 0.0,
+            sum_rr: 0.0,
+        }
+    }
+
+    /// Accumulate a block; returns interval correlation each time an interval completes
+    fn process(&mut self, left: &[f64], right: &[f64], mut on_interval: impl FnMut(f64)) {
+        for (&l, &r) in left.iter().zip(right.iter()) {
+            self.sum_lr += l * r;
+            self.sum_ll += l * l;
+            self.sum_rr += r * r;
+            self.elapsed += 1;
+
+            if self.elapsed >= self.interval_samples {
+                let denom = (self.sum_ll * self.sum_rr).sqrt();
+                let correlation = if denom > 1e-20 {
+                    (self.sum_lr / denom).clamp(-1.0, 1.0)
+                } else {
+                    0.0
+                };
+                on_interval(correlation);
+                self.elapsed = 0;
+                self.sum_lr = 0.0;
+                self.sum_ll = 0.0;
+                self.sum_rr = 0.0;
+            }
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // DATA TRANSFER STRUCTS
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -86,6 +231,19 @@ pub struct PsychoacousticData {
     pub specific_loudness: Vec<f64>,
 }
 
+/// Scrolling meter history for Flutter (oldest first, most recent last)
+#[derive(Debug, Clone, Default)]
+pub struct MeterHistoryData {
+    /// Short-term loudness in LUFS
+    pub lufs_short: Vec<f64>,
+    /// True peak in dBTP
+    pub true_peak_dbtp: Vec<f64>,
+    /// Stereo correlation (-1 to +1)
+    pub correlation: Vec<f64>,
+    /// Time between entries in milliseconds
+    pub interval_ms: f64,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // INITIALIZATION
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -97,6 +255,10 @@ pub fn init_advanced_meters(sample_rate: f64) {
     *CREST_METER.write() = Some(CrestFactorMeter::new(sample_rate, 300.0)); // 300ms window
     *PSYCHOACOUSTIC.write() = Some(PsychoacousticMeter::new(sample_rate));
 
+    let interval_ms = HISTORY_RECORDER.read().interval_ms;
+    *HISTORY_RECORDER.write() = HistoryRecorder::new(sample_rate, interval_ms);
+    METER_HISTORY.clear();
+
     log::info!("Advanced meters initialized @ {} Hz", sample_rate);
 }
 
@@ -111,6 +273,7 @@ pub fn reset_advanced_meters() {
     if let Some(meter) = PSYCHOACOUSTIC.write().as_mut() {
         meter.reset();
     }
+    METER_HISTORY.clear();
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -138,6 +301,21 @@ pub fn process_advanced_meters(left: &[f64], right: &[f64]) {
             meter.process((l + r) * 0.5);
         }
     }
+
+    // History (LUFS-S from PSR meter, true peak from 8x meter)
+    HISTORY_RECORDER
+        .write()
+        .process(left, right, |correlation| {
+            let lufs_short = PSR_METER
+                .read()
+                .as_ref()
+                .map_or(f64::NEG_INFINITY, |m| m.short_term_lufs());
+            let true_peak = TRUE_PEAK_8X
+                .read()
+                .as_ref()
+                .map_or(f64::NEG_INFINITY, |m| m.peak_dbtp());
+            METER_HISTORY.push(lufs_short, true_peak, correlation);
+        });
 }
 
 /// Process PSR meter (needs K-weighted AND raw signal)
@@ -237,6 +415,20 @@ pub fn advanced_get_specific_loudness() -> Vec<f64> {
     }
 }
 
+/// Get scrolling LUFS-S / true peak / correlation history (most recent last)
+#[flutter_rust_bridge::frb(sync)]
+pub fn advanced_get_meter_history() -> MeterHistoryData {
+    METER_HISTORY.snapshot(HISTORY_RECORDER.read().interval_ms)
+}
+
+/// Configure history interval and length (clears recorded history)
+#[flutter_rust_bridge::frb(sync)]
+pub fn advanced_configure_history(interval_ms: f64, length: u32) {
+    let sample_rate = HISTORY_RECORDER.read().sample_rate;
+    *HISTORY_RECORDER.write() = HistoryRecorder::new(sample_rate, interval_ms.max(1.0));
+    METER_HISTORY.set_length(length as usize);
+}
+
 /// Reset 8x True Peak meter
 #[flutter_rust_bridge::frb(sync)]
 pub fn advanced_reset_true_peak() {
@@ -264,3 +456,45 @@ pub fn advanced_reset_all() {
 pub fn advanced_is_initialized() -> bool {
     TRUE_PEAK_8X.read().is_some()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_fills_and_wraps() {
+        let history = MeterHistory::new(16);
+        history.set_length(4);
+
+        for i in 0..3 {
+            history.push(-20.0 - i as f64, -1.0, 0.5);
+        }
+        let snap = history.snapshot(100.0);
+        assert_eq!(snap.lufs_short, vec![-20.0, -21.0, -22.0]);
+
+        for i in 3..10 {
+            history.push(-20.0 - i as f64, -1.0, i as f64 / 10.0);
+        }
+        let snap = history.snapshot(100.0);
+        assert_eq!(snap.lufs_short, vec![-26.0, -27.0, -28.0, -29.0]);
+        assert_eq!(snap.correlation.last(), Some(&0.9));
+        assert_eq!(snap.true_peak_dbtp.len(), 4);
+    }
+
+    #[test]
+    fn test_history_recorder_correlation_interval() {
+        let mut recorder = HistoryRecorder::new(1000.0, 10.0);
+        let left: Vec<f64> = (0..25).map(|i| (i as f64 * 0.3).sin()).collect();
+        let inverted: Vec<f64> = left.iter().map(|x| -x).collect();
+
+        let mut values = Vec::new();
+        recorder.process(&left, &left, |c| values.push(c));
+        assert_eq!(values.len(), 2);
+        assert!((values[0] - 1.0).abs() < 1e-9);
+
+        values.clear();
+        recorder.process(&left, &inverted, |c| values.push(c));
+        assert_eq!(values.len(), 3);
+        assert!((values[2] + 1.0).abs() < 1e-9);
+    }
+}
@@ -217,7 +217,7 @@ pub fn project_export_json() -> Option<String> {
 /// Import project from JSON string
 #[flutter_rust_bridge::frb(sync)]
 pub fn project_import_json(json: String) -> Result<(), String> {
-    let project = rf_state::Project::from_json(&json).map_err(|e| e.to_string())?;
+    let project = rf_state::ProjectLoader::load_str(&json).map_err(|e| e.to_string())?;
 
     let mut engine = ENGINE.write();
     if let Some(ref mut e) = *engine {
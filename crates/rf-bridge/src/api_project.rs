@@ -17,6 +17,7 @@ pub fn project_new(name: String) -> bool {
     if let Some(ref mut e) = *engine {
         e.project = rf_state::Project::new(&name);
         e.undo_manager.clear();
+        crate::midi_bridge::midi_learn_clear();
         e.set_file_path(None);
         e.mark_clean();
         true
@@ -30,12 +31,20 @@ pub fn project_new(name: String) -> bool {
 pub fn project_save_sync(path: String) -> Result<(), String> {
     let mut engine = ENGINE.write();
     if let Some(ref mut e) = *engine {
+        let p = Path::new(&path);
+        let store = rf_state::PluginStateStore::for_project(p);
+
         // Sync tracks from TrackManager to Project before saving
-        sync_tracks_to_project(e);
+        sync_tracks_to_project(e, &store);
+        e.project.midi_mappings = crate::midi_bridge::export_midi_mappings();
 
-        let p = Path::new(&path);
         let format = rf_state::ProjectFormat::from_extension(p);
-        let result = e.project.save(p, format).map_err(|err| err.to_string());
+        // Saving somewhere new carries the sidecar plugin states along
+        let result = match e.file_path().filter(|previous| *previous != path) {
+            Some(previous) => e.project.save_as(Path::new(&previous), p, format),
+            None => e.project.save(p, format),
+        }
+        .map_err(|err| err.to_string());
 
         if result.is_ok() {
             e.set_file_path(Some(path));
@@ -48,7 +57,10 @@ pub fn project_save_sync(path: String) -> Result<(), String> {
 }
 
 /// Sync tracks from TrackManager to Project state
-fn sync_tracks_to_project(e: &mut EngineBridge) {
+///
+/// Instrument plugin states are written to `store` (the sidecar store of the
+/// file being saved).
+fn sync_tracks_to_project(e: &mut EngineBridge, store: &rf_state::PluginStateStore) {
     use rf_engine::track_manager::OutputBus;
     use rf_state::{
         AssetRef, AutomationLaneState, AutomationPointState, RegionState, TrackState, TrackType,
@@ -150,6 +162,21 @@ fn sync_tracks_to_project(e: &mut EngineBridge) {
                 rf_engine::track_manager::TrackType::Aux => TrackType::Aux,
             };
 
+            let instrument_state_ref = e
+                .playback_engine()
+                .instrument_plugin_state(track.id.0)
+                .and_then(|state| match store.store(&state) {
+                    Ok(hash) => Some(hash),
+                    Err(err) => {
+                        log::warn!(
+                            "Failed to store instrument state for track {}: {}",
+                            track.id.0,
+                            err
+                        );
+                        None
+                    }
+                });
+
             TrackState {
                 id: track.id.0.to_string(),
                 name: track.name.clone(),
@@ -164,6 +191,7 @@ fn sync_tracks_to_project(e: &mut EngineBridge) {
                 regions,
                 automation: automation_lanes,
                 instrument_plugin_id: track.instrument_plugin_id.clone(),
+                instrument_state_ref,
                 output_channel_map: track.output_channel_map.iter().map(|bus| {
                     match bus {
                         OutputBus::Master => "Master",
@@ -217,7 +245,11 @@ pub fn project_load_sync(path: String) -> Result<(), String> {
         e.transport.loop_enabled = e.project.loop_enabled;
 
         // Restore tracks from project to TrackManager
-        sync_tracks_from_project(e);
+        sync_tracks_from_project(
+            e,
+            &rf_state::PluginStateStore::for_project(Path::new(&path)),
+        );
+        crate::midi_bridge::import_midi_mappings(&e.project.midi_mappings);
 
         // Mark project as clean and store file path
         e.set_file_path(Some(path));
@@ -230,7 +262,10 @@ pub fn project_load_sync(path: String) -> Result<(), String> {
 }
 
 /// Sync tracks from Project state to TrackManager
-fn sync_tracks_from_project(e: &mut EngineBridge) {
+///
+/// Saved instrument states are read from `store` and handed to the engine,
+/// which applies them when the instrument plugin is loaded.
+fn sync_tracks_from_project(e: &mut EngineBridge, store: &rf_state::PluginStateStore) {
     use rf_engine::track_manager::{Clip, OutputBus};
     use rf_state::{AssetRef, TrackType};
 
@@ -288,6 +323,15 @@ fn sync_tracks_from_project(e: &mut EngineBridge) {
             }).collect();
         });
 
+        if let Some(hash) = &track_state.instrument_state_ref {
+            match store.load(hash) {
+                Ok(state) => e
+                    .playback_engine()
+                    .restore_instrument_plugin_state(track_id.0, state),
+                Err(err) => log::warn!("Failed to load instrument state {}: {}", hash, err),
+            }
+        }
+
         // Add clips/regions for this track
         for region in &track_state.regions {
             // Get audio path from asset ref
@@ -1332,6 +1332,8 @@ impl BridgeRustHandle {
                     end_time: 300.0,
                     include_tail: true,
                     tail_seconds: 2.0,
+                    render_tail: false,
+                    max_tail_seconds: rf_engine::export::DEFAULT_MAX_TAIL_SECONDS,
                     normalize: false,
                     block_size: 512,
                 };
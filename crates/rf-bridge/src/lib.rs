@@ -137,6 +137,7 @@ use rf_cortex::prelude::*;
 use rf_evolution::guardian::{CodeGuardian, GuardianConfig};
 use rf_engine::automation::AutomationEngine;
 use rf_engine::groups::GroupManager;
+use rf_engine::modulation::ModulationEngine;
 use rf_engine::playback::PlaybackEngine as EnginePlayback;
 use rf_engine::track_manager::TrackManager;
 use rf_engine::{DualPathEngine, EngineConfig, ProcessingMode};
@@ -677,6 +678,8 @@ pub struct EngineBridge {
     playback_engine: Arc<EnginePlayback>,
     /// Automation engine for parameter automation
     automation_engine: Arc<AutomationEngine>,
+    /// LFO modulation engine, combined with automation every block
+    modulation_engine: Arc<ModulationEngine>,
     /// VCA/Group manager for track grouping
     group_manager: Arc<RwLock<GroupManager>>,
     /// Dirty state - project has unsaved changes
@@ -732,6 +735,9 @@ impl EngineBridge {
         // Create automation engine
         let automation_engine = Arc::new(AutomationEngine::new(config.sample_rate.as_f64()));
 
+        // Create LFO modulation engine
+        let modulation_engine = Arc::new(ModulationEngine::new());
+
         // Create VCA/group manager
         let group_manager = Arc::new(RwLock::new(GroupManager::new()));
 
@@ -741,6 +747,7 @@ impl EngineBridge {
 
         // Connect automation to playback engine
         playback_engine.set_automation(Arc::clone(&automation_engine));
+        playback_engine.set_modulation(Arc::clone(&modulation_engine));
         // Connect group/VCA manager to playback engine for audio-thread VCA gain
         playback_engine.set_group_manager(Arc::clone(&group_manager));
 
@@ -749,7 +756,7 @@ impl EngineBridge {
         // Connect playback engine to audio output
         PLAYBACK.connect_engine(Arc::clone(&playback_engine));
         log::info!("EngineBridge: Connected rf-engine PlaybackEngine to audio output");
-        log::info!("EngineBridge: Automation and VCA systems connected");
+        log::info!("EngineBridge: Automation, modulation and VCA systems connected");
 
         Self {
             engine: DualPathEngine::new(
@@ -771,6 +778,7 @@ impl EngineBridge {
             track_manager,
             playback_engine,
             automation_engine,
+            modulation_engine,
             group_manager,
             is_dirty: std::sync::atomic::AtomicBool::new(false),
             last_saved_undo_pos: std::sync::atomic::AtomicUsize::new(0),
@@ -829,6 +837,11 @@ impl EngineBridge {
         &self.automation_engine
     }
 
+    /// Get LFO modulation engine
+    pub fn modulation_engine(&self) -> &Arc<ModulationEngine> {
+        &self.modulation_engine
+    }
+
     /// Get VCA/group manager for track grouping
     pub fn group_manager(&self) -> &Arc<RwLock<GroupManager>> {
         &self.group_manager
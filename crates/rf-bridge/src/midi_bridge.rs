@@ -5,6 +5,7 @@
 // - Real-time MIDI input capture
 // - Lock-free event queue for recording
 // - MIDI routing to tracks
+// - MIDI learn (CC → insert parameter mapping)
 
 use std::sync::{
     Mutex,
@@ -13,6 +14,9 @@ use std::sync::{
 
 use midir::{MidiInput, MidiInputConnection, MidiOutput, MidiOutputConnection};
 use parking_lot::RwLock;
+use rf_engine::ffi::PLAYBACK_ENGINE;
+use rf_engine::midi_learn::{CcCurve, MidiCcMapping};
+use rf_state::MidiCcMappingState;
 
 // ============================================================================
 // TYPES
@@ -161,6 +165,13 @@ fn process_midi_input(timestamp_us: u64, data: &[u8]) {
         return;
     }
 
+    // MIDI learn: control changes feed the CC mapping table (binds if a param is armed)
+    if data.len() == 3 && data[0] & 0xF0 == 0xB0 {
+        PLAYBACK_ENGINE
+            .midi_learn()
+            .handle_cc(data[0] & 0x0F, data[1], data[2]);
+    }
+
     // Always push to live input buffer (for MIDI trigger service)
     {
         let mut event_data = [0u8; 3];
@@ -537,6 +548,101 @@ pub fn send_program_change(channel: u8, program: u8) -> Result<(), String> {
     send_midi(&[0xC0 | (channel & 0x0F), program & 0x7F])
 }
 
+// ============================================================================
+// MIDI LEARN
+// ============================================================================
+
+/// Arm a parameter — the next incoming CC is bound to it
+pub fn midi_learn_start(track_id: u64, slot_index: usize, param_index: usize) {
+    PLAYBACK_ENGINE.start_midi_learn(track_id, slot_index, param_index);
+}
+
+/// Disarm MIDI learn without binding
+pub fn midi_learn_cancel() {
+    PLAYBACK_ENGINE.midi_learn().cancel_learn();
+}
+
+/// Check if a parameter is armed for learn
+pub fn midi_learn_is_active() -> bool {
+    PLAYBACK_ENGINE.midi_learn().is_learning()
+}
+
+/// Get all CC mappings with their table indices
+pub fn midi_learn_mappings() -> Vec<(usize, MidiCcMapping)> {
+    PLAYBACK_ENGINE.midi_learn().mappings()
+}
+
+/// Set range, curve (0 = linear, 1 = log, 2 = exp) and smoothing of a mapping
+pub fn midi_learn_set_range(
+    index: usize,
+    min: f64,
+    max: f64,
+    curve: u8,
+    smoothing_ms: f64,
+) -> bool {
+    PLAYBACK_ENGINE
+        .midi_learn()
+        .set_range(index, min, max, CcCurve::from_u8(curve), smoothing_ms)
+}
+
+/// Remove a mapping by index
+pub fn midi_learn_remove(index: usize) -> bool {
+    PLAYBACK_ENGINE.midi_learn().remove(index)
+}
+
+/// Remove all mappings bound to a parameter
+pub fn midi_learn_remove_for_param(track_id: u64, slot_index: usize, param_index: usize) -> bool {
+    PLAYBACK_ENGINE
+        .midi_learn()
+        .remove_for_param(track_id, slot_index, param_index)
+}
+
+/// Remove all mappings
+pub fn midi_learn_clear() {
+    PLAYBACK_ENGINE.midi_learn().clear();
+}
+
+/// Snapshot mappings for project save
+pub fn export_midi_mappings() -> Vec<MidiCcMappingState> {
+    midi_learn_mappings()
+        .iter()
+        .map(|(_, m)| mapping_to_state(m))
+        .collect()
+}
+
+/// Restore mappings from a loaded project (replaces current mappings)
+pub fn import_midi_mappings(states: &[MidiCcMappingState]) {
+    let mappings: Vec<MidiCcMapping> = states.iter().map(mapping_from_state).collect();
+    PLAYBACK_ENGINE.midi_learn().replace_all(&mappings);
+}
+
+fn mapping_to_state(m: &MidiCcMapping) -> MidiCcMappingState {
+    MidiCcMappingState {
+        channel: m.channel,
+        cc: m.cc,
+        track_id: m.track_id,
+        slot_index: m.slot_index as u32,
+        param_index: m.param_index as u32,
+        min: m.min,
+        max: m.max,
+        curve_type: m.curve.as_u8(),
+        smoothing_ms: m.smoothing_ms,
+    }
+}
+
+fn mapping_from_state(s: &MidiCcMappingState) -> MidiCcMapping {
+    MidiCcMapping::new(
+        s.channel,
+        s.cc,
+        s.track_id,
+        s.slot_index as usize,
+        s.param_index as usize,
+    )
+    .with_range(s.min, s.max)
+    .with_curve(CcCurve::from_u8(s.curve_type))
+    .with_smoothing(s.smoothing_ms)
+}
+
 // ============================================================================
 // TESTS
 // ============================================================================
@@ -589,4 +695,16 @@ mod tests {
 
         set_recording_state(MidiRecordingState::Stopped);
     }
+
+    #[test]
+    fn test_midi_mapping_state_round_trip() {
+        let mapping = MidiCcMapping::new(1, 74, 5, 2, 3)
+            .with_range(-24.0, 24.0)
+            .with_curve(CcCurve::Exponential)
+            .with_smoothing(35.0);
+
+        let state = mapping_to_state(&mapping);
+        assert_eq!(state.curve_type, 2);
+        assert_eq!(mapping_from_state(&state), mapping);
+    }
 }
@@ -279,6 +279,125 @@ pub extern "C" fn midi_is_thru_enabled() -> i32 {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// MIDI LEARN
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Arm an insert parameter for MIDI learn (track_id 0 = master bus)
+/// The next incoming CC is bound to it
+#[unsafe(no_mangle)]
+pub extern "C" fn midi_learn_start(track_id: u64, slot_index: u32, param_index: u32) {
+    midi_bridge::midi_learn_start(track_id, slot_index as usize, param_index as usize);
+}
+
+/// Cancel MIDI learn without binding
+#[unsafe(no_mangle)]
+pub extern "C" fn midi_learn_cancel() {
+    midi_bridge::midi_learn_cancel();
+}
+
+/// Check if a parameter is armed for learn
+#[unsafe(no_mangle)]
+pub extern "C" fn midi_learn_is_active() -> i32 {
+    if midi_bridge::midi_learn_is_active() {
+        1
+    } else {
+        0
+    }
+}
+
+/// Get CC mappings as JSON array
+/// Returns: length written (excluding null), -1 on error
+#[unsafe(no_mangle)]
+pub extern "C" fn midi_learn_get_mappings_json(out_json: *mut c_char, max_len: u32) -> i32 {
+    if out_json.is_null() || max_len == 0 {
+        return -1;
+    }
+
+    let entries: Vec<String> = midi_bridge::midi_learn_mappings()
+        .iter()
+        .map(|(index, m)| {
+            format!(
+                "{{\"index\":{},\"channel\":{},\"cc\":{},\"trackId\":{},\"slot\":{},\"param\":{},\"min\":{},\"max\":{},\"curve\":{},\"smoothingMs\":{}}}",
+                index,
+                m.channel,
+                m.cc,
+                m.track_id,
+                m.slot_index,
+                m.param_index,
+                m.min,
+                m.max,
+                m.curve.as_u8(),
+                m.smoothing_ms
+            )
+        })
+        .collect();
+    let json = format!("[{}]", entries.join(","));
+    let bytes = json.as_bytes();
+    if bytes.len() >= max_len as usize {
+        return -1;
+    }
+
+    unsafe {
+        std::ptr::copy_nonoverlapping(bytes.as_ptr(), out_json as *mut u8, bytes.len());
+        *out_json.add(bytes.len()) = 0;
+    }
+
+    bytes.len() as i32
+}
+
+/// Set mapping range and response
+/// curve: 0 = linear, 1 = logarithmic, 2 = exponential
+/// Returns 1 on success, 0 if mapping not found
+#[unsafe(no_mangle)]
+pub extern "C" fn midi_learn_set_range(
+    index: u32,
+    min: f64,
+    max: f64,
+    curve: u8,
+    smoothing_ms: f64,
+) -> i32 {
+    if !min.is_finite() || !max.is_finite() || !smoothing_ms.is_finite() {
+        return 0;
+    }
+    if midi_bridge::midi_learn_set_range(index as usize, min, max, curve, smoothing_ms) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Remove mapping by index
+#[unsafe(no_mangle)]
+pub extern "C" fn midi_learn_remove(index: u32) -> i32 {
+    if midi_bridge::midi_learn_remove(index as usize) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Remove all mappings bound to an insert parameter
+#[unsafe(no_mangle)]
+pub extern "C" fn midi_learn_remove_for_param(
+    track_id: u64,
+    slot_index: u32,
+    param_index: u32,
+) -> i32 {
+    if midi_bridge::midi_learn_remove_for_param(track_id, slot_index as usize, param_index as usize)
+    {
+        1
+    } else {
+        0
+    }
+}
+
+/// Remove all mappings
+#[unsafe(no_mangle)]
+pub extern "C" fn midi_learn_clear() {
+    midi_bridge::midi_learn_clear();
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // MIDI OUTPUT (Send Messages)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -98,11 +98,11 @@ pub extern "C" fn offline_pipeline_destroy(handle: u64) {
 pub extern "C" fn offline_pipeline_set_normalization(handle: u64, mode: i32, target: f64) {
     if let Some(pipeline) = PIPELINES.get(&handle) {
         let norm_mode = match mode {
-            1 => Some(NormalizationMode::Peak { target_db: target }),
+            1 => Some(NormalizationMode::Peak { target_dbfs: target }),
             2 => Some(NormalizationMode::Lufs {
                 target_lufs: target,
             }),
-            3 => Some(NormalizationMode::TruePeak { target_db: target }),
+            3 => Some(NormalizationMode::TruePeak { target_dbtp: target }),
             4 => Some(NormalizationMode::NoClip),
             _ => None,
         };
@@ -296,11 +296,11 @@ pub extern "C" fn offline_process_file_with_options(
 
     if let (Some(mode), Some(target)) = (opts.normalize_mode, opts.normalize_target) {
         let norm = match mode {
-            1 => NormalizationMode::Peak { target_db: target },
+            1 => NormalizationMode::Peak { target_dbfs: target },
             2 => NormalizationMode::Lufs {
                 target_lufs: target,
             },
-            3 => NormalizationMode::TruePeak { target_db: target },
+            3 => NormalizationMode::TruePeak { target_dbtp: target },
             _ => NormalizationMode::NoClip,
         };
         builder = builder.normalize(norm);
@@ -369,9 +369,9 @@ pub extern "C" fn offline_process_file_with_options(
         // Set normalization on pipeline (process_job reads self.normalization, NOT job.normalization)
         if let (Some(mode), Some(target)) = (opts.normalize_mode, opts.normalize_target) {
             let norm = match mode {
-                1 => NormalizationMode::Peak { target_db: target },
+                1 => NormalizationMode::Peak { target_dbfs: target },
                 2 => NormalizationMode::Lufs { target_lufs: target },
-                3 => NormalizationMode::TruePeak { target_db: target },
+                3 => NormalizationMode::TruePeak { target_dbtp: target },
                 4 => NormalizationMode::NoClip,
                 _ => NormalizationMode::NoClip,
             };
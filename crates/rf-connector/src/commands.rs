@@ -1,5 +1,6 @@
 //! Engine commands — FluxForge → Engine communication
 
+use rf_event::EventManagerHandle;
 use serde::{Deserialize, Serialize};
 
 /// Commands that FluxForge can send to the engine
@@ -102,6 +103,83 @@ impl EngineCommand {
     }
 }
 
+/// Commands the engine sends back to FluxForge (engine → FluxForge parameter sync)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum InboundCommand {
+    /// Set an RTPC by name
+    SetRtpc {
+        /// RTPC name
+        name: String,
+        /// New value (clamped to the RTPC range)
+        value: f32,
+    },
+
+    /// Set a state group by name
+    SetState {
+        /// State group name
+        group: String,
+        /// State name
+        state: String,
+    },
+}
+
+impl InboundCommand {
+    /// Get command name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::SetRtpc { .. } => "set_rtpc",
+            Self::SetState { .. } => "set_state",
+        }
+    }
+}
+
+/// Applies inbound commands to the event system, validated against its definitions
+#[derive(Clone)]
+pub struct ParameterSync {
+    events: EventManagerHandle,
+}
+
+impl ParameterSync {
+    /// Bind to an event manager
+    pub fn new(events: EventManagerHandle) -> Self {
+        Self { events }
+    }
+
+    /// Apply a command. Unknown RTPCs, groups or states are logged and dropped.
+    ///
+    /// Returns true if the command was applied.
+    pub fn apply(&self, command: &InboundCommand) -> bool {
+        match command {
+            InboundCommand::SetRtpc { name, value } => {
+                let Some(rtpc) = self.events.get_rtpc_by_name(name) else {
+                    log::warn!("[Connector] Dropping set_rtpc for unknown RTPC '{}'", name);
+                    return false;
+                };
+                if !value.is_finite() {
+                    log::warn!("[Connector] Dropping non-finite value for RTPC '{}'", name);
+                    return false;
+                }
+                self.events
+                    .set_rtpc(rtpc.id, value.clamp(rtpc.min, rtpc.max), 0);
+                true
+            }
+            InboundCommand::SetState { group, state } => {
+                let Some((group_id, state_id)) = self.events.get_state_ids(group, state) else {
+                    log::warn!(
+                        "[Connector] Dropping set_state for unknown state '{}/{}'",
+                        group,
+                        state
+                    );
+                    return false;
+                };
+                self.events.set_state(group_id, state_id);
+                true
+            }
+        }
+    }
+}
+
 /// Command response from engine
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommandResponse {
@@ -514,6 +592,30 @@ mod tests {
         assert_eq!(deserialized.data.unwrap()["items"][1], 2);
     }
 
+    #[test]
+    fn test_inbound_unknown_names_dropped() {
+        let (handle, _processor) = rf_event::create_event_manager(48000);
+        handle.register_rtpc(rf_event::RtpcDefinition::new(1, "Intensity").with_range(0.0, 1.0));
+        let sync = ParameterSync::new(handle);
+
+        assert!(!sync.apply(&InboundCommand::SetRtpc {
+            name: "Missing".into(),
+            value: 0.5
+        }));
+        assert!(!sync.apply(&InboundCommand::SetRtpc {
+            name: "Intensity".into(),
+            value: f32::NAN
+        }));
+        assert!(!sync.apply(&InboundCommand::SetState {
+            group: "Missing".into(),
+            state: "Any".into()
+        }));
+        assert!(sync.apply(&InboundCommand::SetRtpc {
+            name: "Intensity".into(),
+            value: 0.5
+        }));
+    }
+
     #[test]
     fn test_engine_capabilities_serialization() {
         let caps = EngineCapabilities::default();
@@ -9,7 +9,7 @@ use tokio::net::TcpStream;
 use tokio::sync::{broadcast, mpsc, RwLock};
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 
-use crate::commands::EngineCommand;
+use crate::commands::{EngineCommand, InboundCommand, ParameterSync};
 use crate::protocol::{ConnectionConfig, ConnectionState, EngineMessage, ProtocolFrame};
 use rf_stage::event::StageEvent;
 
@@ -31,6 +31,9 @@ pub struct EngineConnector {
     /// Channel for raw engine messages
     message_tx: broadcast::Sender<EngineMessage>,
 
+    /// Applies inbound RTPC/state commands, if bound
+    parameter_sync: Option<ParameterSync>,
+
     /// Connection task handle
     connection_handle: Arc<RwLock<Option<tokio::task::JoinHandle<()>>>>,
 
@@ -53,11 +56,17 @@ impl EngineConnector {
             command_tx,
             command_rx: Arc::new(RwLock::new(Some(command_rx))),
             message_tx,
+            parameter_sync: None,
             connection_handle: Arc::new(RwLock::new(None)),
             shutdown_tx,
         }
     }
 
+    /// Bind inbound parameter sync; takes effect on the next connect
+    pub fn set_parameter_sync(&mut self, sync: ParameterSync) {
+        self.parameter_sync = Some(sync);
+    }
+
     /// Get the current connection state
     pub async fn state(&self) -> ConnectionState {
         *self.state.read().await
@@ -179,6 +188,7 @@ impl EngineConnector {
 
         let event_tx = self.event_tx.clone();
         let message_tx = self.message_tx.clone();
+        let parameter_sync = self.parameter_sync.clone();
         let state = Arc::clone(&self.state);
         let mut shutdown_rx = self.shutdown_tx.subscribe();
 
@@ -190,7 +200,12 @@ impl EngineConnector {
                     msg = read.next() => {
                         match msg {
                             Some(Ok(Message::Text(text))) => {
-                                Self::handle_message(&text, &event_tx, &message_tx);
+                                Self::handle_message(
+                                    &text,
+                                    &event_tx,
+                                    &message_tx,
+                                    parameter_sync.as_ref(),
+                                );
                             }
                             Some(Ok(Message::Close(_))) | None => {
                                 *state.write().await = ConnectionState::Disconnected;
@@ -263,6 +278,7 @@ impl EngineConnector {
 
         let event_tx = self.event_tx.clone();
         let message_tx = self.message_tx.clone();
+        let parameter_sync = self.parameter_sync.clone();
         let state = Arc::clone(&self.state);
         let mut shutdown_rx = self.shutdown_tx.subscribe();
 
@@ -281,7 +297,12 @@ impl EngineConnector {
                                 break;
                             }
                             Ok(_) => {
-                                Self::handle_message(line.trim(), &event_tx, &message_tx);
+                                Self::handle_message(
+                                    line.trim(),
+                                    &event_tx,
+                                    &message_tx,
+                                    parameter_sync.as_ref(),
+                                );
                                 line.clear();
                             }
                             Err(e) => {
@@ -323,6 +344,7 @@ impl EngineConnector {
         text: &str,
         event_tx: &broadcast::Sender<StageEvent>,
         message_tx: &broadcast::Sender<EngineMessage>,
+        parameter_sync: Option<&ParameterSync>,
     ) {
         // Parse JSON
         let Ok(json): Result<serde_json::Value, _> = serde_json::from_str(text) else {
@@ -338,6 +360,14 @@ impl EngineConnector {
         let message = EngineMessage::new(msg_type, json.clone());
         let _ = message_tx.send(message);
 
+        // Apply inbound parameter sync (set_rtpc / set_state)
+        if let Some(sync) = parameter_sync
+            && json.get("command").is_some()
+            && let Ok(command) = serde_json::from_value::<InboundCommand>(json.clone())
+        {
+            sync.apply(&command);
+        }
+
         // Try to parse as stage event
         if (msg_type == "stage_event" || json.get("stage").is_some())
             && let Some(stage_data) = json.get("stage").or(json.get("data"))
@@ -538,4 +568,52 @@ mod tests {
         }
         assert_eq!(connector.state().await, ConnectionState::Disconnected);
     }
+
+    #[test]
+    fn test_inbound_set_rtpc_updates_engine() {
+        let (events, mut processor) = rf_event::create_event_manager(48000);
+        events.register_rtpc(rf_event::RtpcDefinition::new(7, "Intensity").with_range(0.0, 1.0));
+        let mut group = rf_event::StateGroup::new(3, "Phase");
+        group.add_state(1, "BaseGame");
+        group.add_state(2, "FreeSpins");
+        events.register_state_group(group);
+
+        let (event_tx, _) = broadcast::channel(4);
+        let (message_tx, _) = broadcast::channel(4);
+        let sync = ParameterSync::new(events);
+
+        EngineConnector::handle_message(
+            r#"{"command":"set_rtpc","name":"Intensity","value":0.75}"#,
+            &event_tx,
+            &message_tx,
+            Some(&sync),
+        );
+        EngineConnector::handle_message(
+            r#"{"command":"set_state","group":"Phase","state":"FreeSpins"}"#,
+            &event_tx,
+            &message_tx,
+            Some(&sync),
+        );
+        // Unknown names are dropped
+        EngineConnector::handle_message(
+            r#"{"command":"set_rtpc","name":"Unknown","value":0.1}"#,
+            &event_tx,
+            &message_tx,
+            Some(&sync),
+        );
+        processor.process(256);
+
+        assert_eq!(processor.get_rtpc(7), Some(0.75));
+        assert_eq!(processor.get_state(3), Some(2));
+
+        // Out of range values are clamped
+        EngineConnector::handle_message(
+            r#"{"command":"set_rtpc","name":"Intensity","value":4.0}"#,
+            &event_tx,
+            &message_tx,
+            Some(&sync),
+        );
+        processor.process(256);
+        assert_eq!(processor.get_rtpc(7), Some(1.0));
+    }
 }
@@ -0,0 +1,236 @@
+//! Shared buffer operations
+//!
+//! SIMD (`Sample4`) versions of the loops every DSP crate needs. Each
+//! function processes full vectors and finishes the remainder with the
+//! matching `scalar` implementation, so any length is valid. Binary
+//! operations work over the shorter of the two slices.
+
+use std::simd::num::SimdFloat;
+
+use crate::sample::{Sample, Sample4};
+
+/// SIMD lane count
+const LANES: usize = 4;
+
+/// `dst[i] += src[i]`
+#[inline]
+pub fn add(dst: &mut [Sample], src: &[Sample]) {
+    let len = dst.len().min(src.len());
+    let (dst_chunks, dst_tail) = dst[..len].as_chunks_mut::<LANES>();
+    let (src_chunks, src_tail) = src[..len].as_chunks::<LANES>();
+    for (d, s) in dst_chunks.iter_mut().zip(src_chunks) {
+        *d = (Sample4::from_array(*d) + Sample4::from_array(*s)).to_array();
+    }
+    scalar::add(dst_tail, src_tail);
+}
+
+/// `dst[i] *= src[i]`
+#[inline]
+pub fn mul(dst: &mut [Sample], src: &[Sample]) {
+    let len = dst.len().min(src.len());
+    let (dst_chunks, dst_tail) = dst[..len].as_chunks_mut::<LANES>();
+    let (src_chunks, src_tail) = src[..len].as_chunks::<LANES>();
+    for (d, s) in dst_chunks.iter_mut().zip(src_chunks) {
+        *d = (Sample4::from_array(*d) * Sample4::from_array(*s)).to_array();
+    }
+    scalar::mul(dst_tail, src_tail);
+}
+
+/// `dst[i] *= gain`
+#[inline]
+pub fn scale(dst: &mut [Sample], gain: Sample) {
+    let g = Sample4::splat(gain);
+    let (chunks, tail) = dst.as_chunks_mut::<LANES>();
+    for d in chunks {
+        *d = (Sample4::from_array(*d) * g).to_array();
+    }
+    scalar::scale(tail, gain);
+}
+
+/// `dst[i] += src[i] * gain` (not fused, so results match `scalar` exactly)
+#[inline]
+pub fn mul_add(dst: &mut [Sample], src: &[Sample], gain: Sample) {
+    let len = dst.len().min(src.len());
+    let g = Sample4::splat(gain);
+    let (dst_chunks, dst_tail) = dst[..len].as_chunks_mut::<LANES>();
+    let (src_chunks, src_tail) = src[..len].as_chunks::<LANES>();
+    for (d, s) in dst_chunks.iter_mut().zip(src_chunks) {
+        *d = (Sample4::from_array(*d) + Sample4::from_array(*s) * g).to_array();
+    }
+    scalar::mul_add(dst_tail, src_tail, gain);
+}
+
+/// `dst[i] = src[i]`
+#[inline]
+pub fn copy(dst: &mut [Sample], src: &[Sample]) {
+    let len = dst.len().min(src.len());
+    dst[..len].copy_from_slice(&src[..len]);
+}
+
+/// `dst[i] = 0.0`
+#[inline]
+pub fn clear(dst: &mut [Sample]) {
+    dst.fill(0.0);
+}
+
+/// Largest absolute sample value (0.0 for an empty buffer)
+#[inline]
+pub fn find_peak(src: &[Sample]) -> Sample {
+    let (chunks, tail) = src.as_chunks::<LANES>();
+    let mut peak = Sample4::splat(0.0);
+    for s in chunks {
+        peak = peak.simd_max(Sample4::from_array(*s).abs());
+    }
+    peak.reduce_max().max(scalar::find_peak(tail))
+}
+
+/// Plain-loop reference implementations (also used for SIMD tails)
+pub mod scalar {
+    use crate::sample::Sample;
+
+    /// `dst[i] += src[i]`
+    #[inline]
+    pub fn add(dst: &mut [Sample], src: &[Sample]) {
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d += *s;
+        }
+    }
+
+    /// `dst[i] *= src[i]`
+    #[inline]
+    pub fn mul(dst: &mut [Sample], src: &[Sample]) {
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d *= *s;
+        }
+    }
+
+    /// `dst[i] *= gain`
+    #[inline]
+    pub fn scale(dst: &mut [Sample], gain: Sample) {
+        for d in dst.iter_mut() {
+            *d *= gain;
+        }
+    }
+
+    /// `dst[i] += src[i] * gain`
+    #[inline]
+    pub fn mul_add(dst: &mut [Sample], src: &[Sample], gain: Sample) {
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d += *s * gain;
+        }
+    }
+
+    /// `dst[i] = src[i]`
+    #[inline]
+    pub fn copy(dst: &mut [Sample], src: &[Sample]) {
+        for (d, s) in dst.iter_mut().zip(src) {
+            *d = *s;
+        }
+    }
+
+    /// `dst[i] = 0.0`
+    #[inline]
+    pub fn clear(dst: &mut [Sample]) {
+        for d in dst.iter_mut() {
+            *d = 0.0;
+        }
+    }
+
+    /// Largest absolute sample value (0.0 for an empty buffer)
+    #[inline]
+    pub fn find_peak(src: &[Sample]) -> Sample {
+        src.iter().fold(0.0, |peak, s| peak.max(s.abs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Deterministic signal in -1..1 with a different pattern per seed
+    fn signal(len: usize, seed: u64) -> Vec<Sample> {
+        let mut state = seed.wrapping_mul(6364136223846793005).wrapping_add(1);
+        (0..len)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+            })
+            .collect()
+    }
+
+    /// Lengths around multiples of the vector width, including the empty tail
+    fn lengths() -> impl Iterator<Item = usize> {
+        (0..=19).chain([63, 64, 65, 511, 512, 513])
+    }
+
+    #[test]
+    fn test_binary_ops_match_scalar() {
+        type Op = (fn(&mut [Sample], &[Sample]), fn(&mut [Sample], &[Sample]));
+        let ops: [(&str, Op); 3] = [
+            ("add", (add, scalar::add)),
+            ("mul", (mul, scalar::mul)),
+            ("copy", (copy, scalar::copy)),
+        ];
+        for len in lengths() {
+            let src = signal(len, 1);
+            for (name, (simd_op, scalar_op)) in ops {
+                let mut simd_dst = signal(len, 2);
+                let mut scalar_dst = simd_dst.clone();
+                simd_op(&mut simd_dst, &src);
+                scalar_op(&mut scalar_dst, &src);
+                assert_eq!(simd_dst, scalar_dst, "{} len {}", name, len);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gain_ops_match_scalar() {
+        for len in lengths() {
+            let src = signal(len, 3);
+
+            let mut simd_dst = signal(len, 4);
+            let mut scalar_dst = simd_dst.clone();
+            mul_add(&mut simd_dst, &src, 0.37);
+            scalar::mul_add(&mut scalar_dst, &src, 0.37);
+            assert_eq!(simd_dst, scalar_dst, "mul_add len {}", len);
+
+            scale(&mut simd_dst, -1.5);
+            scalar::scale(&mut scalar_dst, -1.5);
+            assert_eq!(simd_dst, scalar_dst, "scale len {}", len);
+
+            clear(&mut simd_dst);
+            scalar::clear(&mut scalar_dst);
+            assert_eq!(simd_dst, scalar_dst, "clear len {}", len);
+            assert!(simd_dst.iter().all(|&s| s == 0.0));
+        }
+    }
+
+    #[test]
+    fn test_find_peak_in_tail() {
+        for len in lengths() {
+            let src = signal(len, 5);
+            assert_eq!(find_peak(&src), scalar::find_peak(&src), "len {}", len);
+
+            // Peak placed in the last (possibly scalar-tail) sample
+            if len > 0 {
+                let mut src = src;
+                src[len - 1] = -2.0;
+                assert_eq!(find_peak(&src), 2.0, "len {}", len);
+            }
+        }
+        assert_eq!(find_peak(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_mismatched_lengths_use_shorter() {
+        let mut dst = vec![1.0; 7];
+        add(&mut dst, &[1.0; 5]);
+        assert_eq!(dst, [2.0, 2.0, 2.0, 2.0, 2.0, 1.0, 1.0]);
+
+        let mut dst = vec![1.0; 5];
+        mul_add(&mut dst, &[1.0; 9], 2.0);
+        assert_eq!(dst, [3.0; 5]);
+    }
+}
@@ -5,9 +5,15 @@
 //! - Grid: Snap to tempo grid
 //! - Shuffle: Auto-close gaps on movement
 //! - Spot: Precise timecode placement via dialog
+//!
+//! Also provides ripple edits (delete/insert time) over clip lists.
+
+use std::ops::Range;
 
 use serde::{Deserialize, Serialize};
 
+use crate::TrackId;
+
 /// Edit mode determines how editing tools behave
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum EditMode {
@@ -228,6 +234,243 @@ impl EditContext {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// RIPPLE EDITING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Clip placement on a track
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EditClip {
+    pub id: u64,
+    pub track_id: TrackId,
+    /// Timeline start (samples)
+    pub start_samples: u64,
+    pub length_samples: u64,
+    /// Offset into the source audio (samples)
+    pub source_offset_samples: u64,
+}
+
+impl EditClip {
+    /// Timeline end (exclusive)
+    pub fn end_samples(&self) -> u64 {
+        self.start_samples + self.length_samples
+    }
+}
+
+/// Crossfade between two clips on the same track
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EditCrossfade {
+    pub track_id: TrackId,
+    pub clip_a: u64,
+    pub clip_b: u64,
+    pub start_samples: u64,
+    pub length_samples: u64,
+}
+
+/// Timeline marker
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EditMarker {
+    pub id: u64,
+    pub name: String,
+    pub position_samples: u64,
+}
+
+/// Tracks affected by a ripple edit
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum EditScope {
+    /// Every track; markers move with the edit
+    #[default]
+    AllTracks,
+    /// Only these tracks; markers stay put since they are shared by all tracks
+    Selected(Vec<TrackId>),
+}
+
+impl EditScope {
+    /// Whether the scope includes a track
+    pub fn contains(&self, track_id: TrackId) -> bool {
+        match self {
+            Self::AllTracks => true,
+            Self::Selected(tracks) => tracks.contains(&track_id),
+        }
+    }
+}
+
+/// Ripple edit operations on a timeline's clips, crossfades and markers
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EditOps {
+    pub clips: Vec<EditClip>,
+    pub crossfades: Vec<EditCrossfade>,
+    pub markers: Vec<EditMarker>,
+}
+
+impl EditOps {
+    pub fn new(
+        clips: Vec<EditClip>,
+        crossfades: Vec<EditCrossfade>,
+        markers: Vec<EditMarker>,
+    ) -> Self {
+        Self {
+            clips,
+            crossfades,
+            markers,
+        }
+    }
+
+    /// Delete a time range and pull later material earlier by its length
+    ///
+    /// Clips inside the range are removed, clips crossing its edges are
+    /// trimmed (a clip spanning the whole range is split and rejoined, the
+    /// tail getting a new id). Crossfades touching the range or a removed
+    /// clip are dropped; later ones shift and follow a split clip's tail.
+    /// Markers inside the range move to its start.
+    pub fn ripple_delete(&mut self, range: Range<u64>, scope: &EditScope) {
+        if range.is_empty() {
+            return;
+        }
+        let (start, end) = (range.start, range.end);
+        let length = end - start;
+        let mut next_id = self.next_clip_id();
+        let mut tails = Vec::new();
+
+        let mut kept = Vec::with_capacity(self.clips.len());
+        for mut clip in self.clips.drain(..) {
+            if !scope.contains(clip.track_id) || clip.end_samples() <= start {
+                kept.push(clip);
+                continue;
+            }
+
+            if clip.start_samples >= end {
+                clip.start_samples -= length;
+                kept.push(clip);
+                continue;
+            }
+
+            // Head before the range survives as is
+            if clip.start_samples < start {
+                let mut head = clip.clone();
+                head.length_samples = start - clip.start_samples;
+                kept.push(head);
+            }
+
+            // Tail after the range closes the gap
+            if clip.end_samples() > end {
+                let cut = end - clip.start_samples;
+                if clip.start_samples < start {
+                    tails.push((clip.id, next_id));
+                    clip.id = next_id;
+                    next_id += 1;
+                }
+                clip.source_offset_samples += cut;
+                clip.length_samples -= cut;
+                clip.start_samples = start;
+                kept.push(clip);
+            }
+        }
+        self.clips = kept;
+
+        let clips = &self.clips;
+        self.crossfades.retain_mut(|xfade| {
+            if !scope.contains(xfade.track_id) {
+                return true;
+            }
+            let xfade_end = xfade.start_samples + xfade.length_samples;
+            if xfade_end > start && xfade.start_samples < end {
+                return false;
+            }
+            if xfade.start_samples >= end {
+                xfade.start_samples -= length;
+                Self::follow_tails(xfade, &tails);
+            }
+            let exists = |id| clips.iter().any(|c| c.id == id);
+            exists(xfade.clip_a) && exists(xfade.clip_b)
+        });
+
+        if *scope == EditScope::AllTracks {
+            for marker in &mut self.markers {
+                if marker.position_samples >= end {
+                    marker.position_samples -= length;
+                } else if marker.position_samples > start {
+                    marker.position_samples = start;
+                }
+            }
+        }
+    }
+
+    /// Insert `length` samples of silence at `at`, pushing later material later
+    ///
+    /// Clips crossing `at` are split around the gap (the tail getting a new
+    /// id); crossfades crossing it are dropped, later ones follow the tail.
+    pub fn insert_time(&mut self, at: u64, length: u64, scope: &EditScope) {
+        if length == 0 {
+            return;
+        }
+        let mut next_id = self.next_clip_id();
+
+        let mut split = Vec::new();
+        let mut tails = Vec::new();
+        for clip in &mut self.clips {
+            if !scope.contains(clip.track_id) || clip.end_samples() <= at {
+                continue;
+            }
+
+            if clip.start_samples >= at {
+                clip.start_samples += length;
+                continue;
+            }
+
+            let head = at - clip.start_samples;
+            split.push(EditClip {
+                id: next_id,
+                track_id: clip.track_id,
+                start_samples: at + length,
+                length_samples: clip.length_samples - head,
+                source_offset_samples: clip.source_offset_samples + head,
+            });
+            tails.push((clip.id, next_id));
+            next_id += 1;
+            clip.length_samples = head;
+        }
+        self.clips.extend(split);
+        self.clips.sort_by_key(|c| (c.track_id.0, c.start_samples));
+
+        self.crossfades.retain_mut(|xfade| {
+            if !scope.contains(xfade.track_id) {
+                return true;
+            }
+            if xfade.start_samples >= at {
+                xfade.start_samples += length;
+                Self::follow_tails(xfade, &tails);
+                return true;
+            }
+            xfade.start_samples + xfade.length_samples <= at
+        });
+
+        if *scope == EditScope::AllTracks {
+            for marker in &mut self.markers {
+                if marker.position_samples >= at {
+                    marker.position_samples += length;
+                }
+            }
+        }
+    }
+
+    /// Point a crossfade after a split at the tail clip instead of the head
+    fn follow_tails(xfade: &mut EditCrossfade, tails: &[(u64, u64)]) {
+        for &(head, tail) in tails {
+            if xfade.clip_a == head {
+                xfade.clip_a = tail;
+            }
+            if xfade.clip_b == head {
+                xfade.clip_b = tail;
+            }
+        }
+    }
+
+    fn next_clip_id(&self) -> u64 {
+        self.clips.iter().map(|c| c.id + 1).max().unwrap_or(1)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -261,6 +504,136 @@ mod tests {
         assert_eq!(snapped, 24500);
     }
 
+    fn clip(id: u64, track: u64, start: u64, length: u64) -> EditClip {
+        EditClip {
+            id,
+            track_id: TrackId(track),
+            start_samples: start,
+            length_samples: length,
+            source_offset_samples: 0,
+        }
+    }
+
+    fn marker(id: u64, position: u64) -> EditMarker {
+        EditMarker {
+            id,
+            name: format!("M{}", id),
+            position_samples: position,
+        }
+    }
+
+    #[test]
+    fn test_ripple_delete_shifts_clips_and_markers() {
+        let sr = 48000;
+        let mut ops = EditOps::new(
+            vec![
+                clip(1, 1, 0, sr),
+                clip(2, 1, 3 * sr, sr),
+                clip(3, 2, 4 * sr, 2 * sr),
+                clip(4, 2, 5 * sr, 2 * sr),
+            ],
+            vec![EditCrossfade {
+                track_id: TrackId(2),
+                clip_a: 3,
+                clip_b: 4,
+                start_samples: 5 * sr,
+                length_samples: sr,
+            }],
+            vec![marker(1, sr / 2), marker(2, 3 * sr), marker(3, 5 * sr)],
+        );
+
+        // Delete 1s..2s on every track
+        ops.ripple_delete(sr..2 * sr, &EditScope::AllTracks);
+
+        let starts: Vec<_> = ops.clips.iter().map(|c| c.start_samples).collect();
+        assert_eq!(starts, vec![0, 2 * sr, 3 * sr, 4 * sr]);
+        assert_eq!(ops.crossfades[0].start_samples, 4 * sr);
+        let markers: Vec<_> = ops.markers.iter().map(|m| m.position_samples).collect();
+        assert_eq!(markers, vec![sr / 2, 2 * sr, 4 * sr]);
+
+        // Selected scope leaves other tracks and markers alone
+        ops.ripple_delete(0..sr, &EditScope::Selected(vec![TrackId(2)]));
+        assert_eq!(ops.clips[1].start_samples, 2 * sr);
+        assert_eq!(ops.clips[2].start_samples, 2 * sr);
+        assert_eq!(ops.markers[1].position_samples, 2 * sr);
+    }
+
+    #[test]
+    fn test_ripple_delete_inside_clip_rejoins() {
+        let mut ops = EditOps::new(vec![clip(1, 1, 0, 1000)], Vec::new(), Vec::new());
+        ops.ripple_delete(400..600, &EditScope::AllTracks);
+
+        assert_eq!(ops.clips.len(), 2);
+        assert_eq!(ops.clips[0].end_samples(), 400);
+        assert_eq!(ops.clips[1].start_samples, 400);
+        assert_eq!(ops.clips[1].source_offset_samples, 600);
+        assert_eq!(ops.clips[1].end_samples(), 800);
+    }
+
+    #[test]
+    fn test_insert_time_splits_and_shifts() {
+        let mut ops = EditOps::new(
+            vec![clip(1, 1, 0, 1000), clip(2, 1, 2000, 500)],
+            Vec::new(),
+            vec![marker(1, 1500)],
+        );
+        ops.insert_time(400, 300, &EditScope::AllTracks);
+
+        let layout: Vec<_> = ops
+            .clips
+            .iter()
+            .map(|c| (c.start_samples, c.length_samples, c.source_offset_samples))
+            .collect();
+        assert_eq!(layout, vec![(0, 400, 0), (700, 600, 400), (2300, 500, 0)]);
+        assert_eq!(ops.markers[0].position_samples, 1800);
+    }
+
+    #[test]
+    fn test_split_crossfade_follows_tail() {
+        let xfade = |start| EditCrossfade {
+            track_id: TrackId(1),
+            clip_a: 1,
+            clip_b: 2,
+            start_samples: start,
+            length_samples: 100,
+        };
+
+        // Clip 1's fade into clip 2 sits at its end, which becomes the tail
+        let mut ops = EditOps::new(
+            vec![clip(1, 1, 0, 1000), clip(2, 1, 900, 500)],
+            vec![xfade(900)],
+            Vec::new(),
+        );
+        ops.insert_time(400, 300, &EditScope::AllTracks);
+        let tail = ops
+            .clips
+            .iter()
+            .find(|c| c.start_samples == 700)
+            .unwrap()
+            .id;
+        assert_ne!(tail, 1);
+        assert_eq!(ops.crossfades.len(), 1);
+        assert_eq!(ops.crossfades[0].clip_a, tail);
+        assert_eq!(ops.crossfades[0].start_samples, 1200);
+
+        let mut ops = EditOps::new(
+            vec![clip(1, 1, 0, 1000), clip(2, 1, 900, 500)],
+            vec![xfade(900)],
+            Vec::new(),
+        );
+        ops.ripple_delete(400..600, &EditScope::AllTracks);
+        let tail = ops
+            .clips
+            .iter()
+            .find(|c| c.start_samples == 400)
+            .unwrap()
+            .id;
+        assert_ne!(tail, 1);
+        assert_eq!(ops.crossfades.len(), 1);
+        assert_eq!(ops.crossfades[0].clip_a, tail);
+        assert_eq!(ops.crossfades[0].start_samples, 700);
+    }
+
     #[test]
     fn test_edit_mode_names() {
         assert_eq!(EditMode::Slip.name(), "Slip");
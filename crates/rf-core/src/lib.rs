@@ -21,6 +21,10 @@ mod time;
 mod track;
 mod widgets;
 
+// Shared SIMD buffer ops. Public module rather than a glob re-export: names
+// like `add`, `copy` and `clear` are too generic for the crate root.
+pub mod buffer;
+
 // FLUX_MASTER_TODO 2.2.2 — RIFF/WAVE 16-bit PCM stereo writer.
 // Pairs sa `rf-engine::master_ring::MasterRingBuffer` (Phase 10e-2) da
 // Problems Inbox snima audio replay pored screenshot-a. Public modul jer
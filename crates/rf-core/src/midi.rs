@@ -6,9 +6,12 @@
 //! - Sample-accurate timing
 //! - MIDI buffer management
 //! - MIDI file parsing helpers
+//! - MIDI clock / MMC generation for syncing external gear
 
 use serde::{Deserialize, Serialize};
 
+use crate::time::{FrameRate, Timecode};
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // MIDI CONSTANTS
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -346,6 +349,9 @@ impl MidiEvent {
                 MidiEventData::PitchBend { value }
             }
             status::SYSTEM => match status {
+                0xF2 if bytes.len() >= 3 => MidiEventData::SongPosition {
+                    position: (bytes[1] & 0x7F) as u16 | ((bytes[2] & 0x7F) as u16) << 7,
+                },
                 0xF8 => MidiEventData::TimingClock,
                 0xFA => MidiEventData::Start,
                 0xFB => MidiEventData::Continue,
@@ -415,6 +421,13 @@ impl MidiEvent {
                     buffer[2] = (pressure.min(127)) as u8;
                     3
                 }
+            MidiEventData::SongPosition { position }
+                if buffer.len() >= 3 => {
+                    buffer[0] = 0xF2;
+                    buffer[1] = (position & 0x7F) as u8;
+                    buffer[2] = ((position >> 7) & 0x7F) as u8;
+                    3
+                }
             MidiEventData::TimingClock
                 if !buffer.is_empty() => {
                     buffer[0] = 0xF8;
@@ -943,6 +956,247 @@ impl Default for MidiClip {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// MIDI CLOCK / MMC (OUTGOING SYNC)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// MIDI clock resolution (pulses per quarter note)
+pub const MIDI_CLOCK_PPQN: u32 = 24;
+
+/// MMC command bytes (MIDI Machine Control, Universal Real Time SysEx sub-ID 06)
+pub mod mmc {
+    pub const STOP: u8 = 0x01;
+    pub const PLAY: u8 = 0x02;
+    pub const DEFERRED_PLAY: u8 = 0x03;
+    pub const LOCATE: u8 = 0x44;
+    /// Device ID addressing every receiver
+    pub const ALL_DEVICES: u8 = 0x7F;
+}
+
+/// Rate code stored in bits 5-6 of the MMC hours byte
+/// (0 = 24, 1 = 25, 2 = 30 drop-frame, 3 = 30 non-drop; higher rates use
+/// the code of their base rate)
+fn mmc_rate_code(rate: FrameRate) -> u8 {
+    match rate {
+        FrameRate::Fps23_976 | FrameRate::Fps24 => 0,
+        FrameRate::Fps25 | FrameRate::Fps50 => 1,
+        FrameRate::Fps29_97Drop | FrameRate::Fps59_94Drop => 2,
+        FrameRate::Fps29_97
+        | FrameRate::Fps30
+        | FrameRate::Fps59_94
+        | FrameRate::Fps60
+        | FrameRate::Custom { .. } => 3,
+    }
+}
+
+/// Transport change queued for the next block
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ClockTransport {
+    Start,
+    Stop,
+}
+
+/// Outgoing MIDI clock (24 ppqn) + transport/MMC generator.
+///
+/// Call `process_block` once per audio block with the transport position at
+/// the block start; it appends the clock ticks due in that block (and any
+/// queued Start/Stop/Locate messages at offset 0) to the output buffer.
+/// Ticks are derived from the beat position, so they stay locked to the
+/// transport across tempo changes and locates.
+#[derive(Debug, Clone)]
+pub struct MidiClockGenerator {
+    sample_rate: f64,
+    /// Clock ticks are being sent
+    running: bool,
+    /// Index of the next clock tick to send (ticks since beat 0)
+    next_tick: u64,
+    /// Beat position expected at the next block start (for locate detection)
+    expected_beats: Option<f64>,
+    /// Queued transport change
+    pending_transport: Option<ClockTransport>,
+    /// Queued locate (beats, seconds)
+    pending_locate: Option<(f64, f64)>,
+    /// Send MMC SysEx alongside System Real Time messages
+    pub send_mmc: bool,
+    /// MMC target device ID
+    pub mmc_device_id: u8,
+    /// MMC locate frame rate
+    pub mmc_frame_rate: FrameRate,
+}
+
+impl MidiClockGenerator {
+    pub fn new(sample_rate: f64) -> Self {
+        Self {
+            sample_rate,
+            running: false,
+            next_tick: 0,
+            expected_beats: None,
+            pending_transport: None,
+            pending_locate: None,
+            send_mmc: true,
+            mmc_device_id: mmc::ALL_DEVICES,
+            mmc_frame_rate: FrameRate::Fps30,
+        }
+    }
+
+    pub fn set_sample_rate(&mut self, sample_rate: f64) {
+        self.sample_rate = sample_rate;
+    }
+
+    /// Is the clock running (sending ticks)
+    pub fn is_running(&self) -> bool {
+        self.running || self.pending_transport == Some(ClockTransport::Start)
+    }
+
+    /// Start sending clock from the next block's position
+    /// (Start at beat 0, otherwise Song Position + Continue)
+    pub fn start(&mut self) {
+        self.pending_transport = Some(ClockTransport::Start);
+    }
+
+    /// Stop sending clock
+    pub fn stop(&mut self) {
+        self.pending_transport = Some(ClockTransport::Stop);
+    }
+
+    /// Relocate external gear (Song Position Pointer + MMC Locate)
+    pub fn locate(&mut self, position_beats: f64, position_seconds: f64) {
+        self.pending_locate = Some((position_beats.max(0.0), position_seconds.max(0.0)));
+    }
+
+    /// Emit the clock ticks and transport messages due in this block
+    pub fn process_block(
+        &mut self,
+        position_beats: f64,
+        position_seconds: f64,
+        tempo_bpm: f64,
+        block_size: usize,
+        out: &mut MidiBuffer,
+    ) {
+        let position_beats = position_beats.max(0.0);
+        let beats_per_sample = tempo_bpm.max(1.0) / 60.0 / self.sample_rate;
+
+        if self.pending_transport == Some(ClockTransport::Stop) {
+            self.pending_transport = None;
+            if self.running {
+                self.running = false;
+                out.push(Self::system(0, MidiEventData::Stop));
+                self.push_mmc(out, &[mmc::STOP]);
+            }
+        }
+
+        if let Some((beats, seconds)) = self.pending_locate.take() {
+            out.push(Self::system(
+                0,
+                MidiEventData::SongPosition {
+                    position: Self::song_position(beats),
+                },
+            ));
+            self.push_mmc_locate(out, seconds);
+        }
+
+        // A position jump (loop, seek) re-aligns the tick counter to the new beat
+        let jumped = self
+            .expected_beats
+            .is_none_or(|expected| (position_beats - expected).abs() > beats_per_sample);
+        if jumped {
+            self.next_tick = Self::first_tick_at(position_beats);
+        }
+
+        if self.pending_transport == Some(ClockTransport::Start) {
+            self.pending_transport = None;
+            if !self.running {
+                self.running = true;
+                self.next_tick = Self::first_tick_at(position_beats);
+                if self.next_tick == 0 {
+                    out.push(Self::system(0, MidiEventData::Start));
+                } else {
+                    out.push(Self::system(
+                        0,
+                        MidiEventData::SongPosition {
+                            position: Self::song_position(position_beats),
+                        },
+                    ));
+                    out.push(Self::system(0, MidiEventData::Continue));
+                }
+                self.push_mmc_locate(out, position_seconds);
+                self.push_mmc(out, &[mmc::PLAY]);
+            }
+        }
+
+        self.expected_beats = Some(position_beats + block_size as f64 * beats_per_sample);
+
+        if !self.running {
+            return;
+        }
+
+        loop {
+            let tick_beats = self.next_tick as f64 / MIDI_CLOCK_PPQN as f64;
+            // Rounded so ticks on exact sample boundaries aren't shifted by float error
+            let offset = ((tick_beats - position_beats) / beats_per_sample)
+                .round()
+                .max(0.0);
+            if offset >= block_size as f64 {
+                break;
+            }
+            out.push(Self::system(offset as u32, MidiEventData::TimingClock));
+            self.next_tick += 1;
+        }
+    }
+
+    /// Index of the first tick at or after `beats`
+    fn first_tick_at(beats: f64) -> u64 {
+        // Tolerance so a block starting exactly on a tick doesn't skip it to rounding
+        (beats * MIDI_CLOCK_PPQN as f64 - 1e-9).ceil().max(0.0) as u64
+    }
+
+    /// Song Position Pointer value (MIDI beats = 16th notes), 14-bit
+    fn song_position(beats: f64) -> u16 {
+        ((beats * 4.0).floor() as u64).min(0x3FFF) as u16
+    }
+
+    fn system(sample_offset: u32, data: MidiEventData) -> MidiEvent {
+        MidiEvent {
+            sample_offset,
+            channel: 0xFF,
+            data,
+        }
+    }
+
+    /// `F0 7F <device> 06 <command...> F7`
+    fn push_mmc(&self, out: &mut MidiBuffer, command: &[u8]) {
+        if !self.send_mmc {
+            return;
+        }
+        let mut msg = [0u8; 16];
+        msg[..4].copy_from_slice(&[0xF0, 0x7F, self.mmc_device_id & 0x7F, 0x06]);
+        msg[4..4 + command.len()].copy_from_slice(command);
+        msg[4 + command.len()] = 0xF7;
+        out.push_sysex(0, &msg[..5 + command.len()]);
+    }
+
+    /// MMC Locate to an absolute time (`44 06 01 hr mn sc fr ff`)
+    fn push_mmc_locate(&self, out: &mut MidiBuffer, seconds: f64) {
+        let rate = self.mmc_frame_rate;
+        // Counted in subframes (1/100 frame) so exact frame times don't floor one short
+        let subframes = (seconds.max(0.0) * rate.fps() * 100.0).round() as u64;
+        let tc = Timecode::from_frames(subframes / 100, rate);
+        self.push_mmc(
+            out,
+            &[
+                mmc::LOCATE,
+                0x06,
+                0x01,
+                (mmc_rate_code(rate) << 5) | (tc.hours % 24) as u8,
+                tc.minutes as u8,
+                tc.seconds as u8,
+                tc.frames as u8,
+                (subframes % 100) as u8,
+            ],
+        );
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // TESTS
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -1014,6 +1268,103 @@ mod tests {
         assert!(!tracker.is_note_on(0, 60));
     }
 
+    #[test]
+    fn test_midi_clock_120_bpm() {
+        let sample_rate = 48000.0;
+        let tempo = 120.0;
+        let mut clock = MidiClockGenerator::new(sample_rate);
+        let mut out = MidiBuffer::new();
+
+        clock.start();
+        let mut ticks = 0;
+        let mut position = 0usize;
+        let mut first_block = true;
+        while position < 48000 * 2 {
+            let block = 512.min(48000 * 2 - position);
+            let beats = position as f64 / sample_rate * tempo / 60.0;
+            out.clear();
+            clock.process_block(beats, position as f64 / sample_rate, tempo, block, &mut out);
+
+            if first_block {
+                assert!(matches!(out.events()[0].data, MidiEventData::Start));
+                // MMC Locate + Play follow the Start
+                let sysex: Vec<_> = out
+                    .events()
+                    .iter()
+                    .filter_map(|e| match e.data {
+                        MidiEventData::SysEx { length, offset } => out.get_sysex(offset, length),
+                        _ => None,
+                    })
+                    .collect();
+                assert_eq!(
+                    sysex.last().unwrap(),
+                    &[0xF0, 0x7F, 0x7F, 0x06, mmc::PLAY, 0xF7]
+                );
+                first_block = false;
+            }
+
+            for event in out.events() {
+                if matches!(event.data, MidiEventData::TimingClock) {
+                    assert!((event.sample_offset as usize) < block);
+                    // 48 ticks/s at 120 BPM → one tick every 1000 samples
+                    assert_eq!((position + event.sample_offset as usize) % 1000, 0);
+                    ticks += 1;
+                }
+            }
+            position += block;
+        }
+        // 2 beats/s * 24 ppqn = 48 ticks per second
+        assert_eq!(ticks, 96);
+
+        // Stop sends Stop + MMC Stop, then no more ticks
+        clock.stop();
+        out.clear();
+        clock.process_block(4.0, 2.0, tempo, 512, &mut out);
+        assert!(matches!(out.events()[0].data, MidiEventData::Stop));
+        assert!(
+            !out.events()
+                .iter()
+                .any(|e| matches!(e.data, MidiEventData::TimingClock))
+        );
+    }
+
+    #[test]
+    fn test_mmc_locate_drop_frame() {
+        let mut clock = MidiClockGenerator::new(48000.0);
+        clock.mmc_frame_rate = FrameRate::Fps29_97Drop;
+        let mut out = MidiBuffer::new();
+
+        // Frame 1800 is labelled 00:01:00;02 (frames 0 and 1 dropped)
+        clock.locate(120.0, 1800.0 * 1001.0 / 30000.0);
+        clock.process_block(120.0, 60.06, 120.0, 512, &mut out);
+        let sysex = out
+            .events()
+            .iter()
+            .find_map(|e| match e.data {
+                MidiEventData::SysEx { length, offset } => out.get_sysex(offset, length),
+                _ => None,
+            })
+            .unwrap();
+        assert_eq!(
+            sysex,
+            &[
+                0xF0,
+                0x7F,
+                0x7F,
+                0x06,
+                mmc::LOCATE,
+                0x06,
+                0x01,
+                2 << 5,
+                1,
+                0,
+                2,
+                0,
+                0xF7
+            ]
+        );
+    }
+
     #[test]
     fn test_midi_clip() {
         let mut clip = MidiClip::new("test", "Test Clip");
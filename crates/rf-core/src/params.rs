@@ -1,6 +1,7 @@
 //! Parameter types for audio processors
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 /// Parameter ID
@@ -99,7 +100,7 @@ pub struct ParamChange {
 }
 
 /// Parameter range specification
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct ParamRange {
     pub min: f64,
     pub max: f64,
@@ -108,7 +109,7 @@ pub struct ParamRange {
 }
 
 impl ParamRange {
-    pub fn linear(min: f64, max: f64, default: f64) -> Self {
+    pub const fn linear(min: f64, max: f64, default: f64) -> Self {
         Self {
             min,
             max,
@@ -117,7 +118,7 @@ impl ParamRange {
         }
     }
 
-    pub fn logarithmic(min: f64, max: f64, default: f64) -> Self {
+    pub const fn logarithmic(min: f64, max: f64, default: f64) -> Self {
         Self {
             min,
             max,
@@ -163,3 +164,203 @@ pub enum ParamSkew {
     Logarithmic,
     Exponential(f64),
 }
+
+/// Read access to current parameter values (plain, not normalized)
+pub trait ParamValues {
+    fn value(&self, id: ParamId) -> Option<f64>;
+}
+
+impl ParamValues for HashMap<ParamId, f64> {
+    fn value(&self, id: ParamId) -> Option<f64> {
+        self.get(&id).copied()
+    }
+}
+
+/// Predicate over other parameters' values
+///
+/// A parameter whose condition references an unknown parameter is disabled.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ParamCondition {
+    /// Switch parameter is on (value >= 0.5)
+    IsOn(ParamId),
+    /// Switch parameter is off (value < 0.5)
+    IsOff(ParamId),
+    /// Choice parameter has exactly this value
+    Equals(ParamId, f64),
+    /// Parameter value within `min..=max`
+    InRange(ParamId, f64, f64),
+    /// All conditions hold
+    All(Vec<ParamCondition>),
+    /// At least one condition holds
+    Any(Vec<ParamCondition>),
+    /// Condition does not hold
+    Not(Box<ParamCondition>),
+}
+
+impl ParamCondition {
+    /// Evaluate against current values
+    pub fn evaluate(&self, state: &impl ParamValues) -> bool {
+        match self {
+            Self::IsOn(id) => state.value(*id).is_some_and(|v| v >= 0.5),
+            Self::IsOff(id) => state.value(*id).is_some_and(|v| v < 0.5),
+            Self::Equals(id, value) => state.value(*id).is_some_and(|v| (v - value).abs() < 1e-9),
+            Self::InRange(id, min, max) => state.value(*id).is_some_and(|v| v >= *min && v <= *max),
+            Self::All(conditions) => conditions.iter().all(|c| c.evaluate(state)),
+            Self::Any(conditions) => conditions.iter().any(|c| c.evaluate(state)),
+            Self::Not(condition) => !condition.evaluate(state),
+        }
+    }
+
+    /// Parameters this condition reads
+    pub fn dependencies(&self) -> Vec<ParamId> {
+        let mut ids = Vec::new();
+        self.collect_dependencies(&mut ids);
+        ids
+    }
+
+    fn collect_dependencies(&self, ids: &mut Vec<ParamId>) {
+        match self {
+            Self::IsOn(id) | Self::IsOff(id) | Self::Equals(id, _) | Self::InRange(id, _, _) => {
+                if !ids.contains(id) {
+                    ids.push(*id);
+                }
+            }
+            Self::All(conditions) | Self::Any(conditions) => {
+                for condition in conditions {
+                    condition.collect_dependencies(ids);
+                }
+            }
+            Self::Not(condition) => condition.collect_dependencies(ids),
+        }
+    }
+}
+
+/// Parameter declaration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Param {
+    pub id: ParamId,
+    pub name: String,
+    pub range: ParamRange,
+    /// Enabled only while this holds (None = always enabled)
+    pub enabled_when: Option<ParamCondition>,
+}
+
+impl Param {
+    pub fn new(id: ParamId, name: &str, range: ParamRange) -> Self {
+        Self {
+            id,
+            name: name.to_string(),
+            range,
+            enabled_when: None,
+        }
+    }
+
+    /// Enable this parameter only while `condition` holds
+    pub fn enabled_when(mut self, condition: ParamCondition) -> Self {
+        self.enabled_when = Some(condition);
+        self
+    }
+}
+
+/// Parameter declarations of one processor, with dependency resolution
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ParamSet {
+    params: Vec<Param>,
+}
+
+impl ParamSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add (or replace) a parameter
+    pub fn add(&mut self, param: Param) {
+        match self.params.iter_mut().find(|p| p.id == param.id) {
+            Some(existing) => *existing = param,
+            None => self.params.push(param),
+        }
+    }
+
+    pub fn get(&self, id: ParamId) -> Option<&Param> {
+        self.params.iter().find(|p| p.id == id)
+    }
+
+    pub fn params(&self) -> &[Param] {
+        &self.params
+    }
+
+    /// Whether a control for `id` should be active
+    ///
+    /// A parameter is disabled if its own condition fails or if any parameter
+    /// its condition reads is itself disabled (e.g. a sidechain filter under a
+    /// disabled sidechain under a bypassed compressor). Unknown ids are
+    /// disabled; dependency cycles resolve to disabled.
+    pub fn is_enabled(&self, id: ParamId, state: &impl ParamValues) -> bool {
+        let mut visiting = Vec::new();
+        self.resolve(id, state, &mut visiting)
+    }
+
+    fn resolve(&self, id: ParamId, state: &impl ParamValues, visiting: &mut Vec<ParamId>) -> bool {
+        let Some(param) = self.get(id) else {
+            return false;
+        };
+        let Some(condition) = &param.enabled_when else {
+            return true;
+        };
+        if visiting.contains(&id) {
+            return false;
+        }
+
+        visiting.push(id);
+        let enabled = condition.evaluate(state)
+            && condition
+                .dependencies()
+                .into_iter()
+                .all(|dep| self.resolve(dep, state, visiting));
+        visiting.pop();
+        enabled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ENABLED: ParamId = ParamId(0);
+    const RATIO: ParamId = ParamId(1);
+    const KNEE: ParamId = ParamId(2);
+
+    fn compressor() -> ParamSet {
+        let mut set = ParamSet::new();
+        set.add(Param::new(
+            ENABLED,
+            "enabled",
+            ParamRange::linear(0.0, 1.0, 1.0),
+        ));
+        set.add(
+            Param::new(RATIO, "ratio", ParamRange::linear(1.0, 20.0, 4.0))
+                .enabled_when(ParamCondition::IsOn(ENABLED)),
+        );
+        set.add(
+            Param::new(KNEE, "knee", ParamRange::linear(0.0, 24.0, 6.0))
+                .enabled_when(ParamCondition::InRange(RATIO, 1.01, 20.0)),
+        );
+        set
+    }
+
+    #[test]
+    fn test_ratio_disabled_when_compressor_off() {
+        let set = compressor();
+        let mut state = HashMap::from([(ENABLED, 1.0), (RATIO, 4.0), (KNEE, 6.0)]);
+
+        assert!(set.is_enabled(ENABLED, &state));
+        assert!(set.is_enabled(RATIO, &state));
+        assert!(set.is_enabled(KNEE, &state));
+
+        state.insert(ENABLED, 0.0);
+        assert!(!set.is_enabled(RATIO, &state));
+        // Knee depends on ratio, which is now disabled
+        assert!(!set.is_enabled(KNEE, &state));
+        assert!(set.is_enabled(ENABLED, &state));
+    }
+}
@@ -8,11 +8,14 @@
 //! - Individual gain per destination
 //! - Quick A/B switching between routing presets
 //! - Pre/post fader routing options
+//!
+//! Also provides [`ChannelMatrix`], the up/down-mix used when connecting
+//! nodes with mismatched channel counts.
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-use crate::{Decibels, TrackId};
+use crate::{Decibels, RfError, RfResult, Sample, TrackId};
 
 /// Maximum number of direct routing destinations per track
 pub const MAX_DIRECT_ROUTES: usize = 8;
@@ -288,6 +291,154 @@ impl RoutingManager {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// CHANNEL FORMAT CONVERSION
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// -3 dB (1/√2) — equal-power gain used for folding one channel into two
+/// or two into one
+pub const MINUS_3DB: Sample = std::f64::consts::FRAC_1_SQRT_2;
+
+/// Channel count of a 5.1 stream (ITU/SMPTE order: L R C LFE Ls Rs)
+pub const SURROUND_51_CHANNELS: usize = 6;
+
+/// Up/down-mix matrix between two channel counts
+///
+/// Supported conversions (all streams interleaved, 5.1 in L R C LFE Ls Rs
+/// order):
+///
+/// | From → To | Coefficients |
+/// |-----------|--------------|
+/// | N → N | identity |
+/// | mono → stereo | L = R = M |
+/// | stereo → mono | M = −3 dB · (L + R) |
+/// | mono → 5.1 | C = M |
+/// | stereo → 5.1 | L = L, R = R, rest silent |
+/// | 5.1 → stereo | ITU-R BS.775: Lo = L + −3 dB · C + −3 dB · Ls, Ro = R + −3 dB · C + −3 dB · Rs, LFE dropped |
+/// | 5.1 → mono | 5.1 → stereo followed by stereo → mono |
+///
+/// Anything else is rejected instead of guessing a layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChannelMatrix {
+    in_channels: usize,
+    out_channels: usize,
+    /// Row-major gains, `coefficients[out * in_channels + in]`
+    coefficients: Vec<Sample>,
+}
+
+impl ChannelMatrix {
+    /// Build the matrix converting `in_channels` to `out_channels`
+    pub fn new(in_channels: usize, out_channels: usize) -> RfResult<Self> {
+        let rows: Vec<Vec<Sample>> = match (in_channels, out_channels) {
+            (n, m) if n == m && n > 0 => (0..n)
+                .map(|o| (0..n).map(|i| if i == o { 1.0 } else { 0.0 }).collect())
+                .collect(),
+            (1, 2) => vec![vec![1.0], vec![1.0]],
+            (2, 1) => vec![vec![MINUS_3DB, MINUS_3DB]],
+            (1, SURROUND_51_CHANNELS) => {
+                let mut rows = vec![vec![0.0]; SURROUND_51_CHANNELS];
+                rows[2] = vec![1.0];
+                rows
+            }
+            (2, SURROUND_51_CHANNELS) => {
+                let mut rows = vec![vec![0.0, 0.0]; SURROUND_51_CHANNELS];
+                rows[0] = vec![1.0, 0.0];
+                rows[1] = vec![0.0, 1.0];
+                rows
+            }
+            (SURROUND_51_CHANNELS, 2) => vec![
+                vec![1.0, 0.0, MINUS_3DB, 0.0, MINUS_3DB, 0.0],
+                vec![0.0, 1.0, MINUS_3DB, 0.0, 0.0, MINUS_3DB],
+            ],
+            (SURROUND_51_CHANNELS, 1) => {
+                return Ok(Self::new(2, 1)?.then(&Self::new(SURROUND_51_CHANNELS, 2)?));
+            }
+            _ => {
+                return Err(RfError::InvalidParam(format!(
+                    "Unsupported channel conversion: {} -> {}",
+                    in_channels, out_channels
+                )));
+            }
+        };
+
+        Ok(Self {
+            in_channels,
+            out_channels,
+            coefficients: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// `self` applied after `first` (`first`: A → B, `self`: B → C)
+    fn then(&self, first: &Self) -> Self {
+        debug_assert_eq!(first.out_channels, self.in_channels);
+        let mut coefficients = vec![0.0; self.out_channels * first.in_channels];
+        for o in 0..self.out_channels {
+            for i in 0..first.in_channels {
+                coefficients[o * first.in_channels + i] = (0..self.in_channels)
+                    .map(|k| self.gain(o, k) * first.gain(k, i))
+                    .sum();
+            }
+        }
+        Self {
+            in_channels: first.in_channels,
+            out_channels: self.out_channels,
+            coefficients,
+        }
+    }
+
+    /// Input channel count
+    pub fn in_channels(&self) -> usize {
+        self.in_channels
+    }
+
+    /// Output channel count
+    pub fn out_channels(&self) -> usize {
+        self.out_channels
+    }
+
+    /// Gain from input channel `input` to output channel `output`
+    #[inline]
+    pub fn gain(&self, output: usize, input: usize) -> Sample {
+        self.coefficients[output * self.in_channels + input]
+    }
+
+    /// Convert interleaved frames from `input` into `output`
+    ///
+    /// Processes as many whole frames as both buffers hold; returns the frame count.
+    pub fn process(&self, input: &[Sample], output: &mut [Sample]) -> usize {
+        let frames = (input.len() / self.in_channels).min(output.len() / self.out_channels);
+        let in_frames = input.chunks_exact(self.in_channels);
+        let out_frames = output.chunks_exact_mut(self.out_channels);
+        for (in_frame, out_frame) in in_frames.zip(out_frames).take(frames) {
+            for (o, out) in out_frame.iter_mut().enumerate() {
+                let row = &self.coefficients[o * self.in_channels..(o + 1) * self.in_channels];
+                *out = row.iter().zip(in_frame).map(|(g, x)| g * x).sum();
+            }
+        }
+        frames
+    }
+
+    /// Convert an interleaved buffer between channel counts
+    pub fn convert(
+        in_channels: usize,
+        out_channels: usize,
+        samples: &[Sample],
+    ) -> RfResult<Vec<Sample>> {
+        let matrix = Self::new(in_channels, out_channels)?;
+        if !samples.len().is_multiple_of(in_channels) {
+            return Err(RfError::InvalidParam(format!(
+                "Buffer of {} samples is not a whole number of {}-channel frames",
+                samples.len(),
+                in_channels
+            )));
+        }
+
+        let mut output = vec![0.0; samples.len() / in_channels * out_channels];
+        matrix.process(samples, &mut output);
+        Ok(output)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -353,4 +504,58 @@ mod tests {
         let sources = manager.get_sources(&RouteDestination::Track(track2));
         assert!(sources.contains(&track1));
     }
+
+    #[test]
+    fn test_channel_matrix_itu_51_to_stereo() {
+        let m = ChannelMatrix::new(6, 2).unwrap();
+        // L R C LFE Ls Rs
+        let expected_left = [1.0, 0.0, MINUS_3DB, 0.0, MINUS_3DB, 0.0];
+        let expected_right = [0.0, 1.0, MINUS_3DB, 0.0, 0.0, MINUS_3DB];
+        for i in 0..6 {
+            assert_eq!(m.gain(0, i), expected_left[i]);
+            assert_eq!(m.gain(1, i), expected_right[i]);
+        }
+        assert!((Decibels::from_gain(MINUS_3DB).0 + 3.0103).abs() < 1e-4);
+
+        // Center-only and LFE-only frames
+        let out = ChannelMatrix::convert(
+            6,
+            2,
+            &[0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0],
+        )
+        .unwrap();
+        assert!((out[0] - MINUS_3DB).abs() < 1e-12);
+        assert!((out[1] - MINUS_3DB).abs() < 1e-12);
+        assert_eq!(&out[2..], &[0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_channel_matrix_mono_stereo() {
+        assert_eq!(
+            ChannelMatrix::convert(1, 2, &[0.5, -0.25]).unwrap(),
+            vec![0.5, 0.5, -0.25, -0.25]
+        );
+
+        let mono = ChannelMatrix::convert(2, 1, &[1.0, 1.0]).unwrap();
+        assert!((mono[0] - 2.0 * MINUS_3DB).abs() < 1e-12);
+
+        // 5.1 → mono is the downmix chain: C at unity, surrounds at -6 dB
+        let m = ChannelMatrix::new(6, 1).unwrap();
+        assert!((m.gain(0, 2) - 1.0).abs() < 1e-12);
+        assert!((m.gain(0, 4) - 0.5).abs() < 1e-12);
+        assert_eq!(m.gain(0, 3), 0.0);
+
+        assert_eq!(
+            ChannelMatrix::convert(2, 2, &[0.1, 0.2]).unwrap(),
+            vec![0.1, 0.2]
+        );
+    }
+
+    #[test]
+    fn test_channel_matrix_rejects_unknown() {
+        assert!(ChannelMatrix::new(3, 2).is_err());
+        assert!(ChannelMatrix::new(2, 4).is_err());
+        assert!(ChannelMatrix::new(0, 0).is_err());
+        assert!(ChannelMatrix::convert(2, 1, &[0.0, 0.0, 0.0]).is_err());
+    }
 }
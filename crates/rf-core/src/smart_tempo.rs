@@ -881,6 +881,183 @@ impl TempoDetector {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// TEMPO ESTIMATE — one-shot detection for warping
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Tempo estimate for clip warping and tempo map creation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TempoEstimate {
+    /// Estimated BPM
+    pub bpm: f64,
+    /// Confidence (0.0-1.0)
+    pub confidence: f64,
+    /// Beat positions (sample positions)
+    pub beat_positions: Vec<u64>,
+}
+
+/// How well a beat grid explains the detected onsets
+#[derive(Debug, Clone)]
+struct GridFit {
+    /// Refined BPM (least-squares fit of matched onsets)
+    bpm: f64,
+    /// Beat positions
+    beats: Vec<u64>,
+    /// Strength-weighted fraction of beats landing on an onset
+    precision: f64,
+    /// Strength-weighted fraction of onsets landing on a beat
+    recall: f64,
+}
+
+impl GridFit {
+    fn f_measure(&self) -> f64 {
+        if self.precision + self.recall <= 0.0 {
+            0.0
+        } else {
+            2.0 * self.precision * self.recall / (self.precision + self.recall)
+        }
+    }
+}
+
+/// Log-Gaussian tempo prior centered at 120 BPM (one octave std dev)
+fn tempo_prior(bpm: f64) -> f64 {
+    let octaves = (bpm / 120.0).log2();
+    (-0.5 * octaves * octaves).exp()
+}
+
+/// Detect tempo and beat positions from mono audio
+///
+/// Onset-based tempo induction (ODF autocorrelation + comb filter), then
+/// octave-error resolution: half, original and double tempo each get a phase-
+/// aligned beat grid, scored by how well the grid and the onsets explain each
+/// other (F-measure) weighted by a log-Gaussian prior around 120 BPM. A 140 BPM
+/// click track explains every onset at 140 but only half at 70, so 70 loses.
+pub fn detect_tempo(samples: &[f64], sample_rate: f64) -> TempoEstimate {
+    let mut detector = TempoDetector::new(sample_rate);
+    detector.set_range(40.0, 240.0);
+    detector.process(samples);
+
+    let detection = detector.analyze();
+    if detection.confidence <= 0.0 || detector.onset_peaks.len() < 4 {
+        return TempoEstimate {
+            bpm: detection.bpm,
+            confidence: 0.0,
+            beat_positions: Vec::new(),
+        };
+    }
+
+    // Onset salience = peak level around the onset. The ODF is a level ratio,
+    // so a quiet hat after silence scores like a kick; accents need the level.
+    let window = detector.hop_size * 2;
+    let salience: Vec<f64> = detector
+        .onset_peaks
+        .iter()
+        .map(|&(position, _)| {
+            let start = (position as usize).min(samples.len());
+            let end = (start + window).min(samples.len());
+            samples[start..end]
+                .iter()
+                .fold(0.0f64, |peak, s| peak.max(s.abs()))
+        })
+        .collect();
+
+    let best = [0.5, 1.0, 2.0]
+        .iter()
+        .map(|factor| detection.bpm * factor)
+        .filter(|bpm| (detector.min_bpm..=detector.max_bpm).contains(bpm))
+        .filter_map(|bpm| detector.fit_grid(bpm, &salience))
+        .map(|fit| (fit.f_measure() * tempo_prior(fit.bpm), fit))
+        .max_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    match best {
+        Some((_, fit)) => TempoEstimate {
+            bpm: fit.bpm,
+            confidence: (fit.f_measure() * 0.7 + detection.confidence * 0.3).clamp(0.0, 1.0),
+            beat_positions: fit.beats,
+        },
+        None => TempoEstimate {
+            bpm: detection.bpm,
+            confidence: detection.confidence * 0.5,
+            beat_positions: detection.beats,
+        },
+    }
+}
+
+impl TempoDetector {
+    /// Phase-align a beat grid at `bpm`, refine its period and score it
+    /// (`salience` holds one weight per onset peak)
+    fn fit_grid(&self, bpm: f64, salience: &[f64]) -> Option<GridFit> {
+        let beats = self.track_beats(bpm);
+        if beats.len() < 2 {
+            return None;
+        }
+
+        let period = self.sample_rate * 60.0 / bpm;
+        let tolerance = (period * 0.2).min(self.sample_rate * 0.05);
+        let max_salience = salience.iter().cloned().fold(0.0, f64::max);
+        if max_salience <= 0.0 {
+            return None;
+        }
+        let onsets = || {
+            self.onset_peaks
+                .iter()
+                .map(|o| o.0 as f64)
+                .zip(salience.iter().copied())
+        };
+
+        // Match each beat to its most salient onset within tolerance
+        let mut matched: Vec<(f64, f64)> = Vec::new(); // (beat index, onset position)
+        let mut precision = 0.0;
+        for (k, &beat) in beats.iter().enumerate() {
+            let nearest = onsets()
+                .filter(|o| (o.0 - beat as f64).abs() < tolerance)
+                .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+            if let Some((position, weight)) = nearest {
+                precision += weight / max_salience;
+                matched.push((k as f64, position));
+            }
+        }
+        precision /= beats.len() as f64;
+
+        let total: f64 = salience.iter().sum();
+        let on_grid: f64 = onsets()
+            .filter(|o| beats.iter().any(|&b| (o.0 - b as f64).abs() < tolerance))
+            .map(|o| o.1)
+            .sum();
+        let recall = on_grid / total;
+
+        // Least-squares line through matched onsets refines period and phase
+        let (bpm, beats) = if matched.len() >= 3 {
+            let n = matched.len() as f64;
+            let mean_k = matched.iter().map(|m| m.0).sum::<f64>() / n;
+            let mean_p = matched.iter().map(|m| m.1).sum::<f64>() / n;
+            let cov: f64 = matched
+                .iter()
+                .map(|m| (m.0 - mean_k) * (m.1 - mean_p))
+                .sum();
+            let var: f64 = matched.iter().map(|m| (m.0 - mean_k).powi(2)).sum();
+            let slope = if var > 0.0 { cov / var } else { period };
+            let intercept = mean_p - slope * mean_k;
+
+            let refined: Vec<u64> = (0..beats.len())
+                .map(|k| intercept + k as f64 * slope)
+                .filter(|&p| p >= 0.0)
+                .map(|p| p as u64)
+                .collect();
+            (self.sample_rate * 60.0 / slope, refined)
+        } else {
+            (bpm, beats)
+        };
+
+        Some(GridFit {
+            bpm,
+            beats,
+            precision,
+            recall,
+        })
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // SMART TEMPO — High-level processor
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -1163,4 +1340,102 @@ mod tests {
             detection.bpm, detection.confidence
         );
     }
+
+    /// Decaying 2 kHz click of given amplitude
+    fn add_click(audio: &mut [f64], pos: usize, amplitude: f64, sample_rate: f64) {
+        for i in 0..480 {
+            if pos + i < audio.len() {
+                let t = i as f64 / sample_rate;
+                audio[pos + i] +=
+                    amplitude * (t * 2000.0 * std::f64::consts::TAU).sin() * (-t * 300.0).exp();
+            }
+        }
+    }
+
+    #[test]
+    fn test_detect_tempo_click_track() {
+        let sample_rate = 48000.0;
+        let bpm = 140.0;
+        let period = sample_rate * 60.0 / bpm;
+        let mut audio = vec![0.0f64; (sample_rate * 12.0) as usize];
+        let mut beat = 0;
+        while ((beat as f64 * period) as usize) < audio.len() {
+            add_click(
+                &mut audio,
+                (beat as f64 * period) as usize,
+                0.8,
+                sample_rate,
+            );
+            beat += 1;
+        }
+
+        let estimate = detect_tempo(&audio, sample_rate);
+        assert!(
+            (estimate.bpm - 140.0).abs() < 1.0,
+            "Expected 140 BPM, got {} (confidence {})",
+            estimate.bpm,
+            estimate.confidence
+        );
+        assert!(
+            estimate.confidence > 0.7,
+            "Confidence too low: {}",
+            estimate.confidence
+        );
+
+        // Beats land on the clicks
+        assert!(estimate.beat_positions.len() > 20);
+        for &pos in &estimate.beat_positions {
+            let offset = pos as f64 % period;
+            let dist = offset.min(period - offset);
+            assert!(
+                dist < sample_rate * 0.02,
+                "Beat {} is {} samples off",
+                pos,
+                dist
+            );
+        }
+    }
+
+    #[test]
+    fn test_detect_tempo_half_double_ambiguity() {
+        // 90 BPM: loud kick on quarters, quiet hat on the off-eighths.
+        // Plausible readings are 45, 90 and 180 — 90 is the sensible one.
+        let sample_rate = 48000.0;
+        let eighth = sample_rate * 60.0 / 90.0 / 2.0;
+        let mut audio = vec![0.0f64; (sample_rate * 12.0) as usize];
+        let mut step = 0;
+        while ((step as f64 * eighth) as usize) < audio.len() {
+            let amplitude = if step % 2 == 0 { 0.9 } else { 0.2 };
+            add_click(
+                &mut audio,
+                (step as f64 * eighth) as usize,
+                amplitude,
+                sample_rate,
+            );
+            step += 1;
+        }
+
+        let estimate = detect_tempo(&audio, sample_rate);
+        assert!(
+            (estimate.bpm - 90.0).abs() < 1.5,
+            "Expected 90 BPM, got {} (confidence {})",
+            estimate.bpm,
+            estimate.confidence
+        );
+
+        // A genuinely slow click track stays slow (not doubled)
+        let period = sample_rate * 60.0 / 72.0;
+        let mut slow = vec![0.0f64; (sample_rate * 15.0) as usize];
+        let mut beat = 0;
+        while ((beat as f64 * period) as usize) < slow.len() {
+            add_click(&mut slow, (beat as f64 * period) as usize, 0.8, sample_rate);
+            beat += 1;
+        }
+        let estimate = detect_tempo(&slow, sample_rate);
+        assert!(
+            (estimate.bpm - 72.0).abs() < 1.0,
+            "Expected 72 BPM, got {}",
+            estimate.bpm
+        );
+    }
 }
@@ -2,6 +2,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::tempo::{MusicalPosition, TempoMap};
+
 /// Sample position in the timeline
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct SamplePosition(pub u64);
@@ -121,3 +123,397 @@ impl Default for Tempo {
 }
 
 // TimeSignature is now in tempo.rs with enhanced functionality
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// SMPTE TIMECODE
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// SMPTE frame rate
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum FrameRate {
+    /// 23.976 fps (24000/1001)
+    Fps23_976,
+    /// 24 fps (film)
+    Fps24,
+    /// 25 fps (PAL)
+    #[default]
+    Fps25,
+    /// 29.97 fps non-drop (30000/1001)
+    Fps29_97,
+    /// 29.97 fps drop-frame
+    Fps29_97Drop,
+    /// 30 fps
+    Fps30,
+    /// 50 fps (PAL high frame rate)
+    Fps50,
+    /// 59.94 fps non-drop (60000/1001)
+    Fps59_94,
+    /// 59.94 fps drop-frame
+    Fps59_94Drop,
+    /// 60 fps
+    Fps60,
+    /// Any other rate, as exact numerator/denominator frames per second
+    Custom { num: u32, den: u32 },
+}
+
+impl FrameRate {
+    /// Exact rate as numerator/denominator frames per second
+    pub fn ratio(self) -> (u64, u64) {
+        match self {
+            FrameRate::Fps23_976 => (24000, 1001),
+            FrameRate::Fps24 => (24, 1),
+            FrameRate::Fps25 => (25, 1),
+            FrameRate::Fps29_97 | FrameRate::Fps29_97Drop => (30000, 1001),
+            FrameRate::Fps30 => (30, 1),
+            FrameRate::Fps50 => (50, 1),
+            FrameRate::Fps59_94 | FrameRate::Fps59_94Drop => (60000, 1001),
+            FrameRate::Fps60 => (60, 1),
+            FrameRate::Custom { num, den } => (num.max(1) as u64, den.max(1) as u64),
+        }
+    }
+
+    /// Frames per second
+    pub fn fps(self) -> f64 {
+        let (num, den) = self.ratio();
+        num as f64 / den as f64
+    }
+
+    /// Frames per timecode second (frame labels count 0..nominal)
+    pub fn nominal(self) -> u64 {
+        match self {
+            FrameRate::Fps23_976 | FrameRate::Fps24 => 24,
+            FrameRate::Fps25 => 25,
+            FrameRate::Fps29_97 | FrameRate::Fps29_97Drop | FrameRate::Fps30 => 30,
+            FrameRate::Fps50 => 50,
+            FrameRate::Fps59_94 | FrameRate::Fps59_94Drop | FrameRate::Fps60 => 60,
+            FrameRate::Custom { .. } => (self.fps().round() as u64).max(1),
+        }
+    }
+
+    /// Drop-frame labelling (the first labels of each minute skipped except
+    /// every 10th: 2 at 29.97, 4 at 59.94)
+    pub fn is_drop_frame(self) -> bool {
+        matches!(self, FrameRate::Fps29_97Drop | FrameRate::Fps59_94Drop)
+    }
+
+    /// Labels skipped per drop-frame minute
+    fn dropped_per_minute(self) -> u64 {
+        if self.is_drop_frame() {
+            self.nominal() / 15
+        } else {
+            0
+        }
+    }
+
+    /// Frame duration in samples at `sample_rate`
+    pub fn frame_duration_samples(self, sample_rate: u32) -> f64 {
+        sample_rate as f64 / self.fps()
+    }
+}
+
+impl std::fmt::Display for FrameRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FrameRate::Fps23_976 => write!(f, "23.976 fps"),
+            FrameRate::Fps24 => write!(f, "24 fps"),
+            FrameRate::Fps25 => write!(f, "25 fps"),
+            FrameRate::Fps29_97 => write!(f, "29.97 fps"),
+            FrameRate::Fps29_97Drop => write!(f, "29.97 fps DF"),
+            FrameRate::Fps30 => write!(f, "30 fps"),
+            FrameRate::Fps50 => write!(f, "50 fps"),
+            FrameRate::Fps59_94 => write!(f, "59.94 fps"),
+            FrameRate::Fps59_94Drop => write!(f, "59.94 fps DF"),
+            FrameRate::Fps60 => write!(f, "60 fps"),
+            FrameRate::Custom { .. } => write!(f, "{:.3} fps", self.fps()),
+        }
+    }
+}
+
+/// SMPTE timecode (HH:MM:SS:FF, `;` before frames for drop-frame)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timecode {
+    pub hours: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+    pub frames: u32,
+    pub rate: FrameRate,
+}
+
+impl Timecode {
+    pub fn new(hours: u32, minutes: u32, seconds: u32, frames: u32, rate: FrameRate) -> Self {
+        Self {
+            hours,
+            minutes,
+            seconds,
+            frames,
+            rate,
+        }
+    }
+
+    /// Timecode label for a frame count from zero
+    pub fn from_frames(frames: u64, rate: FrameRate) -> Self {
+        let nominal = rate.nominal();
+        let mut label = frames;
+        if rate.is_drop_frame() {
+            let drop = rate.dropped_per_minute();
+            let frames_per_min = nominal * 60 - drop;
+            let frames_per_10_min = nominal * 600 - 9 * drop;
+            let tens = frames / frames_per_10_min;
+            let rem = frames % frames_per_10_min;
+            label += 9 * drop * tens;
+            if rem >= drop {
+                label += drop * ((rem - drop) / frames_per_min);
+            }
+        }
+
+        let total_seconds = label / nominal;
+        Self {
+            hours: (total_seconds / 3600) as u32,
+            minutes: (total_seconds / 60 % 60) as u32,
+            seconds: (total_seconds % 60) as u32,
+            frames: (label % nominal) as u32,
+            rate,
+        }
+    }
+
+    /// Frame count from zero
+    pub fn to_frames(&self) -> u64 {
+        let nominal = self.rate.nominal();
+        let total_seconds =
+            self.hours as u64 * 3600 + self.minutes as u64 * 60 + self.seconds as u64;
+        let label = total_seconds * nominal + self.frames as u64;
+        let total_minutes = self.hours as u64 * 60 + self.minutes as u64;
+        label - self.rate.dropped_per_minute() * (total_minutes - total_minutes / 10)
+    }
+
+    /// Seconds from zero
+    pub fn to_seconds(&self) -> f64 {
+        let (num, den) = self.rate.ratio();
+        self.to_frames() as f64 * den as f64 / num as f64
+    }
+
+    /// Samples from zero at `sample_rate` (rounded)
+    pub fn to_samples(&self, sample_rate: u32) -> u64 {
+        Position::from_timecode(self, sample_rate).to_samples()
+    }
+
+    /// Timecode `frames` later (earlier if negative, stopping at zero)
+    pub fn add_frames(&self, frames: i64) -> Self {
+        let frame = (self.to_frames() as i64 + frames).max(0) as u64;
+        Self::from_frames(frame, self.rate)
+    }
+
+    /// Frames from `other` to `self`
+    pub fn difference(&self, other: &Timecode) -> i64 {
+        self.to_frames() as i64 - other.to_frames() as i64
+    }
+
+    /// Parse "HH:MM:SS:FF" (or "HH:MM:SS;FF")
+    pub fn parse(s: &str, rate: FrameRate) -> Option<Self> {
+        let parts: Vec<&str> = s.trim().split([':', ';']).collect();
+        if parts.len() != 4 {
+            return None;
+        }
+        let mut fields = [0u32; 4];
+        for (field, part) in fields.iter_mut().zip(&parts) {
+            *field = part.parse().ok()?;
+        }
+        let [hours, minutes, seconds, frames] = fields;
+        if minutes >= 60 || seconds >= 60 || frames as u64 >= rate.nominal() {
+            return None;
+        }
+        // Dropped labels don't exist
+        if seconds == 0 && (frames as u64) < rate.dropped_per_minute() && minutes % 10 != 0 {
+            return None;
+        }
+        Some(Self {
+            hours,
+            minutes,
+            seconds,
+            frames,
+            rate,
+        })
+    }
+}
+
+impl std::fmt::Display for Timecode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let sep = if self.rate.is_drop_frame() { ';' } else { ':' };
+        write!(
+            f,
+            "{:02}:{:02}:{:02}{}{:02}",
+            self.hours, self.minutes, self.seconds, sep, self.frames
+        )
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// POSITION
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Timeline position convertible between samples, seconds, BBT and timecode
+///
+/// Stored as a sample count (the engine's native resolution), so converting
+/// in and out of samples is lossless; seconds, ticks and frames round to the
+/// nearest unit of the target format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Position {
+    samples: u64,
+    sample_rate: u32,
+}
+
+impl Position {
+    pub fn from_samples(samples: u64, sample_rate: u32) -> Self {
+        Self {
+            samples,
+            sample_rate,
+        }
+    }
+
+    pub fn from_seconds(seconds: f64, sample_rate: u32) -> Self {
+        Self::from_samples(
+            (seconds.max(0.0) * sample_rate as f64).round() as u64,
+            sample_rate,
+        )
+    }
+
+    /// Position of a bar/beat/tick (0-indexed) in the tempo map
+    pub fn from_bbt(pos: &MusicalPosition, tempo_map: &TempoMap) -> Self {
+        let ticks = tempo_map.position_to_ticks(pos);
+        Self::from_samples(tempo_map.ticks_to_samples(ticks), tempo_map.sample_rate())
+    }
+
+    pub fn from_timecode(timecode: &Timecode, sample_rate: u32) -> Self {
+        let (num, den) = timecode.rate.ratio();
+        let scaled = timecode.to_frames() as u128 * sample_rate as u128 * den as u128;
+        let samples = (scaled + num as u128 / 2) / num as u128;
+        Self::from_samples(samples as u64, sample_rate)
+    }
+
+    /// Parse a display string
+    ///
+    /// Accepts timecode ("00:01:16:00"), bars.beats.ticks ("39.1.000",
+    /// 1-indexed), seconds ("76.0s") or a plain sample count ("3648000").
+    pub fn parse(s: &str, tempo_map: &TempoMap, rate: FrameRate) -> Option<Self> {
+        let s = s.trim();
+        let sample_rate = tempo_map.sample_rate();
+        if s.contains([':', ';']) {
+            Timecode::parse(s, rate).map(|tc| Self::from_timecode(&tc, sample_rate))
+        } else if s.matches('.').count() == 2 {
+            MusicalPosition::from_display_string(s).map(|pos| Self::from_bbt(&pos, tempo_map))
+        } else if let Some(seconds) = s.strip_suffix('s') {
+            let seconds: f64 = seconds.trim().parse().ok()?;
+            (seconds.is_finite() && seconds >= 0.0)
+                .then(|| Self::from_seconds(seconds, sample_rate))
+        } else {
+            s.parse()
+                .ok()
+                .map(|samples| Self::from_samples(samples, sample_rate))
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    pub fn to_samples(&self) -> u64 {
+        self.samples
+    }
+
+    pub fn to_seconds(&self) -> f64 {
+        self.samples as f64 / self.sample_rate as f64
+    }
+
+    /// Bars/beats/ticks (0-indexed) in the tempo map
+    pub fn to_bbt(&self, tempo_map: &TempoMap) -> MusicalPosition {
+        let ticks = tempo_map.samples_to_ticks(self.samples_at(tempo_map.sample_rate()));
+        tempo_map.ticks_to_position(ticks)
+    }
+
+    /// Timecode of the frame containing this position
+    pub fn to_timecode(&self, rate: FrameRate) -> Timecode {
+        let (num, den) = rate.ratio();
+        let frames = self.samples as u128 * num as u128 / (self.sample_rate as u128 * den as u128);
+        Timecode::from_frames(frames as u64, rate)
+    }
+
+    /// Sample count at another sample rate (rounded)
+    fn samples_at(&self, sample_rate: u32) -> u64 {
+        if sample_rate == self.sample_rate {
+            return self.samples;
+        }
+        let scaled = self.samples as u128 * sample_rate as u128;
+        ((scaled + self.sample_rate as u128 / 2) / self.sample_rate as u128) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_conversions() {
+        let map = TempoMap::new(48000); // 120 BPM, 4/4
+        let pos = Position::from_samples(3_648_000, 48000);
+
+        assert!((pos.to_seconds() - 76.0).abs() < 1e-12);
+
+        // 76 s at 120 BPM = 152 beats = bar 39, beat 1
+        let bbt = pos.to_bbt(&map);
+        assert_eq!(bbt.to_display_string(), "39.1.000");
+        assert_eq!(Position::from_bbt(&bbt, &map), pos);
+
+        let tc = pos.to_timecode(FrameRate::Fps25);
+        assert_eq!(tc.to_string(), "00:01:16:00");
+        assert_eq!(Position::from_timecode(&tc, 48000), pos);
+
+        // Timecode names the frame containing the position
+        let before = Position::from_samples(3_647_999, 48000);
+        assert_eq!(
+            before.to_timecode(FrameRate::Fps25).to_string(),
+            "00:01:15:24"
+        );
+
+        for text in ["00:01:16:00", "39.1.000", "76s", "3648000"] {
+            assert_eq!(
+                Position::parse(text, &map, FrameRate::Fps25),
+                Some(pos),
+                "{}",
+                text
+            );
+        }
+        assert_eq!(Position::parse("1:2:3", &map, FrameRate::Fps25), None);
+    }
+
+    #[test]
+    fn test_drop_frame_timecode() {
+        let rate = FrameRate::Fps29_97Drop;
+
+        // Labels ;00 and ;01 are skipped at minute 1 but not at minute 10
+        let tc = Timecode::from_frames(1800, rate);
+        assert_eq!(tc.to_string(), "00:01:00;02");
+        assert_eq!(tc.to_frames(), 1800);
+        assert_eq!(
+            Timecode::from_frames(17982, rate).to_string(),
+            "00:10:00;00"
+        );
+        assert!(Timecode::parse("00:01:00;00", rate).is_none());
+
+        for frames in [0, 1799, 1800, 17981, 17982, 107_892] {
+            assert_eq!(Timecode::from_frames(frames, rate).to_frames(), frames);
+        }
+
+        // 59.94 drops four labels per minute
+        let rate = FrameRate::Fps59_94Drop;
+        let tc = Timecode::from_frames(3600, rate);
+        assert_eq!(tc.to_string(), "00:01:00;04");
+        assert_eq!(
+            Timecode::from_frames(35964, rate).to_string(),
+            "00:10:00;00"
+        );
+        assert!(Timecode::parse("00:01:00;03", rate).is_none());
+        for frames in [0, 3599, 3600, 35963, 35964, 215_784] {
+            assert_eq!(Timecode::from_frames(frames, rate).to_frames(), frames);
+        }
+    }
+}
@@ -316,6 +316,41 @@ impl BiquadCoeffs {
             a2: 0.0,
         }
     }
+
+    /// Calculate coefficients for any filter type.
+    /// `gain_db` is ignored by types without gain.
+    pub fn for_type(
+        filter_type: FilterType,
+        freq: f64,
+        q: f64,
+        gain_db: f64,
+        sample_rate: f64,
+    ) -> Self {
+        match filter_type {
+            FilterType::Lowpass => Self::lowpass(freq, q, sample_rate),
+            FilterType::Highpass => Self::highpass(freq, q, sample_rate),
+            FilterType::Bandpass => Self::bandpass(freq, q, sample_rate),
+            FilterType::Notch => Self::notch(freq, q, sample_rate),
+            FilterType::Allpass => Self::allpass(freq, q, sample_rate),
+            FilterType::Peaking => Self::peaking(freq, q, gain_db, sample_rate),
+            FilterType::LowShelf => Self::low_shelf(freq, q, gain_db, sample_rate),
+            FilterType::HighShelf => Self::high_shelf(freq, q, gain_db, sample_rate),
+            // Single-biquad approximation, as in the EQ tilt shelf
+            FilterType::Tilt => Self::high_shelf(freq, 0.5, gain_db, sample_rate),
+        }
+    }
+
+    /// Pull both poles strictly inside the unit circle.
+    ///
+    /// A biquad is stable iff `|a2| < 1` and `|a1| < 1 + a2` (stability
+    /// triangle); coefficients on or outside the edge are clamped just inside.
+    pub fn stabilized(mut self) -> Self {
+        const MARGIN: f64 = 1.0 - 1e-6;
+        self.a2 = self.a2.clamp(-MARGIN, MARGIN);
+        let a1_max = (1.0 + self.a2) * MARGIN;
+        self.a1 = self.a1.clamp(-a1_max, a1_max);
+        self
+    }
 }
 
 /// Transposed Direct Form II biquad filter
@@ -637,6 +672,182 @@ impl BiquadSimd4 {
     }
 }
 
+// ============================================================================
+// SMOOTHED BIQUAD
+// ============================================================================
+
+/// Default parameter smoothing time constant (ms)
+const DEFAULT_SMOOTHING_MS: f64 = 20.0;
+/// Relative parameter distance below which smoothing snaps to target
+const SMOOTHING_SNAP: f64 = 1e-6;
+
+/// TDF-II biquad with per-sample parameter smoothing for live sweeps
+///
+/// Frequency (in the log domain), Q and gain glide towards their targets
+/// with a one-pole smoother, and coefficients are recomputed every sample
+/// while gliding. Each intermediate filter is a valid design, so there is
+/// no zipper noise from per-block coefficient jumps, and coefficients are
+/// clamped inside the stability triangle so fast sweeps cannot blow up.
+#[derive(Debug, Clone)]
+pub struct SmoothedBiquad {
+    filter_type: FilterType,
+    filter: BiquadTDF2,
+    /// Current (log2 frequency, Q, gain dB)
+    current: [f64; 3],
+    /// Target (log2 frequency, Q, gain dB)
+    target: [f64; 3],
+    /// Smoothing time constant (ms)
+    smoothing_ms: f64,
+    /// One-pole smoothing coefficient
+    smoothing_coeff: f64,
+}
+
+impl SmoothedBiquad {
+    pub fn new(filter_type: FilterType, sample_rate: f64) -> Self {
+        let filter = BiquadTDF2::new(sample_rate);
+        let params = [1000.0_f64.log2(), 0.707, 0.0];
+        let mut smoothed = Self {
+            filter_type,
+            smoothing_coeff: Self::coeff_for(DEFAULT_SMOOTHING_MS, filter.sample_rate()),
+            filter,
+            current: params,
+            target: params,
+            smoothing_ms: DEFAULT_SMOOTHING_MS,
+        };
+        smoothed.update_coeffs();
+        smoothed
+    }
+
+    /// One-pole coefficient reaching ~63% of a step in `time_ms`
+    fn coeff_for(time_ms: f64, sample_rate: f64) -> f64 {
+        let samples = time_ms * 0.001 * sample_rate;
+        if samples <= 1.0 {
+            1.0
+        } else {
+            1.0 - (-1.0 / samples).exp()
+        }
+    }
+
+    /// Set smoothing time constant in ms (0 = jump immediately)
+    pub fn set_smoothing_time(&mut self, time_ms: f64) {
+        self.smoothing_ms = time_ms.max(0.0);
+        self.smoothing_coeff = Self::coeff_for(self.smoothing_ms, self.filter.sample_rate());
+    }
+
+    pub fn smoothing_time(&self) -> f64 {
+        self.smoothing_ms
+    }
+
+    pub fn filter_type(&self) -> FilterType {
+        self.filter_type
+    }
+
+    /// Change filter type (coefficients switch immediately)
+    pub fn set_filter_type(&mut self, filter_type: FilterType) {
+        self.filter_type = filter_type;
+        self.update_coeffs();
+    }
+
+    /// Glide towards new parameters
+    pub fn set_target(&mut self, freq: f64, q: f64, gain_db: f64) {
+        let (freq, q, _) = sanitize_params(freq, q, self.filter.sample_rate());
+        self.target = [freq.log2(), q, sanitize_gain(gain_db)];
+    }
+
+    /// Glide towards a new frequency, keeping Q and gain
+    pub fn set_target_freq(&mut self, freq: f64) {
+        let [_, q, gain_db] = self.target;
+        self.set_target(freq, q, gain_db);
+    }
+
+    /// Jump to new parameters without smoothing
+    pub fn set_immediate(&mut self, freq: f64, q: f64, gain_db: f64) {
+        self.set_target(freq, q, gain_db);
+        self.current = self.target;
+        self.update_coeffs();
+    }
+
+    /// Current (smoothed) frequency in Hz
+    pub fn current_freq(&self) -> f64 {
+        self.current[0].exp2()
+    }
+
+    pub fn target_freq(&self) -> f64 {
+        self.target[0].exp2()
+    }
+
+    /// Whether parameters are still gliding
+    pub fn is_smoothing(&self) -> bool {
+        self.current != self.target
+    }
+
+    /// Current coefficients
+    pub fn coeffs(&self) -> &BiquadCoeffs {
+        self.filter.coeffs()
+    }
+
+    /// Clear filter state, keeping parameters
+    pub fn reset(&mut self) {
+        self.filter.reset();
+    }
+
+    /// Process a block sample by sample
+    pub fn process_block(&mut self, buffer: &mut [Sample]) {
+        for sample in buffer.iter_mut() {
+            *sample = self.process_sample(*sample);
+        }
+    }
+
+    #[inline]
+    fn advance_smoothing(&mut self) {
+        for (current, target) in self.current.iter_mut().zip(self.target) {
+            let diff = target - *current;
+            if diff.abs() <= SMOOTHING_SNAP * target.abs().max(1.0) {
+                *current = target;
+            } else {
+                *current += self.smoothing_coeff * diff;
+            }
+        }
+        self.update_coeffs();
+    }
+
+    fn update_coeffs(&mut self) {
+        let [log_freq, q, gain_db] = self.current;
+        let coeffs = BiquadCoeffs::for_type(
+            self.filter_type,
+            log_freq.exp2(),
+            q,
+            gain_db,
+            self.filter.sample_rate(),
+        );
+        self.filter.set_coeffs(coeffs.stabilized());
+    }
+}
+
+impl Processor for SmoothedBiquad {
+    fn reset(&mut self) {
+        self.filter.reset();
+    }
+}
+
+impl MonoProcessor for SmoothedBiquad {
+    #[inline]
+    fn process_sample(&mut self, input: Sample) -> Sample {
+        if self.is_smoothing() {
+            self.advance_smoothing();
+        }
+        self.filter.process_sample(input)
+    }
+}
+
+impl ProcessorConfig for SmoothedBiquad {
+    fn set_sample_rate(&mut self, sample_rate: f64) {
+        self.filter.set_sample_rate(sample_rate);
+        self.smoothing_coeff = Self::coeff_for(self.smoothing_ms, self.filter.sample_rate());
+        self.update_coeffs();
+    }
+}
+
 // ============================================================================
 // RUNTIME CPU DETECTION
 // ============================================================================
@@ -902,6 +1113,64 @@ impl BiquadAdaptive {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_smoothed_sweep_no_zipper() {
+        let sample_rate = 48000.0;
+        let block = 256;
+        let input: Vec<f64> = (0..block * 40)
+            .map(|i| 0.5 * (2.0 * PI * 220.0 * i as f64 / sample_rate).sin())
+            .collect();
+
+        // Largest jump in the output derivative (second difference)
+        let max_kink = |output: &[f64]| {
+            output
+                .windows(3)
+                .map(|w| (w[2] - 2.0 * w[1] + w[0]).abs())
+                .fold(0.0, f64::max)
+        };
+
+        // Resonant lowpass swept 200 Hz -> 8 kHz, target updated once per block
+        let sweep = |b: usize| 200.0 * 40.0_f64.powf(b as f64 / 39.0);
+
+        let mut stepped = BiquadTDF2::new(sample_rate);
+        let mut smoothed = SmoothedBiquad::new(FilterType::Lowpass, sample_rate);
+        smoothed.set_smoothing_time(5.0);
+        smoothed.set_immediate(sweep(0), 4.0, 0.0);
+
+        let mut out_stepped = input.clone();
+        let mut out_smoothed = input.clone();
+        for (b, (a, c)) in out_stepped
+            .chunks_mut(block)
+            .zip(out_smoothed.chunks_mut(block))
+            .enumerate()
+        {
+            stepped.set_lowpass(sweep(b), 4.0);
+            stepped.process_block(a);
+            smoothed.set_target_freq(sweep(b));
+            smoothed.process_block(c);
+        }
+
+        // Steady-state kink of the input sine for scale
+        let sine_kink = max_kink(&input);
+        let smoothed_kink = max_kink(&out_smoothed[block..]);
+        let stepped_kink = max_kink(&out_stepped[block..]);
+        assert!(
+            smoothed_kink < 8.0 * sine_kink,
+            "smoothed {:e} vs sine {:e}",
+            smoothed_kink,
+            sine_kink
+        );
+        assert!(stepped_kink > 2.0 * smoothed_kink);
+
+        // Fast resonant sweeps between the extremes stay bounded
+        smoothed.set_smoothing_time(1.0);
+        for (i, &x) in input.iter().enumerate() {
+            let freq = if (i / 64) % 2 == 0 { 20.0 } else { 23990.0 };
+            smoothed.set_target(freq, 10.0, 0.0);
+            assert!(smoothed.process_sample(x).abs() < 20.0);
+        }
+    }
+
     #[test]
     fn test_bypass() {
         let mut filter = BiquadTDF2::new(48000.0);
@@ -14,7 +14,7 @@
 
 use rf_core::Sample;
 
-use crate::analysis::PeakMeter;
+use crate::analysis::{PeakMeter, RmsMeter};
 use crate::biquad::{BiquadCoeffs, BiquadTDF2};
 use crate::dynamics::{Compressor, CompressorType, Gate, Limiter};
 use crate::spatial::{PanLaw, StereoPanner, StereoWidth};
@@ -175,6 +175,135 @@ pub enum ProcessingOrder {
     EqCompGate,
 }
 
+impl ProcessingOrder {
+    /// Full stage order for this preset (HPF first, limiter last)
+    pub fn stages(self) -> [StripStage; StripStage::COUNT] {
+        use StripStage::*;
+        match self {
+            ProcessingOrder::GateCompEq => [Hpf, Gate, Comp, Eq, Limiter],
+            ProcessingOrder::GateEqComp => [Hpf, Gate, Eq, Comp, Limiter],
+            ProcessingOrder::EqGateComp => [Hpf, Eq, Gate, Comp, Limiter],
+            ProcessingOrder::EqCompGate => [Hpf, Eq, Comp, Gate, Limiter],
+        }
+    }
+}
+
+/// Reorderable channel strip stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StripStage {
+    Hpf,
+    Gate,
+    Comp,
+    Eq,
+    Limiter,
+}
+
+impl StripStage {
+    /// Number of distinct stages
+    pub const COUNT: usize = 5;
+
+    pub const ALL: [StripStage; Self::COUNT] = [
+        StripStage::Hpf,
+        StripStage::Gate,
+        StripStage::Comp,
+        StripStage::Eq,
+        StripStage::Limiter,
+    ];
+}
+
+/// Rejected stage order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StripOrderError {
+    /// Stage appears more than once
+    DuplicateStage(StripStage),
+}
+
+impl std::fmt::Display for StripOrderError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StripOrderError::DuplicateStage(stage) => {
+                write!(f, "stage {:?} appears more than once", stage)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StripOrderError {}
+
+/// RMS window of the gain-staging meters (ms)
+const TAP_RMS_WINDOW_MS: f64 = 300.0;
+
+/// Peak and RMS level at one point of the strip (dBFS)
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TapLevels {
+    pub peak_l_db: f64,
+    pub peak_r_db: f64,
+    pub rms_l_db: f64,
+    pub rms_r_db: f64,
+}
+
+/// Gain-staging meters through the strip
+///
+/// Taps follow their stage wherever it sits in the processing order; a
+/// disabled stage still reports the signal passing its position.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct StripMeters {
+    /// After input gain
+    pub input: TapLevels,
+    pub post_gate: TapLevels,
+    pub post_eq: TapLevels,
+    pub post_comp: TapLevels,
+    /// After width, pan and output gain
+    pub output: TapLevels,
+}
+
+/// Stereo peak + RMS meter at one tap
+#[derive(Debug, Clone)]
+struct TapMeter {
+    peak_l: PeakMeter,
+    peak_r: PeakMeter,
+    rms_l: RmsMeter,
+    rms_r: RmsMeter,
+}
+
+impl TapMeter {
+    fn new(sample_rate: f64) -> Self {
+        Self {
+            peak_l: PeakMeter::new(sample_rate),
+            peak_r: PeakMeter::new(sample_rate),
+            rms_l: RmsMeter::new(sample_rate, TAP_RMS_WINDOW_MS),
+            rms_r: RmsMeter::new(sample_rate, TAP_RMS_WINDOW_MS),
+        }
+    }
+
+    #[inline]
+    fn process(&mut self, l: Sample, r: Sample) {
+        self.peak_l.process(l);
+        self.peak_r.process(r);
+        self.rms_l.process(l);
+        self.rms_r.process(r);
+    }
+
+    fn levels(&self) -> TapLevels {
+        TapLevels {
+            peak_l_db: self.peak_l.current_db(),
+            peak_r_db: self.peak_r.current_db(),
+            rms_l_db: self.rms_l.rms_db(),
+            rms_r_db: self.rms_r.rms_db(),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.peak_l.reset();
+        self.peak_r.reset();
+        self.rms_l.reset();
+        self.rms_r.reset();
+    }
+}
+
+/// Declick ramp after a reorder (ms)
+const REORDER_DECLICK_MS: f64 = 5.0;
+
 /// Complete stereo channel strip
 #[derive(Debug)]
 pub struct ChannelStrip {
@@ -213,14 +342,24 @@ pub struct ChannelStrip {
     panner: StereoPanner,
     width: StereoWidth,
 
-    // Processing order
-    order: ProcessingOrder,
-
-    // Metering
-    input_peak_l: PeakMeter,
-    input_peak_r: PeakMeter,
-    output_peak_l: PeakMeter,
-    output_peak_r: PeakMeter,
+    // Processing order (stages not listed are skipped)
+    stages: [StripStage; StripStage::COUNT],
+    stage_count: usize,
+
+    // Reorder declick: offset ramped out after the chain changes, captured
+    // again once the change has passed through the chain latency
+    reorder_pending: bool,
+    reorder_delayed_capture: usize,
+    declick_offset: (Sample, Sample),
+    declick_remaining: usize,
+    last_chain_out: (Sample, Sample),
+
+    // Gain-staging meters
+    input_meter: TapMeter,
+    post_gate_meter: TapMeter,
+    post_eq_meter: TapMeter,
+    post_comp_meter: TapMeter,
+    output_meter: TapMeter,
 
     // State
     solo: bool,
@@ -257,11 +396,18 @@ impl ChannelStrip {
             limiter_r: Limiter::new(sample_rate),
             panner: StereoPanner::new(),
             width: StereoWidth::new(),
-            order: ProcessingOrder::GateCompEq,
-            input_peak_l: PeakMeter::new(sample_rate),
-            input_peak_r: PeakMeter::new(sample_rate),
-            output_peak_l: PeakMeter::new(sample_rate),
-            output_peak_r: PeakMeter::new(sample_rate),
+            stages: ProcessingOrder::GateCompEq.stages(),
+            stage_count: StripStage::COUNT,
+            reorder_pending: false,
+            reorder_delayed_capture: 0,
+            declick_offset: (0.0, 0.0),
+            declick_remaining: 0,
+            last_chain_out: (0.0, 0.0),
+            input_meter: TapMeter::new(sample_rate),
+            post_gate_meter: TapMeter::new(sample_rate),
+            post_eq_meter: TapMeter::new(sample_rate),
+            post_comp_meter: TapMeter::new(sample_rate),
+            output_meter: TapMeter::new(sample_rate),
             solo: false,
             mute: false,
             sample_rate,
@@ -404,22 +550,75 @@ impl ChannelStrip {
 
     // Processing order
     pub fn set_processing_order(&mut self, order: ProcessingOrder) {
-        self.order = order;
+        let stages = order.stages();
+        self.apply_order(&stages);
+    }
+
+    /// Rearrange the processing chain.
+    ///
+    /// Stages keep their filter/envelope state; stages missing from `order`
+    /// are bypassed. The output discontinuity caused by the new chain is
+    /// ramped out over a few milliseconds.
+    pub fn set_order(&mut self, order: &[StripStage]) -> Result<(), StripOrderError> {
+        for (i, stage) in order.iter().enumerate() {
+            if order[..i].contains(stage) {
+                return Err(StripOrderError::DuplicateStage(*stage));
+            }
+        }
+        self.apply_order(order);
+        Ok(())
+    }
+
+    /// Current processing chain
+    pub fn order(&self) -> &[StripStage] {
+        &self.stages[..self.stage_count]
+    }
+
+    fn apply_order(&mut self, order: &[StripStage]) {
+        if self.order() == order {
+            return;
+        }
+        self.stages[..order.len()].copy_from_slice(order);
+        self.stage_count = order.len();
+        self.reorder_pending = true;
+        self.reorder_delayed_capture = match self.latency() {
+            0 => 0,
+            latency => latency + 1,
+        };
+    }
+
+    /// Latency contributed by a single stage while it is in the chain
+    pub fn stage_latency(&self, stage: StripStage) -> usize {
+        if !self.order().contains(&stage) {
+            return 0;
+        }
+        match stage {
+            StripStage::Comp if self.comp_enabled => self.comp_l.latency_samples(),
+            StripStage::Limiter if self.limiter_enabled => self.limiter_l.latency(),
+            _ => 0,
+        }
     }
 
     // Metering
     pub fn input_peak_db(&self) -> (f64, f64) {
-        (
-            self.input_peak_l.current_db(),
-            self.input_peak_r.current_db(),
-        )
+        let input = self.input_meter.levels();
+        (input.peak_l_db, input.peak_r_db)
     }
 
     pub fn output_peak_db(&self) -> (f64, f64) {
-        (
-            self.output_peak_l.current_db(),
-            self.output_peak_r.current_db(),
-        )
+        let output = self.output_meter.levels();
+        (output.peak_l_db, output.peak_r_db)
+    }
+
+    /// Peak/RMS at every gain-staging tap
+    pub fn meters(&self) -> StripMeters {
+        StripMeters {
+            input: self.input_meter.levels(),
+            post_gate: self.post_gate_meter.levels(),
+            post_eq: self.post_eq_meter.levels(),
+            post_comp: self.post_comp_meter.levels(),
+            output: self.output_meter.levels(),
+        }
     }
 
     pub fn gain_reduction_db(&self) -> f64 {
@@ -470,6 +669,78 @@ impl ChannelStrip {
         }
     }
 
+    /// Process HPF for both channels
+    #[inline]
+    fn process_hpf(&mut self, l: Sample, r: Sample) -> (Sample, Sample) {
+        if self.hpf_enabled {
+            (self.hpf_l.process_sample(l), self.hpf_r.process_sample(r))
+        } else {
+            (l, r)
+        }
+    }
+
+    /// Process limiter for both channels
+    #[inline]
+    fn process_limiter(&mut self, l: Sample, r: Sample) -> (Sample, Sample) {
+        if self.limiter_enabled {
+            (
+                self.limiter_l.process_sample(l),
+                self.limiter_r.process_sample(r),
+            )
+        } else {
+            (l, r)
+        }
+    }
+
+    /// Run the reorderable stages and ramp out any reorder discontinuity
+    #[inline]
+    fn process_chain(&mut self, mut l: Sample, mut r: Sample) -> (Sample, Sample) {
+        for i in 0..self.stage_count {
+            (l, r) = match self.stages[i] {
+                StripStage::Hpf => self.process_hpf(l, r),
+                StripStage::Gate => {
+                    let out = self.process_gate(l, r);
+                    self.post_gate_meter.process(out.0, out.1);
+                    out
+                }
+                StripStage::Comp => {
+                    let out = self.process_comp(l, r);
+                    self.post_comp_meter.process(out.0, out.1);
+                    out
+                }
+                StripStage::Eq => {
+                    let out = self.process_eq(l, r);
+                    self.post_eq_meter.process(out.0, out.1);
+                    out
+                }
+                StripStage::Limiter => self.process_limiter(l, r),
+            };
+        }
+
+        let declick_len = ((REORDER_DECLICK_MS * 0.001 * self.sample_rate) as usize).max(1);
+        let delayed_capture = match self.reorder_delayed_capture {
+            0 => false,
+            n => {
+                self.reorder_delayed_capture = n - 1;
+                n == 1
+            }
+        };
+        if self.reorder_pending || delayed_capture {
+            self.reorder_pending = false;
+            self.declick_offset = (self.last_chain_out.0 - l, self.last_chain_out.1 - r);
+            self.declick_remaining = declick_len;
+        }
+        if self.declick_remaining > 0 {
+            let frac = self.declick_remaining as f64 / declick_len as f64;
+            l += self.declick_offset.0 * frac;
+            r += self.declick_offset.1 * frac;
+            self.declick_remaining -= 1;
+        }
+
+        self.last_chain_out = (l, r);
+        (l, r)
+    }
+
     /// Process EQ for both channels
     #[inline]
     fn process_eq(&mut self, l: Sample, r: Sample) -> (Sample, Sample) {
@@ -495,18 +766,23 @@ impl Processor for ChannelStrip {
         self.limiter_r.reset();
         self.panner.reset();
         self.width.reset();
-        self.input_peak_l.reset();
-        self.input_peak_r.reset();
-        self.output_peak_l.reset();
-        self.output_peak_r.reset();
+        self.input_meter.reset();
+        self.post_gate_meter.reset();
+        self.post_eq_meter.reset();
+        self.post_comp_meter.reset();
+        self.output_meter.reset();
+        self.reorder_pending = false;
+        self.reorder_delayed_capture = 0;
+        self.declick_offset = (0.0, 0.0);
+        self.declick_remaining = 0;
+        self.last_chain_out = (0.0, 0.0);
     }
 
     fn latency(&self) -> usize {
-        let mut lat = 0;
-        if self.limiter_enabled {
-            lat += self.limiter_l.latency();
-        }
-        lat
+        self.order()
+            .iter()
+            .map(|&stage| self.stage_latency(stage))
+            .sum()
     }
 }
 
@@ -522,44 +798,10 @@ impl StereoProcessor for ChannelStrip {
         let mut r = right * self.input_gain;
 
         // Input metering
-        self.input_peak_l.process(l);
-        self.input_peak_r.process(r);
+        self.input_meter.process(l, r);
 
-        // High-pass filter
-        if self.hpf_enabled {
-            l = self.hpf_l.process_sample(l);
-            r = self.hpf_r.process_sample(r);
-        }
-
-        // Processing chain based on order
-        match self.order {
-            ProcessingOrder::GateCompEq => {
-                (l, r) = self.process_gate(l, r);
-                (l, r) = self.process_comp(l, r);
-                (l, r) = self.process_eq(l, r);
-            }
-            ProcessingOrder::GateEqComp => {
-                (l, r) = self.process_gate(l, r);
-                (l, r) = self.process_eq(l, r);
-                (l, r) = self.process_comp(l, r);
-            }
-            ProcessingOrder::EqGateComp => {
-                (l, r) = self.process_eq(l, r);
-                (l, r) = self.process_gate(l, r);
-                (l, r) = self.process_comp(l, r);
-            }
-            ProcessingOrder::EqCompGate => {
-                (l, r) = self.process_eq(l, r);
-                (l, r) = self.process_comp(l, r);
-                (l, r) = self.process_gate(l, r);
-            }
-        }
-
-        // Limiter
-        if self.limiter_enabled {
-            l = self.limiter_l.process_sample(l);
-            r = self.limiter_r.process_sample(r);
-        }
+        // HPF, gate, compressor, EQ, limiter in the configured order
+        (l, r) = self.process_chain(l, r);
 
         // Stereo width
         (l, r) = self.width.process_sample(l, r);
@@ -572,8 +814,7 @@ impl StereoProcessor for ChannelStrip {
         r *= self.output_gain;
 
         // Output metering
-        self.output_peak_l.process(l);
-        self.output_peak_r.process(r);
+        self.output_meter.process(l, r);
 
         (l, r)
     }
@@ -600,10 +841,11 @@ impl ProcessorConfig for ChannelStrip {
         self.limiter_l.set_sample_rate(sample_rate);
         self.limiter_r.set_sample_rate(sample_rate);
 
-        self.input_peak_l = PeakMeter::new(sample_rate);
-        self.input_peak_r = PeakMeter::new(sample_rate);
-        self.output_peak_l = PeakMeter::new(sample_rate);
-        self.output_peak_r = PeakMeter::new(sample_rate);
+        self.input_meter = TapMeter::new(sample_rate);
+        self.post_gate_meter = TapMeter::new(sample_rate);
+        self.post_eq_meter = TapMeter::new(sample_rate);
+        self.post_comp_meter = TapMeter::new(sample_rate);
+        self.output_meter = TapMeter::new(sample_rate);
     }
 }
 
@@ -655,6 +897,130 @@ mod tests {
         assert!(peak_l > -15.0); // 0.25 * 2 = 0.5 ≈ -6dB
     }
 
+    #[test]
+    fn test_strip_meters_gain_staging() {
+        let sine = |i: usize| (2.0 * std::f64::consts::PI * 1000.0 * i as f64 / 48000.0).sin();
+
+        // Half-scale sine: peak -6.02 dBFS, RMS -9.03 dBFS
+        let mut strip = ChannelStrip::new(48000.0);
+        strip.set_eq_enabled(false);
+        for i in 0..48000 {
+            let x = 0.5 * sine(i);
+            strip.process_sample(x, x);
+        }
+        let meters = strip.meters();
+        assert!((meters.input.peak_l_db + 6.02).abs() < 0.1);
+        assert!((meters.input.rms_l_db + 9.03).abs() < 0.1);
+        // Disabled stages pass the level through unchanged
+        assert!((meters.post_eq.rms_l_db - meters.input.rms_l_db).abs() < 1e-6);
+
+        // -6 dB input gain stage reads 6 dB lower at its tap
+        let mut strip = ChannelStrip::new(48000.0);
+        strip.set_eq_enabled(false);
+        strip.set_input_gain_db(-6.0);
+        for i in 0..48000 {
+            let x = sine(i);
+            strip.process_sample(x, x);
+        }
+        let meters = strip.meters();
+        assert!((meters.input.peak_l_db + 6.0).abs() < 0.1);
+        assert!((meters.input.rms_r_db + 9.01).abs() < 0.1);
+        assert!((meters.post_gate.peak_r_db + 6.0).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_channel_strip_reorder_eq_comp() {
+        let sr = 48000.0;
+        let make = || {
+            let mut strip = ChannelStrip::new(sr);
+            strip.set_eq_low(100.0, 12.0);
+            strip.set_comp_enabled(true);
+            strip.set_comp_threshold(-20.0);
+            strip.set_comp_ratio(8.0);
+            strip.set_comp_attack(1.0);
+            strip.set_limiter_enabled(true);
+            strip
+        };
+        let comp_eq = [
+            StripStage::Hpf,
+            StripStage::Gate,
+            StripStage::Comp,
+            StripStage::Eq,
+            StripStage::Limiter,
+        ];
+        let eq_comp = [
+            StripStage::Hpf,
+            StripStage::Gate,
+            StripStage::Eq,
+            StripStage::Comp,
+            StripStage::Limiter,
+        ];
+        let input = |i: usize| 0.3 * (2.0 * std::f64::consts::PI * 100.0 * i as f64 / sr).sin();
+        let n = 24000;
+
+        let mut a = make();
+        let mut b = make();
+        a.set_order(&comp_eq).unwrap();
+        b.set_order(&eq_comp).unwrap();
+        let out_a: Vec<f64> = (0..n)
+            .map(|i| a.process_sample(input(i), input(i)).0)
+            .collect();
+        let out_b: Vec<f64> = (0..n)
+            .map(|i| b.process_sample(input(i), input(i)).0)
+            .collect();
+        let max_diff = out_a[n / 2..]
+            .iter()
+            .zip(&out_b[n / 2..])
+            .map(|(x, y)| (x - y).abs())
+            .fold(0.0f64, f64::max);
+        assert!(
+            max_diff > 0.05,
+            "orders should differ, max diff {}",
+            max_diff
+        );
+
+        // Latency is the sum of active stages, independent of order
+        let limiter_latency = a.stage_latency(StripStage::Limiter);
+        assert!(limiter_latency > 0);
+        assert_eq!(a.latency(), limiter_latency);
+        assert_eq!(b.latency(), limiter_latency);
+        a.set_order(&[StripStage::Eq, StripStage::Comp]).unwrap();
+        assert_eq!(a.latency(), 0);
+        a.set_order(&comp_eq).unwrap();
+
+        // Swap mid-stream: no step larger than the signal's own slope
+        let mut c = make();
+        c.set_order(&comp_eq).unwrap();
+        let swap_at = n / 2;
+        let mut out_c = Vec::with_capacity(n);
+        for i in 0..n {
+            if i == swap_at {
+                c.set_order(&eq_comp).unwrap();
+            }
+            out_c.push(c.process_sample(input(i), input(i)).0);
+        }
+        let max_step = |out: &[f64]| {
+            out.windows(2)
+                .map(|w| (w[1] - w[0]).abs())
+                .fold(0.0f64, f64::max)
+        };
+        let steady = max_step(&out_a[n / 4..swap_at]).max(max_step(&out_b[n / 4..swap_at]));
+        let around_swap = max_step(&out_c[swap_at - 16..swap_at + 480]);
+        assert!(
+            around_swap <= steady * 1.5,
+            "click at reorder: step {} vs steady {}",
+            around_swap,
+            steady
+        );
+        assert_eq!(c.order(), &eq_comp);
+
+        assert_eq!(
+            c.set_order(&[StripStage::Eq, StripStage::Comp, StripStage::Eq]),
+            Err(StripOrderError::DuplicateStage(StripStage::Eq))
+        );
+        assert_eq!(c.order(), &eq_comp);
+    }
+
     #[test]
     fn test_console_eq() {
         let mut eq = ConsoleEq::new(48000.0);
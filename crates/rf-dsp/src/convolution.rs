@@ -12,6 +12,7 @@ use std::sync::Arc;
 use realfft::{ComplexToReal, RealFftPlanner, RealToComplex};
 use rustfft::num_complex::Complex;
 
+use crate::convolution_ultra::DirectConvolver;
 use crate::{Processor, ProcessorConfig, StereoProcessor};
 use rf_core::Sample;
 
@@ -26,36 +27,20 @@ const MAX_PARTITION_SIZE: usize = 8192;
 /// Default partition size (good balance)
 const DEFAULT_PARTITION_SIZE: usize = 512;
 
-// ============ Partition ============
+/// Largest size ratio between consecutive partitions
+const MAX_PARTITION_GROWTH: usize = 8;
 
-/// Single convolution partition (frequency domain IR segment)
-struct Partition {
-    /// FFT of IR segment (complex, half-spectrum)
-    spectrum: Vec<Complex<f64>>,
-    /// Size of this partition
-    size: usize,
-}
-
-impl Partition {
-    fn new(ir_segment: &[f64], fft: &Arc<dyn RealToComplex<f64>>) -> Self {
-        let fft_size = ir_segment.len() * 2;
-        let mut padded = vec![0.0; fft_size];
-        padded[..ir_segment.len()].copy_from_slice(ir_segment);
-
-        let mut spectrum = vec![Complex::new(0.0, 0.0); fft_size / 2 + 1];
-        fft.process(&mut padded, &mut spectrum).ok();
-
-        Self {
-            spectrum,
-            size: ir_segment.len(),
-        }
-    }
-}
+/// Default growth between partition sizes
+const DEFAULT_PARTITION_GROWTH: usize = 2;
 
 // ============ Partition Scheme ============
 
 /// Non-uniform partitioning scheme for low latency
 /// Uses smaller partitions at the start, larger ones for later IR segments
+///
+/// A partition of size `B` at offset `O` is only usable when
+/// `O >= B - first_size`: its overlap-save output for a block arrives one
+/// block late, so it must cover IR samples at least that far in.
 #[derive(Debug, Clone)]
 pub struct PartitionScheme {
     /// Partition sizes (first = smallest for low latency)
@@ -100,6 +85,33 @@ impl PartitionScheme {
         Self { sizes, offsets }
     }
 
+    /// Create scheme with a chosen first partition and growth factor
+    ///
+    /// `first_size` sets the latency. Later partitions are `growth` times
+    /// larger than the previous size, switching up as soon as the IR offset
+    /// allows the larger block to arrive on time. `growth` 1 is uniform.
+    pub fn with_growth(ir_length: usize, first_size: usize, growth: usize) -> Self {
+        let first_size = first_size.clamp(MIN_PARTITION_SIZE, MAX_PARTITION_SIZE);
+        let growth = growth.clamp(1, MAX_PARTITION_GROWTH);
+
+        let mut sizes = Vec::new();
+        let mut offsets = Vec::new();
+        let mut offset = 0;
+        let mut current_size = first_size;
+
+        while offset < ir_length {
+            let next_size = current_size * growth;
+            if offset > 0 && next_size <= MAX_PARTITION_SIZE && offset + first_size >= next_size {
+                current_size = next_size;
+            }
+            sizes.push(current_size);
+            offsets.push(offset);
+            offset += current_size;
+        }
+
+        Self { sizes, offsets }
+    }
+
     /// Get minimum partition size (determines latency)
     pub fn min_size(&self) -> usize {
         self.sizes
@@ -107,263 +119,234 @@ impl PartitionScheme {
             .copied()
             .unwrap_or(DEFAULT_PARTITION_SIZE)
     }
-}
-
-// ============ Convolution Channel ============
 
-/// Single channel convolution processor
-struct ConvolutionChannel {
-    /// IR partitions in frequency domain
-    partitions: Vec<Partition>,
-    /// First partition IR (time domain) for direct convolution (zero latency)
-    direct_ir: Vec<f64>,
-    /// Input history for direct convolution
-    direct_history: Vec<f64>,
-    /// Input buffer (time domain)
-    input_buffer: Vec<f64>,
-    /// Input FDLs (Frequency Delay Lines) for each partition size
-    fdls: Vec<Vec<Vec<Complex<f64>>>>,
-    /// Output overlap buffers per partition size
-    overlap_buffers: Vec<Vec<f64>>,
-    /// FFT planners per partition size
-    fft_forward: Vec<Arc<dyn RealToComplex<f64>>>,
-    fft_inverse: Vec<Arc<dyn ComplexToReal<f64>>>,
-    /// Partition scheme
-    scheme: PartitionScheme,
-    /// Current position in input buffer
-    buffer_pos: usize,
-    /// FDL write positions for each partition size
-    fdl_positions: Vec<usize>,
-    /// Block counter for triggering larger partitions
-    block_counter: usize,
-    /// Unique partition sizes (sorted)
-    unique_sizes: Vec<usize>,
+    /// Partition sizes in IR order
+    pub fn sizes(&self) -> &[usize] {
+        &self.sizes
+    }
 }
 
-impl ConvolutionChannel {
-    fn new(ir: &[f64], scheme: PartitionScheme) -> Self {
-        let mut planner = RealFftPlanner::<f64>::new();
-
-        // Get unique partition sizes
-        let mut unique_sizes: Vec<usize> = scheme.sizes.to_vec();
-        unique_sizes.sort();
-        unique_sizes.dedup();
+// ============ FFT Stage ============
 
-        // Create FFT planners for each size
-        let fft_forward: Vec<_> = unique_sizes
-            .iter()
-            .map(|&size| planner.plan_fft_forward(size * 2))
-            .collect();
-        let fft_inverse: Vec<_> = unique_sizes
-            .iter()
-            .map(|&size| planner.plan_fft_inverse(size * 2))
-            .collect();
-
-        // Create partitions
-        let partitions: Vec<_> = scheme
-            .sizes
-            .iter()
-            .zip(&scheme.offsets)
-            .map(|(&size, &offset)| {
-                let end = (offset + size).min(ir.len());
-                let segment = if offset < ir.len() {
-                    &ir[offset..end]
-                } else {
-                    &[]
-                };
-
-                // Find FFT for this size (guaranteed to exist since unique_sizes was built from sizes)
-                let size_idx = match unique_sizes.iter().position(|&s| s == size) {
-                    Some(idx) => idx,
-                    None => return Partition::new(&vec![0.0; size], &fft_forward[0]),
-                };
-
-                // Pad segment to partition size
-                let mut padded = vec![0.0; size];
-                let copy_len = segment.len().min(size);
-                padded[..copy_len].copy_from_slice(&segment[..copy_len]);
+/// Run of equal-size partitions, convolved by uniformly partitioned
+/// overlap-save from the channel's input history
+struct FftStage {
+    /// Partition (and hop) size
+    block_size: usize,
+    /// Whole blocks the input is read late, so deep IR offsets don't need a
+    /// long output buffer
+    input_delay_blocks: usize,
+    /// Output position relative to the start of the triggering block
+    output_offset: usize,
+    /// IR partition spectra (half-spectrum)
+    spectra: Vec<Vec<Complex<f64>>>,
+    /// Frequency delay line, one input spectrum per partition
+    fdl: Vec<Vec<Complex<f64>>>,
+    /// FDL slot of the newest spectrum
+    fdl_pos: usize,
+    fft_forward: Arc<dyn RealToComplex<f64>>,
+    fft_inverse: Arc<dyn ComplexToReal<f64>>,
+    /// Time-domain scratch (2 * block_size)
+    time: Vec<f64>,
+    /// Spectrum accumulator
+    accum: Vec<Complex<f64>>,
+}
 
-                Partition::new(&padded, &fft_forward[size_idx])
+impl FftStage {
+    fn new(
+        ir: &[f64],
+        offset: usize,
+        block_size: usize,
+        count: usize,
+        latency: usize,
+        planner: &mut RealFftPlanner<f64>,
+    ) -> Self {
+        let fft_size = block_size * 2;
+        let fft_forward = planner.plan_fft_forward(fft_size);
+        let fft_inverse = planner.plan_fft_inverse(fft_size);
+
+        let spectra = (0..count)
+            .map(|p| {
+                let start = (offset + p * block_size).min(ir.len());
+                let end = (start + block_size).min(ir.len());
+                let mut padded = vec![0.0; fft_size];
+                padded[..end - start].copy_from_slice(&ir[start..end]);
+
+                let mut spectrum = vec![Complex::new(0.0, 0.0); block_size + 1];
+                fft_forward.process(&mut padded, &mut spectrum).ok();
+                spectrum
             })
             .collect();
 
-        // Create FDLs for each unique partition size
-        let fdls: Vec<Vec<Vec<Complex<f64>>>> = unique_sizes
-            .iter()
-            .map(|&size| {
-                // Count how many partitions of this size
-                let count = scheme.sizes.iter().filter(|&&s| s == size).count();
-                // Create FDL with enough slots
-                (0..count)
-                    .map(|_| vec![Complex::new(0.0, 0.0); size + 1])
-                    .collect()
-            })
-            .collect();
-
-        let fdl_positions = vec![0; unique_sizes.len()];
-
-        // Input and overlap buffers sized for max partition
-        let max_size = scheme
-            .sizes
-            .iter()
-            .max()
-            .copied()
-            .unwrap_or(DEFAULT_PARTITION_SIZE);
-
-        // Extract first partition for direct convolution (zero latency)
-        let first_size = scheme.min_size();
-        let direct_ir: Vec<f64> = ir.iter().take(first_size).copied().collect();
-        let direct_history = vec![0.0; first_size];
-
-        // Create overlap buffers per unique partition size
-        let overlap_buffers: Vec<Vec<f64>> =
-            unique_sizes.iter().map(|&size| vec![0.0; size]).collect();
+        // Output for the block ending at t covers [t - B + offset, t + offset);
+        // reading input q blocks late pulls that back to within two blocks of t
+        debug_assert!(offset + latency >= block_size);
+        let input_delay_blocks = (offset + latency).saturating_sub(block_size) / block_size;
 
         Self {
-            partitions,
-            direct_ir,
-            direct_history,
-            input_buffer: vec![0.0; max_size * 2],
-            fdls,
-            overlap_buffers,
+            block_size,
+            input_delay_blocks,
+            output_offset: offset - input_delay_blocks * block_size,
+            spectra,
+            fdl: vec![vec![Complex::new(0.0, 0.0); block_size + 1]; count],
+            fdl_pos: 0,
             fft_forward,
             fft_inverse,
-            scheme,
-            buffer_pos: 0,
-            fdl_positions,
-            block_counter: 0,
-            unique_sizes,
+            time: vec![0.0; fft_size],
+            accum: vec![Complex::new(0.0, 0.0); block_size + 1],
         }
     }
 
-    fn process_block(&mut self, input: &[f64], output: &mut [f64]) {
-        let block_size = self.scheme.min_size();
+    /// Input samples this stage needs to look back from the block end
+    fn history_span(&self) -> usize {
+        (self.input_delay_blocks + 2) * self.block_size
+    }
 
-        // Copy input to buffer
-        for (i, &sample) in input.iter().take(block_size).enumerate() {
-            self.input_buffer[self.buffer_pos + i] = sample;
+    /// Convolve the block ending at `end`; the valid output is
+    /// `time[block_size..]` (unnormalized)
+    fn process(&mut self, history: &[f64], end: usize) {
+        let len = history.len();
+        let start = (end + len - self.history_span()) % len;
+        for (i, t) in self.time.iter_mut().enumerate() {
+            *t = history[(start + i) % len];
         }
 
-        // Process each partition size
-        self.process_partitions(output, block_size);
+        let count = self.fdl.len();
+        self.fft_forward
+            .process(&mut self.time, &mut self.fdl[self.fdl_pos])
+            .ok();
 
-        self.buffer_pos = (self.buffer_pos + block_size) % self.input_buffer.len();
-        self.block_counter += 1;
+        self.accum.fill(Complex::new(0.0, 0.0));
+        for (p, spectrum) in self.spectra.iter().enumerate() {
+            let input = &self.fdl[(self.fdl_pos + count - p) % count];
+            for ((acc, x), h) in self.accum.iter_mut().zip(input).zip(spectrum) {
+                *acc += x * h;
+            }
+        }
+        self.fdl_pos = (self.fdl_pos + 1) % count;
+
+        // Real signal: DC and Nyquist bins are real
+        self.accum[0].im = 0.0;
+        self.accum[self.block_size].im = 0.0;
+        self.fft_inverse
+            .process(&mut self.accum, &mut self.time)
+            .ok();
     }
 
-    fn process_partitions(&mut self, output: &mut [f64], block_size: usize) {
-        output.fill(0.0);
+    fn reset(&mut self) {
+        for slot in &mut self.fdl {
+            slot.fill(Complex::new(0.0, 0.0));
+        }
+        self.fdl_pos = 0;
+    }
+}
 
-        // ═══════════════════════════════════════════════════════════════════════════════
-        // STAGE 1: DIRECT CONVOLUTION (ZERO LATENCY)
-        // ═══════════════════════════════════════════════════════════════════════════════
-        // Process first partition in time domain for instant response
+// ============ Convolution Channel ============
 
-        if !self.direct_ir.is_empty() && block_size <= self.direct_ir.len() {
-            for (out_idx, out_sample) in output.iter_mut().enumerate().take(block_size) {
-                let input_sample = self.input_buffer[self.buffer_pos + out_idx];
+/// Single channel convolution processor
+///
+/// The first partition runs as a direct FIR, so the head of the IR needs no
+/// FFT block of its own; later partitions run as FFT stages scheduled on
+/// their own block boundaries. Latency is the first partition size.
+struct ConvolutionChannel {
+    /// First partition, convolved in the time domain
+    head: DirectConvolver,
+    /// FFT stages for the rest of the IR
+    stages: Vec<FftStage>,
+    /// Input history (ring, indexed by absolute sample time)
+    history: Vec<f64>,
+    /// Output accumulator (ring, indexed by absolute sample time)
+    output_ring: Vec<f64>,
+    /// Samples processed so far
+    clock: usize,
+    /// Processing block size (first partition size)
+    block_size: usize,
+}
 
-                // Update history (circular buffer)
-                self.direct_history.rotate_right(1);
-                self.direct_history[0] = input_sample;
+impl ConvolutionChannel {
+    fn new(ir: &[f64], scheme: &PartitionScheme) -> Self {
+        let block_size = scheme.min_size();
+        let mut planner = RealFftPlanner::<f64>::new();
 
-                // Direct convolution: sum of IR * input history
-                let mut sum = 0.0;
-                for (ir_sample, hist_sample) in self.direct_ir.iter().zip(&self.direct_history) {
-                    sum += ir_sample * hist_sample;
-                }
-                *out_sample += sum;
-            }
+        let mut head_ir = vec![0.0; block_size];
+        let head_len = ir.len().min(block_size);
+        head_ir[..head_len].copy_from_slice(&ir[..head_len]);
+
+        // Group consecutive equal-size partitions into stages
+        let mut stages = Vec::new();
+        let mut i = 1;
+        while i < scheme.sizes.len() {
+            let size = scheme.sizes[i];
+            let count = scheme.sizes[i..].iter().take_while(|&&s| s == size).count();
+            stages.push(FftStage::new(
+                ir,
+                scheme.offsets[i],
+                size,
+                count,
+                block_size,
+                &mut planner,
+            ));
+            i += count;
         }
 
-        // ═══════════════════════════════════════════════════════════════════════════════
-        // STAGE 2+: FFT CONVOLUTION (LATER PARTITIONS)
-        // ═══════════════════════════════════════════════════════════════════════════════
-        // Process remaining partitions in frequency domain with non-uniform scheduling
-
-        // Skip first partition (already processed via direct convolution)
-        for partition in self.partitions.iter().skip(1) {
-            let partition_size = partition.size;
-
-            // Find which unique size this partition belongs to
-            let size_idx = self
-                .unique_sizes
-                .iter()
-                .position(|&s| s == partition_size)
-                .unwrap_or(0);
-
-            // Determine if this partition should be processed this block
-            // Larger partitions are triggered less frequently (non-uniform scheduling)
-            let trigger_interval = partition_size / block_size;
-            if !self.block_counter.is_multiple_of(trigger_interval) {
-                continue; // Skip this partition this block
-            }
-
-            let fft_size = partition_size * 2;
-
-            // FFT the input block
-            let mut input_padded = vec![0.0; fft_size];
-            let copy_len = block_size.min(partition_size);
-            let start_pos = self.buffer_pos;
-            let end_pos = start_pos + copy_len;
+        let history_len = stages
+            .iter()
+            .map(FftStage::history_span)
+            .max()
+            .unwrap_or(0)
+            .max(block_size);
+        let max_size = scheme.sizes.iter().max().copied().unwrap_or(block_size);
 
-            if end_pos <= self.input_buffer.len() {
-                input_padded[..copy_len].copy_from_slice(&self.input_buffer[start_pos..end_pos]);
-            }
+        Self {
+            head: DirectConvolver::new(&head_ir),
+            stages,
+            history: vec![0.0; history_len],
+            output_ring: vec![0.0; max_size * 2 + block_size],
+            clock: 0,
+            block_size,
+        }
+    }
 
-            let mut input_spectrum = vec![Complex::new(0.0, 0.0); fft_size / 2 + 1];
-            if let Some(fft) = self.fft_forward.get(size_idx) {
-                fft.process(&mut input_padded, &mut input_spectrum).ok();
-            }
+    fn process_block(&mut self, input: &[f64], output: &mut [f64]) {
+        let history_len = self.history.len();
+        let ring_len = self.output_ring.len();
+        let start = self.clock;
+        let end = start + self.block_size;
+
+        for (i, &sample) in input.iter().take(self.block_size).enumerate() {
+            self.history[(start + i) % history_len] = sample;
+            self.output_ring[(start + i) % ring_len] += self.head.process_sample(sample);
+        }
 
-            // Complex multiply with IR spectrum
-            let mut result_spectrum: Vec<Complex<f64>> = input_spectrum
-                .iter()
-                .zip(&partition.spectrum)
-                .map(|(a, b)| a * b)
-                .collect();
-
-            // IFFT
-            let mut result_time = vec![0.0; fft_size];
-            if let Some(ifft) = self.fft_inverse.get(size_idx) {
-                ifft.process(&mut result_spectrum, &mut result_time).ok();
+        for stage in &mut self.stages {
+            if !end.is_multiple_of(stage.block_size) {
+                continue;
             }
+            stage.process(&self.history, end);
 
-            // Normalize and overlap-add
-            let norm = 1.0 / fft_size as f64;
-            for (i, &sample) in result_time.iter().take(block_size).enumerate() {
-                if let Some(overlap) = self.overlap_buffers.get(size_idx) {
-                    output[i] += sample * norm + overlap.get(i).copied().unwrap_or(0.0);
-                }
+            let norm = 1.0 / (stage.block_size * 2) as f64;
+            let base = end - stage.block_size + stage.output_offset;
+            for (i, &sample) in stage.time[stage.block_size..].iter().enumerate() {
+                self.output_ring[(base + i) % ring_len] += sample * norm;
             }
+        }
 
-            // Save overlap for next block
-            if let Some(overlap) = self.overlap_buffers.get_mut(size_idx) {
-                for i in 0..partition_size.min(overlap.len()) {
-                    overlap[i] = if i + block_size < fft_size {
-                        result_time[i + block_size] * norm
-                    } else {
-                        0.0
-                    };
-                }
-            }
+        for (i, out) in output.iter_mut().take(self.block_size).enumerate() {
+            let idx = (start + i) % ring_len;
+            *out = self.output_ring[idx];
+            self.output_ring[idx] = 0.0;
         }
+        self.clock = end;
     }
 
     fn reset(&mut self) {
-        self.input_buffer.fill(0.0);
-        self.direct_history.fill(0.0);
-        for overlap in &mut self.overlap_buffers {
-            overlap.fill(0.0);
-        }
-        for fdl in &mut self.fdls {
-            for slot in fdl {
-                slot.fill(Complex::new(0.0, 0.0));
-            }
+        self.head.reset();
+        for stage in &mut self.stages {
+            stage.reset();
         }
-        self.buffer_pos = 0;
-        self.fdl_positions.fill(0);
-        self.block_counter = 0;
+        self.history.fill(0.0);
+        self.output_ring.fill(0.0);
+        self.clock = 0;
     }
 }
 
@@ -396,6 +379,12 @@ pub struct ProfessionalConvolution {
     mode: IrMode,
     /// Partition scheme
     scheme: PartitionScheme,
+    /// First partition size (sets latency)
+    first_partition: usize,
+    /// Size ratio between consecutive partitions
+    partition_growth: usize,
+    /// Loaded IRs (LL, RR, LR, RL; one IR in mono mode)
+    irs: Vec<Vec<f64>>,
     /// Dry/wet mix
     dry_wet: f64,
     /// Predelay in samples
@@ -434,6 +423,9 @@ impl ProfessionalConvolution {
             cross_rl: None,
             mode: IrMode::MonoToStereo,
             scheme: PartitionScheme::uniform(0, DEFAULT_PARTITION_SIZE),
+            first_partition: MIN_PARTITION_SIZE,
+            partition_growth: DEFAULT_PARTITION_GROWTH,
+            irs: Vec::new(),
             dry_wet: 0.5,
             predelay_samples: 0,
             predelay_l: vec![0.0; max_predelay],
@@ -453,50 +445,63 @@ impl ProfessionalConvolution {
 
     /// Load mono IR (applied to both channels)
     pub fn load_ir_mono(&mut self, ir: &[f64]) {
-        self.scheme = PartitionScheme::non_uniform(ir.len(), MIN_PARTITION_SIZE);
-        self.block_size = self.scheme.min_size();
-
-        self.left = Some(ConvolutionChannel::new(ir, self.scheme.clone()));
-        self.right = Some(ConvolutionChannel::new(ir, self.scheme.clone()));
-        self.cross_lr = None;
-        self.cross_rl = None;
-
+        self.irs = vec![ir.to_vec()];
         self.mode = IrMode::MonoToStereo;
-        self.resize_buffers();
-        self.ir_loaded = true;
+        self.build_channels();
     }
 
     /// Load true stereo IR (L->L, R->R)
     pub fn load_ir_stereo(&mut self, left: &[f64], right: &[f64]) {
-        let ir_len = left.len().max(right.len());
-        self.scheme = PartitionScheme::non_uniform(ir_len, MIN_PARTITION_SIZE);
-        self.block_size = self.scheme.min_size();
-
-        self.left = Some(ConvolutionChannel::new(left, self.scheme.clone()));
-        self.right = Some(ConvolutionChannel::new(right, self.scheme.clone()));
-        self.cross_lr = None;
-        self.cross_rl = None;
-
+        self.irs = vec![left.to_vec(), right.to_vec()];
         self.mode = IrMode::TrueStereo;
-        self.resize_buffers();
-        self.ir_loaded = true;
+        self.build_channels();
     }
 
     /// Load full stereo matrix IR
     pub fn load_ir_matrix(&mut self, ll: &[f64], lr: &[f64], rl: &[f64], rr: &[f64]) {
-        let ir_len = [ll.len(), lr.len(), rl.len(), rr.len()]
-            .into_iter()
-            .max()
-            .unwrap_or(0);
-        self.scheme = PartitionScheme::non_uniform(ir_len, MIN_PARTITION_SIZE);
+        self.irs = vec![ll.to_vec(), rr.to_vec(), lr.to_vec(), rl.to_vec()];
+        self.mode = IrMode::StereoMatrix;
+        self.build_channels();
+    }
+
+    /// Set the partition scheme (latency vs CPU)
+    ///
+    /// `first_size` (64-8192 samples) is the input latency; later partitions
+    /// grow by `growth` (1-8, 1 = uniform) for efficiency. A loaded IR is
+    /// re-partitioned immediately.
+    pub fn set_partition_scheme(&mut self, first_size: usize, growth: usize) {
+        self.first_partition = first_size.clamp(MIN_PARTITION_SIZE, MAX_PARTITION_SIZE);
+        self.partition_growth = growth.clamp(1, MAX_PARTITION_GROWTH);
+        if self.ir_loaded {
+            self.build_channels();
+        }
+    }
+
+    /// Current partition scheme
+    pub fn partition_scheme(&self) -> &PartitionScheme {
+        &self.scheme
+    }
+
+    /// Input latency in samples (first partition size, excluding predelay)
+    pub fn input_latency(&self) -> usize {
+        self.block_size
+    }
+
+    /// Partition the loaded IRs and rebuild the channel processors
+    fn build_channels(&mut self) {
+        let ir_len = self.irs.iter().map(Vec::len).max().unwrap_or(0);
+        self.scheme =
+            PartitionScheme::with_growth(ir_len, self.first_partition, self.partition_growth);
         self.block_size = self.scheme.min_size();
 
-        self.left = Some(ConvolutionChannel::new(ll, self.scheme.clone()));
-        self.right = Some(ConvolutionChannel::new(rr, self.scheme.clone()));
-        self.cross_lr = Some(ConvolutionChannel::new(lr, self.scheme.clone()));
-        self.cross_rl = Some(ConvolutionChannel::new(rl, self.scheme.clone()));
+        let channel = |ir: Option<&Vec<f64>>, scheme: &PartitionScheme| {
+            ir.map(|ir| ConvolutionChannel::new(ir, scheme))
+        };
+        self.left = channel(self.irs.first(), &self.scheme);
+        self.right = channel(self.irs.get(1).or(self.irs.first()), &self.scheme);
+        self.cross_lr = channel(self.irs.get(2), &self.scheme);
+        self.cross_rl = channel(self.irs.get(3), &self.scheme);
 
-        self.mode = IrMode::StereoMatrix;
         self.resize_buffers();
         self.ir_loaded = true;
     }
@@ -532,14 +537,14 @@ impl ProfessionalConvolution {
         let mut delayed_r = vec![0.0; self.block_size];
 
         for i in 0..self.block_size {
-            let read_pos = (self.predelay_pos + self.predelay_l.len() - self.predelay_samples + i)
-                % self.predelay_l.len();
-            delayed_l[i] = self.predelay_l[read_pos];
-            delayed_r[i] = self.predelay_r[read_pos];
-
             let write_pos = (self.predelay_pos + i) % self.predelay_l.len();
             self.predelay_l[write_pos] = self.input_block_l[i];
             self.predelay_r[write_pos] = self.input_block_r[i];
+
+            let read_pos =
+                (write_pos + self.predelay_l.len() - self.predelay_samples) % self.predelay_l.len();
+            delayed_l[i] = self.predelay_l[read_pos];
+            delayed_r[i] = self.predelay_r[read_pos];
         }
         self.predelay_pos = (self.predelay_pos + self.block_size) % self.predelay_l.len();
 
@@ -688,6 +693,79 @@ mod tests {
         assert!(!scheme.sizes.is_empty());
     }
 
+    #[test]
+    fn test_partition_scheme_with_growth() {
+        let scheme = PartitionScheme::with_growth(10000, 128, 2);
+        assert_eq!(scheme.min_size(), 128);
+        assert_eq!(&scheme.sizes()[..4], &[128, 256, 512, 1024]);
+        assert!(scheme.offsets.last().unwrap() + scheme.sizes.last().unwrap() >= 10000);
+
+        // Every partition can arrive on time
+        for (&size, &offset) in scheme.sizes.iter().zip(&scheme.offsets).skip(1) {
+            assert!(offset + 128 >= size, "{} at {}", size, offset);
+        }
+
+        let uniform = PartitionScheme::with_growth(1000, 64, 1);
+        assert!(uniform.sizes().iter().all(|&s| s == 64));
+    }
+
+    #[test]
+    fn test_partition_scheme_latency_matches_reference() {
+        let noise = |len: usize, mut state: u64| -> Vec<f64> {
+            (0..len)
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    (state >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+                })
+                .collect()
+        };
+        let ir: Vec<f64> = noise(5000, 1)
+            .iter()
+            .enumerate()
+            .map(|(i, s)| s * (-(i as f64) / 1500.0).exp())
+            .collect();
+        let input = noise(12000, 2);
+        let reference: Vec<f64> = (0..input.len())
+            .map(|n| {
+                ir.iter()
+                    .take(n + 1)
+                    .enumerate()
+                    .map(|(k, h)| h * input[n - k])
+                    .sum()
+            })
+            .collect();
+
+        for (first, growth) in [(64, 1), (128, 2), (256, 4)] {
+            let mut conv = ProfessionalConvolution::new(48000.0);
+            conv.load_ir_mono(&ir);
+            conv.set_dry_wet(1.0);
+            conv.set_partition_scheme(first, growth);
+
+            assert_eq!(conv.input_latency(), first);
+            assert_eq!(conv.latency(), first);
+
+            for (n, &x) in input.iter().enumerate() {
+                let (l, r) = conv.process_sample(x, x);
+                let expected = if n >= first {
+                    reference[n - first]
+                } else {
+                    0.0
+                };
+                assert!(
+                    (l - expected).abs() < 1e-9 && (r - expected).abs() < 1e-9,
+                    "first {} growth {} sample {}: {} vs {}",
+                    first,
+                    growth,
+                    n,
+                    l,
+                    expected
+                );
+            }
+        }
+    }
+
     #[test]
     fn test_convolution_without_ir() {
         let mut conv = ProfessionalConvolution::new(48000.0);
@@ -312,13 +312,13 @@ impl DirectConvolver {
         let mut output = 0.0;
         let len = self.coefficients.len();
 
-        // Optimized loop with split to avoid modulo in inner loop
-        let first_part = len - self.position;
-        for i in 0..first_part {
-            output += self.coefficients[i] * self.delay_line[self.position + i];
+        // Optimized loop with split to avoid modulo in inner loop:
+        // coefficient i pairs with the input i samples back
+        for i in 0..=self.position {
+            output += self.coefficients[i] * self.delay_line[self.position - i];
         }
-        for i in 0..self.position {
-            output += self.coefficients[first_part + i] * self.delay_line[i];
+        for i in self.position + 1..len {
+            output += self.coefficients[i] * self.delay_line[len + self.position - i];
         }
 
         self.position = (self.position + 1) % len;
@@ -378,6 +378,11 @@ mod tests {
 
         let output = convolver.process_sample(0.5);
         assert!((output - 0.5).abs() < 0.001);
+
+        // Asymmetric IR: impulse response comes out in order
+        let mut convolver = DirectConvolver::new(&[1.0, 0.5, 0.25]);
+        let output = convolver.process(&[1.0, 0.0, 0.0, 0.0]);
+        assert_eq!(output, vec![1.0, 0.5, 0.25, 0.0]);
     }
 
     #[test]
@@ -10,18 +10,80 @@ use rf_core::Sample;
 use std::f64::consts::PI;
 
 use crate::biquad::BiquadTDF2;
-use crate::{MonoProcessor, Processor, ProcessorConfig, StereoProcessor};
+use crate::{MonoProcessor, ProcessContext, Processor, ProcessorConfig, StereoProcessor};
+
+/// Time the read pointer takes to glide to a new synced length after a
+/// tempo change (ms). Short enough to follow tempo ramps, long enough that
+/// the resulting pitch bend is a gentle tape-style slew rather than a click.
+const TEMPO_RAMP_MS: f64 = 200.0;
+
+/// Delay time, either absolute or a musical division of the host tempo
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DelayTime {
+    /// Absolute time in milliseconds
+    Ms(f64),
+    /// Tempo-synced note: `division` is the note denominator (4 = 1/4,
+    /// 8 = 1/8, ...), `dotted` adds half its length, `triplet` plays
+    /// three in the space of two
+    Sync {
+        division: u32,
+        dotted: bool,
+        triplet: bool,
+    },
+}
+
+impl DelayTime {
+    /// Length in quarter-note beats (`None` for absolute times)
+    pub fn beats(self) -> Option<f64> {
+        match self {
+            DelayTime::Ms(_) => None,
+            DelayTime::Sync {
+                division,
+                dotted,
+                triplet,
+            } => {
+                let mut beats = 4.0 / division.max(1) as f64;
+                if dotted {
+                    beats *= 1.5;
+                }
+                if triplet {
+                    beats *= 2.0 / 3.0;
+                }
+                Some(beats)
+            }
+        }
+    }
+
+    /// Length in milliseconds at `bpm`
+    pub fn to_ms(self, bpm: f64) -> f64 {
+        match self {
+            DelayTime::Ms(ms) => ms,
+            DelayTime::Sync { .. } => {
+                60000.0 / bpm.clamp(20.0, 999.0) * self.beats().unwrap_or(1.0)
+            }
+        }
+    }
+}
 
 /// Simple mono delay with feedback and filtering
 #[derive(Debug, Clone)]
 pub struct Delay {
     buffer: Vec<Sample>,
     write_pos: usize,
-    delay_samples: usize,
+    /// Current (possibly fractional, ramping) read distance in samples
+    delay_samples: f64,
+    /// Read distance the ramp is heading for
+    target_delay_samples: f64,
+    /// Per-sample ramp increment (0 when settled)
+    delay_step: f64,
     max_delay_samples: usize,
     feedback: f64,
     dry_wet: f64,
 
+    // Tempo sync
+    delay_time: DelayTime,
+    tempo: f64,
+
     // Feedback filtering
     highpass: BiquadTDF2,
     lowpass: BiquadTDF2,
@@ -37,10 +99,14 @@ impl Delay {
         let mut delay = Self {
             buffer: vec![0.0; max_delay_samples],
             write_pos: 0,
-            delay_samples: (500.0 * 0.001 * sample_rate) as usize, // Default 500ms
+            delay_samples: 0.0,
+            target_delay_samples: 0.0,
+            delay_step: 0.0,
             max_delay_samples,
             feedback: 0.5,
             dry_wet: 0.5,
+            delay_time: DelayTime::Ms(500.0), // Default 500ms
+            tempo: ProcessContext::default().tempo,
             highpass: BiquadTDF2::new(sample_rate),
             lowpass: BiquadTDF2::new(sample_rate),
             filter_enabled: true,
@@ -49,17 +115,45 @@ impl Delay {
 
         delay.highpass.set_highpass(80.0, 0.707);
         delay.lowpass.set_lowpass(8000.0, 0.707);
+        delay.set_delay_time(delay.delay_time);
 
         delay
     }
 
     pub fn set_delay_ms(&mut self, ms: f64) {
-        let samples = (ms * 0.001 * self.sample_rate) as usize;
-        self.delay_samples = samples.min(self.max_delay_samples - 1);
+        self.set_delay_time(DelayTime::Ms(ms));
     }
 
     pub fn set_delay_samples(&mut self, samples: usize) {
-        self.delay_samples = samples.min(self.max_delay_samples - 1);
+        self.delay_time = DelayTime::Ms(samples as f64 * 1000.0 / self.sample_rate);
+        self.jump_to(samples as f64);
+    }
+
+    /// Set delay time (jumps immediately)
+    pub fn set_delay_time(&mut self, time: DelayTime) {
+        self.delay_time = time;
+        self.jump_to(self.time_to_samples(time));
+    }
+
+    pub fn delay_time(&self) -> DelayTime {
+        self.delay_time
+    }
+
+    /// Current read distance in samples (fractional while ramping)
+    pub fn delay_samples(&self) -> f64 {
+        self.delay_samples
+    }
+
+    /// Follow host transport; a synced delay glides to the new length over
+    /// `TEMPO_RAMP_MS` instead of jumping
+    pub fn set_context(&mut self, context: &ProcessContext) {
+        if context.tempo == self.tempo {
+            return;
+        }
+        self.tempo = context.tempo;
+        if matches!(self.delay_time, DelayTime::Sync { .. }) {
+            self.ramp_to(self.time_to_samples(self.delay_time));
+        }
     }
 
     pub fn set_feedback(&mut self, feedback: f64) {
@@ -82,10 +176,54 @@ impl Delay {
         self.filter_enabled = enabled;
     }
 
+    fn time_to_samples(&self, time: DelayTime) -> f64 {
+        (time.to_ms(self.tempo) * 0.001 * self.sample_rate).round()
+    }
+
+    fn clamp_delay(&self, samples: f64) -> f64 {
+        samples.clamp(0.0, (self.max_delay_samples - 1) as f64)
+    }
+
+    fn jump_to(&mut self, samples: f64) {
+        self.delay_samples = self.clamp_delay(samples);
+        self.target_delay_samples = self.delay_samples;
+        self.delay_step = 0.0;
+    }
+
+    fn ramp_to(&mut self, samples: f64) {
+        self.target_delay_samples = self.clamp_delay(samples);
+        let ramp_samples = (TEMPO_RAMP_MS * 0.001 * self.sample_rate).max(1.0);
+        self.delay_step = (self.target_delay_samples - self.delay_samples) / ramp_samples;
+    }
+
+    #[inline]
+    fn advance_ramp(&mut self) {
+        if self.delay_step != 0.0 {
+            self.delay_samples += self.delay_step;
+            let arrived = if self.delay_step > 0.0 {
+                self.delay_samples >= self.target_delay_samples
+            } else {
+                self.delay_samples <= self.target_delay_samples
+            };
+            if arrived {
+                self.delay_samples = self.target_delay_samples;
+                self.delay_step = 0.0;
+            }
+        }
+    }
+
     fn read_delayed(&self) -> Sample {
-        let read_pos =
-            (self.write_pos + self.max_delay_samples - self.delay_samples) % self.max_delay_samples;
-        self.buffer[read_pos]
+        let whole = self.delay_samples.floor();
+        let frac = self.delay_samples - whole;
+        let whole = whole as usize;
+        let read_pos = (self.write_pos + self.max_delay_samples - whole) % self.max_delay_samples;
+        let current = self.buffer[read_pos];
+        if frac == 0.0 {
+            return current;
+        }
+        // Linear interpolation towards the next-older sample
+        let older = self.buffer[(read_pos + self.max_delay_samples - 1) % self.max_delay_samples];
+        current + (older - current) * frac
     }
 }
 
@@ -100,6 +238,7 @@ impl Processor for Delay {
 
 impl MonoProcessor for Delay {
     fn process_sample(&mut self, input: Sample) -> Sample {
+        self.advance_ramp();
         let delayed = self.read_delayed();
 
         // Apply filtering to feedback path
@@ -124,8 +263,8 @@ impl ProcessorConfig for Delay {
         let ratio = sample_rate / self.sample_rate;
         self.sample_rate = sample_rate;
         self.max_delay_samples = (self.max_delay_samples as f64 * ratio) as usize;
-        self.delay_samples = (self.delay_samples as f64 * ratio) as usize;
         self.buffer = vec![0.0; self.max_delay_samples];
+        self.set_delay_time(self.delay_time);
         self.highpass.set_sample_rate(sample_rate);
         self.lowpass.set_sample_rate(sample_rate);
     }
@@ -2346,4 +2485,70 @@ mod tests {
         }
         assert!(any_different);
     }
+
+    #[test]
+    fn test_sync_quarter_at_120_bpm() {
+        let mut delay = Delay::new(48000.0, 2000.0);
+        delay.set_context(&ProcessContext {
+            tempo: 120.0,
+            ..Default::default()
+        });
+        delay.set_delay_time(DelayTime::Sync {
+            division: 4,
+            dotted: false,
+            triplet: false,
+        });
+        // 1/4 at 120 BPM = 500ms
+        assert_eq!(delay.delay_samples(), 24000.0);
+
+        let dotted_eighth = DelayTime::Sync {
+            division: 8,
+            dotted: true,
+            triplet: false,
+        };
+        assert!((dotted_eighth.to_ms(120.0) - 375.0).abs() < 1e-9);
+        let eighth_triplet = DelayTime::Sync {
+            division: 8,
+            dotted: false,
+            triplet: true,
+        };
+        assert!((eighth_triplet.to_ms(120.0) - 500.0 / 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sync_tempo_change_ramps() {
+        let mut delay = Delay::new(48000.0, 2000.0);
+        delay.set_delay_time(DelayTime::Sync {
+            division: 4,
+            dotted: false,
+            triplet: false,
+        });
+        assert_eq!(delay.delay_samples(), 24000.0);
+
+        // 120 → 100 BPM: 1/4 becomes 600ms, reached by gliding, not jumping
+        delay.set_context(&ProcessContext {
+            tempo: 100.0,
+            ..Default::default()
+        });
+        assert_eq!(delay.delay_samples(), 24000.0);
+
+        let ramp_samples = (TEMPO_RAMP_MS * 0.001 * 48000.0) as usize;
+        let max_step = 4800.0 / ramp_samples as f64 + 1e-9;
+        let mut previous = delay.delay_samples();
+        for _ in 0..ramp_samples + 10 {
+            delay.process_sample(0.0);
+            let current = delay.delay_samples();
+            assert!(current >= previous && current - previous <= max_step);
+            previous = current;
+        }
+        assert_eq!(delay.delay_samples(), 28800.0);
+
+        // Absolute times ignore tempo
+        delay.set_delay_ms(100.0);
+        delay.set_context(&ProcessContext {
+            tempo: 140.0,
+            ..Default::default()
+        });
+        assert_eq!(delay.delay_samples(), 4800.0);
+    }
 }
@@ -9,6 +9,7 @@
 //! - Lookup tables for fast dB/gain conversions
 
 use rf_core::Sample;
+use std::collections::VecDeque;
 
 #[cfg(target_arch = "x86_64")]
 use std::simd::prelude::SimdFloat;
@@ -1619,6 +1620,10 @@ impl StereoCompressor {
         self.link = link.clamp(0.0, 1.0);
     }
 
+    pub fn link(&self) -> f64 {
+        self.link
+    }
+
     pub fn left(&mut self) -> &mut Compressor {
         &mut self.left
     }
@@ -2870,52 +2875,158 @@ impl ProcessorConfig for TruePeakLimiter {
     }
 }
 
-/// Simple peak limiter (for compatibility)
+/// Fixed release for transients in auto-release mode
+const LIMITER_AUTO_FAST_RELEASE_MS: f64 = 10.0;
+/// How quickly sustained gain reduction builds in auto-release mode
+const LIMITER_AUTO_SLOW_ATTACK_MS: f64 = 80.0;
+
+/// Lookahead brickwall peak limiter
+///
+/// The required gain is the minimum over the lookahead window, smoothed by a
+/// moving average of the same length, so reduction ramps in ahead of each
+/// peak and no delayed sample exceeds the threshold.
+///
+/// With `release_auto`, a dual-release detector runs a fast envelope
+/// (transients recover in ~10 ms) against a slow one that follows sustained
+/// overload and releases over `release_ms`; the deeper of the two is applied.
 #[derive(Debug, Clone)]
 pub struct Limiter {
     threshold_db: f64,
-    release_coeff: f64,
+    lookahead_ms: f64,
+    release_ms: f64,
+    release_auto: bool,
+    /// Release coefficient of the fast (or only, in manual mode) envelope
+    fast_release_coeff: f64,
+    slow_attack_coeff: f64,
+    slow_release_coeff: f64,
+    /// Fast envelope (instant attack)
+    fast_env: f64,
+    /// Slow envelope (sustained reduction)
+    slow_env: f64,
+    /// Applied gain after smoothing
     gain: f64,
     lookahead_samples: usize,
+    /// Audio delay line (`lookahead_samples` long)
     lookahead_buffer: Vec<Sample>,
     buffer_pos: usize,
+    /// Sliding minimum of target gain: (sample index, gain)
+    min_window: VecDeque<(u64, f64)>,
+    /// Moving-average ring of envelope gain (`lookahead_samples + 1` long)
+    smooth_ring: Vec<f64>,
+    smooth_pos: usize,
+    smooth_sum: f64,
+    sample_index: u64,
     sample_rate: f64,
 }
 
 impl Limiter {
     pub fn new(sample_rate: f64) -> Self {
-        let lookahead_ms = 5.0;
-        let lookahead_samples = (lookahead_ms * 0.001 * sample_rate) as usize;
-
-        Self {
+        let mut limiter = Self {
             threshold_db: -0.3,
-            release_coeff: (-1.0 / (100.0 * 0.001 * sample_rate)).exp(),
+            lookahead_ms: 5.0,
+            release_ms: 100.0,
+            release_auto: false,
+            fast_release_coeff: 0.0,
+            slow_attack_coeff: 0.0,
+            slow_release_coeff: 0.0,
+            fast_env: 1.0,
+            slow_env: 1.0,
             gain: 1.0,
-            lookahead_samples,
-            lookahead_buffer: vec![0.0; lookahead_samples],
+            lookahead_samples: 0,
+            lookahead_buffer: Vec::new(),
             buffer_pos: 0,
+            min_window: VecDeque::new(),
+            smooth_ring: Vec::new(),
+            smooth_pos: 0,
+            smooth_sum: 0.0,
+            sample_index: 0,
             sample_rate,
-        }
+        };
+        limiter.update_coeffs();
+        limiter.allocate_lookahead();
+        limiter
     }
 
     pub fn set_threshold(&mut self, db: f64) {
         self.threshold_db = db;
     }
 
+    /// Release time in ms (slow release when `release_auto` is on)
     pub fn set_release(&mut self, ms: f64) {
-        self.release_coeff = (-1.0 / (ms * 0.001 * self.sample_rate)).exp();
+        self.release_ms = ms.clamp(1.0, 5000.0);
+        self.update_coeffs();
+    }
+
+    /// Enable program-dependent (dual) release
+    pub fn set_release_auto(&mut self, enabled: bool) {
+        self.release_auto = enabled;
+        self.update_coeffs();
+    }
+
+    /// Lookahead in ms (0-20); reallocates the delay line
+    pub fn set_lookahead(&mut self, ms: f64) {
+        self.lookahead_ms = ms.clamp(0.0, 20.0);
+        self.allocate_lookahead();
+    }
+
+    pub fn threshold_db(&self) -> f64 {
+        self.threshold_db
+    }
+
+    pub fn release_ms(&self) -> f64 {
+        self.release_ms
+    }
+
+    pub fn release_auto(&self) -> bool {
+        self.release_auto
+    }
+
+    pub fn lookahead_ms(&self) -> f64 {
+        self.lookahead_ms
+    }
+
+    /// Current gain reduction in dB (positive = reducing)
+    pub fn gain_reduction_db(&self) -> f64 {
+        -20.0 * self.gain.max(1e-10).log10()
     }
 
     fn threshold_linear(&self) -> f64 {
         db_to_linear_fast(self.threshold_db)
     }
+
+    fn update_coeffs(&mut self) {
+        let coeff = |ms: f64| (-1.0 / (ms * 0.001 * self.sample_rate)).exp();
+        self.fast_release_coeff = if self.release_auto {
+            coeff(LIMITER_AUTO_FAST_RELEASE_MS)
+        } else {
+            coeff(self.release_ms)
+        };
+        self.slow_attack_coeff = coeff(LIMITER_AUTO_SLOW_ATTACK_MS);
+        self.slow_release_coeff = coeff(self.release_ms);
+    }
+
+    fn allocate_lookahead(&mut self) {
+        self.lookahead_samples = (self.lookahead_ms * 0.001 * self.sample_rate).round() as usize;
+        let window = self.lookahead_samples + 1;
+        self.lookahead_buffer = vec![0.0; self.lookahead_samples];
+        self.min_window = VecDeque::with_capacity(window + 1);
+        self.smooth_ring = vec![1.0; window];
+        self.reset();
+    }
 }
 
 impl Processor for Limiter {
     fn reset(&mut self) {
+        self.fast_env = 1.0;
+        self.slow_env = 1.0;
         self.gain = 1.0;
         self.lookahead_buffer.fill(0.0);
         self.buffer_pos = 0;
+        self.min_window.clear();
+        self.smooth_ring.fill(1.0);
+        self.smooth_pos = 0;
+        self.smooth_sum = self.smooth_ring.len() as f64;
+        self.sample_index = 0;
     }
 
     fn latency(&self) -> usize {
@@ -2926,11 +3037,9 @@ impl Processor for Limiter {
 impl MonoProcessor for Limiter {
     #[inline(always)]
     fn process_sample(&mut self, input: Sample) -> Sample {
-        let delayed = self.lookahead_buffer[self.buffer_pos];
-        self.lookahead_buffer[self.buffer_pos] = input;
-        self.buffer_pos = (self.buffer_pos + 1) % self.lookahead_samples;
-
         let threshold = self.threshold_linear();
+        let window = self.smooth_ring.len() as u64;
+
         let abs_input = input.abs();
         let target_gain = if abs_input > threshold {
             threshold / abs_input
@@ -2938,23 +3047,70 @@ impl MonoProcessor for Limiter {
             1.0
         };
 
-        if target_gain < self.gain {
-            self.gain = target_gain;
-        } else {
-            self.gain = target_gain + self.release_coeff * (self.gain - target_gain);
+        // Sliding minimum over the lookahead window (monotonic deque)
+        let n = self.sample_index;
+        self.sample_index += 1;
+        while let Some(&(_, g)) = self.min_window.back()
+            && g >= target_gain
+        {
+            self.min_window.pop_back();
+        }
+        self.min_window.push_back((n, target_gain));
+        while let Some(&(i, _)) = self.min_window.front()
+            && i + window <= n
+        {
+            self.min_window.pop_front();
         }
+        let required = self.min_window.front().map_or(1.0, |&(_, g)| g);
+
+        // Release envelopes never rise above the required gain
+        self.fast_env = if required < self.fast_env {
+            required
+        } else {
+            required + self.fast_release_coeff * (self.fast_env - required)
+        };
+        let envelope = if self.release_auto {
+            let coeff = if required < self.slow_env {
+                self.slow_attack_coeff
+            } else {
+                self.slow_release_coeff
+            };
+            self.slow_env = required + coeff * (self.slow_env - required);
+            self.fast_env.min(self.slow_env)
+        } else {
+            self.fast_env
+        };
 
-        delayed * self.gain
+        // Moving average ramps the reduction in over the lookahead
+        self.smooth_sum += envelope - self.smooth_ring[self.smooth_pos];
+        self.smooth_ring[self.smooth_pos] = envelope;
+        self.smooth_pos += 1;
+        if self.smooth_pos == self.smooth_ring.len() {
+            self.smooth_pos = 0;
+            // Drop accumulated rounding error once per window
+            self.smooth_sum = self.smooth_ring.iter().sum();
+        }
+        self.gain = self.smooth_sum / self.smooth_ring.len() as f64;
+
+        let delayed = if self.lookahead_samples > 0 {
+            let delayed = self.lookahead_buffer[self.buffer_pos];
+            self.lookahead_buffer[self.buffer_pos] = input;
+            self.buffer_pos = (self.buffer_pos + 1) % self.lookahead_samples;
+            delayed
+        } else {
+            input
+        };
+
+        // Guard against rounding past the ceiling
+        (delayed * self.gain).clamp(-threshold, threshold)
     }
 }
 
 impl ProcessorConfig for Limiter {
     fn set_sample_rate(&mut self, sample_rate: f64) {
         self.sample_rate = sample_rate;
-        let lookahead_ms = 5.0;
-        self.lookahead_samples = (lookahead_ms * 0.001 * sample_rate) as usize;
-        self.lookahead_buffer = vec![0.0; self.lookahead_samples];
-        self.buffer_pos = 0;
+        self.update_coeffs();
+        self.allocate_lookahead();
     }
 }
 
@@ -4384,6 +4540,49 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_limiter_auto_release_transient_vs_sustain() {
+        let sample_rate = 48000.0;
+        let mut limiter = Limiter::new(sample_rate);
+        limiter.set_threshold(-6.0);
+        limiter.set_lookahead(5.0);
+        limiter.set_release(300.0);
+        limiter.set_release_auto(true);
+        assert_eq!(limiter.latency(), 240);
+
+        let ceiling = db_to_linear_fast(-6.0);
+        let sine = |i: usize, amp: f64| {
+            amp * (2.0 * std::f64::consts::PI * 1000.0 * i as f64 / sample_rate).sin()
+        };
+
+        // Loud section of `loud_ms`, then quiet; returns ms until GR < 1 dB
+        let mut recovery_ms = |loud_ms: usize| {
+            limiter.reset();
+            let loud = loud_ms * 48;
+            let end = loud + limiter.latency();
+            let mut recovered = None;
+            for i in 0..loud + 48000 {
+                let amp = if i < loud { 1.0 } else { 0.1 };
+                let out = limiter.process_sample(sine(i, amp));
+                assert!(out.abs() <= ceiling + 1e-9, "overshoot {} at {}", out, i);
+                if i > end && recovered.is_none() && limiter.gain_reduction_db() < 1.0 {
+                    recovered = Some((i - end) as f64 / 48.0);
+                }
+            }
+            recovered.expect("gain never recovered")
+        };
+
+        let transient = recovery_ms(2);
+        let sustain = recovery_ms(1000);
+        assert!(transient < 40.0, "transient recovery {} ms", transient);
+        assert!(
+            sustain > 4.0 * transient,
+            "sustain {} ms vs transient {} ms",
+            sustain,
+            transient
+        );
+    }
+
     #[test]
     fn test_gate_with_hold() {
         let mut gate = Gate::new(48000.0);
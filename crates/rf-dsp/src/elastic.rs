@@ -200,6 +200,25 @@ impl ElasticAudio {
         }
     }
 
+    /// Replace all warp markers with `(source, target)` pairs
+    ///
+    /// Pairs whose source or target does not increase are dropped so every
+    /// segment between markers keeps a positive length.
+    pub fn set_markers(&mut self, markers: &[(u64, u64)]) {
+        self.markers.clear();
+        let mut sorted = markers.to_vec();
+        sorted.sort_unstable();
+        let mut last: Option<(u64, u64)> = None;
+        for (source, target) in sorted {
+            if last.is_some_and(|(s, t)| source <= s || target <= t) {
+                continue;
+            }
+            self.markers.insert(source, WarpMarker::new(source, target));
+            last = Some((source, target));
+        }
+        self.recalculate_target_length();
+    }
+
     /// Add markers from transient detection
     pub fn add_markers_from_transients(&mut self, transients: &[(u64, f64)]) {
         for &(position, strength) in transients {
@@ -308,6 +327,87 @@ impl ElasticAudio {
         }
     }
 
+    /// Render with each inter-marker segment stretched by its own ratio
+    ///
+    /// Grains are centred on a grid that restarts at every marker, and each
+    /// output sample crossfades (raised cosine) between the two nearest grains.
+    /// A grain contributes alone at its centre, so every marked source sample
+    /// lands exactly at its target. Before the first marker the map is anchored
+    /// at the origin; after the last it follows the last marker's ratio.
+    pub fn render_warped(&self, source: &[f64]) -> Vec<f64> {
+        let target_len = self.target_length as usize;
+        let mut output = vec![0.0; target_len];
+        if source.is_empty() || target_len == 0 {
+            return output;
+        }
+
+        // (source, target) anchors including the implicit start and end
+        let mut anchors: Vec<(f64, f64)> = Vec::with_capacity(self.markers.len() + 2);
+        if self.markers.keys().next().is_none_or(|&s| s > 0) {
+            anchors.push((0.0, 0.0));
+        }
+        anchors.extend(
+            self.markers
+                .values()
+                .map(|m| (m.source_position as f64, m.target_position as f64)),
+        );
+        if let Some(&(s, t)) = anchors.last()
+            && t < target_len as f64
+        {
+            anchors.push((
+                self.source_length.max(s as u64 + 1) as f64,
+                target_len as f64,
+            ));
+        }
+
+        // Grain centres (target, source), exact at every anchor
+        let hop = (self.window.len() / self.config.algorithm.overlap()).max(64) as f64;
+        let mut grains: Vec<(i64, i64)> = Vec::new();
+        for pair in anchors.windows(2) {
+            let ((s0, t0), (s1, t1)) = (pair[0], pair[1]);
+            let steps = ((t1 - t0) / hop).round().max(1.0) as usize;
+            for j in 0..steps {
+                let a = j as f64 / steps as f64;
+                grains.push((
+                    (t0 + a * (t1 - t0)).round() as i64,
+                    (s0 + a * (s1 - s0)).round() as i64,
+                ));
+            }
+        }
+        if let Some(&(s, t)) = anchors.last() {
+            grains.push((t.round() as i64, s.round() as i64));
+        }
+        grains.dedup_by_key(|g| g.0);
+
+        let read = |index: i64| -> f64 {
+            usize::try_from(index)
+                .ok()
+                .and_then(|i| source.get(i))
+                .copied()
+                .unwrap_or(0.0)
+        };
+
+        let mut grain = 0;
+        for (t, out) in output.iter_mut().enumerate() {
+            let t = t as i64;
+            while grain + 1 < grains.len() && grains[grain + 1].0 <= t {
+                grain += 1;
+            }
+            let (c0, s0) = grains[grain];
+            let current = read(s0 + t - c0);
+            *out = match grains.get(grain + 1) {
+                Some(&(c1, s1)) if t > c0 => {
+                    let a = (t - c0) as f64 / (c1 - c0) as f64;
+                    let fade = 0.5 - 0.5 * (std::f64::consts::PI * a).cos();
+                    current * (1.0 - fade) + read(s1 + t - c1) * fade
+                }
+                _ => current,
+            };
+        }
+
+        output
+    }
+
     /// Simple slice-based processing (no stretching, just repositioning)
     fn process_slice(&self, source: &[f64], target_len: usize) -> Vec<f64> {
         let mut output = vec![0.0; target_len];
@@ -504,6 +604,35 @@ mod tests {
         assert!((output[0] - source[0]).abs() < 0.001);
     }
 
+    #[test]
+    fn test_warp_markers_local_ratios() {
+        let mut elastic = ElasticAudio::new(48000.0, 14400);
+        elastic.set_markers(&[(4800, 9600), (9600, 12000)]);
+
+        // 2x stretch before the first marker, 0.5x between the markers
+        assert!((elastic.ratio_at(2400) - 2.0).abs() < 1e-9);
+        assert!((elastic.ratio_at(7200) - 0.5).abs() < 1e-9);
+        assert_eq!(elastic.target_length(), 18000);
+
+        // A ramp makes each output sample name the source sample it came from
+        let source: Vec<f64> = (0..14400).map(|i| i as f64).collect();
+        let output = elastic.render_warped(&source);
+        assert_eq!(output.len(), 18000);
+        assert_eq!(output[0], 0.0);
+        assert_eq!(output[9600], 4800.0);
+        assert_eq!(output[12000], 9600.0);
+
+        // Source advances at each segment's own rate between grain centres
+        let first = output[6000] - output[2000];
+        let second = output[11600] - output[10000];
+        assert!((first / 4000.0 - 0.5).abs() < 0.05, "first slope {}", first);
+        assert!(
+            (second / 1600.0 - 2.0).abs() < 0.2,
+            "second slope {}",
+            second
+        );
+    }
+
     #[test]
     fn test_algorithm_settings() {
         assert_eq!(StretchAlgorithm::Rhythmic.window_size(), 256);
@@ -2131,6 +2131,20 @@ impl EqBand {
         (total_mag, total_phase)
     }
 
+    /// Current dynamic gain change in dB (negative = reduction, 0 when static)
+    ///
+    /// Takes the channel moving the most, for GR metering / curve display.
+    pub fn dynamic_gain_db(&self) -> f64 {
+        if !self.enabled || !self.dynamic.enabled {
+            return 0.0;
+        }
+        let gain_l = self.envelope_l.calculate_gain(&self.dynamic);
+        let gain_r = self.envelope_r.calculate_gain(&self.dynamic);
+        let db_l = 20.0 * gain_l.max(1e-10).log10();
+        let db_r = 20.0 * gain_r.max(1e-10).log10();
+        if db_l.abs() >= db_r.abs() { db_l } else { db_r }
+    }
+
     pub fn reset(&mut self) {
         for stage in &mut self.svf_stages_l {
             stage.reset();
@@ -2367,12 +2381,19 @@ pub struct SpectrumAnalyzer {
     smoothing: f64,
     /// Peak decay rate
     peak_decay: f64,
+    /// Peak fall per FFT frame (dB), set by `peak_hold_decay`
+    peak_fall_db: Option<f64>,
+    /// Display tilt (dB/octave around 1 kHz)
+    slope_db_per_oct: f64,
     /// FFT size
     fft_size: usize,
     /// Sample rate
     sample_rate: f64,
 }
 
+/// Display tilt pivot frequency
+const SPECTRUM_SLOPE_PIVOT_HZ: f64 = 1000.0;
+
 impl SpectrumAnalyzer {
     pub fn new(sample_rate: f64) -> Self {
         let fft_size = SPECTRUM_FFT_SIZE;
@@ -2400,6 +2421,8 @@ impl SpectrumAnalyzer {
             buffer_pos: 0,
             smoothing: 0.8,
             peak_decay: 0.995,
+            peak_fall_db: None,
+            slope_db_per_oct: 0.0,
             fft_size,
             sample_rate,
         }
@@ -2428,11 +2451,38 @@ impl SpectrumAnalyzer {
             buffer_pos: 0,
             smoothing: 0.8,
             peak_decay: 0.995,
+            peak_fall_db: None,
+            slope_db_per_oct: 0.0,
             fft_size,
             sample_rate,
         }
     }
 
+    /// Display tilt in dB/octave around 1 kHz (3.0 or 4.5 shows pink noise flat)
+    pub fn slope_db_per_oct(mut self, db_per_oct: f64) -> Self {
+        self.set_slope_db_per_oct(db_per_oct);
+        self
+    }
+
+    /// Peak-hold release time constant (ms) — how fast the peak line falls
+    pub fn peak_hold_decay(mut self, ms: f64) -> Self {
+        self.set_peak_hold_decay(ms);
+        self
+    }
+
+    /// Set display tilt (dB/octave)
+    pub fn set_slope_db_per_oct(&mut self, db_per_oct: f64) {
+        self.slope_db_per_oct = db_per_oct.clamp(-12.0, 12.0);
+    }
+
+    /// Set peak-hold release time constant (ms)
+    pub fn set_peak_hold_decay(&mut self, ms: f64) {
+        // Exponential release: 20·log10(e) dB per time constant
+        let frame_secs = self.fft_size as f64 / self.sample_rate;
+        let tau_secs = ms.max(1.0) * 0.001;
+        self.peak_fall_db = Some(20.0 * std::f64::consts::LOG10_E * frame_secs / tau_secs);
+    }
+
     /// Feed samples to analyzer
     pub fn process(&mut self, samples: &[f64]) {
         for &sample in samples {
@@ -2475,6 +2525,8 @@ impl SpectrumAnalyzer {
             // Peak hold
             if db > self.peak_hold_db[i] {
                 self.peak_hold_db[i] = db;
+            } else if let Some(fall) = self.peak_fall_db {
+                self.peak_hold_db[i] = (self.peak_hold_db[i] - fall).max(db);
             } else {
                 self.peak_hold_db[i] *= self.peak_decay;
             }
@@ -2491,8 +2543,35 @@ impl SpectrumAnalyzer {
         }
     }
 
+    /// Get peak-hold level at frequency
+    pub fn peak_at(&self, freq: f64) -> f64 {
+        let bin = (freq * self.fft_size as f64 / self.sample_rate) as usize;
+        if bin < self.peak_hold_db.len() {
+            self.peak_hold_db[bin]
+        } else {
+            -120.0
+        }
+    }
+
+    /// Display tilt offset at frequency (dB)
+    pub fn slope_offset_db(&self, freq: f64) -> f64 {
+        if self.slope_db_per_oct == 0.0 || freq <= 0.0 {
+            return 0.0;
+        }
+        self.slope_db_per_oct * (freq / SPECTRUM_SLOPE_PIVOT_HZ).log2()
+    }
+
     /// Get spectrum data for GPU upload (256 points, log-scaled)
     pub fn get_spectrum_data(&self, num_points: usize) -> Vec<f32> {
+        self.display_data(num_points, |freq| self.magnitude_at(freq))
+    }
+
+    /// Get peak-hold data for GPU upload (log-scaled, same layout as spectrum)
+    pub fn get_peak_data(&self, num_points: usize) -> Vec<f32> {
+        self.display_data(num_points, |freq| self.peak_at(freq))
+    }
+
+    fn display_data(&self, num_points: usize, level_at: impl Fn(f64) -> f64) -> Vec<f32> {
         let mut data = Vec::with_capacity(num_points);
         let log_min = 20.0_f64.log10();
         let log_max = (self.sample_rate / 2.0).log10();
@@ -2500,7 +2579,7 @@ impl SpectrumAnalyzer {
         for i in 0..num_points {
             let t = i as f64 / (num_points - 1) as f64;
             let freq = 10.0_f64.powf(log_min + t * (log_max - log_min));
-            let db = self.magnitude_at(freq);
+            let db = level_at(freq) + self.slope_offset_db(freq);
             // Normalize to 0-1 range (-120 to 0 dB)
             let normalized = ((db + 120.0) / 120.0).clamp(0.0, 1.0);
             data.push(normalized as f32);
@@ -3480,6 +3559,36 @@ mod tests {
         assert_eq!(data.len(), 256);
     }
 
+    #[test]
+    fn test_spectrum_analyzer_slope() {
+        let analyzer = SpectrumAnalyzer::new(48000.0).slope_db_per_oct(4.5);
+        assert_eq!(analyzer.slope_offset_db(1000.0), 0.0);
+        assert!((analyzer.slope_offset_db(2000.0) - 4.5).abs() < 1e-9);
+        assert!((analyzer.slope_offset_db(250.0) + 9.0).abs() < 1e-9);
+
+        // Default is flat
+        assert_eq!(SpectrumAnalyzer::new(48000.0).slope_offset_db(8000.0), 0.0);
+    }
+
+    #[test]
+    fn test_spectrum_analyzer_peak_hold_decay() {
+        let tone: Vec<f64> = (0..8192)
+            .map(|i| (2.0 * PI * 1000.0 * i as f64 / 48000.0).sin())
+            .collect();
+        let silence = vec![0.0; 8192];
+
+        let mut fast = SpectrumAnalyzer::new(48000.0).peak_hold_decay(50.0);
+        let mut slow = SpectrumAnalyzer::new(48000.0).peak_hold_decay(2000.0);
+        for analyzer in [&mut fast, &mut slow] {
+            analyzer.process(&tone);
+            analyzer.process(&silence);
+        }
+
+        // One silent frame: slow release barely moves, fast one drops ~30 dB
+        assert!(slow.peak_at(1000.0) > -20.0);
+        assert!(fast.peak_at(1000.0) < slow.peak_at(1000.0) - 20.0);
+    }
+
     #[test]
     fn test_ab_comparison() {
         let mut eq = ProEq::new(48000.0);
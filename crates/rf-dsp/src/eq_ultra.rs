@@ -10,9 +10,13 @@
 //! - Denormal prevention (flush-to-zero)
 //! - Inter-channel correlation monitoring
 //! - AI-based frequency suggestions
+//! - Per-band phase mode (minimum, linear, natural crossover)
 
 use std::f64::consts::PI;
 
+use realfft::RealFftPlanner;
+use rustfft::num_complex::Complex;
+
 use crate::{Processor, ProcessorConfig, StereoProcessor};
 use rf_core::Sample;
 
@@ -30,6 +34,12 @@ const COEFF_SMOOTH_SAMPLES: usize = 64;
 #[allow(dead_code)]
 const MAX_OVERSAMPLE: usize = 8;
 
+/// Linear-phase FIR length (latency is half of it)
+pub const ULTRA_LINEAR_FIR_SIZE: usize = 1024;
+
+/// Default split between minimum- and linear-phase bands in natural mode
+const NATURAL_PHASE_CROSSOVER_HZ: f64 = 1000.0;
+
 // ============================================================================
 // MATCHED Z-TRANSFORM FILTER
 // ============================================================================
@@ -111,6 +121,16 @@ impl MztCoeffs {
         }
     }
 
+    /// Magnitude response at `freq`
+    pub fn magnitude_at(&self, freq: f64, sample_rate: f64) -> f64 {
+        let w = 2.0 * PI * freq / sample_rate;
+        let z1 = Complex::new(w.cos(), -w.sin());
+        let z2 = z1 * z1;
+        let num = z1 * self.b1 + z2 * self.b2 + self.b0;
+        let den = z1 * self.a1 + z2 * self.a2 + 1.0;
+        num.norm() / den.norm().max(1e-20)
+    }
+
     /// High-shelf using MZT
     pub fn high_shelf_mzt(freq: f64, q: f64, gain_db: f64, sample_rate: f64) -> Self {
         let _omega = 2.0 * PI * freq / sample_rate;
@@ -1154,11 +1174,25 @@ pub struct UltraBand {
     pub transient_aware: bool,
     /// Transient Q reduction factor (0-1)
     pub transient_q_reduction: f64,
+    /// Phase response; linear bands run in the EQ's shared FIR stage
+    pub phase_mode: UltraPhaseMode,
 
     sample_rate: f64,
     needs_update: bool,
 }
 
+/// Per-band phase mode
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum UltraPhaseMode {
+    /// IIR, no pre-ringing or latency
+    #[default]
+    Minimum,
+    /// Symmetric FIR, constant group delay
+    Linear,
+    /// Minimum phase below the EQ's natural-phase crossover, linear above
+    Natural,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
 pub enum UltraFilterType {
     #[default]
@@ -1185,11 +1219,21 @@ impl UltraBand {
             filter_type: UltraFilterType::Bell,
             transient_aware: false,
             transient_q_reduction: 0.5,
+            phase_mode: UltraPhaseMode::Minimum,
             sample_rate,
             needs_update: true,
         }
     }
 
+    /// Whether the band runs in the linear-phase FIR stage
+    pub fn uses_linear_phase(&self, natural_crossover_hz: f64) -> bool {
+        match self.phase_mode {
+            UltraPhaseMode::Minimum => false,
+            UltraPhaseMode::Linear => true,
+            UltraPhaseMode::Natural => self.frequency >= natural_crossover_hz,
+        }
+    }
+
     pub fn set_params(&mut self, freq: f64, gain_db: f64, q: f64, filter_type: UltraFilterType) {
         self.frequency = freq.clamp(10.0, 30000.0);
         self.gain_db = gain_db.clamp(-30.0, 30.0);
@@ -1208,7 +1252,15 @@ impl UltraBand {
             return;
         }
 
-        let coeffs = match self.filter_type {
+        let coeffs = self.design_coeffs();
+        self.filter_l.set_coeffs(coeffs);
+        self.filter_r.set_coeffs(coeffs);
+        self.needs_update = false;
+    }
+
+    /// Static coefficients for the current parameters
+    fn design_coeffs(&self) -> MztCoeffs {
+        match self.filter_type {
             UltraFilterType::Bell => {
                 MztCoeffs::bell_mzt(self.frequency, self.q, self.gain_db, self.sample_rate)
             }
@@ -1224,11 +1276,7 @@ impl UltraBand {
             UltraFilterType::HighCut => {
                 MztCoeffs::lowpass_mzt(self.frequency, self.q, self.sample_rate)
             }
-        };
-
-        self.filter_l.set_coeffs(coeffs);
-        self.filter_r.set_coeffs(coeffs);
-        self.needs_update = false;
+        }
     }
 
     /// Process stereo sample
@@ -1285,6 +1333,190 @@ impl UltraBand {
     }
 }
 
+// ============================================================================
+// LINEAR PHASE STAGE
+// ============================================================================
+
+/// Parameters a linear-phase band contributes to the FIR
+type LinearBandKey = (f64, f64, f64, UltraFilterType);
+
+/// Fade length when a new kernel arrives through `process_sample`
+const LINEAR_KERNEL_SAMPLE_FADE: usize = 64;
+
+/// Shared FIR for all linear-phase bands
+///
+/// Magnitudes of the linear bands are multiplied into one zero-phase
+/// response, giving a symmetric impulse and `ULTRA_LINEAR_FIR_SIZE / 2`
+/// samples of latency. Saturation and transient-aware Q do not apply here.
+///
+/// Kernels are designed by the parameter setters and parked in `pending`;
+/// the audio side swaps them in at the start of a block and crossfades the
+/// old and new kernel outputs over that block. Replaced kernels go to
+/// `retired` and are freed by the next design, so the audio path never
+/// allocates or frees.
+#[derive(Clone)]
+struct LinearPhaseStage {
+    /// Active impulse response (empty when no band is linear)
+    fir: Vec<f64>,
+    /// Outgoing impulse response while crossfading
+    previous: Vec<f64>,
+    /// Designed kernel waiting for the next block
+    pending: Option<Vec<f64>>,
+    /// Kernel swapped out by the audio side, freed on the control side
+    retired: Option<Vec<f64>>,
+    fade_pos: usize,
+    fade_len: usize,
+    /// Band parameters and sample rate the last kernel was designed for
+    designed_for: Vec<LinearBandKey>,
+    designed_rate: f64,
+    /// Input history, written twice so the last FIR-length window is contiguous
+    history_l: Vec<f64>,
+    history_r: Vec<f64>,
+    pos: usize,
+}
+
+impl LinearPhaseStage {
+    fn new() -> Self {
+        Self {
+            fir: Vec::new(),
+            previous: Vec::new(),
+            pending: None,
+            retired: None,
+            fade_pos: 0,
+            fade_len: 0,
+            designed_for: Vec::new(),
+            designed_rate: 0.0,
+            history_l: vec![0.0; ULTRA_LINEAR_FIR_SIZE * 2],
+            history_r: vec![0.0; ULTRA_LINEAR_FIR_SIZE * 2],
+            pos: 0,
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        !self.fir.is_empty()
+    }
+
+    /// Design a new kernel if the linear-phase bands changed (control side)
+    fn design(&mut self, bands: &[UltraBand], crossover_hz: f64, sample_rate: f64) {
+        self.retired = None;
+
+        let linear = || {
+            bands
+                .iter()
+                .filter(|b| b.enabled && b.uses_linear_phase(crossover_hz))
+        };
+        let key = |b: &UltraBand| (b.frequency, b.gain_db, b.q, b.filter_type);
+
+        if self.designed_rate == sample_rate
+            && linear().map(key).eq(self.designed_for.iter().copied())
+        {
+            return;
+        }
+
+        self.designed_for = linear().map(key).collect();
+        self.designed_rate = sample_rate;
+
+        if self.designed_for.is_empty() {
+            self.pending = Some(Vec::new());
+            return;
+        }
+
+        let n = ULTRA_LINEAR_FIR_SIZE;
+        let coeffs: Vec<MztCoeffs> = linear().map(|b| b.design_coeffs()).collect();
+        let mut spectrum: Vec<Complex<f64>> = (0..=n / 2)
+            .map(|k| {
+                let freq = k as f64 * sample_rate / n as f64;
+                let mag: f64 = coeffs
+                    .iter()
+                    .map(|c| c.magnitude_at(freq, sample_rate))
+                    .product();
+                Complex::new(mag, 0.0)
+            })
+            .collect();
+
+        let mut impulse = vec![0.0; n];
+        RealFftPlanner::<f64>::new()
+            .plan_fft_inverse(n)
+            .process(&mut spectrum, &mut impulse)
+            .ok();
+
+        // Center the zero-phase impulse and window it (periodic Blackman-Harris
+        // keeps the taps exactly symmetric around n/2)
+        let half = n / 2;
+        let fir = (0..n)
+            .map(|i| {
+                let t = i as f64 / n as f64;
+                let window = 0.35875 - 0.48829 * (2.0 * PI * t).cos()
+                    + 0.14128 * (4.0 * PI * t).cos()
+                    - 0.01168 * (6.0 * PI * t).cos();
+                impulse[(i + half) % n] / n as f64 * window
+            })
+            .collect();
+        self.pending = Some(fir);
+    }
+
+    /// Swap in a pending kernel, crossfading from the old one over `fade_len` samples
+    fn begin_block(&mut self, fade_len: usize) {
+        let Some(mut next) = self.pending.take() else {
+            return;
+        };
+        std::mem::swap(&mut self.fir, &mut next);
+        // `next` now holds the outgoing kernel
+        std::mem::swap(&mut self.previous, &mut next);
+        self.retired = Some(next);
+
+        if self.fir.is_empty() {
+            // Stage switched off; latency changes with it
+            self.reset();
+        } else if self.previous.is_empty() {
+            // Stage switched on; history holds the dry input, nothing to fade from
+            self.fade_len = 0;
+        } else {
+            self.fade_pos = 0;
+            self.fade_len = fade_len.max(1);
+        }
+    }
+
+    #[inline]
+    fn convolve(fir: &[f64], history: &[f64]) -> f64 {
+        fir.iter().rev().zip(history).map(|(h, x)| h * x).sum()
+    }
+
+    #[inline]
+    fn process(&mut self, left: f64, right: f64) -> (f64, f64) {
+        let n = ULTRA_LINEAR_FIR_SIZE;
+        self.history_l[self.pos] = left;
+        self.history_l[self.pos + n] = left;
+        self.history_r[self.pos] = right;
+        self.history_r[self.pos + n] = right;
+
+        // Oldest to newest
+        let window = self.pos + 1..self.pos + 1 + n;
+        let hist_l = &self.history_l[window.clone()];
+        let hist_r = &self.history_r[window];
+        let mut out_l = Self::convolve(&self.fir, hist_l);
+        let mut out_r = Self::convolve(&self.fir, hist_r);
+
+        if self.fade_pos < self.fade_len {
+            self.fade_pos += 1;
+            let t = self.fade_pos as f64 / self.fade_len as f64;
+            out_l = out_l * t + Self::convolve(&self.previous, hist_l) * (1.0 - t);
+            out_r = out_r * t + Self::convolve(&self.previous, hist_r) * (1.0 - t);
+        }
+
+        self.pos = (self.pos + 1) % n;
+        (out_l, out_r)
+    }
+
+    fn reset(&mut self) {
+        self.history_l.fill(0.0);
+        self.history_r.fill(0.0);
+        self.pos = 0;
+        self.fade_pos = 0;
+        self.fade_len = 0;
+    }
+}
+
 // ============================================================================
 // ULTRA EQ (MAIN PROCESSOR)
 // ============================================================================
@@ -1307,6 +1539,9 @@ pub struct UltraEq {
     pub output_gain_db: f64,
     /// Global oversample mode
     pub oversample_mode: OversampleMode,
+    /// Natural-phase split: bands below are minimum phase, above linear
+    natural_phase_crossover_hz: f64,
+    linear_stage: LinearPhaseStage,
     sample_rate: f64,
 }
 
@@ -1325,6 +1560,8 @@ impl UltraEq {
             loudness_curve: EqualLoudness::generate_curve(70.0, 256),
             output_gain_db: 0.0,
             oversample_mode: OversampleMode::Off,
+            natural_phase_crossover_hz: NATURAL_PHASE_CROSSOVER_HZ,
+            linear_stage: LinearPhaseStage::new(),
             sample_rate,
         }
     }
@@ -1344,6 +1581,7 @@ impl UltraEq {
         if let Some(band) = self.bands.get_mut(index) {
             band.enabled = enabled;
         }
+        self.update_linear_phase();
     }
 
     /// Set band parameters
@@ -1359,6 +1597,45 @@ impl UltraEq {
             band.enabled = true;
             band.set_params(freq, gain_db, q, filter_type);
         }
+        self.update_linear_phase();
+    }
+
+    /// Set band phase mode
+    pub fn set_band_phase_mode(&mut self, index: usize, mode: UltraPhaseMode) {
+        if let Some(band) = self.bands.get_mut(index) {
+            band.phase_mode = mode;
+        }
+        self.update_linear_phase();
+    }
+
+    /// Natural-phase crossover frequency
+    pub fn natural_phase_crossover(&self) -> f64 {
+        self.natural_phase_crossover_hz
+    }
+
+    /// Set the natural-phase crossover frequency
+    pub fn set_natural_phase_crossover(&mut self, hz: f64) {
+        self.natural_phase_crossover_hz = hz;
+        self.update_linear_phase();
+    }
+
+    /// Redesign the linear-phase FIR for the current bands
+    ///
+    /// Called by the setters; call it after editing a band through `band_mut`.
+    /// The new kernel is crossfaded in over the next processed block.
+    pub fn update_linear_phase(&mut self) {
+        self.linear_stage.design(
+            &self.bands,
+            self.natural_phase_crossover_hz,
+            self.sample_rate,
+        );
+    }
+
+    /// Whether any enabled band runs in the linear-phase FIR stage
+    pub fn has_linear_phase_bands(&self) -> bool {
+        self.bands
+            .iter()
+            .any(|b| b.enabled && b.uses_linear_phase(self.natural_phase_crossover_hz))
     }
 
     /// Set global oversample mode
@@ -1381,19 +1658,32 @@ impl UltraEq {
         self.analyzer.analyze()
     }
 
+    /// Run minimum-phase bands, then the linear-phase FIR stage
+    #[inline]
+    fn process_bands(&mut self, left: f64, right: f64) -> (f64, f64) {
+        let (mut out_l, mut out_r) = (left, right);
+        let crossover = self.natural_phase_crossover_hz;
+
+        for band in &mut self.bands {
+            if band.enabled && !band.uses_linear_phase(crossover) {
+                (out_l, out_r) = band.process(out_l, out_r);
+            }
+        }
+
+        if self.linear_stage.is_active() {
+            (out_l, out_r) = self.linear_stage.process(out_l, out_r);
+        }
+
+        (out_l, out_r)
+    }
+
     /// Process stereo block
     pub fn process_block(&mut self, left: &mut [Sample], right: &mut [Sample]) {
         debug_assert_eq!(left.len(), right.len());
+        self.linear_stage.begin_block(left.len());
 
         for (l, r) in left.iter_mut().zip(right.iter_mut()) {
-            let (mut out_l, mut out_r) = (*l, *r);
-
-            // Process through all enabled bands
-            for band in &mut self.bands {
-                if band.enabled {
-                    (out_l, out_r) = band.process(out_l, out_r);
-                }
-            }
+            let (mut out_l, mut out_r) = self.process_bands(*l, *r);
 
             // Apply output gain
             let gain = 10.0_f64.powf(self.output_gain_db / 20.0);
@@ -1416,29 +1706,30 @@ impl Processor for UltraEq {
         }
         self.correlation.reset();
         self.analyzer.reset();
+        self.linear_stage.reset();
     }
 
     fn latency(&self) -> usize {
-        match self.oversample_mode {
+        let oversample = match self.oversample_mode {
             OversampleMode::Off => 0,
             OversampleMode::X2 => 23,
             OversampleMode::X4 => 46,
             OversampleMode::X8 => 69,
             OversampleMode::Adaptive => 23,
-        }
+        };
+        let linear_phase = if self.has_linear_phase_bands() {
+            ULTRA_LINEAR_FIR_SIZE / 2
+        } else {
+            0
+        };
+        oversample + linear_phase
     }
 }
 
 impl StereoProcessor for UltraEq {
     fn process_sample(&mut self, left: Sample, right: Sample) -> (Sample, Sample) {
-        let mut out_l = left;
-        let mut out_r = right;
-
-        for band in &mut self.bands {
-            if band.enabled {
-                (out_l, out_r) = band.process(out_l, out_r);
-            }
-        }
+        self.linear_stage.begin_block(LINEAR_KERNEL_SAMPLE_FADE);
+        let (out_l, out_r) = self.process_bands(left, right);
 
         let gain = 10.0_f64.powf(self.output_gain_db / 20.0);
         (out_l * gain, out_r * gain)
@@ -1454,6 +1745,7 @@ impl ProcessorConfig for UltraEq {
             band.transient_detector = TransientDetector::new(sample_rate);
         }
         self.correlation = CorrelationMeter::new(sample_rate);
+        self.update_linear_phase();
     }
 }
 
@@ -1626,4 +1918,99 @@ mod tests {
         assert!(out_l.is_finite());
         assert!(out_r.is_finite());
     }
+
+    #[test]
+    fn test_linear_kernel_crossfades_over_block() {
+        let linear_eq = |gain_db| {
+            let mut eq = UltraEq::new(48000.0);
+            eq.set_band(0, 4000.0, gain_db, 2.0, UltraFilterType::Bell);
+            eq.set_band_phase_mode(0, UltraPhaseMode::Linear);
+            eq
+        };
+        let block = 256;
+        let render = |eq: &mut UltraEq, switch_at: Option<usize>| -> Vec<f64> {
+            let mut out = Vec::new();
+            for b in 0..8 {
+                if switch_at == Some(b) {
+                    eq.set_band(0, 4000.0, -12.0, 2.0, UltraFilterType::Bell);
+                }
+                let mut l: Vec<f64> = (0..block)
+                    .map(|i| (2.0 * PI * 5000.0 * (b * block + i) as f64 / 48000.0).sin())
+                    .collect();
+                let mut r = l.clone();
+                eq.process_block(&mut l, &mut r);
+                out.extend(l);
+            }
+            out
+        };
+
+        let boost = render(&mut linear_eq(12.0), None);
+        let cut = render(&mut linear_eq(-12.0), None);
+        let switched = render(&mut linear_eq(12.0), Some(4));
+
+        for i in 0..boost.len() {
+            let expected = match i / block {
+                0..=3 => boost[i],
+                4 => {
+                    let t = (i % block + 1) as f64 / block as f64;
+                    boost[i] * (1.0 - t) + cut[i] * t
+                }
+                _ => cut[i],
+            };
+            assert!((switched[i] - expected).abs() < 1e-9, "sample {i}");
+        }
+    }
+
+    /// Impulse response of `eq` to a unit impulse at `at`
+    fn impulse_response(eq: &mut UltraEq, at: usize, len: usize) -> Vec<f64> {
+        (0..len)
+            .map(|i| {
+                let x = if i == at { 1.0 } else { 0.0 };
+                eq.process_sample(x, x).0
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_natural_phase_ringing() {
+        let at = 100;
+
+        // Linear-phase high band: symmetric ring around the delayed peak
+        let mut eq = UltraEq::new(48000.0);
+        eq.set_band(0, 8000.0, 12.0, 4.0, UltraFilterType::Bell);
+        eq.set_band_phase_mode(0, UltraPhaseMode::Linear);
+        assert_eq!(eq.latency(), ULTRA_LINEAR_FIR_SIZE / 2);
+
+        let ir = impulse_response(&mut eq, at, 2048);
+        let peak = at + ULTRA_LINEAR_FIR_SIZE / 2;
+        let pre: f64 = ir[peak - 200..peak].iter().map(|x| x * x).sum();
+        let post: f64 = ir[peak + 1..=peak + 200].iter().map(|x| x * x).sum();
+        assert!(pre > 1e-3, "expected pre-ring, got {pre:e}");
+        for k in 1..200 {
+            assert!((ir[peak - k] - ir[peak + k]).abs() < 1e-9);
+        }
+        assert!((pre - post).abs() < 1e-9);
+
+        // Minimum-phase low band: no latency, nothing before the impulse
+        let mut eq = UltraEq::new(48000.0);
+        eq.set_band(0, 100.0, 12.0, 4.0, UltraFilterType::Bell);
+        assert_eq!(eq.latency(), 0);
+
+        let ir = impulse_response(&mut eq, at, 2048);
+        // (only denormal-prevention noise before the impulse)
+        assert!(ir[..at].iter().all(|x| x.abs() < 1e-12));
+        let post: f64 = ir[at + 1..=at + 200].iter().map(|x| x * x).sum();
+        assert!(post > 1e-3);
+
+        // Natural mode splits at the crossover
+        let mut eq = UltraEq::new(48000.0);
+        eq.set_band(0, 100.0, 6.0, 1.0, UltraFilterType::Bell);
+        eq.set_band(1, 8000.0, 6.0, 1.0, UltraFilterType::Bell);
+        eq.set_band_phase_mode(0, UltraPhaseMode::Natural);
+        eq.set_band_phase_mode(1, UltraPhaseMode::Natural);
+        let crossover = eq.natural_phase_crossover();
+        assert!(!eq.band(0).unwrap().uses_linear_phase(crossover));
+        assert!(eq.band(1).unwrap().uses_linear_phase(crossover));
+        assert_eq!(eq.latency(), ULTRA_LINEAR_FIR_SIZE / 2);
+    }
 }
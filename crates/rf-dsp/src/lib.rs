@@ -111,7 +111,7 @@ pub use transient::{
 // Re-export pitch editor
 pub use pitch::{
     Pitch, PitchCorrector, PitchDetector, PitchDetectorConfig, PitchEditorState, PitchSegment,
-    Scale,
+    PitchShifter, Scale,
 };
 
 // Re-export spectral processors
@@ -131,8 +131,8 @@ pub use eq_pro::{
 
 // Re-export Ultimate EQ (legacy — UltraEqWrapper now uses ProEq internally)
 pub use eq_ultra::{
-    TransientDetector as UltraTransientDetector, ULTRA_MAX_BANDS, UltraBand, UltraEq,
-    UltraFilterType,
+    TransientDetector as UltraTransientDetector, ULTRA_LINEAR_FIR_SIZE, ULTRA_MAX_BANDS, UltraBand,
+    UltraEq, UltraFilterType, UltraPhaseMode,
 };
 
 // Re-export Analog EQ models
@@ -264,3 +264,48 @@ pub trait StereoProcessor: Processor {
 pub trait ProcessorConfig {
     fn set_sample_rate(&mut self, sample_rate: f64);
 }
+
+/// Host transport state handed to plugins and tempo-aware processors
+#[derive(Debug, Clone)]
+pub struct ProcessContext {
+    /// Sample rate in Hz
+    pub sample_rate: f64,
+    /// Maximum block size
+    pub max_block_size: usize,
+    /// Current tempo in BPM
+    pub tempo: f64,
+    /// Time signature numerator
+    pub time_sig_num: u32,
+    /// Time signature denominator
+    pub time_sig_denom: u32,
+    /// Current playback position in samples
+    pub position_samples: i64,
+    /// Is playing
+    pub is_playing: bool,
+    /// Is recording
+    pub is_recording: bool,
+    /// Is looping
+    pub is_looping: bool,
+    /// Loop start in samples
+    pub loop_start: i64,
+    /// Loop end in samples
+    pub loop_end: i64,
+}
+
+impl Default for ProcessContext {
+    fn default() -> Self {
+        Self {
+            sample_rate: 48000.0,
+            max_block_size: 512,
+            tempo: 120.0,
+            time_sig_num: 4,
+            time_sig_denom: 4,
+            position_samples: 0,
+            is_playing: false,
+            is_recording: false,
+            is_looping: false,
+            loop_start: 0,
+            loop_end: 0,
+        }
+    }
+}
@@ -155,17 +155,12 @@ impl ZwickerLoudness {
             let energy = self.band_energies[band] / self.integration_len as f64;
             let level_db = 10.0 * energy.max(1e-20).log10() + 94.0; // Reference: 94 dB SPL at full scale
 
-            // Excitation level above threshold
-            let excitation = (level_db - THRESHOLD_QUIET[band]).max(0.0);
-
-            // Specific loudness calculation (simplified Zwicker model)
-            // N' = 0.08 * (E_TQ / s) * [(0.5 + 0.5 * E / E_TQ)^0.23 - 1]
-            // Simplified: N' ≈ (excitation / 40)^0.35 for typical levels
-            self.specific_loudness[band] = if excitation > 0.0 {
-                (excitation / 40.0).powf(0.35)
-            } else {
-                0.0
-            };
+            // Zwicker specific loudness (sone/Bark), threshold factor s = 0.5:
+            // N' = 0.08 * (E_TQ / E_0)^0.23 * [(0.5 + 0.5 * E / E_TQ)^0.23 - 1]
+            let e_tq = 10.0_f64.powf(THRESHOLD_QUIET[band] / 10.0);
+            let e = 10.0_f64.powf(level_db / 10.0);
+            let n = 0.08 * e_tq.powf(0.23) * ((0.5 + 0.5 * e / e_tq).powf(0.23) - 1.0);
+            self.specific_loudness[band] = n.max(0.0);
         }
     }
 
@@ -228,32 +223,7 @@ impl SharpnessMeter {
 
     /// Calculate sharpness in acum
     pub fn sharpness(&self) -> f64 {
-        let specific = self.zwicker.specific_loudness();
-        let total_loudness = self.zwicker.loudness_sones();
-
-        if total_loudness < 0.001 {
-            return 0.0;
-        }
-
-        // Zwicker sharpness formula
-        // S = 0.11 * ∫ N'(z) * g(z) * z dz / N
-        // where g(z) is a weighting function that increases for high Bark numbers
-
-        let mut numerator = 0.0;
-        for band in 0..NUM_BARK_BANDS {
-            let z = band as f64 + 0.5; // Bark position
-
-            // Weighting function g(z)
-            let g = if z < 15.8 {
-                1.0
-            } else {
-                0.066 * (0.171 * z).exp()
-            };
-
-            numerator += specific[band] * g * z;
-        }
-
-        0.11 * numerator / total_loudness
+        zwicker_sharpness(self.zwicker.specific_loudness())
     }
 
     /// Get sharpness assessment
@@ -277,6 +247,32 @@ impl SharpnessMeter {
     }
 }
 
+/// Zwicker sharpness in acum from specific loudness
+///
+/// S = 0.11 * Σ N'(z) * g(z) * z / N, where g(z) weights bands above 15.8 Bark
+pub fn zwicker_sharpness(specific: &[f64; NUM_BARK_BANDS]) -> f64 {
+    let total_loudness: f64 = specific.iter().sum();
+    if total_loudness < 0.001 {
+        return 0.0;
+    }
+
+    let mut numerator = 0.0;
+    for (band, &n) in specific.iter().enumerate() {
+        let z = band as f64 + 0.5; // Bark position
+
+        // Weighting function g(z)
+        let g = if z < 15.8 {
+            1.0
+        } else {
+            0.066 * (0.171 * z).exp()
+        };
+
+        numerator += n * g * z;
+    }
+
+    0.11 * numerator / total_loudness
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // FLUCTUATION STRENGTH
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -474,10 +470,23 @@ impl PsychoacousticMeter {
         self.roughness.process(sample);
     }
 
-    pub fn process_block(&mut self, samples: &[Sample]) {
+    /// Process a mono block and return the current perceptual metrics
+    pub fn process_block(&mut self, samples: &[Sample]) -> PsychoMetrics {
         for &s in samples {
             self.process(s);
         }
+        self.metrics()
+    }
+
+    /// Loudness, sharpness and roughness from the last completed integration window
+    ///
+    /// Sharpness is derived from the loudness meter's specific loudness.
+    pub fn metrics(&self) -> PsychoMetrics {
+        PsychoMetrics {
+            loudness_sone: self.loudness.loudness_sones(),
+            sharpness_acum: zwicker_sharpness(self.loudness.specific_loudness()),
+            roughness_asper: self.roughness.roughness(),
+        }
     }
 
     /// Get all metrics as a report
@@ -501,6 +510,17 @@ impl PsychoacousticMeter {
     }
 }
 
+/// Per-block perceptual metrics
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PsychoMetrics {
+    /// Total loudness (sone)
+    pub loudness_sone: f64,
+    /// Sharpness (acum)
+    pub sharpness_acum: f64,
+    /// Roughness (asper)
+    pub roughness_asper: f64,
+}
+
 /// Psychoacoustic analysis report
 #[derive(Debug, Clone)]
 pub struct PsychoacousticReport {
@@ -561,4 +581,52 @@ mod tests {
         let sharp = meter.sharpness();
         assert!(sharp > 1.5, "Sharpness: {} acum", sharp);
     }
+
+    #[test]
+    fn test_block_sharpness_bright_vs_dark_at_equal_lufs() {
+        use crate::metering::LufsMeter;
+
+        let sr = 48000.0;
+        let mut seed: u32 = 0x1234_5678;
+        let noise: Vec<f64> = (0..96000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                seed as f64 / u32::MAX as f64 * 2.0 - 1.0
+            })
+            .collect();
+
+        // First difference = high-frequency tilt; one-pole at ~500 Hz = low-passed
+        let bright: Vec<f64> = noise.windows(2).map(|w| w[1] - w[0]).collect();
+        let coeff = (-2.0 * PI * 500.0 / sr).exp();
+        let mut z = 0.0;
+        let dark: Vec<f64> = noise
+            .iter()
+            .map(|&x| {
+                z = (1.0 - coeff) * x + coeff * z;
+                z
+            })
+            .collect();
+
+        // Normalize both to -23 LUFS
+        let normalize = |signal: &[f64]| -> Vec<f64> {
+            let mut lufs = LufsMeter::new(sr);
+            lufs.process_block(signal, signal);
+            let gain = 10.0_f64.powf((-23.0 - lufs.integrated_loudness()) / 20.0);
+            signal.iter().map(|x| x * gain).collect()
+        };
+        let bright = normalize(&bright);
+        let dark = normalize(&dark);
+
+        let bright_metrics = PsychoacousticMeter::new(sr).process_block(&bright);
+        let dark_metrics = PsychoacousticMeter::new(sr).process_block(&dark);
+
+        assert!(bright_metrics.loudness_sone > 0.0);
+        assert!(dark_metrics.loudness_sone > 0.0);
+        assert!(
+            bright_metrics.sharpness_acum > dark_metrics.sharpness_acum,
+            "bright {:?} vs dark {:?}",
+            bright_metrics,
+            dark_metrics
+        );
+    }
 }
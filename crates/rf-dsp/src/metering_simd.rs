@@ -159,18 +159,21 @@ pub fn calculate_correlation_simd(left: &[Sample], right: &[Sample]) -> f64 {
 // 8X OVERSAMPLING TRUE PEAK (SUPERIOR TO ITU 4X)
 // ═══════════════════════════════════════════════════════════════════════════════
 
+/// Taps per polyphase branch of the 8x true-peak interpolator
+const TP_TAPS_PER_PHASE: usize = 12;
+
 /// 8x oversampling True Peak meter - SUPERIOR to ITU-R BS.1770-4 (4x)
 ///
-/// Uses 48-tap polyphase FIR filter for 8x oversampling,
+/// Uses 96-tap polyphase FIR filter for 8x oversampling,
 /// catching inter-sample peaks that 4x might miss.
 #[derive(Debug, Clone)]
 pub struct TruePeak8x {
-    /// 48-tap FIR coefficients (8 phases × 6 taps)
-    coeffs: [f64; 48],
-    /// Filter state for left channel
-    state_l: [f64; 6],
-    /// Filter state for right channel
-    state_r: [f64; 6],
+    /// 96-tap FIR coefficients (8 phases × 12 taps)
+    coeffs: [f64; 8 * TP_TAPS_PER_PHASE],
+    /// Filter state for left channel (last input samples, carried across calls)
+    state_l: [f64; TP_TAPS_PER_PHASE],
+    /// Filter state for right channel (last input samples, carried across calls)
+    state_r: [f64; TP_TAPS_PER_PHASE],
     /// Current peaks
     peak_l: f64,
     peak_r: f64,
@@ -188,15 +191,16 @@ pub struct TruePeak8x {
 impl TruePeak8x {
     /// Create 8x oversampling True Peak meter
     pub fn new(sample_rate: f64) -> Self {
-        // 48-tap windowed sinc filter for 8x oversampling
+        // 96-tap windowed sinc filter for 8x oversampling
         // Kaiser window, beta = 8.6 for excellent stopband attenuation
-        let mut coeffs = [0.0; 48];
+        const TAPS: usize = 8 * TP_TAPS_PER_PHASE;
+        let mut coeffs = [0.0; TAPS];
 
         // Generate windowed sinc coefficients
-        let m = 47;
+        let m = TAPS - 1;
         let fc = 0.5 / 8.0; // Normalized cutoff for 8x
 
-        for i in 0..48 {
+        for i in 0..TAPS {
             let n = i as f64 - m as f64 / 2.0;
             if n.abs() < 1e-10 {
                 coeffs[i] = 2.0 * fc;
@@ -218,10 +222,17 @@ impl TruePeak8x {
             *c /= sum;
         }
 
+        // Polyphase decomposition: phase p takes every 8th tap starting at p,
+        // scaled by 8 to restore unity gain per phase
+        let polyphase = std::array::from_fn(|j| {
+            let (phase, tap) = (j / TP_TAPS_PER_PHASE, j % TP_TAPS_PER_PHASE);
+            coeffs[tap * 8 + phase] * 8.0
+        });
+
         Self {
-            coeffs,
-            state_l: [0.0; 6],
-            state_r: [0.0; 6],
+            coeffs: polyphase,
+            state_l: [0.0; TP_TAPS_PER_PHASE],
+            state_r: [0.0; TP_TAPS_PER_PHASE],
             peak_l: 0.0,
             peak_r: 0.0,
             max_l: 0.0,
@@ -237,7 +248,7 @@ impl TruePeak8x {
     /// Process stereo sample
     pub fn process(&mut self, left: Sample, right: Sample) {
         // Shift state
-        for i in (1..6).rev() {
+        for i in (1..TP_TAPS_PER_PHASE).rev() {
             self.state_l[i] = self.state_l[i - 1];
             self.state_r[i] = self.state_r[i - 1];
         }
@@ -252,8 +263,8 @@ impl TruePeak8x {
             let mut sum_l = 0.0;
             let mut sum_r = 0.0;
 
-            for i in 0..6 {
-                let coeff = self.coeffs[phase * 6 + i];
+            for i in 0..TP_TAPS_PER_PHASE {
+                let coeff = self.coeffs[phase * TP_TAPS_PER_PHASE + i];
                 sum_l += self.state_l[i] * coeff;
                 sum_r += self.state_r[i] * coeff;
             }
@@ -342,8 +353,8 @@ impl TruePeak8x {
 
     /// Reset meter
     pub fn reset(&mut self) {
-        self.state_l = [0.0; 6];
-        self.state_r = [0.0; 6];
+        self.state_l = [0.0; TP_TAPS_PER_PHASE];
+        self.state_r = [0.0; TP_TAPS_PER_PHASE];
         self.peak_l = 0.0;
         self.peak_r = 0.0;
         self.max_l = 0.0;
@@ -698,6 +709,47 @@ mod tests {
         assert!(meter.peak_dbtp() < 1.0);
     }
 
+    #[test]
+    fn test_true_peak_8x_inter_sample() {
+        // fs/4 sine at 45°: samples sit at ±0.707, the waveform peaks at 1.0
+        let signal: Vec<f64> = (0..4800)
+            .map(|i| (std::f64::consts::FRAC_PI_2 * i as f64 + std::f64::consts::FRAC_PI_4).sin())
+            .collect();
+
+        let mut meter = TruePeak8x::new(48000.0);
+        meter.process_block(&signal, &signal);
+        assert!(meter.max_dbtp() > -0.3, "max {} dBTP", meter.max_dbtp());
+    }
+
+    #[test]
+    fn test_true_peak_8x_block_split() {
+        // Short burst whose inter-sample peak falls between samples 100 and 101
+        let signal: Vec<f64> = (0..256)
+            .map(|i| {
+                let t = i as f64 - 100.5;
+                (std::f64::consts::FRAC_PI_2 * t).cos() * (-(t / 12.0).powi(2)).exp()
+            })
+            .collect();
+
+        let mut whole = TruePeak8x::new(48000.0);
+        whole.process_block(&signal, &signal);
+        assert!(whole.max_dbtp() > 20.0 * find_peak_simd(&signal).log10() + 1.0);
+
+        for block in [1, 7, 64, 101] {
+            let mut split = TruePeak8x::new(48000.0);
+            for chunk in signal.chunks(block) {
+                split.process_block(chunk, chunk);
+            }
+            assert!(
+                (split.max_dbtp() - whole.max_dbtp()).abs() < 1e-9,
+                "block {}: {} vs {}",
+                block,
+                split.max_dbtp(),
+                whole.max_dbtp()
+            );
+        }
+    }
+
     #[test]
     fn test_crest_factor_sine() {
         let mut meter = CrestFactorMeter::new(48000.0, 300.0);
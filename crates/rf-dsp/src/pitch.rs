@@ -6,11 +6,20 @@
 //! - Pitch drift and vibrato detection
 //! - Pitch correction (auto-tune style)
 //! - Formant preservation during pitch shift
+//! - Streaming phase-vocoder pitch shifter for live tracks
 //!
 //! Similar to Cubase VariAudio, Melodyne Essential
 
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+use realfft::{ComplexToReal, RealFftPlanner, RealToComplex};
+use rf_core::Sample;
+use rustfft::num_complex::Complex;
 use serde::{Deserialize, Serialize};
 
+use crate::{Processor, ProcessorConfig, StereoProcessor};
+
 // ═══════════════════════════════════════════════════════════════════════════
 // PITCH REPRESENTATION
 // ═══════════════════════════════════════════════════════════════════════════
@@ -695,6 +704,320 @@ impl PitchCorrector {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// REAL-TIME PITCH SHIFTER
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Default STFT size for the live pitch shifter (21 ms at 48 kHz)
+const SHIFTER_FFT_SIZE: usize = 1024;
+
+/// STFT overlap factor (hop = fft_size / 4)
+const SHIFTER_OVERLAP: usize = 4;
+
+/// Half-width of the spectral envelope smoothing window (bins)
+const ENVELOPE_HALF_WIDTH: usize = 8;
+
+/// Maximum envelope correction gain (limits noise boost in spectral holes)
+const MAX_FORMANT_GAIN: f64 = 8.0;
+
+/// Values below this are flushed to zero in the overlap-add state
+const DENORMAL_THRESHOLD: f64 = 1e-30;
+
+/// Per-channel phase-vocoder state
+struct ShifterChannel {
+    in_fifo: Vec<f64>,
+    out_fifo: Vec<f64>,
+    output_accum: Vec<f64>,
+    last_phase: Vec<f64>,
+    sum_phase: Vec<f64>,
+    /// Write position in `in_fifo` (starts at `fft_size - hop`)
+    rover: usize,
+}
+
+impl ShifterChannel {
+    fn new(fft_size: usize, start: usize) -> Self {
+        let bins = fft_size / 2 + 1;
+        Self {
+            in_fifo: vec![0.0; fft_size],
+            out_fifo: vec![0.0; fft_size],
+            output_accum: vec![0.0; fft_size * 2],
+            last_phase: vec![0.0; bins],
+            sum_phase: vec![0.0; bins],
+            rover: start,
+        }
+    }
+
+    fn reset(&mut self, start: usize) {
+        self.in_fifo.fill(0.0);
+        self.out_fifo.fill(0.0);
+        self.output_accum.fill(0.0);
+        self.last_phase.fill(0.0);
+        self.sum_phase.fill(0.0);
+        self.rover = start;
+    }
+}
+
+/// Streaming phase-vocoder pitch shifter with independent formant shift
+///
+/// Unlike `PitchEditorState` (offline, segment based) this runs sample by
+/// sample on live tracks. `semitones` moves the pitch; `formant` moves the
+/// spectral envelope relative to the input, so `formant = 0` keeps the
+/// original formants and `formant = semitones` gives the classic
+/// "chipmunk" shift. Latency is one `fft_size` and never changes with
+/// the shift amount.
+pub struct PitchShifter {
+    sample_rate: f64,
+    fft_size: usize,
+    hop: usize,
+    semitones: f64,
+    formant: f64,
+    fft_forward: Arc<dyn RealToComplex<f64>>,
+    fft_inverse: Arc<dyn ComplexToReal<f64>>,
+    window: Vec<f64>,
+    channels: [ShifterChannel; 2],
+    // Scratch (pre-allocated, no audio-thread allocation)
+    frame: Vec<f64>,
+    spectrum: Vec<Complex<f64>>,
+    ana_mag: Vec<f64>,
+    ana_freq: Vec<f64>,
+    syn_mag: Vec<f64>,
+    syn_freq: Vec<f64>,
+    envelope: Vec<f64>,
+}
+
+impl PitchShifter {
+    /// Create shifter with the default low-latency FFT size
+    pub fn new(sample_rate: f64) -> Self {
+        Self::with_fft_size(sample_rate, SHIFTER_FFT_SIZE)
+    }
+
+    /// Create shifter with a custom FFT size (power of two, >= 256)
+    pub fn with_fft_size(sample_rate: f64, fft_size: usize) -> Self {
+        let fft_size = fft_size.max(256).next_power_of_two();
+        let hop = fft_size / SHIFTER_OVERLAP;
+        let bins = fft_size / 2 + 1;
+
+        let mut planner = RealFftPlanner::<f64>::new();
+        let window = (0..fft_size)
+            .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f64 / fft_size as f64).cos())
+            .collect();
+
+        Self {
+            sample_rate,
+            fft_size,
+            hop,
+            semitones: 0.0,
+            formant: 0.0,
+            fft_forward: planner.plan_fft_forward(fft_size),
+            fft_inverse: planner.plan_fft_inverse(fft_size),
+            window,
+            channels: [
+                ShifterChannel::new(fft_size, fft_size - hop),
+                ShifterChannel::new(fft_size, fft_size - hop),
+            ],
+            frame: vec![0.0; fft_size],
+            spectrum: vec![Complex::new(0.0, 0.0); bins],
+            ana_mag: vec![0.0; bins],
+            ana_freq: vec![0.0; bins],
+            syn_mag: vec![0.0; bins],
+            syn_freq: vec![0.0; bins],
+            envelope: vec![0.0; bins],
+        }
+    }
+
+    /// Set pitch shift in semitones (clamped to ±24)
+    pub fn set_semitones(&mut self, semitones: f64) {
+        self.semitones = semitones.clamp(-24.0, 24.0);
+    }
+
+    /// Pitch shift in semitones
+    pub fn semitones(&self) -> f64 {
+        self.semitones
+    }
+
+    /// Set formant shift in semitones relative to the input (clamped to ±24)
+    pub fn set_formant(&mut self, semitones: f64) {
+        self.formant = semitones.clamp(-24.0, 24.0);
+    }
+
+    /// Formant shift in semitones
+    pub fn formant(&self) -> f64 {
+        self.formant
+    }
+
+    /// STFT size in samples
+    pub fn fft_size(&self) -> usize {
+        self.fft_size
+    }
+
+    fn process_channel(&mut self, ch: usize, input: f64) -> f64 {
+        let start = self.fft_size - self.hop;
+        let channel = &mut self.channels[ch];
+
+        channel.in_fifo[channel.rover] = input;
+        let output = channel.out_fifo[channel.rover - start];
+        channel.rover += 1;
+
+        if channel.rover >= self.fft_size {
+            channel.rover = start;
+            self.process_frame(ch);
+        }
+
+        output
+    }
+
+    /// Analyze one STFT frame, move partials by the pitch ratio, resynthesize
+    fn process_frame(&mut self, ch: usize) {
+        let fft_size = self.fft_size;
+        let hop = self.hop;
+        let bins = fft_size / 2 + 1;
+        let bin_hz = self.sample_rate / fft_size as f64;
+        let expected = 2.0 * PI * hop as f64 / fft_size as f64;
+        let pitch_ratio = 2.0_f64.powf(self.semitones / 12.0);
+        let formant_ratio = 2.0_f64.powf(self.formant / 12.0);
+        let channel = &mut self.channels[ch];
+
+        for (i, sample) in self.frame.iter_mut().enumerate() {
+            *sample = channel.in_fifo[i] * self.window[i];
+        }
+        if self
+            .fft_forward
+            .process(&mut self.frame, &mut self.spectrum)
+            .is_err()
+        {
+            return;
+        }
+
+        // Analysis: magnitude + true frequency of each bin
+        for k in 0..bins {
+            let (mag, phase) = self.spectrum[k].to_polar();
+            let mut delta = phase - channel.last_phase[k] - k as f64 * expected;
+            channel.last_phase[k] = phase;
+            delta -= 2.0 * PI * (delta / (2.0 * PI)).round();
+            self.ana_mag[k] = mag;
+            self.ana_freq[k] = (k as f64 + delta / expected) * bin_hz;
+        }
+
+        // Shift partials
+        self.syn_mag.fill(0.0);
+        self.syn_freq.fill(0.0);
+        for k in 0..bins {
+            let target = (k as f64 * pitch_ratio).round() as usize;
+            if target < bins {
+                self.syn_mag[target] += self.ana_mag[k];
+                self.syn_freq[target] = self.ana_freq[k] * pitch_ratio;
+            }
+        }
+
+        // Formant control: the shifted spectrum carries the input envelope
+        // stretched by the pitch ratio; re-shape it to the formant ratio.
+        if (pitch_ratio - formant_ratio).abs() > 1e-9 {
+            smooth_envelope(&self.ana_mag, &mut self.envelope);
+            for k in 0..bins {
+                let carried = sample_envelope(&self.envelope, k as f64 / pitch_ratio);
+                let wanted = sample_envelope(&self.envelope, k as f64 / formant_ratio);
+                if carried > 1e-12 {
+                    self.syn_mag[k] *= (wanted / carried).min(MAX_FORMANT_GAIN);
+                }
+            }
+        }
+
+        // Synthesis: accumulate phase from the shifted true frequencies
+        for k in 0..bins {
+            let deviation = (self.syn_freq[k] / bin_hz - k as f64) * expected;
+            let phase = channel.sum_phase[k] + k as f64 * expected + deviation;
+            channel.sum_phase[k] = phase - 2.0 * PI * (phase / (2.0 * PI)).round();
+            self.spectrum[k] = Complex::from_polar(self.syn_mag[k], channel.sum_phase[k]);
+        }
+        // DC and Nyquist must be real for the inverse real FFT
+        self.spectrum[0].im = 0.0;
+        self.spectrum[bins - 1].im = 0.0;
+
+        if self
+            .fft_inverse
+            .process(&mut self.spectrum, &mut self.frame)
+            .is_err()
+        {
+            return;
+        }
+
+        // Overlap-add (Hann² at 75% overlap sums to 1.5)
+        let norm = 1.0 / (fft_size as f64 * 1.5);
+        for (i, sample) in self.frame.iter().enumerate() {
+            channel.output_accum[i] += self.window[i] * sample * norm;
+        }
+        channel.out_fifo[..hop].copy_from_slice(&channel.output_accum[..hop]);
+        channel.output_accum.copy_within(hop.., 0);
+        let len = channel.output_accum.len();
+        channel.output_accum[len - hop..].fill(0.0);
+        for value in channel.output_accum.iter_mut() {
+            if value.abs() < DENORMAL_THRESHOLD {
+                *value = 0.0;
+            }
+        }
+        channel.in_fifo.copy_within(hop.., 0);
+    }
+}
+
+/// Moving-average spectral envelope of `mag`
+fn smooth_envelope(mag: &[f64], envelope: &mut [f64]) {
+    let n = mag.len();
+    let mut sum: f64 = mag[..ENVELOPE_HALF_WIDTH.min(n)].iter().sum();
+    let mut count = ENVELOPE_HALF_WIDTH.min(n);
+    for k in 0..n {
+        let add = k + ENVELOPE_HALF_WIDTH;
+        if add < n {
+            sum += mag[add];
+            count += 1;
+        }
+        if k > ENVELOPE_HALF_WIDTH {
+            sum -= mag[k - ENVELOPE_HALF_WIDTH - 1];
+            count -= 1;
+        }
+        envelope[k] = sum.max(0.0) / count as f64;
+    }
+}
+
+/// Linearly interpolated envelope value at a fractional bin
+fn sample_envelope(envelope: &[f64], bin: f64) -> f64 {
+    let last = envelope.len() - 1;
+    if bin >= last as f64 {
+        return envelope[last];
+    }
+    let i = bin.max(0.0) as usize;
+    let frac = bin - i as f64;
+    envelope[i] * (1.0 - frac) + envelope[i + 1] * frac
+}
+
+impl Processor for PitchShifter {
+    fn reset(&mut self) {
+        let start = self.fft_size - self.hop;
+        for channel in &mut self.channels {
+            channel.reset(start);
+        }
+    }
+
+    fn latency(&self) -> usize {
+        self.fft_size
+    }
+}
+
+impl StereoProcessor for PitchShifter {
+    fn process_sample(&mut self, left: Sample, right: Sample) -> (Sample, Sample) {
+        (
+            self.process_channel(0, left),
+            self.process_channel(1, right),
+        )
+    }
+}
+
+impl ProcessorConfig for PitchShifter {
+    fn set_sample_rate(&mut self, sample_rate: f64) {
+        self.sample_rate = sample_rate;
+        self.reset();
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // PITCH EDITOR STATE
 // ═══════════════════════════════════════════════════════════════════════════
@@ -907,4 +1230,64 @@ mod tests {
             assert!(conf > 0.5);
         }
     }
+
+    #[test]
+    fn test_pitch_shifter_octave_up() {
+        let sample_rate = 48000.0;
+        let mut shifter = PitchShifter::new(sample_rate);
+        let latency = shifter.latency();
+        shifter.set_semitones(12.0);
+        shifter.set_formant(0.0);
+        assert_eq!(shifter.latency(), latency);
+
+        // 220 Hz with a few harmonics, formants preserved
+        let input: Vec<f64> = (0..16384)
+            .map(|i| {
+                let t = 2.0 * PI * 220.0 * i as f64 / sample_rate;
+                (1..=4)
+                    .map(|h| (t * h as f64).sin() / h as f64)
+                    .sum::<f64>()
+                    * 0.3
+            })
+            .collect();
+        let output: Vec<f64> = input
+            .iter()
+            .map(|&x| shifter.process_sample(x, x).0)
+            .collect();
+
+        let mut detector = PitchDetector::new(sample_rate);
+        let (pitch, _) = detector
+            .detect_frame(&output[8192..12288])
+            .expect("no pitch detected");
+        assert!(
+            (pitch.to_frequency() - 440.0).abs() < 440.0 * 0.03,
+            "detected {} Hz",
+            pitch.to_frequency()
+        );
+
+        // Retuning mid-stream must not move the latency
+        shifter.set_semitones(-5.0);
+        shifter.set_formant(3.0);
+        assert_eq!(shifter.latency(), latency);
+    }
+
+    #[test]
+    fn test_pitch_shifter_reported_latency() {
+        let mut shifter = PitchShifter::new(48000.0);
+        let output: Vec<f64> = (0..4096)
+            .map(|i| {
+                shifter
+                    .process_sample(if i == 0 { 1.0 } else { 0.0 }, 0.0)
+                    .0
+            })
+            .collect();
+
+        let peak = output
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.abs().total_cmp(&b.1.abs()))
+            .map(|(i, _)| i);
+        assert_eq!(peak, Some(shifter.latency()));
+        assert!(output[..shifter.latency()].iter().all(|x| x.abs() < 1e-9));
+    }
 }
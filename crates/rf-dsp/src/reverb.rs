@@ -1643,12 +1643,14 @@ struct FDNDelayLine {
     feedback_allpass: FeedbackAllpass,
     // Delay line jitter offset in samples (R6.4)
     jitter_offset: f64,
+    // One-pole HF damping state in feedback path
+    damping_z1: f64,
 }
 
 impl FDNDelayLine {
     fn new(base_delay: usize, allpass_delay: usize) -> Self {
         // Allocate extra for modulation headroom + cubic interpolation (needs 4 points)
-        let buf_size = base_delay + 68;
+        let buf_size = base_delay + FDN_LINE_HEADROOM;
         Self {
             buffer: vec![0.0; buf_size],
             write_pos: 0,
@@ -1658,6 +1660,7 @@ impl FDNDelayLine {
             dc_prev_out: 0.0,
             feedback_allpass: FeedbackAllpass::new(allpass_delay, 0.15),
             jitter_offset: 0.0,
+            damping_z1: 0.0,
         }
     }
 
@@ -1777,6 +1780,14 @@ impl FDNDelayLine {
         dc_out
     }
 
+    /// One-pole lowpass in feedback path — attenuates highs on every pass
+    /// `coeff` = 0.0 bypasses the filter
+    #[inline(always)]
+    fn apply_damping(&mut self, sample: f64, coeff: f64) -> f64 {
+        self.damping_z1 = sample * (1.0 - coeff) + self.damping_z1 * coeff;
+        self.damping_z1
+    }
+
     fn reset(&mut self) {
         self.buffer.fill(0.0);
         self.write_pos = 0;
@@ -1785,6 +1796,7 @@ impl FDNDelayLine {
         self.dc_prev_out = 0.0;
         self.feedback_allpass.reset();
         self.jitter_offset = 0.0;
+        self.damping_z1 = 0.0;
     }
 }
 
@@ -1908,6 +1920,8 @@ enum FeedbackMode {
 /// - Delay line jitter for comb-filter coloration prevention (R6.4)
 /// - Adaptive feedback ceiling based on decay (R6.5)
 /// - Chorus mode: detuned pitch-shift on 2 lines (R6.6)
+/// - Late LFO: sine modulation of all line lengths, phase-spread per line
+/// - HF damping: one-pole lowpass per line in the feedback path
 #[derive(Debug, Clone)]
 struct FDNCore {
     delay_lines: Vec<FDNDelayLine>,
@@ -1948,8 +1962,23 @@ struct FDNCore {
     nonlinear_drive: f64,
     // Shimmer pitch shifters — one per applicable line (R7.2)
     shimmer_shifters: Vec<ShimmerShifter>,
+    // Late LFO: depth in samples, shared phase (lines are offset by 2π·i/n)
+    lfo_depth_samples: f64,
+    lfo_phase: f64,
+    lfo_increment: f64,
+    // HF damping one-pole coefficient (0.0 = bypass)
+    damping_coeff: f64,
 }
 
+/// Extra delay line samples reserved for modulation + cubic interpolation
+const FDN_LINE_HEADROOM: usize = 68;
+
+/// Late LFO maximum depth in samples @ 48kHz (±0.25ms — subtle, no audible pitch wobble)
+const LATE_MOD_MAX_DEPTH_48K: f64 = 12.0;
+
+/// Damping frequency at or above which the feedback lowpass is bypassed
+const DAMPING_OFF_HZ: f64 = 20000.0;
+
 /// FDN delay lengths (prime-distributed, samples @ 48kHz)
 /// 16 primes covering the range for small/medium/large FDN
 const FDN_BASE_DELAYS: [usize; 16] = [
@@ -2099,6 +2128,10 @@ impl FDNCore {
             feedback_mode: FeedbackMode::Normal,
             nonlinear_drive: 0.0,
             shimmer_shifters: (0..n).map(|_| ShimmerShifter::new()).collect(),
+            lfo_depth_samples: 0.0,
+            lfo_phase: 0.0,
+            lfo_increment: 0.0,
+            damping_coeff: 0.0,
         }
     }
 
@@ -2113,6 +2146,9 @@ impl FDNCore {
         let old_chorus = self.chorus_enabled;
         let old_chorus_depth = self.chorus_depth_samples;
         let old_jitter = self.jitter_amount;
+        let old_lfo_depth = self.lfo_depth_samples;
+        let old_lfo_increment = self.lfo_increment;
+        let old_damping = self.damping_coeff;
         *self = Self::with_config(sample_rate, size, old_matrix);
         self.spin_depth = old_spin;
         self.wander_depth = old_wander;
@@ -2120,6 +2156,9 @@ impl FDNCore {
         self.chorus_enabled = old_chorus;
         self.chorus_depth_samples = old_chorus_depth;
         self.jitter_amount = old_jitter;
+        self.lfo_increment = old_lfo_increment;
+        self.damping_coeff = old_damping;
+        self.set_lfo_depth(old_lfo_depth);
     }
 
     fn set_matrix_type(&mut self, matrix: MixingMatrix) {
@@ -2168,13 +2207,42 @@ impl FDNCore {
         for i in 0..n {
             let dl = &mut self.delay_lines[i];
             dl.base_delay = ((FDN_BASE_DELAYS[i] as f64) * sr_scale * scale) as usize;
-            let needed = dl.base_delay + 68;
+        }
+        self.ensure_headroom();
+    }
+
+    /// Grow delay buffers so base delay + late LFO excursion stays readable
+    fn ensure_headroom(&mut self) {
+        let extra = FDN_LINE_HEADROOM + self.lfo_depth_samples.ceil() as usize;
+        for dl in self.delay_lines.iter_mut().take(self.active_size) {
+            let needed = dl.base_delay + extra;
             if dl.buffer.len() < needed {
                 dl.buffer.resize(needed, 0.0);
             }
         }
     }
 
+    fn set_lfo_depth(&mut self, depth_samples: f64) {
+        self.lfo_depth_samples = depth_samples.max(0.0);
+        self.ensure_headroom();
+    }
+
+    /// Set late LFO modulation — depth in samples, rate in Hz
+    fn set_late_modulation(&mut self, depth_samples: f64, rate_hz: f64, sample_rate: f64) {
+        self.set_lfo_depth(depth_samples);
+        self.lfo_increment = 2.0 * std::f64::consts::PI * rate_hz / sample_rate;
+    }
+
+    /// Set HF damping cutoff (one-pole: a = e^(-2π·fc/fs))
+    fn set_damping_freq(&mut self, freq: f64, sample_rate: f64) {
+        self.damping_coeff = if freq >= DAMPING_OFF_HZ {
+            0.0
+        } else {
+            let fc = freq.clamp(20.0, sample_rate * 0.45);
+            (-2.0 * std::f64::consts::PI * fc / sample_rate).exp()
+        };
+    }
+
     /// Update jitter offsets using velvet noise RNG state (R6.4)
     fn update_jitter(&mut self) {
         if self.jitter_amount <= 0.001 {
@@ -2240,6 +2308,12 @@ impl FDNCore {
             // Add jitter offset (R6.4)
             mod_offset += self.delay_lines[i].jitter_offset;
 
+            // Late LFO: same rate on every line, phase spread breaks up static modes
+            if self.lfo_depth_samples > 0.0 {
+                let offset = 2.0 * std::f64::consts::PI * i as f64 / n as f64;
+                mod_offset += (self.lfo_phase + offset).sin() * self.lfo_depth_samples;
+            }
+
             // Chorus mode (R6.6): add pitch-shift LFO on lines 0 and half
             if self.chorus_enabled && (i == 0 || i == half) {
                 let chorus_mod = if i == 0 {
@@ -2254,6 +2328,14 @@ impl FDNCore {
             outputs[i] = self.delay_lines[i].read_modulated(mod_offset);
         }
 
+        // Advance late LFO
+        if self.lfo_depth_samples > 0.0 {
+            self.lfo_phase += self.lfo_increment;
+            if self.lfo_phase > 2.0 * std::f64::consts::PI {
+                self.lfo_phase -= 2.0 * std::f64::consts::PI;
+            }
+        }
+
         // Advance chorus LFO
         if self.chorus_enabled {
             self.chorus_phase += self.chorus_increment;
@@ -2310,8 +2392,15 @@ impl FDNCore {
                 self.freeze,
             );
 
+            // HF damping — bypassed in freeze so the frozen tail keeps its spectrum
+            let damped = if self.freeze || self.damping_coeff <= 0.0 {
+                shaped
+            } else {
+                self.delay_lines[i].apply_damping(shaped, self.damping_coeff)
+            };
+
             // Allpass in feedback path (R6.3) — adds density to tail
-            let with_allpass = self.delay_lines[i].feedback_allpass.process(shaped);
+            let with_allpass = self.delay_lines[i].feedback_allpass.process(damped);
 
             // Feedback mode processing (R7.2/R7.3): pitch shift or waveshaping
             let with_mode = match self.feedback_mode {
@@ -2365,6 +2454,7 @@ impl FDNCore {
             self.velvet_gens[i].reset(VN_SEEDS[i]);
         }
         self.chorus_phase = 0.0;
+        self.lfo_phase = 0.0;
     }
 }
 
@@ -2566,6 +2656,11 @@ pub struct AlgorithmicReverb {
     fdn_size_param: u8,           // 36: FDN Size (0=Small/4, 1=Medium/8, 2=Large/16)
     matrix_type_param: u8,        // 37: Matrix Type (0=Hadamard, 1=Householder)
 
+    // Late reverb modulation + HF damping
+    late_mod_depth: f64,          // Depth (0.0-1.0 → 0-0.25ms line excursion)
+    late_mod_rate: f64,           // Rate Hz (0.05-5.0)
+    damping_freq: f64,            // Feedback lowpass Hz (500-20000, 20000 = off)
+
     // PreDelay circular buffer
     predelay_buffer_l: Vec<Sample>,
     predelay_buffer_r: Vec<Sample>,
@@ -2643,6 +2738,11 @@ impl AlgorithmicReverb {
             fdn_size_param: 1,  // Medium (8×8)
             matrix_type_param: 0, // Hadamard
 
+            // Late modulation off, damping off
+            late_mod_depth: 0.0,
+            late_mod_rate: 0.5,
+            damping_freq: DAMPING_OFF_HZ,
+
             predelay_buffer_l: vec![0.0; max_predelay.max(1)],
             predelay_buffer_r: vec![0.0; max_predelay.max(1)],
             predelay_pos: 0,
@@ -2719,6 +2819,16 @@ impl AlgorithmicReverb {
         self.fdn.jitter_amount = self.character * 0.5; // 0-50% of max jitter
         self.fdn.update_jitter();
 
+        // Late LFO: smears static FDN modes so the tail doesn't ring metallic
+        let depth_samples =
+            self.late_mod_depth * LATE_MOD_MAX_DEPTH_48K * self.sample_rate / 48000.0;
+        self.fdn
+            .set_late_modulation(depth_samples, self.late_mod_rate, self.sample_rate);
+
+        // HF damping in feedback path
+        self.fdn
+            .set_damping_freq(self.damping_freq, self.sample_rate);
+
         // Chorus / Shimmer mode
         if self.style == ReverbType::Shimmer {
             // Shimmer (R7.2): always-on, ±1200 cents (octave up), slow LFO
@@ -2850,6 +2960,21 @@ impl AlgorithmicReverb {
         self.recalc_internals();
     }
 
+    /// Late reverb modulation: sine LFO on the FDN delay-line lengths
+    /// depth 0.0-1.0 (0 = off, 1 = ±0.25ms), rate 0.05-5.0 Hz
+    pub fn set_modulation(&mut self, depth: f64, rate: f64) {
+        self.late_mod_depth = depth.clamp(0.0, 1.0);
+        self.late_mod_rate = rate.clamp(0.05, 5.0);
+        self.recalc_internals();
+    }
+
+    /// HF damping: feedback lowpass cutoff Hz (500-20000, 20000 = off)
+    /// Highs lose energy on every pass through the FDN
+    pub fn set_hf_damping_hz(&mut self, freq: f64) {
+        self.damping_freq = freq.clamp(500.0, DAMPING_OFF_HZ);
+        self.recalc_internals();
+    }
+
     /// ER Level: early reflections gain (0.0-1.0)
     pub fn set_er_level(&mut self, level: f64) {
         self.er_level = level.clamp(0.0, 1.0);
@@ -2943,6 +3068,12 @@ impl AlgorithmicReverb {
     pub fn wander(&self) -> f64 {
         self.wander
     }
+    pub fn modulation(&self) -> (f64, f64) {
+        (self.late_mod_depth, self.late_mod_rate)
+    }
+    pub fn hf_damping_hz(&self) -> f64 {
+        self.damping_freq
+    }
     pub fn er_level(&self) -> f64 {
         self.er_level
     }
@@ -3330,6 +3461,9 @@ impl ProcessorConfig for AlgorithmicReverb {
             self.predelay_feedback = old.predelay_feedback;
             self.fdn_size_param = old.fdn_size_param;
             self.matrix_type_param = old.matrix_type_param;
+            self.late_mod_depth = old.late_mod_depth;
+            self.late_mod_rate = old.late_mod_rate;
+            self.damping_freq = old.damping_freq;
             self.set_predelay(old.predelay_ms);
             self.set_style(old.style); // Restore per-style ER pattern
             self.update_output_eq();
@@ -4089,6 +4223,92 @@ mod tests {
         assert!(!r.is_nan());
     }
 
+    /// Peak-to-mean magnitude ratio of the late tail spectrum (impulse response)
+    fn tail_resonance_ratio(reverb: &mut AlgorithmicReverb) -> f64 {
+        const TAIL_START: usize = 24000;
+        const TAIL_LEN: usize = 32768;
+        let mut tail = Vec::with_capacity(TAIL_LEN);
+        for n in 0..TAIL_START + TAIL_LEN {
+            let x = if n == 0 { 1.0 } else { 0.0 };
+            let (l, _) = reverb.process_sample(x, x);
+            if n >= TAIL_START {
+                let phase = 2.0 * std::f64::consts::PI * tail.len() as f64 / TAIL_LEN as f64;
+                let w = 0.5 - 0.5 * phase.cos();
+                tail.push(Complex::new(l * w, 0.0));
+            }
+        }
+        FftPlanner::new()
+            .plan_fft_forward(TAIL_LEN)
+            .process(&mut tail);
+        let mags: Vec<f64> = tail[1..TAIL_LEN / 2].iter().map(|c| c.norm()).collect();
+        let peak = mags.iter().cloned().fold(0.0, f64::max);
+        let mean = mags.iter().sum::<f64>() / mags.len() as f64;
+        peak / mean
+    }
+
+    #[test]
+    fn test_late_modulation_reduces_tail_resonance() {
+        let make = || {
+            let mut reverb = AlgorithmicReverb::new(48000.0);
+            reverb.set_mix(1.0);
+            reverb.set_decay(0.9);
+            reverb.set_character(0.0);
+            reverb.set_spin(0.0);
+            reverb.set_wander(0.0);
+            reverb.set_er_level(0.0);
+            reverb
+        };
+
+        let mut dry = make();
+        let static_ratio = tail_resonance_ratio(&mut dry);
+
+        let mut modulated = make();
+        modulated.set_modulation(1.0, 0.7);
+        assert_eq!(modulated.modulation(), (1.0, 0.7));
+        let modulated_ratio = tail_resonance_ratio(&mut modulated);
+
+        assert!(
+            modulated_ratio < static_ratio,
+            "Modulation should flatten tail resonances: static={} modulated={}",
+            static_ratio,
+            modulated_ratio
+        );
+    }
+
+    #[test]
+    fn test_damping_attenuates_tail_highs() {
+        let hf_ratio = |freq: f64| {
+            let mut reverb = AlgorithmicReverb::new(48000.0);
+            reverb.set_mix(1.0);
+            reverb.set_decay(0.8);
+            reverb.set_hf_damping_hz(freq);
+            let mut low = 0.0;
+            let mut high = 0.0;
+            let mut prev = 0.0;
+            for n in 0..48000 {
+                let x = if n == 0 { 1.0 } else { 0.0 };
+                let (l, _) = reverb.process_sample(x, x);
+                if n >= 12000 {
+                    // First difference ≈ HF energy, raw signal ≈ total energy
+                    high += (l - prev) * (l - prev);
+                    low += l * l;
+                }
+                prev = l;
+            }
+            high / low.max(1e-30)
+        };
+
+        assert!((AlgorithmicReverb::new(48000.0).hf_damping_hz() - 20000.0).abs() < 1e-10);
+        let open = hf_ratio(20000.0);
+        let damped = hf_ratio(2000.0);
+        assert!(
+            damped < open * 0.5,
+            "Damping should darken the tail: open={} damped={}",
+            open,
+            damped
+        );
+    }
+
     #[test]
     fn test_fdn_sustained_energy() {
         let mut reverb = AlgorithmicReverb::new(48000.0);
@@ -909,6 +909,30 @@ pub enum NoiseShapeType {
     ModifiedE,
     /// F-weighted (aggressive, for 16-bit)
     FWeighted,
+    /// 5-tap E-weighted (Lipshitz), noise moved out of 2-5 kHz
+    EWeighted,
+    /// 9-tap improved E-weighted (Lipshitz)
+    HighOrder,
+}
+
+/// Longest noise-shaping filter
+const MAX_SHAPE_TAPS: usize = 9;
+
+impl NoiseShapeType {
+    /// Error-feedback coefficients; noise transfer is `1 - Σ c[k] z^-(k+1)`
+    pub fn coefficients(&self) -> &'static [f64] {
+        match self {
+            NoiseShapeType::None => &[],
+            NoiseShapeType::FirstOrder => &[1.0],
+            // Optimized for human hearing, tuned for 44.1/48kHz
+            NoiseShapeType::ModifiedE => &[1.623, -0.982, 0.109],
+            NoiseShapeType::FWeighted => &[2.033, -2.165, 1.959, -0.209],
+            NoiseShapeType::EWeighted => &[2.033, -2.165, 1.959, -1.590, 0.6149],
+            NoiseShapeType::HighOrder => &[
+                2.847, -4.685, 6.214, -7.184, 6.639, -5.032, 3.263, -1.632, 0.4191,
+            ],
+        }
+    }
 }
 
 /// Professional Dither Processor
@@ -926,8 +950,8 @@ pub struct Dither {
     quant_step: f64,
     /// Previous random value (for TPDF)
     prev_rand: f64,
-    /// Error feedback buffer (for noise shaping)
-    error_buf: [f64; 4],
+    /// Past quantization errors, newest first (for noise shaping)
+    error_buf: [f64; MAX_SHAPE_TAPS],
     /// RNG state (xorshift64)
     rng_state: u64,
 }
@@ -949,11 +973,32 @@ impl Dither {
             shape_type,
             quant_step,
             prev_rand: 0.0,
-            error_buf: [0.0; 4],
+            error_buf: [0.0; MAX_SHAPE_TAPS],
             rng_state: 0x853c49e6748fea9b, // Good seed
         }
     }
 
+    /// Use a different noise seed (e.g. to decorrelate stereo channels)
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng_state = seed.max(1);
+        self
+    }
+
+    /// Target bit depth
+    pub fn target_bits(&self) -> u32 {
+        self.target_bits
+    }
+
+    /// Dither type
+    pub fn dither_type(&self) -> DitherType {
+        self.dither_type
+    }
+
+    /// Noise shaping type
+    pub fn shape_type(&self) -> NoiseShapeType {
+        self.shape_type
+    }
+
     /// Fast xorshift64 random
     #[inline(always)]
     fn next_rand(&mut self) -> f64 {
@@ -984,41 +1029,21 @@ impl Dither {
         }
     }
 
-    /// Apply noise shaping
-    fn apply_shaping(&mut self, error: f64) -> f64 {
-        let shaped = match self.shape_type {
-            NoiseShapeType::None => 0.0,
-            NoiseShapeType::FirstOrder => {
-                // Simple first-order: y = x - e[n-1]
-                self.error_buf[0]
-            }
-            NoiseShapeType::ModifiedE => {
-                // Modified E-weighted: optimized for human hearing
-                // Coefficients tuned for 44.1/48kHz
-                1.623 * self.error_buf[0] - 0.982 * self.error_buf[1] + 0.109 * self.error_buf[2]
-            }
-            NoiseShapeType::FWeighted => {
-                // Aggressive F-weighted for 16-bit
-                2.033 * self.error_buf[0] - 2.165 * self.error_buf[1] + 1.959 * self.error_buf[2]
-                    - 0.209 * self.error_buf[3]
-            }
-        };
-
-        // Shift error buffer
-        self.error_buf[3] = self.error_buf[2];
-        self.error_buf[2] = self.error_buf[1];
-        self.error_buf[1] = self.error_buf[0];
-        self.error_buf[0] = error;
-
-        shaped
+    /// Noise shaping feedback: filtered past quantization error
+    fn shaping_feedback(&self) -> f64 {
+        self.shape_type
+            .coefficients()
+            .iter()
+            .zip(self.error_buf.iter())
+            .map(|(c, e)| c * e)
+            .sum()
     }
 
     /// Process single sample
     #[inline]
     pub fn process(&mut self, input: Sample) -> Sample {
         // Add noise shaping feedback
-        let shaped = self.apply_shaping(0.0);
-        let shaped_input = input - shaped;
+        let shaped_input = input - self.shaping_feedback();
 
         // Add dither
         let dithered = shaped_input + self.generate_dither();
@@ -1026,8 +1051,11 @@ impl Dither {
         // Quantize
         let quantized = (dithered / self.quant_step).round() * self.quant_step;
 
-        // Calculate error for feedback
-        let error = quantized - shaped_input;
+        // Calculate error for feedback, bounded so high-order shaping stays
+        // stable when the input clips
+        let limit = 4.0 * self.quant_step;
+        let error = (quantized - shaped_input).clamp(-limit, limit);
+        self.error_buf.copy_within(0..MAX_SHAPE_TAPS - 1, 1);
         self.error_buf[0] = error;
 
         // Clamp to valid range
@@ -1046,7 +1074,7 @@ impl Dither {
 
     pub fn reset(&mut self) {
         self.prev_rand = 0.0;
-        self.error_buf = [0.0; 4];
+        self.error_buf = [0.0; MAX_SHAPE_TAPS];
     }
 }
 
@@ -1166,6 +1194,41 @@ impl Default for SoftClip {
 // COMPLETE SIGNAL CHAIN
 // ═══════════════════════════════════════════════════════════════════════════════
 
+/// What the signal integrity chain did to the last processed block
+///
+/// Intended for a "safety" readout next to the meters.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SignalIntegrityReport {
+    /// DC removed by the DC blocker in millivolts (1.0 FS = 1 V), max of L/R
+    pub dc_removed_mv: f64,
+    /// Auto-gain applied at the end of the block in dB
+    pub auto_gain_db: f64,
+    /// Number of samples the ISP limiter reduced
+    pub isp_reductions: usize,
+    /// Deepest ISP gain reduction in dB (0.0 = none, negative = reduction)
+    pub isp_max_reduction_db: f64,
+    /// Applied dither type (`DitherType::None` when dither is off)
+    pub dither_type: DitherType,
+    /// Applied noise shaping
+    pub noise_shape: NoiseShapeType,
+    /// Dither target bit depth (0 when dither is off)
+    pub dither_bits: u32,
+}
+
+impl Default for SignalIntegrityReport {
+    fn default() -> Self {
+        Self {
+            dc_removed_mv: 0.0,
+            auto_gain_db: 0.0,
+            isp_reductions: 0,
+            isp_max_reduction_db: 0.0,
+            dither_type: DitherType::None,
+            noise_shape: NoiseShapeType::None,
+            dither_bits: 0,
+        }
+    }
+}
+
 /// Complete Signal Integrity Chain
 ///
 /// Combines all processors for ultimate signal quality:
@@ -1179,6 +1242,16 @@ pub struct SignalIntegrityChain {
     pub dither: Option<Dither>,
     /// Bypass entire chain
     pub bypass: bool,
+    /// Enable DC block stage in [`Self::process`]
+    pub dc_block_enabled: bool,
+    /// Enable auto-gain stage in [`Self::process`]
+    pub auto_gain_enabled: bool,
+    /// Enable soft clip stage in [`Self::process`]
+    pub soft_clip_enabled: bool,
+    /// Enable ISP limiter stage in [`Self::process`]
+    pub isp_enabled: bool,
+    /// Report from the last [`Self::process`] call
+    report: SignalIntegrityReport,
 }
 
 impl SignalIntegrityChain {
@@ -1191,6 +1264,11 @@ impl SignalIntegrityChain {
             isp_limiter: IspLimiter::new(sample_rate),
             dither: None, // Only enabled for 16-bit export
             bypass: false,
+            dc_block_enabled: true,
+            auto_gain_enabled: true,
+            soft_clip_enabled: true,
+            isp_enabled: true,
+            report: SignalIntegrityReport::default(),
         }
     }
 
@@ -1230,6 +1308,78 @@ impl SignalIntegrityChain {
         }
     }
 
+    /// Process stereo block in place through all enabled stages
+    ///
+    /// Order: DC Block → Auto-Gain → Soft Clip → ISP Limiter → Dither.
+    /// Returns a report of what each stage did to this block.
+    pub fn process(&mut self, left: &mut [Sample], right: &mut [Sample]) -> SignalIntegrityReport {
+        let len = left.len().min(right.len());
+        let mut report = SignalIntegrityReport::default();
+
+        if self.bypass || len == 0 {
+            self.report = report;
+            return report;
+        }
+
+        // Removed DC = mean of (input - blocked) per channel
+        let mut dc_sum_l = 0.0;
+        let mut dc_sum_r = 0.0;
+        let mut min_gr = 1.0_f64;
+
+        for (l, r) in left[..len].iter_mut().zip(right[..len].iter_mut()) {
+            let (mut sl, mut sr) = (*l, *r);
+
+            if self.dc_block_enabled {
+                let (bl, br) = self.dc_blocker.process(sl, sr);
+                dc_sum_l += sl - bl;
+                dc_sum_r += sr - br;
+                (sl, sr) = (bl, br);
+            }
+
+            if self.auto_gain_enabled {
+                (sl, sr) = self.auto_gain.process_stereo(sl, sr);
+            }
+
+            if self.soft_clip_enabled {
+                (sl, sr) = self.soft_clip.process_stereo(sl, sr);
+            }
+
+            if self.isp_enabled {
+                (sl, sr) = self.isp_limiter.process(sl, sr);
+                if self.isp_limiter.gain_reduction < 1.0 {
+                    report.isp_reductions += 1;
+                    min_gr = min_gr.min(self.isp_limiter.gain_reduction);
+                }
+            }
+
+            if let Some(ref mut dither) = self.dither {
+                (sl, sr) = (dither.process(sl), dither.process(sr));
+            }
+
+            (*l, *r) = (sl, sr);
+        }
+
+        let n = len as f64;
+        report.dc_removed_mv = (dc_sum_l / n).abs().max((dc_sum_r / n).abs()) * 1000.0;
+        if self.auto_gain_enabled {
+            report.auto_gain_db = self.auto_gain.gain_db();
+        }
+        report.isp_max_reduction_db = 20.0 * min_gr.log10();
+        if let Some(ref dither) = self.dither {
+            report.dither_type = dither.dither_type();
+            report.noise_shape = dither.shape_type();
+            report.dither_bits = dither.target_bits();
+        }
+
+        self.report = report;
+        report
+    }
+
+    /// Report from the last [`Self::process`] call
+    pub fn report(&self) -> SignalIntegrityReport {
+        self.report
+    }
+
     /// Get total latency in samples
     pub fn latency(&self) -> usize {
         self.isp_limiter.latency()
@@ -1252,6 +1402,7 @@ impl SignalIntegrityChain {
         if let Some(ref mut dither) = self.dither {
             dither.reset();
         }
+        self.report = SignalIntegrityReport::default();
     }
 }
 
@@ -1806,6 +1957,43 @@ mod tests {
         assert!(error < 1e-10, "Kahan should maintain precision");
     }
 
+    #[test]
+    fn test_chain_process_report() {
+        let sample_rate = 48000.0;
+        let mut chain = SignalIntegrityChain::new(sample_rate);
+        chain.auto_gain.set_target(-3.0);
+        chain.soft_clip_enabled = false;
+        chain.enable_dither(24, NoiseShapeType::None);
+
+        // 1 kHz at +0.2 dBFS riding on a 50 mV DC offset
+        let dc = 0.05;
+        let mut left: Vec<f64> = (0..48000)
+            .map(|i| 1.02 * (2.0 * PI * 1000.0 * i as f64 / sample_rate).sin() + dc)
+            .collect();
+        let mut right = left.clone();
+
+        let mut report = SignalIntegrityReport::default();
+        for (l, r) in left.chunks_mut(480).zip(right.chunks_mut(480)) {
+            report = chain.process(l, r);
+        }
+
+        // Last half second: DC-free and under the -1 dBTP ceiling
+        let tail = &left[24000..];
+        let mean = tail.iter().sum::<f64>() / tail.len() as f64;
+        assert!(mean.abs() < 1e-3, "DC remaining: {}", mean);
+        let threshold = 10.0_f64.powf(-1.0 / 20.0);
+        let peak = tail.iter().fold(0.0_f64, |m, &s| m.max(s.abs()));
+        assert!(peak <= threshold + 1e-3, "peak {} over ceiling", peak);
+
+        assert!((report.dc_removed_mv - dc * 1000.0).abs() < 2.0);
+        assert!(report.auto_gain_db < 0.0);
+        assert!(report.isp_reductions > 0);
+        assert!(report.isp_max_reduction_db < 0.0);
+        assert_eq!(report.dither_type, DitherType::Tpdf);
+        assert_eq!(report.dither_bits, 24);
+        assert_eq!(chain.report(), report);
+    }
+
     #[test]
     fn test_soft_clip() {
         let mut clip = SoftClip::new();
@@ -229,8 +229,10 @@ impl StftProcessor {
 pub struct SpectralGate {
     /// STFT processor
     stft: StftProcessor,
-    /// Threshold (dB)
+    /// Threshold relative to the `learn_noise_*` noise floor (dB)
     threshold_db: f64,
+    /// Threshold above the `learn_profile` noise profile (dB)
+    threshold_offset_db: f64,
     /// Reduction (dB)
     reduction_db: f64,
     /// Attack time (ms)
@@ -241,6 +243,8 @@ pub struct SpectralGate {
     bin_gains: Vec<f64>,
     /// Noise floor estimate per bin
     noise_floor: Vec<f64>,
+    /// Learned noise profile per bin (0 = not learned)
+    noise_profile: Vec<f64>,
     /// Noise estimation buffer
     noise_frames: VecDeque<SpectralFrame>,
     /// Learn noise flag
@@ -274,11 +278,13 @@ impl SpectralGate {
         Self {
             stft: StftProcessor::new(fft_size, hop_size),
             threshold_db: -40.0,
+            threshold_offset_db: 6.0,
             reduction_db: -60.0,
             attack_ms: 10.0,
             release_ms: 100.0,
             bin_gains: vec![1.0; num_bins],
             noise_floor: vec![0.0; num_bins],
+            noise_profile: vec![0.0; num_bins],
             noise_frames: VecDeque::with_capacity(NOISE_FRAMES),
             learning_noise: false,
             sample_rate,
@@ -295,10 +301,21 @@ impl SpectralGate {
     }
 
     /// Set threshold in dB
+    ///
+    /// Relative to the noise floor captured with `learn_noise_start`/
+    /// `learn_noise_stop`; bins without a floor are never gated. Bins with a
+    /// `learn_profile` profile use `set_threshold_offset` instead.
     pub fn set_threshold(&mut self, db: f64) {
         self.threshold_db = db.clamp(-80.0, 0.0);
     }
 
+    /// Set threshold above the learned noise profile in dB
+    ///
+    /// Each bin with a `learn_profile` profile gates below profile + offset.
+    pub fn set_threshold_offset(&mut self, db: f64) {
+        self.threshold_offset_db = db.clamp(-20.0, 40.0);
+    }
+
     /// Set reduction in dB
     pub fn set_reduction(&mut self, db: f64) {
         self.reduction_db = db.clamp(-80.0, 0.0);
@@ -337,9 +354,59 @@ impl SpectralGate {
         }
     }
 
+    /// Learn the noise profile from a noise-only recording.
+    ///
+    /// Averages every full STFT frame of `noise`; each bin then gates at its
+    /// own profile plus the threshold offset, regardless of `threshold_db`.
+    /// Buffers shorter than one FFT frame leave the current profile unchanged.
+    pub fn learn_profile(&mut self, noise: &[Sample]) {
+        let fft_size = self.stft.fft_size;
+        if noise.len() < fft_size {
+            return;
+        }
+
+        self.noise_profile.fill(0.0);
+        let mut frames = 0;
+        for start in (0..=noise.len() - fft_size).step_by(self.stft.hop_size) {
+            self.stft
+                .analyze_into(&noise[start..start + fft_size], &mut self.scratch_frame);
+            for (profile, &mag) in self
+                .noise_profile
+                .iter_mut()
+                .zip(&self.scratch_frame.magnitude)
+            {
+                *profile += mag;
+            }
+            frames += 1;
+        }
+        for profile in &mut self.noise_profile {
+            *profile /= frames as f64;
+        }
+    }
+
+    /// Clear the learned noise profile (back to `threshold_db`)
+    pub fn clear_profile(&mut self) {
+        self.noise_profile.fill(0.0);
+    }
+
+    /// Whether a bin's magnitude opens the gate
+    ///
+    /// A learned profile gates below profile + offset; otherwise the signal
+    /// must exceed the noise floor by the threshold (no floor = always open).
+    #[inline]
+    fn bin_open(mag: f64, profile: f64, noise: f64, threshold: f64, offset: f64) -> bool {
+        if profile > 1e-10 {
+            mag > profile * offset
+        } else {
+            let signal_ratio = if noise > 1e-10 { mag / noise } else { 1000.0 };
+            signal_ratio > threshold
+        }
+    }
+
     fn process_frame(&mut self, frame: &mut SpectralFrame) {
         let num_bins = frame.magnitude.len();
         let threshold_linear = 10.0_f64.powf(self.threshold_db / 20.0);
+        let offset_linear = 10.0_f64.powf(self.threshold_offset_db / 20.0);
         let reduction_linear = 10.0_f64.powf(self.reduction_db / 20.0);
 
         // Time constants
@@ -350,12 +417,15 @@ impl SpectralGate {
 
         for i in 0..num_bins {
             let mag = frame.magnitude[i];
-            let noise = self.noise_floor[i];
-
-            // Signal above noise floor?
-            let signal_ratio = if noise > 1e-10 { mag / noise } else { 1000.0 };
-
-            let target_gain = if signal_ratio > threshold_linear {
+            let open = Self::bin_open(
+                mag,
+                self.noise_profile[i],
+                self.noise_floor[i],
+                threshold_linear,
+                offset_linear,
+            );
+
+            let target_gain = if open {
                 1.0
             } else {
                 reduction_linear
@@ -429,6 +499,7 @@ impl StereoProcessor for SpectralGate {
             {
                 let num_bins = self.scratch_frame.magnitude.len();
                 let threshold_linear = 10.0_f64.powf(self.threshold_db / 20.0);
+                let offset_linear = 10.0_f64.powf(self.threshold_offset_db / 20.0);
                 let reduction_linear = 10.0_f64.powf(self.reduction_db / 20.0);
 
                 let attack_coef = (-1.0
@@ -440,11 +511,15 @@ impl StereoProcessor for SpectralGate {
 
                 for i in 0..num_bins {
                     let mag = self.scratch_frame.magnitude[i];
-                    let noise = self.noise_floor[i];
-
-                    let signal_ratio = if noise > 1e-10 { mag / noise } else { 1000.0 };
-
-                    let target_gain = if signal_ratio > threshold_linear {
+                    let open = Self::bin_open(
+                        mag,
+                        self.noise_profile[i],
+                        self.noise_floor[i],
+                        threshold_linear,
+                        offset_linear,
+                    );
+
+                    let target_gain = if open {
                         1.0
                     } else {
                         reduction_linear
@@ -1254,6 +1329,121 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_spectral_gate_learned_profile() {
+        let sample_rate = 48000.0;
+        let fft_size = DEFAULT_FFT_SIZE;
+
+        // Lowpassed noise: floor is ~20 dB higher in the low bins than the high ones
+        let mut state = 12345u64;
+        let mut lp = 0.0;
+        let noise: Vec<f64> = (0..sample_rate as usize)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                let white = (state >> 11) as f64 / (1u64 << 53) as f64 - 0.5;
+                lp = 0.1 * white + 0.9 * lp;
+                lp
+            })
+            .collect();
+
+        // Bin-centered tone in the quiet part of the spectrum
+        let tone_freq = 341.0 * sample_rate / fft_size as f64;
+        let tone: Vec<f64> = (0..noise.len())
+            .map(|i| 0.05 * (2.0 * PI * tone_freq * i as f64 / sample_rate).sin())
+            .collect();
+
+        let rms = |x: &[f64]| (x.iter().map(|s| s * s).sum::<f64>() / x.len() as f64).sqrt();
+        // Amplitude of `freq` in `x` (single-bin DFT)
+        let amplitude = |x: &[f64], freq: f64| {
+            let (re, im) = x.iter().enumerate().fold((0.0, 0.0), |(re, im), (i, &s)| {
+                let w = 2.0 * PI * freq * i as f64 / sample_rate;
+                (re + s * w.cos(), im - s * w.sin())
+            });
+            2.0 * (re * re + im * im).sqrt() / x.len() as f64
+        };
+
+        let run_with = |input: &[f64], configure: &dyn Fn(&mut SpectralGate)| {
+            let mut gate = SpectralGate::new(sample_rate);
+            gate.set_threshold_offset(10.0);
+            gate.learn_profile(&noise[..sample_rate as usize / 2]);
+            configure(&mut gate);
+            let out: Vec<f64> = input.iter().map(|&s| gate.process_sample(s, s).0).collect();
+            // Skip latency and gain settling
+            out[4 * fft_size..].to_vec()
+        };
+        let run = |input: &[f64]| run_with(input, &|_| {});
+
+        // Noise alone is gated in every bin, loud or quiet
+        let noise_out = run(&noise);
+        assert!(
+            rms(&noise_out) < rms(&noise) * 0.1,
+            "noise rms {} vs {}",
+            rms(&noise_out),
+            rms(&noise)
+        );
+
+        // Tone rides through above its bin's floor
+        let mixed: Vec<f64> = noise.iter().zip(&tone).map(|(n, t)| n + t).collect();
+        let mixed_out = run(&mixed);
+        let tone_out = amplitude(&mixed_out, tone_freq);
+        assert!(tone_out > 0.05 * 0.7, "tone amplitude {}", tone_out);
+
+        // Raising the offset lifts every bin's threshold above the tone
+        let raised_out = run_with(&mixed, &|gate| gate.set_threshold_offset(40.0));
+        let raised_tone = amplitude(&raised_out, tone_freq);
+        assert!(raised_tone < tone_out * 0.1, "raised tone {}", raised_tone);
+
+        // threshold_db does not apply to bins with a learned profile
+        assert_eq!(run_with(&mixed, &|gate| gate.set_threshold(0.0)), mixed_out);
+        assert_eq!(run_with(&mixed, &|gate| gate.set_threshold(-80.0)), mixed_out);
+    }
+
+    #[test]
+    fn test_spectral_gate_threshold_vs_noise_floor() {
+        let sample_rate = 48000.0;
+        let fft_size = DEFAULT_FFT_SIZE;
+
+        let mut state = 987654321u64;
+        let noise: Vec<f64> = (0..sample_rate as usize / 2)
+            .map(|_| {
+                state = state
+                    .wrapping_mul(6364136223846793005)
+                    .wrapping_add(1442695040888963407);
+                ((state >> 11) as f64 / (1u64 << 53) as f64 - 0.5) * 0.2
+            })
+            .collect();
+        // Same noise 12 dB below the learned floor
+        let quiet: Vec<f64> = noise.iter().map(|s| s * 0.25).collect();
+        let rms = |x: &[f64]| (x.iter().map(|s| s * s).sum::<f64>() / x.len() as f64).sqrt();
+
+        let run = |learn: bool, threshold_db: f64| {
+            let mut gate = SpectralGate::new(sample_rate);
+            if learn {
+                gate.learn_noise_start();
+                for &s in &noise {
+                    gate.process_sample(s, s);
+                }
+                gate.learn_noise_stop();
+            }
+            gate.set_threshold(threshold_db);
+            let out: Vec<f64> = quiet.iter().map(|&s| gate.process_sample(s, s).0).collect();
+            rms(&out[4 * fft_size..])
+        };
+        let input_rms = rms(&quiet[4 * fft_size..]);
+
+        // No noise floor: never gated, even at the highest threshold
+        let unlearned = run(false, 0.0);
+        assert!(unlearned > input_rms * 0.8, "unlearned {} vs {}", unlearned, input_rms);
+
+        // With a floor, the threshold is relative to it
+        let low = run(true, -40.0);
+        assert!(low > input_rms * 0.8, "-40 dB {} vs {}", low, input_rms);
+        let high = run(true, 0.0);
+        assert!(high < input_rms * 0.1, "0 dB {} vs {}", high, input_rms);
+    }
+
     #[test]
     fn test_spectral_freeze() {
         let mut freeze = SpectralFreeze::new(48000.0);
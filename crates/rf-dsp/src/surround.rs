@@ -5,6 +5,7 @@
 //! - 7.1 Surround (adds Lss, Rss or Lrs, Rrs)
 //! - Dolby Atmos / Object-based (beds + objects with XYZ position)
 //! - Ambisonics (1st/2nd/3rd order)
+//! - Bass management (Linkwitz-Riley redirect to LFE) and ITU stereo fold-down
 //!
 //! Pan laws:
 //! - VBAP (Vector Base Amplitude Panning)
@@ -12,7 +13,10 @@
 //! - Ambisonics encoding
 
 use rf_core::Sample;
-use std::f64::consts::PI;
+use std::f64::consts::{FRAC_1_SQRT_2, PI};
+
+use crate::MonoProcessor;
+use crate::biquad::BiquadTDF2;
 
 // ═══════════════════════════════════════════════════════════════════════════════
 // CHANNEL LAYOUTS
@@ -108,6 +112,14 @@ impl ChannelLayout {
             _ => vec![], // Ambisonics uses virtual speakers
         }
     }
+
+    /// Index of the LFE channel, if the layout has one
+    pub fn lfe_channel(&self) -> Option<usize> {
+        match self {
+            Self::Surround51 | Self::Surround71 | Self::Surround714 | Self::Surround916 => Some(3),
+            _ => None,
+        }
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -631,6 +643,207 @@ impl AtmosRenderer {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// BASS MANAGEMENT
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Butterworth Q for the two cascaded sections of a Linkwitz-Riley 4th order filter
+const LR4_Q: f64 = FRAC_1_SQRT_2;
+
+/// 4th order Linkwitz-Riley section (two cascaded Butterworth biquads)
+#[derive(Debug, Clone)]
+struct Lr4 {
+    stages: [BiquadTDF2; 2],
+}
+
+impl Lr4 {
+    fn lowpass(freq: f64, sample_rate: f64) -> Self {
+        let mut stage = BiquadTDF2::new(sample_rate);
+        stage.set_lowpass(freq, LR4_Q);
+        Self {
+            stages: [stage.clone(), stage],
+        }
+    }
+
+    fn highpass(freq: f64, sample_rate: f64) -> Self {
+        let mut stage = BiquadTDF2::new(sample_rate);
+        stage.set_highpass(freq, LR4_Q);
+        Self {
+            stages: [stage.clone(), stage],
+        }
+    }
+
+    #[inline]
+    fn process(&mut self, input: Sample) -> Sample {
+        let mid = self.stages[0].process_sample(input);
+        self.stages[1].process_sample(mid)
+    }
+
+    fn reset(&mut self) {
+        for stage in &mut self.stages {
+            stage.reset();
+        }
+    }
+}
+
+/// Redirects low frequencies from all main channels into the LFE
+///
+/// Mains are high-passed and their summed bass low-passed with matching
+/// Linkwitz-Riley 4th order filters, so main + sub add up flat and in phase
+/// at the crossover. The bass of every channel passes through the same
+/// low-pass after summing, so it adds coherently in the LFE.
+#[derive(Debug, Clone)]
+pub struct BassManager {
+    layout: ChannelLayout,
+    crossover: f64,
+    sample_rate: f64,
+    /// Level of redirected bass in the LFE (linear)
+    bass_gain: f64,
+    highpass: Vec<Lr4>,
+    lowpass: Lr4,
+}
+
+impl BassManager {
+    /// Create bass manager with crossover in Hz (clamped to 40-200 Hz)
+    pub fn new(crossover: f64, layout: ChannelLayout, sample_rate: f64) -> Self {
+        let crossover = crossover.clamp(40.0, 200.0);
+        let mains = layout.channel_count();
+        Self {
+            layout,
+            crossover,
+            sample_rate,
+            bass_gain: 1.0,
+            highpass: (0..mains)
+                .map(|_| Lr4::highpass(crossover, sample_rate))
+                .collect(),
+            lowpass: Lr4::lowpass(crossover, sample_rate),
+        }
+    }
+
+    pub fn crossover(&self) -> f64 {
+        self.crossover
+    }
+
+    /// Change crossover frequency (resets filter state)
+    pub fn set_crossover(&mut self, crossover: f64) {
+        *self = Self {
+            bass_gain: self.bass_gain,
+            ..Self::new(crossover, self.layout, self.sample_rate)
+        };
+    }
+
+    /// Level of redirected bass in the LFE (linear)
+    pub fn set_bass_gain(&mut self, gain: f64) {
+        self.bass_gain = gain.max(0.0);
+    }
+
+    /// Process one multichannel frame in place (channel order of the layout)
+    ///
+    /// Layouts without an LFE channel are left untouched.
+    pub fn process_frame(&mut self, frame: &mut [Sample]) {
+        let Some(lfe) = self.layout.lfe_channel() else {
+            return;
+        };
+        if frame.len() < self.highpass.len() {
+            return;
+        }
+
+        let mut bass_sum = 0.0;
+        for (ch, (sample, highpass)) in frame.iter_mut().zip(&mut self.highpass).enumerate() {
+            if ch == lfe {
+                continue;
+            }
+            bass_sum += *sample;
+            *sample = highpass.process(*sample);
+        }
+
+        frame[lfe] += self.lowpass.process(bass_sum) * self.bass_gain;
+    }
+
+    pub fn reset(&mut self) {
+        for filter in &mut self.highpass {
+            filter.reset();
+        }
+        self.lowpass.reset();
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// STEREO DOWNMIX
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Surround to stereo fold-down (ITU-R BS.775)
+///
+/// Fronts go straight to their side, centre and all surrounds/heights at
+/// -3 dB. No phase-shifted matrix terms are used, so the fold-down stays
+/// mono-compatible and bass-managed content does not cancel.
+#[derive(Debug, Clone)]
+pub struct Downmixer {
+    layout: ChannelLayout,
+    /// (to left, to right) gain per input channel
+    coefficients: Vec<(f64, f64)>,
+}
+
+impl Downmixer {
+    pub fn new(layout: ChannelLayout) -> Self {
+        Self::with_lfe_gain(layout, 0.0)
+    }
+
+    /// Create downmixer that also folds in the LFE at `lfe_gain`
+    /// (ITU omits the LFE; monitoring fold-downs often keep it)
+    pub fn with_lfe_gain(layout: ChannelLayout, lfe_gain: f64) -> Self {
+        let lfe = layout.lfe_channel();
+        let coefficients = layout
+            .speaker_positions()
+            .iter()
+            .enumerate()
+            .map(|(ch, &(azimuth, elevation))| {
+                if Some(ch) == lfe {
+                    let gain = lfe_gain * FRAC_1_SQRT_2;
+                    (gain, gain)
+                } else if azimuth == 0.0 {
+                    (FRAC_1_SQRT_2, FRAC_1_SQRT_2)
+                } else if azimuth.abs() <= 30.0 && elevation == 0.0 {
+                    if azimuth < 0.0 {
+                        (1.0, 0.0)
+                    } else {
+                        (0.0, 1.0)
+                    }
+                } else if azimuth < 0.0 {
+                    (FRAC_1_SQRT_2, 0.0)
+                } else {
+                    (0.0, FRAC_1_SQRT_2)
+                }
+            })
+            .collect();
+
+        Self {
+            layout,
+            coefficients,
+        }
+    }
+
+    pub fn layout(&self) -> ChannelLayout {
+        self.layout
+    }
+
+    /// (to left, to right) gain per input channel
+    pub fn coefficients(&self) -> &[(f64, f64)] {
+        &self.coefficients
+    }
+
+    /// Fold one multichannel frame down to stereo
+    #[inline]
+    pub fn process_frame(&self, frame: &[Sample]) -> (Sample, Sample) {
+        frame
+            .iter()
+            .zip(&self.coefficients)
+            .fold((0.0, 0.0), |(l, r), (&x, &(gl, gr))| {
+                (l + x * gl, r + x * gr)
+            })
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // TESTS
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -710,4 +923,52 @@ mod tests {
 
         assert!((norm.distance() - 1.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_bass_management_and_fold_down() {
+        let sample_rate = 48000.0;
+        let layout = ChannelLayout::Surround51;
+        let mut manager = BassManager::new(80.0, layout, sample_rate);
+        let downmixer = Downmixer::new(layout);
+
+        let n = 48000;
+        let tone = |freq: f64, i: usize| (2.0 * PI * freq * i as f64 / sample_rate).sin();
+        let mut energy = [0.0; 6];
+        let mut fold_power = 0.0;
+        let mut dialogue_power = 0.0;
+        for i in 0..n {
+            // 40 Hz in both surrounds, 2 kHz air in Ls, 1 kHz dialogue in C
+            let bass = 0.5 * tone(40.0, i);
+            let mut frame = [0.0, 0.0, 0.5 * tone(1000.0, i), 0.0, bass, bass];
+            frame[4] += 0.1 * tone(2000.0, i);
+
+            let (lo, ro) = downmixer.process_frame(&frame);
+            if i >= n / 2 {
+                fold_power += lo * lo + ro * ro;
+                dialogue_power += frame[2] * frame[2];
+            }
+
+            manager.process_frame(&mut frame);
+            if i >= n / 2 {
+                for (e, x) in energy.iter_mut().zip(frame) {
+                    *e += x * x;
+                }
+            }
+        }
+        let rms = |e: f64| (e / (n / 2) as f64).sqrt();
+
+        // Both surrounds' bass adds in phase: ~2 x 0.5 amplitude in the LFE
+        assert!((rms(energy[3]) - 2.0 * 0.5 * FRAC_1_SQRT_2).abs() < 0.1);
+        // Surrounds keep their highs and lose their bass
+        assert!((rms(energy[4]) - 0.1 * FRAC_1_SQRT_2).abs() < 0.02);
+        assert!(rms(energy[5]) < 0.1 * 0.5 * FRAC_1_SQRT_2);
+        // Dialogue above the crossover is untouched
+        assert!((rms(energy[2]) - 0.5 * FRAC_1_SQRT_2).abs() < 0.01);
+
+        // Fold-down keeps dialogue power: C at -3 dB in both sides
+        let (gl, gr) = downmixer.coefficients()[2];
+        assert!((gl * gl + gr * gr - 1.0).abs() < 1e-9);
+        assert!(fold_power > dialogue_power);
+        assert_eq!(downmixer.coefficients()[3], (0.0, 0.0));
+    }
 }
@@ -9,7 +9,8 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
-use std::time::Instant;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 
 use crossbeam_channel::{Receiver, Sender, bounded};
 use parking_lot::{Mutex, RwLock};
@@ -41,6 +42,18 @@ pub struct ProcessingResult {
     pub outputs: Vec<Vec<Sample>>,
     pub sequence: u64,
     pub actual_time_us: u64,
+    /// Node missed its deadline; `outputs` is the fallback, not fresh audio
+    pub degraded: bool,
+}
+
+/// What a node outputs for a block in which it missed its deadline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DeadlinePolicy {
+    /// Repeat the node's last on-time output (silence if there is none)
+    #[default]
+    HoldLast,
+    /// Output silence
+    Silence,
 }
 
 /// Processing statistics for a node
@@ -54,6 +67,14 @@ pub struct NodeStats {
     pub max_time_us: AtomicU64,
     /// Moving average (exponential)
     pub avg_time_us: AtomicU64,
+    /// Blocks in which the node missed its deadline
+    pub deadline_misses: AtomicU64,
+    /// Node's last block was replaced by its fallback
+    pub degraded: AtomicBool,
+    /// Missed blocks output silence instead of holding the last output
+    silence_on_miss: AtomicBool,
+    /// Deadline override in microseconds (0 = one block)
+    deadline_us: AtomicU64,
 }
 
 impl NodeStats {
@@ -88,6 +109,45 @@ impl NodeStats {
     pub fn average_us(&self) -> u64 {
         self.avg_time_us.load(Ordering::Relaxed)
     }
+
+    /// What the node outputs when it misses its deadline
+    pub fn deadline_policy(&self) -> DeadlinePolicy {
+        if self.silence_on_miss.load(Ordering::Relaxed) {
+            DeadlinePolicy::Silence
+        } else {
+            DeadlinePolicy::HoldLast
+        }
+    }
+}
+
+/// Audio-thread side of `AnticipatoryScheduler::collect_block`
+///
+/// Built on the control thread with `block_collector` and handed to the audio
+/// thread, which owns it exclusively. Node statistics, hold-last buffers and
+/// one result slot per node are allocated up front, so collecting a block
+/// takes no locks and reuses every buffer. Rebuild it when the node set
+/// changes.
+pub struct BlockCollector {
+    nodes: Vec<NodeId>,
+    stats: Vec<Option<Arc<NodeStats>>>,
+    /// Last on-time output per node (hold-last fallback)
+    last_outputs: Vec<Vec<Vec<Sample>>>,
+    /// Node's result for the current block arrived in time
+    received: Vec<bool>,
+    /// One result per node, in `nodes` order
+    results: Vec<ProcessingResult>,
+}
+
+impl BlockCollector {
+    /// Nodes collected each block
+    pub fn nodes(&self) -> &[NodeId] {
+        &self.nodes
+    }
+
+    /// Results of the last collected block, in `nodes` order
+    pub fn results(&self) -> &[ProcessingResult] {
+        &self.results
+    }
 }
 
 /// Anticipatory scheduler configuration
@@ -130,6 +190,12 @@ pub struct SchedulerStats {
     pub last_block_time_us: AtomicU64,
     /// Estimated CPU utilization
     pub cpu_utilization: AtomicU64, // Stored as percentage * 100
+    /// Total node deadline misses
+    pub deadline_misses: AtomicU64,
+    /// Nodes that fell back to hold-last/silence in the last block
+    pub degraded_nodes: AtomicUsize,
+    /// Degraded nodes that came back on time
+    pub recoveries: AtomicU64,
 }
 
 impl SchedulerStats {
@@ -151,8 +217,17 @@ pub struct AnticipatoryScheduler {
     /// Result queue
     result_tx: Sender<ProcessingResult>,
     result_rx: Receiver<ProcessingResult>,
+    /// Spent output buffers handed back to the workers for reuse
+    spare_tx: Sender<Vec<Vec<Sample>>>,
+    spare_rx: Receiver<Vec<Vec<Sample>>>,
     /// Workers running
     running: Arc<AtomicBool>,
+    /// Worker threads
+    workers: Mutex<Vec<JoinHandle<()>>>,
+    /// Reference point for block start times
+    epoch: Instant,
+    /// Start of the last scheduled block (microseconds since `epoch`)
+    block_start_us: AtomicU64,
     /// Global statistics
     stats: Arc<SchedulerStats>,
     /// Block size
@@ -166,6 +241,7 @@ impl AnticipatoryScheduler {
     pub fn new(config: SchedulerConfig, block_size: usize, sample_rate: f64) -> Self {
         let (job_tx, job_rx) = bounded(config.max_queue_size);
         let (result_tx, result_rx) = bounded(config.max_queue_size);
+        let (spare_tx, spare_rx) = bounded(config.max_queue_size);
 
         Self {
             config,
@@ -174,7 +250,12 @@ impl AnticipatoryScheduler {
             job_rx,
             result_tx,
             result_rx,
+            spare_tx,
+            spare_rx,
             running: Arc::new(AtomicBool::new(false)),
+            workers: Mutex::new(Vec::new()),
+            epoch: Instant::now(),
+            block_start_us: AtomicU64::new(0),
             stats: Arc::new(SchedulerStats::default()),
             block_size,
             sample_rate,
@@ -193,6 +274,93 @@ impl AnticipatoryScheduler {
         self.node_stats.write().remove(&node_id);
     }
 
+    /// Set what a registered node outputs when it misses its deadline
+    pub fn set_deadline_policy(&self, node_id: NodeId, policy: DeadlinePolicy) {
+        if let Some(stats) = self.node_stats.read().get(&node_id) {
+            stats
+                .silence_on_miss
+                .store(policy == DeadlinePolicy::Silence, Ordering::Relaxed);
+        }
+    }
+
+    /// Override a registered node's deadline (None = one block)
+    pub fn set_node_deadline(&self, node_id: NodeId, deadline_us: Option<u64>) {
+        if let Some(stats) = self.node_stats.read().get(&node_id) {
+            stats
+                .deadline_us
+                .store(deadline_us.unwrap_or(0), Ordering::Relaxed);
+        }
+    }
+
+    /// Start worker threads that run `processor` on scheduled jobs
+    ///
+    /// `processor` writes a job's outputs into the buffer it is given, which
+    /// is recycled from results `collect_block` has already consumed (empty
+    /// when none is spare).
+    pub fn start_workers<F>(&self, processor: F)
+    where
+        F: Fn(NodeId, &[Vec<Sample>], &mut Vec<Vec<Sample>>) + Send + Sync + 'static,
+    {
+        if self.running.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let processor = Arc::new(processor);
+        let mut workers = self.workers.lock();
+        for index in 0..self.config.num_workers.max(1) {
+            let processor = Arc::clone(&processor);
+            let job_rx = self.job_rx.clone();
+            let result_tx = self.result_tx.clone();
+            let spare_rx = self.spare_rx.clone();
+            let running = Arc::clone(&self.running);
+
+            let spawned = std::thread::Builder::new()
+                .name(format!("anticipatory-{}", index))
+                .spawn(move || {
+                    while running.load(Ordering::Relaxed) {
+                        let Ok(job) = job_rx.recv_timeout(Duration::from_millis(10)) else {
+                            continue;
+                        };
+                        let start = Instant::now();
+                        let mut outputs = spare_rx.try_recv().unwrap_or_default();
+                        processor(job.node_id, &job.inputs, &mut outputs);
+                        let _ = result_tx.try_send(ProcessingResult {
+                            node_id: job.node_id,
+                            outputs,
+                            sequence: job.sequence,
+                            actual_time_us: start.elapsed().as_micros() as u64,
+                            degraded: false,
+                        });
+                    }
+                });
+            match spawned {
+                Ok(handle) => workers.push(handle),
+                Err(e) => log::error!("Failed to spawn anticipatory worker: {}", e),
+            }
+        }
+    }
+
+    /// Stop and join the worker threads
+    pub fn stop_workers(&self) {
+        self.running.store(false, Ordering::SeqCst);
+        for handle in self.workers.lock().drain(..) {
+            let _ = handle.join();
+        }
+    }
+
+    /// Block duration in microseconds
+    pub fn block_budget_us(&self) -> u64 {
+        (self.block_size as f64 / self.sample_rate * 1_000_000.0) as u64
+    }
+
+    /// Whether a node's last block was replaced by its fallback
+    pub fn is_degraded(&self, node_id: NodeId) -> bool {
+        self.node_stats
+            .read()
+            .get(&node_id)
+            .is_some_and(|s| s.degraded.load(Ordering::Relaxed))
+    }
+
     /// Get estimated processing time for a node
     pub fn estimated_time(&self, node_id: NodeId) -> u64 {
         self.node_stats
@@ -214,6 +382,10 @@ impl AnticipatoryScheduler {
 
     /// Schedule jobs for processing
     pub fn schedule(&self, mut jobs: Vec<ProcessingJob>) {
+        // Deadlines in collect_block count from here
+        self.block_start_us
+            .store(self.epoch.elapsed().as_micros() as u64, Ordering::Release);
+
         // Update estimates
         for job in &mut jobs {
             job.estimated_time_us = self.estimated_time(job.node_id);
@@ -254,6 +426,8 @@ impl AnticipatoryScheduler {
 
         // Process sequentially (parallel processing requires thread-safe processor)
         // For true parallel processing, use process_parallel with Arc<Mutex<dyn AudioNode>>
+        // No deadline enforcement here: a late node has already run by the time
+        // it could be detected (use start_workers + collect_block)
         let results: Vec<ProcessingResult> = sorted_jobs
             .into_iter()
             .map(|job| {
@@ -273,6 +447,7 @@ impl AnticipatoryScheduler {
                     outputs,
                     sequence: job.sequence,
                     actual_time_us: elapsed,
+                    degraded: false,
                 }
             })
             .collect();
@@ -284,7 +459,7 @@ impl AnticipatoryScheduler {
             .store(block_time, Ordering::Relaxed);
 
         // Estimate CPU utilization
-        let block_budget_us = (self.block_size as f64 / self.sample_rate * 1_000_000.0) as u64;
+        let block_budget_us = self.block_budget_us();
         let utilization = if block_budget_us > 0 {
             ((block_time as f64 / block_budget_us as f64) * 10000.0) as u64
         } else {
@@ -306,6 +481,139 @@ impl AnticipatoryScheduler {
         results
     }
 
+    /// Build the audio-thread collector for `nodes` (control thread)
+    ///
+    /// Hold-last buffers start as `channels` channels of block-size silence.
+    pub fn block_collector(&self, nodes: &[NodeId], channels: usize) -> BlockCollector {
+        let silence = vec![vec![0.0; self.block_size]; channels];
+        let node_stats = self.node_stats.read();
+        BlockCollector {
+            nodes: nodes.to_vec(),
+            stats: nodes.iter().map(|n| node_stats.get(n).cloned()).collect(),
+            last_outputs: vec![silence.clone(); nodes.len()],
+            received: vec![false; nodes.len()],
+            results: nodes
+                .iter()
+                .map(|&node_id| ProcessingResult {
+                    node_id,
+                    outputs: silence.clone(),
+                    sequence: 0,
+                    actual_time_us: 0,
+                    degraded: false,
+                })
+                .collect(),
+        }
+    }
+
+    /// Collect one block's worker results, enforcing each node's deadline
+    ///
+    /// Waits for the results of the collector's nodes for block `sequence`
+    /// while any missing node's deadline, counted from the last `schedule`
+    /// call, is still ahead. A node whose result is not in by its deadline
+    /// gets its fallback instead, flagged `degraded`: its last on-time output
+    /// (`HoldLast`) or silence of the same shape. Late results from earlier
+    /// blocks are dropped. Results follow the collector's node order.
+    pub fn collect_block<'a>(
+        &self,
+        sequence: u64,
+        collector: &'a mut BlockCollector,
+    ) -> &'a [ProcessingResult] {
+        let block_start =
+            self.epoch + Duration::from_micros(self.block_start_us.load(Ordering::Acquire));
+        let block_budget_us = self.block_budget_us();
+        let BlockCollector {
+            nodes,
+            stats,
+            last_outputs,
+            received,
+            results,
+        } = collector;
+        let deadline = |index: usize| {
+            let us = stats[index]
+                .as_ref()
+                .map(|s| s.deadline_us.load(Ordering::Relaxed))
+                .filter(|&us| us > 0)
+                .unwrap_or(block_budget_us);
+            block_start + Duration::from_micros(us)
+        };
+
+        // Wait while a missing node can still make its deadline
+        received.fill(false);
+        loop {
+            let now = Instant::now();
+            let Some(wait_until) = (0..nodes.len())
+                .filter(|&index| !received[index])
+                .map(deadline)
+                .filter(|&d| d > now)
+                .max()
+            else {
+                break;
+            };
+            let Ok(mut result) = self.result_rx.recv_deadline(wait_until) else {
+                break;
+            };
+            let on_time = nodes
+                .iter()
+                .position(|n| *n == result.node_id)
+                .filter(|&index| {
+                    result.sequence == sequence
+                        && !received[index]
+                        && Instant::now() <= deadline(index)
+                });
+
+            if let Some(index) = on_time {
+                received[index] = true;
+                if let Some(stats) = &stats[index] {
+                    stats.record(result.actual_time_us);
+                    if stats.degraded.swap(false, Ordering::Relaxed) {
+                        self.stats.recoveries.fetch_add(1, Ordering::Relaxed);
+                    }
+                }
+                last_outputs[index].clone_from(&result.outputs);
+                self.stats.jobs_processed.fetch_add(1, Ordering::Relaxed);
+                let slot = &mut results[index];
+                slot.outputs.clone_from(&result.outputs);
+                slot.sequence = result.sequence;
+                slot.actual_time_us = result.actual_time_us;
+                slot.degraded = false;
+            }
+
+            // Hand the worker's buffers back instead of freeing them here
+            let _ = self.spare_tx.try_send(std::mem::take(&mut result.outputs));
+        }
+
+        // Fallbacks reuse the slot's buffers from the previous block
+        let mut degraded_nodes = 0;
+        for index in (0..nodes.len()).filter(|&index| !received[index]) {
+            degraded_nodes += 1;
+            self.stats.deadline_misses.fetch_add(1, Ordering::Relaxed);
+            let silence = match &stats[index] {
+                Some(stats) => {
+                    stats.deadline_misses.fetch_add(1, Ordering::Relaxed);
+                    stats.degraded.store(true, Ordering::Relaxed);
+                    stats.deadline_policy() == DeadlinePolicy::Silence
+                }
+                None => true,
+            };
+            let result = &mut results[index];
+            result.outputs.clone_from(&last_outputs[index]);
+            if silence {
+                result
+                    .outputs
+                    .iter_mut()
+                    .for_each(|channel| channel.fill(0.0));
+            }
+            result.sequence = sequence;
+            result.actual_time_us = block_start.elapsed().as_micros() as u64;
+            result.degraded = true;
+        }
+
+        self.stats
+            .degraded_nodes
+            .store(degraded_nodes, Ordering::Relaxed);
+        results
+    }
+
     /// Get statistics
     pub fn stats(&self) -> &SchedulerStats {
         &self.stats
@@ -321,16 +629,26 @@ impl AnticipatoryScheduler {
         self.stats.jobs_processed.store(0, Ordering::Relaxed);
         self.stats.stolen_jobs.store(0, Ordering::Relaxed);
         self.stats.queue_max.store(0, Ordering::Relaxed);
+        self.stats.deadline_misses.store(0, Ordering::Relaxed);
+        self.stats.degraded_nodes.store(0, Ordering::Relaxed);
+        self.stats.recoveries.store(0, Ordering::Relaxed);
 
         for stats in self.node_stats.read().values() {
             stats.total_time_us.store(0, Ordering::Relaxed);
             stats.block_count.store(0, Ordering::Relaxed);
             stats.max_time_us.store(0, Ordering::Relaxed);
             stats.avg_time_us.store(0, Ordering::Relaxed);
+            stats.deadline_misses.store(0, Ordering::Relaxed);
         }
     }
 }
 
+impl Drop for AnticipatoryScheduler {
+    fn drop(&mut self) {
+        self.stop_workers();
+    }
+}
+
 /// Work-stealing deque for load balancing
 #[allow(dead_code)]
 pub struct WorkStealingDeque<T> {
@@ -491,4 +809,91 @@ mod tests {
         assert_eq!(results.len(), 2);
         assert_eq!(scheduler.stats.jobs_processed.load(Ordering::Relaxed), 2);
     }
+
+    #[test]
+    fn test_deadline_miss_degrades_only_slow_nodes() {
+        let config = SchedulerConfig {
+            num_workers: 4,
+            ..Default::default()
+        };
+        let scheduler = AnticipatoryScheduler::new(config, 256, 48000.0);
+        let (fast, hold, silent) = (NodeId::new(1), NodeId::new(2), NodeId::new(3));
+        let nodes = [fast, hold, silent];
+        for node in nodes {
+            scheduler.register_node(node);
+            // Generous deadline: on-time results never depend on machine load
+            scheduler.set_node_deadline(node, Some(10_000_000));
+        }
+        scheduler.set_deadline_policy(silent, DeadlinePolicy::Silence);
+
+        // Slow nodes stall while the test holds the gate (plugin spike)
+        let gate = Arc::new(RwLock::new(()));
+        let worker_gate = Arc::clone(&gate);
+        let recycled = Arc::new(AtomicUsize::new(0));
+        let worker_recycled = Arc::clone(&recycled);
+        scheduler.start_workers(move |node_id, inputs, outputs| {
+            if node_id != NodeId::new(1) {
+                drop(worker_gate.read());
+            }
+            if !outputs.is_empty() {
+                worker_recycled.fetch_add(1, Ordering::Relaxed);
+            }
+            outputs.resize_with(inputs.len(), Vec::new);
+            for (output, input) in outputs.iter_mut().zip(inputs) {
+                output.clone_from(input);
+            }
+        });
+
+        let mut collector = scheduler.block_collector(&nodes, 1);
+        let mut run_block = |block: u64| {
+            let jobs = nodes
+                .into_iter()
+                .map(|node_id| ProcessingJob {
+                    node_id,
+                    inputs: vec![vec![block as Sample + 1.0; 256]],
+                    sidechains: vec![],
+                    sequence: block,
+                    estimated_time_us: 100,
+                    priority: 0,
+                })
+                .collect();
+            scheduler.schedule(jobs);
+            scheduler
+                .collect_block(block, &mut collector)
+                .iter()
+                .map(|r| (r.node_id, (r.outputs[0][0], r.degraded)))
+                .collect::<HashMap<_, _>>()
+        };
+
+        let block0 = run_block(0);
+        assert!(block0.values().all(|&(v, degraded)| v == 1.0 && !degraded));
+
+        // Spike: the stalled nodes miss a 1 ms deadline while the fast node
+        // still reports within its own
+        let spike = gate.write();
+        scheduler.set_node_deadline(hold, Some(1000));
+        scheduler.set_node_deadline(silent, Some(1000));
+        let block1 = run_block(1);
+        drop(spike);
+        assert_eq!(block1[&fast], (2.0, false));
+        assert_eq!(block1[&hold], (1.0, true));
+        assert_eq!(block1[&silent], (0.0, true));
+        assert_eq!(scheduler.stats().deadline_misses.load(Ordering::Relaxed), 2);
+        assert_eq!(scheduler.stats().degraded_nodes.load(Ordering::Relaxed), 2);
+        assert!(scheduler.is_degraded(hold));
+        assert!(!scheduler.is_degraded(fast));
+
+        // Next block on time: the late block-1 results are dropped, both recover
+        scheduler.set_node_deadline(hold, Some(10_000_000));
+        scheduler.set_node_deadline(silent, Some(10_000_000));
+        let block2 = run_block(2);
+        assert!(block2.values().all(|&(v, degraded)| v == 3.0 && !degraded));
+        assert_eq!(scheduler.stats().degraded_nodes.load(Ordering::Relaxed), 0);
+        assert_eq!(scheduler.stats().recoveries.load(Ordering::Relaxed), 2);
+        assert!(!scheduler.is_degraded(hold));
+        // Consumed results went back to the workers instead of being freed
+        assert!(recycled.load(Ordering::Relaxed) > 0);
+
+        scheduler.stop_workers();
+    }
 }
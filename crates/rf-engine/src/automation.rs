@@ -134,6 +134,33 @@ impl ParamId {
         }
     }
 
+    pub fn track_input_trim(track_id: u64) -> Self {
+        Self {
+            target_id: track_id,
+            target_type: TargetType::Track,
+            param_name: "input_trim".to_string(),
+            slot: None,
+        }
+    }
+
+    pub fn track_phase_invert(track_id: u64) -> Self {
+        Self {
+            target_id: track_id,
+            target_type: TargetType::Track,
+            param_name: "phase_invert".to_string(),
+            slot: None,
+        }
+    }
+
+    pub fn track_mono_sum(track_id: u64) -> Self {
+        Self {
+            target_id: track_id,
+            target_type: TargetType::Track,
+            param_name: "mono_sum".to_string(),
+            slot: None,
+        }
+    }
+
     pub fn plugin_param(track_id: u64, slot: u32, param_name: &str) -> Self {
         Self {
             target_id: track_id,
@@ -409,6 +436,18 @@ pub struct TrimInfo {
     pub delta: f64,
 }
 
+/// Max deviation (normalized) for dropping a recorded point as redundant
+const RECORD_THIN_TOLERANCE: f64 = 1e-3;
+
+/// State of an active recording pass for one parameter
+#[derive(Debug, Clone, Copy)]
+struct RecordPass {
+    /// Last written point
+    last: (u64, f64),
+    /// Point written before `last` in this pass
+    prev: Option<(u64, f64)>,
+}
+
 /// Automation engine
 pub struct AutomationEngine {
     /// All automation lanes
@@ -423,8 +462,8 @@ pub struct AutomationEngine {
     param_modes: RwLock<HashMap<ParamId, AutomationMode>>,
     /// Parameters currently being touched
     touched_params: RwLock<HashMap<ParamId, f64>>,
-    /// Pending changes for recording
-    pending_changes: RwLock<Vec<ParamChange>>,
+    /// Active recording passes per parameter
+    record_passes: RwLock<HashMap<ParamId, RecordPass>>,
     /// Is transport playing
     is_playing: std::sync::atomic::AtomicBool,
     /// Is recording enabled
@@ -442,7 +481,7 @@ impl AutomationEngine {
             mode: RwLock::new(AutomationMode::Read),
             param_modes: RwLock::new(HashMap::new()),
             touched_params: RwLock::new(HashMap::new()),
-            pending_changes: RwLock::new(Vec::new()),
+            record_passes: RwLock::new(HashMap::new()),
             is_playing: std::sync::atomic::AtomicBool::new(false),
             is_recording: std::sync::atomic::AtomicBool::new(false),
             trim_info: RwLock::new(HashMap::new()),
@@ -712,7 +751,7 @@ impl AutomationEngine {
 
         if mode == AutomationMode::Touch {
             self.touched_params.write().remove(param_id);
-            self.commit_pending_changes(param_id);
+            self.record_passes.write().remove(param_id);
         } else if mode == AutomationMode::Trim {
             // Apply trim delta to all points in the range
             if let Some(trim) = self.trim_info.write().remove(param_id) {
@@ -739,72 +778,100 @@ impl AutomationEngine {
         }
     }
 
-    /// Record parameter change
+    /// Record parameter change (only while recording is armed)
     pub fn record_change(&self, param_id: ParamId, value: f64) {
-        if !self.is_playing() || !self.is_recording() {
+        if !self.is_recording() {
             return;
         }
+        self.write_point(&param_id, value);
+    }
 
-        let mode = self.param_mode(&param_id);
+    /// Capture a live parameter move into its lane at the current position
+    ///
+    /// Only writes while the transport plays, according to the parameter's mode:
+    /// - Touch: while touched
+    /// - Latch: from first touch until the transport stops
+    /// - Write: always, without a touch
+    /// - Trim: updates the trim delta applied on release
+    ///
+    /// Existing points between the previous write of the pass and now are
+    /// overwritten, and points collinear with their neighbours are thinned out.
+    /// Returns true if the lane was modified.
+    pub fn write_point(&self, param_id: &ParamId, value: f64) -> bool {
+        if !self.is_playing() {
+            return false;
+        }
 
-        // Handle Trim mode - update delta instead of recording new points
-        if mode == AutomationMode::Trim {
-            if self.touched_params.read().contains_key(&param_id) {
-                let mut trim_info = self.trim_info.write();
-                if let Some(info) = trim_info.get_mut(&param_id) {
+        let mode = self.param_mode(param_id);
+        let touched = self.touched_params.read().contains_key(param_id);
+
+        match mode {
+            AutomationMode::Trim => {
+                if touched && let Some(info) = self.trim_info.write().get_mut(param_id) {
                     // Calculate delta from original value to new value
                     info.delta = value - info.original_value;
                 }
+                return false;
             }
-            return;
+            AutomationMode::Write => {}
+            AutomationMode::Touch | AutomationMode::Latch if touched => {}
+            _ => return false,
         }
 
-        let should_record = match mode {
-            AutomationMode::Write => true,
-            AutomationMode::Touch | AutomationMode::Latch => {
-                self.touched_params.read().contains_key(&param_id)
-            }
-            _ => false,
+        let pos = self.position();
+        let value = value.clamp(0.0, 1.0);
+
+        let mut lanes = self.lanes.write();
+        let Some(lane) = lanes.get_mut(param_id) else {
+            return false;
         };
 
-        if should_record {
-            let change = ParamChange {
-                param_id,
-                value,
-                time_samples: self.position(),
-            };
-            self.pending_changes.write().push(change);
+        let mut passes = self.record_passes.write();
+        // A locate/loop back starts a new pass
+        let mut pass = passes
+            .get(param_id)
+            .copied()
+            .filter(|pass| pos >= pass.last.0);
+        // Another write at the same position replaces the last point
+        if let Some(current) = pass
+            && current.last.0 == pos
+        {
+            pass = current.prev.map(|last| RecordPass { last, prev: None });
         }
-    }
 
-    /// Commit pending changes to automation lane
-    fn commit_pending_changes(&self, param_id: &ParamId) {
-        let mut pending = self.pending_changes.write();
-        let changes: Vec<_> = pending
-            .drain(..)
-            .filter(|c| &c.param_id == param_id)
-            .collect();
+        // Overwrite whatever the lane held since the previous write
+        let overwrite_from = pass.map_or(pos, |pass| pass.last.0 + 1);
+        lane.points
+            .retain(|p| p.time_samples < overwrite_from || p.time_samples > pos);
 
-        if let Some(lane) = self.lanes.write().get_mut(param_id) {
-            for change in changes {
-                lane.add_point(AutomationPoint::new(change.time_samples, change.value));
+        let mut prev = pass.map(|pass| pass.last);
+        if let Some(pass) = pass
+            && let Some((t0, v0)) = pass.prev
+        {
+            // Drop the last point if it lies on the line to the new one
+            let (t1, v1) = pass.last;
+            let frac = (t1 - t0) as f64 / (pos - t0).max(1) as f64;
+            let expected = v0 + (value - v0) * frac;
+            if (expected - v1).abs() <= RECORD_THIN_TOLERANCE {
+                lane.points.retain(|p| p.time_samples != t1);
+                prev = pass.prev;
             }
         }
+
+        lane.add_point(AutomationPoint::new(pos, value));
+        passes.insert(
+            param_id.clone(),
+            RecordPass {
+                last: (pos, value),
+                prev,
+            },
+        );
+        true
     }
 
-    /// Commit all pending changes (call on transport stop)
+    /// End all recording passes and release latched params (call on transport stop)
     pub fn commit_all_pending(&self) {
-        let mut pending = self.pending_changes.write();
-        let changes: Vec<_> = pending.drain(..).collect();
-
-        let mut lanes = self.lanes.write();
-        for change in changes {
-            if let Some(lane) = lanes.get_mut(&change.param_id) {
-                lane.add_point(AutomationPoint::new(change.time_samples, change.value));
-            }
-        }
-
-        // Release all touched params in Latch mode
+        self.record_passes.write().clear();
         self.touched_params.write().clear();
     }
 
@@ -821,7 +888,7 @@ impl AutomationEngine {
     /// Clear all automation
     pub fn clear_all(&self) {
         self.lanes.write().clear();
-        self.pending_changes.write().clear();
+        self.record_passes.write().clear();
         self.touched_params.write().clear();
     }
 
@@ -1942,6 +2009,94 @@ mod tests {
         assert_eq!(engine.param_mode(&param_id), AutomationMode::Touch);
     }
 
+    #[test]
+    fn test_touch_recording_writes_only_while_touched() {
+        let engine = AutomationEngine::new(48000.0);
+        let param_id = ParamId::track_volume(1);
+        engine.get_or_create_lane(param_id.clone(), "Volume");
+        engine.add_point(&param_id, AutomationPoint::new(0, 0.5));
+        engine.add_point(&param_id, AutomationPoint::new(48000, 0.5));
+        engine.set_param_mode(param_id.clone(), AutomationMode::Touch);
+        engine.set_playing(true);
+
+        // Play 1s in 512-sample blocks; the fader is held from 0.25s to 0.5s
+        let (touch_start, touch_end) = (12000, 24000);
+        let mut pos = 0;
+        while pos < 48000 {
+            engine.set_position(pos);
+            if pos >= touch_start && pos < touch_end {
+                if !engine.touched_params.read().contains_key(&param_id) {
+                    engine.touch_param(param_id.clone(), 0.5);
+                }
+                // Ramp up, then hold
+                let value = (0.5 + (pos - touch_start) as f64 / 24000.0).min(0.7);
+                engine.write_point(&param_id, value);
+            } else {
+                engine.release_param(&param_id);
+                // Moves outside the touch window are ignored
+                assert!(!engine.write_point(&param_id, 0.9));
+            }
+            pos += 512;
+        }
+
+        let lane = engine.lane(&param_id).unwrap();
+        let recorded: Vec<_> = lane
+            .points
+            .iter()
+            .filter(|p| p.time_samples != 0 && p.time_samples != 48000)
+            .collect();
+        assert!(!recorded.is_empty());
+        assert!(
+            recorded
+                .iter()
+                .all(|p| p.time_samples >= touch_start && p.time_samples < touch_end)
+        );
+        // The linear ramp and the hold collapse to a handful of points
+        assert!(recorded.len() <= 4, "{} points", recorded.len());
+        assert!((lane.value_at(18000) - 0.7).abs() < 0.01);
+        // Points outside the touch window are untouched
+        assert_eq!(lane.points.first().unwrap().value, 0.5);
+        assert_eq!(lane.points.last().unwrap().time_samples, 48000);
+        assert_eq!(lane.points.last().unwrap().value, 0.5);
+    }
+
+    #[test]
+    fn test_latch_and_write_recording() {
+        let engine = AutomationEngine::new(48000.0);
+        let param_id = ParamId::track_pan(1);
+        engine.get_or_create_lane(param_id.clone(), "Pan");
+        engine.set_playing(true);
+
+        // Latch keeps writing after release until transport stop
+        engine.set_param_mode(param_id.clone(), AutomationMode::Latch);
+        engine.set_position(100);
+        assert!(!engine.write_point(&param_id, 0.2));
+        engine.touch_param(param_id.clone(), 0.2);
+        assert!(engine.write_point(&param_id, 0.2));
+        engine.release_param(&param_id);
+        engine.set_position(200);
+        assert!(engine.write_point(&param_id, 0.8));
+        engine.commit_all_pending();
+        engine.set_position(300);
+        assert!(!engine.write_point(&param_id, 0.3));
+
+        // Write overwrites existing points without a touch
+        engine.set_param_mode(param_id.clone(), AutomationMode::Write);
+        engine.add_point(&param_id, AutomationPoint::new(150, 1.0));
+        engine.set_position(100);
+        assert!(engine.write_point(&param_id, 0.0));
+        engine.set_position(200);
+        assert!(engine.write_point(&param_id, 0.0));
+        let times: Vec<_> = engine
+            .lane(&param_id)
+            .unwrap()
+            .points
+            .iter()
+            .map(|p| p.time_samples)
+            .collect();
+        assert_eq!(times, vec![100, 200]);
+    }
+
     #[test]
     fn test_automation_block() {
         let block = AutomationBlock {
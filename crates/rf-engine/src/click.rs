@@ -364,6 +364,27 @@ impl CountInMode {
     }
 }
 
+/// Count-in progress event for UI countdown / record arming
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CountInEvent {
+    /// A count-in beat was clicked
+    Beat {
+        /// 0-based beat index within the count-in
+        index: u32,
+        /// Total count-in beats
+        total: u32,
+        /// Beat within the bar (0 = downbeat)
+        beat_in_bar: u8,
+        /// Count-in sample position of the beat
+        sample: u64,
+    },
+    /// Count-in finished — recording starts on this downbeat
+    RecordStart {
+        /// Count-in sample position of the downbeat
+        sample: u64,
+    },
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // CLICK TRACK
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -482,8 +503,11 @@ pub struct ClickTrack {
     count_in_beats_played: u32,
     /// Sample position within count-in (independent from transport)
     count_in_sample_pos: u64,
-    /// Last tick that triggered during count-in
-    count_in_last_tick: u64,
+    /// Beat/record-start events not yet drained by the UI
+    /// (capacity reserved at start, so the audio thread never allocates)
+    count_in_events: Vec<CountInEvent>,
+    /// Block frame where recording starts, set by the block that ends count-in
+    record_start_frame: Option<usize>,
 
     // ── Tap tempo state ──
     /// Last 8 tap timestamps (milliseconds since epoch)
@@ -524,7 +548,8 @@ impl ClickTrack {
             count_in_total_beats: 0,
             count_in_beats_played: 0,
             count_in_sample_pos: 0,
-            count_in_last_tick: u64::MAX,
+            count_in_events: Vec::new(),
+            record_start_frame: None,
             // Tap tempo
             tap_times: [0; 8],
             tap_count: 0,
@@ -689,16 +714,28 @@ impl ClickTrack {
 
     // ── Count-In ──
 
-    /// Start a count-in sequence (called from FFI before transport starts)
-    pub fn start_count_in(&mut self) {
-        let total = self.count_in.beats(self.beats_per_bar);
+    /// Start a count-in of `bars` bars at the current time signature.
+    /// Recording starts on the downbeat right after the last count-in beat.
+    pub fn start_count_in(&mut self, bars: u32) {
+        self.begin_count_in(bars * self.beats_per_bar as u32);
+    }
+
+    /// Start a count-in using the configured `CountInMode`
+    /// (called from FFI before transport starts)
+    pub fn start_count_in_from_mode(&mut self) {
+        self.begin_count_in(self.count_in.beats(self.beats_per_bar));
+    }
+
+    fn begin_count_in(&mut self, total: u32) {
         if total == 0 {
             return; // Count-in is Off
         }
         self.count_in_total_beats = total;
         self.count_in_beats_played = 0;
         self.count_in_sample_pos = 0;
-        self.count_in_last_tick = u64::MAX;
+        self.count_in_events.clear();
+        self.count_in_events.reserve(total as usize + 1);
+        self.record_start_frame = None;
         self.count_in_active.store(true, Ordering::Release);
     }
 
@@ -715,47 +752,68 @@ impl ClickTrack {
         self.count_in_beats_played as i32
     }
 
+    /// Total beats of the current/last count-in
+    pub fn get_count_in_total_beats(&self) -> u32 {
+        self.count_in_total_beats
+    }
+
+    /// Take pending count-in events (oldest first)
+    pub fn drain_count_in_events(&mut self) -> Vec<CountInEvent> {
+        // append() keeps the reserved capacity for the audio thread
+        let mut events = Vec::with_capacity(self.count_in_events.len());
+        events.append(&mut self.count_in_events);
+        events
+    }
+
+    /// Frame within the last processed block where recording starts
+    /// (`Some` only for the block in which count-in completed)
+    pub fn record_start_frame(&self) -> Option<usize> {
+        self.record_start_frame
+    }
+
     /// Process count-in audio (independent sample position, no transport advance).
     /// Returns true when count-in is complete.
+    ///
+    /// Beat `n` falls on sample `round(n * samples_per_beat)`; the beat after
+    /// the last count-in beat is the record-start downbeat. The rest of the
+    /// completing block keeps rendering the click tail.
     fn process_count_in_block(
         &mut self,
         output_l: &mut [Sample],
         output_r: &mut [Sample],
         frames: usize,
     ) -> bool {
+        self.record_start_frame = None;
         let tempo = self.get_tempo();
         if tempo <= 0.0 {
             return false;
         }
 
-        let sample_rate = self.sample_rate as f64;
-        let ppq = self.ppq as f64;
-        let samples_per_tick = (sample_rate * 60.0) / (tempo * ppq);
-        let ticks_per_beat = self.ppq as u64;
+        let samples_per_beat = self.sample_rate as f64 * 60.0 / tempo;
+        let beat_sample = |beat: u32| (beat as f64 * samples_per_beat).round() as u64;
 
         for frame in 0..frames {
             let sample_pos = self.count_in_sample_pos;
-            let tick = (sample_pos as f64 / samples_per_tick) as u64;
-
-            if tick != self.count_in_last_tick && tick.is_multiple_of(ticks_per_beat) {
-                // A new beat in count-in
-                let beat_in_bar = (self.count_in_beats_played % self.beats_per_bar as u32) as u8;
-                let is_downbeat = beat_in_bar == 0;
-                self.trigger(beat_in_bar, is_downbeat, false);
-                self.count_in_last_tick = tick;
-                self.count_in_beats_played += 1;
 
+            if self.record_start_frame.is_none()
+                && sample_pos >= beat_sample(self.count_in_beats_played)
+            {
                 if self.count_in_beats_played >= self.count_in_total_beats {
-                    // Count-in complete — render remaining sound then signal done
-                    self.render_sample(output_l, output_r, frame);
-                    self.count_in_sample_pos += 1;
-                    // Render rest of block
-                    for f2 in (frame + 1)..frames {
-                        self.render_sample(output_l, output_r, f2);
-                        self.count_in_sample_pos += 1;
-                    }
+                    // Downbeat after the count-in — recording starts here
+                    self.push_count_in_event(CountInEvent::RecordStart { sample: sample_pos });
+                    self.record_start_frame = Some(frame);
                     self.count_in_active.store(false, Ordering::Release);
-                    return true;
+                } else {
+                    let beat_in_bar =
+                        (self.count_in_beats_played % self.beats_per_bar.max(1) as u32) as u8;
+                    self.trigger(beat_in_bar, beat_in_bar == 0, false);
+                    self.push_count_in_event(CountInEvent::Beat {
+                        index: self.count_in_beats_played,
+                        total: self.count_in_total_beats,
+                        beat_in_bar,
+                        sample: sample_pos,
+                    });
+                    self.count_in_beats_played += 1;
                 }
             }
 
@@ -763,7 +821,14 @@ impl ClickTrack {
             self.count_in_sample_pos += 1;
         }
 
-        false
+        self.record_start_frame.is_some()
+    }
+
+    /// Queue a count-in event without growing past the reserved capacity
+    fn push_count_in_event(&mut self, event: CountInEvent) {
+        if self.count_in_events.len() < self.count_in_events.capacity() {
+            self.count_in_events.push(event);
+        }
     }
 
     /// Render one sample of the current click sound at frame index
@@ -1198,6 +1263,55 @@ mod tests {
         assert_eq!(CountInMode::TwoBars.beats(3), 6);
     }
 
+    #[test]
+    fn test_count_in_bars_record_start_on_downbeat() {
+        let mut click = ClickTrack::new(48000);
+        click.set_enabled(true);
+        click.set_tempo(120.0);
+        click.start_count_in(2);
+        assert!(click.is_count_in_active());
+
+        // Odd block size so record-start lands mid-block
+        let block = 441;
+        let mut left = vec![0.0; block];
+        let mut right = vec![0.0; block];
+        let mut start = None;
+        for n in 0..1000 {
+            if click.process_block(&mut left, &mut right, 0, block, true) {
+                start = click.record_start_frame().map(|f| (n * block + f) as u64);
+                break;
+            }
+        }
+
+        // 2 bars of 4/4 at 120 BPM = 8 beats of 24000 samples
+        let events = click.drain_count_in_events();
+        let beats: Vec<_> = events
+            .iter()
+            .filter_map(|e| match *e {
+                CountInEvent::Beat {
+                    index,
+                    beat_in_bar,
+                    sample,
+                    ..
+                } => Some((index, beat_in_bar, sample)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(beats.len(), 8);
+        for (i, &(index, beat_in_bar, sample)) in beats.iter().enumerate() {
+            assert_eq!(index, i as u32);
+            assert_eq!(beat_in_bar, (i % 4) as u8);
+            assert_eq!(sample, i as u64 * 24000);
+        }
+
+        assert_eq!(
+            events.last(),
+            Some(&CountInEvent::RecordStart { sample: 192000 })
+        );
+        assert_eq!(start, Some(192000));
+        assert!(!click.is_count_in_active());
+    }
+
     #[test]
     fn test_click_trigger() {
         let click = ClickTrack::new(48000);
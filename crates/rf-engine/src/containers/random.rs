@@ -292,9 +292,9 @@ pub struct RandomContainer {
     pub mode: RandomMode,
     /// Child sounds
     pub children: SmallVec<[RandomChild; MAX_RANDOM_CHILDREN]>,
-    /// Avoid consecutive repeats (for Random mode)
+    /// Never play the same child twice in a row (for Random mode)
     pub avoid_repeat: bool,
-    /// Number of recent plays to avoid (for avoid_repeat)
+    /// Number of most recent plays that can't be selected (for Random mode)
     pub avoid_repeat_count: usize,
     /// Global pitch variation (applied on top of per-child)
     pub global_pitch_min: f64,
@@ -438,9 +438,10 @@ impl RandomContainer {
 
         // Update history
         self.last_selected = Some(selected_id);
-        if self.avoid_repeat_count > 0 {
+        let window = self.history_window();
+        if window > 0 {
             self.recent_history.push(selected_id);
-            if self.recent_history.len() > self.avoid_repeat_count {
+            if self.recent_history.len() > window {
                 self.recent_history.remove(0);
             }
         }
@@ -462,53 +463,50 @@ impl RandomContainer {
         self.rng_state = state.max(1);
     }
 
+    /// Number of recent plays excluded from Random selection
+    fn history_window(&self) -> usize {
+        self.avoid_repeat_count.max(self.avoid_repeat as usize)
+    }
+
     /// Weighted random selection
+    ///
+    /// Recently played children are excluded and the weights of the rest are
+    /// renormalized. At least one child always stays eligible, so the window
+    /// shrinks when it would exclude every child with a non-zero weight.
     fn select_random(&mut self) -> Option<ChildId> {
-        let total_weight: f64 = self.children.iter().map(|c| c.weight).sum();
-        if total_weight <= 0.0 {
-            return None;
-        }
-
-        let mut attempts = 0;
-        const MAX_ATTEMPTS: usize = 10;
+        let r = self.next_random();
+        let mut exclude = self
+            .history_window()
+            .min(self.recent_history.len())
+            .min(self.children.len().saturating_sub(1));
 
         loop {
-            let r = self.next_random() * total_weight;
-            let mut cumulative = 0.0;
-
-            for child in &self.children {
-                cumulative += child.weight;
-                if r < cumulative {
-                    // Check avoid_repeat
-                    if self.avoid_repeat && Some(child.id) == self.last_selected {
-                        attempts += 1;
-                        if attempts >= MAX_ATTEMPTS || self.children.len() == 1 {
-                            return Some(child.id);
-                        }
-                        break; // Try again
-                    }
-
-                    // Check recent history
-                    if self.avoid_repeat_count > 0
-                        && self.recent_history.contains(&child.id)
-                        && self.children.len() > self.avoid_repeat_count
-                    {
-                        attempts += 1;
-                        if attempts >= MAX_ATTEMPTS {
-                            return Some(child.id);
-                        }
-                        break;
+            let recent = &self.recent_history[self.recent_history.len() - exclude..];
+            let eligible = self
+                .children
+                .iter()
+                .filter(|c| c.weight > 0.0 && !recent.contains(&c.id));
+            let total_weight: f64 = eligible.clone().map(|c| c.weight).sum();
+
+            if total_weight > 0.0 {
+                let target = r * total_weight;
+                let mut cumulative = 0.0;
+                let mut last = None;
+                for child in eligible {
+                    cumulative += child.weight;
+                    if target < cumulative {
+                        return Some(child.id);
                     }
-
-                    return Some(child.id);
+                    last = Some(child.id);
                 }
+                // r == 1.0 lands past the final boundary
+                return last;
             }
 
-            attempts += 1;
-            if attempts >= MAX_ATTEMPTS {
-                // Fallback: return first valid child
-                return self.children.first().map(|c| c.id);
+            if exclude == 0 {
+                return None;
             }
+            exclude -= 1;
         }
     }
 
@@ -525,12 +523,11 @@ impl RandomContainer {
                 self.shuffle_deck.swap(i, j.min(i));
             }
 
-            // Move last played to end (avoid consecutive repeat)
-            if let Some(last) = self.last_selected
-                && let Some(pos) = self.shuffle_deck.iter().position(|&id| id == last)
-                    && pos == 0 && self.shuffle_deck.len() > 1 {
-                        self.shuffle_deck.swap(0, 1);
-                    }
+            // Move last played away from the top (avoid consecutive repeat)
+            let top = n.saturating_sub(1);
+            if n > 1 && self.last_selected == Some(self.shuffle_deck[top]) {
+                self.shuffle_deck.swap(0, top);
+            }
         }
 
         self.shuffle_deck.pop()
@@ -670,5 +667,84 @@ mod tests {
         }
         let unique: std::collections::HashSet<_> = first_cycle.iter().collect();
         assert_eq!(unique.len(), 3);
+
+        // Deck refills never repeat across the boundary
+        let mut last = *first_cycle.last().unwrap();
+        for _ in 0..300 {
+            let id = container.select().unwrap().child_id;
+            assert_ne!(id, last);
+            last = id;
+        }
+    }
+
+    #[test]
+    fn test_weighted_no_repeat_distribution() {
+        let weights = [1.0, 2.0, 3.0, 4.0];
+        let total: f64 = weights.iter().sum();
+
+        let mut container = RandomContainer::new(1, "footsteps");
+        container.seed(777);
+        for (i, &w) in weights.iter().enumerate() {
+            let mut child = RandomChild::with_weight(i as ChildId, format!("step_{}", i), w);
+            child.variation = RandomVariation::new(-1.0, 1.0, -6.0, 0.0);
+            container.add_child(child);
+        }
+
+        // Count transitions previous -> next
+        let draws = 200_000;
+        let mut counts = [[0usize; 4]; 4];
+        let mut prev = container.select().unwrap().child_id as usize;
+        for _ in 0..draws {
+            let result = container.select().unwrap();
+            let next = result.child_id as usize;
+            assert_ne!(next, prev, "immediate repeat");
+            assert!((-1.0..=1.0).contains(&result.pitch_offset));
+            assert!((-6.0..=0.0).contains(&result.volume_offset));
+            counts[prev][next] += 1;
+            prev = next;
+        }
+
+        // After child i, child j is drawn with weight renormalized over the rest
+        for (i, row) in counts.iter().enumerate() {
+            let row_total: usize = row.iter().sum();
+            for (j, &count) in row.iter().enumerate() {
+                let expected = if i == j {
+                    0.0
+                } else {
+                    weights[j] / (total - weights[i])
+                };
+                let actual = count as f64 / row_total as f64;
+                assert!(
+                    (actual - expected).abs() < 0.02,
+                    "{} -> {}: {:.3} vs {:.3}",
+                    i,
+                    j,
+                    actual,
+                    expected
+                );
+            }
+        }
+
+        // Avoiding the last two plays
+        container.avoid_repeat_count = 2;
+        container.reset_state();
+        let mut history = vec![];
+        for _ in 0..1000 {
+            let id = container.select().unwrap().child_id;
+            assert!(!history.iter().rev().take(2).any(|&h| h == id));
+            history.push(id);
+        }
+
+        // Without repeat avoidance the marginal follows the raw weights
+        container.avoid_repeat = false;
+        container.avoid_repeat_count = 0;
+        let mut marginal = [0usize; 4];
+        for _ in 0..draws {
+            marginal[container.select().unwrap().child_id as usize] += 1;
+        }
+        for (i, &count) in marginal.iter().enumerate() {
+            let actual = count as f64 / draws as f64;
+            assert!((actual - weights[i] / total).abs() < 0.01);
+        }
     }
 }
@@ -4,7 +4,7 @@
 //! Provides lock-free parameter updates and command queue integration.
 #![allow(clippy::erasing_op, clippy::identity_op)]
 
-use crate::insert_chain::InsertProcessor;
+use crate::insert_chain::{InsertProcessor, ParameterInfo};
 use rf_core::Sample;
 use rf_dsp::delay_compensation::LatencySamples;
 use rf_dsp::eq_room::RoomCorrectionEq;
@@ -43,6 +43,32 @@ impl Default for AtomicF64 {
     }
 }
 
+// ============ Parameter Descriptor Tables ============
+
+/// Build a descriptor table laid out as `head`, then `bands` copies of `band`, then `tail`
+const fn param_table<const N: usize>(
+    head: &[ParameterInfo],
+    band: &[ParameterInfo],
+    bands: usize,
+    tail: &[ParameterInfo],
+) -> [ParameterInfo; N] {
+    assert!(N == head.len() + band.len() * bands + tail.len());
+    let band_end = head.len() + band.len() * bands;
+    let mut out = [ParameterInfo::toggle("", false); N];
+    let mut i = 0;
+    while i < N {
+        out[i] = if i < head.len() {
+            head[i]
+        } else if i < band_end {
+            band[(i - head.len()) % band.len()]
+        } else {
+            tail[i - band_end]
+        };
+        i += 1;
+    }
+    out
+}
+
 // ============ ProEQ Wrapper ============
 
 /// Professional 64-band EQ wrapper
@@ -135,6 +161,34 @@ impl ProEqWrapper {
     }
 }
 
+const PRO_EQ_BAND_PARAMS: &[ParameterInfo] = &[
+    ParameterInfo::log("Frequency", "Hz", 10.0, 30000.0, 1000.0),
+    ParameterInfo::linear("Gain", "dB", -30.0, 30.0, 0.0),
+    ParameterInfo::log("Q", "", 0.05, 50.0, 1.0),
+    ParameterInfo::toggle("Enabled", false),
+    ParameterInfo::choice("Shape", 10, 0),
+    ParameterInfo::toggle("Dynamic Enabled", false),
+    ParameterInfo::linear("Dynamic Threshold", "dB", -60.0, 0.0, -20.0),
+    ParameterInfo::linear("Dynamic Ratio", "", 1.0, 20.0, 2.0),
+    ParameterInfo::log("Dynamic Attack", "ms", 0.1, 500.0, 5.0),
+    ParameterInfo::log("Dynamic Release", "ms", 1.0, 5000.0, 50.0),
+    ParameterInfo::linear("Dynamic Knee", "dB", 0.0, 24.0, 6.0),
+    ParameterInfo::choice("Placement", 5, 0),
+];
+const PRO_EQ_GLOBAL_PARAMS: &[ParameterInfo] = &[
+    ParameterInfo::linear("Output Gain", "dB", -24.0, 24.0, 0.0),
+    ParameterInfo::toggle("Auto-Gain", false),
+    ParameterInfo::stepped("Solo Band", "", -1, 63, -1),
+];
+
+/// Per-band block repeated for every band slot, followed by the globals
+static PRO_EQ_PARAMS: [ParameterInfo; rf_dsp::PRO_EQ_MAX_BANDS * 12 + 3] = param_table(
+    &[],
+    PRO_EQ_BAND_PARAMS,
+    rf_dsp::PRO_EQ_MAX_BANDS,
+    PRO_EQ_GLOBAL_PARAMS,
+);
+
 impl InsertProcessor for ProEqWrapper {
     fn name(&self) -> &str {
         "FluxForge Studio Pro-EQ 64"
@@ -191,6 +245,10 @@ impl InsertProcessor for ProEqWrapper {
         self.eq.set_sample_rate(sample_rate);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &PRO_EQ_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         // 12 params per band: freq, gain, q, enabled, shape, dynEnabled, dynThreshold, dynRatio, dynAttack, dynRelease, dynKnee, placement
         // + 3 global params
@@ -362,6 +420,12 @@ impl InsertProcessor for ProEqWrapper {
             _ => "",
         }
     }
+
+    /// Meter index = band index: current dynamic gain offset of that band (dB,
+    /// negative = cut). 0.0 for static or disabled bands.
+    fn get_meter(&self, index: usize) -> f64 {
+        self.eq.band(index).map_or(0.0, |b| b.dynamic_gain_db())
+    }
 }
 
 // ============ UltraEQ Wrapper (backed by ProEq with Ultra features) ============
@@ -435,6 +499,42 @@ impl UltraEqWrapper {
     }
 }
 
+const ULTRA_EQ_BAND_PARAMS: &[ParameterInfo] = &[
+    ParameterInfo::log("Frequency", "Hz", 10.0, 30000.0, 1000.0),
+    ParameterInfo::linear("Gain", "dB", -30.0, 30.0, 0.0),
+    ParameterInfo::log("Q", "", 0.05, 50.0, 1.0),
+    ParameterInfo::toggle("Enabled", false),
+    ParameterInfo::choice("Shape", 10, 0),
+    ParameterInfo::toggle("Dynamic Enabled", false),
+    ParameterInfo::linear("Dynamic Threshold", "dB", -60.0, 0.0, -20.0),
+    ParameterInfo::linear("Dynamic Ratio", "", 1.0, 20.0, 2.0),
+    ParameterInfo::log("Dynamic Attack", "ms", 0.1, 500.0, 5.0),
+    ParameterInfo::log("Dynamic Release", "ms", 1.0, 5000.0, 50.0),
+    ParameterInfo::linear("Dynamic Knee", "dB", 0.0, 24.0, 6.0),
+    ParameterInfo::choice("Placement", 5, 0),
+    ParameterInfo::toggle("MZT Mode", false),
+    ParameterInfo::toggle("Transient Aware", false),
+    ParameterInfo::linear("Transient Q Reduction", "", 0.0, 1.0, 0.5),
+    ParameterInfo::linear("Saturator Drive", "dB", 0.0, 36.0, 0.0),
+    ParameterInfo::linear("Saturator Mix", "", 0.0, 1.0, 0.0),
+    ParameterInfo::choice("Saturator Type", 3, 0),
+];
+const ULTRA_EQ_GLOBAL_PARAMS: &[ParameterInfo] = &[
+    ParameterInfo::linear("Output Gain", "dB", -24.0, 24.0, 0.0),
+    ParameterInfo::toggle("Auto-Gain", false),
+    ParameterInfo::stepped("Solo Band", "", -1, 63, -1),
+    ParameterInfo::toggle("Equal Loudness", false),
+    ParameterInfo::choice("Global Oversample", 4, 1),
+];
+
+static ULTRA_EQ_PARAMS: [ParameterInfo; rf_dsp::PRO_EQ_MAX_BANDS * ULTRA_PARAMS_PER_BAND
+    + ULTRA_GLOBAL_PARAMS] = param_table(
+    &[],
+    ULTRA_EQ_BAND_PARAMS,
+    rf_dsp::PRO_EQ_MAX_BANDS,
+    ULTRA_EQ_GLOBAL_PARAMS,
+);
+
 impl InsertProcessor for UltraEqWrapper {
     fn name(&self) -> &str {
         "FluxForge Studio Ultra-EQ"
@@ -486,6 +586,10 @@ impl InsertProcessor for UltraEqWrapper {
         self.eq.set_sample_rate(sample_rate);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &ULTRA_EQ_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         rf_dsp::PRO_EQ_MAX_BANDS * ULTRA_PARAMS_PER_BAND + ULTRA_GLOBAL_PARAMS
     }
@@ -863,6 +967,19 @@ impl PultecWrapper {
     }
 }
 
+static PULTEC_PARAMS: [ParameterInfo; 10] = [
+    ParameterInfo::linear("Low Boost", "", 0.0, 10.0, 0.0),
+    ParameterInfo::linear("Low Atten", "", 0.0, 10.0, 0.0),
+    ParameterInfo::linear("High Boost", "", 0.0, 10.0, 0.0),
+    ParameterInfo::linear("High Atten", "", 0.0, 10.0, 0.0),
+    ParameterInfo::choice("Low Freq", 4, 3),
+    ParameterInfo::choice("High Boost Freq", 7, 5),
+    ParameterInfo::choice("High Atten Freq", 3, 1),
+    ParameterInfo::linear("Bandwidth", "", 0.0, 1.0, 0.5),
+    ParameterInfo::linear("Drive", "", 0.0, 1.0, 0.0),
+    ParameterInfo::linear("Output Level", "dB", -12.0, 12.0, 0.0),
+];
+
 impl InsertProcessor for PultecWrapper {
     fn name(&self) -> &str {
         "FF EQP-1A"
@@ -909,6 +1026,10 @@ impl InsertProcessor for PultecWrapper {
         self.eq.right.set_drive(self.drive);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &PULTEC_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         10
     }
@@ -1176,6 +1297,19 @@ impl Api550Wrapper {
     }
 }
 
+static API550_PARAMS: [ParameterInfo; 10] = [
+    ParameterInfo::linear("Low Gain", "dB", -12.0, 12.0, 0.0),
+    ParameterInfo::linear("Mid Gain", "dB", -12.0, 12.0, 0.0),
+    ParameterInfo::linear("High Gain", "dB", -12.0, 12.0, 0.0),
+    ParameterInfo::choice("Low Freq", 7, 3),
+    ParameterInfo::choice("Mid Freq", 7, 4),
+    ParameterInfo::choice("High Freq", 7, 3),
+    ParameterInfo::linear("Output Level", "dB", -12.0, 12.0, 0.0),
+    ParameterInfo::toggle("Low Shape", false),
+    ParameterInfo::toggle("High Shape", false),
+    ParameterInfo::toggle("Bandpass", false),
+];
+
 impl InsertProcessor for Api550Wrapper {
     fn name(&self) -> &str {
         "FF 550A"
@@ -1216,6 +1350,10 @@ impl InsertProcessor for Api550Wrapper {
         self.eq.right.set_bandpass(self.bandpass_enabled);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &API550_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         10
     }
@@ -1439,6 +1577,17 @@ impl Neve1073Wrapper {
     }
 }
 
+static NEVE1073_PARAMS: [ParameterInfo; 8] = [
+    ParameterInfo::toggle("HP Enabled", false),
+    ParameterInfo::linear("LF Gain", "dB", -16.0, 16.0, 0.0),
+    ParameterInfo::linear("MF Gain", "dB", -18.0, 18.0, 0.0),
+    ParameterInfo::linear("HF Gain", "dB", -16.0, 16.0, 0.0),
+    ParameterInfo::choice("HP Freq", 4, 3),
+    ParameterInfo::choice("LF Freq", 4, 3),
+    ParameterInfo::choice("MF Freq", 6, 2),
+    ParameterInfo::linear("Output Level", "dB", -12.0, 12.0, 0.0),
+];
+
 impl InsertProcessor for Neve1073Wrapper {
     fn name(&self) -> &str {
         "FF 1073"
@@ -1478,6 +1627,10 @@ impl InsertProcessor for Neve1073Wrapper {
         self.eq.right.set_high(self.high_gain);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &NEVE1073_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         8
     }
@@ -1622,6 +1775,10 @@ impl RoomCorrectionWrapper {
     }
 }
 
+static ROOM_CORRECTION_PARAMS: [ParameterInfo; 1] = [
+    ParameterInfo::toggle("Enabled", false),
+];
+
 impl InsertProcessor for RoomCorrectionWrapper {
     fn name(&self) -> &str {
         "Room Correction"
@@ -1642,6 +1799,10 @@ impl InsertProcessor for RoomCorrectionWrapper {
         self.eq = RoomCorrectionEq::new(sample_rate);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &ROOM_CORRECTION_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         1 // Enabled
     }
@@ -1737,6 +1898,34 @@ impl CompressorWrapper {
     }
 }
 
+static COMPRESSOR_PARAMS: [ParameterInfo; 25] = [
+    ParameterInfo::linear("Threshold", "dB", -60.0, 0.0, -20.0),
+    ParameterInfo::linear("Ratio", "", 1.0, 100.0, 4.0),
+    ParameterInfo::log("Attack", "ms", 0.01, 500.0, 10.0),
+    ParameterInfo::log("Release", "ms", 1.0, 5000.0, 100.0),
+    ParameterInfo::linear("Makeup", "dB", -24.0, 24.0, 0.0),
+    ParameterInfo::linear("Mix", "", 0.0, 1.0, 1.0),
+    ParameterInfo::linear("Link", "", 0.0, 1.0, 1.0),
+    ParameterInfo::choice("Type", 6, 0),
+    ParameterInfo::choice("Character", 4, 0),
+    ParameterInfo::linear("Drive", "dB", 0.0, 24.0, 0.0),
+    ParameterInfo::linear("Range", "dB", -60.0, 0.0, -60.0),
+    ParameterInfo::log("SC HP Freq", "Hz", 20.0, 500.0, 20.0),
+    ParameterInfo::log("SC LP Freq", "Hz", 1000.0, 20000.0, 20000.0),
+    ParameterInfo::toggle("SC Audition", false),
+    ParameterInfo::linear("Lookahead", "ms", 0.0, 20.0, 0.0),
+    ParameterInfo::log("SC Mid Freq", "Hz", 200.0, 5000.0, 1000.0),
+    ParameterInfo::linear("SC Mid Gain", "dB", -12.0, 12.0, 0.0),
+    ParameterInfo::toggle("Auto-Threshold", false),
+    ParameterInfo::toggle("Auto-Makeup", false),
+    ParameterInfo::choice("Detection", 3, 0),
+    ParameterInfo::toggle("Adaptive Rel", false),
+    ParameterInfo::toggle("Host Sync", false),
+    ParameterInfo::linear("Host BPM", "", 20.0, 300.0, 120.0),
+    ParameterInfo::toggle("Mid/Side", false),
+    ParameterInfo::linear("Knee", "dB", 0.0, 24.0, 6.0),
+];
+
 impl InsertProcessor for CompressorWrapper {
     fn name(&self) -> &str {
         "FluxForge Studio Compressor"
@@ -1744,8 +1933,8 @@ impl InsertProcessor for CompressorWrapper {
 
     fn set_sidechain_input(&mut self, left: &[Sample], right: &[Sample]) {
         // Enable external sidechain and store buffer for per-sample feeding
-        self.comp.left().set_sidechain_enabled(true);
-        self.comp.right().set_sidechain_enabled(true);
+        // (stereo-level flag: linked detection reads the stereo key, not the channels')
+        self.comp.set_sidechain_enabled(true);
         // Copy sidechain into pre-allocated buffer for per-sample access in process_stereo
         let len = left.len().min(right.len()).min(self.sc_buffer.len());
         for i in 0..len {
@@ -1759,8 +1948,7 @@ impl InsertProcessor for CompressorWrapper {
         for (i, (l, r)) in left.iter_mut().zip(right.iter_mut()).enumerate() {
             // Feed sidechain per-sample if available
             if i < sc_len {
-                self.comp.left().set_sidechain_key(self.sc_buffer[i]);
-                self.comp.right().set_sidechain_key(self.sc_buffer[i]);
+                self.comp.set_sidechain_key_mono(self.sc_buffer[i]);
             }
             let (out_l, out_r) = self.comp.process_sample(*l, *r);
             *l = out_l;
@@ -1783,6 +1971,10 @@ impl InsertProcessor for CompressorWrapper {
         self.comp.set_host_bpm(bpm);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &COMPRESSOR_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         25
     }
@@ -1796,7 +1988,7 @@ impl InsertProcessor for CompressorWrapper {
             3 => left.release_ms(),
             4 => left.makeup_gain_db(),
             5 => left.mix(),
-            6 => 1.0, // Link stored on StereoCompressor, default linked
+            6 => self.comp.link(),
             7 => match left.comp_type() {
                 CompressorType::Vca => 0.0,
                 CompressorType::Opto => 1.0,
@@ -1994,6 +2186,23 @@ impl TruePeakLimiterWrapper {
     }
 }
 
+static LIMITER_PARAMS: [ParameterInfo; 14] = [
+    ParameterInfo::linear("Input Trim", "dB", -12.0, 12.0, 0.0),
+    ParameterInfo::linear("Threshold", "dB", -30.0, 0.0, 0.0),
+    ParameterInfo::linear("Ceiling", "dBTP", -3.0, 0.0, -0.3),
+    ParameterInfo::log("Release", "ms", 1.0, 1000.0, 100.0),
+    ParameterInfo::log("Attack", "ms", 0.01, 10.0, 0.1),
+    ParameterInfo::linear("Lookahead", "ms", 0.0, 20.0, 5.0),
+    ParameterInfo::choice("Style", 8, 7),
+    ParameterInfo::choice("Oversampling", 4, 1),
+    ParameterInfo::linear("Stereo Link", "%", 0.0, 100.0, 100.0),
+    ParameterInfo::toggle("M/S Mode", false),
+    ParameterInfo::linear("Mix", "%", 0.0, 100.0, 100.0),
+    ParameterInfo::choice("Dither Bits", 5, 0),
+    ParameterInfo::choice("Latency Profile", 3, 1),
+    ParameterInfo::choice("Channel Config", 3, 0),
+];
+
 impl InsertProcessor for TruePeakLimiterWrapper {
     fn name(&self) -> &str {
         "FluxForge Studio True Peak Limiter"
@@ -2020,6 +2229,10 @@ impl InsertProcessor for TruePeakLimiterWrapper {
         self.limiter.set_sample_rate(sample_rate);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &LIMITER_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         14
     }
@@ -2239,6 +2452,22 @@ impl GateWrapper {
     }
 }
 
+static GATE_PARAMS: [ParameterInfo; 13] = [
+    ParameterInfo::linear("Threshold", "dB", -80.0, 0.0, -40.0),
+    ParameterInfo::linear("Range", "dB", -80.0, 0.0, -80.0),
+    ParameterInfo::log("Attack", "ms", 0.01, 100.0, 1.0),
+    ParameterInfo::linear("Hold", "ms", 0.0, 500.0, 50.0),
+    ParameterInfo::log("Release", "ms", 1.0, 1000.0, 100.0),
+    ParameterInfo::choice("Mode", 3, 0),
+    ParameterInfo::toggle("SC Enable", false),
+    ParameterInfo::log("SC HP Freq", "Hz", 20.0, 500.0, 20.0),
+    ParameterInfo::log("SC LP Freq", "Hz", 1000.0, 20000.0, 20000.0),
+    ParameterInfo::linear("Lookahead", "ms", 0.0, 100.0, 0.0),
+    ParameterInfo::linear("Hysteresis", "dB", 0.0, 12.0, 0.0),
+    ParameterInfo::linear("Ratio", "", 1.0, 100.0, 100.0),
+    ParameterInfo::toggle("SC Audition", false),
+];
+
 impl InsertProcessor for GateWrapper {
     fn name(&self) -> &str {
         "FluxForge Studio Gate"
@@ -2349,6 +2578,10 @@ impl InsertProcessor for GateWrapper {
         self.mode = mode;
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &GATE_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         13
     }
@@ -2477,6 +2710,14 @@ impl ExpanderWrapper {
     }
 }
 
+static EXPANDER_PARAMS: [ParameterInfo; 5] = [
+    ParameterInfo::linear("Threshold", "dB", -80.0, 0.0, -30.0),
+    ParameterInfo::linear("Ratio", "", 1.0, 20.0, 2.0),
+    ParameterInfo::linear("Knee", "dB", 0.0, 24.0, 6.0),
+    ParameterInfo::log("Attack", "ms", 0.1, 500.0, 5.0),
+    ParameterInfo::log("Release", "ms", 1.0, 5000.0, 100.0),
+];
+
 impl InsertProcessor for ExpanderWrapper {
     fn name(&self) -> &str {
         "FluxForge Studio Expander"
@@ -2503,6 +2744,10 @@ impl InsertProcessor for ExpanderWrapper {
         self.right.set_times(self.attack_ms, self.release_ms);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &EXPANDER_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         5
     }
@@ -2605,6 +2850,18 @@ impl DeEsserWrapper {
     }
 }
 
+static DEESSER_PARAMS: [ParameterInfo; 9] = [
+    ParameterInfo::log("Frequency", "Hz", 2000.0, 16000.0, 7000.0),
+    ParameterInfo::linear("Bandwidth", "oct", 0.25, 4.0, 1.5),
+    ParameterInfo::linear("Threshold", "dB", -60.0, 0.0, -30.0),
+    ParameterInfo::linear("Range", "dB", 0.0, 24.0, 16.0),
+    ParameterInfo::choice("Mode", 2, 1),
+    ParameterInfo::linear("Attack", "ms", 0.1, 50.0, 0.3),
+    ParameterInfo::linear("Release", "ms", 10.0, 500.0, 30.0),
+    ParameterInfo::toggle("Listen", false),
+    ParameterInfo::toggle("Bypass", false),
+];
+
 impl InsertProcessor for DeEsserWrapper {
     fn name(&self) -> &str {
         "FluxForge Studio De-Esser"
@@ -2649,6 +2906,10 @@ impl InsertProcessor for DeEsserWrapper {
         self.deesser.set_bypass(bypassed);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &DEESSER_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         9 // frequency, bandwidth, threshold, range, mode, attack, release, listen, bypass
     }
@@ -2748,6 +3009,17 @@ impl LinearPhaseEqWrapper {
     }
 }
 
+const LINEAR_PHASE_EQ_BAND_PARAMS: &[ParameterInfo] = &[
+    ParameterInfo::log("Frequency", "Hz", 20.0, 20000.0, 1000.0),
+    ParameterInfo::linear("Gain", "dB", -24.0, 24.0, 0.0),
+    ParameterInfo::log("Q", "", 0.1, 30.0, 1.0),
+    ParameterInfo::toggle("Enabled", false),
+    ParameterInfo::choice("Type", 8, 0),
+];
+
+static LINEAR_PHASE_EQ_PARAMS: [ParameterInfo; 32 * 5] =
+    param_table(&[], LINEAR_PHASE_EQ_BAND_PARAMS, 32, &[]);
+
 impl InsertProcessor for LinearPhaseEqWrapper {
     fn name(&self) -> &str {
         "FluxForge Studio Linear Phase EQ"
@@ -2776,6 +3048,10 @@ impl InsertProcessor for LinearPhaseEqWrapper {
         self.eq = LinearPhaseEQ::new(sample_rate);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &LINEAR_PHASE_EQ_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         // Per band: freq(0), gain(1), q(2), enabled(3), type(4)
         32 * 5 // 32 bands max, 5 params each
@@ -2817,6 +3093,36 @@ impl InsertProcessor for LinearPhaseEqWrapper {
         }
     }
 
+    fn get_param(&self, index: usize) -> f64 {
+        let band_idx = index / 5;
+        let Some(band) = self.eq.get_band(band_idx) else {
+            return LINEAR_PHASE_EQ_PARAMS.get(index).map_or(0.0, |p| p.range.default);
+        };
+        match index % 5 {
+            0 => band.frequency,
+            1 => band.gain,
+            2 => band.q,
+            3 => {
+                if band.enabled {
+                    1.0
+                } else {
+                    0.0
+                }
+            }
+            4 => match band.filter_type {
+                LinearPhaseFilterType::Bell => 0.0,
+                LinearPhaseFilterType::LowShelf => 1.0,
+                LinearPhaseFilterType::HighShelf => 2.0,
+                LinearPhaseFilterType::LowCut => 3.0,
+                LinearPhaseFilterType::HighCut => 4.0,
+                LinearPhaseFilterType::Notch => 5.0,
+                LinearPhaseFilterType::BandPass => 6.0,
+                LinearPhaseFilterType::Tilt => 7.0,
+            },
+            _ => 0.0,
+        }
+    }
+
     fn param_name(&self, index: usize) -> &str {
         match index % 5 {
             0 => "Frequency",
@@ -2870,6 +3176,47 @@ impl ReverbWrapper {
     }
 }
 
+static REVERB_PARAMS: [ParameterInfo; 38] = [
+    ParameterInfo::linear("Space", "", 0.0, 1.0, 0.5),
+    ParameterInfo::linear("Brightness", "", 0.0, 1.0, 0.6),
+    ParameterInfo::linear("Width", "", 0.0, 2.0, 1.0),
+    ParameterInfo::linear("Mix", "", 0.0, 1.0, 1.0),
+    ParameterInfo::linear("PreDelay", "ms", 0.0, 500.0, 0.0),
+    ParameterInfo::choice("Style", 10, 1),
+    ParameterInfo::linear("Diffusion", "", 0.0, 1.0, 0.0),
+    ParameterInfo::linear("Distance", "", 0.0, 1.0, 0.0),
+    ParameterInfo::linear("Decay", "", 0.0, 1.0, 0.5),
+    ParameterInfo::linear("Low Decay", "", 0.5, 2.0, 1.0),
+    ParameterInfo::linear("High Decay", "", 0.5, 2.0, 1.0),
+    ParameterInfo::linear("Character", "", 0.0, 1.0, 0.0),
+    ParameterInfo::linear("Thickness", "", 0.0, 1.0, 0.0),
+    ParameterInfo::linear("Ducking", "", 0.0, 1.0, 0.0),
+    ParameterInfo::toggle("Freeze", false),
+    ParameterInfo::linear("Spin", "", 0.0, 1.0, 0.5),
+    ParameterInfo::linear("Wander", "", 0.0, 1.0, 0.5),
+    ParameterInfo::linear("ER Level", "", 0.0, 1.0, 1.0),
+    ParameterInfo::linear("Late Level", "", 0.0, 1.0, 1.0),
+    ParameterInfo::log("XO Freq 1", "Hz", 20.0, 2000.0, 250.0),
+    ParameterInfo::log("XO Freq 2", "Hz", 200.0, 10000.0, 2000.0),
+    ParameterInfo::log("XO Freq 3", "Hz", 2000.0, 20000.0, 8000.0),
+    ParameterInfo::linear("LowMid Decay", "", 0.5, 2.0, 1.0),
+    ParameterInfo::linear("HighMid Decay", "", 0.5, 2.0, 1.0),
+    ParameterInfo::linear("Out EQ Lo Gain", "dB", -12.0, 12.0, 0.0),
+    ParameterInfo::log("Out EQ Lo Freq", "Hz", 80.0, 500.0, 200.0),
+    ParameterInfo::linear("Out EQ Hi Gain", "dB", -12.0, 12.0, 0.0),
+    ParameterInfo::log("Out EQ Hi Freq", "Hz", 2000.0, 16000.0, 8000.0),
+    ParameterInfo::linear("Out EQ Mid Gain", "dB", -12.0, 12.0, 0.0),
+    ParameterInfo::log("Out EQ Mid Freq", "Hz", 200.0, 8000.0, 1000.0),
+    ParameterInfo::linear("Out EQ Mid Q", "", 0.5, 5.0, 1.0),
+    ParameterInfo::toggle("Soft Limiter", false),
+    ParameterInfo::toggle("BPM Sync", false),
+    ParameterInfo::linear("BPM", "", 60.0, 200.0, 120.0),
+    ParameterInfo::choice("Note Div", 8, 2),
+    ParameterInfo::linear("PD Feedback", "", 0.0, 0.5, 0.0),
+    ParameterInfo::choice("FDN Size", 3, 1),
+    ParameterInfo::choice("Matrix Type", 2, 0),
+];
+
 impl InsertProcessor for ReverbWrapper {
     fn name(&self) -> &str {
         "FluxForge Reverb"
@@ -2901,6 +3248,10 @@ impl InsertProcessor for ReverbWrapper {
         self.reverb.latency() as LatencySamples
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &REVERB_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         38
     }
@@ -3147,6 +3498,19 @@ impl SaturatorWrapper {
     }
 }
 
+static SATURATOR_PARAMS: [ParameterInfo; 10] = [
+    ParameterInfo::linear("Drive", "dB", -24.0, 40.0, 0.0),
+    ParameterInfo::choice("Type", 6, 0),
+    ParameterInfo::linear("Tone", "", -100.0, 100.0, 0.0),
+    ParameterInfo::linear("Mix", "%", 0.0, 100.0, 100.0),
+    ParameterInfo::linear("Output", "dB", -24.0, 24.0, 0.0),
+    ParameterInfo::linear("Tape Bias", "%", 0.0, 100.0, 50.0),
+    ParameterInfo::choice("Oversampling", 4, 1),
+    ParameterInfo::linear("Input Trim", "dB", -12.0, 12.0, 0.0),
+    ParameterInfo::toggle("M/S Mode", false),
+    ParameterInfo::toggle("Stereo Link", true),
+];
+
 impl InsertProcessor for SaturatorWrapper {
     fn name(&self) -> &str {
         "FluxForge Studio Saturator"
@@ -3225,6 +3589,10 @@ impl InsertProcessor for SaturatorWrapper {
         self.saturator.set_sample_rate(sample_rate);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &SATURATOR_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         10
     }
@@ -3465,6 +3833,34 @@ impl MultibandSaturatorWrapper {
     }
 }
 
+const MB_SATURATOR_GLOBAL_PARAMS: &[ParameterInfo] = &[
+    ParameterInfo::linear("Input Gain", "dB", -24.0, 24.0, 0.0),
+    ParameterInfo::linear("Output Gain", "dB", -24.0, 24.0, 0.0),
+    ParameterInfo::linear("Global Mix", "%", 0.0, 100.0, 100.0),
+    ParameterInfo::toggle("M/S Mode", false),
+    ParameterInfo::stepped("Num Bands", "", 2, 6, 4),
+    ParameterInfo::choice("Crossover Type", 3, 1),
+    ParameterInfo::log("Crossover 1", "Hz", 20.0, 20000.0, 120.0),
+    ParameterInfo::log("Crossover 2", "Hz", 20.0, 20000.0, 750.0),
+    ParameterInfo::log("Crossover 3", "Hz", 20.0, 20000.0, 2500.0),
+    ParameterInfo::log("Crossover 4", "Hz", 20.0, 20000.0, 7000.0),
+    ParameterInfo::log("Crossover 5", "Hz", 20.0, 20000.0, 14000.0),
+];
+const MB_SATURATOR_BAND_PARAMS: &[ParameterInfo] = &[
+    ParameterInfo::linear("Band Drive", "dB", -24.0, 52.0, 0.0),
+    ParameterInfo::choice("Band Type", 6, 0),
+    ParameterInfo::linear("Band Tone", "", -100.0, 100.0, 0.0),
+    ParameterInfo::linear("Band Mix", "%", 0.0, 100.0, 100.0),
+    ParameterInfo::linear("Band Output", "dB", -24.0, 24.0, 0.0),
+    ParameterInfo::linear("Band Dynamics", "", -1.0, 1.0, 0.0),
+    ParameterInfo::toggle("Band Solo", false),
+    ParameterInfo::toggle("Band Mute", false),
+    ParameterInfo::toggle("Band Bypass", false),
+];
+
+static MB_SATURATOR_PARAMS: [ParameterInfo; 65] =
+    param_table(MB_SATURATOR_GLOBAL_PARAMS, MB_SATURATOR_BAND_PARAMS, 6, &[]);
+
 impl InsertProcessor for MultibandSaturatorWrapper {
     fn name(&self) -> &str {
         "FluxForge Saturn 2 Multiband Saturator"
@@ -3518,6 +3914,10 @@ impl InsertProcessor for MultibandSaturatorWrapper {
         self.saturator.set_sample_rate(sample_rate);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &MB_SATURATOR_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         65
     }
@@ -4055,6 +4455,67 @@ impl DelayWrapper {
     }
 }
 
+static DELAY_PARAMS: [ParameterInfo; 58] = [
+    ParameterInfo::linear("Delay L", "ms", 1.0, 5000.0, 500.0),
+    ParameterInfo::linear("Delay R", "ms", 1.0, 5000.0, 500.0),
+    ParameterInfo::linear("Feedback", "%", 0.0, 99.0, 50.0),
+    ParameterInfo::linear("Mix", "%", 0.0, 100.0, 50.0),
+    ParameterInfo::linear("Ping-Pong", "%", 0.0, 100.0, 0.0),
+    ParameterInfo::log("HP Filter", "Hz", 20.0, 2000.0, 80.0),
+    ParameterInfo::log("LP Filter", "Hz", 200.0, 20000.0, 8000.0),
+    ParameterInfo::linear("Mod Rate", "Hz", 0.0, 20.0, 0.0),
+    ParameterInfo::linear("Mod Depth", "%", 0.0, 100.0, 0.0),
+    ParameterInfo::linear("Width", "%", 0.0, 200.0, 100.0),
+    ParameterInfo::linear("Ducking", "%", 0.0, 100.0, 0.0),
+    ParameterInfo::toggle("Link L/R", true),
+    ParameterInfo::toggle("Freeze", false),
+    ParameterInfo::toggle("Tempo Sync", false),
+    ParameterInfo::linear("HP Q", "", 0.5, 10.0, 0.707),
+    ParameterInfo::linear("LP Q", "", 0.5, 10.0, 0.707),
+    ParameterInfo::log("Mid Freq", "Hz", 80.0, 16000.0, 1000.0),
+    ParameterInfo::linear("Mid Q", "", 0.5, 10.0, 1.0),
+    ParameterInfo::linear("Mid Gain", "dB", -18.0, 18.0, 0.0),
+    ParameterInfo::linear("Drive", "%", 0.0, 100.0, 0.0),
+    ParameterInfo::choice("Drive Mode", 3, 0),
+    ParameterInfo::linear("Tilt", "dB/oct", -6.0, 6.0, 0.0),
+    ParameterInfo::linear("Filter LFO Rate", "Hz", 0.0, 20.0, 0.0),
+    ParameterInfo::linear("Filter LFO Depth", "%", 0.0, 100.0, 0.0),
+    ParameterInfo::log("LFO1 Rate", "Hz", 0.01, 20.0, 1.0),
+    ParameterInfo::linear("LFO1 Depth", "%", 0.0, 100.0, 0.0),
+    ParameterInfo::choice("LFO1 Shape", 7, 0),
+    ParameterInfo::toggle("LFO1 Sync", false),
+    ParameterInfo::stepped("LFO1 Sync Div", "", 0, 15, 4),
+    ParameterInfo::toggle("LFO1 Retrigger", false),
+    ParameterInfo::log("LFO2 Rate", "Hz", 0.01, 20.0, 1.0),
+    ParameterInfo::linear("LFO2 Depth", "%", 0.0, 100.0, 0.0),
+    ParameterInfo::choice("LFO2 Shape", 7, 0),
+    ParameterInfo::toggle("LFO2 Sync", false),
+    ParameterInfo::stepped("LFO2 Sync Div", "", 0, 15, 4),
+    ParameterInfo::linear("ENV Sensitivity", "%", 0.0, 100.0, 50.0),
+    ParameterInfo::linear("ENV Attack", "ms", 0.1, 100.0, 5.0),
+    ParameterInfo::linear("ENV Release", "ms", 1.0, 1000.0, 50.0),
+    ParameterInfo::linear("Pitch Shift", "st", -12.0, 12.0, 0.0),
+    ParameterInfo::choice("Mod Routing", 9, 0),
+    ParameterInfo::linear("BPM", "", 20.0, 999.0, 120.0),
+    ParameterInfo::stepped("Note Value L", "", 0, 18, 9),
+    ParameterInfo::stepped("Note Value R", "", 0, 18, 9),
+    ParameterInfo::linear("Swing", "%", 0.0, 100.0, 0.0),
+    ParameterInfo::choice("Vintage Mode", 5, 0),
+    ParameterInfo::linear("Vintage Amount", "%", 0.0, 100.0, 50.0),
+    ParameterInfo::choice("Stereo Routing", 5, 1),
+    ParameterInfo::linear("Cross-Feedback", "%", 0.0, 100.0, 0.0),
+    ParameterInfo::linear("Haas Delay", "ms", 0.0, 30.0, 0.0),
+    ParameterInfo::linear("Diffusion", "%", 0.0, 100.0, 0.0),
+    ParameterInfo::toggle("Reverse", false),
+    ParameterInfo::toggle("Stutter", false),
+    ParameterInfo::linear("Stutter Rate", "ms", 10.0, 1000.0, 125.0),
+    ParameterInfo::toggle("Infinite FB", false),
+    ParameterInfo::toggle("Sidechain", false),
+    ParameterInfo::choice("MIDI Trigger", 4, 0),
+    ParameterInfo::linear("Smoothing", "%", 0.0, 100.0, 0.0),
+    ParameterInfo::linear("Reserved", "", 0.0, 1.0, 0.0),
+];
+
 impl InsertProcessor for DelayWrapper {
     fn name(&self) -> &str {
         "FluxForge Timeless 3 Delay"
@@ -4352,6 +4813,10 @@ impl InsertProcessor for DelayWrapper {
         self.freeze_buf_r = vec![0.0; freeze_len];
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &DELAY_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         58
     }
@@ -4801,6 +5266,7 @@ impl InsertProcessor for DelayWrapper {
             54 => "Sidechain",
             55 => "MIDI Trigger",
             56 => "Smoothing",
+            57 => "Reserved",
             _ => "Unknown",
         }
     }
@@ -4852,6 +5318,21 @@ impl StereoImagerWrapper {
     }
 }
 
+static STEREO_IMAGER_PARAMS: [ParameterInfo; 12] = [
+    ParameterInfo::linear("Width", "", 0.0, 2.0, 1.0),
+    ParameterInfo::linear("Pan", "", -1.0, 1.0, 0.0),
+    ParameterInfo::choice("Pan Law", 4, 1),
+    ParameterInfo::linear("Balance", "", -1.0, 1.0, 0.0),
+    ParameterInfo::linear("Mid Gain (dB)", "dB", -24.0, 12.0, 0.0),
+    ParameterInfo::linear("Side Gain (dB)", "dB", -24.0, 12.0, 0.0),
+    ParameterInfo::linear("Rotation (deg)", "°", -180.0, 180.0, 0.0),
+    ParameterInfo::toggle("Enable Balance", false),
+    ParameterInfo::toggle("Enable Panner", false),
+    ParameterInfo::toggle("Enable Width", true),
+    ParameterInfo::toggle("Enable M/S", false),
+    ParameterInfo::toggle("Enable Rotation", false),
+];
+
 impl InsertProcessor for StereoImagerWrapper {
     fn name(&self) -> &str {
         "FluxForge Stereo Imager"
@@ -4874,6 +5355,10 @@ impl InsertProcessor for StereoImagerWrapper {
         self.imager.set_sample_rate(sample_rate);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &STEREO_IMAGER_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         12
     }
@@ -4959,6 +5444,16 @@ impl HaasDelayWrapper {
     }
 }
 
+static HAAS_DELAY_PARAMS: [ParameterInfo; 7] = [
+    ParameterInfo::linear("Delay (ms)", "ms", 0.1, 30.0, 8.0),
+    ParameterInfo::choice("Delayed Channel", 2, 1),
+    ParameterInfo::linear("Mix", "", 0.0, 1.0, 1.0),
+    ParameterInfo::toggle("LP Enabled", true),
+    ParameterInfo::log("LP Frequency", "Hz", 200.0, 18000.0, 8000.0),
+    ParameterInfo::linear("Feedback", "", 0.0, 0.7, 0.0),
+    ParameterInfo::toggle("Phase Invert", false),
+];
+
 impl InsertProcessor for HaasDelayWrapper {
     fn name(&self) -> &str {
         "FluxForge Haas Delay"
@@ -4981,6 +5476,10 @@ impl InsertProcessor for HaasDelayWrapper {
         self.haas.set_sample_rate(sample_rate);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &HAAS_DELAY_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         7
     }
@@ -5126,6 +5625,39 @@ impl MultibandStereoImagerWrapper {
     }
 }
 
+const MB_IMAGER_GLOBAL_PARAMS: &[ParameterInfo] = &[
+    ParameterInfo::linear("Input Gain", "dB", -24.0, 24.0, 0.0),
+    ParameterInfo::linear("Output Gain", "dB", -24.0, 24.0, 0.0),
+    ParameterInfo::linear("Global Mix", "%", 0.0, 100.0, 100.0),
+    ParameterInfo::stepped("Num Bands", "", 2, 6, 4),
+    ParameterInfo::choice("Crossover Type", 3, 1),
+    ParameterInfo::log("Crossover 1", "Hz", 20.0, 20000.0, 120.0),
+    ParameterInfo::log("Crossover 2", "Hz", 20.0, 20000.0, 750.0),
+    ParameterInfo::log("Crossover 3", "Hz", 20.0, 20000.0, 2500.0),
+    ParameterInfo::log("Crossover 4", "Hz", 20.0, 20000.0, 7000.0),
+    ParameterInfo::log("Crossover 5", "Hz", 20.0, 20000.0, 14000.0),
+    ParameterInfo::toggle("M/S Mode", false),
+];
+const MB_IMAGER_BAND_PARAMS: &[ParameterInfo] = &[
+    ParameterInfo::linear("Band Width", "", 0.0, 3.0, 1.0),
+    ParameterInfo::linear("Band Pan", "", -1.0, 1.0, 0.0),
+    ParameterInfo::linear("Band Mid Gain", "dB", -24.0, 24.0, 0.0),
+    ParameterInfo::linear("Band Side Gain", "dB", -24.0, 24.0, 0.0),
+    ParameterInfo::linear("Band Rotation", "°", -180.0, 180.0, 0.0),
+    ParameterInfo::toggle("Band Enable Width", true),
+    ParameterInfo::toggle("Band Solo", false),
+    ParameterInfo::toggle("Band Mute", false),
+    ParameterInfo::toggle("Band Bypass", false),
+];
+const MB_IMAGER_TAIL_PARAMS: &[ParameterInfo] = &[
+    ParameterInfo::toggle("Stereoize Enabled", false),
+    ParameterInfo::linear("Stereoize Amount", "", 0.0, 1.0, 0.0),
+    ParameterInfo::toggle("Band Link", false),
+];
+
+static MB_IMAGER_PARAMS: [ParameterInfo; 68] =
+    param_table(MB_IMAGER_GLOBAL_PARAMS, MB_IMAGER_BAND_PARAMS, 6, MB_IMAGER_TAIL_PARAMS);
+
 impl InsertProcessor for MultibandStereoImagerWrapper {
     fn name(&self) -> &str {
         "FluxForge Multiband Stereo Imager"
@@ -5187,6 +5719,10 @@ impl InsertProcessor for MultibandStereoImagerWrapper {
         self.imager.set_sample_rate(sample_rate);
     }
 
+    fn parameters(&self) -> &[ParameterInfo] {
+        &MB_IMAGER_PARAMS
+    }
+
     fn num_params(&self) -> usize {
         68
     }
@@ -5532,6 +6068,41 @@ mod tests {
         assert!(eq.band_count() > 0);
     }
 
+    #[test]
+    fn test_compressor_slot_mix() {
+        let input: Vec<Sample> = (0..1024).map(|i| (i as f64 * 0.05).sin() * 0.9).collect();
+        let new_compressor = || {
+            let mut comp = CompressorWrapper::new(48000.0);
+            comp.set_param(0, -30.0); // threshold
+            comp.set_param(1, 8.0); // ratio
+            comp
+        };
+
+        let mut processed_l = input.clone();
+        let mut processed_r = input.clone();
+        new_compressor().process_stereo(&mut processed_l, &mut processed_r);
+        assert!(
+            processed_l
+                .iter()
+                .zip(&input)
+                .any(|(p, x)| (p - x).abs() > 1e-3)
+        );
+
+        // Wet/dry lives on the insert slot, so it applies to every wrapper
+        let run = |mix: f64| {
+            let mut slot = crate::insert_chain::InsertSlot::new(0);
+            slot.load(Box::new(new_compressor()));
+            slot.set_mix(mix);
+            let mut left = input.clone();
+            let mut right = input.clone();
+            slot.process(&mut left, &mut right);
+            left
+        };
+
+        assert_eq!(run(0.0), input);
+        assert_eq!(run(1.0), processed_l);
+    }
+
     #[test]
     fn test_pultec_wrapper() {
         let mut eq = PultecWrapper::new(48000.0);
@@ -7454,4 +8025,50 @@ mod tests {
             diff_count
         );
     }
+
+    #[test]
+    fn test_parameter_descriptors_match_wrappers() {
+        for key in available_processors() {
+            let proc = create_processor_extended(key, 48000.0).unwrap();
+            let params = proc.parameters();
+            assert!(!params.is_empty(), "{key}: no parameter descriptors");
+            assert_eq!(params.len(), proc.num_params(), "{key}: descriptor count");
+
+            for (i, info) in params.iter().enumerate() {
+                assert_eq!(info.name, proc.param_name(i), "{key}: name of param {i}");
+                assert!(info.range.min < info.range.max, "{key}: empty range for {}", info.name);
+                assert!(
+                    (proc.get_param(i) - info.range.default).abs() < 1e-6,
+                    "{key}: default of {} is {}, wrapper reports {}",
+                    info.name,
+                    info.range.default,
+                    proc.get_param(i)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parameter_normalized_round_trip() {
+        for key in available_processors() {
+            let mut proc = create_processor_extended(key, 48000.0).unwrap();
+            // Slot-level mix is owned by the insert slot, not the processor
+            let slot_mix = proc.slot_mix_param();
+
+            for i in 0..proc.num_params() {
+                if Some(i) == slot_mix {
+                    continue;
+                }
+                let info = proc.parameters()[i];
+                let expected = info.normalize(info.denormalize(0.75));
+                proc.set_param_normalized(i, 0.75);
+                let actual = proc.get_param_normalized(i);
+                assert!(
+                    (actual - expected).abs() < 1e-3,
+                    "{key}: {} round-trip expected {expected}, got {actual}",
+                    info.name
+                );
+            }
+        }
+    }
 }
@@ -4,17 +4,21 @@
 //! - WAV export (16/24/32-bit)
 //! - Full mix bounce (all tracks + master)
 //! - Region export (loop regions)
+//! - Stems export (per track, or grouped by bus/group/track set)
+//! - Tail rendering until silence (reverb/delay decay past the timeline end)
 //! - Real-time or faster-than-real-time rendering
 //! - Progress callback support
 
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 use crate::audio_import::SampleRateConverter;
 use crate::freeze::OfflineRenderer;
+use crate::groups::GroupId;
 use crate::playback::PlaybackEngine;
-use crate::track_manager::TrackManager;
+use crate::track_manager::{OutputBus, TrackManager};
 
 use rf_file::{AudioData, BitDepth, write_flac, write_mp3};
 
@@ -22,6 +26,15 @@ use rf_file::{AudioData, BitDepth, write_flac, write_mp3};
 // EXPORT CONFIG
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Level below which a rendered tail counts as silent (dBFS)
+pub const TAIL_SILENCE_DB: f64 = -90.0;
+
+/// How long the tail must stay below `TAIL_SILENCE_DB` before rendering stops
+pub const TAIL_SILENCE_HOLD_SECONDS: f64 = 0.1;
+
+/// Default cap on a rendered tail (seconds)
+pub const DEFAULT_MAX_TAIL_SECONDS: f64 = 30.0;
+
 /// Export format
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
 pub enum ExportFormat {
@@ -108,6 +121,11 @@ pub struct ExportConfig {
     pub include_tail: bool,
     /// Tail length in seconds
     pub tail_seconds: f64,
+    /// Render past the end until the output decays below `TAIL_SILENCE_DB`
+    /// (replaces the fixed `include_tail`/`tail_seconds` tail)
+    pub render_tail: bool,
+    /// Longest tail `render_tail` may add, in seconds
+    pub max_tail_seconds: f64,
     /// Normalize to -0.1 dBFS
     pub normalize: bool,
     /// Render block size
@@ -124,6 +142,8 @@ impl Default for ExportConfig {
             end_time: 60.0,
             include_tail: true,
             tail_seconds: 3.0,
+            render_tail: false,
+            max_tail_seconds: DEFAULT_MAX_TAIL_SECONDS,
             normalize: false,
             block_size: 512,
         }
@@ -196,7 +216,7 @@ impl ExportEngine {
             return Err(ExportError::InvalidTimeRange);
         }
 
-        let total_duration = if config.include_tail {
+        let total_duration = if config.include_tail && !config.render_tail {
             render_duration + config.tail_seconds
         } else {
             render_duration
@@ -253,6 +273,18 @@ impl ExportEngine {
             self.progress.store(progress.to_bits(), Ordering::Relaxed);
         }
 
+        // Keep rendering past the end until the tail has decayed
+        if config.render_tail {
+            let tail_start = (config.start_time * engine_rate as f64) as usize + render_samples;
+            self.render_tail(
+                &config,
+                tail_start,
+                engine_rate,
+                &mut render_l,
+                &mut render_r,
+            )?;
+        }
+
         // Normalize if requested (before SRC to preserve precision)
         if config.normalize {
             self.normalize_audio(&mut render_l, &mut render_r);
@@ -308,6 +340,56 @@ impl ExportEngine {
         Ok(())
     }
 
+    /// Render from `start_sample` until the output stays below
+    /// `TAIL_SILENCE_DB` for `TAIL_SILENCE_HOLD_SECONDS` (or `max_tail_seconds`
+    /// runs out), appending the tail up to its last audible sample
+    fn render_tail(
+        &self,
+        config: &ExportConfig,
+        start_sample: usize,
+        engine_rate: u32,
+        left: &mut Vec<f64>,
+        right: &mut Vec<f64>,
+    ) -> Result<(), ExportError> {
+        let threshold = 10.0_f64.powf(TAIL_SILENCE_DB / 20.0);
+        let hold = (TAIL_SILENCE_HOLD_SECONDS * engine_rate as f64) as usize;
+        let max_tail = (config.max_tail_seconds.max(0.0) * engine_rate as f64) as usize;
+        let timeline_len = left.len();
+
+        let mut block_l = vec![0.0f64; config.block_size];
+        let mut block_r = vec![0.0f64; config.block_size];
+        // Samples rendered past the end / up to the last audible one
+        let mut tail_len = 0;
+        let mut audible_len = 0;
+
+        while tail_len < max_tail && tail_len - audible_len < hold {
+            if self.cancel_flag.load(Ordering::Relaxed) {
+                self.is_exporting.store(false, Ordering::Relaxed);
+                log::info!("ExportEngine: export aborted while rendering tail");
+                return Err(ExportError::Cancelled);
+            }
+
+            let len = config.block_size.min(max_tail - tail_len);
+            let (block_l, block_r) = (&mut block_l[..len], &mut block_r[..len]);
+            self.playback_engine
+                .process_offline(start_sample + tail_len, block_l, block_r);
+
+            if let Some(last) = (0..len)
+                .rev()
+                .find(|&i| block_l[i].abs().max(block_r[i].abs()) > threshold)
+            {
+                audible_len = tail_len + last + 1;
+            }
+            left.extend_from_slice(block_l);
+            right.extend_from_slice(block_r);
+            tail_len += len;
+        }
+
+        left.truncate(timeline_len + audible_len);
+        right.truncate(timeline_len + audible_len);
+        Ok(())
+    }
+
     /// Create AudioData from left/right buffers
     fn create_audio_data(&self, left: &[f64], right: &[f64], sample_rate: u32) -> AudioData {
         let mut audio_data = AudioData::new(2, left.len(), sample_rate);
@@ -453,7 +535,7 @@ pub struct StemInfo {
 
 impl ExportEngine {
     /// Export stems (individual tracks)
-    pub fn export_stems(&self, config: StemsConfig) -> Result<Vec<StemInfo>, ExportError> {
+    pub fn export_track_stems(&self, config: StemsConfig) -> Result<Vec<StemInfo>, ExportError> {
         // Check if already exporting
         if self.is_exporting.swap(true, Ordering::Relaxed) {
             return Err(ExportError::AlreadyExporting);
@@ -614,6 +696,211 @@ impl ExportEngine {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// GROUPED STEMS EXPORT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// What feeds a grouped stem
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StemSource {
+    /// Every track reaching this bus (directly or via bus-to-bus routing)
+    Bus(OutputBus),
+    /// Members of a track group
+    Group(GroupId),
+    /// Explicit track set
+    Tracks(Vec<u64>),
+}
+
+/// Grouped stem definition — one output file per stem
+#[derive(Debug, Clone)]
+pub struct StemDef {
+    /// Stem name (used in the filename)
+    pub name: String,
+    /// Contributing tracks
+    pub source: StemSource,
+    /// Render the stem tracks' sends, so reverb/delay returns carry their
+    /// wet signal. When false the stem is dry.
+    pub include_returns: bool,
+}
+
+impl StemDef {
+    /// Stem of everything reaching `bus`
+    pub fn bus(name: &str, bus: OutputBus) -> Self {
+        Self::new(name, StemSource::Bus(bus))
+    }
+
+    /// Stem of a track group
+    pub fn group(name: &str, group_id: GroupId) -> Self {
+        Self::new(name, StemSource::Group(group_id))
+    }
+
+    /// Stem of an explicit track set
+    pub fn tracks(name: &str, track_ids: &[u64]) -> Self {
+        Self::new(name, StemSource::Tracks(track_ids.to_vec()))
+    }
+
+    fn new(name: &str, source: StemSource) -> Self {
+        Self {
+            name: name.to_string(),
+            source,
+            include_returns: true,
+        }
+    }
+
+    /// Include or exclude send returns
+    pub fn with_returns(mut self, include: bool) -> Self {
+        self.include_returns = include;
+        self
+    }
+}
+
+impl ExportEngine {
+    /// Export grouped stems (one file per bus, group or track set)
+    ///
+    /// Each stem renders the full mix path with only its contributing tracks
+    /// audible (solo without touching the session's solo state). Clip audio
+    /// comes from the shared playback cache, so sources decode once for all
+    /// stems. Files are named `{prefix}_{stem name}.{ext}` (or `{stem name}.{ext}`).
+    pub fn export_stems(
+        &self,
+        stems: &[StemDef],
+        config: &StemsConfig,
+    ) -> Result<Vec<PathBuf>, ExportError> {
+        if self.is_exporting.swap(true, Ordering::Relaxed) {
+            return Err(ExportError::AlreadyExporting);
+        }
+
+        let result = self.render_grouped_stems(stems, config);
+        self.is_exporting.store(false, Ordering::Relaxed);
+        result
+    }
+
+    fn render_grouped_stems(
+        &self,
+        stems: &[StemDef],
+        config: &StemsConfig,
+    ) -> Result<Vec<PathBuf>, ExportError> {
+        let render_duration = config.end_time - config.start_time;
+        if render_duration <= 0.0 {
+            return Err(ExportError::InvalidTimeRange);
+        }
+
+        // Resolve contributing tracks up front so a bad definition fails before rendering
+        let stem_tracks = stems
+            .iter()
+            .map(|stem| {
+                let ids: HashSet<u64> = match &stem.source {
+                    StemSource::Bus(bus) => self.playback_engine.tracks_feeding_bus(*bus),
+                    StemSource::Group(group_id) => self.playback_engine.group_members(*group_id),
+                    StemSource::Tracks(ids) => ids.clone(),
+                }
+                .into_iter()
+                .collect();
+                if ids.is_empty() {
+                    Err(ExportError::RenderError(format!(
+                        "Stem '{}' has no tracks",
+                        stem.name
+                    )))
+                } else {
+                    Ok(ids)
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        std::fs::create_dir_all(&config.output_dir)
+            .map_err(|e| ExportError::IoError(e.to_string()))?;
+
+        let total_duration = if config.include_tail {
+            render_duration + config.tail_seconds
+        } else {
+            render_duration
+        };
+        let engine_rate = self.playback_engine.sample_rate();
+        let target_rate = if config.sample_rate == 0 {
+            engine_rate
+        } else {
+            config.sample_rate
+        };
+        let render_samples = (total_duration * engine_rate as f64) as usize;
+        let num_blocks = render_samples.div_ceil(config.block_size);
+        let start_sample = (config.start_time * engine_rate as f64) as usize;
+
+        self.progress.store(0.0_f64.to_bits(), Ordering::Relaxed);
+        self.cancel_flag.store(false, Ordering::SeqCst);
+
+        let extension = config.format.file_extension();
+        let mut paths = Vec::with_capacity(stems.len());
+
+        for (idx, (stem, tracks)) in stems.iter().zip(stem_tracks.iter()).enumerate() {
+            let filename = if config.prefix.is_empty() {
+                format!("{}.{}", sanitize_filename(&stem.name), extension)
+            } else {
+                format!(
+                    "{}_{}.{}",
+                    config.prefix,
+                    sanitize_filename(&stem.name),
+                    extension
+                )
+            };
+            let output_path = config.output_dir.join(filename);
+
+            let mut render_l = vec![0.0f64; render_samples];
+            let mut render_r = vec![0.0f64; render_samples];
+
+            for block_idx in 0..num_blocks {
+                if self.cancel_flag.load(Ordering::Relaxed) {
+                    log::info!("ExportEngine: stems export aborted at stem '{}'", stem.name);
+                    return Err(ExportError::Cancelled);
+                }
+                let block_start = block_idx * config.block_size;
+                let block_end = (block_start + config.block_size).min(render_samples);
+
+                self.playback_engine.process_offline_stem(
+                    start_sample + block_start,
+                    tracks,
+                    stem.include_returns,
+                    &mut render_l[block_start..block_end],
+                    &mut render_r[block_start..block_end],
+                );
+            }
+
+            if config.normalize {
+                self.normalize_audio(&mut render_l, &mut render_r);
+            }
+
+            let (final_l, final_r) = if target_rate != engine_rate {
+                resample_stereo(&render_l, &render_r, engine_rate, target_rate)
+            } else {
+                (render_l, render_r)
+            };
+
+            self.write_output(&output_path, &final_l, &final_r, target_rate, config.format)?;
+            paths.push(output_path);
+
+            let progress = ((idx + 1) as f64 / stems.len() as f64) * 100.0;
+            self.progress.store(progress.to_bits(), Ordering::Relaxed);
+        }
+
+        Ok(paths)
+    }
+}
+
+/// Sinc-resample a stereo pair (f64 → f32 SRC → f64)
+fn resample_stereo(left: &[f64], right: &[f64], from: u32, to: u32) -> (Vec<f64>, Vec<f64>) {
+    let interleaved: Vec<f32> = left
+        .iter()
+        .zip(right.iter())
+        .flat_map(|(&l, &r)| [l as f32, r as f32])
+        .collect();
+    let resampled = SampleRateConverter::convert_sinc(&interleaved, from, to, 2);
+    resampled
+        .as_chunks::<2>()
+        .0
+        .iter()
+        .map(|&[l, r]| (l as f64, r as f64))
+        .unzip()
+}
+
 /// Sanitize filename by removing invalid characters
 fn sanitize_filename(name: &str) -> String {
     name.chars()
@@ -651,6 +938,8 @@ pub enum ExportError {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::playback::add_tone_track;
+    use crate::track_manager::TrackId;
 
     #[test]
     fn test_export_config_default() {
@@ -658,6 +947,264 @@ mod tests {
         assert_eq!(config.format, ExportFormat::Wav24);
         assert_eq!(config.sample_rate, 48000);
         assert!(config.include_tail);
+        assert!(!config.render_tail);
+    }
+
+    #[test]
+    fn test_export_render_tail_until_silence() {
+        use crate::insert_chain::InsertProcessor;
+
+        // One-pole feedback "reverb": rings for ~0.2 s after the input stops
+        struct Decay {
+            feedback: f64,
+            state: [f64; 2],
+        }
+
+        impl InsertProcessor for Decay {
+            fn name(&self) -> &str {
+                "Decay"
+            }
+
+            fn process_stereo(&mut self, left: &mut [f64], right: &mut [f64]) {
+                for (ch, buffer) in [left, right].into_iter().enumerate() {
+                    for s in buffer.iter_mut() {
+                        self.state[ch] = *s + self.feedback * self.state[ch];
+                        *s = self.state[ch];
+                    }
+                }
+            }
+
+            fn reset(&mut self) {
+                self.state = [0.0; 2];
+            }
+
+            fn set_sample_rate(&mut self, _: f64) {}
+        }
+
+        let sample_rate = 48000;
+        let track_manager = Arc::new(TrackManager::new());
+        let playback_engine = Arc::new(PlaybackEngine::new(track_manager.clone(), sample_rate));
+
+        // Half a second of tone, ending exactly at the export end
+        let frames = sample_rate as usize / 2;
+        let track_id = TrackId(add_tone_track(
+            &playback_engine,
+            &track_manager,
+            "tone",
+            440.0,
+            0.01,
+            0.5,
+            OutputBus::Master,
+        ));
+
+        let export_engine = ExportEngine::new(playback_engine.clone(), track_manager);
+        let dir = tempfile::tempdir().unwrap();
+        let read_left = |path: &Path| -> Vec<f64> {
+            let bytes = std::fs::read(path).unwrap();
+            bytes[44..]
+                .chunks_exact(8)
+                .map(|frame| f32::from_le_bytes(frame[..4].try_into().unwrap()) as f64)
+                .collect()
+        };
+        let export = |name: &str, render_tail: bool| {
+            playback_engine.load_track_insert(
+                track_id.0,
+                0,
+                Box::new(Decay {
+                    feedback: 0.999,
+                    state: [0.0; 2],
+                }),
+            );
+            let config = ExportConfig {
+                output_path: dir.path().join(name),
+                format: ExportFormat::Wav32Float,
+                sample_rate: 0,
+                start_time: 0.0,
+                end_time: 0.5,
+                include_tail: false,
+                render_tail,
+                ..Default::default()
+            };
+            export_engine.export(config).unwrap();
+            read_left(&dir.path().join(name))
+        };
+
+        let cut = export("cut.wav", false);
+        assert_eq!(cut.len(), frames);
+        assert!(cut[frames - 1].abs() > 0.01, "clip ends mid-ring");
+
+        let tail = export("tail.wav", true);
+        let extra = &tail[frames..];
+        // ~0.2 s to fall from ~0.17 to -90 dBFS, well short of the 30 s cap
+        assert!(extra.len() > 4800, "tail too short: {}", extra.len());
+        assert!(extra.len() < sample_rate as usize);
+        assert!(extra[0].abs() > 0.01);
+        let peak = |s: &[f64]| s.iter().fold(0.0f64, |m, x| m.max(x.abs()));
+        assert!(peak(&extra[extra.len() / 2..]) < peak(&extra[..extra.len() / 2]));
+        assert!(extra.last().unwrap().abs() > 10.0_f64.powf(TAIL_SILENCE_DB / 20.0) * 0.5);
+        assert!(peak(&extra[extra.len() - 480..]) < 1e-3);
+    }
+
+    /// Amplitude of `freq` in a signal (single-bin DFT)
+    fn tone_level(signal: &[f64], freq: f64, sample_rate: u32) -> f64 {
+        let w = 2.0 * std::f64::consts::PI * freq / sample_rate as f64;
+        let (re, im) = signal
+            .iter()
+            .enumerate()
+            .fold((0.0, 0.0), |(re, im), (n, &x)| {
+                (re + x * (w * n as f64).cos(), im + x * (w * n as f64).sin())
+            });
+        2.0 * (re * re + im * im).sqrt() / signal.len() as f64
+    }
+
+    /// Left channel of a 32-bit float stereo WAV
+    fn read_wav_left(path: &Path) -> Vec<f64> {
+        let bytes = std::fs::read(path).unwrap();
+        bytes[44..]
+            .chunks_exact(8)
+            .map(|frame| f32::from_le_bytes(frame[..4].try_into().unwrap()) as f64)
+            .collect()
+    }
+
+    fn stems_config(dir: &Path) -> StemsConfig {
+        StemsConfig {
+            output_dir: dir.to_path_buf(),
+            format: ExportFormat::Wav32Float,
+            sample_rate: 0,
+            start_time: 0.0,
+            end_time: 1.0,
+            include_tail: false,
+            prefix: "mix".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_export_stems_two_tracks() {
+        let sample_rate = 48000;
+        let track_manager = Arc::new(TrackManager::new());
+        let playback_engine = Arc::new(PlaybackEngine::new(track_manager.clone(), sample_rate));
+
+        // 440 Hz on Music, 1 kHz on Sfx
+        let _music = add_tone_track(
+            &playback_engine,
+            &track_manager,
+            "music",
+            440.0,
+            0.25,
+            1.0,
+            OutputBus::Music,
+        );
+        let sfx = add_tone_track(
+            &playback_engine,
+            &track_manager,
+            "sfx",
+            1000.0,
+            0.25,
+            1.0,
+            OutputBus::Sfx,
+        );
+
+        let export_engine = ExportEngine::new(playback_engine, track_manager);
+        let dir = tempfile::tempdir().unwrap();
+        let stems = [
+            StemDef::bus("Music Bus", OutputBus::Music),
+            StemDef::tracks("sfx", &[sfx]).with_returns(false),
+        ];
+
+        let paths = export_engine
+            .export_stems(&stems, &stems_config(dir.path()))
+            .unwrap();
+        assert_eq!(
+            paths,
+            vec![
+                dir.path().join("mix_Music Bus.wav"),
+                dir.path().join("mix_sfx.wav")
+            ]
+        );
+
+        // Each stem carries only its own tone
+        let music = read_wav_left(&paths[0]);
+        let sfx = read_wav_left(&paths[1]);
+        assert!(tone_level(&music, 440.0, sample_rate) > 0.05);
+        assert!(tone_level(&music, 1000.0, sample_rate) < 0.005);
+        assert!(tone_level(&sfx, 1000.0, sample_rate) > 0.05);
+        assert!(tone_level(&sfx, 440.0, sample_rate) < 0.005);
+        assert!(!export_engine.is_exporting());
+    }
+
+    #[test]
+    fn test_export_stems_include_returns() {
+        let sample_rate = 48000;
+        let track_manager = Arc::new(TrackManager::new());
+        let playback_engine = Arc::new(PlaybackEngine::new(track_manager.clone(), sample_rate));
+
+        // Voice track with a unity post-fader send to the Aux (reverb return) bus
+        let vox = add_tone_track(
+            &playback_engine,
+            &track_manager,
+            "vox",
+            440.0,
+            0.25,
+            1.0,
+            OutputBus::Voice,
+        );
+        track_manager.update_track(TrackId(vox), |track| {
+            track.set_send_destination(0, Some(OutputBus::Aux));
+            track.set_send_level(0, 1.0);
+        });
+
+        let export_engine = ExportEngine::new(playback_engine, track_manager);
+        let dir = tempfile::tempdir().unwrap();
+        let stems = [
+            StemDef::tracks("wet", &[vox]),
+            StemDef::tracks("dry", &[vox]).with_returns(false),
+        ];
+        let paths = export_engine
+            .export_stems(&stems, &stems_config(dir.path()))
+            .unwrap();
+
+        // The wet stem adds the return on top of the direct signal
+        let wet = tone_level(&read_wav_left(&paths[0]), 440.0, sample_rate);
+        let dry = tone_level(&read_wav_left(&paths[1]), 440.0, sample_rate);
+        assert!(dry > 0.05);
+        assert!((wet / dry - 2.0).abs() < 0.1, "wet {} dry {}", wet, dry);
+    }
+
+    #[test]
+    fn test_process_offline_renders_sends() {
+        let sample_rate = 48000;
+        let track_manager = Arc::new(TrackManager::new());
+        let playback_engine = PlaybackEngine::new(track_manager.clone(), sample_rate);
+
+        // Fader down: only a pre-fader send can reach the output
+        let track = add_tone_track(
+            &playback_engine,
+            &track_manager,
+            "tone",
+            440.0,
+            0.25,
+            1.0,
+            OutputBus::Music,
+        );
+        let render = |pre_fader: bool| {
+            track_manager.update_track(TrackId(track), |t| {
+                t.volume = 0.0;
+                t.set_send_destination(0, Some(OutputBus::Aux));
+                t.set_send_level(0, 1.0);
+                t.set_send_pre_fader(0, pre_fader);
+            });
+            let mut left = vec![0.0; sample_rate as usize];
+            let mut right = vec![0.0; sample_rate as usize];
+            playback_engine.process_offline(0, &mut left, &mut right);
+            tone_level(&left, 440.0, sample_rate)
+        };
+
+        assert!(
+            render(true) > 0.05,
+            "pre-fader send should survive the fader"
+        );
+        assert!(render(false) < 1e-6, "post-fader send follows the fader");
     }
 
     #[test]
@@ -142,7 +142,13 @@ static METADATA_THREAD_POOL: LazyLock<rayon::ThreadPool> = LazyLock::new(|| rayo
         .thread_name(|i| format!("ff-metadata-{}", i))
         .build()
         .expect("Failed to create metadata thread pool"));
-pub static PLAYBACK_ENGINE: LazyLock<Arc<PlaybackEngine>> = LazyLock::new(|| Arc::new(PlaybackEngine::new(Arc::clone(&TRACK_MANAGER), 48000)));
+pub static PLAYBACK_ENGINE: LazyLock<Arc<PlaybackEngine>> = LazyLock::new(|| {
+    let mut engine = PlaybackEngine::new(Arc::clone(&TRACK_MANAGER), 48000);
+    // Folders/VCAs edited through the FFI are the ones the audio thread reads
+    engine.set_group_manager(Arc::clone(&GROUP_MANAGER));
+    engine.set_modulation(Arc::clone(&MODULATION_ENGINE));
+    Arc::new(engine)
+});
 /// Last import error message (thread-safe error tracking for FFI)
 static LAST_IMPORT_ERROR: LazyLock<RwLock<Option<String>>> = LazyLock::new(|| RwLock::new(None));
 static UNDO_MANAGER: LazyLock<RwLock<UndoManager>> = LazyLock::new(|| RwLock::new(UndoManager::new(500)));
@@ -1819,6 +1825,45 @@ pub extern "C" fn engine_set_clip_gain(clip_id: u64, gain: f64) -> i32 {
     1
 }
 
+/// Set clip gain in dB (up to +12dB)
+#[unsafe(no_mangle)]
+pub extern "C" fn engine_set_clip_gain_db(clip_id: u64, gain_db: f64) -> i32 {
+    TRACK_MANAGER.update_clip(ClipId(clip_id), |clip| {
+        clip.set_gain_db(gain_db);
+    });
+    1
+}
+
+/// Set clip playback fades (durations in seconds)
+/// curve: 0=Linear, 1=EqualPower, 2=SCurve, 3=Logarithmic, 4=Exponential, 5=Quadratic
+#[unsafe(no_mangle)]
+pub extern "C" fn engine_set_clip_fades(
+    clip_id: u64,
+    fade_in_secs: f64,
+    fade_in_curve: u8,
+    fade_out_secs: f64,
+    fade_out_curve: u8,
+) -> i32 {
+    TRACK_MANAGER.update_clip(ClipId(clip_id), |clip| {
+        clip.fade_in = fade_in_secs.clamp(0.0, clip.duration);
+        clip.fade_out = fade_out_secs.clamp(0.0, clip.duration);
+        clip.fade_in_curve = clip_fade_curve(fade_in_curve);
+        clip.fade_out_curve = clip_fade_curve(fade_out_curve);
+    });
+    1
+}
+
+fn clip_fade_curve(curve: u8) -> CrossfadeCurve {
+    match curve {
+        1 => CrossfadeCurve::EqualPower,
+        2 => CrossfadeCurve::SCurve,
+        3 => CrossfadeCurve::Logarithmic,
+        4 => CrossfadeCurve::Exponential,
+        5 => CrossfadeCurve::Quadratic,
+        _ => CrossfadeCurve::Linear,
+    }
+}
+
 /// Set clip mute state
 #[unsafe(no_mangle)]
 pub extern "C" fn engine_set_clip_muted(clip_id: u64, muted: i32) -> i32 {
@@ -2995,8 +3040,8 @@ pub extern "C" fn engine_set_sample_rate(sample_rate: u32) -> i32 {
         }
     }
 
-    // Update PlaybackEngine
-    PLAYBACK_ENGINE.position.set_sample_rate(sample_rate);
+    // Update PlaybackEngine (position, meters, sidechain key filters)
+    PLAYBACK_ENGINE.set_sample_rate(sample_rate);
 
     // Note: AutomationEngine.set_sample_rate requires &mut self but AUTOMATION_ENGINE
     // is LazyLock (shared). SR is only used for time↔sample conversion in automation.
@@ -3116,6 +3161,20 @@ pub extern "C" fn engine_get_master_soft_clip() -> i32 {
     if PLAYBACK_ENGINE.master_soft_clip_enabled() { 1 } else { 0 }
 }
 
+/// Set output soft-start/stop ramp time in milliseconds (0 = off, max 500)
+#[unsafe(no_mangle)]
+pub extern "C" fn engine_set_output_ramp_ms(ms: f64) {
+    if ms.is_finite() {
+        PLAYBACK_ENGINE.output_ramp().set_ramp_ms(ms);
+    }
+}
+
+/// Get output soft-start/stop ramp time in milliseconds
+#[unsafe(no_mangle)]
+pub extern "C" fn engine_get_output_ramp_ms() -> f64 {
+    PLAYBACK_ENGINE.output_ramp().ramp_ms()
+}
+
 /// Get bus volume
 #[unsafe(no_mangle)]
 pub extern "C" fn engine_get_bus_volume(bus_idx: i32) -> f64 {
@@ -3308,6 +3367,42 @@ pub extern "C" fn metering_get_master_balance() -> f32 {
     SharedMeterBuffer::read_f64(&SHARED_METERS.balance) as f32
 }
 
+/// Get master stereo width (side/mid RMS ratio, 0.0 = mono)
+/// Reads from SHARED_METERS (single source of truth for all metering)
+#[unsafe(no_mangle)]
+pub extern "C" fn metering_get_master_stereo_width() -> f32 {
+    SharedMeterBuffer::read_f64(&SHARED_METERS.stereo_width) as f32
+}
+
+/// Get broadband level lost folding the master to mono (dB, 0.0 = mono-compatible)
+/// Reads from SHARED_METERS (single source of truth for all metering)
+#[unsafe(no_mangle)]
+pub extern "C" fn metering_get_master_mono_fold_loss() -> f32 {
+    SharedMeterBuffer::read_f64(&SHARED_METERS.mono_fold_loss) as f32
+}
+
+/// Get per-band mono-fold loss in dB (bass, lowmid, highmid, treble)
+/// Returns number of bands written (up to max_count)
+#[unsafe(no_mangle)]
+pub extern "C" fn metering_get_master_mono_fold_band_loss(
+    out_data: *mut f32,
+    max_count: usize,
+) -> usize {
+    if out_data.is_null() {
+        return 0;
+    }
+    let count = SHARED_METERS.mono_fold_band_loss.len().min(max_count);
+    for (i, slot) in SHARED_METERS.mono_fold_band_loss[..count]
+        .iter()
+        .enumerate()
+    {
+        unsafe {
+            *out_data.add(i) = SharedMeterBuffer::read_f64(slot) as f32;
+        }
+    }
+    count
+}
+
 /// Get master dynamic range (peak - RMS in dB)
 #[unsafe(no_mangle)]
 pub extern "C" fn metering_get_master_dynamic_range() -> f32 {
@@ -4236,6 +4331,11 @@ pub extern "C" fn click_set_tempo(bpm: f64) {
     PLAYBACK_ENGINE.position.set_tempo(bpm);
     // BUG#7 FIX: Propagate BPM to all tempo-synced insert processors
     PLAYBACK_ENGINE.sync_bpm_all_inserts(bpm);
+    // Tempo-synced simple delays glide to the new note length
+    let context = delay_process_context();
+    for delay in SIMPLE_DELAYS.write().values_mut() {
+        delay.set_context(&context);
+    }
 }
 
 /// Get click tempo (BPM)
@@ -4329,7 +4429,19 @@ pub extern "C" fn click_get_preset() -> u8 {
 /// Start count-in sequence (call before transport play/record)
 #[unsafe(no_mangle)]
 pub extern "C" fn click_start_count_in() {
-    CLICK_TRACK.write().start_count_in();
+    CLICK_TRACK.write().start_count_in_from_mode();
+}
+
+/// Start a count-in of `bars` bars at the current time signature
+#[unsafe(no_mangle)]
+pub extern "C" fn click_start_count_in_bars(bars: u32) {
+    CLICK_TRACK.write().start_count_in(bars);
+}
+
+/// Get total beats of the current count-in (for visual countdown)
+#[unsafe(no_mangle)]
+pub extern "C" fn click_get_count_in_total_beats() -> u32 {
+    CLICK_TRACK.read().get_count_in_total_beats()
 }
 
 /// Check if count-in is currently active (1=active, 0=inactive)
@@ -4348,6 +4460,39 @@ pub extern "C" fn click_get_count_in_beat() -> i32 {
     CLICK_TRACK.read().get_count_in_beat()
 }
 
+/// Poll count-in events (beat clicks, record start) as a JSON string
+///
+/// Returns a JSON array, oldest first:
+/// `[{"type":"beat","index":0,"total":4,"beat_in_bar":0,"sample":0},
+///   {"type":"record_start","sample":96000}]`
+///
+/// Caller must free the returned string with `engine_free_string`.
+#[unsafe(no_mangle)]
+pub extern "C" fn click_poll_count_in_events() -> *mut c_char {
+    use crate::click::CountInEvent;
+
+    let events: Vec<String> = CLICK_TRACK
+        .write()
+        .drain_count_in_events()
+        .into_iter()
+        .map(|event| match event {
+            CountInEvent::Beat {
+                index,
+                total,
+                beat_in_bar,
+                sample,
+            } => format!(
+                r#"{{"type":"beat","index":{index},"total":{total},"beat_in_bar":{beat_in_bar},"sample":{sample}}}"#
+            ),
+            CountInEvent::RecordStart { sample } => {
+                format!(r#"{{"type":"record_start","sample":{sample}}}"#)
+            }
+        })
+        .collect();
+
+    string_to_cstr(&format!("[{}]", events.join(",")))
+}
+
 // ── Tap Tempo ──
 
 /// Record a tap and return calculated BPM
@@ -4740,7 +4885,6 @@ pub extern "C" fn orb_capture_last_n_seconds(
 // SIDECHAIN ROUTING FFI
 // ═══════════════════════════════════════════════════════════════════════════
 
-static SIDECHAIN_ROUTER: LazyLock<parking_lot::RwLock<crate::sidechain::SidechainRouter>> = LazyLock::new(|| parking_lot::RwLock::new(crate::sidechain::SidechainRouter::new(512)));
 static SIDECHAIN_INPUTS: LazyLock<parking_lot::RwLock<std::collections::HashMap<u32, crate::sidechain::SidechainInput>>> = LazyLock::new(|| parking_lot::RwLock::new(std::collections::HashMap::new()));
 
 /// Add a sidechain route
@@ -4751,18 +4895,63 @@ pub extern "C" fn sidechain_add_route(
     dest_processor_id: u32,
     pre_fader: i32,
 ) -> u32 {
-    let mut router = SIDECHAIN_ROUTER.write();
+    let mut router = PLAYBACK_ENGINE.sidechain_router().write();
     router.add_route(source_id, dest_processor_id, pre_fader != 0)
 }
 
+/// Add a sidechain route keyed from a bus/return
+/// tap_point: 0=PreFader, 1=PostFader, 2=PostPan
+/// dest_processor_id from sidechain_bus_insert_processor_id keys a bus insert slot
+/// Returns route ID (non-zero) or 0 on failure
+#[unsafe(no_mangle)]
+pub extern "C" fn sidechain_add_bus_route(
+    bus_id: u32,
+    dest_processor_id: u32,
+    tap_point: u8,
+) -> u32 {
+    use crate::send_return::SendTapPoint;
+    let tap = match tap_point {
+        0 => SendTapPoint::PreFader,
+        2 => SendTapPoint::PostPan,
+        _ => SendTapPoint::PostFader,
+    };
+    PLAYBACK_ENGINE.add_sidechain_bus_route(bus_id as usize, tap, dest_processor_id)
+}
+
+/// Sidechain processor ID of a bus insert slot
+/// bus_id: 0=Master routing, 1=Music, 2=Sfx, 3=Voice, 4=Amb, 5=Aux; slot_index: 0-7
+#[unsafe(no_mangle)]
+pub extern "C" fn sidechain_bus_insert_processor_id(bus_id: u32, slot_index: u32) -> u32 {
+    PlaybackEngine::bus_insert_processor_id(bus_id as usize, slot_index as usize)
+}
+
 /// Remove a sidechain route
 /// Returns 1 on success, 0 on failure
 #[unsafe(no_mangle)]
 pub extern "C" fn sidechain_remove_route(route_id: u32) -> i32 {
-    let mut router = SIDECHAIN_ROUTER.write();
+    let mut router = PLAYBACK_ENGINE.sidechain_router().write();
     if router.remove_route(route_id) { 1 } else { 0 }
 }
 
+/// Set the key filter of a sidechain route (applied before detection)
+/// mode: 0=Off, 1=HighPass, 2=LowPass, 3=BandPass; freq: 20-20000 Hz
+/// Returns 1 on success, 0 if the route doesn't exist
+#[unsafe(no_mangle)]
+pub extern "C" fn sidechain_set_route_key_filter(route_id: u32, mode: u8, freq: f64) -> i32 {
+    let mut router = PLAYBACK_ENGINE.sidechain_router().write();
+    if router.set_route_key_filter(route_id, sidechain_filter_mode(mode), freq) { 1 } else { 0 }
+}
+
+fn sidechain_filter_mode(mode: u8) -> crate::sidechain::SidechainFilterMode {
+    use crate::sidechain::SidechainFilterMode;
+    match mode {
+        1 => SidechainFilterMode::HighPass,
+        2 => SidechainFilterMode::LowPass,
+        3 => SidechainFilterMode::BandPass,
+        _ => SidechainFilterMode::Off,
+    }
+}
+
 /// Create sidechain input for a processor
 #[unsafe(no_mangle)]
 pub extern "C" fn sidechain_create_input(processor_id: u32) {
@@ -4780,8 +4969,8 @@ pub extern "C" fn sidechain_remove_input(processor_id: u32) {
 }
 
 /// Set sidechain source type
-/// source_type: 0=Internal, 1=External, 2=Mid, 3=Side
-/// external_id: Source track ID (only used when source_type=1)
+/// source_type: 0=Internal, 1=External, 2=Mid, 3=Side, 4=Bus
+/// external_id: Source track ID (source_type=1) or bus/return ID (source_type=4)
 #[unsafe(no_mangle)]
 pub extern "C" fn sidechain_set_source(processor_id: u32, source_type: u8, external_id: u32) {
     use crate::sidechain::SidechainSource;
@@ -4790,6 +4979,7 @@ pub extern "C" fn sidechain_set_source(processor_id: u32, source_type: u8, exter
         1 => SidechainSource::External(external_id),
         2 => SidechainSource::Mid,
         3 => SidechainSource::Side,
+        4 => SidechainSource::Bus(external_id),
         _ => SidechainSource::Internal,
     };
     let mut inputs = SIDECHAIN_INPUTS.write();
@@ -4802,14 +4992,7 @@ pub extern "C" fn sidechain_set_source(processor_id: u32, source_type: u8, exter
 /// mode: 0=Off, 1=HighPass, 2=LowPass, 3=BandPass
 #[unsafe(no_mangle)]
 pub extern "C" fn sidechain_set_filter_mode(processor_id: u32, mode: u8) {
-    use crate::sidechain::SidechainFilterMode;
-    let filter_mode = match mode {
-        0 => SidechainFilterMode::Off,
-        1 => SidechainFilterMode::HighPass,
-        2 => SidechainFilterMode::LowPass,
-        3 => SidechainFilterMode::BandPass,
-        _ => SidechainFilterMode::Off,
-    };
+    let filter_mode = sidechain_filter_mode(mode);
     let mut inputs = SIDECHAIN_INPUTS.write();
     if let Some(input) = inputs.get_mut(&processor_id) {
         input.set_filter_mode(filter_mode);
@@ -5281,6 +5464,21 @@ pub extern "C" fn automation_release_plugin(track_id: u64, slot: u32, param_inde
 // AUTOMATION ITEMS FFI (Reaper-style pooled containerized automation)
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// LFO shape from FFI index
+/// 0=Sine, 1=Triangle, 2=Square, 3=SawUp, 4=SawDown, 5=Random, 6=S&H
+fn lfo_shape_from_u8(shape: u8) -> crate::automation::LfoShape {
+    match shape {
+        0 => crate::automation::LfoShape::Sine,
+        1 => crate::automation::LfoShape::Triangle,
+        2 => crate::automation::LfoShape::Square,
+        3 => crate::automation::LfoShape::SawUp,
+        4 => crate::automation::LfoShape::SawDown,
+        5 => crate::automation::LfoShape::Random,
+        6 => crate::automation::LfoShape::SampleAndHold,
+        _ => crate::automation::LfoShape::Sine,
+    }
+}
+
 static AUTO_ITEM_MANAGER: LazyLock<crate::automation::AutomationItemManager> = LazyLock::new(|| crate::automation::AutomationItemManager::new(48000.0));
 
 /// Add an LFO automation item to a lane.
@@ -5303,16 +5501,7 @@ pub extern "C" fn auto_item_add_lfo(
         }
     };
 
-    let shape = match lfo_shape {
-        0 => crate::automation::LfoShape::Sine,
-        1 => crate::automation::LfoShape::Triangle,
-        2 => crate::automation::LfoShape::Square,
-        3 => crate::automation::LfoShape::SawUp,
-        4 => crate::automation::LfoShape::SawDown,
-        5 => crate::automation::LfoShape::Random,
-        6 => crate::automation::LfoShape::SampleAndHold,
-        _ => crate::automation::LfoShape::Sine,
-    };
+    let shape = lfo_shape_from_u8(lfo_shape);
 
     let param_id = crate::automation::ParamId {
         target_id: track_id,
@@ -5675,6 +5864,81 @@ pub extern "C" fn auto_item_clear_lane(
     AUTO_ITEM_MANAGER.clear_lane(&param_id);
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// LFO MODULATION FFI
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// LFO modulation engine, evaluated by `PLAYBACK_ENGINE` every block
+static MODULATION_ENGINE: LazyLock<Arc<crate::modulation::ModulationEngine>> = LazyLock::new(|| Arc::new(crate::modulation::ModulationEngine::new()));
+
+/// Add an LFO. Returns LFO ID.
+/// shape: 0=Sine, 1=Triangle, 2=Square, 3=SawUp, 4=SawDown, 5=Random, 6=S&H
+/// synced != 0: `rate` is quarter notes per cycle (1.0 = 1/4); otherwise Hz
+#[unsafe(no_mangle)]
+pub extern "C" fn modulation_add_lfo(shape: u8, synced: i32, rate: f64) -> u32 {
+    use crate::modulation::LfoConfig;
+
+    let shape = lfo_shape_from_u8(shape);
+    let config = if synced != 0 {
+        LfoConfig::synced(shape, rate)
+    } else {
+        LfoConfig::free(shape, rate)
+    };
+    MODULATION_ENGINE.add_lfo(config).0
+}
+
+/// Remove an LFO and its assignments
+#[unsafe(no_mangle)]
+pub extern "C" fn modulation_remove_lfo(lfo_id: u32) -> i32 {
+    let removed = MODULATION_ENGINE.remove_lfo(crate::modulation::LfoId(lfo_id));
+    if removed { 1 } else { 0 }
+}
+
+/// Assign an LFO to a track parameter ("volume", "pan", "mute", ...)
+/// depth: -1.0 to 1.0, offset: center value (0.0-1.0) when not automated
+#[unsafe(no_mangle)]
+pub extern "C" fn modulation_assign_track_param(
+    lfo_id: u32,
+    track_id: u64,
+    param_name: *const c_char,
+    depth: f64,
+    offset: f64,
+) -> i32 {
+    let Some(param_name) = (unsafe { cstr_to_string(param_name) }) else {
+        return 0;
+    };
+    let param_id = crate::automation::ParamId {
+        target_id: track_id,
+        target_type: crate::automation::TargetType::Track,
+        param_name,
+        slot: None,
+    };
+    let lfo_id = crate::modulation::LfoId(lfo_id);
+    let assigned = MODULATION_ENGINE.assign_with_offset(lfo_id, param_id, depth, offset);
+    if assigned { 1 } else { 0 }
+}
+
+/// Remove an LFO → track parameter assignment
+#[unsafe(no_mangle)]
+pub extern "C" fn modulation_unassign_track_param(
+    lfo_id: u32,
+    track_id: u64,
+    param_name: *const c_char,
+) -> i32 {
+    let Some(param_name) = (unsafe { cstr_to_string(param_name) }) else {
+        return 0;
+    };
+    let param_id = crate::automation::ParamId {
+        target_id: track_id,
+        target_type: crate::automation::TargetType::Track,
+        param_name,
+        slot: None,
+    };
+    let lfo_id = crate::modulation::LfoId(lfo_id);
+    let unassigned = MODULATION_ENGINE.unassign(lfo_id, &param_id);
+    if unassigned { 1 } else { 0 }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // INSERT EFFECTS FFI
 // ═══════════════════════════════════════════════════════════════════════════
@@ -6437,6 +6701,9 @@ pub extern "C" fn engine_start_playback() -> i32 {
         return 1; // Already running
     }
 
+    // Soft-start: first blocks ramp up from silence
+    PLAYBACK_ENGINE.output_ramp().fade_in();
+
     // Create channel for shutdown signal
     let (shutdown_tx, shutdown_rx) = mpsc::channel::<()>();
 
@@ -6742,6 +7009,15 @@ pub extern "C" fn engine_stop_playback() {
         return;
     }
 
+    // Soft-stop: let the callback ramp output to silence before the stream drops
+    let ramp = PLAYBACK_ENGINE.output_ramp();
+    ramp.fade_out();
+    let deadline = std::time::Instant::now()
+        + std::time::Duration::from_secs_f64(ramp.ramp_ms() / 1000.0 + 0.05);
+    while !ramp.is_silent() && std::time::Instant::now() < deadline {
+        std::thread::sleep(std::time::Duration::from_millis(2));
+    }
+
     AUDIO_STREAM_RUNNING.store(false, Ordering::Release);
     PLAYBACK_ENGINE.pause();
 
@@ -8152,6 +8428,77 @@ pub extern "C" fn insert_get_metering_json(track_id: u32, slot_index: u32) -> *m
     })
 }
 
+/// Engine `NodeStats` of an insert slot as the chain view's `SlotStats`
+fn insert_slot_stats(slot: &crate::insert_chain::InsertSlot) -> rf_viz::SlotStats {
+    rf_viz::SlotStats {
+        avg_time_us: slot.stats().average_us(),
+        latency_samples: slot.latency() as u32,
+    }
+}
+
+/// Chain view of the loaded inserts, fed with their live stats
+fn insert_chain_state(
+    chain: &crate::insert_chain::InsertChain,
+    block_time_us: f64,
+) -> rf_viz::PluginChainState {
+    let mut state = rf_viz::PluginChainState::new();
+    let mut stats = Vec::new();
+    for (index, name) in chain.loaded_slots() {
+        let Some(slot) = chain.slot(index) else {
+            continue;
+        };
+        let mut view = rf_viz::ChainSlotState::new(index, name, name);
+        view.bypassed = slot.is_bypassed();
+        view.mix = slot.mix() as f32;
+        state.add_slot(view);
+        stats.push(insert_slot_stats(slot));
+    }
+    state.apply_stats(&stats, block_time_us);
+    state
+}
+
+/// Get live CPU/latency stats for every loaded insert as JSON
+/// track_id=0 means master bus, others are audio track IDs
+///
+/// `cpu_percent` is the slot's average processing time as a share of
+/// one audio block.
+///
+/// Returns JSON:
+/// ```json
+/// {
+///   "slots": [{ "index": 0, "plugin_name": "Pro-EQ", "cpu_percent": 4.2, "latency_samples": 0, ... }],
+///   "total_cpu": 4.2,
+///   "total_latency": 0
+/// }
+/// ```
+///
+/// CALLER MUST FREE using free_string()
+#[unsafe(no_mangle)]
+pub extern "C" fn insert_get_chain_stats_json(track_id: u32) -> *mut c_char {
+    ffi_panic_guard!(std::ptr::null_mut(), {
+        let sample_rate = PLAYBACK_ENGINE.sample_rate().max(1) as f64;
+        let block_time_us = PLAYBACK_ENGINE.max_block_size() as f64 / sample_rate * 1e6;
+
+        let Some(state) = PLAYBACK_ENGINE
+            .with_insert_chain(track_id as u64, |chain| insert_chain_state(chain, block_time_us))
+        else {
+            return std::ptr::null_mut();
+        };
+
+        let json = serde_json::json!({
+            "slots": state.slots,
+            "total_cpu": state.total_cpu,
+            "total_latency": state.total_latency,
+        });
+
+        let json_str = serde_json::to_string(&json).unwrap_or_default();
+        match CString::new(json_str) {
+            Ok(s) => s.into_raw(),
+            Err(_) => cstring_literal!("{}"),
+        }
+    })
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // BUS INSERT CHAIN FFI
 // ═══════════════════════════════════════════════════════════════════════════
@@ -8758,6 +9105,8 @@ pub extern "C" fn track_duplicate(track_id: u64) -> u64 {
         new_clip.source_duration = clip.source_duration;
         new_clip.fade_in = clip.fade_in;
         new_clip.fade_out = clip.fade_out;
+        new_clip.fade_in_curve = clip.fade_in_curve.clone();
+        new_clip.fade_out_curve = clip.fade_out_curve.clone();
         new_clip.gain = clip.gain;
         TRACK_MANAGER.add_clip(new_clip);
     }
@@ -8784,7 +9133,8 @@ pub extern "C" fn track_set_color(track_id: u64, color: u32) -> i32 {
 
 use crate::groups::{GroupManager, LinkMode, LinkParameter};
 
-static GROUP_MANAGER: LazyLock<RwLock<GroupManager>> = LazyLock::new(|| RwLock::new(GroupManager::new()));
+/// Group/VCA/folder state, shared with `PLAYBACK_ENGINE`
+static GROUP_MANAGER: LazyLock<Arc<RwLock<GroupManager>>> = LazyLock::new(|| Arc::new(RwLock::new(GroupManager::new())));
 
 /// Create a new VCA fader
 /// Returns VCA ID
@@ -9280,11 +9630,20 @@ pub extern "C" fn group_set_color(group_id: u64, color: u32) -> i32 {
 // FOLDER TRACK FFI
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Apply a change to a folder, then resync the engine's folder buses
+/// Returns 1 if the folder exists, 0 otherwise
+fn update_folder(folder_id: u64, update: impl FnOnce(&mut crate::groups::FolderTrack)) -> i32 {
+    let found = GROUP_MANAGER.write().folders.get_mut(&folder_id).map(update).is_some();
+    PLAYBACK_ENGINE.sync_folder_buses();
+    if found { 1 } else { 0 }
+}
+
 /// Create a folder track
 #[unsafe(no_mangle)]
 pub extern "C" fn folder_create(folder_id: u64, name: *const c_char) -> i32 {
     let name = unsafe { cstr_to_string(name) }.unwrap_or_else(|| "Folder".to_string());
     GROUP_MANAGER.write().create_folder(folder_id, &name);
+    PLAYBACK_ENGINE.sync_folder_buses();
     1
 }
 
@@ -9292,43 +9651,44 @@ pub extern "C" fn folder_create(folder_id: u64, name: *const c_char) -> i32 {
 #[unsafe(no_mangle)]
 pub extern "C" fn folder_delete(folder_id: u64) -> i32 {
     GROUP_MANAGER.write().delete_folder(folder_id);
+    PLAYBACK_ENGINE.sync_folder_buses();
     1
 }
 
 /// Add child track to folder
 #[unsafe(no_mangle)]
 pub extern "C" fn folder_add_child(folder_id: u64, track_id: u64) -> i32 {
-    let mut mgr = GROUP_MANAGER.write();
-    if let Some(folder) = mgr.folders.get_mut(&folder_id) {
-        folder.add_child(track_id);
-        1
-    } else {
-        0
-    }
+    update_folder(folder_id, |folder| folder.add_child(track_id))
 }
 
 /// Remove child track from folder
 #[unsafe(no_mangle)]
 pub extern "C" fn folder_remove_child(folder_id: u64, track_id: u64) -> i32 {
-    let mut mgr = GROUP_MANAGER.write();
-    if let Some(folder) = mgr.folders.get_mut(&folder_id) {
-        folder.remove_child(track_id);
-        1
-    } else {
-        0
-    }
+    update_folder(folder_id, |folder| folder.remove_child(track_id))
 }
 
 /// Toggle folder expanded state
 #[unsafe(no_mangle)]
 pub extern "C" fn folder_toggle(folder_id: u64) -> i32 {
-    let mut mgr = GROUP_MANAGER.write();
-    if let Some(folder) = mgr.folders.get_mut(&folder_id) {
-        folder.toggle();
-        1
-    } else {
-        0
-    }
+    update_folder(folder_id, |folder| folder.toggle())
+}
+
+/// Set folder fader level in dB (applied to summed children)
+#[unsafe(no_mangle)]
+pub extern "C" fn folder_set_volume(folder_id: u64, db: f64) -> i32 {
+    update_folder(folder_id, |folder| folder.set_volume(db))
+}
+
+/// Set folder mute (mutes all children's contribution)
+#[unsafe(no_mangle)]
+pub extern "C" fn folder_set_mute(folder_id: u64, muted: i32) -> i32 {
+    update_folder(folder_id, |folder| folder.muted = muted != 0)
+}
+
+/// Set folder bus output (0=Master, 1=Music, 2=Sfx, 3=Voice, 4=Ambience, 5=Aux)
+#[unsafe(no_mangle)]
+pub extern "C" fn folder_set_output_bus(folder_id: u64, bus: u32) -> i32 {
+    update_folder(folder_id, |folder| folder.output_bus = OutputBus::from(bus))
 }
 
 /// Check if folder is expanded
@@ -9381,13 +9741,7 @@ pub extern "C" fn folder_get_parent(track_id: u64) -> u64 {
 /// Set folder color
 #[unsafe(no_mangle)]
 pub extern "C" fn folder_set_color(folder_id: u64, color: u32) -> i32 {
-    let mut mgr = GROUP_MANAGER.write();
-    if let Some(folder) = mgr.folders.get_mut(&folder_id) {
-        folder.color = color;
-        1
-    } else {
-        0
-    }
+    update_folder(folder_id, |folder| folder.color = color)
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -10952,16 +11306,24 @@ static PING_PONG_DELAYS: LazyLock<parking_lot::RwLock<std::collections::HashMap<
 static MULTI_TAP_DELAYS: LazyLock<parking_lot::RwLock<std::collections::HashMap<u32, rf_dsp::delay::MultiTapDelay>>> = LazyLock::new(|| parking_lot::RwLock::new(std::collections::HashMap::new()));
 static MODULATED_DELAYS: LazyLock<parking_lot::RwLock<std::collections::HashMap<u32, rf_dsp::delay::ModulatedDelay>>> = LazyLock::new(|| parking_lot::RwLock::new(std::collections::HashMap::new()));
 
+/// Current transport state for tempo-synced delays
+fn delay_process_context() -> rf_dsp::ProcessContext {
+    rf_dsp::ProcessContext {
+        sample_rate: PLAYBACK_ENGINE.position.sample_rate() as f64,
+        tempo: PLAYBACK_ENGINE.position.get_tempo().unwrap_or(120.0),
+        ..Default::default()
+    }
+}
+
 // --- Simple Delay ---
 
 /// Create simple delay for track
 #[unsafe(no_mangle)]
 pub extern "C" fn simple_delay_create(track_id: u32, sample_rate: f64, max_delay_ms: f64) -> i32 {
-    let mut delays = SIMPLE_DELAYS.write();
-    delays.insert(
-        track_id,
-        rf_dsp::delay::Delay::new(sample_rate, max_delay_ms),
-    );
+    let mut delay = rf_dsp::delay::Delay::new(sample_rate, max_delay_ms);
+    // Sync current project BPM immediately on creation
+    delay.set_context(&delay_process_context());
+    SIMPLE_DELAYS.write().insert(track_id, delay);
     1
 }
 
@@ -10984,6 +11346,27 @@ pub extern "C" fn simple_delay_set_time(track_id: u32, delay_ms: f64) -> i32 {
     }
 }
 
+/// Set tempo-synced delay time (division: 4 = 1/4, 8 = 1/8, ...)
+#[unsafe(no_mangle)]
+pub extern "C" fn simple_delay_set_sync(
+    track_id: u32,
+    division: u32,
+    dotted: i32,
+    triplet: i32,
+) -> i32 {
+    let mut delays = SIMPLE_DELAYS.write();
+    if let Some(delay) = delays.get_mut(&track_id) {
+        delay.set_delay_time(rf_dsp::delay::DelayTime::Sync {
+            division,
+            dotted: dotted != 0,
+            triplet: triplet != 0,
+        });
+        1
+    } else {
+        0
+    }
+}
+
 /// Set feedback (0.0-0.99)
 #[unsafe(no_mangle)]
 pub extern "C" fn simple_delay_set_feedback(track_id: u32, feedback: f64) -> i32 {
@@ -13410,6 +13793,8 @@ pub extern "C" fn spectral_gate_destroy(track_id: u32) -> i32 {
     }
 }
 
+/// Gate threshold in dB: flat dBFS until a noise profile is learned, then it
+/// shifts every bin's threshold relative to the profile (-40 dB = profile + offset)
 #[unsafe(no_mangle)]
 pub extern "C" fn spectral_gate_set_threshold(track_id: u32, db: f64) -> i32 {
     let mut gates = SPECTRAL_GATES.write();
@@ -15688,6 +16073,191 @@ mod tests {
         assert_eq!(end, 5.0);
         assert_eq!(enabled, 1);
     }
+
+    #[test]
+    #[serial]
+    fn test_simple_delay_follows_engine_tempo() {
+        use rf_dsp::MonoProcessor;
+
+        click_set_tempo(120.0);
+        let track_id = 9001;
+        assert_eq!(simple_delay_create(track_id, 48000.0, 2000.0), 1);
+        assert_eq!(simple_delay_set_sync(track_id, 4, 0, 0), 1);
+        assert_eq!(SIMPLE_DELAYS.read()[&track_id].delay_samples(), 24000.0);
+
+        // 1/4 at 100 BPM = 600 ms, reached by gliding after the tempo change
+        click_set_tempo(100.0);
+        {
+            let mut delays = SIMPLE_DELAYS.write();
+            let delay = delays.get_mut(&track_id).unwrap();
+            assert_eq!(delay.delay_samples(), 24000.0);
+            for _ in 0..48000 {
+                delay.process_sample(0.0);
+            }
+            assert!((delay.delay_samples() - 28800.0).abs() < 1e-6);
+        }
+
+        click_set_tempo(120.0);
+        simple_delay_remove(track_id);
+    }
+
+    #[test]
+    #[serial]
+    fn test_folder_created_through_ffi_sums_children() {
+        use crate::playback::add_tone_track;
+
+        engine_clear_all();
+        let folder_id = 9100;
+        let name = CString::new("Folder").unwrap();
+        assert_eq!(folder_create(folder_id, name.as_ptr()), 1);
+        for (name, freq) in [("folder_a", 440.0), ("folder_b", 660.0)] {
+            let track_id = add_tone_track(
+                &PLAYBACK_ENGINE,
+                &TRACK_MANAGER,
+                name,
+                freq,
+                0.25,
+                1.0,
+                OutputBus::Music,
+            );
+            assert_eq!(folder_add_child(folder_id, track_id), 1);
+        }
+
+        let render = || {
+            PLAYBACK_ENGINE.position.set_samples(0);
+            PLAYBACK_ENGINE.play();
+            let frames = 256;
+            let mut left = vec![0.0; frames];
+            let mut right = vec![0.0; frames];
+            for _ in 0..16 {
+                PLAYBACK_ENGINE.process(&mut left, &mut right);
+            }
+            left
+        };
+
+        // Folder fader and mute only apply when the children sum through the folder bus
+        let unity = render();
+        assert_eq!(folder_set_volume(folder_id, -6.0), 1);
+        let attenuated = render();
+        assert_eq!(folder_set_mute(folder_id, 1), 1);
+        let muted = render();
+
+        folder_delete(folder_id);
+        engine_clear_all();
+
+        assert!(unity.iter().any(|x| x.abs() > 0.05));
+        let rms = |x: &[f64]| (x.iter().map(|v| v * v).sum::<f64>() / x.len() as f64).sqrt();
+        let ratio_db = 20.0 * (rms(&attenuated) / rms(&unity)).log10();
+        assert!((ratio_db + 6.0).abs() < 0.05, "folder {} dB", ratio_db);
+        // Only the master chain's decay from the previous render is left
+        assert!(rms(&muted) < rms(&unity) * 1e-3);
+    }
+
+    #[test]
+    #[serial]
+    fn test_lfo_added_through_ffi_modulates_playback() {
+        engine_clear_all();
+        click_set_tempo(120.0);
+        let name = CString::new("LFO Target").unwrap();
+        let track_id = engine_create_track(name.as_ptr(), 0, 0);
+
+        // 1/4-note square on mute: on for the first half of each 24000-sample cycle
+        let lfo = modulation_add_lfo(2, 1, 1.0);
+        let param = CString::new("mute").unwrap();
+        assert_eq!(
+            modulation_assign_track_param(lfo, track_id, param.as_ptr(), 1.0, 0.5),
+            1
+        );
+
+        let muted_at = |sample: u64| {
+            PLAYBACK_ENGINE.position.set_samples(sample);
+            PLAYBACK_ENGINE.play();
+            let mut left = vec![0.0; 256];
+            let mut right = vec![0.0; 256];
+            PLAYBACK_ENGINE.process(&mut left, &mut right);
+            TRACK_MANAGER.get_track(TrackId(track_id)).unwrap().muted
+        };
+        let first_half = muted_at(1000);
+        let second_half = muted_at(13000);
+
+        assert_eq!(
+            modulation_unassign_track_param(lfo, track_id, param.as_ptr()),
+            1
+        );
+        assert_eq!(modulation_remove_lfo(lfo), 1);
+        engine_clear_all();
+
+        assert!(first_half);
+        assert!(!second_half);
+    }
+
+    #[test]
+    fn test_ffi_restoration_pipeline_round_trips_through_profile() {
+        let settings = RestorationSettingsFFI {
+            denoise_enabled: 1,
+            declick_enabled: 1,
+            declip_enabled: 1,
+            dehum_enabled: 1,
+            dereverb_enabled: 1,
+            ..Default::default()
+        };
+        let sample_rate = 48000;
+        let mut original = build_restoration_pipeline(&settings, sample_rate);
+
+        let json = original.to_profile().unwrap().to_json().unwrap();
+        let profile = rf_restore::RestoreProfile::from_json(&json).unwrap();
+        assert_eq!(profile.modules.len(), 5);
+        let config = RestoreConfig {
+            sample_rate,
+            ..Default::default()
+        };
+        let mut rebuilt = RestorationPipeline::from_profile(&profile, config);
+
+        let mut seed = 0x1357_9bdfu32;
+        let input: Vec<f32> = (0..sample_rate as usize / 2)
+            .map(|i| {
+                seed ^= seed << 13;
+                seed ^= seed >> 17;
+                seed ^= seed << 5;
+                let t = i as f32 / sample_rate as f32;
+                (2.0 * std::f32::consts::PI * 50.0 * t).sin() * 0.2
+                    + (seed as f32 / u32::MAX as f32 - 0.5) * 0.05
+            })
+            .collect();
+
+        let mut expected = vec![0.0; input.len()];
+        let mut actual = vec![0.0; input.len()];
+        original.process(&input, &mut expected).unwrap();
+        rebuilt.process(&input, &mut actual).unwrap();
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn test_insert_chain_state_applies_slot_stats() {
+        use crate::insert_chain::InsertChain;
+
+        let mut chain = InsertChain::new(48000.0);
+        let eq = crate::dsp_wrappers::create_processor("pro-eq", 48000.0).unwrap();
+        assert!(chain.load(2, eq));
+
+        let slot = chain.slot(2).unwrap();
+        slot.stats().record(250);
+        slot.set_bypass(true);
+
+        // 1000 µs block budget: 250 µs per block is 25% load
+        let state = insert_chain_state(&chain, 1000.0);
+        assert_eq!(state.slots.len(), 1);
+        assert_eq!(state.slots[0].index, 2);
+        assert!(state.slots[0].bypassed);
+        assert!((state.slots[0].cpu_percent - 25.0).abs() < 1e-4);
+        assert_eq!(state.slots[0].latency_samples, slot.latency() as u32);
+        // Bypassed slots don't count towards the chain total
+        assert_eq!(state.total_cpu, 0.0);
+
+        slot.set_bypass(false);
+        let state = insert_chain_state(&chain, 1000.0);
+        assert!((state.total_cpu - 25.0).abs() < 1e-4);
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -16110,6 +16680,8 @@ pub extern "C" fn render_selection_to_new_clip(
         source_duration,
         fade_in: 0.0,
         fade_out: 0.0,
+        fade_in_curve: CrossfadeCurve::Linear,
+        fade_out_curve: CrossfadeCurve::Linear,
         gain: 1.0,
         muted: false,
         selected: false,
@@ -19074,6 +19646,15 @@ pub extern "C" fn input_bus_create_mono(name: *const c_char, hw_channel: i32) ->
     PLAYBACK_ENGINE.input_bus_manager().create_bus(config)
 }
 
+/// Create loopback input bus capturing an engine bus (0 = master)
+/// Returns bus ID
+#[unsafe(no_mangle)]
+pub extern "C" fn input_bus_create_loopback(source_bus: u32) -> u32 {
+    PLAYBACK_ENGINE
+        .input_bus_manager()
+        .create_loopback(OutputBus::from(source_bus))
+}
+
 /// Delete input bus
 /// Returns 1 on success, 0 on failure
 #[unsafe(no_mangle)]
@@ -19226,6 +19807,47 @@ pub extern "C" fn track_get_phase_invert(track_id: u64) -> i32 {
         .unwrap_or(0)
 }
 
+/// Set track input trim in dB (clamped to ±24 dB)
+/// Applied before the insert chain, together with phase invert and mono sum
+#[unsafe(no_mangle)]
+pub extern "C" fn track_set_input_trim(track_id: u64, trim_db: f64) {
+    if let Some(mut track) = TRACK_MANAGER.tracks.get_mut(&TrackId(track_id)) {
+        let max = crate::track_manager::MAX_INPUT_TRIM_DB;
+        track.input_trim_db = trim_db.clamp(-max, max);
+        PROJECT_STATE.mark_dirty();
+    }
+}
+
+/// Get track input trim in dB
+#[unsafe(no_mangle)]
+pub extern "C" fn track_get_input_trim(track_id: u64) -> f64 {
+    TRACK_MANAGER
+        .tracks
+        .get(&TrackId(track_id))
+        .map(|track| track.input_trim_db)
+        .unwrap_or(0.0)
+}
+
+/// Set track mono sum (stereo input summed to (L+R)/2 on both channels)
+#[unsafe(no_mangle)]
+pub extern "C" fn track_set_mono_sum(track_id: u64, enabled: i32) {
+    if let Some(mut track) = TRACK_MANAGER.tracks.get_mut(&TrackId(track_id)) {
+        track.mono_sum = enabled != 0;
+        PROJECT_STATE.mark_dirty();
+    }
+}
+
+/// Get track mono sum state
+/// Returns: 0=Stereo, 1=Mono sum
+#[unsafe(no_mangle)]
+pub extern "C" fn track_get_mono_sum(track_id: u64) -> i32 {
+    TRACK_MANAGER
+        .tracks
+        .get(&TrackId(track_id))
+        .map(|track| if track.mono_sum { 1 } else { 0 })
+        .unwrap_or(0)
+}
+
 /// Set track input monitor state
 /// When enabled, the track's input is passed through to output for monitoring
 #[unsafe(no_mangle)]
@@ -19278,6 +19900,8 @@ pub extern "C" fn export_audio(
         end_time,
         include_tail: true,
         tail_seconds: 3.0,
+        render_tail: false,
+        max_tail_seconds: crate::export::DEFAULT_MAX_TAIL_SECONDS,
         normalize: normalize != 0,
         block_size: 512,
     };
@@ -19353,7 +19977,7 @@ pub extern "C" fn export_stems(
         prefix: prefix_str,
     };
 
-    match EXPORT_ENGINE.export_stems(config) {
+    match EXPORT_ENGINE.export_track_stems(config) {
         Ok(stems) => stems.len() as i32,
         Err(e) => {
             log::error!("Stems export failed: {}", e);
@@ -20870,6 +21494,7 @@ pub extern "C" fn wave_cache_query_tiles(
                     frame_offset: l.frame_offset,
                     min: l.min.min(r.min),
                     max: l.max.max(r.max),
+                    rms: ((l.rms * l.rms + r.rms * r.rms) / 2.0).sqrt(),
                 }
             })
             .collect()
@@ -21736,7 +22361,7 @@ pub extern "C" fn video_get_info_json(clip_id: u64) -> *mut c_char {
                 let info = serde_json::json!({
                     "duration_frames": clip.source.duration_frames,
                     "duration_secs": clip.source.duration_secs,
-                    "frame_rate": clip.source.frame_rate.as_f64(),
+                    "frame_rate": clip.source.frame_rate.fps(),
                     "width": clip.source.width,
                     "height": clip.source.height,
                     "codec": clip.source.codec,
@@ -21810,17 +22435,15 @@ pub extern "C" fn video_format_timecode(
     } else {
         rf_video::FrameRate::Fps60
     };
-
-    let frame_number = (seconds * fr.as_f64()) as u64;
-    let timecode = rf_video::Timecode::from_frame_number(frame_number, &fr);
-
-    let mut timecode = timecode;
-    timecode.format = if drop_frame != 0 {
-        rf_video::TimecodeFormat::DropFrame
-    } else {
-        rf_video::TimecodeFormat::NonDropFrame
+    let fr = match fr {
+        rf_video::FrameRate::Fps29_97 if drop_frame != 0 => rf_video::FrameRate::Fps29_97Drop,
+        rf_video::FrameRate::Fps59_94 if drop_frame != 0 => rf_video::FrameRate::Fps59_94Drop,
+        fr => fr,
     };
 
+    let frame_number = (seconds * fr.fps()) as u64;
+    let timecode = rf_video::Timecode::from_frames(frame_number, fr);
+
     let tc_str = timecode.to_string();
     string_to_cstr(&tc_str)
 }
@@ -21834,11 +22457,7 @@ pub extern "C" fn video_parse_timecode(tc_str: *const c_char, frame_rate: f64) -
         None => return -1.0,
     };
 
-    let format = if tc.contains(';') {
-        rf_video::TimecodeFormat::DropFrame
-    } else {
-        rf_video::TimecodeFormat::NonDropFrame
-    };
+    let drop_frame = tc.contains(';');
 
     let fr = if frame_rate <= 23.976 {
         rf_video::FrameRate::Fps23_976
@@ -21846,6 +22465,8 @@ pub extern "C" fn video_parse_timecode(tc_str: *const c_char, frame_rate: f64) -
         rf_video::FrameRate::Fps24
     } else if frame_rate <= 25.0 {
         rf_video::FrameRate::Fps25
+    } else if frame_rate <= 29.97 && drop_frame {
+        rf_video::FrameRate::Fps29_97Drop
     } else if frame_rate <= 29.97 {
         rf_video::FrameRate::Fps29_97
     } else {
@@ -21853,12 +22474,9 @@ pub extern "C" fn video_parse_timecode(tc_str: *const c_char, frame_rate: f64) -
         rf_video::FrameRate::Fps30
     };
 
-    match rf_video::Timecode::parse(&tc, format) {
-        Ok(timecode) => {
-            let frame_number = timecode.to_frame_number(&fr);
-            frame_number as f64 / fr.as_f64()
-        }
-        Err(_) => -1.0,
+    match rf_video::Timecode::parse(&tc, fr) {
+        Some(timecode) => timecode.to_seconds(),
+        None => -1.0,
     }
 }
 
@@ -22176,13 +22794,13 @@ fn genre_to_u8(genre: Genre) -> u8 {
 // =============================================================================
 
 use rf_restore::{
-    AnalysisResult as RestoreAnalysisResult, RestorationPipeline, RestoreConfig,
+    AnalysisResult as RestoreAnalysisResult, RestorationPipeline, RestoreConfig, RestoreModule,
     analysis::RestoreAnalyzer,
-    declick::{Declick, DeclickConfig},
-    declip::{Declip, DeclipConfig},
-    dehum::{Dehum, DehumConfig},
+    declick::DeclickConfig,
+    declip::DeclipConfig,
+    dehum::DehumConfig,
     denoise::{Denoise, DenoiseConfig, NoiseProfile},
-    dereverb::{Dereverb, DereverbConfig},
+    dereverb::DereverbConfig,
 };
 
 /// Global restoration pipeline
@@ -22542,59 +23160,67 @@ pub extern "C" fn restoration_reset() {
 // Helper: rebuild pipeline from settings
 fn rebuild_restoration_pipeline() {
     let settings = RESTORATION_SETTINGS.read().clone();
-    let config = RestoreConfig::default();
-    let mut pipeline = RestorationPipeline::new(config.clone());
     let sample_rate = PLAYBACK_ENGINE.sample_rate().max(44100);
+    let mut pipeline = build_restoration_pipeline(&settings, sample_rate);
+
+    // Apply previously learned noise profile if available
+    if let Some(ref profile) = *LEARNED_NOISE_PROFILE.read() {
+        pipeline.apply_noise_profile(profile);
+    }
+
+    *RESTORATION_PIPELINE.write() = pipeline;
+}
+
+// Helper: build the enabled modules from their parameters, in processing
+// order, so the pipeline can be saved with `to_profile`
+fn build_restoration_pipeline(
+    settings: &RestorationSettingsFFI,
+    sample_rate: u32,
+) -> RestorationPipeline {
+    let config = RestoreConfig {
+        sample_rate,
+        ..Default::default()
+    };
+    let mut pipeline = RestorationPipeline::new(config.clone());
 
-    // Add enabled modules in processing order
     if settings.denoise_enabled != 0 {
-        let denoise_config = DenoiseConfig {
+        pipeline.add_profile_module(RestoreModule::Denoise(DenoiseConfig {
             base: config,
             reduction_db: settings.denoise_strength * 0.3, // 0-30 dB range
             ..Default::default()
-        };
-        let mut denoise = Denoise::new(denoise_config, sample_rate);
-        // Apply previously learned noise profile if available
-        if let Some(ref profile) = *LEARNED_NOISE_PROFILE.read() {
-            denoise.set_noise_profile(profile.clone());
-        }
-        pipeline.add_module(Box::new(denoise));
+        }));
     }
 
     if settings.declick_enabled != 0 {
-        let declick_config = DeclickConfig {
+        pipeline.add_profile_module(RestoreModule::Declick(DeclickConfig {
             sensitivity: settings.declick_sensitivity / 100.0,
             ..Default::default()
-        };
-        pipeline.add_module(Box::new(Declick::new(declick_config, sample_rate)));
+        }));
     }
 
     if settings.declip_enabled != 0 {
-        let declip_config = DeclipConfig {
+        pipeline.add_profile_module(RestoreModule::Declip(DeclipConfig {
             threshold: 10.0_f32.powf(settings.declip_threshold / 20.0), // dB to linear
             ..Default::default()
-        };
-        pipeline.add_module(Box::new(Declip::new(declip_config)));
+        }));
     }
 
     if settings.dehum_enabled != 0 {
-        let dehum_config = DehumConfig {
+        pipeline.add_profile_module(RestoreModule::Dehum(DehumConfig {
             frequency: settings.dehum_frequency,
             harmonics: settings.dehum_harmonics as usize,
             ..Default::default()
-        };
-        pipeline.add_module(Box::new(Dehum::new(dehum_config, sample_rate)));
+        }));
     }
 
     if settings.dereverb_enabled != 0 {
-        let dereverb_config = DereverbConfig {
+        pipeline.add_profile_module(RestoreModule::Dereverb(DereverbConfig {
             mix: settings.dereverb_amount / 100.0,
             ..Default::default()
-        };
-        pipeline.add_module(Box::new(Dereverb::new(dereverb_config, sample_rate)));
+        }));
     }
 
-    *RESTORATION_PIPELINE.write() = pipeline;
+    pipeline
 }
 
 // Helper: load audio file for analysis (simple mono mixdown)
@@ -24261,6 +24887,11 @@ pub struct SharedMeterBuffer {
     // Stored as f32 bits in AtomicU32 — per-band RMS is in [0, ~1.5] so f32
     // precision is ample and this halves memory vs f64.
     pub bus_band_rms: [AtomicU32; 24],
+
+    // Master mono-compatibility: level lost folding to mono (dB, 0 = compatible).
+    // Band layout matches bus_band_rms: [bass, lowmid, highmid, treble].
+    pub mono_fold_loss: AtomicU64,
+    pub mono_fold_band_loss: [AtomicU64; 4],
 }
 
 impl Default for SharedMeterBuffer {
@@ -24354,6 +24985,13 @@ impl SharedMeterBuffer {
                 AtomicU32::new(0), AtomicU32::new(0), AtomicU32::new(0), AtomicU32::new(0),
                 AtomicU32::new(0), AtomicU32::new(0), AtomicU32::new(0), AtomicU32::new(0),
             ],
+            mono_fold_loss: AtomicU64::new(ZERO),
+            mono_fold_band_loss: [
+                AtomicU64::new(ZERO),
+                AtomicU64::new(ZERO),
+                AtomicU64::new(ZERO),
+                AtomicU64::new(ZERO),
+            ],
         }
     }
 
@@ -24472,6 +25110,12 @@ pub extern "C" fn metering_read_all_json() -> *mut c_char {
             "correlation": SharedMeterBuffer::read_f64(&SHARED_METERS.correlation),
             "balance": SharedMeterBuffer::read_f64(&SHARED_METERS.balance),
             "width": SharedMeterBuffer::read_f64(&SHARED_METERS.stereo_width),
+            "mono_fold_loss": SharedMeterBuffer::read_f64(&SHARED_METERS.mono_fold_loss),
+            "mono_fold_band_loss": SHARED_METERS
+                .mono_fold_band_loss
+                .iter()
+                .map(SharedMeterBuffer::read_f64)
+                .collect::<Vec<_>>(),
         },
         "dynamics": {
             "range": SharedMeterBuffer::read_f64(&SHARED_METERS.dynamic_range),
@@ -24609,6 +25253,7 @@ pub extern "C" fn metering_update_spectrum_band(band: u32, value: f64) {
 ///   19 = playback_position_samples, 20 = is_playing, 21 = sample_rate
 ///   22 = channel_peaks (base), 23 = spectrum_bands (base)
 ///   24 = bus_band_rms (base, 24 × f32 = 6 buses × 4 bands)  [Phase 10e-3]
+///   25 = mono_fold_loss, 26 = mono_fold_band_loss (base, 4 × f64)
 #[unsafe(no_mangle)]
 pub extern "C" fn metering_get_field_offset(field_id: u32) -> u64 {
     use std::mem::offset_of;
@@ -24639,6 +25284,8 @@ pub extern "C" fn metering_get_field_offset(field_id: u32) -> u64 {
         22 => offset_of!(SharedMeterBuffer, channel_peaks) as u64,
         23 => offset_of!(SharedMeterBuffer, spectrum_bands) as u64,
         24 => offset_of!(SharedMeterBuffer, bus_band_rms) as u64,
+        25 => offset_of!(SharedMeterBuffer, mono_fold_loss) as u64,
+        26 => offset_of!(SharedMeterBuffer, mono_fold_band_loss) as u64,
         _ => u64::MAX, // Invalid field
     }
 }
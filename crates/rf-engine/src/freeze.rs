@@ -14,12 +14,12 @@ use parking_lot::RwLock;
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::{BufWriter, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
 use crate::audio_import::{AudioImporter, ImportedAudio};
 use crate::insert_chain::InsertChain;
-use crate::track_manager::{Clip, TrackId, TrackManager};
+use crate::track_manager::{Clip, OutputBus, TrackId, TrackManager};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // FREEZE CONFIG
@@ -113,6 +113,151 @@ impl OfflineRenderer {
         let total_duration = (end_time - start_time) + tail_seconds;
         let total_samples = (total_duration * self.sample_rate) as usize;
 
+        self.render_blocks(
+            clips,
+            insert_chain,
+            audio_cache,
+            start_time,
+            total_samples,
+            progress_callback,
+            &mut |_, _| {},
+        )
+    }
+
+    /// Render a track through its inserts onto a new audio track
+    ///
+    /// The "commit" counterpart to freezing: the source track and its clips
+    /// are left as they are. The processed audio is written as a 32-bit float
+    /// WAV into `output_dir` and placed as a single clip on a new track (same
+    /// color and output bus), starting at the track's first clip. Insert
+    /// latency is rendered past and trimmed, so the clip lines up with the
+    /// original.
+    ///
+    /// With `include_sends`, each active send is run through the insert chain
+    /// of its destination bus (`send_returns`, indexed by `OutputBus`) and
+    /// summed in, printing the effect returns (reverb, delay) into the new
+    /// track. The track's fader and pan are not rendered, for the dry signal
+    /// and post-fader sends alike, so the wet/dry balance matches live
+    /// playback at unity; the new track starts at unity.
+    pub fn render_to_track(
+        &self,
+        track_manager: &TrackManager,
+        track_id: TrackId,
+        insert_chain: &mut InsertChain,
+        send_returns: &mut [InsertChain; 6],
+        audio_cache: &HashMap<String, Arc<ImportedAudio>>,
+        include_sends: bool,
+        tail_seconds: f64,
+        output_dir: &Path,
+    ) -> Result<Clip, FreezeError> {
+        let track = track_manager
+            .get_track(track_id)
+            .ok_or_else(|| FreezeError::RenderError(format!("Track {} not found", track_id.0)))?;
+        let clips = track_manager.get_clips_for_track(track_id);
+        if clips.is_empty() {
+            return Err(FreezeError::RenderError("Track has no clips".to_string()));
+        }
+
+        let start_time = clips.iter().map(|c| c.start_time).fold(f64::MAX, f64::min);
+        let end_time = clips.iter().map(|c| c.end_time()).fold(0.0, f64::max);
+        let duration = (end_time - start_time) + tail_seconds;
+        let render_samples = (duration * self.sample_rate) as usize;
+        let latency = insert_chain.total_latency();
+
+        // Active sends as (destination, gain L, gain R)
+        let sends: Vec<(OutputBus, f64, f64)> = track
+            .sends
+            .iter()
+            .filter(|send| include_sends && !send.muted && send.level > 0.0)
+            .filter_map(|send| {
+                let dest = send.destination?;
+                // Constant-power send pan, normalized so center = unity
+                let angle = (send.pan.clamp(-1.0, 1.0) + 1.0) * std::f64::consts::FRAC_PI_4;
+                let gain = send.level * std::f64::consts::SQRT_2;
+                Some((dest, gain * angle.cos(), gain * angle.sin()))
+            })
+            .collect();
+
+        let mut send_l = vec![0.0f64; self.block_size];
+        let mut send_r = vec![0.0f64; self.block_size];
+        let mut returns = |block_l: &mut [f64], block_r: &mut [f64]| {
+            let len = block_l.len();
+            let mut fed = [false; 6];
+            for &(dest, _, _) in &sends {
+                let bus = dest as usize;
+                if std::mem::replace(&mut fed[bus], true) {
+                    continue;
+                }
+                // Sum every send to this bus, then run its chain once
+                send_l[..len].fill(0.0);
+                send_r[..len].fill(0.0);
+                for &(_, gain_l, gain_r) in sends.iter().filter(|(d, _, _)| *d == dest) {
+                    for i in 0..len {
+                        send_l[i] += block_l[i] * gain_l;
+                        send_r[i] += block_r[i] * gain_r;
+                    }
+                }
+                send_returns[bus].process_all(&mut send_l[..len], &mut send_r[..len]);
+                for i in 0..len {
+                    block_l[i] += send_l[i];
+                    block_r[i] += send_r[i];
+                }
+            }
+        };
+
+        let (mut left, mut right) = self.render_blocks(
+            &clips,
+            insert_chain,
+            audio_cache,
+            start_time,
+            render_samples + latency,
+            None,
+            &mut returns,
+        );
+        left.drain(..latency);
+        right.drain(..latency);
+
+        let timestamp = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis();
+        std::fs::create_dir_all(output_dir)?;
+        let path = output_dir.join(format!("render_{}_{}.wav", track_id.0, timestamp));
+        Self::write_wav_f32(&path, &left, &right, self.sample_rate as u32)?;
+
+        let name = format!("{} (Rendered)", track.name);
+        let new_track = track_manager.create_track(&name, track.color, track.output_bus);
+        let clip = Clip::new(
+            new_track,
+            &name,
+            &path.to_string_lossy(),
+            start_time,
+            left.len() as f64 / self.sample_rate,
+        );
+        track_manager.add_clip(clip.clone());
+
+        log::info!(
+            "Rendered track {} to new track {} ({:?}, {:.2}s)",
+            track_id.0,
+            new_track.0,
+            path,
+            clip.duration
+        );
+
+        Ok(clip)
+    }
+
+    /// Block loop shared by the renders: clips → inserts → `post_process`
+    fn render_blocks(
+        &self,
+        clips: &[Clip],
+        insert_chain: &mut InsertChain,
+        audio_cache: &HashMap<String, Arc<ImportedAudio>>,
+        start_time: f64,
+        total_samples: usize,
+        progress_callback: Option<&dyn Fn(f32)>,
+        post_process: &mut dyn FnMut(&mut [f64], &mut [f64]),
+    ) -> (Vec<f64>, Vec<f64>) {
         let mut output_l = vec![0.0f64; total_samples];
         let mut output_r = vec![0.0f64; total_samples];
 
@@ -161,6 +306,7 @@ impl OfflineRenderer {
 
             // Apply insert chain processing
             insert_chain.process_all(&mut block_l, &mut block_r);
+            post_process(&mut block_l, &mut block_r);
 
             // Copy to output
             output_l[block_start..block_start + block_len].copy_from_slice(&block_l[..block_len]);
@@ -186,7 +332,10 @@ impl OfflineRenderer {
         block_r: &mut [f64],
     ) {
         let block_len = block_l.len();
-        let clip_start_sample = (clip.start_time * self.sample_rate) as i64;
+        // Round timeline positions: truncating `start + n / sr` drifts a
+        // sample early whenever the product lands just below an integer
+        let clip_start_sample = (clip.start_time * self.sample_rate).round() as i64;
+        let block_start_sample = (block_start_time * self.sample_rate).round() as i64;
         let source_sample_rate = audio.sample_rate as f64;
         let rate_ratio = source_sample_rate / self.sample_rate;
 
@@ -199,7 +348,7 @@ impl OfflineRenderer {
         let clip_duration_samples = (clip.duration * self.sample_rate) as i64;
 
         for i in 0..block_len {
-            let playback_sample = ((block_start_time * self.sample_rate) as i64) + i as i64;
+            let playback_sample = block_start_sample + i as i64;
             let clip_relative_sample = playback_sample - clip_start_sample;
 
             // Check bounds
@@ -857,4 +1006,145 @@ mod tests {
 
         let _ = std::fs::remove_file(&path);
     }
+
+    #[test]
+    fn test_render_to_track_matches_live_chain() {
+        use crate::dsp_wrappers::ProEqWrapper;
+        use crate::insert_chain::InsertProcessor;
+        use rf_dsp::FilterShape;
+
+        struct Gain(f64);
+
+        impl InsertProcessor for Gain {
+            fn name(&self) -> &str {
+                "Gain"
+            }
+
+            fn process_stereo(&mut self, left: &mut [f64], right: &mut [f64]) {
+                for s in left.iter_mut().chain(right.iter_mut()) {
+                    *s *= self.0;
+                }
+            }
+
+            fn reset(&mut self) {}
+
+            fn set_sample_rate(&mut self, _: f64) {}
+        }
+
+        let sample_rate = 48000;
+        let sr = sample_rate as f64;
+        let chain = || {
+            let mut chain = InsertChain::new(sr);
+            chain.load(0, Box::new(Gain(0.5)));
+            let mut eq = ProEqWrapper::new(sr);
+            eq.add_band(1000.0, 6.0, 1.0, FilterShape::Bell);
+            chain.load(1, Box::new(eq));
+            chain
+        };
+
+        // Half a second of 300 Hz + 1 kHz, starting 0.5 s into the timeline
+        let frames = sample_rate as usize / 2;
+        let samples: Vec<f32> = (0..frames)
+            .flat_map(|i| {
+                let t = i as f64 / sr;
+                let l = 0.3 * (2.0 * std::f64::consts::PI * 300.0 * t).sin();
+                let r = 0.3 * (2.0 * std::f64::consts::PI * 1000.0 * t).sin();
+                [l as f32, r as f32]
+            })
+            .collect();
+        let source = "/virtual/render_source.wav".to_string();
+        let mut audio_cache = HashMap::new();
+        audio_cache.insert(
+            source.clone(),
+            Arc::new(ImportedAudio {
+                samples: samples.clone(),
+                sample_rate,
+                channels: 2,
+                duration_secs: 0.5,
+                sample_count: frames,
+                source_path: source.clone(),
+                name: "source".to_string(),
+                bit_depth: None,
+                format: "wav".to_string(),
+            }),
+        );
+
+        let track_manager = TrackManager::new();
+        let track_id = track_manager.create_track("Vocal", 0xFF00FF00, OutputBus::Voice);
+        track_manager.create_clip(track_id, "source", &source, 0.5, 0.5, 0.5);
+
+        // Post-fader send at half level into an Aux return that halves again.
+        // The fader is ignored for dry and send alike.
+        track_manager.update_track(track_id, |track| {
+            track.volume = 0.25;
+            track.set_send_level(0, 0.5);
+            track.set_send_destination(0, Some(OutputBus::Aux));
+        });
+        let return_chain = || {
+            let mut chain = InsertChain::new(sr);
+            chain.load(0, Box::new(Gain(0.5)));
+            chain
+        };
+        let mut send_returns: [InsertChain; 6] = std::array::from_fn(|_| InsertChain::new(sr));
+        send_returns[OutputBus::Aux as usize] = return_chain();
+
+        let dir = std::env::temp_dir().join("rf_freeze_test");
+        let tail = 0.1;
+        let renderer = OfflineRenderer::new(sr, 512);
+        let clip = renderer
+            .render_to_track(
+                &track_manager,
+                track_id,
+                &mut chain(),
+                &mut send_returns,
+                &audio_cache,
+                true,
+                tail,
+                &dir,
+            )
+            .expect("render to track");
+
+        // New track, aligned with the source clip; original untouched
+        assert_ne!(clip.track_id, track_id);
+        assert_eq!(clip.start_time, 0.5);
+        assert!((clip.duration - (0.5 + tail)).abs() < 1e-9);
+        let new_track = track_manager.get_track(clip.track_id).unwrap();
+        assert_eq!(new_track.output_bus, OutputBus::Voice);
+        assert_eq!(track_manager.get_clips_for_track(clip.track_id).len(), 1);
+        assert_eq!(track_manager.get_clips_for_track(track_id).len(), 1);
+
+        // Live path: same chain at a smaller buffer size, from clip start
+        let total = frames + (tail * sr) as usize;
+        let mut live_l = vec![0.0f64; total];
+        let mut live_r = vec![0.0f64; total];
+        for i in 0..frames {
+            live_l[i] = samples[i * 2] as f64;
+            live_r[i] = samples[i * 2 + 1] as f64;
+        }
+        let mut live_chain = chain();
+        let mut live_return = return_chain();
+        for (l, r) in live_l.chunks_mut(128).zip(live_r.chunks_mut(128)) {
+            live_chain.process_all(l, r);
+            let mut send_l: Vec<f64> = l.iter().map(|s| s * 0.5).collect();
+            let mut send_r: Vec<f64> = r.iter().map(|s| s * 0.5).collect();
+            live_return.process_all(&mut send_l, &mut send_r);
+            for i in 0..l.len() {
+                l[i] += send_l[i];
+                r[i] += send_r[i];
+            }
+        }
+
+        let path = std::path::PathBuf::from(&clip.source_file);
+        sync_file(&path);
+        let rendered = AudioImporter::import(&path).expect("read rendered wav");
+        assert_eq!(rendered.channels, 2);
+        assert_eq!(rendered.samples.len(), total * 2);
+        for i in 0..total {
+            let dl = (rendered.samples[i * 2] as f64 - live_l[i]).abs();
+            let dr = (rendered.samples[i * 2 + 1] as f64 - live_r[i]).abs();
+            assert!(dl < 1e-6 && dr < 1e-6, "sample {}: {} / {}", i, dl, dr);
+        }
+
+        let _ = std::fs::remove_file(&path);
+    }
 }
@@ -99,6 +99,14 @@ pub struct MacroMapping {
     pub curve: MappingCurve,
 }
 
+impl MacroMapping {
+    /// Target parameter value for a macro position (0.0-1.0).
+    /// `min_value > max_value` inverts the mapping.
+    pub fn map(&self, macro_value: f64) -> f64 {
+        self.min_value + (self.max_value - self.min_value) * self.curve.apply(macro_value)
+    }
+}
+
 /// Mapping curve type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum MappingCurve {
@@ -312,15 +320,49 @@ impl FxContainer {
         }
 
         let value = value.clamp(0.0, 1.0);
-        self.macros[macro_index as usize].value = value;
+        let macro_param = &mut self.macros[macro_index as usize];
+        macro_param.value = value;
+
+        for mapping in &macro_param.mappings {
+            if let Some(path) = self.paths.get_mut(mapping.path_id.0 as usize) {
+                path.chain.set_slot_param(
+                    mapping.fx_slot as usize,
+                    mapping.param_index as usize,
+                    mapping.map(value),
+                );
+            }
+        }
+    }
+
+    /// Get macro value (0.0-1.0)
+    pub fn macro_value(&self, macro_index: u8) -> Option<f64> {
+        self.macros.get(macro_index as usize).map(|m| m.value)
+    }
 
-        // Note: Macro mappings require InsertSlot to expose parameter setters.
-        // For now, we just store the value. Full implementation would require
-        // extending InsertSlot with a set_param method that delegates to the processor.
-        // The mappings are stored for future use when the slot API is extended.
+    /// Get all targets of a macro
+    pub fn macro_mappings(&self, macro_index: u8) -> &[MacroMapping] {
+        self.macros
+            .get(macro_index as usize)
+            .map(|m| m.mappings.as_slice())
+            .unwrap_or(&[])
     }
 
-    /// Add macro mapping
+    /// Add a mapping to the macro named by `mapping.macro_index`.
+    /// A macro can drive any number of targets, each with its own range and curve.
+    pub fn add_mapping(&mut self, mapping: MacroMapping) {
+        if let Some(macro_param) = self.macros.get_mut(mapping.macro_index as usize) {
+            macro_param.mappings.push(mapping);
+        }
+    }
+
+    /// Remove all mappings of a macro
+    pub fn clear_macro_mappings(&mut self, macro_index: u8) {
+        if let Some(macro_param) = self.macros.get_mut(macro_index as usize) {
+            macro_param.mappings.clear();
+        }
+    }
+
+    /// Add linear macro mapping
     pub fn add_macro_mapping(
         &mut self,
         macro_index: u8,
@@ -330,21 +372,15 @@ impl FxContainer {
         min_value: f64,
         max_value: f64,
     ) {
-        if (macro_index as usize) >= MAX_MACROS {
-            return;
-        }
-
-        self.macros[macro_index as usize]
-            .mappings
-            .push(MacroMapping {
-                macro_index,
-                path_id,
-                fx_slot,
-                param_index,
-                min_value,
-                max_value,
-                curve: MappingCurve::Linear,
-            });
+        self.add_mapping(MacroMapping {
+            macro_index,
+            path_id,
+            fx_slot,
+            param_index,
+            min_value,
+            max_value,
+            curve: MappingCurve::Linear,
+        });
     }
 
     /// Check if any path is soloed
@@ -659,6 +695,68 @@ mod tests {
         assert!(MappingCurve::Logarithmic.apply(0.5) > 0.5); // sqrt(0.5) ≈ 0.707
     }
 
+    /// Passthrough processor that just stores its parameters
+    struct ParamProbe {
+        params: [f64; 4],
+    }
+
+    impl InsertProcessor for ParamProbe {
+        fn name(&self) -> &str {
+            "Probe"
+        }
+        fn process_stereo(&mut self, _left: &mut [Sample], _right: &mut [Sample]) {}
+        fn reset(&mut self) {}
+        fn set_sample_rate(&mut self, _sample_rate: f64) {}
+        fn num_params(&self) -> usize {
+            self.params.len()
+        }
+        fn get_param(&self, index: usize) -> f64 {
+            self.params.get(index).copied().unwrap_or(0.0)
+        }
+        fn set_param(&mut self, index: usize, value: f64) {
+            if let Some(p) = self.params.get_mut(index) {
+                *p = value;
+            }
+        }
+    }
+
+    #[test]
+    fn test_macro_drives_multiple_targets() {
+        let mut container = FxContainer::new(ContainerId::new(1), "Macro", 48000.0, 256);
+        let filter_path = container.add_path("Filter");
+        let reverb_path = container.add_path("Reverb");
+        assert!(container.add_fx_to_path(filter_path, Box::new(ParamProbe { params: [0.0; 4] })));
+        assert!(container.add_fx_to_path(reverb_path, Box::new(ParamProbe { params: [0.0; 4] })));
+
+        // Macro 0 opens the filter cutoff linearly and raises the send logarithmically
+        container.add_macro_mapping(0, filter_path, 0, 1, 200.0, 8000.0);
+        container.add_mapping(MacroMapping {
+            macro_index: 0,
+            path_id: reverb_path,
+            fx_slot: 0,
+            param_index: 2,
+            min_value: 0.0,
+            max_value: 0.8,
+            curve: MappingCurve::Logarithmic,
+        });
+        assert_eq!(container.macro_mappings(0).len(), 2);
+
+        container.set_macro(0, 0.25);
+
+        let param = |path: PathId, index| {
+            container
+                .get_path(path)
+                .unwrap()
+                .chain
+                .get_slot_param(0, index)
+        };
+        assert!((param(filter_path, 1) - (200.0 + 7800.0 * 0.25)).abs() < 1e-9);
+        assert!((param(reverb_path, 2) - 0.8 * 0.25_f64.sqrt()).abs() < 1e-9);
+        // Unmapped parameters are untouched
+        assert_eq!(param(filter_path, 2), 0.0);
+        assert_eq!(container.macro_value(0), Some(0.25));
+    }
+
     #[test]
     fn test_passthrough_processing() {
         let mut container = FxContainer::new(ContainerId::new(1), "Empty", 48000.0, 256);
@@ -3,7 +3,7 @@
 //! Professional group/VCA control like Cubase/Pro Tools:
 //! - Group channels (link faders, mutes, solos, pans)
 //! - VCA faders (non-destructive level control)
-//! - Folder tracks (visual organization)
+//! - Folder tracks (summing bus with own inserts and fader)
 //! - Automation spill (edit group automation)
 //!
 //! ## VCA vs Group
@@ -18,6 +18,8 @@ use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicU64, Ordering};
 
+use crate::track_manager::OutputBus;
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // TYPES
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -236,7 +238,11 @@ impl VcaFader {
 // FOLDER TRACK
 // ═══════════════════════════════════════════════════════════════════════════════
 
-/// Folder track for visual organization
+/// Folder track — groups children in the UI and sums them into its own bus
+///
+/// Children are routed into the folder bus instead of their own output bus.
+/// The folder's insert chain (keyed by folder ID) and fader then apply to the
+/// summed signal. Collapse/expand is purely visual and never affects audio.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FolderTrack {
     /// Unique ID
@@ -251,6 +257,15 @@ pub struct FolderTrack {
     pub color: u32,
     /// Solo defeats folder (folder solo affects children)
     pub solo_defeat: bool,
+    /// Folder fader level in dB (applied to summed children)
+    #[serde(default)]
+    pub volume_db: f64,
+    /// Folder mute (silences all children's contribution)
+    #[serde(default)]
+    pub muted: bool,
+    /// Bus the summed folder signal is routed to
+    #[serde(default)]
+    pub output_bus: OutputBus,
 }
 
 impl FolderTrack {
@@ -262,6 +277,9 @@ impl FolderTrack {
             expanded: true,
             color: 0x808080, // Gray
             solo_defeat: false,
+            volume_db: 0.0,
+            muted: false,
+            output_bus: OutputBus::Master,
         }
     }
 
@@ -290,6 +308,92 @@ impl FolderTrack {
     pub fn toggle(&mut self) {
         self.expanded = !self.expanded;
     }
+
+    /// Set folder fader level
+    pub fn set_volume(&mut self, db: f64) {
+        self.volume_db = db.clamp(-144.0, 12.0);
+    }
+
+    /// Linear gain applied to the summed children (0.0 when muted)
+    pub fn gain(&self) -> f64 {
+        if self.muted {
+            0.0
+        } else {
+            db_to_linear(self.volume_db)
+        }
+    }
+}
+
+/// Folder audio routing for a child track (copied out for the audio thread)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FolderRouting {
+    /// Folder the child sums into
+    pub folder_id: TrackId,
+    /// Folder fader gain (linear, 0.0 when muted)
+    pub gain: f64,
+    /// Destination of the summed signal
+    pub output_bus: OutputBus,
+}
+
+/// Summing buffer for one folder bus
+///
+/// Children accumulate into it during the track loop, then the folder
+/// inserts and fader run on the sum before it is routed to `output_bus`.
+#[derive(Debug, Clone, Default)]
+pub struct FolderBus {
+    pub left: Vec<f64>,
+    pub right: Vec<f64>,
+    /// Routing of the last contributing child (None = silent this block)
+    routing: Option<FolderRouting>,
+}
+
+impl FolderBus {
+    pub fn new(block_size: usize) -> Self {
+        Self {
+            left: vec![0.0; block_size],
+            right: vec![0.0; block_size],
+            routing: None,
+        }
+    }
+
+    /// Frames the bus can hold (fixed at creation)
+    pub fn capacity(&self) -> usize {
+        self.left.len()
+    }
+
+    /// Clear the first `frames` samples for a new block (never reallocates)
+    pub fn clear(&mut self, frames: usize) {
+        let frames = frames.min(self.capacity());
+        self.left[..frames].fill(0.0);
+        self.right[..frames].fill(0.0);
+        self.routing = None;
+    }
+
+    /// Mix a child's post-fader output into the folder sum
+    pub fn accumulate(&mut self, routing: FolderRouting, left: &[f64], right: &[f64]) {
+        for (dst, src) in self.left.iter_mut().zip(left) {
+            *dst += src;
+        }
+        for (dst, src) in self.right.iter_mut().zip(right) {
+            *dst += src;
+        }
+        self.routing = Some(routing);
+    }
+
+    /// Apply the folder fader to the first `frames` samples
+    pub fn apply_gain(&mut self, gain: f64, frames: usize) {
+        for sample in self.left[..frames]
+            .iter_mut()
+            .chain(self.right[..frames].iter_mut())
+        {
+            *sample *= gain;
+        }
+    }
+
+    /// Folder routing, if any child was summed this block
+    pub fn routing(&self) -> Option<FolderRouting> {
+        self.routing
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -446,6 +550,11 @@ impl GroupManager {
             .unwrap_or(0.0)
     }
 
+    /// Linear gain of all VCAs controlling a track (1.0 when unassigned)
+    pub fn vca_gain(&self, track_id: TrackId) -> f64 {
+        db_to_linear(self.get_vca_contribution(track_id))
+    }
+
     /// Check if track is muted by any VCA
     pub fn is_vca_muted(&self, track_id: TrackId) -> bool {
         self.track_vcas
@@ -481,6 +590,20 @@ impl GroupManager {
             .find(|f| f.children.contains(&track_id))
     }
 
+    /// Get folder by ID (mutable)
+    pub fn folder_mut(&mut self, id: TrackId) -> Option<&mut FolderTrack> {
+        self.folders.get_mut(&id)
+    }
+
+    /// Audio routing for a child track, if it lives in a folder
+    pub fn folder_routing(&self, track_id: TrackId) -> Option<FolderRouting> {
+        self.parent_folder(track_id).map(|folder| FolderRouting {
+            folder_id: folder.id,
+            gain: folder.gain(),
+            output_bus: folder.output_bus,
+        })
+    }
+
     // ─────────────────────────────────────────────────────────────────────────────
     // FFI Helper Methods (for Flutter bridge)
     // ─────────────────────────────────────────────────────────────────────────────
@@ -640,4 +763,40 @@ mod tests {
         assert!(parent.is_some());
         assert_eq!(parent.unwrap().name, "Drums Folder");
     }
+
+    #[test]
+    fn test_folder_fader_attenuates_sum() {
+        let mut manager = GroupManager::new();
+        manager.create_folder(100, "Drums Folder");
+        let folder = manager.folder_mut(100).unwrap();
+        folder.add_child(1);
+        folder.add_child(2);
+        folder.set_volume(-6.0);
+
+        let frames = 64;
+        let kick = vec![0.5; frames];
+        let snare = vec![0.25; frames];
+
+        let mut bus = FolderBus::new(frames);
+        bus.clear(frames);
+        for (child, signal) in [(1, &kick), (2, &snare)] {
+            let routing = manager.folder_routing(child).unwrap();
+            assert_eq!(routing.folder_id, 100);
+            bus.accumulate(routing, signal, signal);
+        }
+        let routing = bus.routing().unwrap();
+        bus.apply_gain(routing.gain, frames);
+
+        let ratio_db = 20.0 * (bus.left[0] / 0.75).log10();
+        assert!((ratio_db - (-6.0)).abs() < 0.001);
+        assert_eq!(bus.left, bus.right);
+
+        // Collapsing is visual only; mute silences every child
+        let folder = manager.folder_mut(100).unwrap();
+        folder.toggle();
+        assert_eq!(manager.folder_routing(1).unwrap().gain, routing.gain);
+        manager.folder_mut(100).unwrap().muted = true;
+        assert_eq!(manager.folder_routing(2).unwrap().gain, 0.0);
+        assert!(manager.folder_routing(3).is_none());
+    }
 }
@@ -5,12 +5,15 @@
 //! - Each track selects which bus to monitor/record
 //! - Zero-copy audio routing
 //! - Lock-free communication
+//! - Loopback buses capture engine output (master or any bus) for re-recording
 
 use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::Arc;
-use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+
+use crate::track_manager::OutputBus;
 
 /// Input bus ID
 pub type InputBusId = u32;
@@ -53,6 +56,10 @@ pub struct InputBus {
     peaks: Vec<AtomicU64>,
     /// Enabled state (atomic for audio thread)
     enabled: AtomicBool,
+    /// Engine bus captured by this bus (None = hardware input)
+    loopback: Option<OutputBus>,
+    /// Timeline position of the captured block, latency-compensated
+    capture_position: AtomicU64,
 }
 
 impl InputBus {
@@ -73,6 +80,22 @@ impl InputBus {
             buffers,
             peaks,
             enabled,
+            loopback: None,
+            capture_position: AtomicU64::new(0),
+        }
+    }
+
+    /// Create loopback bus capturing an engine bus output
+    pub fn new_loopback(id: InputBusId, source: OutputBus, buffer_size: usize) -> Self {
+        let config = InputBusConfig {
+            name: format!("Loopback ({:?})", source),
+            channels: 2,
+            hardware_channels: Vec::new(),
+            enabled: true,
+        };
+        Self {
+            loopback: Some(source),
+            ..Self::new(id, config, buffer_size)
         }
     }
 
@@ -107,10 +130,54 @@ impl InputBus {
         self.config.write().enabled = enabled;
     }
 
+    /// Engine bus captured by this bus (None for hardware inputs)
+    pub fn loopback_source(&self) -> Option<OutputBus> {
+        self.loopback
+    }
+
+    /// Is this a loopback bus
+    pub fn is_loopback(&self) -> bool {
+        self.loopback.is_some()
+    }
+
+    /// Timeline position the current loopback buffer belongs to
+    ///
+    /// The captured block was rendered one block ago and delayed by the
+    /// processing latency, so recording must use this position rather than
+    /// the current playhead. None for hardware inputs.
+    pub fn capture_position(&self) -> Option<u64> {
+        self.loopback
+            .map(|_| self.capture_position.load(Ordering::Relaxed))
+    }
+
+    /// Write processed engine output to a loopback bus
+    /// Called from audio thread after the source bus is fully processed
+    pub fn write_loopback(&self, left: &[f64], right: &[f64], position: u64, latency: u64) {
+        if !self.is_enabled() || !self.is_loopback() {
+            return;
+        }
+
+        for (ch_idx, input) in [left, right].into_iter().enumerate() {
+            if let Some(mut buffer) = self.buffers[ch_idx].try_write() {
+                let frames = input.len().min(buffer.len());
+                let mut peak = 0.0f32;
+                for (dst, &src) in buffer[..frames].iter_mut().zip(input) {
+                    *dst = src as f32;
+                    peak = peak.max(dst.abs());
+                }
+                buffer[frames..].fill(0.0);
+                self.peaks[ch_idx].store(peak.to_bits() as u64, Ordering::Relaxed);
+            }
+        }
+
+        self.capture_position
+            .store(position.saturating_sub(latency), Ordering::Relaxed);
+    }
+
     /// Write audio from hardware input to bus buffers
     /// Called from audio thread — lock-free
     pub fn write_from_hardware(&self, hardware_input: &[f32], frames: usize) {
-        if !self.is_enabled() {
+        if !self.is_enabled() || self.is_loopback() {
             return;
         }
 
@@ -184,6 +251,8 @@ pub struct InputBusManager {
     next_id: AtomicU64,
     /// Buffer size (matches audio engine block size)
     buffer_size: usize,
+    /// Number of loopback buses (audio thread skips capture when zero)
+    loopback_count: AtomicUsize,
 }
 
 impl InputBusManager {
@@ -193,6 +262,7 @@ impl InputBusManager {
             buses: RwLock::new(HashMap::new()),
             next_id: AtomicU64::new(1),
             buffer_size,
+            loopback_count: AtomicUsize::new(0),
         }
     }
 
@@ -204,9 +274,30 @@ impl InputBusManager {
         id
     }
 
+    /// Create loopback bus recording the processed output of `source`
+    ///
+    /// The bus is fed after the source bus (or the master chain) has run,
+    /// with its capture position set back by the processing latency so that
+    /// recordings line up with the timeline.
+    pub fn create_loopback(&self, source: OutputBus) -> InputBusId {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed) as InputBusId;
+        let bus = Arc::new(InputBus::new_loopback(id, source, self.buffer_size));
+        self.buses.write().insert(id, bus);
+        self.loopback_count.fetch_add(1, Ordering::Relaxed);
+        id
+    }
+
     /// Delete input bus
     pub fn delete_bus(&self, id: InputBusId) -> bool {
-        self.buses.write().remove(&id).is_some()
+        match self.buses.write().remove(&id) {
+            Some(bus) => {
+                if bus.is_loopback() {
+                    self.loopback_count.fetch_sub(1, Ordering::Relaxed);
+                }
+                true
+            }
+            None => false,
+        }
     }
 
     /// Get input bus by ID
@@ -232,6 +323,33 @@ impl InputBusManager {
     /// Clear all buses
     pub fn clear(&self) {
         self.buses.write().clear();
+        self.loopback_count.store(0, Ordering::Relaxed);
+    }
+
+    /// Any loopback bus exists (lock-free)
+    pub fn has_loopback(&self) -> bool {
+        self.loopback_count.load(Ordering::Relaxed) > 0
+    }
+
+    /// Feed processed output of `source` to its loopback buses
+    /// Called from audio thread
+    pub fn capture_loopback(
+        &self,
+        source: OutputBus,
+        left: &[f64],
+        right: &[f64],
+        position: u64,
+        latency: u64,
+    ) {
+        if !self.has_loopback() {
+            return;
+        }
+        let buses = self.buses.read();
+        for bus in buses.values() {
+            if bus.loopback_source() == Some(source) {
+                bus.write_loopback(left, right, position, latency);
+            }
+        }
     }
 
     /// Route hardware input to all buses
@@ -358,4 +476,47 @@ mod tests {
         assert_eq!(bus.peak(0), 0.8);
         assert_eq!(bus.peak(1), 0.6);
     }
+
+    #[test]
+    fn test_loopback_capture_by_source() {
+        let manager = InputBusManager::new(256);
+        manager.create_default_stereo_bus();
+        let loopback_id = manager.create_loopback(OutputBus::Master);
+        let music_id = manager.create_loopback(OutputBus::Music);
+        assert!(manager.has_loopback());
+
+        let frames = 256;
+        let master_l: Vec<f64> = (0..frames).map(|i| (i as f64 * 0.05).sin() * 0.4).collect();
+        let master_r: Vec<f64> = master_l.iter().map(|x| -x).collect();
+
+        let block_start = 48_000;
+        let latency = 128;
+        manager.capture_loopback(
+            OutputBus::Master,
+            &master_l,
+            &master_r,
+            block_start,
+            latency,
+        );
+
+        // Hardware routing must not overwrite loopback buses
+        manager.route_hardware_input(&vec![0.9f32; frames * 2], frames);
+
+        let bus = manager.get_bus(loopback_id).unwrap();
+        let (left, right) = bus.read_buffers().unwrap();
+        let right = right.unwrap();
+        for i in 0..frames {
+            assert!((left[i] as f64 - master_l[i]).abs() < 1e-6);
+            assert!((right[i] as f64 - master_r[i]).abs() < 1e-6);
+        }
+        assert_eq!(bus.capture_position(), Some(block_start - latency));
+
+        // Other sources stay silent
+        let music = manager.get_bus(music_id).unwrap();
+        assert!(music.read_buffers().unwrap().0.iter().all(|&x| x == 0.0));
+
+        manager.delete_bus(loopback_id);
+        manager.delete_bus(music_id);
+        assert!(!manager.has_loopback());
+    }
 }
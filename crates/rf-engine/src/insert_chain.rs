@@ -11,10 +11,12 @@
 
 use std::collections::HashMap;
 
-use rf_core::Sample;
+use rf_core::{ParamRange, Sample, buffer};
 use rf_dsp::delay_compensation::LatencySamples;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Instant;
 
+use crate::anticipatory::NodeStats;
 use crate::pin_connector::PinConnector;
 
 // =============================================================================
@@ -181,6 +183,8 @@ pub struct InsertSlot {
     // ═══ P10.0.1: Per-Processor Metering ═══
     /// Real-time metering data (input/output levels, GR, load)
     metering: ProcessorMetering,
+    /// Per-block processing time of the loaded processor
+    stats: NodeStats,
     // ═══ P7: Pin Connector (per-plugin channel routing) ═══
     /// Optional pin connector for multi-channel routing
     /// When None, standard stereo pass-through is used (zero overhead)
@@ -218,6 +222,7 @@ impl InsertSlot {
             sample_rate: DEFAULT_SAMPLE_RATE,
             // P10.0.1: Metering
             metering: ProcessorMetering::new(),
+            stats: NodeStats::default(),
             // P7: Pin Connector (None = standard stereo, zero overhead)
             pin_connector: None,
         }
@@ -227,6 +232,7 @@ impl InsertSlot {
     pub fn load(&mut self, processor: Box<dyn InsertProcessor>) {
         self.latency = processor.latency();
         self.processor = Some(processor);
+        self.stats = NodeStats::default();
     }
 
     /// Unload the processor
@@ -338,6 +344,7 @@ impl InsertSlot {
             self.dry_buffer_r[..len].copy_from_slice(&right[..len]);
 
             // Process wet signal — with or without Pin Connector
+            let started = Instant::now();
             if let Some(ref mut pc) = self.pin_connector {
                 if pc.is_enabled() {
                     // Pin Connector path: route through matrix
@@ -346,8 +353,8 @@ impl InsertSlot {
                     processor.process_stereo(pc_left, pc_right);
                     // Route plugin output back to host via output matrix
                     // First clear host buffers since route_output will accumulate
-                    left[..len].fill(0.0);
-                    right[..len].fill(0.0);
+                    buffer::clear(&mut left[..len]);
+                    buffer::clear(&mut right[..len]);
                     pc.route_output_stereo(&mut left[..len], &mut right[..len], len);
                 } else {
                     processor.process_stereo(&mut left[..len], &mut right[..len]);
@@ -355,6 +362,7 @@ impl InsertSlot {
             } else {
                 processor.process_stereo(&mut left[..len], &mut right[..len]);
             }
+            self.stats.record(started.elapsed().as_micros() as u64);
 
             // Sanitize processor output — prevent NaN/Inf from propagating
             // through bus buffers. If any sample is non-finite, replace with
@@ -409,6 +417,11 @@ impl InsertSlot {
         self.metering.reset();
     }
 
+    /// Processing time stats of the loaded processor (reset on load)
+    pub fn stats(&self) -> &NodeStats {
+        &self.stats
+    }
+
     /// Get processor-specific meter value (e.g. gain reduction from compressor)
     pub fn get_processor_meter(&self, index: usize) -> f64 {
         if let Some(ref processor) = self.processor {
@@ -537,6 +550,124 @@ impl InsertSlot {
     pub fn has_external_sidechain(&self) -> bool {
         self.sidechain_source >= 0
     }
+
+    /// Feed a key signal to the processor for its next block
+    pub fn set_sidechain_input(&mut self, left: &[Sample], right: &[Sample]) {
+        if let Some(ref mut processor) = self.processor {
+            processor.set_sidechain_input(left, right);
+        }
+    }
+}
+
+// ============ Parameter Descriptors ============
+
+/// Static descriptor for one processor parameter
+///
+/// Values passed to `get_param`/`set_param` are in plain units (dB, Hz, ms...);
+/// the descriptor maps them to and from the normalized 0..1 domain used by
+/// automation lanes and generic UI controls through its `ParamRange`.
+#[derive(Debug, Clone, Copy)]
+pub struct ParameterInfo {
+    /// Display name (matches `param_name`)
+    pub name: &'static str,
+    /// Unit label ("dB", "Hz", "ms", "%", or "" when unitless)
+    pub unit: &'static str,
+    /// Plain-value range, default and normalization curve
+    pub range: ParamRange,
+    /// Discrete steps between min and max (0 = continuous, 1 = toggle)
+    pub steps: u32,
+}
+
+impl ParameterInfo {
+    /// Continuous parameter with linear mapping
+    pub const fn linear(
+        name: &'static str,
+        unit: &'static str,
+        min: f64,
+        max: f64,
+        default: f64,
+    ) -> Self {
+        Self {
+            name,
+            unit,
+            range: ParamRange::linear(min, max, default),
+            steps: 0,
+        }
+    }
+
+    /// Continuous parameter with logarithmic mapping (frequency, time). `min` must be > 0.
+    pub const fn log(
+        name: &'static str,
+        unit: &'static str,
+        min: f64,
+        max: f64,
+        default: f64,
+    ) -> Self {
+        Self {
+            name,
+            unit,
+            range: ParamRange::logarithmic(min, max, default),
+            steps: 0,
+        }
+    }
+
+    /// On/off parameter (0.0 / 1.0)
+    pub const fn toggle(name: &'static str, default: bool) -> Self {
+        Self {
+            name,
+            unit: "",
+            range: ParamRange::linear(0.0, 1.0, if default { 1.0 } else { 0.0 }),
+            steps: 1,
+        }
+    }
+
+    /// Enumerated parameter with `count` choices (0..count-1)
+    pub const fn choice(name: &'static str, count: u32, default: u32) -> Self {
+        Self {
+            name,
+            unit: "",
+            range: ParamRange::linear(0.0, (count - 1) as f64, default as f64),
+            steps: count - 1,
+        }
+    }
+
+    /// Integer parameter in `min..=max`
+    pub const fn stepped(
+        name: &'static str,
+        unit: &'static str,
+        min: i32,
+        max: i32,
+        default: i32,
+    ) -> Self {
+        Self {
+            name,
+            unit,
+            range: ParamRange::linear(min as f64, max as f64, default as f64),
+            steps: (max - min) as u32,
+        }
+    }
+
+    /// Whether the parameter only takes discrete values
+    pub fn is_discrete(&self) -> bool {
+        self.steps > 0
+    }
+
+    /// Map a plain value to 0..1
+    pub fn normalize(&self, value: f64) -> f64 {
+        if self.range.max <= self.range.min {
+            return 0.0;
+        }
+        self.range.normalize(value).clamp(0.0, 1.0)
+    }
+
+    /// Map 0..1 to a plain value (snapped to the nearest step for discrete parameters)
+    pub fn denormalize(&self, normalized: f64) -> f64 {
+        let mut n = normalized.clamp(0.0, 1.0);
+        if self.steps > 0 {
+            n = (n * self.steps as f64).round() / self.steps as f64;
+        }
+        self.range.denormalize(n)
+    }
 }
 
 // ============ Insert Processor Trait ============
@@ -602,6 +733,28 @@ pub trait InsertProcessor: Send + Sync {
         ""
     }
 
+    /// Parameter descriptors indexed by parameter id (same index as `get_param`/`set_param`)
+    /// Default: empty (processor exposes no descriptor table)
+    fn parameters(&self) -> &[ParameterInfo] {
+        &[]
+    }
+
+    /// Get parameter value normalized to 0..1 via its descriptor
+    fn get_param_normalized(&self, index: usize) -> f64 {
+        self.parameters()
+            .get(index)
+            .map(|info| info.normalize(self.get_param(index)))
+            .unwrap_or(0.0)
+    }
+
+    /// Set parameter from a normalized 0..1 value via its descriptor
+    fn set_param_normalized(&mut self, index: usize, value: f64) {
+        let Some(plain) = self.parameters().get(index).map(|info| info.denormalize(value)) else {
+            return;
+        };
+        self.set_param(index, plain);
+    }
+
     /// Get metering value by index
     /// 0 = gain reduction L (dB), 1 = gain reduction R (dB)
     /// Default returns 0.0 (no metering)
@@ -806,6 +959,25 @@ impl InsertChain {
         }
     }
 
+    /// Feed a key signal to one slot (0-3 = pre, 4-7 = post) for its next block
+    ///
+    /// Used for keys routed by the `SidechainRouter`; a slot with its own
+    /// track sidechain source still takes that key when it processes.
+    pub fn set_slot_sidechain_input(
+        &mut self,
+        index: usize,
+        left: &[Sample],
+        right: &[Sample],
+    ) -> bool {
+        match self.slot_mut(index) {
+            Some(slot) if slot.is_loaded() => {
+                slot.set_sidechain_input(left, right);
+                true
+            }
+            _ => false,
+        }
+    }
+
     /// Check if any slot has external sidechain enabled
     pub fn has_any_sidechain(&self) -> bool {
         self.pre_slots.iter().any(|s| s.has_external_sidechain())
@@ -830,6 +1002,18 @@ impl InsertChain {
         self.total_latency
     }
 
+    /// Latency of the slots ahead of `index` (0-3 = pre, 4-7 = post)
+    ///
+    /// `MAX_INSERT_SLOTS / 2` gives the latency at the fader.
+    pub fn latency_before(&self, index: usize) -> LatencySamples {
+        self.pre_slots
+            .iter()
+            .chain(self.post_slots.iter())
+            .take(index)
+            .map(|s| s.latency())
+            .sum()
+    }
+
     /// Set sample rate
     pub fn set_sample_rate(&mut self, sample_rate: f64) {
         self.sample_rate = sample_rate;
@@ -929,6 +1113,15 @@ impl InsertChain {
             .unwrap_or(0.0)
     }
 
+    /// Descriptor of a parameter on the processor in a specific slot
+    pub fn slot_param_info(&self, slot_index: usize, param_index: usize) -> Option<ParameterInfo> {
+        self.slot(slot_index)?
+            .processor()?
+            .parameters()
+            .get(param_index)
+            .copied()
+    }
+
     /// Get meter value from processor in specific slot
     /// meter_index: 0=GR left, 1=GR right (for dynamics processors)
     pub fn get_slot_meter(&self, slot_index: usize, meter_index: usize) -> f64 {
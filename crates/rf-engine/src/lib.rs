@@ -80,7 +80,10 @@ pub mod daw_mixer;
 
 // Phase 8: Automation Engine
 pub mod automation;
+pub mod modulation;
 pub mod param_smoother;
+pub mod output_ramp;
+pub mod midi_learn;
 
 // Phase 10: Recording
 pub mod recording_manager;
@@ -135,6 +138,9 @@ pub use rf_core::wav_writer;
 // Phase 10e-3: Per-bus 4-band energy analyzer for precise masking detection.
 pub mod per_bus_band_energy;
 
+// Master mono-fold loss / stereo width meter (mono-compatibility checks)
+pub mod mono_compat;
+
 // Re-exports: Core
 pub use bus::*;
 pub use graph::*;
@@ -147,7 +153,7 @@ pub use realtime::*;
 pub use parallel_graph::{BufferPool, Connection, ConnectionType, ParallelAudioGraph};
 
 pub use insert_chain::{
-    InsertChain, InsertPosition, InsertProcessor, InsertSlot, MAX_INSERT_SLOTS,
+    InsertChain, InsertPosition, InsertProcessor, InsertSlot, MAX_INSERT_SLOTS, ParameterInfo,
 };
 
 pub use send_return::{
@@ -161,7 +167,7 @@ pub use dual_path::{
 
 pub use sidechain::{
     SidechainFilterMode, SidechainId, SidechainInput, SidechainRoute, SidechainRouter,
-    SidechainSource,
+    SidechainSource, SidechainSourceKind,
 };
 
 pub use freeze::{FreezeConfig, FreezeError, FreezeManager, FrozenTrackInfo};
@@ -171,7 +177,9 @@ pub use groups::{
     VcaInfo,
 };
 
-pub use click::{ClickPattern, ClickSound, ClickTrack, ClickTrackSettings, CountInMode};
+pub use click::{
+    ClickPattern, ClickSound, ClickTrack, ClickTrackSettings, CountInEvent, CountInMode,
+};
 
 pub use pdc::{
     ConnectionType as PdcConnectionType, DEFAULT_CONSTRAIN_THRESHOLD, MAX_PDC_SAMPLES,
@@ -180,8 +188,8 @@ pub use pdc::{
 };
 
 pub use anticipatory::{
-    AnticipatoryScheduler, NodeStats, ProcessingJob, ProcessingResult, SchedulerConfig,
-    SchedulerStats,
+    AnticipatoryScheduler, BlockCollector, DeadlinePolicy, NodeStats, ProcessingJob,
+    ProcessingResult, SchedulerConfig, SchedulerStats,
 };
 
 pub use fx_container::{
@@ -307,17 +315,17 @@ pub use render_matrix::{
 // Re-exports: Phase 13 - Disk Streaming
 pub use streaming::{
     AssetCatalog, AssetInfo, AudioEvent, AudioFormat, AudioRingBuffer, ControlCommand,
-    ControlCommandType, ControlQueue, DEFAULT_RING_BUFFER_FRAMES, DiskJob, DiskReaderPool,
-    EventIndex, HIGH_WATER_FRAMES, LOW_WATER_FRAMES, StreamRT, StreamState, StreamingEngine,
-    TrackRT,
+    ControlCommandType, ControlQueue, DEFAULT_RING_BUFFER_FRAMES, DiskJob, DiskJobClass,
+    DiskReaderPool, DiskSource, EventIndex, FileDiskSource, HIGH_WATER_FRAMES, LOW_WATER_FRAMES,
+    StreamRT, StreamState, StreamingEngine, TrackRT,
 };
 
 // Re-exports: Phase 14 - Wave Cache
 pub use wave_cache::{
     BASE_TILE_SAMPLES, BuildProgress, BuildState, CachedTile, GetCacheResult, MipLevel,
-    NUM_MIP_LEVELS, TileData, TileRequest, TileResponse, WFC_MAGIC, WFC_VERSION, WaveCacheBuilder,
-    WaveCacheError, WaveCacheManager, WaveCacheQuery, WfcFile, WfcHeader, build_from_samples,
-    tiles_to_flat_array,
+    NUM_MIP_LEVELS, TileData, TileRequest, TileResponse, WFC_MAGIC, WFC_MIN_VERSION, WFC_VERSION,
+    WaveCacheBuilder, WaveCacheError, WaveCacheManager, WaveCacheQuery, WfcFile, WfcHeader,
+    build_from_samples, tiles_to_flat_array,
 };
 
 // Re-exports: Phase 15 - Stage Audio
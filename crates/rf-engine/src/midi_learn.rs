@@ -0,0 +1,605 @@
+//! MIDI Learn — CC to insert parameter mapping
+//!
+//! Binds hardware controllers (MIDI CC) to insert processor parameters:
+//! - Arm a parameter, the next incoming CC is bound to it
+//! - Per-mapping range and response curve
+//! - Per-mapping smoothing (7-bit CC steps would otherwise zipper)
+//!
+//! # Lock-Free Design
+//! Mappings live in a fixed-size table of atomics. The MIDI input thread writes
+//! scaled targets, the audio thread advances smoothing and applies the values.
+//! Only table edits (learn/bind/remove) from non-audio threads take the edit lock.
+
+use parking_lot::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, AtomicU64, Ordering};
+
+/// Maximum number of CC mappings
+pub const MAX_MIDI_CC_MAPPINGS: usize = 128;
+
+/// Default smoothing time for CC mappings in milliseconds
+pub const DEFAULT_CC_SMOOTHING_MS: f64 = 20.0;
+
+/// Smoothing is considered done below this distance to the target
+const SMOOTH_THRESHOLD: f64 = 1e-9;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// MAPPING
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Response curve applied to the normalized CC value before range scaling
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CcCurve {
+    /// Straight line
+    #[default]
+    Linear,
+    /// sqrt — finer control at the top of the knob travel
+    Logarithmic,
+    /// x² — finer control at the bottom of the knob travel
+    Exponential,
+}
+
+impl CcCurve {
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => Self::Logarithmic,
+            2 => Self::Exponential,
+            _ => Self::Linear,
+        }
+    }
+
+    pub fn as_u8(self) -> u8 {
+        match self {
+            Self::Linear => 0,
+            Self::Logarithmic => 1,
+            Self::Exponential => 2,
+        }
+    }
+
+    /// Shape a normalized 0..1 value
+    #[inline]
+    pub fn apply(self, x: f64) -> f64 {
+        let x = x.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => x,
+            Self::Logarithmic => x.sqrt(),
+            Self::Exponential => x * x,
+        }
+    }
+}
+
+/// CC → insert parameter binding
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MidiCcMapping {
+    /// MIDI channel (0-15)
+    pub channel: u8,
+    /// Controller number (0-127)
+    pub cc: u8,
+    /// Target track (0 = master bus)
+    pub track_id: u64,
+    /// Insert slot on the target track
+    pub slot_index: usize,
+    /// Parameter index on the insert processor
+    pub param_index: usize,
+    /// Parameter value at CC 0
+    pub min: f64,
+    /// Parameter value at CC 127
+    pub max: f64,
+    /// Response curve
+    pub curve: CcCurve,
+    /// Smoothing time in milliseconds (0 = none)
+    pub smoothing_ms: f64,
+}
+
+impl MidiCcMapping {
+    /// Create mapping with a 0..1 range, linear curve and default smoothing
+    pub fn new(channel: u8, cc: u8, track_id: u64, slot_index: usize, param_index: usize) -> Self {
+        Self {
+            channel: channel & 0x0F,
+            cc: cc & 0x7F,
+            track_id,
+            slot_index,
+            param_index,
+            min: 0.0,
+            max: 1.0,
+            curve: CcCurve::Linear,
+            smoothing_ms: DEFAULT_CC_SMOOTHING_MS,
+        }
+    }
+
+    /// Set parameter range
+    pub fn with_range(mut self, min: f64, max: f64) -> Self {
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Set response curve
+    pub fn with_curve(mut self, curve: CcCurve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Set smoothing time
+    pub fn with_smoothing(mut self, smoothing_ms: f64) -> Self {
+        self.smoothing_ms = smoothing_ms.max(0.0);
+        self
+    }
+
+    /// Map a 7-bit CC value to the parameter range
+    #[inline]
+    pub fn scale(&self, value: u8) -> f64 {
+        let x = self.curve.apply(f64::from(value.min(127)) / 127.0);
+        self.min + x * (self.max - self.min)
+    }
+
+    #[inline]
+    fn key(&self) -> u32 {
+        cc_key(self.channel, self.cc)
+    }
+}
+
+#[inline]
+fn cc_key(channel: u8, cc: u8) -> u32 {
+    (u32::from(channel & 0x0F) << 8) | u32::from(cc & 0x7F)
+}
+
+#[inline]
+fn pack_target(slot_index: usize, param_index: usize) -> u64 {
+    ((slot_index as u64) << 32) | (param_index as u64 & 0xFFFF_FFFF)
+}
+
+#[inline]
+fn unpack_target(packed: u64) -> (usize, usize) {
+    ((packed >> 32) as usize, (packed & 0xFFFF_FFFF) as usize)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// LOCK-FREE SLOT
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// One mapping stored as atomics (f64 values stored as bits)
+#[derive(Debug, Default)]
+struct CcSlot {
+    active: AtomicBool,
+    key: AtomicU32,
+    track_id: AtomicU64,
+    target_packed: AtomicU64,
+    min: AtomicU64,
+    max: AtomicU64,
+    curve: AtomicU8,
+    smoothing_ms: AtomicU64,
+    /// Latest scaled value from the MIDI thread
+    target: AtomicU64,
+    /// Smoothed value (audio thread only)
+    current: AtomicU64,
+    /// Target changed, or smoothing still running
+    pending: AtomicBool,
+    /// No value applied yet — first value jumps instead of ramping
+    primed: AtomicBool,
+}
+
+impl CcSlot {
+    fn store(&self, mapping: &MidiCcMapping) {
+        // Deactivate while fields are rewritten so the audio thread skips it
+        self.active.store(false, Ordering::Release);
+        self.key.store(mapping.key(), Ordering::Relaxed);
+        self.track_id.store(mapping.track_id, Ordering::Relaxed);
+        self.target_packed.store(
+            pack_target(mapping.slot_index, mapping.param_index),
+            Ordering::Relaxed,
+        );
+        self.min.store(mapping.min.to_bits(), Ordering::Relaxed);
+        self.max.store(mapping.max.to_bits(), Ordering::Relaxed);
+        self.curve.store(mapping.curve.as_u8(), Ordering::Relaxed);
+        self.smoothing_ms
+            .store(mapping.smoothing_ms.to_bits(), Ordering::Relaxed);
+        self.pending.store(false, Ordering::Relaxed);
+        self.primed.store(false, Ordering::Relaxed);
+        self.active.store(true, Ordering::Release);
+    }
+
+    fn load(&self) -> MidiCcMapping {
+        let key = self.key.load(Ordering::Relaxed);
+        let (slot_index, param_index) = unpack_target(self.target_packed.load(Ordering::Relaxed));
+        MidiCcMapping {
+            channel: ((key >> 8) & 0x0F) as u8,
+            cc: (key & 0x7F) as u8,
+            track_id: self.track_id.load(Ordering::Relaxed),
+            slot_index,
+            param_index,
+            min: f64::from_bits(self.min.load(Ordering::Relaxed)),
+            max: f64::from_bits(self.max.load(Ordering::Relaxed)),
+            curve: CcCurve::from_u8(self.curve.load(Ordering::Relaxed)),
+            smoothing_ms: f64::from_bits(self.smoothing_ms.load(Ordering::Relaxed)),
+        }
+    }
+
+    fn is_active(&self) -> bool {
+        self.active.load(Ordering::Acquire)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TABLE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// MIDI learn state and CC mapping table
+#[derive(Debug)]
+pub struct MidiLearnTable {
+    slots: Box<[CcSlot]>,
+    /// A parameter is armed and waiting for a CC
+    learning: AtomicBool,
+    learn_track_id: AtomicU64,
+    learn_target: AtomicU64,
+    /// Plain range seeded into the learned mapping (f64 bits)
+    learn_min: AtomicU64,
+    learn_max: AtomicU64,
+    /// Serializes table edits (never taken on the audio thread)
+    edit_lock: Mutex<()>,
+}
+
+impl Default for MidiLearnTable {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MidiLearnTable {
+    pub fn new() -> Self {
+        Self {
+            slots: (0..MAX_MIDI_CC_MAPPINGS)
+                .map(|_| CcSlot::default())
+                .collect(),
+            learning: AtomicBool::new(false),
+            learn_track_id: AtomicU64::new(0),
+            learn_target: AtomicU64::new(0),
+            learn_min: AtomicU64::new(0.0f64.to_bits()),
+            learn_max: AtomicU64::new(1.0f64.to_bits()),
+            edit_lock: Mutex::new(()),
+        }
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // LEARN
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Arm a parameter — the next incoming CC is bound to it
+    ///
+    /// `min`/`max` seed the learned mapping's range in the parameter's plain
+    /// units (`PlaybackEngine::start_midi_learn` takes them from the
+    /// parameter descriptor).
+    pub fn start_learn(
+        &self,
+        track_id: u64,
+        slot_index: usize,
+        param_index: usize,
+        min: f64,
+        max: f64,
+    ) {
+        self.learn_track_id.store(track_id, Ordering::Relaxed);
+        self.learn_target
+            .store(pack_target(slot_index, param_index), Ordering::Relaxed);
+        self.learn_min.store(min.to_bits(), Ordering::Relaxed);
+        self.learn_max.store(max.to_bits(), Ordering::Relaxed);
+        self.learning.store(true, Ordering::Release);
+    }
+
+    /// Disarm without binding
+    pub fn cancel_learn(&self) {
+        self.learning.store(false, Ordering::Release);
+    }
+
+    /// Is a parameter armed
+    pub fn is_learning(&self) -> bool {
+        self.learning.load(Ordering::Acquire)
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // MIDI INPUT (MIDI thread)
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Handle a control change message
+    ///
+    /// Binds the CC if a parameter is armed, then updates the target of every
+    /// mapping listening to it. Returns true if any mapping consumed the message.
+    pub fn handle_cc(&self, channel: u8, cc: u8, value: u8) -> bool {
+        if self.learning.swap(false, Ordering::AcqRel) {
+            let track_id = self.learn_track_id.load(Ordering::Relaxed);
+            let (slot_index, param_index) =
+                unpack_target(self.learn_target.load(Ordering::Relaxed));
+            let min = f64::from_bits(self.learn_min.load(Ordering::Relaxed));
+            let max = f64::from_bits(self.learn_max.load(Ordering::Relaxed));
+            self.bind(
+                MidiCcMapping::new(channel, cc, track_id, slot_index, param_index)
+                    .with_range(min, max),
+            );
+        }
+
+        let key = cc_key(channel, cc);
+        let mut consumed = false;
+        for slot in self.slots.iter() {
+            if slot.is_active() && slot.key.load(Ordering::Relaxed) == key {
+                let scaled = slot.load().scale(value);
+                slot.target.store(scaled.to_bits(), Ordering::Relaxed);
+                slot.pending.store(true, Ordering::Release);
+                consumed = true;
+            }
+        }
+        consumed
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // TABLE EDITS (UI / MIDI thread)
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Add a mapping, replacing any existing mapping for the same parameter
+    ///
+    /// Returns the mapping index, or None if the table is full.
+    pub fn bind(&self, mapping: MidiCcMapping) -> Option<usize> {
+        let _guard = self.edit_lock.lock();
+        let target = pack_target(mapping.slot_index, mapping.param_index);
+        let existing = self.slots.iter().position(|s| {
+            s.is_active()
+                && s.track_id.load(Ordering::Relaxed) == mapping.track_id
+                && s.target_packed.load(Ordering::Relaxed) == target
+        });
+        let index = existing.or_else(|| self.slots.iter().position(|s| !s.is_active()))?;
+        self.slots[index].store(&mapping);
+        Some(index)
+    }
+
+    /// Update range, curve and smoothing of an existing mapping
+    pub fn set_range(
+        &self,
+        index: usize,
+        min: f64,
+        max: f64,
+        curve: CcCurve,
+        smoothing_ms: f64,
+    ) -> bool {
+        let _guard = self.edit_lock.lock();
+        let Some(slot) = self.slots.get(index).filter(|s| s.is_active()) else {
+            return false;
+        };
+        slot.min.store(min.to_bits(), Ordering::Relaxed);
+        slot.max.store(max.to_bits(), Ordering::Relaxed);
+        slot.curve.store(curve.as_u8(), Ordering::Relaxed);
+        slot.smoothing_ms
+            .store(smoothing_ms.max(0.0).to_bits(), Ordering::Relaxed);
+        true
+    }
+
+    /// Remove mapping by index
+    pub fn remove(&self, index: usize) -> bool {
+        let _guard = self.edit_lock.lock();
+        match self.slots.get(index) {
+            Some(slot) if slot.is_active() => {
+                slot.active.store(false, Ordering::Release);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Remove all mappings bound to a parameter
+    pub fn remove_for_param(&self, track_id: u64, slot_index: usize, param_index: usize) -> bool {
+        let _guard = self.edit_lock.lock();
+        let target = pack_target(slot_index, param_index);
+        let mut removed = false;
+        for slot in self.slots.iter() {
+            if slot.is_active()
+                && slot.track_id.load(Ordering::Relaxed) == track_id
+                && slot.target_packed.load(Ordering::Relaxed) == target
+            {
+                slot.active.store(false, Ordering::Release);
+                removed = true;
+            }
+        }
+        removed
+    }
+
+    /// Remove all mappings and disarm learn
+    pub fn clear(&self) {
+        let _guard = self.edit_lock.lock();
+        self.learning.store(false, Ordering::Release);
+        for slot in self.slots.iter() {
+            slot.active.store(false, Ordering::Release);
+        }
+    }
+
+    /// Replace all mappings (project load)
+    pub fn replace_all(&self, mappings: &[MidiCcMapping]) {
+        self.clear();
+        for mapping in mappings {
+            self.bind(*mapping);
+        }
+    }
+
+    /// Get mapping by index
+    pub fn get(&self, index: usize) -> Option<MidiCcMapping> {
+        self.slots
+            .get(index)
+            .filter(|s| s.is_active())
+            .map(CcSlot::load)
+    }
+
+    /// Snapshot of all active mappings with their table indices
+    pub fn mappings(&self) -> Vec<(usize, MidiCcMapping)> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, s)| s.is_active())
+            .map(|(i, s)| (i, s.load()))
+            .collect()
+    }
+
+    /// Number of active mappings
+    pub fn len(&self) -> usize {
+        self.slots.iter().filter(|s| s.is_active()).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // AUDIO THREAD
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Advance smoothing by one block and apply changed values (AUDIO THREAD)
+    ///
+    /// `apply(track_id, slot_index, param_index, value)` is called for every
+    /// mapping whose value moved this block. No locks, no allocations.
+    pub fn process_block<F>(&self, frames: usize, sample_rate: f64, mut apply: F)
+    where
+        F: FnMut(u64, usize, usize, f64),
+    {
+        for slot in self.slots.iter() {
+            if !slot.is_active() || !slot.pending.load(Ordering::Acquire) {
+                continue;
+            }
+
+            let target = f64::from_bits(slot.target.load(Ordering::Relaxed));
+            let smoothing_ms = f64::from_bits(slot.smoothing_ms.load(Ordering::Relaxed));
+
+            let value = if !slot.primed.swap(true, Ordering::Relaxed) || smoothing_ms <= 0.0 {
+                target
+            } else {
+                // Block-rate one-pole: coeff = 1 - exp(-frames / time_constant)
+                let current = f64::from_bits(slot.current.load(Ordering::Relaxed));
+                let tau_samples = smoothing_ms / 1000.0 * sample_rate;
+                let coeff = 1.0 - (-(frames as f64) / tau_samples.max(1.0)).exp();
+                let next = current + coeff * (target - current);
+                if (target - next).abs() < SMOOTH_THRESHOLD {
+                    target
+                } else {
+                    next
+                }
+            };
+
+            slot.current.store(value.to_bits(), Ordering::Relaxed);
+            if value == target {
+                // Only clear if no newer target arrived meanwhile
+                if f64::from_bits(slot.target.load(Ordering::Relaxed)) == target {
+                    slot.pending.store(false, Ordering::Release);
+                }
+            }
+
+            let (slot_index, param_index) =
+                unpack_target(slot.target_packed.load(Ordering::Relaxed));
+            apply(
+                slot.track_id.load(Ordering::Relaxed),
+                slot_index,
+                param_index,
+                value,
+            );
+        }
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dsp_wrappers::ProEqWrapper;
+    use crate::insert_chain::InsertChain;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_learn_binds_next_cc() {
+        let table = MidiLearnTable::new();
+        table.start_learn(3, 1, 7, -12.0, 12.0);
+        assert!(table.is_learning());
+
+        assert!(table.handle_cc(2, 74, 10));
+        assert!(!table.is_learning());
+
+        let mappings = table.mappings();
+        assert_eq!(mappings.len(), 1);
+        let (_, m) = mappings[0];
+        assert_eq!((m.channel, m.cc), (2, 74));
+        assert_eq!((m.track_id, m.slot_index, m.param_index), (3, 1, 7));
+        assert_eq!((m.min, m.max), (-12.0, 12.0));
+
+        // Other controllers are ignored
+        assert!(!table.handle_cc(2, 75, 10));
+        assert!(!table.handle_cc(3, 74, 10));
+    }
+
+    #[test]
+    fn test_learned_cc_drives_param_across_range() {
+        let table = MidiLearnTable::new();
+        let mut chain = InsertChain::new(48000.0);
+        chain.load(0, Box::new(ProEqWrapper::new(48000.0)));
+
+        // Learn CC 21 on ProEq band 0 gain, then set a ±24 dB range, no smoothing
+        table.start_learn(1, 0, 1, 0.0, 1.0);
+        table.handle_cc(0, 21, 0);
+        let (index, _) = table.mappings()[0];
+        assert!(table.set_range(index, -24.0, 24.0, CcCurve::Linear, 0.0));
+
+        for (cc_value, expected) in [(0u8, -24.0), (127, 24.0), (64, -24.0 + 48.0 * 64.0 / 127.0)] {
+            table.handle_cc(0, 21, cc_value);
+            table.process_block(256, 48000.0, |_, slot, param, value| {
+                chain.set_slot_param(slot, param, value);
+            });
+            let actual = chain.get_slot_param(0, 1);
+            assert!(
+                (actual - expected).abs() < 1e-9,
+                "CC {cc_value}: expected {expected}, got {actual}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_smoothing_ramps_to_target() {
+        let table = MidiLearnTable::new();
+        let index = table
+            .bind(
+                MidiCcMapping::new(0, 1, 1, 0, 0)
+                    .with_range(0.0, 100.0)
+                    .with_smoothing(10.0),
+            )
+            .unwrap();
+        assert_eq!(table.get(index).unwrap().max, 100.0);
+
+        let last = Cell::new(f64::NAN);
+        let record = |_: u64, _: usize, _: usize, v: f64| last.set(v);
+
+        // First value jumps
+        table.handle_cc(0, 1, 0);
+        table.process_block(64, 48000.0, record);
+        assert_eq!(last.get(), 0.0);
+
+        // Then ramps monotonically towards the new target
+        table.handle_cc(0, 1, 127);
+        table.process_block(64, 48000.0, record);
+        let first_step = last.get();
+        assert!(first_step > 0.0 && first_step < 100.0);
+        for _ in 0..200 {
+            table.process_block(64, 48000.0, record);
+        }
+        assert!((last.get() - 100.0).abs() < 1e-6);
+        assert!(last.get() > first_step);
+    }
+
+    #[test]
+    fn test_curves_and_rebind() {
+        let m = MidiCcMapping::new(0, 1, 1, 0, 0).with_curve(CcCurve::Exponential);
+        assert!((m.scale(64) - (64.0f64 / 127.0).powi(2)).abs() < 1e-12);
+        assert_eq!(m.scale(127), 1.0);
+
+        let table = MidiLearnTable::new();
+        table.bind(MidiCcMapping::new(0, 1, 1, 0, 0));
+        // Same parameter, different CC: replaces instead of adding
+        table.bind(MidiCcMapping::new(0, 2, 1, 0, 0));
+        assert_eq!(table.len(), 1);
+        assert_eq!(table.mappings()[0].1.cc, 2);
+
+        assert!(table.remove_for_param(1, 0, 0));
+        assert!(table.is_empty());
+    }
+}
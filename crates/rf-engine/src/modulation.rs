@@ -0,0 +1,435 @@
+//! Modulation Engine
+//!
+//! Per-block LFO modulation of automatable parameters:
+//! - Sine/Triangle/Saw/Random shapes (shared with `LfoShape`)
+//! - Tempo-synced (beats per cycle) or free-running (Hz) rates
+//! - Any number of parameter assignments per LFO, each with depth and offset
+//! - Combined with `AutomationEngine` block output
+
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use crate::automation::{AutomationChange, LfoShape, ParamId};
+
+// ═══════════════════════════════════════════════════════════════════════════
+// LFO
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// LFO identifier
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct LfoId(pub u32);
+
+/// LFO rate
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LfoRate {
+    /// Tempo-synced: one cycle every `beats` quarter notes (1.0 = 1/4, 0.5 = 1/8, 4.0 = 1 bar in 4/4)
+    Synced { beats: f64 },
+    /// Free-running frequency in Hz
+    Free { hz: f64 },
+}
+
+impl Default for LfoRate {
+    fn default() -> Self {
+        Self::Synced { beats: 1.0 }
+    }
+}
+
+/// LFO configuration
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LfoConfig {
+    /// Waveform shape
+    pub shape: LfoShape,
+    /// Cycle rate
+    pub rate: LfoRate,
+    /// Phase offset (0.0-1.0)
+    pub phase_offset: f64,
+}
+
+impl Default for LfoConfig {
+    fn default() -> Self {
+        Self {
+            shape: LfoShape::Sine,
+            rate: LfoRate::default(),
+            phase_offset: 0.0,
+        }
+    }
+}
+
+impl LfoConfig {
+    /// Tempo-synced LFO with one cycle every `beats` quarter notes
+    pub fn synced(shape: LfoShape, beats: f64) -> Self {
+        Self {
+            shape,
+            rate: LfoRate::Synced { beats },
+            phase_offset: 0.0,
+        }
+    }
+
+    /// Free-running LFO at `hz`
+    pub fn free(shape: LfoShape, hz: f64) -> Self {
+        Self {
+            shape,
+            rate: LfoRate::Free { hz },
+            phase_offset: 0.0,
+        }
+    }
+
+    /// Cycle length in samples (infinite for a zero rate)
+    pub fn cycle_samples(&self, sample_rate: f64, bpm: f64) -> f64 {
+        let hz = match self.rate {
+            LfoRate::Synced { beats } => bpm / 60.0 / beats,
+            LfoRate::Free { hz } => hz,
+        };
+        if hz > 0.0 && hz.is_finite() {
+            sample_rate / hz
+        } else {
+            f64::INFINITY
+        }
+    }
+
+    /// LFO output (-1.0 to 1.0) at an absolute timeline position.
+    ///
+    /// Phase is derived from the position rather than accumulated, so seeking
+    /// and looping stay locked to the grid (assumes constant tempo).
+    pub fn value_at(&self, time_samples: u64, sample_rate: f64, bpm: f64) -> f64 {
+        let cycle = self.cycle_samples(sample_rate, bpm);
+        if !cycle.is_finite() {
+            return self.shape.evaluate(self.phase_offset.rem_euclid(1.0));
+        }
+        let cycles = time_samples as f64 / cycle + self.phase_offset;
+        match self.shape {
+            // One random value per cycle, deterministic for a given cycle index
+            LfoShape::Random | LfoShape::SampleAndHold => {
+                let index = cycles.floor();
+                self.shape
+                    .evaluate((index * 0.618_033_988_749_895).rem_euclid(1.0))
+            }
+            shape => shape.evaluate(cycles.rem_euclid(1.0)),
+        }
+    }
+}
+
+/// LFO → parameter assignment
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ModAssignment {
+    /// Target parameter
+    pub param_id: ParamId,
+    /// Modulation depth in normalized units (LFO ±1.0 → ±depth)
+    pub depth: f64,
+    /// Center value (0.0-1.0) used when the parameter has no automation
+    pub offset: f64,
+}
+
+#[derive(Debug, Clone)]
+struct Lfo {
+    config: LfoConfig,
+    assignments: Vec<ModAssignment>,
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// MODULATION ENGINE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Modulation engine
+pub struct ModulationEngine {
+    /// All LFOs
+    lfos: RwLock<HashMap<LfoId, Lfo>>,
+    /// Next LFO ID
+    next_id: AtomicU32,
+}
+
+impl Default for ModulationEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ModulationEngine {
+    pub fn new() -> Self {
+        Self {
+            lfos: RwLock::new(HashMap::new()),
+            next_id: AtomicU32::new(1),
+        }
+    }
+
+    /// Add an LFO
+    pub fn add_lfo(&self, config: LfoConfig) -> LfoId {
+        let id = LfoId(self.next_id.fetch_add(1, Ordering::Relaxed));
+        self.lfos.write().insert(
+            id,
+            Lfo {
+                config,
+                assignments: Vec::new(),
+            },
+        );
+        id
+    }
+
+    /// Remove an LFO and all its assignments
+    pub fn remove_lfo(&self, lfo_id: LfoId) -> bool {
+        self.lfos.write().remove(&lfo_id).is_some()
+    }
+
+    /// Replace an LFO's configuration
+    pub fn set_lfo_config(&self, lfo_id: LfoId, config: LfoConfig) -> bool {
+        match self.lfos.write().get_mut(&lfo_id) {
+            Some(lfo) => {
+                lfo.config = config;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Get an LFO's configuration
+    pub fn lfo_config(&self, lfo_id: LfoId) -> Option<LfoConfig> {
+        self.lfos.read().get(&lfo_id).map(|lfo| lfo.config)
+    }
+
+    /// Assign an LFO to a parameter, centered at 0.5 when not automated
+    pub fn assign(&self, lfo_id: LfoId, param_id: ParamId, depth: f64) -> bool {
+        self.assign_with_offset(lfo_id, param_id, depth, 0.5)
+    }
+
+    /// Assign an LFO to a parameter with an explicit center value.
+    /// Re-assigning the same parameter updates depth and offset.
+    pub fn assign_with_offset(
+        &self,
+        lfo_id: LfoId,
+        param_id: ParamId,
+        depth: f64,
+        offset: f64,
+    ) -> bool {
+        let mut lfos = self.lfos.write();
+        let Some(lfo) = lfos.get_mut(&lfo_id) else {
+            return false;
+        };
+        let depth = depth.clamp(-1.0, 1.0);
+        let offset = offset.clamp(0.0, 1.0);
+        match lfo.assignments.iter_mut().find(|a| a.param_id == param_id) {
+            Some(existing) => {
+                existing.depth = depth;
+                existing.offset = offset;
+            }
+            None => lfo.assignments.push(ModAssignment {
+                param_id,
+                depth,
+                offset,
+            }),
+        }
+        true
+    }
+
+    /// Remove an LFO → parameter assignment
+    pub fn unassign(&self, lfo_id: LfoId, param_id: &ParamId) -> bool {
+        let mut lfos = self.lfos.write();
+        let Some(lfo) = lfos.get_mut(&lfo_id) else {
+            return false;
+        };
+        let before = lfo.assignments.len();
+        lfo.assignments.retain(|a| &a.param_id != param_id);
+        lfo.assignments.len() != before
+    }
+
+    /// Assignments of an LFO
+    pub fn assignments(&self, lfo_id: LfoId) -> Vec<ModAssignment> {
+        self.lfos
+            .read()
+            .get(&lfo_id)
+            .map(|lfo| lfo.assignments.clone())
+            .unwrap_or_default()
+    }
+
+    /// Number of LFOs
+    pub fn lfo_count(&self) -> usize {
+        self.lfos.read().len()
+    }
+
+    /// Evaluate all LFOs for a block and combine them with automation output.
+    ///
+    /// Allocating convenience wrapper around `process_block_into`.
+    pub fn process_block(
+        &self,
+        start_sample: u64,
+        sample_rate: f64,
+        bpm: f64,
+        automation: &[AutomationChange],
+    ) -> Vec<AutomationChange> {
+        let mut changes = Vec::new();
+        let count =
+            self.process_block_into(start_sample, sample_rate, bpm, automation, &mut changes);
+        changes.truncate(count);
+        changes
+    }
+
+    /// Evaluate all LFOs for a block into reusable storage (audio thread).
+    ///
+    /// Each modulated parameter is centered on its block-start automation value
+    /// (from `automation`, the output of `AutomationEngine::get_block_changes`)
+    /// or on the assignment offset when it isn't automated. Contributions from
+    /// several LFOs on the same parameter add up; the result is clamped to 0-1.
+    ///
+    /// The first `count` entries of `changes` (the return value) are this
+    /// block's changes, all at `sample_offset` 0; apply them after the
+    /// automation changes so they take precedence. Entries past `count` are
+    /// kept as storage, so once `changes` has grown to the number of modulated
+    /// parameters no block allocates.
+    pub fn process_block_into(
+        &self,
+        start_sample: u64,
+        sample_rate: f64,
+        bpm: f64,
+        automation: &[AutomationChange],
+        changes: &mut Vec<AutomationChange>,
+    ) -> usize {
+        // Lock contention - skip modulation this block
+        let Some(lfos) = self.lfos.try_read() else {
+            return 0;
+        };
+
+        let mut count = 0;
+        for lfo in lfos.values() {
+            if lfo.assignments.is_empty() {
+                continue;
+            }
+            let lfo_value = lfo.config.value_at(start_sample, sample_rate, bpm);
+            for assignment in &lfo.assignments {
+                let index = match changes[..count]
+                    .iter()
+                    .position(|c| c.param_id == assignment.param_id)
+                {
+                    Some(index) => index,
+                    None => {
+                        let center = automation
+                            .iter()
+                            .find(|c| c.sample_offset == 0 && c.param_id == assignment.param_id)
+                            .map_or(assignment.offset, |c| c.value);
+                        match changes.get_mut(count) {
+                            Some(slot) => {
+                                copy_param_id(&mut slot.param_id, &assignment.param_id);
+                                slot.value = center;
+                            }
+                            None => changes.push(AutomationChange {
+                                sample_offset: 0,
+                                param_id: assignment.param_id.clone(),
+                                value: center,
+                            }),
+                        }
+                        count += 1;
+                        count - 1
+                    }
+                };
+                changes[index].value += assignment.depth * lfo_value;
+            }
+        }
+
+        for change in &mut changes[..count] {
+            change.sample_offset = 0;
+            change.value = change.value.clamp(0.0, 1.0);
+        }
+        count
+    }
+}
+
+/// Overwrite `dst` with `src`, reusing `dst`'s name buffer
+fn copy_param_id(dst: &mut ParamId, src: &ParamId) {
+    if dst == src {
+        return;
+    }
+    dst.target_id = src.target_id;
+    dst.target_type = src.target_type;
+    dst.param_name.clone_from(&src.param_name);
+    dst.slot = src.slot;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quarter_note_sine_at_120_bpm() {
+        let sample_rate = 48000.0;
+        let bpm = 120.0;
+        let engine = ModulationEngine::new();
+        let lfo = engine.add_lfo(LfoConfig::synced(LfoShape::Sine, 1.0));
+        let param = ParamId::track_volume(1);
+        assert!(engine.assign(lfo, param.clone(), 0.25));
+
+        // 1/4 note at 120 BPM = 500ms = 24000 samples per cycle
+        let config = engine.lfo_config(lfo).unwrap();
+        assert!((config.cycle_samples(sample_rate, bpm) - 24000.0).abs() < 1e-9);
+        for t in [0, 1000, 6000, 17000] {
+            let a = config.value_at(t, sample_rate, bpm);
+            let b = config.value_at(t + 24000, sample_rate, bpm);
+            assert!((a - b).abs() < 1e-9, "t={} {} vs {}", t, a, b);
+        }
+
+        // Peak at a quarter cycle, trough at three quarters
+        let at = |sample: u64| {
+            let changes = engine.process_block(sample, sample_rate, bpm, &[]);
+            assert_eq!(changes.len(), 1);
+            assert_eq!(changes[0].param_id, param);
+            changes[0].value
+        };
+        assert!((at(6000) - 0.75).abs() < 1e-9);
+        assert!((at(18000) - 0.25).abs() < 1e-9);
+
+        let (mut min, mut max) = (f64::MAX, f64::MIN);
+        for block in 0..(24000 / 256 + 1) {
+            let value = at(block * 256);
+            min = min.min(value);
+            max = max.max(value);
+        }
+        assert!(min >= 0.25 - 1e-9 && max <= 0.75 + 1e-9);
+        assert!(max - min > 0.49);
+
+        // Automation value replaces the offset as center
+        let automated = [AutomationChange {
+            sample_offset: 0,
+            param_id: param.clone(),
+            value: 0.4,
+        }];
+        let changes = engine.process_block(6000, sample_rate, bpm, &automated);
+        assert!((changes[0].value - 0.65).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_process_block_into_reuses_storage() {
+        let engine = ModulationEngine::new();
+        let a = engine.add_lfo(LfoConfig::free(LfoShape::Sine, 2.0));
+        let b = engine.add_lfo(LfoConfig::free(LfoShape::Triangle, 3.0));
+        engine.assign(a, ParamId::track_volume(1), 0.2);
+        engine.assign(b, ParamId::track_volume(1), 0.1);
+        engine.assign(b, ParamId::track_pan(1), 0.3);
+
+        let mut changes = Vec::with_capacity(4);
+        let storage = changes.as_ptr();
+        for block in 0..64u64 {
+            let start = block * 256;
+            let count = engine.process_block_into(start, 48000.0, 120.0, &[], &mut changes);
+            assert_eq!(count, 2);
+            assert_eq!(changes.as_ptr(), storage);
+
+            // Same result as the allocating path, two LFOs summed on volume
+            let summary = |c: &[AutomationChange]| {
+                let mut v: Vec<_> = c
+                    .iter()
+                    .map(|c| (c.param_id.param_name.clone(), c.sample_offset, c.value))
+                    .collect();
+                v.sort_by(|x, y| x.0.cmp(&y.0));
+                v
+            };
+            let expected = engine.process_block(start, 48000.0, 120.0, &[]);
+            assert_eq!(summary(&changes[..count]), summary(&expected));
+        }
+
+        // Dropping an assignment leaves its slot as spare storage
+        engine.unassign(b, &ParamId::track_pan(1));
+        let count = engine.process_block_into(0, 48000.0, 120.0, &[], &mut changes);
+        assert_eq!(count, 1);
+        assert_eq!(changes[0].param_id, ParamId::track_volume(1));
+        assert_eq!(changes.len(), 2);
+    }
+}
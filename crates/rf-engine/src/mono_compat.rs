@@ -0,0 +1,225 @@
+//! Master Mono-Compatibility Meter
+//!
+//! Estimates how much level the master loses when folded to mono, broadband
+//! and in the same 4 bands as `per_bus_band_energy` (bass / lowmid / highmid /
+//! treble), so phase problems show up before delivery rather than on a mono
+//! playback system.
+//!
+//! # Measure
+//!
+//! Per band, the fold loss compares the mean channel power with the power of
+//! the mono fold `(L + R) / 2`:
+//!
+//! ```text
+//! loss_db = 10 * log10( (L² + R²) / 2  /  ((L + R) / 2)² )
+//! ```
+//!
+//! | Signal                    | Loss          |
+//! |---------------------------|---------------|
+//! | Identical L/R (mono)      | 0 dB          |
+//! | Uncorrelated / hard-panned| ~3 dB         |
+//! | Polarity-inverted L/R     | `MAX_FOLD_LOSS_DB` |
+//!
+//! Stereo width is reported alongside as the side/mid RMS ratio (0 = mono,
+//! 1 = as much side as mid).
+//!
+//! # Real-time contract
+//!
+//! Zero allocation; the audio thread calls `process_block()` once per block on
+//! the final master output. Energies are smoothed with the same ~120 ms
+//! per-block release as the per-bus band analyzer.
+
+use rf_core::Sample;
+use rf_dsp::MonoProcessor;
+use rf_dsp::biquad::BiquadTDF2;
+
+use crate::per_bus_band_energy::{NUM_BANDS, band_filters};
+
+/// Reported loss for a fully cancelling fold (and the upper clamp)
+pub const MAX_FOLD_LOSS_DB: f64 = 60.0;
+
+/// Per-block smoothing coefficient (~120 ms at 512 samples / 48 kHz)
+const SMOOTHING: f64 = 0.92;
+
+/// Reported width for pure side (no mid) signal
+pub const MAX_WIDTH: f64 = 100.0;
+
+/// Channel energy below which the meter reads as silent (0 dB loss, 0 width)
+const SILENCE_ENERGY: f64 = 1e-12;
+
+/// Smoothed energies for one band (or broadband)
+#[derive(Debug, Clone, Copy, Default)]
+struct FoldEnergy {
+    /// Mean channel power `(L² + R²) / 2`
+    stereo: f64,
+    /// Mono fold power `((L + R) / 2)²`
+    mono: f64,
+}
+
+impl FoldEnergy {
+    #[inline]
+    fn accumulate(&mut self, l: f64, r: f64) {
+        let mid = (l + r) * 0.5;
+        self.stereo += (l * l + r * r) * 0.5;
+        self.mono += mid * mid;
+    }
+
+    #[inline]
+    fn smooth_toward(&mut self, block: FoldEnergy, inv_n: f64) {
+        self.stereo = SMOOTHING * self.stereo + (1.0 - SMOOTHING) * block.stereo * inv_n;
+        self.mono = SMOOTHING * self.mono + (1.0 - SMOOTHING) * block.mono * inv_n;
+    }
+
+    fn loss_db(&self) -> f64 {
+        if self.stereo < SILENCE_ENERGY {
+            return 0.0;
+        }
+        if self.mono <= self.stereo * 10.0_f64.powf(-MAX_FOLD_LOSS_DB / 10.0) {
+            return MAX_FOLD_LOSS_DB;
+        }
+        (10.0 * (self.stereo / self.mono).log10()).clamp(0.0, MAX_FOLD_LOSS_DB)
+    }
+
+    /// Side/mid RMS ratio (side power = stereo - mono), capped at `MAX_WIDTH`
+    fn width(&self) -> f64 {
+        if self.stereo < SILENCE_ENERGY {
+            return 0.0;
+        }
+        let side = (self.stereo - self.mono).max(0.0);
+        (side / self.mono.max(SILENCE_ENERGY)).sqrt().min(MAX_WIDTH)
+    }
+}
+
+/// Broadband + per-band mono-fold loss meter
+pub struct MonoCompatMeter {
+    filters_l: [BiquadTDF2; NUM_BANDS],
+    filters_r: [BiquadTDF2; NUM_BANDS],
+    broadband: FoldEnergy,
+    bands: [FoldEnergy; NUM_BANDS],
+    sample_rate: f64,
+}
+
+impl MonoCompatMeter {
+    pub fn new(sample_rate: f64) -> Self {
+        let sr = if sample_rate > 0.0 {
+            sample_rate
+        } else {
+            48_000.0
+        };
+        Self {
+            filters_l: band_filters(sr),
+            filters_r: band_filters(sr),
+            broadband: FoldEnergy::default(),
+            bands: [FoldEnergy::default(); NUM_BANDS],
+            sample_rate: sr,
+        }
+    }
+
+    /// Re-tune band filters for a new sample rate (clears state)
+    pub fn set_sample_rate(&mut self, sample_rate: f64) {
+        if sample_rate <= 0.0 || !sample_rate.is_finite() {
+            return;
+        }
+        if (sample_rate - self.sample_rate).abs() < 0.5 {
+            return;
+        }
+        *self = Self::new(sample_rate);
+    }
+
+    /// Clear smoothed energies and filter state
+    pub fn reset(&mut self) {
+        *self = Self::new(self.sample_rate);
+    }
+
+    /// Analyze one stereo block of the master output
+    pub fn process_block(&mut self, left: &[Sample], right: &[Sample]) {
+        let n = left.len().min(right.len());
+        if n == 0 {
+            return;
+        }
+
+        let mut broadband = FoldEnergy::default();
+        let mut bands = [FoldEnergy::default(); NUM_BANDS];
+        for (&l, &r) in left[..n].iter().zip(&right[..n]) {
+            broadband.accumulate(l, r);
+            for (b, band) in bands.iter_mut().enumerate() {
+                let yl = self.filters_l[b].process_sample(l);
+                let yr = self.filters_r[b].process_sample(r);
+                band.accumulate(yl, yr);
+            }
+        }
+
+        let inv_n = 1.0 / n as f64;
+        self.broadband.smooth_toward(broadband, inv_n);
+        for (smoothed, block) in self.bands.iter_mut().zip(bands) {
+            smoothed.smooth_toward(block, inv_n);
+        }
+    }
+
+    /// Broadband level lost folding to mono (dB, 0 = fully mono-compatible)
+    pub fn fold_loss_db(&self) -> f64 {
+        self.broadband.loss_db()
+    }
+
+    /// Per-band level lost folding to mono (dB), in band-table order
+    pub fn band_fold_loss_db(&self) -> [f64; NUM_BANDS] {
+        std::array::from_fn(|b| self.bands[b].loss_db())
+    }
+
+    /// Broadband stereo width (side/mid RMS ratio, 0 = mono)
+    pub fn stereo_width(&self) -> f64 {
+        self.broadband.width()
+    }
+}
+
+impl Default for MonoCompatMeter {
+    fn default() -> Self {
+        Self::new(48_000.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    fn sine(freq: f64, len: usize, sr: f64) -> Vec<f64> {
+        (0..len)
+            .map(|i| 0.5 * (2.0 * PI * freq * i as f64 / sr).sin())
+            .collect()
+    }
+
+    fn run(meter: &mut MonoCompatMeter, left: &[f64], right: &[f64]) {
+        for (l, r) in left.chunks(512).zip(right.chunks(512)) {
+            meter.process_block(l, r);
+        }
+    }
+
+    #[test]
+    fn test_out_of_phase_component_loses_level_in_mono() {
+        let sr = 48_000.0;
+        let len = sr as usize * 2;
+        let bass = sine(100.0, len, sr);
+        let treble = sine(6000.0, len, sr);
+
+        // Correlated: same bass + treble in both channels
+        let mixed: Vec<f64> = bass.iter().zip(&treble).map(|(b, t)| b + t).collect();
+        let mut meter = MonoCompatMeter::new(sr);
+        run(&mut meter, &mixed, &mixed);
+        assert!(meter.fold_loss_db() < 0.1, "{}", meter.fold_loss_db());
+        for loss in meter.band_fold_loss_db() {
+            assert!(loss < 0.1, "{:?}", meter.band_fold_loss_db());
+        }
+        assert!(meter.stereo_width() < 0.01);
+
+        // Treble polarity-inverted on the right channel: cancels in mono
+        let inverted: Vec<f64> = bass.iter().zip(&treble).map(|(b, t)| b - t).collect();
+        let mut meter = MonoCompatMeter::new(sr);
+        run(&mut meter, &mixed, &inverted);
+        let bands = meter.band_fold_loss_db();
+        assert!(meter.fold_loss_db() > 2.5, "{}", meter.fold_loss_db());
+        assert!(bands[3] > 20.0, "treble {:?}", bands);
+        assert!(bands[0] < 1.0, "bass {:?}", bands);
+        assert!(meter.stereo_width() > 0.5);
+    }
+}
@@ -0,0 +1,189 @@
+//! Output Soft-Start / Soft-Stop Ramp
+//!
+//! Linear gain ramp applied at the very end of the master output stage.
+//! When the engine starts producing audio, the first blocks may carry DC or
+//! full-level signal that thumps the monitors; the ramp brings output up
+//! from silence over a short configurable time, and back down on stop.
+//!
+//! # Lock-Free Design
+//! UI thread requests fade-in/out via atomics; the audio thread owns the
+//! current gain and advances it sample by sample.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+
+/// Default ramp time in milliseconds
+pub const DEFAULT_OUTPUT_RAMP_MS: f64 = 20.0;
+
+/// Maximum ramp time in milliseconds
+pub const MAX_OUTPUT_RAMP_MS: f64 = 500.0;
+
+/// Master output fade-in/fade-out ramp
+#[derive(Debug)]
+pub struct OutputRamp {
+    /// Ramp time in milliseconds (f64 bits)
+    ramp_ms: AtomicU64,
+    /// Ramp toward unity (true) or silence (false)
+    open: AtomicBool,
+    /// Restart from silence on the next block (set by `fade_in`)
+    restart: AtomicBool,
+    /// Current gain 0.0-1.0 (f64 bits, advanced by the audio thread)
+    gain: AtomicU64,
+}
+
+impl OutputRamp {
+    /// Create a ramp that fades in on the first processed block
+    pub fn new(ramp_ms: f64) -> Self {
+        Self {
+            ramp_ms: AtomicU64::new(ramp_ms.clamp(0.0, MAX_OUTPUT_RAMP_MS).to_bits()),
+            open: AtomicBool::new(true),
+            restart: AtomicBool::new(false),
+            gain: AtomicU64::new(0.0_f64.to_bits()),
+        }
+    }
+
+    /// Set ramp time in milliseconds (0 = no ramp)
+    pub fn set_ramp_ms(&self, ms: f64) {
+        self.ramp_ms.store(
+            ms.clamp(0.0, MAX_OUTPUT_RAMP_MS).to_bits(),
+            Ordering::Relaxed,
+        );
+    }
+
+    /// Get ramp time in milliseconds
+    pub fn ramp_ms(&self) -> f64 {
+        f64::from_bits(self.ramp_ms.load(Ordering::Relaxed))
+    }
+
+    /// Fade in from silence (engine start / unmute)
+    pub fn fade_in(&self) {
+        self.restart.store(true, Ordering::Relaxed);
+        self.open.store(true, Ordering::Release);
+    }
+
+    /// Fade out to silence from the current gain (engine stop / mute)
+    pub fn fade_out(&self) {
+        self.restart.store(false, Ordering::Relaxed);
+        self.open.store(false, Ordering::Release);
+    }
+
+    /// Current gain (0.0-1.0)
+    pub fn gain(&self) -> f64 {
+        f64::from_bits(self.gain.load(Ordering::Relaxed))
+    }
+
+    /// True once a fade-out has reached silence
+    pub fn is_silent(&self) -> bool {
+        !self.open.load(Ordering::Acquire) && self.gain() <= 0.0
+    }
+
+    /// Apply the ramp to a stereo block (audio thread)
+    pub fn process(&self, left: &mut [f64], right: &mut [f64], sample_rate: f64) {
+        let mut gain = if self.restart.swap(false, Ordering::Relaxed) {
+            0.0
+        } else {
+            self.gain()
+        };
+        let target = if self.open.load(Ordering::Acquire) {
+            1.0
+        } else {
+            0.0
+        };
+
+        // Settled: unity passes through, silence zeroes
+        if gain == target {
+            if target == 0.0 {
+                left.fill(0.0);
+                right.fill(0.0);
+            }
+            self.gain.store(gain.to_bits(), Ordering::Relaxed);
+            return;
+        }
+
+        let ramp_samples = self.ramp_ms() * 0.001 * sample_rate;
+        let step = if ramp_samples >= 1.0 {
+            1.0 / ramp_samples
+        } else {
+            1.0
+        };
+
+        for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+            *l *= gain;
+            *r *= gain;
+            gain = if target > gain {
+                (gain + step).min(target)
+            } else {
+                (gain - step).max(target)
+            };
+        }
+
+        // Snap accumulated rounding error so the settled path takes over
+        if (gain - target).abs() < 1e-9 {
+            gain = target;
+        }
+        self.gain.store(gain.to_bits(), Ordering::Relaxed);
+    }
+}
+
+impl Default for OutputRamp {
+    fn default() -> Self {
+        Self::new(DEFAULT_OUTPUT_RAMP_MS)
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════
+// TESTS
+// ═══════════════════════════════════════════════════════════════════════════
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fade_in_first_block() {
+        // 10ms @ 48kHz = 480 samples
+        let ramp = OutputRamp::new(10.0);
+        let mut left = vec![1.0; 1024];
+        let mut right = vec![-1.0; 1024];
+        ramp.process(&mut left, &mut right, 48000.0);
+
+        assert_eq!(left[0], 0.0);
+        for i in 0..480 {
+            let expected = i as f64 / 480.0;
+            assert!((left[i] - expected).abs() < 1e-9, "sample {}", i);
+            assert!((right[i] + expected).abs() < 1e-9, "sample {}", i);
+        }
+        assert!(left[480..].iter().all(|&s| (s - 1.0).abs() < 1e-9));
+        assert_eq!(ramp.gain(), 1.0);
+
+        // Later blocks pass through untouched
+        let mut left = vec![0.5; 64];
+        let mut right = vec![0.5; 64];
+        ramp.process(&mut left, &mut right, 48000.0);
+        assert!(left.iter().chain(&right).all(|&s| s == 0.5));
+    }
+
+    #[test]
+    fn test_fade_out_and_restart() {
+        let ramp = OutputRamp::new(1.0); // 48 samples
+        let mut left = vec![1.0; 256];
+        let mut right = vec![1.0; 256];
+        ramp.process(&mut left, &mut right, 48000.0);
+
+        ramp.fade_out();
+        let mut left = vec![1.0; 256];
+        let mut right = vec![1.0; 256];
+        ramp.process(&mut left, &mut right, 48000.0);
+        assert_eq!(left[0], 1.0);
+        assert!(left.windows(2).all(|w| w[1] <= w[0]));
+        assert!(left[48..].iter().all(|&s| s == 0.0));
+        assert!(ramp.is_silent());
+
+        // Fade-in always restarts from silence
+        ramp.fade_in();
+        let mut left = vec![1.0; 64];
+        let mut right = vec![1.0; 64];
+        ramp.process(&mut left, &mut right, 48000.0);
+        assert_eq!(left[0], 0.0);
+        assert_eq!(left[63], 1.0);
+    }
+}
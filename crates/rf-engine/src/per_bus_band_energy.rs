@@ -45,6 +45,20 @@ pub const NUM_BANDS: usize = 4;
 /// Total atomic slots (matches `SharedMeterBuffer::bus_band_rms.len()`).
 pub const TOTAL_SLOTS: usize = NUM_BUSES * NUM_BANDS;
 
+/// One filter per band, in band-table order. Shared with the master
+/// mono-compatibility meter so both report the same bands.
+pub(crate) fn band_filters(sr: f64) -> [BiquadTDF2; NUM_BANDS] {
+    let mut bass = BiquadTDF2::new(sr);
+    bass.set_lowpass(200.0, 0.707);
+    let mut lowmid = BiquadTDF2::new(sr);
+    lowmid.set_bandpass(450.0, 1.0);
+    let mut highmid = BiquadTDF2::new(sr);
+    highmid.set_bandpass(1800.0, 1.0);
+    let mut treble = BiquadTDF2::new(sr);
+    treble.set_highpass(3500.0, 0.707);
+    [bass, lowmid, highmid, treble]
+}
+
 /// Per-channel-per-band filter state for one bus.
 #[derive(Debug)]
 struct BusFilters {
@@ -66,15 +80,7 @@ impl BusFilters {
     }
 
     fn build_filters(sr: f64) -> [BiquadTDF2; NUM_BANDS] {
-        let mut bass = BiquadTDF2::new(sr);
-        bass.set_lowpass(200.0, 0.707);
-        let mut lowmid = BiquadTDF2::new(sr);
-        lowmid.set_bandpass(450.0, 1.0);
-        let mut highmid = BiquadTDF2::new(sr);
-        highmid.set_bandpass(1800.0, 1.0);
-        let mut treble = BiquadTDF2::new(sr);
-        treble.set_highpass(3500.0, 0.707);
-        [bass, lowmid, highmid, treble]
+        band_filters(sr)
     }
 
     fn retune(&mut self, sr: f64) {
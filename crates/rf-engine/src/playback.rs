@@ -11,7 +11,7 @@
 //! P0.5/P0.6 FIX: Background eviction thread to avoid RT allocations
 
 use std::cell::{RefCell, UnsafeCell};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU8, AtomicU32, AtomicU64, AtomicUsize, Ordering};
@@ -23,6 +23,7 @@ use dashmap::DashMap;
 use parking_lot::{Mutex, RwLock};
 use rayon::prelude::*;
 
+use crate::output_ramp::OutputRamp;
 use crate::sinc_table::{self, ResampleMode, SincTable};
 
 /// Normalise a parameter display name for fuzzy matching: lowercase,
@@ -137,23 +138,34 @@ thread_local! {
     /// Heap-allocated to support any block size without stack overflow or truncation
     static BUS_ACCUM_L: RefCell<Vec<Vec<f64>>> = const { RefCell::new(Vec::new()) };
     static BUS_ACCUM_R: RefCell<Vec<Vec<f64>>> = const { RefCell::new(Vec::new()) };
+    /// Thread-local LFO modulation changes, reused block to block
+    static MODULATION_CHANGES: RefCell<Vec<AutomationChange>> =
+        RefCell::new(Vec::with_capacity(MODULATION_CHANGES_CAPACITY));
 }
 
 use crate::audio_import::{AudioImporter, ImportedAudio};
-use crate::automation::{AutomationEngine, ParamId};
+use crate::automation::{AutomationChange, AutomationEngine, ParamId};
+use crate::modulation::ModulationEngine;
+use crate::mono_compat::MonoCompatMeter;
+use crate::per_bus_band_energy::NUM_BANDS as MONO_COMPAT_BANDS;
 use crate::control_room::{ControlRoom, SoloMode};
-use crate::groups::{GroupManager, VcaId};
+use crate::groups::{FolderBus, FolderRouting, GroupId, GroupManager, VcaId};
 use crate::input_bus::{InputBusManager, MonitorMode};
 use crate::insert_chain::{InsertChain, InsertParamChange};
+use crate::midi_learn::MidiLearnTable;
 use crate::recording_manager::RecordingManager;
 use crate::routing::ChannelId;
 #[cfg(feature = "unified_routing")]
 use crate::routing::{ChannelKind, OutputDestination, RoutingCommandSender, RoutingGraphRT};
 use crate::routing_pdc::{GraphNode, PDCCalculator, PDCResult, RoutingGraph};
+use crate::send_return::SendTapPoint;
+use crate::sidechain::{SidechainId, SidechainRouter, SidechainSourceKind};
 use crate::track_manager::{
-    Clip, ClipFxChain, ClipFxSlot, ClipFxType, Crossfade, OutputBus, Track, TrackId, TrackManager,
+    Clip, ClipFxChain, ClipFxSlot, ClipFxType, Crossfade, CrossfadeCurve, MAX_INPUT_TRIM_DB,
+    OutputBus, Track, TrackId, TrackManager,
 };
 
+use rf_core::buffer;
 use rf_dsp::analysis::FftAnalyzer;
 use rf_dsp::delay_compensation::DelayCompensationManager;
 use rf_dsp::metering::{LufsMeter, TruePeakMeter};
@@ -1047,6 +1059,19 @@ fn pick_one_shot_slot(voices: &[OneShotVoice]) -> Option<usize> {
 /// effectively impossible in any realistic slot scenario.
 const MAX_ONE_SHOT_VOICES: usize = 256;
 
+/// Folder bus capacity in frames (matches the audio-thread scratch buffers)
+const FOLDER_BUS_MAX_FRAMES: usize = 8192;
+
+/// Modulated parameters the audio thread can track before its change list grows
+const MODULATION_CHANGES_CAPACITY: usize = 64;
+
+/// Sidechain router capacity in frames (bus key taps and PDC outputs)
+const SIDECHAIN_MAX_FRAMES: usize = 8192;
+
+/// Sidechain processor ID marker for bus insert slots (same marker as the
+/// bus track IDs in the insert param ring buffer)
+const BUS_INSERT_PROCESSOR_BASE: u32 = 0xFFFF_0000;
+
 /// One-shot voice for event-triggered audio playback
 /// Routes directly to a bus (bypasses track system)
 #[derive(Debug)]
@@ -1146,6 +1171,48 @@ pub struct OneShotVoice {
     hpf_active: bool,
     /// Whether LPF is engaged (cutoff < 20000 Hz)
     lpf_active: bool,
+    /// Scheduled curved gain ramp (stage cue crossfades)
+    ramp: Option<VoiceRamp>,
+}
+
+/// Curved gain ramp scheduled on a one-shot voice
+///
+/// A fade-in ramp holds the voice silent at its start position until the
+/// ramp begins; a fade-out ramp keeps it at full gain until then and stops
+/// the voice once the ramp reaches zero.
+#[derive(Debug, Clone)]
+struct VoiceRamp {
+    /// Samples before the ramp starts
+    delay_samples: u64,
+    /// Ramp length in samples (0 = hard cut)
+    ramp_samples: u64,
+    /// Elapsed ramp samples
+    elapsed_samples: u64,
+    /// Fade-in curve; fade-outs use its mirror image
+    curve: CrossfadeCurve,
+    /// Ramp up (incoming voice) or down (outgoing voice)
+    fade_in: bool,
+}
+
+impl VoiceRamp {
+    /// Gain for the next sample (None once a fade-out has finished)
+    #[inline]
+    fn next_gain(&mut self) -> Option<f32> {
+        if self.delay_samples > 0 {
+            self.delay_samples -= 1;
+            return Some(if self.fade_in { 0.0 } else { 1.0 });
+        }
+        if self.elapsed_samples >= self.ramp_samples {
+            return self.fade_in.then_some(1.0);
+        }
+        self.elapsed_samples += 1;
+        let t = self.elapsed_samples as f32 / self.ramp_samples as f32;
+        Some(if self.fade_in {
+            self.curve.evaluate(t)
+        } else {
+            self.curve.evaluate_fade_out(t)
+        })
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1285,6 +1352,7 @@ impl OneShotVoice {
             lpf_r: rf_dsp::biquad::BiquadTDF2::new(48000.0),
             hpf_active: false,
             lpf_active: false,
+            ramp: None,
         }
     }
 
@@ -1333,6 +1401,7 @@ impl OneShotVoice {
         self.send_gain = 0.0;
         self.hpf_active = false;
         self.lpf_active = false;
+        self.ramp = None;
         self.hpf_l.reset();
         self.hpf_r.reset();
         self.lpf_l.reset();
@@ -1426,6 +1495,7 @@ impl OneShotVoice {
         self.phase_invert = false;
         self.meter_peak_l = 0.0;
         self.meter_peak_r = 0.0;
+        self.ramp = None;
     }
 
     fn deactivate(&mut self) {
@@ -1452,6 +1522,16 @@ impl OneShotVoice {
         }
     }
 
+    /// Schedule a curved gain ramp (replaces the anti-click fade-in when ramping in)
+    fn start_ramp(&mut self, ramp: VoiceRamp) {
+        if ramp.fade_in && ramp.ramp_samples > 0 {
+            self.fade_in_samples_total = 0;
+            self.fade_in_samples_elapsed = 0;
+            self.fade_gain = 1.0;
+        }
+        self.ramp = Some(ramp);
+    }
+
     /// Fill buffer with audio, returns true if still playing
     /// Applies equal-power panning for spatial positioning
     /// P0.2: Supports seamless looping for REEL_SPIN and similar events
@@ -1462,6 +1542,19 @@ impl OneShotVoice {
             return false;
         }
 
+        // Scheduled fade-in: stay silent at the start position until the ramp begins
+        if let Some(ramp) = self.ramp.as_mut()
+            && ramp.fade_in
+            && ramp.delay_samples > 0
+        {
+            let wait = (ramp.delay_samples as usize).min(left.len());
+            ramp.delay_samples -= wait as u64;
+            if wait == left.len() {
+                return true;
+            }
+            return self.fill_buffer(&mut left[wait..], &mut right[wait..]);
+        }
+
         let frames_needed = left.len();
         let channels_src = self.audio.channels as usize;
         let total_frames = self.audio.samples.len() / channels_src.max(1);
@@ -1588,10 +1681,20 @@ impl OneShotVoice {
                 break;
             }
 
+            // Scheduled crossfade ramp; a finished fade-out stops the voice
+            let ramp_gain = match self.ramp.as_mut().map(VoiceRamp::next_gain) {
+                Some(Some(gain)) => gain,
+                Some(None) => {
+                    self.active = false;
+                    return false;
+                }
+                None => 1.0,
+            };
+
             let gain = if self.muted {
                 0.0
             } else {
-                self.volume * self.fade_gain * self.input_gain
+                self.volume * self.fade_gain * self.input_gain * ramp_gain
             };
 
             // Blackman-Harris windowed sinc interpolation for SRC + pitch shift
@@ -1775,6 +1878,26 @@ pub enum OneShotCommand {
     SetLpf { id: u64, cutoff_hz: f32 },
     /// Phase 6: per-voice pre-fader send level (0.0..1.0)
     SetSend { id: u64, level: f32 },
+    /// Play a voice that starts after a delay and ramps in along a curve
+    PlayScheduled {
+        id: u64,
+        audio: Arc<ImportedAudio>,
+        volume: f32,
+        pan: f32,
+        bus: OutputBus,
+        source: PlaybackSource,
+        looping: bool,
+        delay_samples: u64,
+        fade_samples: u64,
+        curve: CrossfadeCurve,
+    },
+    /// Ramp a voice out along a curve after a delay, then stop it
+    CrossfadeOut {
+        id: u64,
+        delay_samples: u64,
+        fade_samples: u64,
+        curve: CrossfadeCurve,
+    },
     /// Play a voice with 3D spatial positioning (HRTF binaural rendering)
     PlaySpatial {
         id: u64,
@@ -1818,11 +1941,11 @@ impl BusBuffers {
 
     pub fn clear(&mut self) {
         for (l, r) in &mut self.buffers {
-            l.fill(0.0);
-            r.fill(0.0);
+            buffer::clear(l);
+            buffer::clear(r);
         }
-        self.master_l.fill(0.0);
-        self.master_r.fill(0.0);
+        buffer::clear(&mut self.master_l);
+        buffer::clear(&mut self.master_r);
     }
 
     /// P2.2: SIMD-optimized bus mixing (4x speedup with AVX2)
@@ -1839,9 +1962,9 @@ impl BusBuffers {
         if idx < self.buffers.len() {
             let (bus_l, bus_r) = &mut self.buffers[idx];
             let len = left.len().min(bus_l.len());
-            // P2.2: Use SIMD mix_add (dest += src * 1.0)
-            rf_dsp::simd::mix_add(&mut bus_l[..len], &left[..len], 1.0);
-            rf_dsp::simd::mix_add(&mut bus_r[..len], &right[..len], 1.0);
+            // P2.2: SIMD summation (dest += src)
+            buffer::add(&mut bus_l[..len], &left[..len]);
+            buffer::add(&mut bus_r[..len], &right[..len]);
         }
     }
 
@@ -1884,9 +2007,9 @@ impl BusBuffers {
     /// P2.2: SIMD-optimized master summation (4x speedup with AVX2)
     pub fn sum_to_master(&mut self) {
         for (bus_l, bus_r) in &self.buffers {
-            // P2.2: Use SIMD mix_add for vectorized summation
-            rf_dsp::simd::mix_add(&mut self.master_l, bus_l, 1.0);
-            rf_dsp::simd::mix_add(&mut self.master_r, bus_r, 1.0);
+            // P2.2: SIMD summation
+            buffer::add(&mut self.master_l, bus_l);
+            buffer::add(&mut self.master_r, bus_r);
         }
     }
 
@@ -2089,8 +2212,18 @@ pub struct PlaybackEngine {
     pub correlation: AtomicU64,
     /// Stereo balance (-1.0 left to 1.0 right)
     pub balance: AtomicU64,
+    /// Mono-compatibility meter (master output)
+    mono_compat_meter: RwLock<MonoCompatMeter>,
+    /// Broadband level lost folding master to mono (dB)
+    pub mono_fold_loss_db: AtomicU64,
+    /// Per-band mono-fold loss (dB): bass, lowmid, highmid, treble
+    pub mono_fold_band_loss_db: [AtomicU64; MONO_COMPAT_BANDS],
+    /// Master stereo width (side/mid RMS ratio, 0 = mono)
+    pub stereo_width: AtomicU64,
     /// Automation engine
     automation: Option<Arc<AutomationEngine>>,
+    /// LFO modulation engine (combined with automation each block)
+    modulation: Option<Arc<ModulationEngine>>,
     /// Parameter smoother manager for zipper-free automation
     param_smoother: Arc<crate::param_smoother::ParamSmootherManager>,
     /// Group/VCA manager (RwLock for shared mutation with bridge)
@@ -2121,6 +2254,8 @@ pub struct PlaybackEngine {
     /// Consumer is used by audio thread (at start of each block)
     insert_param_tx: parking_lot::Mutex<rtrb::Producer<InsertParamChange>>,
     insert_param_rx: parking_lot::Mutex<rtrb::Consumer<InsertParamChange>>,
+    /// MIDI learn CC mappings (MIDI thread writes targets, audio thread applies)
+    midi_learn: MidiLearnTable,
     /// Per-track stereo meters (track_id -> TrackMeter with L/R peaks, RMS, correlation)
     track_meters: RwLock<HashMap<u64, TrackMeter>>,
     /// Per-track LUFS meters (separate from TrackMeter to keep LufsMeter state)
@@ -2188,6 +2323,8 @@ pub struct PlaybackEngine {
     /// Created on UI thread when user loads an instrument plugin on an Instrument track.
     /// Audio thread calls process() with MidiBuffer to generate audio.
     instrument_plugins: RwLock<HashMap<u64, Arc<parking_lot::RwLock<Box<dyn rf_plugin::PluginInstance>>>>>,
+    /// Saved instrument states waiting for their plugin to be loaded (track_id -> state)
+    pending_instrument_states: RwLock<HashMap<u64, Vec<u8>>>,
     /// Pre-allocated MidiBuffer for instrument MIDI input (avoid audio-thread allocations)
     instrument_midi_buffer: RwLock<rf_core::MidiBuffer>,
     /// Pre-allocated MidiBuffer for instrument MIDI output (avoid audio-thread allocations)
@@ -2269,6 +2406,8 @@ pub struct PlaybackEngine {
     master_delay_write_pos: AtomicUsize,
     /// Master soft-clipper enable (tanh saturation at 0dBFS — prevents digital clipping)
     master_soft_clip_enabled: AtomicBool,
+    /// Soft-start/stop gain ramp (last stage before the device)
+    output_ramp: OutputRamp,
     /// DC offset filter state (1-pole high-pass at ~5Hz, per-channel)
     /// Stored as AtomicU64 (f64 bits) for lock-free audio thread access
     dc_filter_state_l: AtomicU64,
@@ -2292,6 +2431,15 @@ pub struct PlaybackEngine {
     /// Key = track_id as i64, Value = (left_buffer, right_buffer).
     /// Pre-allocated at track creation; clear()/copy each block, no audio-thread allocation.
     sidechain_taps: RwLock<HashMap<i64, (Vec<f64>, Vec<f64>)>>,
+    /// Sidechain routes keyed from tracks or buses (key filters, PDC)
+    sidechain_router: RwLock<SidechainRouter>,
+
+    // === FOLDER BUSES ===
+    /// Per-folder summing buffers (key = folder track ID).
+    /// Children of a folder mix here instead of their output bus; the folder's
+    /// insert chain and fader are applied to the sum after the track loop.
+    /// Allocated by sync_folder_buses() at FOLDER_BUS_MAX_FRAMES, never on the audio thread.
+    folder_buses: RwLock<HashMap<u64, FolderBus>>,
 }
 
 /// Soft-clip a single sample with smooth knee transition.
@@ -2351,7 +2499,12 @@ impl PlaybackEngine {
             true_peak_r: AtomicU64::new((-70.0_f64).to_bits()),
             correlation: AtomicU64::new(1.0_f64.to_bits()),
             balance: AtomicU64::new(0.0_f64.to_bits()),
+            mono_compat_meter: RwLock::new(MonoCompatMeter::new(sample_rate as f64)),
+            mono_fold_loss_db: AtomicU64::new(0.0_f64.to_bits()),
+            mono_fold_band_loss_db: std::array::from_fn(|_| AtomicU64::new(0.0_f64.to_bits())),
+            stereo_width: AtomicU64::new(0.0_f64.to_bits()),
             automation: None,
+            modulation: None,
             param_smoother: Arc::new(crate::param_smoother::ParamSmootherManager::new(
                 sample_rate as f64,
             )),
@@ -2370,6 +2523,7 @@ impl PlaybackEngine {
             // Lock-free ring buffer for insert params (4096 = ~85ms at 60fps UI updates)
             insert_param_tx: parking_lot::Mutex::new(insert_param_tx),
             insert_param_rx: parking_lot::Mutex::new(insert_param_rx),
+            midi_learn: MidiLearnTable::new(),
             track_meters: RwLock::new(HashMap::new()),
             track_lufs_meters: RwLock::new(HashMap::new()),
             // 8192-point FFT for better bass frequency resolution
@@ -2404,6 +2558,7 @@ impl PlaybackEngine {
             recording_manager: Arc::new(RecordingManager::new(sample_rate)),
             // Instrument plugin instances per track
             instrument_plugins: RwLock::new(HashMap::new()),
+            pending_instrument_states: RwLock::new(HashMap::new()),
             instrument_midi_buffer: RwLock::new(rf_core::MidiBuffer::new()),
             instrument_midi_out: RwLock::new(rf_core::MidiBuffer::new()),
             instrument_audio_in: RwLock::new(rf_plugin::AudioBuffer::new(2, 4096)),
@@ -2445,6 +2600,7 @@ impl PlaybackEngine {
             master_delay_buf_r: RwLock::new(vec![0.0_f64; 8192]),
             master_delay_write_pos: AtomicUsize::new(0),
             master_soft_clip_enabled: AtomicBool::new(true), // ON by default — safety net
+            output_ramp: OutputRamp::default(),
             dc_filter_state_l: AtomicU64::new(0.0_f64.to_bits()),
             dc_filter_state_r: AtomicU64::new(0.0_f64.to_bits()),
             dc_filter_alpha: AtomicU64::new(
@@ -2456,6 +2612,12 @@ impl PlaybackEngine {
             hook_graph_fb_rx: parking_lot::Mutex::new(hg_fb_rx),
             // Sidechain tap buffers: pre-allocated per-track for zero audio-thread allocation
             sidechain_taps: RwLock::new(HashMap::new()),
+            sidechain_router: RwLock::new({
+                let mut router = SidechainRouter::new(SIDECHAIN_MAX_FRAMES);
+                router.set_sample_rate(sample_rate as f64);
+                router
+            }),
+            folder_buses: RwLock::new(HashMap::new()),
         }
     }
 
@@ -2557,9 +2719,44 @@ impl PlaybackEngine {
         self.automation = Some(automation);
     }
 
+    /// Attach LFO modulation engine
+    pub fn set_modulation(&mut self, modulation: Arc<ModulationEngine>) {
+        self.modulation = Some(modulation);
+    }
+
+    /// Get LFO modulation engine
+    pub fn modulation(&self) -> Option<&Arc<ModulationEngine>> {
+        self.modulation.as_ref()
+    }
+
+    /// Sidechain router for this engine's bus/track key routes
+    pub fn sidechain_router(&self) -> &RwLock<SidechainRouter> {
+        &self.sidechain_router
+    }
+
     /// Attach group/VCA manager (shared with bridge)
     pub fn set_group_manager(&mut self, manager: Arc<RwLock<GroupManager>>) {
         self.group_manager = Some(manager);
+        self.sync_folder_buses();
+    }
+
+    /// Allocate/free folder summing buses to match the group manager's folders
+    ///
+    /// Call after folders are created or deleted. Children of a folder without
+    /// a bus keep routing to their own output bus.
+    pub fn sync_folder_buses(&self) {
+        let Some(manager) = &self.group_manager else {
+            return;
+        };
+        let folder_ids: Vec<u64> = manager.read().folders.keys().copied().collect();
+
+        let mut buses = self.folder_buses.write();
+        buses.retain(|id, _| folder_ids.contains(id));
+        for id in folder_ids {
+            buses
+                .entry(id)
+                .or_insert_with(|| FolderBus::new(FOLDER_BUS_MAX_FRAMES));
+        }
     }
 
     /// Get automation engine
@@ -2581,7 +2778,7 @@ impl PlaybackEngine {
         }
     }
 
-    /// Get combined VCA gain for track
+    /// Get combined VCA gain for track (linear)
     /// Uses the GroupManager's get_vca_contribution which handles nested VCAs
     fn get_vca_gain(&self, track_id: u64) -> f64 {
         let manager = match &self.group_manager {
@@ -2592,11 +2789,20 @@ impl PlaybackEngine {
         // GroupManager uses u64 track_id directly (groups::TrackId = u64)
         // Use try_read to avoid blocking audio thread
         match manager.try_read() {
-            Some(gm) => gm.get_vca_contribution(track_id),
+            Some(gm) => gm.vca_gain(track_id),
             None => 1.0, // Return unity gain if lock is contended
         }
     }
 
+    /// Get folder bus routing for a child track (None = not in a folder)
+    /// Uses try_read to avoid blocking audio thread
+    fn get_folder_routing(&self, track_id: u64) -> Option<FolderRouting> {
+        self.group_manager
+            .as_ref()
+            .and_then(|manager| manager.try_read())
+            .and_then(|gm| gm.folder_routing(track_id))
+    }
+
     /// Check if track is muted by any VCA
     /// Uses try_read to avoid blocking audio thread
     fn is_vca_muted(&self, track_id: u64) -> bool {
@@ -2834,12 +3040,62 @@ impl PlaybackEngine {
             return false;
         }
 
+        // Restore state saved with the project, if any
+        if let Some(state) = self.pending_instrument_states.write().remove(&track_id)
+            && let Err(e) = plugin.set_state(&state)
+        {
+            log::warn!(
+                "Failed to restore instrument state on track {}: {}",
+                track_id,
+                e
+            );
+        }
+
         let plugin_arc = Arc::new(parking_lot::RwLock::new(plugin));
         self.instrument_plugins.write().insert(track_id, plugin_arc);
         log::info!("Loaded instrument plugin on track {}", track_id);
         true
     }
 
+    /// Instrument plugin state for saving (a restored state not yet applied
+    /// to a loaded plugin is returned as-is)
+    pub fn instrument_plugin_state(&self, track_id: u64) -> Option<Vec<u8>> {
+        if let Some(plugin) = self.instrument_plugins.read().get(&track_id) {
+            return match plugin.read().get_state() {
+                Ok(state) => Some(state),
+                Err(e) => {
+                    log::warn!(
+                        "Failed to read instrument state on track {}: {}",
+                        track_id,
+                        e
+                    );
+                    None
+                }
+            };
+        }
+        self.pending_instrument_states
+            .read()
+            .get(&track_id)
+            .cloned()
+    }
+
+    /// Restore a saved instrument state; applied on load if no plugin is loaded yet
+    pub fn restore_instrument_plugin_state(&self, track_id: u64, state: Vec<u8>) {
+        if let Some(plugin) = self.instrument_plugins.read().get(&track_id) {
+            if let Err(e) = plugin.write().set_state(&state) {
+                log::warn!(
+                    "Failed to restore instrument state on track {}: {}",
+                    track_id,
+                    e
+                );
+            }
+            return;
+        }
+        self.pending_instrument_states
+            .write()
+            .insert(track_id, state);
+    }
+
     /// Unload instrument plugin from track.
     /// Deactivates the plugin before removing it.
     pub fn unload_instrument_plugin(&self, track_id: u64) {
@@ -3164,6 +3420,8 @@ impl PlaybackEngine {
         }
         let mut bus_inserts = self.bus_inserts.write();
         let result = bus_inserts[bus_id].load(slot_index, processor);
+        drop(bus_inserts);
+        self.sync_sidechain_latencies();
         log::info!(
             "[BusInsert] Loaded processor into bus {} slot {} -> {}",
             bus_id,
@@ -3182,8 +3440,9 @@ impl PlaybackEngine {
         if bus_id >= 6 {
             return None;
         }
-        let mut bus_inserts = self.bus_inserts.write();
-        bus_inserts[bus_id].unload(slot_index)
+        let processor = self.bus_inserts.write()[bus_id].unload(slot_index);
+        self.sync_sidechain_latencies();
+        processor
     }
 
     /// Set bypass for bus insert slot
@@ -3194,6 +3453,7 @@ impl PlaybackEngine {
         if let Some(slot) = self.bus_inserts.read()[bus_id].slot(slot_index) {
             slot.set_bypass(bypass);
         }
+        self.sync_sidechain_latencies();
     }
 
     /// Set wet/dry mix for bus insert slot
@@ -3269,6 +3529,130 @@ impl PlaybackEngine {
         self.bus_inserts.read()[bus_id].total_latency()
     }
 
+    // ═══════════════════════════════════════════════════════════════════════
+    // BUS SIDECHAIN KEYS
+    // ═══════════════════════════════════════════════════════════════════════
+    //
+    // Buses publish pre-fader, post-fader and post-pan taps to the sidechain
+    // router; routes whose destination is a bus insert slot feed that slot's
+    // sidechain input before the bus chain runs.
+
+    /// Sidechain processor ID of a bus insert slot (destination of a key route)
+    pub fn bus_insert_processor_id(bus_id: usize, slot_index: usize) -> u32 {
+        BUS_INSERT_PROCESSOR_BASE | ((bus_id as u32) << 8) | (slot_index as u32 & 0xFF)
+    }
+
+    /// Bus and slot addressed by a bus insert processor ID
+    fn bus_insert_slot(processor_id: u32) -> Option<(usize, usize)> {
+        if processor_id & 0xFFFF_0000 != BUS_INSERT_PROCESSOR_BASE {
+            return None;
+        }
+        let bus_id = ((processor_id >> 8) & 0xFF) as usize;
+        let slot_index = (processor_id & 0xFF) as usize;
+        (bus_id < 6 && slot_index < crate::insert_chain::MAX_INSERT_SLOTS)
+            .then_some((bus_id, slot_index))
+    }
+
+    /// Key a processor from a bus at `tap_point`
+    ///
+    /// `dest_processor_id` from `bus_insert_processor_id` keys that bus insert
+    /// slot. Returns the route ID.
+    pub fn add_sidechain_bus_route(
+        &self,
+        key_bus: usize,
+        tap_point: SendTapPoint,
+        dest_processor_id: u32,
+    ) -> SidechainId {
+        let route_id = self.sidechain_router.write().add_bus_route(
+            key_bus as u32,
+            dest_processor_id,
+            tap_point,
+        );
+        self.sync_sidechain_latencies();
+        route_id
+    }
+
+    /// Report bus tap and keyed slot latencies to the sidechain router (PDC)
+    ///
+    /// Bus taps sit after the pre-fader inserts; a keyed slot's input lags by
+    /// the slots ahead of it. Called whenever bus inserts or key routes change.
+    pub fn sync_sidechain_latencies(&self) {
+        use crate::insert_chain::MAX_INSERT_SLOTS;
+
+        let bus_inserts = self.bus_inserts.read();
+        let mut router = self.sidechain_router.write();
+        for (bus_id, chain) in bus_inserts.iter().enumerate() {
+            router.set_source_latency(
+                SidechainSourceKind::Bus,
+                bus_id as u32,
+                chain.latency_before(MAX_INSERT_SLOTS / 2),
+            );
+        }
+        let destinations: Vec<u32> = router
+            .all_routes()
+            .iter()
+            .map(|r| r.dest_processor_id)
+            .collect();
+        for processor_id in destinations {
+            if let Some((bus_id, slot_index)) = Self::bus_insert_slot(processor_id) {
+                router.set_processor_latency(
+                    processor_id,
+                    bus_inserts[bus_id].latency_before(slot_index),
+                );
+            }
+        }
+    }
+
+    /// Bus processing order for one block
+    ///
+    /// Buses routed to other buses go first so their output reaches the parent
+    /// bus; within each group, buses keying a sidechain go first so keyed
+    /// buses read this block's key.
+    fn bus_process_order(
+        bus_states: &[BusState; 6],
+        router: Option<&SidechainRouter>,
+    ) -> [usize; 6] {
+        let is_key = |bus_idx: usize| router.is_some_and(|r| r.is_bus_key_source(bus_idx as u32));
+        let mut order = [0; 6];
+        let mut count = 0;
+        for to_bus in [true, false] {
+            for keys in [true, false] {
+                for bus_idx in 0..6 {
+                    let routes_to_bus =
+                        matches!(bus_states[bus_idx].output_dest, BusOutputDest::Bus(_));
+                    if routes_to_bus == to_bus && is_key(bus_idx) == keys {
+                        order[count] = bus_idx;
+                        count += 1;
+                    }
+                }
+            }
+        }
+        order
+    }
+
+    /// Feed routed sidechain keys to a bus's keyed insert slots
+    fn feed_bus_sidechain_keys(
+        router: &mut SidechainRouter,
+        chain: &mut InsertChain,
+        bus_idx: usize,
+        frames: usize,
+    ) {
+        for route_idx in 0..router.all_routes().len() {
+            let route = &router.all_routes()[route_idx];
+            let Some((dest_bus, slot_index)) = Self::bus_insert_slot(route.dest_processor_id)
+            else {
+                continue;
+            };
+            if dest_bus != bus_idx {
+                continue;
+            }
+            let route_id = route.id;
+            if let Some((key_l, key_r)) = router.route_key(route_id, frames) {
+                chain.set_slot_sidechain_input(slot_index, key_l, key_r);
+            }
+        }
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // DELAY COMPENSATION
     // ═══════════════════════════════════════════════════════════════════════
@@ -3314,6 +3698,27 @@ impl PlaybackEngine {
         self.delay_comp.read().total_latency()
     }
 
+    /// Latency of a bus output as captured by loopback input buses
+    ///
+    /// Tracks are aligned to the graph PDC maximum; the master chain adds its
+    /// own insert latency on top. Non-blocking (audio thread).
+    fn loopback_latency(&self, source: OutputBus) -> u64 {
+        let graph = self
+            .graph_pdc_result
+            .try_read()
+            .and_then(|result| result.as_ref().map(|r| r.max_latency))
+            .unwrap_or(0);
+        let master = if source == OutputBus::Master {
+            self.master_insert
+                .try_read()
+                .map(|chain| chain.total_latency() as u64)
+                .unwrap_or(0)
+        } else {
+            0
+        };
+        graph + master
+    }
+
     /// Apply delay compensation to track buffers
     pub fn apply_track_delay_compensation(
         &self,
@@ -3664,6 +4069,56 @@ impl PlaybackEngine {
         }
     }
 
+    /// MIDI learn mapping table
+    pub fn midi_learn(&self) -> &MidiLearnTable {
+        &self.midi_learn
+    }
+
+    /// Arm MIDI learn on an insert parameter
+    ///
+    /// The learned mapping spans the parameter's plain range from its
+    /// descriptor (0..1 if the processor has none), matching the units
+    /// `set_slot_param` expects.
+    pub fn start_midi_learn(&self, track_id: u64, slot_index: usize, param_index: usize) {
+        let info = if track_id == 0 {
+            self.master_insert
+                .read()
+                .slot_param_info(slot_index, param_index)
+        } else {
+            self.insert_chains
+                .read()
+                .get(&track_id)
+                .and_then(|chain| chain.slot_param_info(slot_index, param_index))
+        };
+        let (min, max) = info.map_or((0.0, 1.0), |info| (info.range.min, info.range.max));
+        self.midi_learn
+            .start_learn(track_id, slot_index, param_index, min, max);
+    }
+
+    /// Apply smoothed MIDI CC mapping values to insert params (AUDIO THREAD)
+    ///
+    /// Same lock-free rules as `consume_insert_param_changes`: try_write only,
+    /// skip the block on contention (smoothing simply resumes next block).
+    fn apply_midi_learn(&self, frames: usize) {
+        if self.midi_learn.is_empty() {
+            return;
+        }
+        let Some(mut chains) = self.insert_chains.try_write() else {
+            return;
+        };
+        let sample_rate = self.sample_rate() as f64;
+        self.midi_learn
+            .process_block(frames, sample_rate, |track_id, slot_index, param_index, value| {
+                if track_id == 0 {
+                    if let Some(mut master) = self.master_insert.try_write() {
+                        master.set_slot_param(slot_index, param_index, value);
+                    }
+                } else if let Some(chain) = chains.get_mut(&track_id) {
+                    chain.set_slot_param(slot_index, param_index, value);
+                }
+            });
+    }
+
     /// Get parameter from track insert processor
     pub fn get_track_insert_param(
         &self,
@@ -3856,6 +4311,19 @@ impl PlaybackEngine {
             .into()
     }
 
+    /// Read a track's insert chain (track 0 = master). Returns None if not found.
+    pub fn with_insert_chain<R, F: FnOnce(&InsertChain) -> R>(
+        &self,
+        track_id: u64,
+        f: F,
+    ) -> Option<R> {
+        if track_id == 0 {
+            Some(f(&self.master_insert.read()))
+        } else {
+            self.insert_chains.read().get(&track_id).map(f)
+        }
+    }
+
     /// Get metering data for bus insert slot
     pub fn get_bus_insert_metering(
         &self,
@@ -4004,6 +4472,23 @@ impl PlaybackEngine {
         f64::from_bits(self.balance.load(Ordering::Relaxed))
     }
 
+    /// Get broadband level lost folding the master to mono (dB, 0 = mono-compatible)
+    pub fn get_mono_fold_loss_db(&self) -> f64 {
+        f64::from_bits(self.mono_fold_loss_db.load(Ordering::Relaxed))
+    }
+
+    /// Get per-band mono-fold loss in dB (bass, lowmid, highmid, treble)
+    pub fn get_mono_fold_band_loss_db(&self) -> [f64; MONO_COMPAT_BANDS] {
+        std::array::from_fn(|b| {
+            f64::from_bits(self.mono_fold_band_loss_db[b].load(Ordering::Relaxed))
+        })
+    }
+
+    /// Get master stereo width (side/mid RMS ratio, 0 = mono)
+    pub fn get_stereo_width(&self) -> f64 {
+        f64::from_bits(self.stereo_width.load(Ordering::Relaxed))
+    }
+
     /// Get track peak by track ID (0.0 - 1.0+) - returns max of L/R for backward compatibility
     pub fn get_track_peak(&self, track_id: u64) -> f64 {
         self.track_meters
@@ -4492,6 +4977,11 @@ impl PlaybackEngine {
         self.master_soft_clip_enabled.load(Ordering::Relaxed)
     }
 
+    /// Soft-start/stop ramp on the master output
+    pub fn output_ramp(&self) -> &OutputRamp {
+        &self.output_ramp
+    }
+
     /// Get current playback position in seconds (sample-accurate)
     pub fn position_seconds(&self) -> f64 {
         self.position.seconds()
@@ -4618,16 +5108,7 @@ impl PlaybackEngine {
             }
         };
 
-        // Map bus_id to OutputBus
-        let bus = match bus_id {
-            0 => OutputBus::Sfx, // Master routes through Sfx
-            1 => OutputBus::Music,
-            2 => OutputBus::Sfx,
-            3 => OutputBus::Voice,
-            4 => OutputBus::Ambience,
-            5 => OutputBus::Aux,
-            _ => OutputBus::Sfx, // Default to Sfx
-        };
+        let bus = OutputBus::from_engine_id(bus_id);
 
         // Get next voice ID
         let id = self.next_one_shot_id.fetch_add(1, Ordering::Relaxed);
@@ -4677,16 +5158,7 @@ impl PlaybackEngine {
             }
         };
 
-        // Map bus_id to OutputBus
-        let bus = match bus_id {
-            0 => OutputBus::Sfx,
-            1 => OutputBus::Music,
-            2 => OutputBus::Sfx,
-            3 => OutputBus::Voice,
-            4 => OutputBus::Ambience,
-            5 => OutputBus::Aux,
-            _ => OutputBus::Sfx,
-        };
+        let bus = OutputBus::from_engine_id(bus_id);
 
         // Get next voice ID
         let id = self.next_one_shot_id.fetch_add(1, Ordering::Relaxed);
@@ -4745,16 +5217,7 @@ impl PlaybackEngine {
             }
         };
 
-        // Map bus_id to OutputBus
-        let bus = match bus_id {
-            0 => OutputBus::Sfx,
-            1 => OutputBus::Music,
-            2 => OutputBus::Sfx,
-            3 => OutputBus::Voice,
-            4 => OutputBus::Ambience,
-            5 => OutputBus::Aux,
-            _ => OutputBus::Sfx,
-        };
+        let bus = OutputBus::from_engine_id(bus_id);
 
         // Get next voice ID
         let id = self.next_one_shot_id.fetch_add(1, Ordering::Relaxed);
@@ -4789,6 +5252,72 @@ impl PlaybackEngine {
         }
     }
 
+    /// Scheduled playback for stage cues: the voice starts `delay_samples` after the
+    /// command reaches the audio thread and fades in over `fade_samples` along `curve`
+    /// (0 = no ramp beyond the anti-click fade). Returns voice ID (0 = failed to queue)
+    pub fn play_scheduled_to_bus(
+        &self,
+        path: &str,
+        volume: f32,
+        pan: f32,
+        bus_id: u32,
+        source: PlaybackSource,
+        looping: bool,
+        delay_samples: u64,
+        fade_samples: u64,
+        curve: CrossfadeCurve,
+    ) -> u64 {
+        let audio = match self.cache.load(path) {
+            Some(a) => a,
+            None => {
+                log::warn!("[PlaybackEngine] Failed to load scheduled audio: {}", path);
+                return 0;
+            }
+        };
+
+        let bus = OutputBus::from_engine_id(bus_id);
+
+        let id = self.next_one_shot_id.fetch_add(1, Ordering::Relaxed);
+
+        if let Some(mut tx) = self.one_shot_cmd_tx.try_lock() {
+            let _ = tx.push(OneShotCommand::PlayScheduled {
+                id,
+                audio,
+                volume,
+                pan: pan.clamp(-1.0, 1.0),
+                bus,
+                source,
+                looping,
+                delay_samples,
+                fade_samples,
+                curve,
+            });
+            id
+        } else {
+            log::warn!("[PlaybackEngine] One-shot command queue busy");
+            0
+        }
+    }
+
+    /// Fade a voice out over `fade_samples` along `curve` (mirrored) after
+    /// `delay_samples`, then stop it
+    pub fn crossfade_out_one_shot(
+        &self,
+        voice_id: u64,
+        delay_samples: u64,
+        fade_samples: u64,
+        curve: CrossfadeCurve,
+    ) {
+        if let Some(mut tx) = self.one_shot_cmd_tx.try_lock() {
+            let _ = tx.push(OneShotCommand::CrossfadeOut {
+                id: voice_id,
+                delay_samples,
+                fade_samples,
+                curve,
+            });
+        }
+    }
+
     /// Play a one-shot voice with 3D spatial positioning through HRTF binaural rendering.
     /// The voice audio bypasses pan law and routes through SpatialManager instead.
     /// `spatial_source_id` must be pre-registered via `spatial_set_source_position()`.
@@ -4808,15 +5337,7 @@ impl PlaybackEngine {
             }
         };
 
-        let bus = match bus_id {
-            0 => OutputBus::Sfx,
-            1 => OutputBus::Music,
-            2 => OutputBus::Sfx,
-            3 => OutputBus::Voice,
-            4 => OutputBus::Ambience,
-            5 => OutputBus::Aux,
-            _ => OutputBus::Sfx,
-        };
+        let bus = OutputBus::from_engine_id(bus_id);
 
         let id = self.next_one_shot_id.fetch_add(1, Ordering::Relaxed);
 
@@ -5130,6 +5651,51 @@ impl PlaybackEngine {
                         voice.engine_sample_rate = self.sample_rate();
                     }
                 }
+                OneShotCommand::PlayScheduled {
+                    id,
+                    audio,
+                    volume,
+                    pan,
+                    bus,
+                    source,
+                    looping,
+                    delay_samples,
+                    fade_samples,
+                    curve,
+                } => {
+                    // Stage cue entry: delayed start + curved fade-in, one command
+                    // so the voice is never heard before its ramp is in place
+                    let slot_idx = pick_one_shot_slot(&voices[..]);
+                    if let Some(idx) = slot_idx {
+                        let voice = &mut voices[idx];
+                        voice.activate(id, audio, volume, pan, bus, source);
+                        voice.looping = looping;
+                        voice.engine_sample_rate = self.sample_rate();
+                        voice.start_ramp(VoiceRamp {
+                            delay_samples,
+                            ramp_samples: fade_samples,
+                            elapsed_samples: 0,
+                            curve,
+                            fade_in: true,
+                        });
+                    }
+                }
+                OneShotCommand::CrossfadeOut {
+                    id,
+                    delay_samples,
+                    fade_samples,
+                    curve,
+                } => {
+                    if let Some(voice) = voices.iter_mut().find(|v| v.id == id && v.active) {
+                        voice.start_ramp(VoiceRamp {
+                            delay_samples,
+                            ramp_samples: fade_samples,
+                            elapsed_samples: 0,
+                            curve,
+                            fade_in: false,
+                        });
+                    }
+                }
                 OneShotCommand::Stop { id } => {
                     if let Some(voice) = voices.iter_mut().find(|v| v.id == id && v.active) {
                         // Fade out over ~5ms at 48kHz
@@ -6067,6 +6633,14 @@ impl PlaybackEngine {
     }
 
     pub fn process(&self, output_l: &mut [f64], output_r: &mut [f64]) {
+        self.render_block(output_l, output_r);
+
+        // ═══ OUTPUT RAMP (soft start/stop — after metering, before the device) ═══
+        let sample_rate = self.position.sample_rate() as f64;
+        self.output_ramp.process(output_l, output_r, sample_rate);
+    }
+
+    fn render_block(&self, output_l: &mut [f64], output_r: &mut [f64]) {
         let frames = output_l.len();
 
         // Clear output buffers
@@ -6168,6 +6742,7 @@ impl PlaybackEngine {
         // This acquires insert_chains lock once, applies all params, then releases
         // Track processing below will re-acquire the lock for actual processing
         self.consume_insert_param_changes();
+        self.apply_midi_learn(frames);
 
         // Check if playing (for DAW timeline tracks)
         // One-shot voices already processed above, so transport-stopped still outputs them
@@ -6212,10 +6787,8 @@ impl PlaybackEngine {
                                 } else {
                                     chain.process_pre_fader(&mut sl[..frames], &mut sr[..frames]);
                                 }
-                                for i in 0..frames {
-                                    output_l[i] += sl[i];
-                                    output_r[i] += sr[i];
-                                }
+                                buffer::add(&mut output_l[..frames], &sl[..frames]);
+                                buffer::add(&mut output_r[..frames], &sr[..frames]);
                             }
                         }
 
@@ -6239,10 +6812,8 @@ impl PlaybackEngine {
                                 }
 
                                 let volume = bus_states[bus_idx].volume;
-                                for i in 0..frames {
-                                    sl[i] *= volume;
-                                    sr[i] *= volume;
-                                }
+                                buffer::scale(&mut sl[..frames], volume);
+                                buffer::scale(&mut sr[..frames], volume);
 
                                 if let Some(ref taps) = tail_taps {
                                     bus_inserts[bus_idx]
@@ -6252,10 +6823,8 @@ impl PlaybackEngine {
                                         .process_post_fader(&mut sl[..frames], &mut sr[..frames]);
                                 }
 
-                                for i in 0..frames {
-                                    output_l[i] += sl[i];
-                                    output_r[i] += sr[i];
-                                }
+                                buffer::add(&mut output_l[..frames], &sl[..frames]);
+                                buffer::add(&mut output_r[..frames], &sr[..frames]);
                             }
                             } // if let Some(bus_states)
                         }
@@ -6275,10 +6844,8 @@ impl PlaybackEngine {
                 }
 
                 let master = self.master_volume();
-                for i in 0..frames {
-                    output_l[i] *= master;
-                    output_r[i] *= master;
-                }
+                buffer::scale(&mut output_l[..frames], master);
+                buffer::scale(&mut output_r[..frames], master);
 
                 if let Some(ref mut master_insert) = master_insert_guard {
                     if let Some(ref taps) = tail_sidechain_taps {
@@ -6446,15 +7013,35 @@ impl PlaybackEngine {
 
         // === SAMPLE-ACCURATE AUTOMATION ===
         // Get all automation changes within this block
-        if let Some(ref automation) = self.automation {
-            let automation_changes = automation.get_block_changes(start_sample, frames);
-
-            // Apply all automation changes BEFORE processing audio
-            // This is simpler than splitting the block, and still sample-accurate
-            // because changes are applied at exact sample positions before audio rendering
-            for change in automation_changes {
-                self.apply_automation_change(&change);
-            }
+        let automation_changes = self
+            .automation
+            .as_ref()
+            .map(|automation| automation.get_block_changes(start_sample, frames))
+            .unwrap_or_default();
+
+        // Apply all automation changes BEFORE processing audio
+        // This is simpler than splitting the block, and still sample-accurate
+        // because changes are applied at exact sample positions before audio rendering
+        for change in &automation_changes {
+            self.apply_automation_change(change);
+        }
+
+        // LFO modulation on top of automation (applied last so it takes precedence)
+        if let Some(ref modulation) = self.modulation {
+            let bpm = self.position.get_tempo().unwrap_or(120.0);
+            MODULATION_CHANGES.with(|changes| {
+                let mut changes = changes.borrow_mut();
+                let count = modulation.process_block_into(
+                    start_sample,
+                    sample_rate,
+                    bpm,
+                    &automation_changes,
+                    &mut changes,
+                );
+                for change in &changes[..count] {
+                    self.apply_automation_change(change);
+                }
+            });
         }
 
         // Decay factor for meters (60dB in ~300ms at 48kHz, 256 block size)
@@ -6528,14 +7115,22 @@ impl PlaybackEngine {
         let mut track_lufs_guard = self.track_lufs_meters.try_write();
         let mut delay_comp_guard = self.delay_comp.try_write();
         let mut sidechain_taps_guard = self.sidechain_taps.try_write();
+        let mut folder_buses_guard = self.folder_buses.try_write();
+        if let Some(ref mut buses) = folder_buses_guard {
+            for bus in buses.values_mut() {
+                bus.clear(frames);
+            }
+        }
 
-        // Process each track → route to its bus
+        // Process each track → route to its bus (or its folder's summing bus)
         // DashMap iter() returns references that auto-release shard locks
         for entry in self.track_manager.tracks.iter() {
             let track = entry.value();
-            // Skip muted tracks (including VCA mute), or non-soloed tracks when solo is active
+            // Skip muted tracks (including VCA/folder mute), or non-soloed when solo is active
             let vca_muted = self.is_vca_muted(track.id.0);
-            if track.muted || vca_muted || (solo_active && !track.soloed) {
+            let folder_routing = self.get_folder_routing(track.id.0);
+            let folder_muted = folder_routing.is_some_and(|r| r.gain == 0.0);
+            if track.muted || vca_muted || folder_muted || (solo_active && !track.soloed) {
                 continue;
             }
 
@@ -6548,32 +7143,43 @@ impl PlaybackEngine {
             if let Some(input_bus_id) = track.input_bus
                 && let Some(bus) = self.input_bus_manager.get_bus(input_bus_id)
             {
-                // Check monitor mode and armed state
-                let should_monitor = match track.monitor_mode {
-                    MonitorMode::Manual => true,
-                    MonitorMode::Auto => track.armed && self.position.is_playing(),
-                    MonitorMode::Off => false,
-                };
+                // Check monitor mode and armed state.
+                // Loopback buses carry engine output: monitoring them would
+                // feed back into the mix, so they are record-only.
+                let is_loopback = bus.is_loopback();
+                let should_monitor = !is_loopback
+                    && match track.monitor_mode {
+                        MonitorMode::Manual => true,
+                        MonitorMode::Auto => track.armed && self.position.is_playing(),
+                        MonitorMode::Off => false,
+                    };
+                let records_loopback =
+                    is_loopback && track.armed && self.position.is_recording();
 
-                if should_monitor {
+                if should_monitor || records_loopback {
                     // Read audio from input bus (zero-copy reference)
                     if let Some((left, right)) = bus.read_buffers() {
                         // Mix input into track buffer (for monitoring)
                         let frames_to_copy = frames.min(left.len());
-                        for i in 0..frames_to_copy {
-                            track_l[i] += left[i] as f64;
-                            if let Some(ref r) = right {
-                                track_r[i] += r[i] as f64;
-                            } else {
-                                // Mono input - copy to both channels
-                                track_r[i] += left[i] as f64;
+                        if should_monitor {
+                            for i in 0..frames_to_copy {
+                                track_l[i] += left[i] as f64;
+                                if let Some(ref r) = right {
+                                    track_r[i] += r[i] as f64;
+                                } else {
+                                    // Mono input - copy to both channels
+                                    track_r[i] += left[i] as f64;
+                                }
                             }
                         }
 
+                        // Loopback audio belongs to an earlier, PDC-delayed block
+                        let record_position = bus.capture_position().unwrap_or(start_sample);
+
                         // Send to RecordingManager if track is armed and recording
                         if track.armed && self.position.is_recording() {
                             // Check punch in/out
-                            if self.recording_manager.check_punch(start_sample) {
+                            if self.recording_manager.check_punch(record_position) {
                                 // Prepare interleaved samples for recording
                                 // Use stack-allocated buffer for small blocks, heap for larger
                                 let num_samples = frames_to_copy * 2; // stereo interleaved
@@ -6588,7 +7194,7 @@ impl PlaybackEngine {
                                     self.recording_manager.write_samples(
                                         TrackId(track.id.0),
                                         &rec_buffer[..num_samples],
-                                        start_sample,
+                                        record_position,
                                     );
                                 } else {
                                     // Heap allocation for large blocks (rare)
@@ -6601,7 +7207,7 @@ impl PlaybackEngine {
                                     self.recording_manager.write_samples(
                                         TrackId(track.id.0),
                                         &rec_buffer,
-                                        start_sample,
+                                        record_position,
                                     );
                                 }
                             }
@@ -6772,6 +7378,9 @@ impl PlaybackEngine {
 
             } // end else (Audio track clip rendering)
 
+            // === INPUT SECTION: trim, polarity, mono sum (before everything else) ===
+            track.process_input(track_l, track_r);
+
             // === SIDECHAIN TAP: store post-clip/pre-insert audio for other tracks ===
             // This feeds sidechain compressors etc. with 1-block latency (standard in DAWs).
             // Updated BEFORE insert processing so inserts can read OTHER tracks' taps.
@@ -7090,7 +7699,15 @@ impl PlaybackEngine {
             }
 
             // Route track to output bus(es)
-            if track.output_channel_map.is_empty() {
+            // Folder children sum into the folder bus (main stereo pair only)
+            if let Some(routing) = folder_routing
+                && let Some(ref mut buses) = folder_buses_guard
+                && let Some(bus) = buses
+                    .get_mut(&routing.folder_id)
+                    .filter(|bus| bus.capacity() >= frames)
+            {
+                bus.accumulate(routing, track_l, track_r);
+            } else if track.output_channel_map.is_empty() {
                 // Standard stereo routing — single bus destination
                 bus_buffers.add_to_bus(track.output_bus, track_l, track_r);
             } else {
@@ -7133,8 +7750,35 @@ impl PlaybackEngine {
             }
         }
 
+        // === FOLDER BUSES ===
+        // Summed children → folder pre-fader inserts → folder fader → post-fader inserts → bus
+        if let Some(ref mut buses) = folder_buses_guard {
+            for (folder_id, bus) in buses.iter_mut() {
+                let Some(routing) = bus.routing() else {
+                    continue;
+                };
+                if let Some(ref mut chains) = insert_chains_guard
+                    && let Some(chain) = chains.get_mut(folder_id)
+                {
+                    chain.process_pre_fader(&mut bus.left[..frames], &mut bus.right[..frames]);
+                }
+                bus.apply_gain(routing.gain, frames);
+                if let Some(ref mut chains) = insert_chains_guard
+                    && let Some(chain) = chains.get_mut(folder_id)
+                {
+                    chain.process_post_fader(&mut bus.left[..frames], &mut bus.right[..frames]);
+                }
+                bus_buffers.add_to_bus(
+                    routing.output_bus,
+                    &bus.left[..frames],
+                    &bus.right[..frames],
+                );
+            }
+        }
+
         // Release coalesced guards — no longer needed after track loop.
         // Minimizes hold duration so UI plugin load/remove is unblocked sooner.
+        drop(folder_buses_guard);
         drop(insert_chains_guard);
         drop(stereo_imagers_guard);
         drop(track_meters_guard);
@@ -7167,6 +7811,11 @@ impl PlaybackEngine {
         // Re-acquire sidechain taps (immutable) for bus insert sidechain routing
         // (e.g. voice track ducking music bus via sidechain compressor on bus)
         let bus_sidechain_taps = self.sidechain_taps.try_read();
+        // Sidechain router: buses publish key taps, keyed bus slots read them
+        let mut sidechain_router = self.sidechain_router.try_write();
+        if let Some(ref mut router) = sidechain_router {
+            router.clear_buffers();
+        }
 
         // ═══ TOPOLOGICAL BUS ORDERING ═══
         // Buses that route to other buses must be processed FIRST so their output
@@ -7174,24 +7823,7 @@ impl PlaybackEngine {
         // (e.g., Sfx→Music, Voice→Aux submix).
         // Simple 2-level depth: child buses first (route to bus), then parent buses (route to master).
         // Circular routing (A→B→A) is prevented by the UI layer.
-
-        // Build processing order: children first, then parents
-        let mut process_order: [usize; 6] = [0; 6];
-        let mut order_idx = 0;
-        // Pass 1: buses that route to OTHER buses (children)
-        for bus_idx in 0..6 {
-            if let BusOutputDest::Bus(_) = bus_states[bus_idx].output_dest {
-                process_order[order_idx] = bus_idx;
-                order_idx += 1;
-            }
-        }
-        // Pass 2: all remaining buses (route to master or unrecognized state)
-        for bus_idx in 0..6 {
-            if !process_order[..order_idx].contains(&bus_idx) {
-                process_order[order_idx] = bus_idx;
-                order_idx += 1;
-            }
-        }
+        let process_order = Self::bus_process_order(bus_states, sidechain_router.as_deref());
 
         // Intermediate buffers for bus-to-bus routing.
         // After processing a child bus, its output is accumulated here before
@@ -7219,7 +7851,7 @@ impl PlaybackEngine {
         // Acquire stereo imagers ONCE for entire bus loop
         let mut bus_imagers_guard = self.bus_stereo_imagers.try_write();
 
-        for &bus_idx in &process_order[..order_idx] {
+        for &bus_idx in &process_order {
             let state = &bus_states[bus_idx];
 
             // Skip if muted, or if solo is active and this bus isn't soloed
@@ -7230,12 +7862,7 @@ impl PlaybackEngine {
             }
 
             let bus = match bus_idx {
-                0 => OutputBus::Master,
-                1 => OutputBus::Music,
-                2 => OutputBus::Sfx,
-                3 => OutputBus::Voice,
-                4 => OutputBus::Ambience,
-                5 => OutputBus::Aux,
+                0..=5 => OutputBus::from(bus_idx as u32),
                 _ => continue,
             };
 
@@ -7255,6 +7882,12 @@ impl PlaybackEngine {
             // Get mutable bus buffer for InsertChain processing
             let (bus_l, bus_r) = bus_buffers.get_bus_mut(bus);
 
+            // ═══ BUS SIDECHAIN KEYS ═══
+            // Keys routed from other buses (already processed this block)
+            if let (Some(router), Some(inserts)) = (&mut sidechain_router, &mut bus_inserts) {
+                Self::feed_bus_sidechain_keys(router, &mut inserts[bus_idx], bus_idx, frames);
+            }
+
             // ═══ BUS INSERT CHAIN (PRE-FADER) ═══
             // Process inserts BEFORE bus fader — affects sends, allows gain staging
             // Sidechain-aware: bus inserts can receive sidechain from any track tap
@@ -7266,6 +7899,20 @@ impl PlaybackEngine {
                 }
             }
 
+            // ═══ BUS SIDECHAIN TAPS (PRE/POST FADER) ═══
+            // Post-fader tap is the pre-fader signal at fader gain (before pan)
+            if let Some(ref mut router) = sidechain_router {
+                let (tap_l, tap_r) = (&bus_l[..frames], &bus_r[..frames]);
+                router.store_bus_signal(bus_idx as u32, SendTapPoint::PreFader, tap_l, tap_r);
+                router.store_bus_signal_scaled(
+                    bus_idx as u32,
+                    SendTapPoint::PostFader,
+                    tap_l,
+                    tap_r,
+                    state.volume,
+                );
+            }
+
             // Apply bus volume and dual-pan (fader stage)
             // Dual-pan: pan controls L channel placement, pan_right controls R channel placement
             // Default: pan=-1 (L stays left), pan_right=1 (R stays right) = stereo pass-through
@@ -7302,6 +7949,16 @@ impl PlaybackEngine {
                 }
             }
 
+            // ═══ BUS SIDECHAIN TAP (POST-PAN) ═══
+            if let Some(ref mut router) = sidechain_router {
+                router.store_bus_signal(
+                    bus_idx as u32,
+                    SendTapPoint::PostPan,
+                    &bus_l[..frames],
+                    &bus_r[..frames],
+                );
+            }
+
             // ═══ BUS INSERT CHAIN (POST-FADER) ═══
             // Process inserts AFTER bus fader — typical EQ/Compressor placement
             // Sidechain-aware: same tap access as pre-fader
@@ -7313,6 +7970,14 @@ impl PlaybackEngine {
                 }
             }
 
+            // ═══ BUS LOOPBACK CAPTURE ═══
+            // Master loopback is captured after the master chain instead
+            if bus != OutputBus::Master && self.input_bus_manager.has_loopback() {
+                let latency = self.loopback_latency(bus);
+                self.input_bus_manager
+                    .capture_loopback(bus, bus_l, bus_r, start_sample, latency);
+            }
+
             // ═══ PER-BUS PEAK METERING ═══
             // Calculate peak levels after all processing (volume, pan, inserts)
             // and store in SHARED_METERS for UI display
@@ -7331,10 +7996,8 @@ impl PlaybackEngine {
             match state.output_dest {
                 BusOutputDest::Master => {
                     // Standard: sum directly to master output
-                    for i in 0..frames {
-                        output_l[i] += bus_l[i];
-                        output_r[i] += bus_r[i];
-                    }
+                    buffer::add(&mut output_l[..frames], &bus_l[..frames]);
+                    buffer::add(&mut output_r[..frames], &bus_r[..frames]);
                 }
                 BusOutputDest::Bus(target_idx) => {
                     // Hierarchical: accumulate into target bus's accum buffer.
@@ -7342,24 +8005,24 @@ impl PlaybackEngine {
                     if target_idx < 6 && target_idx != bus_idx {
                         BUS_ACCUM_L.with(|cell| {
                             let mut v = cell.borrow_mut();
-                            for i in 0..frames { v[target_idx][i] += bus_l[i]; }
+                            buffer::add(&mut v[target_idx][..frames], &bus_l[..frames]);
                         });
                         BUS_ACCUM_R.with(|cell| {
                             let mut v = cell.borrow_mut();
-                            for i in 0..frames { v[target_idx][i] += bus_r[i]; }
+                            buffer::add(&mut v[target_idx][..frames], &bus_r[..frames]);
                         });
                         bus_has_accum[target_idx] = true;
                     } else {
                         // Self-routing or invalid target: fall back to master
-                        for i in 0..frames {
-                            output_l[i] += bus_l[i];
-                            output_r[i] += bus_r[i];
-                        }
+                        buffer::add(&mut output_l[..frames], &bus_l[..frames]);
+                        buffer::add(&mut output_r[..frames], &bus_r[..frames]);
                     }
                 }
             }
         }
 
+        drop(sidechain_router);
+
         // Acquire master insert chain ONCE for pre+post fader (BUG#14 lock coalescing)
         // Previously acquired twice (pre-fader + post-fader) = 2 contention windows.
         let mut master_insert_guard = self.master_insert.try_write();
@@ -7375,10 +8038,8 @@ impl PlaybackEngine {
 
         // Apply master volume
         let master = self.master_volume();
-        for i in 0..frames {
-            output_l[i] *= master;
-            output_r[i] *= master;
-        }
+        buffer::scale(&mut output_l[..frames], master);
+        buffer::scale(&mut output_r[..frames], master);
 
         // ═══ MASTER STEREO IMAGER (post-volume, pre-post-inserts) ═══
         if let Some(mut master_imager) = self.master_stereo_imager.try_write() {
@@ -7487,6 +8148,18 @@ impl PlaybackEngine {
             self.dc_filter_state_r.store(state_r.to_bits(), Ordering::Relaxed);
         }
 
+        // ═══ MASTER LOOPBACK CAPTURE (fully processed, before click/monitor) ═══
+        if self.input_bus_manager.has_loopback() {
+            let latency = self.loopback_latency(OutputBus::Master);
+            self.input_bus_manager.capture_loopback(
+                OutputBus::Master,
+                output_l,
+                output_r,
+                start_sample,
+                latency,
+            );
+        }
+
         // Calculate metering (after volume is applied)
         let prev_peak_l = f64::from_bits(self.peak_l.load(Ordering::Relaxed));
         let prev_peak_r = f64::from_bits(self.peak_r.load(Ordering::Relaxed));
@@ -7577,6 +8250,22 @@ impl PlaybackEngine {
             self.true_peak_r.store(dbtp_r.to_bits(), Ordering::Relaxed);
         }
 
+        // Mono-compatibility: level lost folding to mono, broadband and per band
+        if let Some(mut mono) = self.mono_compat_meter.try_write() {
+            mono.process_block(output_l, output_r);
+            self.mono_fold_loss_db
+                .store(mono.fold_loss_db().to_bits(), Ordering::Relaxed);
+            for (slot, loss) in self
+                .mono_fold_band_loss_db
+                .iter()
+                .zip(mono.band_fold_loss_db())
+            {
+                slot.store(loss.to_bits(), Ordering::Relaxed);
+            }
+            self.stereo_width
+                .store(mono.stereo_width().to_bits(), Ordering::Relaxed);
+        }
+
         // ═══ FORWARD ALL METERS TO SHARED MEMORY (Dart reads this) ═══
         // Peak/RMS already forwarded via update_channel_peak(0, ...) above.
         // Forward LUFS, True Peak, stereo analysis so Dart LUFS meter works.
@@ -7596,6 +8285,21 @@ impl PlaybackEngine {
             crate::ffi::SHARED_METERS.correlation.store(corr.to_bits(), Ordering::Relaxed);
             crate::ffi::SHARED_METERS.balance.store(bal.to_bits(), Ordering::Relaxed);
 
+            crate::ffi::SHARED_METERS
+                .stereo_width
+                .store(self.stereo_width.load(Ordering::Relaxed), Ordering::Relaxed);
+            crate::ffi::SHARED_METERS.mono_fold_loss.store(
+                self.mono_fold_loss_db.load(Ordering::Relaxed),
+                Ordering::Relaxed,
+            );
+            for (shared, slot) in crate::ffi::SHARED_METERS
+                .mono_fold_band_loss
+                .iter()
+                .zip(&self.mono_fold_band_loss_db)
+            {
+                shared.store(slot.load(Ordering::Relaxed), Ordering::Relaxed);
+            }
+
             // Master peak/RMS (redundant with update_channel_peak but fills master-specific fields)
             crate::ffi::SHARED_METERS.update_master(peak_l, peak_r, rms_l, rms_r);
 
@@ -7677,6 +8381,7 @@ impl PlaybackEngine {
         // Uses try_write() to avoid blocking the audio thread if UI is changing settings.
         // Passes is_recording so click can implement "only during record" mode.
         // process_block() returns true when count-in completes → signal transport to start.
+        let mut count_in_record_frame = None;
         if let Some(mut click) = crate::ffi::CLICK_TRACK.try_write() {
             let is_count_in = click.is_count_in_active();
             if self.position.is_playing() || is_count_in {
@@ -7685,9 +8390,10 @@ impl PlaybackEngine {
                 let count_in_done =
                     click.process_block(output_l, output_r, start_sample, frames, is_recording);
                 if count_in_done {
-                    // Count-in completed — transport should now begin playing.
-                    // The transport start is handled by the UI layer polling
-                    // click_is_count_in_active() which will return false.
+                    // Count-in completed mid-block — the transport starts at the
+                    // record-start downbeat, so only the frames after it advance.
+                    // UI still observes completion via click_is_count_in_active().
+                    count_in_record_frame = click.record_start_frame();
                 }
             }
         }
@@ -7709,8 +8415,9 @@ impl PlaybackEngine {
         // Advance position (only if not scrubbing - scrub position is controlled externally)
         if self.position.should_advance() && !count_in_blocks_advance {
             let varispeed_rate = self.effective_playback_rate();
+            let advance = frames - count_in_record_frame.unwrap_or(0).min(frames);
             self.position
-                .advance_with_rate(frames as u64, varispeed_rate);
+                .advance_with_rate(advance as u64, varispeed_rate);
         } else if self.position.is_scrubbing() {
             // During scrubbing, advance within the scrub window (loops automatically)
             self.position.advance_scrub(frames as u64);
@@ -7759,6 +8466,29 @@ impl PlaybackEngine {
                             track.muted = muted;
                         }
                     }
+                    "input_trim" => {
+                        // Automation 0-1 → trim -24..+24 dB
+                        let trim_db = (change.value * 2.0 - 1.0) * MAX_INPUT_TRIM_DB;
+                        if let Some(mut track) =
+                            self.track_manager.tracks.get_mut(&TrackId(track_id))
+                        {
+                            track.input_trim_db = trim_db;
+                        }
+                    }
+                    "phase_invert" => {
+                        if let Some(mut track) =
+                            self.track_manager.tracks.get_mut(&TrackId(track_id))
+                        {
+                            track.phase_inverted = change.value > 0.5;
+                        }
+                    }
+                    "mono_sum" => {
+                        if let Some(mut track) =
+                            self.track_manager.tracks.get_mut(&TrackId(track_id))
+                        {
+                            track.mono_sum = change.value > 0.5;
+                        }
+                    }
                     _ => {
                         log::trace!("Unknown track parameter: {}", param_id.param_name);
                     }
@@ -7970,13 +8700,8 @@ impl PlaybackEngine {
                 self.process_clip_simple(clip, &audio, start_sample, sample_rate, track_l, track_r);
             }
 
-            // Apply phase invert (polarity flip) before pan
-            if track.phase_inverted {
-                for i in 0..frames {
-                    track_l[i] = -track_l[i];
-                    track_r[i] = -track_r[i];
-                }
-            }
+            // Apply input section (trim, polarity, mono sum) before pan
+            track.process_input(track_l, track_r);
 
             // Apply dual-pan for stereo tracks BEFORE feeding to routing graph
             // Pro Tools style: L channel has own pan, R channel has own pan
@@ -8215,7 +8940,9 @@ impl PlaybackEngine {
                             // Store raw sinc output (no gain yet — Signalsmith needs clean signal)
                             pv_l[frame_idx] = interp_l;
                             // Store per-sample gain for post-stretch application
-                            pv_gain[frame_idx] = clip.gain * loop_xf_gain;
+                            pv_gain[frame_idx] = clip.gain
+                                * clip.fade_gain_at(clip_offset, sample_rate)
+                                * loop_xf_gain;
 
                             if channels >= 2 {
                                 pv_r[frame_idx] = sinc_table::interpolate_sample(
@@ -8361,15 +9088,16 @@ impl PlaybackEngine {
                     clip_resample_mode, source_pos_f64, &audio.samples, channels,
                     total_source_frames, 0, clip_sinc_ref,
                 ) as f64;
+                let gain = clip.gain * clip.fade_gain_at(clip_offset, sample_rate) * loop_xf_gain;
                 if channels >= 2 {
                     let interp_r = sinc_table::interpolate_sample(
                         clip_resample_mode, source_pos_f64, &audio.samples, channels,
                         total_source_frames, 1, clip_sinc_ref,
                     ) as f64;
-                    output_l[frame_idx] += interp_l * clip.gain * loop_xf_gain;
-                    output_r[frame_idx] += interp_r * clip.gain * loop_xf_gain;
+                    output_l[frame_idx] += interp_l * gain;
+                    output_r[frame_idx] += interp_r * gain;
                 } else {
-                    let mono = interp_l * clip.gain * loop_xf_gain;
+                    let mono = interp_l * gain;
                     output_l[frame_idx] += mono;
                     output_r[frame_idx] += mono;
                 }
@@ -8384,6 +9112,84 @@ impl PlaybackEngine {
     /// - Uses blocking locks (safe for offline processing)
     /// - Does not update meters or advance transport
     pub fn process_offline(&self, start_sample: usize, output_l: &mut [f64], output_r: &mut [f64]) {
+        self.render_offline(start_sample, output_l, output_r, None);
+    }
+
+    /// Process a stem offline (for grouped stems export)
+    ///
+    /// Same full-mix path as `process_offline()` (buses, master chain), but only
+    /// `tracks` are audible — the user's solo state is ignored, mutes still apply.
+    /// With `include_sends` false the stem tracks' sends are dropped, so send
+    /// returns (reverb/delay buses) only carry what is routed to them directly.
+    pub fn process_offline_stem(
+        &self,
+        start_sample: usize,
+        tracks: &HashSet<u64>,
+        include_sends: bool,
+        output_l: &mut [f64],
+        output_r: &mut [f64],
+    ) {
+        self.render_offline(
+            start_sample,
+            output_l,
+            output_r,
+            Some((tracks, include_sends)),
+        );
+    }
+
+    /// Track IDs whose audio reaches `bus` (directly or via bus-to-bus routing)
+    pub fn tracks_feeding_bus(&self, bus: OutputBus) -> Vec<u64> {
+        let bus_states = self.bus_states.read();
+        let target = bus as usize;
+        let feeds = |from: OutputBus| {
+            let mut idx = from as usize;
+            // Bounded walk — bus graph has 6 nodes, guards against cycles
+            for _ in 0..6 {
+                if idx == target {
+                    return true;
+                }
+                match bus_states[idx].output_dest {
+                    BusOutputDest::Bus(next) if next < 6 && next != idx => idx = next,
+                    _ => return false,
+                }
+            }
+            false
+        };
+
+        let mut ids: Vec<u64> = self
+            .track_manager
+            .tracks
+            .iter()
+            .filter(|entry| feeds(entry.value().output_bus))
+            .map(|entry| entry.value().id.0)
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Member track IDs of a group (empty if unknown or no group manager)
+    pub fn group_members(&self, group_id: GroupId) -> Vec<u64> {
+        let Some(manager) = &self.group_manager else {
+            return Vec::new();
+        };
+        let mut ids: Vec<u64> = manager
+            .read()
+            .groups
+            .get(&group_id)
+            .map(|group| group.members.iter().copied().collect())
+            .unwrap_or_default();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Shared offline render — `stem` = (audible tracks, include sends)
+    fn render_offline(
+        &self,
+        start_sample: usize,
+        output_l: &mut [f64],
+        output_r: &mut [f64],
+        stem: Option<(&HashSet<u64>, bool)>,
+    ) {
         let frames = output_l.len();
 
         // Clear output buffers
@@ -8409,6 +9215,9 @@ impl PlaybackEngine {
 
         let mut track_l = vec![0.0f64; frames];
         let mut track_r = vec![0.0f64; frames];
+        let mut pre_fader_l = vec![0.0f64; frames];
+        let mut pre_fader_r = vec![0.0f64; frames];
+        let render_sends = stem.is_none_or(|(_, include_sends)| include_sends);
 
         // Acquire insert chains and sidechain taps for offline track processing
         let mut insert_chains = self.insert_chains.write();
@@ -8428,9 +9237,14 @@ impl PlaybackEngine {
 
         for track_entry in self.track_manager.tracks.iter() {
             let track = track_entry.value();
-            // Skip muted tracks (including VCA mute), or non-soloed tracks when solo is active
+            // Skip muted tracks (including VCA mute), or non-soloed tracks when solo is active.
+            // Stem renders replace the solo state with the stem's track set.
             let vca_muted = self.is_vca_muted(track.id.0);
-            if track.muted || vca_muted || (solo_active && !track.soloed) {
+            let audible = match stem {
+                Some((tracks, _)) => tracks.contains(&track.id.0),
+                None => !solo_active || track.soloed,
+            };
+            if track.muted || vca_muted || !audible {
                 continue;
             }
 
@@ -8475,11 +9289,25 @@ impl PlaybackEngine {
                 );
             }
 
+            // ═══ TRACK INPUT SECTION (offline) ═══
+            track.process_input(&mut track_l, &mut track_r);
+
             // ═══ TRACK PRE-FADER INSERTS (offline) ═══
             if let Some(chain) = insert_chains.get_mut(&track.id.0) {
                 chain.process_pre_fader_with_taps(&mut track_l, &mut track_r, &offline_sc_taps, frames);
             }
 
+            // Capture pre-fader signal for pre-fader sends (before volume/pan)
+            let has_pre_fader_sends = render_sends
+                && track
+                    .sends
+                    .iter()
+                    .any(|s| s.pre_fader && !s.muted && s.level > 0.0 && s.destination.is_some());
+            if has_pre_fader_sends {
+                pre_fader_l.copy_from_slice(&track_l);
+                pre_fader_r.copy_from_slice(&track_r);
+            }
+
             // Apply track volume and pan
             let track_volume = self.get_track_volume_with_automation(track);
             let vca_gain = self.get_vca_gain(track.id.0);
@@ -8517,6 +9345,33 @@ impl PlaybackEngine {
                 chain.process_post_fader_with_taps(&mut track_l, &mut track_r, &offline_sc_taps, frames);
             }
 
+            // ═══ SENDS (offline — mirrors live path) ═══
+            if render_sends {
+                for send in track.sends.iter() {
+                    let Some(dest_bus) = send.destination else {
+                        continue;
+                    };
+                    if send.muted || send.level <= 0.0 {
+                        continue;
+                    }
+
+                    // Constant-power send pan, normalized so center = unity
+                    let send_pan_angle =
+                        (send.pan.clamp(-1.0, 1.0) + 1.0) * std::f64::consts::FRAC_PI_4;
+                    let gain_l = send.level * send_pan_angle.cos() * std::f64::consts::SQRT_2;
+                    let gain_r = send.level * send_pan_angle.sin() * std::f64::consts::SQRT_2;
+
+                    let (src_l, src_r) = if send.pre_fader {
+                        (&pre_fader_l, &pre_fader_r)
+                    } else {
+                        (&track_l, &track_r)
+                    };
+                    let (dest_l, dest_r) = bus_buffers.get_bus_mut(dest_bus);
+                    buffer::mul_add(&mut dest_l[..frames], &src_l[..frames], gain_l);
+                    buffer::mul_add(&mut dest_r[..frames], &src_r[..frames], gain_r);
+                }
+            }
+
             // Route to bus
             bus_buffers.add_to_bus(track.output_bus, &track_l, &track_r);
         }
@@ -8532,6 +9387,8 @@ impl PlaybackEngine {
             let mut bus_inserts = self.bus_inserts.write();
             let bus_states = self.bus_states.read();
             let mut bus_imagers = self.bus_stereo_imagers.try_write();
+            let mut sidechain_router = self.sidechain_router.write();
+            sidechain_router.clear_buffers();
 
             let buses = [
                 OutputBus::Master, OutputBus::Music, OutputBus::Sfx,
@@ -8539,27 +9396,14 @@ impl PlaybackEngine {
             ];
 
             // Topological ordering: children (route to bus) first, then parents (route to master)
-            let mut process_order: [usize; 6] = [0; 6];
-            let mut order_idx = 0;
-            for bus_idx in 0..6 {
-                if let BusOutputDest::Bus(_) = bus_states[bus_idx].output_dest {
-                    process_order[order_idx] = bus_idx;
-                    order_idx += 1;
-                }
-            }
-            for bus_idx in 0..6 {
-                if !process_order[..order_idx].contains(&bus_idx) {
-                    process_order[order_idx] = bus_idx;
-                    order_idx += 1;
-                }
-            }
+            let process_order = Self::bus_process_order(&bus_states, Some(&sidechain_router));
 
             // Accum buffers for bus-to-bus routing (offline can heap-alloc freely)
             let mut accum_l: Vec<Vec<f64>> = (0..6).map(|_| vec![0.0; frames]).collect();
             let mut accum_r: Vec<Vec<f64>> = (0..6).map(|_| vec![0.0; frames]).collect();
             let mut has_accum = [false; 6];
 
-            for &bus_idx in &process_order[..order_idx] {
+            for &bus_idx in &process_order {
                 let state = &bus_states[bus_idx];
                 if state.muted { continue; }
 
@@ -8572,8 +9416,28 @@ impl PlaybackEngine {
                     for i in 0..frames { bus_r[i] += accum_r[bus_idx][i]; }
                 }
 
-                // Pre-fader inserts
+                // Bus-keyed sidechain inputs, pre-fader inserts, then pre/post fader taps
+                Self::feed_bus_sidechain_keys(
+                    &mut sidechain_router,
+                    &mut bus_inserts[bus_idx],
+                    bus_idx,
+                    frames,
+                );
                 bus_inserts[bus_idx].process_pre_fader_with_taps(bus_l, bus_r, &offline_sc_taps, frames);
+                let (tap_l, tap_r) = (&bus_l[..frames], &bus_r[..frames]);
+                sidechain_router.store_bus_signal(
+                    bus_idx as u32,
+                    SendTapPoint::PreFader,
+                    tap_l,
+                    tap_r,
+                );
+                sidechain_router.store_bus_signal_scaled(
+                    bus_idx as u32,
+                    SendTapPoint::PostFader,
+                    tap_l,
+                    tap_r,
+                    state.volume,
+                );
 
                 // Bus volume + dual-pan (same math as live path)
                 let volume = state.volume;
@@ -8605,6 +9469,12 @@ impl PlaybackEngine {
                         bus_r[i] = r;
                     }
                 }
+                sidechain_router.store_bus_signal(
+                    bus_idx as u32,
+                    SendTapPoint::PostPan,
+                    &bus_l[..frames],
+                    &bus_r[..frames],
+                );
 
                 // Post-fader inserts
                 bus_inserts[bus_idx].process_post_fader_with_taps(bus_l, bus_r, &offline_sc_taps, frames);
@@ -8612,12 +9482,12 @@ impl PlaybackEngine {
                 // Route: bus-to-bus or direct to master sum
                 match state.output_dest {
                     BusOutputDest::Bus(target_idx) if target_idx < 6 && target_idx != bus_idx => {
-                        for i in 0..frames { accum_l[target_idx][i] += bus_l[i]; }
-                        for i in 0..frames { accum_r[target_idx][i] += bus_r[i]; }
+                        buffer::add(&mut accum_l[target_idx][..frames], &bus_l[..frames]);
+                        buffer::add(&mut accum_r[target_idx][..frames], &bus_r[..frames]);
                         has_accum[target_idx] = true;
                         // Zero this bus so sum_to_master doesn't double-count it
-                        bus_l.iter_mut().for_each(|x| *x = 0.0);
-                        bus_r.iter_mut().for_each(|x| *x = 0.0);
+                        buffer::clear(bus_l);
+                        buffer::clear(bus_r);
                     }
                     _ => {} // Master-routed buses get summed by sum_to_master() below
                 }
@@ -8640,10 +9510,8 @@ impl PlaybackEngine {
         master_insert.process_pre_fader_with_taps(output_l, output_r, &offline_sc_taps, frames);
 
         let master = self.master_volume();
-        for i in 0..frames {
-            output_l[i] *= master;
-            output_r[i] *= master;
-        }
+        buffer::scale(&mut output_l[..frames], master);
+        buffer::scale(&mut output_r[..frames], master);
 
         // ═══ MASTER STEREO IMAGER (offline — mirrors live path) ═══
         if let Some(mut master_imager) = self.master_stereo_imager.try_write() {
@@ -8778,9 +9646,8 @@ impl PlaybackEngine {
         let vca_gain = self.get_vca_gain(track.id.0);
         let final_volume = track_volume * vca_gain;
 
-        // Apply phase invert (polarity flip) if enabled
-        // This multiplies the signal by -1, flipping the polarity
-        let phase_mult = if track.phase_inverted { -1.0 } else { 1.0 };
+        // Apply input section (trim, polarity, mono sum)
+        track.process_input(output_l, output_r);
 
         if track.is_stereo() {
             let pan_l_angle = (track.pan + 1.0) * std::f64::consts::FRAC_PI_4;
@@ -8792,8 +9659,8 @@ impl PlaybackEngine {
             let pan_r_r = pan_r_angle.sin();
 
             for i in 0..frames {
-                let l_sample = output_l[i] * phase_mult;
-                let r_sample = output_r[i] * phase_mult;
+                let l_sample = output_l[i];
+                let r_sample = output_r[i];
                 output_l[i] = final_volume * (l_sample * pan_l_l + r_sample * pan_r_l);
                 output_r[i] = final_volume * (l_sample * pan_l_r + r_sample * pan_r_r);
             }
@@ -8803,8 +9670,8 @@ impl PlaybackEngine {
             let pan_r = pan_angle.sin();
 
             for i in 0..frames {
-                output_l[i] *= final_volume * pan_l * phase_mult;
-                output_r[i] *= final_volume * pan_r * phase_mult;
+                output_l[i] *= final_volume * pan_l;
+                output_r[i] *= final_volume * pan_r;
             }
         }
 
@@ -8874,9 +9741,6 @@ impl PlaybackEngine {
         let static_playback_rate = clip.effective_playback_rate();
         let static_gain = clip.gain;
 
-        // Fade parameters
-        let fade_in_samples = (clip.fade_in * sample_rate) as i64;
-        let fade_out_samples = (clip.fade_out * sample_rate) as i64;
         let clip_duration_samples = (clip.duration * sample_rate) as i64;
 
         // Crossfade parameters (if applicable)
@@ -8913,8 +9777,7 @@ impl PlaybackEngine {
                 clip, audio, crossfade, start_sample, sample_rate,
                 output_l, output_r, frames, clip_start_sample,
                 source_sample_rate, rate_ratio, has_envelopes,
-                static_playback_rate, static_gain,
-                fade_in_samples, fade_out_samples, clip_duration_samples,
+                static_playback_rate, static_gain, clip_duration_samples,
                 xf_start_sample, xf_end_sample, is_clip_a,
                 source_offset_samples_f64,
                 clip2_resample_mode, &clip2_sinc_guard,
@@ -9062,23 +9925,11 @@ impl PlaybackEngine {
                 sample_r = fx_r;
             }
 
-            // Calculate fade envelope
-            let mut fade = 1.0;
-
-            // Fade in (only if not in crossfade or this is clip B)
-            if clip_relative_sample < fade_in_samples && fade_in_samples > 0 {
-                fade = clip_relative_sample as f64 / fade_in_samples as f64;
-                fade = fade * fade; // Quadratic curve
-            }
+            // Calculate fade envelope from the clip's fade shapes
+            let mut fade = clip.fade_gain_at(clip_relative_sample as u64, sample_rate);
 
-            // Fade out (only if not in crossfade or this is clip A)
-            let samples_from_end = clip_duration_samples - clip_relative_sample;
-            if samples_from_end < fade_out_samples && fade_out_samples > 0 {
-                let fade_out = samples_from_end as f64 / fade_out_samples as f64;
-                fade *= fade_out * fade_out;
-            }
-
-            // Apply crossfade envelope if within crossfade region
+            // Within a crossfade region the crossfade shape replaces the clip fades,
+            // so clip A's fade-out and clip B's fade-in don't stack
             if let Some(xf) = crossfade {
                 if playback_sample >= xf_start_sample && playback_sample < xf_end_sample {
                     // Calculate normalized position within crossfade (0.0 to 1.0)
@@ -9091,10 +9942,10 @@ impl PlaybackEngine {
                     // Apply the appropriate gain
                     if is_clip_a {
                         // Clip A is fading out
-                        fade *= fade_out_gain as f64;
+                        fade = fade_out_gain as f64;
                     } else {
                         // Clip B is fading in
-                        fade *= fade_in_gain as f64;
+                        fade = fade_in_gain as f64;
                     }
                 } else if is_clip_a && playback_sample >= xf_end_sample {
                     // Clip A after crossfade - silent
@@ -9157,8 +10008,6 @@ impl PlaybackEngine {
         has_envelopes: bool,
         static_playback_rate: f64,
         static_gain: f64,
-        fade_in_samples: i64,
-        fade_out_samples: i64,
         clip_duration_samples: i64,
         xf_start_sample: i64,
         xf_end_sample: i64,
@@ -9307,22 +10156,13 @@ impl PlaybackEngine {
                 pv_r[i] = fx_r;
 
                 // Calculate and store per-sample gain (fade + crossfade + gain envelope)
-                let mut fade = 1.0_f64;
-                if clip_relative_sample < fade_in_samples && fade_in_samples > 0 {
-                    let f = clip_relative_sample as f64 / fade_in_samples as f64;
-                    fade = f * f;
-                }
-                let samples_from_end = clip_duration_samples - clip_relative_sample;
-                if samples_from_end < fade_out_samples && fade_out_samples > 0 {
-                    let f = samples_from_end as f64 / fade_out_samples as f64;
-                    fade *= f * f;
-                }
+                let mut fade = clip.fade_gain_at(clip_relative_sample as u64, sample_rate);
                 if let Some(xf) = crossfade {
                     if playback_sample >= xf_start_sample && playback_sample < xf_end_sample {
                         let xf_t = (playback_sample - xf_start_sample) as f32
                             / (xf_end_sample - xf_start_sample) as f32;
                         let (fo, fi) = xf.shape.evaluate(xf_t);
-                        fade *= if is_clip_a { fo as f64 } else { fi as f64 };
+                        fade = if is_clip_a { fo as f64 } else { fi as f64 };
                     } else if (is_clip_a && playback_sample >= xf_end_sample)
                         || (!is_clip_a && playback_sample < xf_start_sample)
                     {
@@ -9615,10 +10455,55 @@ impl PlaybackEngine {
     /// Called from engine_start_playback() after cpal device config is resolved
     pub fn set_sample_rate(&self, sr: u32) {
         self.position.set_sample_rate(sr);
+        self.mono_compat_meter.write().set_sample_rate(sr as f64);
+        self.sidechain_router.write().set_sample_rate(sr as f64);
         log::info!("[PlaybackEngine] Sample rate updated to {} Hz", sr);
     }
 }
 
+/// Cache `seconds` of stereo sine tone and put it on a new track routed to `bus`
+/// (test fixture shared across the engine's test modules)
+#[cfg(test)]
+pub(crate) fn add_tone_track(
+    engine: &PlaybackEngine,
+    track_manager: &TrackManager,
+    name: &str,
+    freq: f64,
+    amplitude: f64,
+    seconds: f64,
+    bus: OutputBus,
+) -> u64 {
+    use crate::audio_import::ImportedAudio;
+
+    let sample_rate = engine.sample_rate();
+    let frames = (seconds * sample_rate as f64) as usize;
+    let samples: Vec<f32> = (0..frames)
+        .flat_map(|i| {
+            let phase = 2.0 * std::f64::consts::PI * freq * i as f64 / sample_rate as f64;
+            let s = (amplitude * phase.sin()) as f32;
+            [s, s]
+        })
+        .collect();
+    let source = format!("/virtual/{}.wav", name);
+    engine.cache().insert(
+        source.clone(),
+        Arc::new(ImportedAudio {
+            samples,
+            sample_rate,
+            channels: 2,
+            duration_secs: seconds,
+            sample_count: frames,
+            source_path: source.clone(),
+            name: name.to_string(),
+            bit_depth: None,
+            format: "wav".to_string(),
+        }),
+    );
+    let track_id = track_manager.create_track(name, 0, bus);
+    track_manager.create_clip(track_id, name, &source, 0.0, seconds, seconds);
+    track_id.0
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // TESTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -9641,6 +10526,255 @@ mod tests {
         assert!((pos.seconds() - 1.0).abs() < 0.001);
     }
 
+    #[test]
+    fn test_master_loopback_receives_master_output() {
+        let sample_rate = 48000;
+        let track_manager = Arc::new(TrackManager::new());
+        let engine = PlaybackEngine::new(Arc::clone(&track_manager), sample_rate);
+
+        // Two tone tracks summed through the Music bus and master, master at -6 dB
+        for (name, freq) in [("a", 440.0), ("b", 660.0)] {
+            add_tone_track(
+                &engine,
+                &track_manager,
+                name,
+                freq,
+                0.25,
+                1.0,
+                OutputBus::Music,
+            );
+        }
+        engine.set_master_volume(0.5);
+
+        let loopback = engine
+            .input_bus_manager()
+            .create_loopback(OutputBus::Master);
+        let music = engine.input_bus_manager().create_loopback(OutputBus::Music);
+        engine.play();
+
+        // Run past the output start ramp, which is applied after the capture point
+        let frames = 256;
+        let mut left = vec![0.0; frames];
+        let mut right = vec![0.0; frames];
+        let blocks = 16;
+        for _ in 0..blocks {
+            engine.process(&mut left, &mut right);
+        }
+        assert!(left.iter().any(|x| x.abs() > 0.05));
+
+        let bus = engine.input_bus_manager().get_bus(loopback).unwrap();
+        let (cap_l, cap_r) = bus.read_buffers().unwrap();
+        let cap_r = cap_r.unwrap();
+        for i in 0..frames {
+            assert!((cap_l[i] as f64 - left[i]).abs() < 1e-6);
+            assert!((cap_r[i] as f64 - right[i]).abs() < 1e-6);
+        }
+        // No PDC in this session, so the block is stamped with its own position
+        assert_eq!(bus.capture_position(), Some(((blocks - 1) * frames) as u64));
+
+        // The Music loopback is taken before the master gain
+        let (music_l, _) = engine
+            .input_bus_manager()
+            .get_bus(music)
+            .unwrap()
+            .read_buffers()
+            .unwrap();
+        let music_l: Vec<f64> = music_l.iter().map(|&x| x as f64).collect();
+        let rms = |x: &[f64]| (x.iter().map(|v| v * v).sum::<f64>() / x.len() as f64).sqrt();
+        let ratio = rms(&left) / rms(&music_l);
+        assert!((ratio - 0.5).abs() < 0.02, "master/music ratio {}", ratio);
+    }
+
+    #[test]
+    fn test_instrument_state_restored_on_load() {
+        use rf_plugin::PluginInstance;
+        use rf_plugin::internal::InternalPlugin;
+
+        let engine = PlaybackEngine::new(Arc::new(TrackManager::new()), 48000);
+        let load = || {
+            Box::new(InternalPlugin::load(std::path::Path::new("rf.utility.gain")).unwrap())
+                as Box<dyn PluginInstance>
+        };
+
+        let mut source = load();
+        source.set_parameter(0, 0.25).unwrap();
+        let saved = source.get_state().unwrap();
+
+        // Project load happens before the instrument is instantiated
+        engine.restore_instrument_plugin_state(7, saved.clone());
+        assert_eq!(engine.instrument_plugin_state(7), Some(saved.clone()));
+
+        assert!(engine.load_instrument_plugin(7, load()));
+        assert_eq!(engine.instrument_plugin_state(7), Some(saved));
+        assert!(engine.pending_instrument_states.read().is_empty());
+    }
+
+    #[test]
+    fn test_folder_bus_sums_children_through_folder_fader() {
+        // Render two tone children of folder 1000; None = no folder bus allocated
+        let render = |folder: Option<(f64, bool)>| -> Vec<f64> {
+            let sample_rate = 48000;
+            let track_manager = Arc::new(TrackManager::new());
+            let mut engine = PlaybackEngine::new(Arc::clone(&track_manager), sample_rate);
+            let manager = Arc::new(RwLock::new(GroupManager::new()));
+            engine.set_group_manager(Arc::clone(&manager));
+            manager.write().create_folder(1000, "Folder");
+
+            for (name, freq) in [("a", 440.0), ("b", 660.0)] {
+                let track_id = add_tone_track(
+                    &engine,
+                    &track_manager,
+                    name,
+                    freq,
+                    0.25,
+                    1.0,
+                    OutputBus::Music,
+                );
+                let mut gm = manager.write();
+                gm.folder_mut(1000).unwrap().add_child(track_id);
+            }
+            if let Some((volume_db, muted)) = folder {
+                let mut gm = manager.write();
+                let folder = gm.folder_mut(1000).unwrap();
+                folder.set_volume(volume_db);
+                folder.muted = muted;
+                drop(gm);
+                engine.sync_folder_buses();
+            }
+            engine.play();
+
+            let frames = 256;
+            let mut left = vec![0.0; frames];
+            let mut right = vec![0.0; frames];
+            for _ in 0..16 {
+                engine.process(&mut left, &mut right);
+            }
+            left
+        };
+
+        let direct = render(None);
+        let unity = render(Some((0.0, false)));
+        let attenuated = render(Some((-6.0, false)));
+        let muted = render(Some((0.0, true)));
+
+        assert!(direct.iter().any(|x| x.abs() > 0.05));
+        for i in 0..direct.len() {
+            assert!((unity[i] - direct[i]).abs() < 1e-9);
+        }
+        let rms = |x: &[f64]| (x.iter().map(|v| v * v).sum::<f64>() / x.len() as f64).sqrt();
+        let ratio_db = 20.0 * (rms(&attenuated) / rms(&unity)).log10();
+        assert!((ratio_db + 6.0).abs() < 0.05, "folder {} dB", ratio_db);
+        assert!(muted.iter().all(|x| *x == 0.0));
+    }
+
+    #[test]
+    fn test_voice_bus_ducks_music_bus() {
+        use crate::insert_chain::InsertProcessor;
+
+        // Music tone through a compressor on Music slot 0 keyed from the Voice
+        // bus; the Voice bus is panned hard left, so the right channel carries
+        // Music alone. Returns the right-channel rms of the last block.
+        let render = |voice_amp: f64, tap: SendTapPoint, voice_fader: f64| -> f64 {
+            let sample_rate = 48000;
+            let track_manager = Arc::new(TrackManager::new());
+            let engine = PlaybackEngine::new(Arc::clone(&track_manager), sample_rate);
+            for (name, freq, amplitude, bus) in [
+                ("music", 440.0, 0.25, OutputBus::Music),
+                ("voice", 220.0, voice_amp, OutputBus::Voice),
+            ] {
+                add_tone_track(&engine, &track_manager, name, freq, amplitude, 1.0, bus);
+            }
+            engine.set_bus_pan(3, -1.0);
+            engine.set_bus_pan_right(3, -1.0);
+            engine.set_bus_volume(3, voice_fader);
+
+            let mut comp = crate::dsp_wrappers::CompressorWrapper::new(sample_rate as f64);
+            comp.set_param(0, -30.0);
+            comp.set_param(1, 10.0);
+            comp.set_param(2, 1.0);
+            assert!(engine.load_bus_insert(1, 0, Box::new(comp)));
+            engine.add_sidechain_bus_route(3, tap, PlaybackEngine::bus_insert_processor_id(1, 0));
+            engine.play();
+
+            let frames = 256;
+            let mut left = vec![0.0; frames];
+            let mut right = vec![0.0; frames];
+            for _ in 0..32 {
+                engine.process(&mut left, &mut right);
+            }
+            (right.iter().map(|x| x * x).sum::<f64>() / frames as f64).sqrt()
+        };
+
+        let quiet = render(0.001, SendTapPoint::PostFader, 1.0);
+        let ducked = render(0.9, SendTapPoint::PostFader, 1.0);
+        let fader_down = render(0.9, SendTapPoint::PostFader, 0.0);
+        let pre_fader = render(0.9, SendTapPoint::PreFader, 0.0);
+
+        assert!(quiet > 0.1, "music rms {}", quiet);
+        let db = |x: f64| 20.0 * (x / quiet).log10();
+        assert!(db(ducked) < -6.0, "ducked {} dB", db(ducked));
+        // Post-fader key follows the voice fader, pre-fader key ignores it
+        assert!(db(fader_down).abs() < 0.5, "{} dB", db(fader_down));
+        assert!(db(pre_fader) < -6.0, "{} dB", db(pre_fader));
+    }
+
+    #[test]
+    fn test_midi_learn_spans_param_plain_range() {
+        let engine = PlaybackEngine::new(Arc::new(TrackManager::new()), 48000);
+        assert!(engine.load_track_insert(
+            5,
+            0,
+            Box::new(crate::dsp_wrappers::ProEqWrapper::new(48000.0))
+        ));
+
+        // Band 0 frequency: 10 Hz..30 kHz
+        engine.start_midi_learn(5, 0, 0);
+        engine.midi_learn().handle_cc(0, 21, 32);
+        let (_, mapping) = engine.midi_learn().mappings()[0];
+        assert_eq!((mapping.min, mapping.max), (10.0, 30000.0));
+
+        // First value jumps straight to the target
+        engine.apply_midi_learn(256);
+        let expected = 10.0 + 29990.0 * 32.0 / 127.0;
+        let actual = engine.get_track_insert_param(5, 0, 0);
+        assert!(
+            (actual - expected).abs() < 1e-6,
+            "expected {expected} Hz, got {actual} Hz"
+        );
+    }
+
+    #[test]
+    fn test_bus_sidechain_latency_follows_inserts() {
+        use crate::insert_chain::InsertProcessor;
+
+        let engine = PlaybackEngine::new(Arc::new(TrackManager::new()), 48000);
+        let keyed = PlaybackEngine::bus_insert_processor_id(1, 1);
+        let route = engine.add_sidechain_bus_route(3, SendTapPoint::PostFader, keyed);
+        let key_delay = || {
+            engine
+                .sidechain_router()
+                .read()
+                .get_route(route)
+                .unwrap()
+                .key_delay
+        };
+        assert_eq!(key_delay(), 0);
+
+        // A lookahead compressor ahead of the keyed slot delays the key to match
+        let mut lookahead = crate::dsp_wrappers::CompressorWrapper::new(48000.0);
+        lookahead.set_param(14, 5.0);
+        let latency = lookahead.latency();
+        assert!(latency > 0);
+        assert!(engine.load_bus_insert(1, 0, Box::new(lookahead)));
+        assert_eq!(key_delay(), latency);
+
+        engine.set_bus_insert_bypass(1, 0, true);
+        assert_eq!(key_delay(), 0);
+        engine.set_bus_insert_bypass(1, 0, false);
+        assert!(engine.unload_bus_insert(1, 0).is_some());
+        assert_eq!(key_delay(), 0);
+    }
+
     #[test]
     fn test_playback_loop() {
         let pos = PlaybackPosition::new(48000);
@@ -9775,6 +10909,68 @@ mod tests {
             "reset must queue a pending request for the audio thread to drain");
     }
 
+    #[test]
+    fn test_input_section_automation() {
+        use crate::automation::{AutomationChange, ParamId};
+
+        let manager = Arc::new(crate::track_manager::TrackManager::new());
+        let track_id = manager.create_track("Input", 0xFF0000FF, OutputBus::Master);
+        let engine = PlaybackEngine::new(Arc::clone(&manager), 48000);
+
+        let change = |param_id: ParamId, value: f64| AutomationChange {
+            sample_offset: 0,
+            param_id,
+            value,
+        };
+        engine.apply_automation_change(&change(ParamId::track_input_trim(track_id.0), 0.75));
+        engine.apply_automation_change(&change(ParamId::track_phase_invert(track_id.0), 1.0));
+        engine.apply_automation_change(&change(ParamId::track_mono_sum(track_id.0), 1.0));
+
+        let track = manager.get_track(track_id).unwrap();
+        assert!((track.input_trim_db - 12.0).abs() < 1e-9);
+        assert!(track.phase_inverted);
+        assert!(track.mono_sum);
+    }
+
+    #[test]
+    fn test_clip_linear_fade_in_ramps_to_clip_gain() {
+        use crate::track_manager::CrossfadeCurve;
+
+        let manager = Arc::new(crate::track_manager::TrackManager::new());
+        let track_id = manager.create_track("Fade", 0xFF0000FF, OutputBus::Master);
+        let track = manager.get_track(track_id).unwrap();
+        let engine = PlaybackEngine::new(Arc::clone(&manager), 48000);
+
+        let audio = ImportedAudio::new_mono(vec![1.0; 48000], 48000, "fade_dc.wav");
+        let mut clip = Clip::new(track_id, "DC", "fade_dc.wav", 0.0, 1.0);
+        clip.fade_in = 0.1;
+        clip.fade_in_curve = CrossfadeCurve::Linear;
+        clip.set_gain_db(-6.0);
+        let gain = clip.gain;
+
+        let mut out_l = vec![0.0; 9600];
+        let mut out_r = vec![0.0; 9600];
+        engine.process_clip_with_crossfade(
+            &clip, &track, &audio, None, 0, 48000.0, &mut out_l, &mut out_r,
+        );
+
+        // 100ms at 48kHz = 4800 samples from silence to clip gain
+        assert!(out_l[0].abs() < 1e-9);
+        for n in [1200, 2400, 3600, 4799] {
+            let expected = gain * n as f64 / 4800.0;
+            assert!(
+                (out_l[n] - expected).abs() < 1e-3,
+                "sample {n}: {} vs {expected}",
+                out_l[n]
+            );
+            assert!(out_l[n] > out_l[n - 1]);
+        }
+        for n in [4800, 6000, 9599] {
+            assert!((out_l[n] - gain).abs() < 1e-3, "sample {n}: {}", out_l[n]);
+        }
+        assert_eq!(out_l, out_r);
+    }
+
     /// Calling reset twice without an audio block in between coalesces into
     /// a single drain — the flag is sticky-true, not a counter.
     #[test]
@@ -9789,4 +10985,12 @@ mod tests {
         assert!(!engine.lufs_integrated_reset_pending.swap(false, Ordering::AcqRel),
             "second drain must observe a clean flag");
     }
+
+    #[test]
+    fn test_set_sample_rate_updates_sidechain_router() {
+        let engine = PlaybackEngine::new(Arc::new(crate::track_manager::TrackManager::new()), 48000);
+        engine.set_sample_rate(96000);
+        assert_eq!(engine.sample_rate(), 96000);
+        assert_eq!(engine.sidechain_router().read().sample_rate(), 96000.0);
+    }
 }
@@ -154,6 +154,12 @@ pub enum RoutingCommand {
         to: ChannelId,
         pre_fader: bool,
     },
+    /// Add feedback send (delivered one block late, may close a loop)
+    AddFeedbackSend {
+        from: ChannelId,
+        to: ChannelId,
+        pre_fader: bool,
+    },
     /// Remove send
     RemoveSend { from: ChannelId, send_index: usize },
     /// Set send level
@@ -436,7 +442,7 @@ impl ChannelKind {
 
 /// Pan mode for stereo channels
 /// Determines how panning is applied in the routing channel
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum PanMode {
     /// Standard single-knob pan (mono or balance-style)
     /// Routing channel applies pan normally
@@ -536,6 +542,10 @@ pub struct SendConfig {
     pub tap_point: SendTapPoint,
     /// Is send enabled
     pub enabled: bool,
+    /// Feedback send: signal reaches the destination one block later,
+    /// so the send may route back into its own source chain
+    #[serde(default)]
+    pub feedback: bool,
 }
 
 impl SendConfig {
@@ -547,6 +557,7 @@ impl SendConfig {
             pan: 0.0,
             tap_point: SendTapPoint::PostFader,
             enabled: true,
+            feedback: false,
         }
     }
 
@@ -558,6 +569,14 @@ impl SendConfig {
         }
     }
 
+    /// Create feedback send (one block of latency breaks the loop)
+    pub fn feedback(destination: ChannelId) -> Self {
+        Self {
+            feedback: true,
+            ..Self::new(destination)
+        }
+    }
+
     /// Get linear gain
     pub fn gain(&self) -> f64 {
         if self.level_db <= -60.0 {
@@ -639,6 +658,10 @@ pub struct Channel {
     postfader_left: Vec<Sample>,
     postfader_right: Vec<Sample>,
 
+    // Feedback sends (summed this block, consumed as input next block)
+    feedback_left: Vec<Sample>,
+    feedback_right: Vec<Sample>,
+
     // PDC (Plugin Delay Compensation)
     /// Per-channel delay buffer for routing-level PDC
     pdc_buffer: ChannelPdcBuffer,
@@ -716,6 +739,8 @@ impl Channel {
             prefader_right: vec![0.0; block_size],
             postfader_left: vec![0.0; block_size],
             postfader_right: vec![0.0; block_size],
+            feedback_left: vec![0.0; block_size],
+            feedback_right: vec![0.0; block_size],
             // PDC buffer with max 1 second delay at 48kHz
             pdc_buffer: ChannelPdcBuffer::new(MAX_PDC_DELAY),
             own_latency: 0,
@@ -898,6 +923,15 @@ impl Channel {
         self.sends.push(send);
     }
 
+    /// Add feedback send (one block delayed)
+    pub fn add_feedback_send(&mut self, destination: ChannelId, pre_fader: bool) {
+        let mut send = SendConfig::feedback(destination);
+        if pre_fader {
+            send.tap_point = SendTapPoint::PreFader;
+        }
+        self.sends.push(send);
+    }
+
     /// Remove send
     pub fn remove_send(&mut self, index: usize) {
         if index < self.sends.len() {
@@ -911,6 +945,27 @@ impl Channel {
         self.input_right.fill(0.0);
     }
 
+    /// Move feedback received during the previous block into the input buffer
+    pub fn take_feedback_input(&mut self) {
+        let len = self.input_left.len().min(self.feedback_left.len());
+        for i in 0..len {
+            self.input_left[i] += self.feedback_left[i];
+            self.input_right[i] += self.feedback_right[i];
+        }
+        self.feedback_left.fill(0.0);
+        self.feedback_right.fill(0.0);
+    }
+
+    /// Add to feedback buffer (consumed at the start of the next block)
+    pub fn add_to_feedback(&mut self, left: &[Sample], right: &[Sample]) {
+        for (i, (&l, &r)) in left.iter().zip(right.iter()).enumerate() {
+            if i < self.feedback_left.len() {
+                self.feedback_left[i] += l;
+                self.feedback_right[i] += r;
+            }
+        }
+    }
+
     /// Add to input buffer (for summing from children)
     pub fn add_to_input(&mut self, left: &[Sample], right: &[Sample]) {
         for (i, (&l, &r)) in left.iter().zip(right.iter()).enumerate() {
@@ -1106,6 +1161,8 @@ impl Channel {
         self.prefader_right.resize(block_size, 0.0);
         self.postfader_left.resize(block_size, 0.0);
         self.postfader_right.resize(block_size, 0.0);
+        self.feedback_left.resize(block_size, 0.0);
+        self.feedback_right.resize(block_size, 0.0);
 
         // Recreate plugin chain with new block size
         if self.plugin_chain.is_some() {
@@ -1131,8 +1188,9 @@ impl Channel {
 /// Routing error types
 #[derive(Debug, Clone)]
 pub enum RoutingError {
-    /// Connection would create feedback loop
-    WouldCreateCycle { from: ChannelId, to: ChannelId },
+    /// Connection would create feedback loop without a feedback send to break it.
+    /// `path` starts and ends at the source of the rejected connection.
+    FeedbackLoop { path: Vec<ChannelId> },
     /// Channel not found
     ChannelNotFound(ChannelId),
     /// Cannot route to self
@@ -1255,6 +1313,15 @@ impl RoutingCommandSender {
         })
     }
 
+    /// Add feedback send (one block delayed)
+    pub fn add_feedback_send(&mut self, from: ChannelId, to: ChannelId, pre_fader: bool) -> bool {
+        self.send(RoutingCommand::AddFeedbackSend {
+            from,
+            to,
+            pre_fader,
+        })
+    }
+
     /// Remove send from channel
     pub fn remove_send(&mut self, from: ChannelId, send_index: usize) -> bool {
         self.send(RoutingCommand::RemoveSend { from, send_index })
@@ -1396,11 +1463,8 @@ impl RoutingGraph {
             }
 
             // Check for cycle
-            if self.would_create_cycle(id, to_id) {
-                return Err(RoutingError::WouldCreateCycle {
-                    from: id,
-                    to: to_id,
-                });
+            if let Some(path) = self.feedback_path(id, to_id) {
+                return Err(RoutingError::FeedbackLoop { path });
             }
         }
 
@@ -1420,6 +1484,44 @@ impl RoutingGraph {
         to: ChannelId,
         pre_fader: bool,
     ) -> Result<(), RoutingError> {
+        self.validate_send(from, to)?;
+
+        // Check for cycle
+        if let Some(path) = self.feedback_path(from, to) {
+            return Err(RoutingError::FeedbackLoop { path });
+        }
+
+        // Add send
+        if let Some(channel) = self.channels.get_mut(&from) {
+            channel.add_send(to, pre_fader);
+            self.dirty.store(true, Ordering::Release);
+        }
+
+        Ok(())
+    }
+
+    /// Add feedback send with validation
+    ///
+    /// The send is delivered one block late, which is what makes it legal to
+    /// route back into the source chain (no cycle check is performed).
+    pub fn add_feedback_send(
+        &mut self,
+        from: ChannelId,
+        to: ChannelId,
+        pre_fader: bool,
+    ) -> Result<(), RoutingError> {
+        self.validate_send(from, to)?;
+
+        if let Some(channel) = self.channels.get_mut(&from) {
+            channel.add_feedback_send(to, pre_fader);
+            self.dirty.store(true, Ordering::Release);
+        }
+
+        Ok(())
+    }
+
+    /// Common send endpoint checks
+    fn validate_send(&self, from: ChannelId, to: ChannelId) -> Result<(), RoutingError> {
         if from == to {
             return Err(RoutingError::SelfReference(from));
         }
@@ -1432,46 +1534,53 @@ impl RoutingGraph {
             return Err(RoutingError::ChannelNotFound(to));
         }
 
-        // Check for cycle
-        if self.would_create_cycle(from, to) {
-            return Err(RoutingError::WouldCreateCycle { from, to });
-        }
-
-        // Add send
-        if let Some(channel) = self.channels.get_mut(&from) {
-            channel.add_send(to, pre_fader);
-            self.dirty.store(true, Ordering::Release);
-        }
-
         Ok(())
     }
 
-    /// Check if adding edge would create cycle (DFS)
-    fn would_create_cycle(&self, from: ChannelId, to: ChannelId) -> bool {
-        // Check if 'from' is reachable from 'to' (would create cycle)
+    /// Find the loop that adding edge `from -> to` would close (DFS)
+    ///
+    /// Returns the path `from -> to -> ... -> from`, or `None` if `from` is not
+    /// reachable from `to`. Feedback sends are skipped since they are delayed.
+    fn feedback_path(&self, from: ChannelId, to: ChannelId) -> Option<Vec<ChannelId>> {
+        let mut parent: HashMap<ChannelId, ChannelId> = HashMap::new();
         let mut visited = HashSet::new();
         let mut stack = vec![to];
 
         while let Some(current) = stack.pop() {
             if current == from {
-                return true;
+                // Walk back to 'to', then prepend the new edge
+                let mut path = vec![from];
+                let mut node = from;
+                while let Some(&prev) = parent.get(&node) {
+                    path.push(prev);
+                    node = prev;
+                }
+                path.push(from);
+                path.reverse();
+                return Some(path);
             }
 
             if visited.insert(current)
                 && let Some(channel) = self.channels.get(&current)
             {
                 // Check output
-                if let Some(target) = channel.output.target_channel() {
-                    stack.push(target);
-                }
-                // Check sends
-                for send in &channel.sends {
-                    stack.push(send.destination);
+                let targets = channel.output.target_channel().into_iter().chain(
+                    channel
+                        .sends
+                        .iter()
+                        .filter(|s| !s.feedback)
+                        .map(|s| s.destination),
+                );
+                for target in targets {
+                    if !visited.contains(&target) {
+                        parent.entry(target).or_insert(current);
+                        stack.push(target);
+                    }
                 }
             }
         }
 
-        false
+        None
     }
 
     /// Recompute processing order using Kahn's algorithm (topological sort)
@@ -1491,7 +1600,8 @@ impl RoutingGraph {
             if let Some(target) = channel.output.target_channel() {
                 *in_degree.entry(target).or_insert(0) += 1;
             }
-            for send in &channel.sends {
+            // Feedback sends are delayed by a block and impose no ordering
+            for send in channel.sends.iter().filter(|s| !s.feedback) {
                 *in_degree.entry(send.destination).or_insert(0) += 1;
             }
         }
@@ -1520,7 +1630,7 @@ impl RoutingGraph {
                     }
 
                 // Process sends
-                for send in &channel.sends {
+                for send in channel.sends.iter().filter(|s| !s.feedback) {
                     if let Some(deg) = in_degree.get_mut(&send.destination) {
                         *deg -= 1;
                         if *deg == 0 {
@@ -1555,9 +1665,10 @@ impl RoutingGraph {
         self.global_solo_active
             .store(solo_active, Ordering::Release);
 
-        // Clear all inputs
+        // Clear all inputs, then pick up last block's feedback sends
         for channel in self.channels.values_mut() {
             channel.clear_input();
+            channel.take_feedback_input();
         }
 
         // Process in topological order
@@ -1599,20 +1710,21 @@ impl RoutingGraph {
             // Third pass: process sends with proper tap points
             if num_sends > 0 {
                 // Collect send info with tap points (small stack allocation, max ~8 sends typical)
-                let mut send_info: [(ChannelId, f64, SendTapPoint); 16] =
-                    [(ChannelId(0), 0.0, SendTapPoint::PostFader); 16];
+                let mut send_info: [(ChannelId, f64, SendTapPoint, bool); 16] =
+                    [(ChannelId(0), 0.0, SendTapPoint::PostFader, false); 16];
                 let mut send_count = 0;
 
                 if let Some(channel) = self.channels.get(&id) {
                     for send in channel.sends.iter().filter(|s| s.enabled).take(16) {
-                        send_info[send_count] = (send.destination, send.gain(), send.tap_point);
+                        send_info[send_count] =
+                            (send.destination, send.gain(), send.tap_point, send.feedback);
                         send_count += 1;
                     }
                 }
 
                 // Apply sends using appropriate tap point buffer
                 for i in 0..send_count {
-                    let (dest_id, gain, tap_point) = send_info[i];
+                    let (dest_id, gain, tap_point, feedback) = send_info[i];
 
                     // Get source buffer based on tap point
                     let (src_l, src_r) = if let Some(channel) = self.channels.get(&id) {
@@ -1629,7 +1741,11 @@ impl RoutingGraph {
                     }
 
                     if let Some(target) = self.channels.get_mut(&dest_id) {
-                        target.add_to_input(&self.scratch_send_l, &self.scratch_send_r);
+                        if feedback {
+                            target.add_to_feedback(&self.scratch_send_l, &self.scratch_send_r);
+                        } else {
+                            target.add_to_input(&self.scratch_send_l, &self.scratch_send_r);
+                        }
                     }
                 }
             }
@@ -1809,6 +1925,185 @@ impl Default for RoutingGraph {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// SERIALIZATION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Persisted channel routing and mixer state
+///
+/// DSP strip and plugin chain state are saved separately with the project.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializedChannel {
+    pub id: ChannelId,
+    pub kind: ChannelKind,
+    pub name: String,
+    pub color: u32,
+    pub output: OutputDestination,
+    pub sends: Vec<SendConfig>,
+    pub fader_db: f64,
+    pub pan: f64,
+    #[serde(default)]
+    pub pan_mode: PanMode,
+    #[serde(default)]
+    pub muted: bool,
+    #[serde(default)]
+    pub soloed: bool,
+}
+
+/// Persisted routing graph (channels sorted by ID, master first)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SerializedRoutingGraph {
+    pub channels: Vec<SerializedChannel>,
+    /// Next channel ID to allocate (keeps IDs stable across reloads)
+    pub next_id: u32,
+}
+
+impl Serialize for RoutingGraph {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_serialized().serialize(serializer)
+    }
+}
+
+impl RoutingGraph {
+    /// Snapshot channels, sends and output destinations for saving
+    pub fn to_serialized(&self) -> SerializedRoutingGraph {
+        let mut channels: Vec<SerializedChannel> = self
+            .channels
+            .values()
+            .map(|c| SerializedChannel {
+                id: c.id,
+                kind: c.kind,
+                name: c.name.clone(),
+                color: c.color,
+                output: c.output,
+                sends: c.sends.clone(),
+                fader_db: c.fader_db,
+                pan: c.pan,
+                pan_mode: c.pan_mode,
+                muted: c.is_muted(),
+                soloed: c.is_soloed(),
+            })
+            .collect();
+        channels.sort_by_key(|c| c.id.0);
+
+        SerializedRoutingGraph {
+            channels,
+            next_id: self.next_id.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Rebuild a graph from saved data
+    ///
+    /// Rejects duplicate or misplaced master channels, outputs/sends to
+    /// channels that do not exist, self-routing, and non-feedback cycles.
+    pub fn from_serialized(
+        data: &SerializedRoutingGraph,
+        block_size: usize,
+        sample_rate: f64,
+    ) -> Result<Self, RoutingError> {
+        let mut graph = Self::with_sample_rate(block_size, sample_rate);
+
+        // Create channels first so routing can reference any of them
+        for saved in &data.channels {
+            let is_master_kind = saved.kind == ChannelKind::Master;
+            if saved.id.is_master() != is_master_kind {
+                return Err(RoutingError::InvalidConnection {
+                    from: saved.id,
+                    to: saved.id,
+                    reason: "Master kind must use the master channel ID",
+                });
+            }
+            if !saved.id.is_master() {
+                if graph.channels.contains_key(&saved.id) {
+                    return Err(RoutingError::InvalidConnection {
+                        from: saved.id,
+                        to: saved.id,
+                        reason: "Duplicate channel ID",
+                    });
+                }
+                let channel = Channel::with_sample_rate(
+                    saved.id,
+                    saved.kind,
+                    &saved.name,
+                    block_size,
+                    sample_rate,
+                );
+                graph.channels.insert(saved.id, channel);
+                graph.channel_count.fetch_add(1, Ordering::Release);
+            }
+
+            let channel = graph.restored_mut(saved.id);
+            channel.name = saved.name.clone();
+            channel.color = saved.color;
+            channel.set_fader(saved.fader_db);
+            channel.set_pan(saved.pan);
+            channel.set_pan_mode(saved.pan_mode);
+            channel.set_mute(saved.muted);
+            channel.set_solo(saved.soloed);
+        }
+
+        // Validate references, then apply routing
+        for saved in &data.channels {
+            match saved.output {
+                OutputDestination::Channel(_) if saved.id.is_master() => {
+                    return Err(RoutingError::InvalidConnection {
+                        from: saved.id,
+                        to: ChannelId::NONE,
+                        reason: "Cannot change master output",
+                    });
+                }
+                OutputDestination::Channel(to) if to == saved.id => {
+                    return Err(RoutingError::SelfReference(to));
+                }
+                OutputDestination::Channel(to) if !graph.channels.contains_key(&to) => {
+                    return Err(RoutingError::ChannelNotFound(to));
+                }
+                _ => {}
+            }
+            for send in &saved.sends {
+                graph.validate_send(saved.id, send.destination)?;
+            }
+        }
+        for saved in &data.channels {
+            let channel = graph.restored_mut(saved.id);
+            channel.output = saved.output;
+            channel.sends = saved.sends.clone();
+        }
+
+        // Saved data may have been edited by hand — reject cycles
+        for saved in &data.channels {
+            let targets = saved.output.target_channel().into_iter().chain(
+                saved
+                    .sends
+                    .iter()
+                    .filter(|s| !s.feedback)
+                    .map(|s| s.destination),
+            );
+            for to in targets {
+                if let Some(path) = graph.feedback_path(saved.id, to) {
+                    return Err(RoutingError::FeedbackLoop { path });
+                }
+            }
+        }
+
+        let max_id = data.channels.iter().map(|c| c.id.0).max().unwrap_or(0);
+        graph
+            .next_id
+            .store(data.next_id.max(max_id + 1), Ordering::Relaxed);
+        graph.dirty.store(true, Ordering::Release);
+        graph.update_processing_order();
+
+        Ok(graph)
+    }
+
+    /// Channel that was just created or restored by `from_serialized`
+    fn restored_mut(&mut self, id: ChannelId) -> &mut Channel {
+        self.channels
+            .get_mut(&id)
+            .expect("channel created before restore")
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // ROUTING GRAPH RT (Real-Time version with command queue)
 // ═══════════════════════════════════════════════════════════════════════════
@@ -1899,6 +2194,18 @@ impl RoutingGraphRT {
                 }
             }
 
+            RoutingCommand::AddFeedbackSend {
+                from,
+                to,
+                pre_fader,
+            } => {
+                if let Err(e) = self.graph.add_feedback_send(from, to, pre_fader) {
+                    let _ = self.response_tx.push(RoutingResponse::Error {
+                        message: format!("{:?}", e),
+                    });
+                }
+            }
+
             RoutingCommand::RemoveSend { from, send_index } => {
                 if let Some(channel) = self.graph.get_mut(from) {
                     channel.remove_send(send_index);
@@ -2272,6 +2579,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_serialized_round_trip() {
+        let mut graph = RoutingGraph::new(256);
+        let drums = graph.create_bus("Drums");
+        let verb = graph.create_aux("Verb");
+        let kick = graph.create_channel(ChannelKind::Audio, Some("Kick"));
+        graph
+            .set_output(kick, OutputDestination::Channel(drums))
+            .unwrap();
+        graph.add_send(kick, verb, true).unwrap();
+        graph.get_mut(kick).unwrap().sends[0].tap_point = SendTapPoint::PostPan;
+        graph.get_mut(kick).unwrap().set_fader(-3.5);
+
+        let json = serde_json::to_string(&graph).unwrap();
+        let data: SerializedRoutingGraph = serde_json::from_str(&json).unwrap();
+        let mut restored = RoutingGraph::from_serialized(&data, 256, 48000.0).unwrap();
+
+        assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+        let kick_ch = restored.get(kick).unwrap();
+        assert_eq!(kick_ch.output, OutputDestination::Channel(drums));
+        assert_eq!(kick_ch.sends[0].destination, verb);
+        assert_eq!(kick_ch.sends[0].tap_point, SendTapPoint::PostPan);
+        assert_eq!(restored.channel_count(), 3);
+
+        // New channels don't collide with restored IDs
+        let next = restored.create_bus("Next");
+        assert!(next.0 > kick.0);
+
+        // A hand-edited cycle is rejected on load
+        let mut cyclic = data.clone();
+        let drums_saved = cyclic.channels.iter_mut().find(|c| c.id == drums).unwrap();
+        drums_saved.output = OutputDestination::Channel(kick);
+        assert!(matches!(
+            RoutingGraph::from_serialized(&cyclic, 256, 48000.0),
+            Err(RoutingError::FeedbackLoop { .. })
+        ));
+    }
+
     #[test]
     fn test_cycle_prevention() {
         let mut graph = RoutingGraph::new(256);
@@ -2286,7 +2631,65 @@ mod tests {
 
         // B -> A (would create cycle)
         let result = graph.set_output(bus_b, OutputDestination::Channel(bus_a));
-        assert!(matches!(result, Err(RoutingError::WouldCreateCycle { .. })));
+        assert!(matches!(result, Err(RoutingError::FeedbackLoop { .. })));
+    }
+
+    #[test]
+    fn test_feedback_loop_rejected_with_path() {
+        let mut graph = RoutingGraph::new(256);
+
+        let a = graph.create_bus("A");
+        let b = graph.create_bus("B");
+        let c = graph.create_bus("C");
+
+        // A -> B -> C is a chain, not a loop
+        graph.set_output(a, OutputDestination::Channel(b)).unwrap();
+        graph.add_send(b, c, false).unwrap();
+
+        // A -> B -> A closes a loop
+        match graph.add_send(b, a, false) {
+            Err(RoutingError::FeedbackLoop { path }) => assert_eq!(path, vec![b, a, b]),
+            other => panic!("expected FeedbackLoop, got {:?}", other),
+        }
+
+        // Longer loop through the send: C -> A -> B -> C
+        match graph.set_output(c, OutputDestination::Channel(a)) {
+            Err(RoutingError::FeedbackLoop { path }) => assert_eq!(path, vec![c, a, b, c]),
+            other => panic!("expected FeedbackLoop, got {:?}", other),
+        }
+        assert_eq!(graph.get(b).unwrap().sends.len(), 1);
+    }
+
+    #[test]
+    fn test_feedback_send_delays_one_block() {
+        let mut graph = RoutingGraph::new(64);
+
+        let a = graph.create_bus("A");
+        let b = graph.create_bus("B");
+        graph.set_output(a, OutputDestination::Channel(b)).unwrap();
+
+        // Explicit feedback send may close the loop
+        graph.add_feedback_send(b, a, false).unwrap();
+        graph.get_mut(b).unwrap().sends[0].level_db = 0.0;
+        graph.update_processing_order();
+        assert!(graph.processing_order.contains(&a));
+        assert!(graph.processing_order.contains(&b));
+
+        // A plain send on top of it is still rejected
+        assert!(matches!(
+            graph.add_send(b, a, false),
+            Err(RoutingError::FeedbackLoop { .. })
+        ));
+
+        // Seed B with signal at the start of the first block
+        graph.get_mut(b).unwrap().add_to_feedback(&[1.0; 64], &[1.0; 64]);
+        graph.process();
+        assert!(graph.get(b).unwrap().output().0[0].abs() > 0.0);
+        // Nothing fed back to A within the same block
+        assert_eq!(graph.get(a).unwrap().output().0[0], 0.0);
+
+        graph.process();
+        assert!(graph.get(a).unwrap().output().0[0].abs() > 0.0);
     }
 
     #[test]
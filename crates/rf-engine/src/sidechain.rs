@@ -1,7 +1,7 @@
 //! Sidechain Routing System
 //!
 //! Provides professional sidechain routing for dynamics processors:
-//! - External sidechain input selection
+//! - External sidechain input selection (track or bus/return key)
 //! - Internal sidechain (from channel signal)
 //! - Sidechain filtering (HPF/LPF)
 //! - Sidechain monitoring
@@ -12,7 +12,9 @@
 //! - De-essing: HPF filtered sidechain for vocal sibilance
 //! - Pumping: Rhythmic sidechain from synth pattern
 //! - M/S Sidechain: Compress based on mid or side only
+//! - Bus keying: Whole drum bus ducks the music bus
 
+use crate::send_return::SendTapPoint;
 use rf_core::Sample;
 use rf_dsp::biquad::{BiquadCoeffs, BiquadTDF2};
 use rf_dsp::smoothing::{SmoothedParam, SmoothingType};
@@ -29,8 +31,10 @@ pub enum SidechainSource {
     /// Internal sidechain (uses the input signal)
     #[default]
     Internal,
-    /// External sidechain from another track/bus
+    /// External sidechain from another track
     External(u32),
+    /// External sidechain from a summed bus or return
+    Bus(u32),
     /// Mid component of stereo signal
     Mid,
     /// Side component of stereo signal
@@ -243,7 +247,7 @@ impl SidechainInput {
             // Get base signal based on source
             let (base_left, base_right) = match self.source {
                 SidechainSource::Internal => (internal_left[i], internal_right[i]),
-                SidechainSource::External(_) => {
+                SidechainSource::External(_) | SidechainSource::Bus(_) => {
                     // Mix internal and external based on mix parameter
                     let mix = self.mix.next_value();
                     let int_left = internal_left[i];
@@ -407,6 +411,16 @@ impl SidechainInput {
 /// Sidechain routing point ID
 pub type SidechainId = u32;
 
+/// Kind of signal a sidechain route listens to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SidechainSourceKind {
+    /// Single track
+    #[default]
+    Track,
+    /// Summed bus or return
+    Bus,
+}
+
 /// Sidechain routing entry
 #[derive(Debug, Clone)]
 pub struct SidechainRoute {
@@ -414,12 +428,23 @@ pub struct SidechainRoute {
     pub id: SidechainId,
     /// Source track/bus ID
     pub source_id: u32,
-    /// Source tap point (pre/post fader)
-    pub pre_fader: bool,
+    /// Whether `source_id` names a track or a bus/return
+    pub source_kind: SidechainSourceKind,
+    /// Source tap point (pre/post fader, post pan)
+    pub tap_point: SendTapPoint,
     /// Destination processor ID
     pub dest_processor_id: u32,
     /// Is active
     pub active: bool,
+    /// Delay applied to the key so it lines up with the destination (PDC)
+    pub key_delay: usize,
+    /// Samples the key arrives after the destination audio; the graph must
+    /// delay the destination by this much to stay aligned
+    pub key_lateness: usize,
+    /// Filter applied to the key before detection
+    pub key_filter: SidechainFilterMode,
+    /// Key filter frequency (HPF/LPF cutoff, band-pass center)
+    pub key_filter_freq: f64,
 }
 
 /// Sidechain router for the entire project
@@ -432,55 +457,247 @@ pub struct SidechainRouter {
     next_id: AtomicU32,
     /// Buffer storage for each source
     source_buffers: Vec<SidechainBuffer>,
+    /// PDC delay line per route
+    route_delays: Vec<RouteDelay>,
+    /// Reported latency at each source tap
+    source_latencies: Vec<(SidechainSourceKind, u32, usize)>,
+    /// Reported latency at each destination processor input
+    processor_latencies: Vec<(u32, usize)>,
     /// Block size
     block_size: usize,
+    /// Sample rate (for key filters)
+    sample_rate: f64,
 }
 
 /// Buffer for a single sidechain source
 struct SidechainBuffer {
+    source_kind: SidechainSourceKind,
     source_id: u32,
+    tap_point: SendTapPoint,
     left: Vec<Sample>,
     right: Vec<Sample>,
     valid: bool,
 }
 
+/// Key delay line for one route
+struct RouteDelay {
+    route_id: SidechainId,
+    line_left: Vec<Sample>,
+    line_right: Vec<Sample>,
+    pos: usize,
+    out_left: Vec<Sample>,
+    out_right: Vec<Sample>,
+    filter: KeyFilter,
+}
+
+impl RouteDelay {
+    fn new(route_id: SidechainId, block_size: usize, sample_rate: f64) -> Self {
+        Self {
+            route_id,
+            line_left: Vec::new(),
+            line_right: Vec::new(),
+            pos: 0,
+            out_left: vec![0.0; block_size],
+            out_right: vec![0.0; block_size],
+            filter: KeyFilter::new(sample_rate),
+        }
+    }
+
+    fn set_delay(&mut self, delay: usize) {
+        if delay != self.line_left.len() {
+            self.line_left = vec![0.0; delay];
+            self.line_right = vec![0.0; delay];
+            self.pos = 0;
+        }
+    }
+
+    /// Delay `len` samples of input (None = silence) into the output buffers
+    fn process(&mut self, input: Option<(&[Sample], &[Sample])>, len: usize) {
+        let delay = self.line_left.len();
+        for i in 0..len {
+            let (in_left, in_right) = input.map_or((0.0, 0.0), |(l, r)| (l[i], r[i]));
+            if delay == 0 {
+                self.out_left[i] = in_left;
+                self.out_right[i] = in_right;
+            } else {
+                self.out_left[i] = self.line_left[self.pos];
+                self.out_right[i] = self.line_right[self.pos];
+                self.line_left[self.pos] = in_left;
+                self.line_right[self.pos] = in_right;
+                self.pos = (self.pos + 1) % delay;
+            }
+        }
+        self.filter
+            .process(&mut self.out_left[..len], &mut self.out_right[..len]);
+    }
+}
+
+/// Stereo key filter for one route
+///
+/// Same response as `SidechainInput`'s filter: band-pass is an HPF at half
+/// and an LPF at twice the frequency, so a vocal key can drop bass rumble
+/// and cymbal wash together.
+struct KeyFilter {
+    mode: SidechainFilterMode,
+    hpf_left: BiquadTDF2,
+    hpf_right: BiquadTDF2,
+    lpf_left: BiquadTDF2,
+    lpf_right: BiquadTDF2,
+}
+
+impl KeyFilter {
+    /// Butterworth Q for the key filters
+    const Q: f64 = 0.707;
+
+    fn new(sample_rate: f64) -> Self {
+        Self {
+            mode: SidechainFilterMode::Off,
+            hpf_left: BiquadTDF2::new(sample_rate),
+            hpf_right: BiquadTDF2::new(sample_rate),
+            lpf_left: BiquadTDF2::new(sample_rate),
+            lpf_right: BiquadTDF2::new(sample_rate),
+        }
+    }
+
+    fn configure(&mut self, mode: SidechainFilterMode, freq: f64, sample_rate: f64) {
+        if mode != self.mode {
+            self.hpf_left.reset();
+            self.hpf_right.reset();
+            self.lpf_left.reset();
+            self.lpf_right.reset();
+        }
+        self.mode = mode;
+
+        let (hpf, lpf) = match mode {
+            SidechainFilterMode::Off => return,
+            SidechainFilterMode::HighPass => (Some(freq), None),
+            SidechainFilterMode::LowPass => (None, Some(freq)),
+            SidechainFilterMode::BandPass => (Some(freq * 0.5), Some(freq * 2.0)),
+        };
+        // Keep cutoffs below Nyquist at low sample rates
+        let max_freq = sample_rate * 0.49;
+        if let Some(freq) = hpf {
+            let coeffs = BiquadCoeffs::highpass(freq.min(max_freq), Self::Q, sample_rate);
+            self.hpf_left.set_coeffs(coeffs);
+            self.hpf_right.set_coeffs(coeffs);
+        }
+        if let Some(freq) = lpf {
+            let coeffs = BiquadCoeffs::lowpass(freq.min(max_freq), Self::Q, sample_rate);
+            self.lpf_left.set_coeffs(coeffs);
+            self.lpf_right.set_coeffs(coeffs);
+        }
+    }
+
+    fn process(&mut self, left: &mut [Sample], right: &mut [Sample]) {
+        if matches!(
+            self.mode,
+            SidechainFilterMode::HighPass | SidechainFilterMode::BandPass
+        ) {
+            self.hpf_left.process_block(left);
+            self.hpf_right.process_block(right);
+        }
+        if matches!(
+            self.mode,
+            SidechainFilterMode::LowPass | SidechainFilterMode::BandPass
+        ) {
+            self.lpf_left.process_block(left);
+            self.lpf_right.process_block(right);
+        }
+    }
+}
+
 impl SidechainRouter {
     pub fn new(block_size: usize) -> Self {
         Self {
             routes: Vec::new(),
             next_id: AtomicU32::new(1),
             source_buffers: Vec::new(),
+            route_delays: Vec::new(),
+            source_latencies: Vec::new(),
+            processor_latencies: Vec::new(),
             block_size,
+            sample_rate: 48000.0,
         }
     }
 
-    /// Add a sidechain route
+    /// Add a sidechain route keyed from a track
     pub fn add_route(
         &mut self,
         source_id: u32,
         dest_processor_id: u32,
         pre_fader: bool,
+    ) -> SidechainId {
+        let tap_point = if pre_fader {
+            SendTapPoint::PreFader
+        } else {
+            SendTapPoint::PostFader
+        };
+        self.insert_route(
+            SidechainSourceKind::Track,
+            source_id,
+            tap_point,
+            dest_processor_id,
+        )
+    }
+
+    /// Add a sidechain route keyed from a summed bus or return
+    ///
+    /// The bus must publish its signal at `tap_point` through
+    /// `store_bus_signal` each block.
+    pub fn add_bus_route(
+        &mut self,
+        bus_id: u32,
+        dest_processor_id: u32,
+        tap_point: SendTapPoint,
+    ) -> SidechainId {
+        self.insert_route(
+            SidechainSourceKind::Bus,
+            bus_id,
+            tap_point,
+            dest_processor_id,
+        )
+    }
+
+    fn insert_route(
+        &mut self,
+        source_kind: SidechainSourceKind,
+        source_id: u32,
+        tap_point: SendTapPoint,
+        dest_processor_id: u32,
     ) -> SidechainId {
         let id = self.next_id.fetch_add(1, Ordering::Relaxed);
 
         self.routes.push(SidechainRoute {
             id,
             source_id,
-            pre_fader,
+            source_kind,
+            tap_point,
             dest_processor_id,
             active: true,
+            key_delay: 0,
+            key_lateness: 0,
+            key_filter: SidechainFilterMode::Off,
+            key_filter_freq: 150.0,
         });
+        self.route_delays
+            .push(RouteDelay::new(id, self.block_size, self.sample_rate));
 
         // Ensure source buffer exists
-        if !self.source_buffers.iter().any(|b| b.source_id == source_id) {
+        let exists = self.source_buffers.iter().any(|b| {
+            b.source_kind == source_kind && b.source_id == source_id && b.tap_point == tap_point
+        });
+        if !exists {
             self.source_buffers.push(SidechainBuffer {
+                source_kind,
                 source_id,
+                tap_point,
                 left: vec![0.0; self.block_size],
                 right: vec![0.0; self.block_size],
                 valid: false,
             });
         }
 
+        self.update_compensation();
         id
     }
 
@@ -488,6 +705,7 @@ impl SidechainRouter {
     pub fn remove_route(&mut self, id: SidechainId) -> bool {
         if let Some(pos) = self.routes.iter().position(|r| r.id == id) {
             self.routes.remove(pos);
+            self.route_delays.retain(|d| d.route_id != id);
             true
         } else {
             false
@@ -504,6 +722,31 @@ impl SidechainRouter {
         self.routes.iter_mut().find(|r| r.id == id)
     }
 
+    /// Filter a route's key before detection
+    ///
+    /// A high-pass above the bass keeps kick and rumble on a full-range key
+    /// (e.g. a vocal bus) from triggering ducking. `freq` is clamped to
+    /// 20-20000 Hz. Returns false if the route doesn't exist.
+    pub fn set_route_key_filter(
+        &mut self,
+        id: SidechainId,
+        mode: SidechainFilterMode,
+        freq: f64,
+    ) -> bool {
+        let Some(route) = self.routes.iter_mut().find(|r| r.id == id) else {
+            return false;
+        };
+        route.key_filter = mode;
+        route.key_filter_freq = freq.clamp(20.0, 20000.0);
+
+        if let Some(delay) = self.route_delays.iter_mut().find(|d| d.route_id == id) {
+            delay
+                .filter
+                .configure(mode, route.key_filter_freq, self.sample_rate);
+        }
+        true
+    }
+
     /// Get all routes for a destination processor
     pub fn routes_for_processor(&self, processor_id: u32) -> Vec<&SidechainRoute> {
         self.routes
@@ -512,38 +755,202 @@ impl SidechainRouter {
             .collect()
     }
 
-    /// Get all routes from a source
+    /// Get all routes from a track source
     pub fn routes_from_source(&self, source_id: u32) -> Vec<&SidechainRoute> {
         self.routes
             .iter()
-            .filter(|r| r.source_id == source_id && r.active)
+            .filter(|r| {
+                r.source_kind == SidechainSourceKind::Track && r.source_id == source_id && r.active
+            })
+            .collect()
+    }
+
+    /// Get all routes from a bus/return source
+    pub fn routes_from_bus(&self, bus_id: u32) -> Vec<&SidechainRoute> {
+        self.routes
+            .iter()
+            .filter(|r| {
+                r.source_kind == SidechainSourceKind::Bus && r.source_id == bus_id && r.active
+            })
             .collect()
     }
 
     /// Store source signal for this processing block
     /// Call this during the source track's processing
     pub fn store_source_signal(&mut self, source_id: u32, left: &[Sample], right: &[Sample]) {
-        if let Some(buffer) = self
+        let len = left.len().min(right.len()).min(self.block_size);
+        for buffer in self
             .source_buffers
             .iter_mut()
-            .find(|b| b.source_id == source_id)
+            .filter(|b| b.source_kind == SidechainSourceKind::Track && b.source_id == source_id)
         {
-            let len = left.len().min(right.len()).min(self.block_size);
             buffer.left[..len].copy_from_slice(&left[..len]);
             buffer.right[..len].copy_from_slice(&right[..len]);
             buffer.valid = true;
         }
     }
 
+    /// Store a bus/return signal at one tap point for this processing block
+    ///
+    /// Call from the bus processing at each tap (pre-fader, post-fader,
+    /// post-pan); taps nobody listens to are ignored.
+    pub fn store_bus_signal(
+        &mut self,
+        bus_id: u32,
+        tap_point: SendTapPoint,
+        left: &[Sample],
+        right: &[Sample],
+    ) {
+        self.store_bus_signal_scaled(bus_id, tap_point, left, right, 1.0);
+    }
+
+    /// Store a bus/return signal scaled by `gain`
+    ///
+    /// Lets the bus publish its post-fader tap from the pre-fader signal
+    /// without a scratch buffer.
+    pub fn store_bus_signal_scaled(
+        &mut self,
+        bus_id: u32,
+        tap_point: SendTapPoint,
+        left: &[Sample],
+        right: &[Sample],
+        gain: f64,
+    ) {
+        let len = left.len().min(right.len()).min(self.block_size);
+        if let Some(buffer) = self.source_buffers.iter_mut().find(|b| {
+            b.source_kind == SidechainSourceKind::Bus
+                && b.source_id == bus_id
+                && b.tap_point == tap_point
+        }) {
+            for i in 0..len {
+                buffer.left[i] = left[i] * gain;
+                buffer.right[i] = right[i] * gain;
+            }
+            buffer.valid = true;
+        }
+    }
+
+    /// Check if an active route listens to a bus/return
+    pub fn is_bus_key_source(&self, bus_id: u32) -> bool {
+        self.routes
+            .iter()
+            .any(|r| r.source_kind == SidechainSourceKind::Bus && r.source_id == bus_id && r.active)
+    }
+
     /// Get source signal for a destination
     /// Returns None if source hasn't been processed yet this block
     pub fn get_source_signal(&self, source_id: u32) -> Option<(&[Sample], &[Sample])> {
         self.source_buffers
             .iter()
-            .find(|b| b.source_id == source_id && b.valid)
+            .find(|b| {
+                b.source_kind == SidechainSourceKind::Track && b.source_id == source_id && b.valid
+            })
+            .map(|b| (b.left.as_slice(), b.right.as_slice()))
+    }
+
+    /// Get a bus signal at a tap point
+    /// Returns None if the bus hasn't been processed yet this block
+    pub fn get_bus_signal(
+        &self,
+        bus_id: u32,
+        tap_point: SendTapPoint,
+    ) -> Option<(&[Sample], &[Sample])> {
+        self.source_buffers
+            .iter()
+            .find(|b| {
+                b.source_kind == SidechainSourceKind::Bus
+                    && b.source_id == bus_id
+                    && b.tap_point == tap_point
+                    && b.valid
+            })
             .map(|b| (b.left.as_slice(), b.right.as_slice()))
     }
 
+    /// Latency-compensated key for a route
+    ///
+    /// Call once per block per route after the source has been stored.
+    /// The key is delayed by the route's `key_delay` and passed through its
+    /// key filter; a source that did not run this block feeds silence.
+    pub fn route_key(
+        &mut self,
+        route_id: SidechainId,
+        frames: usize,
+    ) -> Option<(&[Sample], &[Sample])> {
+        let route = self.routes.iter().find(|r| r.id == route_id && r.active)?;
+        let buffer = self.source_buffers.iter().find(|b| {
+            b.source_kind == route.source_kind
+                && b.source_id == route.source_id
+                && b.tap_point == route.tap_point
+                && b.valid
+        });
+        let delay = self
+            .route_delays
+            .iter_mut()
+            .find(|d| d.route_id == route_id)?;
+
+        let len = frames.min(self.block_size);
+        delay.process(buffer.map(|b| (b.left.as_slice(), b.right.as_slice())), len);
+        Some((&delay.out_left[..len], &delay.out_right[..len]))
+    }
+
+    /// Report the latency of a source at its tap (for PDC)
+    pub fn set_source_latency(
+        &mut self,
+        kind: SidechainSourceKind,
+        source_id: u32,
+        samples: usize,
+    ) {
+        match self
+            .source_latencies
+            .iter_mut()
+            .find(|(k, id, _)| *k == kind && *id == source_id)
+        {
+            Some(entry) => entry.2 = samples,
+            None => self.source_latencies.push((kind, source_id, samples)),
+        }
+        self.update_compensation();
+    }
+
+    /// Report the latency of a destination processor's input (for PDC)
+    pub fn set_processor_latency(&mut self, processor_id: u32, samples: usize) {
+        match self
+            .processor_latencies
+            .iter_mut()
+            .find(|(id, _)| *id == processor_id)
+        {
+            Some(entry) => entry.1 = samples,
+            None => self.processor_latencies.push((processor_id, samples)),
+        }
+        self.update_compensation();
+    }
+
+    /// Recompute key delays from reported latencies
+    fn update_compensation(&mut self) {
+        for route in &mut self.routes {
+            let source = self
+                .source_latencies
+                .iter()
+                .find(|(k, id, _)| *k == route.source_kind && *id == route.source_id)
+                .map_or(0, |e| e.2);
+            let dest = self
+                .processor_latencies
+                .iter()
+                .find(|(id, _)| *id == route.dest_processor_id)
+                .map_or(0, |e| e.1);
+
+            route.key_delay = dest.saturating_sub(source);
+            route.key_lateness = source.saturating_sub(dest);
+
+            if let Some(delay) = self
+                .route_delays
+                .iter_mut()
+                .find(|d| d.route_id == route.id)
+            {
+                delay.set_delay(route.key_delay);
+            }
+        }
+    }
+
     /// Clear all source buffers (call at start of each processing block)
     pub fn clear_buffers(&mut self) {
         for buffer in &mut self.source_buffers {
@@ -558,6 +965,32 @@ impl SidechainRouter {
             buffer.left.resize(size, 0.0);
             buffer.right.resize(size, 0.0);
         }
+        for delay in &mut self.route_delays {
+            delay.out_left.resize(size, 0.0);
+            delay.out_right.resize(size, 0.0);
+        }
+    }
+
+    /// Current sample rate
+    pub fn sample_rate(&self) -> f64 {
+        self.sample_rate
+    }
+
+    /// Set sample rate (recomputes key filters)
+    pub fn set_sample_rate(&mut self, sample_rate: f64) {
+        self.sample_rate = sample_rate;
+        for route in &self.routes {
+            if let Some(delay) = self
+                .route_delays
+                .iter_mut()
+                .find(|d| d.route_id == route.id)
+            {
+                delay.filter = KeyFilter::new(sample_rate);
+                delay
+                    .filter
+                    .configure(route.key_filter, route.key_filter_freq, sample_rate);
+            }
+        }
     }
 
     /// Get all routes
@@ -568,6 +1001,7 @@ impl SidechainRouter {
     /// Clear all routes
     pub fn clear_routes(&mut self) {
         self.routes.clear();
+        self.route_delays.clear();
     }
 }
 
@@ -689,6 +1123,160 @@ mod tests {
         assert!(router.routes_for_processor(100).is_empty());
     }
 
+    #[test]
+    fn test_sidechain_bus_key_pdc() {
+        let mut router = SidechainRouter::new(256);
+        let id = router.add_bus_route(7, 100, SendTapPoint::PostFader);
+
+        // Bus tap runs 64 samples late, compressor input 192: key waits 128
+        router.set_source_latency(SidechainSourceKind::Bus, 7, 64);
+        router.set_processor_latency(100, 192);
+        let route = router.get_route(id).unwrap();
+        assert_eq!(route.key_delay, 128);
+        assert_eq!(route.key_lateness, 0);
+
+        // Only the post-fader tap feeds this route
+        let mut impulse = vec![0.0; 256];
+        impulse[0] = 1.0;
+        router.store_bus_signal(7, SendTapPoint::PreFader, &[0.5; 256], &[0.5; 256]);
+        router.store_bus_signal(7, SendTapPoint::PostFader, &impulse, &impulse);
+        assert!(router.get_bus_signal(7, SendTapPoint::PreFader).is_none());
+
+        let (key_l, _) = router.route_key(id, 256).unwrap();
+        let peak = key_l.iter().position(|&x| x == 1.0);
+        assert_eq!(peak, Some(128));
+        assert_eq!(router.routes_from_bus(7).len(), 1);
+        assert!(router.routes_from_source(7).is_empty());
+
+        // Key later than the destination is reported for graph PDC
+        router.set_processor_latency(100, 0);
+        assert_eq!(router.get_route(id).unwrap().key_lateness, 64);
+    }
+
+    #[test]
+    fn test_sidechain_bus_key_ducks() {
+        use rf_dsp::dynamics::Compressor;
+
+        let block = 256;
+        let sr = 48000.0;
+        let drum_bus = 3;
+        let bus_fader = 0.5;
+
+        let mut router = SidechainRouter::new(block);
+        let route = router.add_bus_route(drum_bus, 100, SendTapPoint::PostFader);
+
+        let mut comp = Compressor::new(sr);
+        comp.set_threshold(-20.0);
+        comp.set_ratio(8.0);
+        comp.set_attack(1.0);
+        comp.set_release(20.0);
+        comp.set_sidechain_enabled(true);
+
+        let tone = |n: usize, freq: f64| (2.0 * std::f64::consts::PI * freq * n as f64 / sr).sin();
+        let mut n = 0;
+        let mut bus_l = vec![0.0; block];
+        let mut bus_r = vec![0.0; block];
+
+        // Run blocks with given track levels, return max GR of the last block
+        let mut run = |kick: f64, snare: f64, blocks: usize| {
+            let mut max_gr: f64 = 0.0;
+            for _ in 0..blocks {
+                router.clear_buffers();
+
+                // Two tracks feeding the drum bus, then the bus fader
+                for i in 0..block {
+                    let sum = kick * tone(n + i, 60.0) + snare * tone(n + i, 200.0);
+                    bus_l[i] = sum * bus_fader;
+                    bus_r[i] = sum * bus_fader;
+                }
+                router.store_bus_signal(drum_bus, SendTapPoint::PostFader, &bus_l, &bus_r);
+
+                // Music track compressor keyed by the bus
+                let (key_l, key_r) = router.route_key(route, block).unwrap();
+                max_gr = 0.0;
+                for i in 0..block {
+                    comp.set_sidechain_key((key_l[i] + key_r[i]) * 0.5);
+                    comp.process_sample(0.25 * tone(n + i, 440.0));
+                    max_gr = max_gr.max(comp.gain_reduction_db());
+                }
+                n += block;
+            }
+            max_gr
+        };
+
+        assert!(run(0.005, 0.005, 20) < 0.5, "quiet bus should not duck");
+        assert!(run(0.9, 0.005, 20) > 6.0, "loud kick should duck");
+        assert!(run(0.005, 0.005, 40) < 0.5, "should recover");
+        assert!(run(0.005, 0.9, 20) > 6.0, "loud snare should duck");
+    }
+
+    #[test]
+    fn test_route_key_hpf_ignores_bass() {
+        use rf_dsp::dynamics::Compressor;
+
+        let block = 256;
+        let sr = 48000.0;
+        let vocal_bus = 4;
+
+        // Max GR of the last block for a key made of the given partials
+        let run_key = |partials: &[(f64, f64)], key_hpf: Option<f64>| {
+            let mut router = SidechainRouter::new(block);
+            let route = router.add_bus_route(vocal_bus, 100, SendTapPoint::PostFader);
+            if let Some(freq) = key_hpf {
+                assert!(router.set_route_key_filter(route, SidechainFilterMode::HighPass, freq));
+            }
+
+            let mut comp = Compressor::new(sr);
+            comp.set_threshold(-20.0);
+            comp.set_ratio(8.0);
+            comp.set_attack(1.0);
+            comp.set_release(20.0);
+            comp.set_sidechain_enabled(true);
+
+            let mut key = vec![0.0; block];
+            let mut max_gr: f64 = 0.0;
+            for b in 0..40 {
+                router.clear_buffers();
+                for (i, k) in key.iter_mut().enumerate() {
+                    let t = (b * block + i) as f64 / sr;
+                    *k = partials
+                        .iter()
+                        .map(|&(freq, amp)| amp * (2.0 * std::f64::consts::PI * freq * t).sin())
+                        .sum();
+                }
+                router.store_bus_signal(vocal_bus, SendTapPoint::PostFader, &key, &key);
+
+                let (key_l, key_r) = router.route_key(route, block).unwrap();
+                max_gr = 0.0;
+                for i in 0..block {
+                    comp.set_sidechain_key((key_l[i] + key_r[i]) * 0.5);
+                    comp.process_sample(0.25);
+                    max_gr = max_gr.max(comp.gain_reduction_db());
+                }
+            }
+            max_gr
+        };
+
+        let bass = [(50.0, 0.5)];
+        let full_range = [(50.0, 0.5), (2000.0, 0.5)];
+
+        assert!(run_key(&bass, None) > 6.0, "unfiltered bass should duck");
+        assert!(
+            run_key(&bass, Some(400.0)) < 0.5,
+            "HPF above the bass must not duck"
+        );
+        assert!(
+            run_key(&full_range, Some(400.0)) > 6.0,
+            "full-range key should duck"
+        );
+
+        let mut router = SidechainRouter::new(block);
+        let route = router.add_bus_route(vocal_bus, 100, SendTapPoint::PostFader);
+        assert!(router.set_route_key_filter(route, SidechainFilterMode::BandPass, 1e6));
+        assert_eq!(router.get_route(route).unwrap().key_filter_freq, 20000.0);
+        assert!(!router.set_route_key_filter(99, SidechainFilterMode::HighPass, 100.0));
+    }
+
     #[test]
     fn test_sidechain_gain() {
         let mut sc = SidechainInput::new(48000.0, 256);
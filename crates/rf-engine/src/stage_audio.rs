@@ -5,6 +5,7 @@
 //! - Timed audio preview based on stage timing
 //! - Audio markers synced to stages
 //! - Preview playback with stage-driven transport
+//! - Crossfaded transitions between exclusive stage cues (BASE → FREESPINS)
 
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -12,8 +13,8 @@ use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
 use parking_lot::RwLock;
 
-use crate::playback::PlaybackEngine;
-use crate::track_manager::TrackManager;
+use crate::playback::{PlaybackEngine, PlaybackSource};
+use crate::track_manager::{CrossfadeCurve, TrackManager};
 use rf_stage::event::StageEvent;
 use rf_stage::timing::{TimedStageEvent, TimedStageTrace};
 
@@ -40,6 +41,9 @@ pub struct StageCue {
     pub delay_ms: f64,
     /// Whether cue is enabled
     pub enabled: bool,
+    /// Exclusive stage cue (music/ambience bed): replaces the current exclusive
+    /// cue through a crossfade instead of layering on top of it
+    pub exclusive: bool,
 }
 
 impl Default for StageCue {
@@ -53,10 +57,90 @@ impl Default for StageCue {
             pan: 0.0,
             delay_ms: 0.0,
             enabled: true,
+            exclusive: false,
         }
     }
 }
 
+/// Bus for exclusive cues (music/ambience beds)
+const EXCLUSIVE_CUE_BUS: u32 = 1;
+/// Bus for layered one-shot cues
+const ONE_SHOT_CUE_BUS: u32 = 2;
+
+// ═══════════════════════════════════════════════════════════════════════════
+// STAGE CROSSFADE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Crossfade settings for transitions between exclusive stage cues
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageCrossfade {
+    /// Crossfade length in milliseconds (0 = hard cut)
+    pub duration_ms: f64,
+    /// Fade-in curve; the fade-out is its mirror image
+    pub curve: CrossfadeCurve,
+    /// Delay the crossfade start to the next beat at this tempo (None = immediate)
+    pub beat_sync_bpm: Option<f64>,
+}
+
+impl Default for StageCrossfade {
+    fn default() -> Self {
+        Self {
+            duration_ms: 500.0,
+            curve: CrossfadeCurve::EqualPower,
+            beat_sync_bpm: None,
+        }
+    }
+}
+
+impl StageCrossfade {
+    /// Crossfade start for a transition requested at `time_ms`
+    pub fn start_time(&self, time_ms: f64) -> f64 {
+        match self.beat_sync_bpm {
+            Some(bpm) if bpm > 0.0 => {
+                let beat_ms = 60_000.0 / bpm;
+                (time_ms / beat_ms).ceil() * beat_ms
+            }
+            _ => time_ms,
+        }
+    }
+}
+
+/// Crossfade in progress between the outgoing and incoming exclusive cue
+#[derive(Debug, Clone, PartialEq)]
+pub struct StageTransition {
+    /// Outgoing cue (None when nothing was playing)
+    pub from_cue: Option<u64>,
+    /// Incoming cue
+    pub to_cue: u64,
+    /// Crossfade start on the stage timeline (ms)
+    pub start_ms: f64,
+    /// Crossfade length (ms)
+    pub duration_ms: f64,
+    /// Fade curve
+    pub curve: CrossfadeCurve,
+}
+
+impl StageTransition {
+    /// (outgoing gain, incoming gain) at `time_ms`
+    pub fn gains_at(&self, time_ms: f64) -> (f64, f64) {
+        if time_ms < self.start_ms {
+            return (1.0, 0.0);
+        }
+        if self.duration_ms <= 0.0 || time_ms >= self.start_ms + self.duration_ms {
+            return (0.0, 1.0);
+        }
+        let t = ((time_ms - self.start_ms) / self.duration_ms) as f32;
+        let fade_in = self.curve.evaluate(t) as f64;
+        let fade_out = self.curve.evaluate(1.0 - t) as f64;
+        (fade_out, fade_in)
+    }
+
+    /// Whether the incoming cue has fully replaced the outgoing one
+    pub fn is_complete(&self, time_ms: f64) -> bool {
+        time_ms >= self.start_ms + self.duration_ms.max(0.0)
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // STAGE AUDIO ENGINE
 // ═══════════════════════════════════════════════════════════════════════════
@@ -78,6 +162,14 @@ pub struct StageAudioEngine {
     preview_playing: AtomicBool,
     /// Next cue ID
     next_cue_id: AtomicU64,
+    /// Crossfade settings for exclusive cue transitions
+    crossfade: RwLock<StageCrossfade>,
+    /// Current exclusive cue (0 = none)
+    active_cue: AtomicU64,
+    /// Crossfade in progress (if any)
+    transition: RwLock<Option<StageTransition>>,
+    /// Playing voice per exclusive cue (cue_id -> one-shot voice id)
+    cue_voices: RwLock<HashMap<u64, u64>>,
     /// Sample rate
     sample_rate: u32,
 }
@@ -97,6 +189,10 @@ impl StageAudioEngine {
             preview_position_ms: AtomicU64::new(0),
             preview_playing: AtomicBool::new(false),
             next_cue_id: AtomicU64::new(1),
+            crossfade: RwLock::new(StageCrossfade::default()),
+            active_cue: AtomicU64::new(0),
+            transition: RwLock::new(None),
+            cue_voices: RwLock::new(HashMap::new()),
             sample_rate,
         }
     }
@@ -194,10 +290,12 @@ impl StageAudioEngine {
         }
     }
 
-    /// Trigger an audio cue at specified time
+    /// Trigger an audio cue for a stage event at `trigger_time_ms`
+    ///
+    /// Exclusive cues crossfade against the current one; other cues play as
+    /// one-shots layered on top, both after the cue's delay.
     fn trigger_cue(&self, cue: &StageCue, trigger_time_ms: f64) {
         let actual_time_ms = trigger_time_ms + cue.delay_ms;
-        let position_samples = self.ms_to_samples(actual_time_ms);
 
         log::debug!(
             "[StageAudio] Triggering cue {} at {}ms (audio: {})",
@@ -206,10 +304,142 @@ impl StageAudioEngine {
             cue.audio_path
         );
 
-        // Load and schedule audio through playback engine
-        // For now, seek to position and rely on track clips
-        // Full implementation would create one-shot clips or use event-based triggering
-        self.playback.seek_samples(position_samples);
+        if cue.exclusive {
+            self.start_transition(cue.id, actual_time_ms, trigger_time_ms);
+        } else if !cue.audio_path.is_empty() {
+            self.playback.play_scheduled_to_bus(
+                &cue.audio_path,
+                cue.volume as f32,
+                cue.pan as f32,
+                ONE_SHOT_CUE_BUS,
+                PlaybackSource::SlotLab,
+                false,
+                self.ms_to_samples(cue.delay_ms.max(0.0)),
+                0,
+                CrossfadeCurve::Linear,
+            );
+        }
+    }
+
+    /// Find a registered cue by ID
+    fn find_cue(&self, cue_id: u64) -> Option<StageCue> {
+        let cues = self.cues.read();
+        cues.values().flatten().find(|c| c.id == cue_id).cloned()
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // STAGE TRANSITIONS
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Set crossfade length/curve/beat sync for exclusive cue transitions
+    pub fn set_crossfade(&self, crossfade: StageCrossfade) {
+        *self.crossfade.write() = crossfade;
+    }
+
+    /// Get crossfade settings
+    pub fn crossfade(&self) -> StageCrossfade {
+        self.crossfade.read().clone()
+    }
+
+    /// Start crossfading from the current exclusive cue to `cue_id` at `time_ms`
+    ///
+    /// The outgoing cue's voice fades out and the incoming cue starts looping on
+    /// the music bus with the mirrored fade-in. Re-triggering the active cue is a
+    /// no-op (returns None).
+    pub fn transition_to(&self, cue_id: u64, time_ms: f64) -> Option<StageTransition> {
+        self.start_transition(cue_id, time_ms, time_ms)
+    }
+
+    /// Crossfade requested for `time_ms`, issued to the audio thread at `now_ms`
+    fn start_transition(&self, cue_id: u64, time_ms: f64, now_ms: f64) -> Option<StageTransition> {
+        let previous = self.active_cue.load(Ordering::Relaxed);
+        if previous == cue_id {
+            return None;
+        }
+
+        let crossfade = self.crossfade.read().clone();
+        let transition = StageTransition {
+            from_cue: (previous != 0).then_some(previous),
+            to_cue: cue_id,
+            start_ms: crossfade.start_time(time_ms),
+            duration_ms: crossfade.duration_ms.max(0.0),
+            curve: crossfade.curve,
+        };
+
+        log::debug!(
+            "[StageAudio] Crossfade {:?} -> {} at {}ms over {}ms",
+            transition.from_cue,
+            cue_id,
+            transition.start_ms,
+            transition.duration_ms
+        );
+
+        self.active_cue.store(cue_id, Ordering::Relaxed);
+        *self.transition.write() = Some(transition.clone());
+
+        // Both ramps start at the same sample offset from now
+        let delay_samples = self.ms_to_samples((transition.start_ms - now_ms).max(0.0));
+        let fade_samples = self.ms_to_samples(transition.duration_ms);
+        let mut cue_voices = self.cue_voices.write();
+        if let Some(voice_id) = transition.from_cue.and_then(|id| cue_voices.remove(&id)) {
+            self.playback.crossfade_out_one_shot(
+                voice_id,
+                delay_samples,
+                fade_samples,
+                transition.curve.clone(),
+            );
+        }
+        if let Some(cue) = self.find_cue(cue_id).filter(|c| !c.audio_path.is_empty()) {
+            let voice_id = self.playback.play_scheduled_to_bus(
+                &cue.audio_path,
+                cue.volume as f32,
+                cue.pan as f32,
+                EXCLUSIVE_CUE_BUS,
+                PlaybackSource::SlotLab,
+                true,
+                delay_samples,
+                fade_samples,
+                transition.curve.clone(),
+            );
+            if voice_id != 0 {
+                cue_voices.insert(cue_id, voice_id);
+            }
+        }
+
+        Some(transition)
+    }
+
+    /// Current exclusive cue (the incoming one during a crossfade)
+    pub fn active_cue(&self) -> Option<u64> {
+        let id = self.active_cue.load(Ordering::Relaxed);
+        (id != 0).then_some(id)
+    }
+
+    /// Crossfade in progress, if any
+    pub fn current_transition(&self) -> Option<StageTransition> {
+        self.transition.read().clone()
+    }
+
+    /// Crossfade gain for an exclusive cue at `time_ms` (0.0 if not playing)
+    pub fn cue_gain(&self, cue_id: u64, time_ms: f64) -> f64 {
+        if let Some(transition) = self.transition.read().as_ref()
+            && !transition.is_complete(time_ms)
+        {
+            let (fade_out, fade_in) = transition.gains_at(time_ms);
+            if cue_id == transition.to_cue {
+                return fade_in;
+            }
+            if transition.from_cue == Some(cue_id) {
+                return fade_out;
+            }
+            return 0.0;
+        }
+
+        if self.active_cue() == Some(cue_id) {
+            1.0
+        } else {
+            0.0
+        }
     }
 
     /// Convert milliseconds to samples
@@ -437,4 +667,155 @@ mod tests {
         // Reverse conversion
         assert!((engine.samples_to_ms(48000) - 1000.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_stage_transition_crossfade() {
+        let track_manager = Arc::new(TrackManager::new());
+        let playback = Arc::new(PlaybackEngine::new(Arc::clone(&track_manager), 48000));
+        let engine = StageAudioEngine::new(playback, track_manager, 48000);
+
+        let base = engine.add_cue(StageCue {
+            stage_trigger: "base_game".to_string(),
+            exclusive: true,
+            ..Default::default()
+        });
+        let free_spins = engine.add_cue(StageCue {
+            stage_trigger: "free_spins_trigger".to_string(),
+            exclusive: true,
+            ..Default::default()
+        });
+
+        // Each pass starts on base and crossfades to free spins 1 s later
+        for (pass, curve) in [CrossfadeCurve::Linear, CrossfadeCurve::EqualPower]
+            .into_iter()
+            .enumerate()
+        {
+            let t0 = pass as f64 * 10_000.0;
+            engine.set_crossfade(StageCrossfade {
+                duration_ms: 400.0,
+                curve: curve.clone(),
+                beat_sync_bpm: None,
+            });
+            engine.transition_to(base, t0);
+            assert!(engine.transition_to(base, t0 + 500.0).is_none());
+            assert_eq!(engine.cue_gain(base, t0 + 1000.0), 1.0);
+
+            let transition = engine.transition_to(free_spins, t0 + 1000.0).unwrap();
+            assert_eq!(transition.from_cue, Some(base));
+
+            // Overlap = both cues audible; measured in 1 ms steps
+            let mut overlap_ms = 0;
+            for ms in 900..1500 {
+                let t = t0 + ms as f64;
+                let out = engine.cue_gain(base, t);
+                let inc = engine.cue_gain(free_spins, t);
+                if out > 0.0 && inc > 0.0 {
+                    overlap_ms += 1;
+                }
+                if curve == CrossfadeCurve::Linear {
+                    assert!((out + inc - 1.0).abs() < 1e-6);
+                } else {
+                    assert!((out * out + inc * inc - 1.0).abs() < 1e-5);
+                    assert!(out + inc <= std::f64::consts::SQRT_2 + 1e-6);
+                }
+            }
+            assert_eq!(overlap_ms, 399);
+            assert_eq!(engine.cue_gain(base, t0 + 1400.0), 0.0);
+            assert_eq!(engine.cue_gain(free_spins, t0 + 1400.0), 1.0);
+        }
+
+        engine.transition_to(base, 20_000.0);
+
+        // Beat sync: 120 BPM → crossfade waits for the next 500 ms beat
+        engine.set_crossfade(StageCrossfade {
+            beat_sync_bpm: Some(120.0),
+            ..Default::default()
+        });
+        let transition = engine.transition_to(free_spins, 21_230.0).unwrap();
+        assert_eq!(transition.start_ms, 21_500.0);
+        assert_eq!(engine.cue_gain(base, 21_400.0), 1.0);
+        assert_eq!(engine.cue_gain(free_spins, 21_400.0), 0.0);
+    }
+
+    #[test]
+    fn test_stage_transition_renders_crossfade() {
+        use crate::audio_import::ImportedAudio;
+
+        let sample_rate = 48000;
+        let track_manager = Arc::new(TrackManager::new());
+        let playback = Arc::new(PlaybackEngine::new(Arc::clone(&track_manager), sample_rate));
+        playback.set_active_section(PlaybackSource::SlotLab);
+
+        // 1 kHz beds: base panned hard left, free spins hard right
+        let tone: Vec<f32> = (0..sample_rate as usize)
+            .map(|i| {
+                let phase = 2.0 * std::f64::consts::PI * 1000.0 * i as f64 / sample_rate as f64;
+                (0.25 * phase.sin()) as f32
+            })
+            .collect();
+        for path in ["/virtual/base.wav", "/virtual/free_spins.wav"] {
+            playback.cache().insert(
+                path.to_string(),
+                Arc::new(ImportedAudio::new_mono(tone.clone(), sample_rate, path)),
+            );
+        }
+
+        let engine = StageAudioEngine::new(Arc::clone(&playback), track_manager, sample_rate);
+        engine.set_crossfade(StageCrossfade {
+            duration_ms: 200.0,
+            curve: CrossfadeCurve::EqualPower,
+            beat_sync_bpm: None,
+        });
+        let base = engine.add_cue(StageCue {
+            stage_trigger: "base_game".to_string(),
+            audio_path: "/virtual/base.wav".to_string(),
+            pan: -1.0,
+            exclusive: true,
+            ..Default::default()
+        });
+        let free_spins = engine.add_cue(StageCue {
+            stage_trigger: "free_spins_trigger".to_string(),
+            audio_path: "/virtual/free_spins.wav".to_string(),
+            pan: 1.0,
+            exclusive: true,
+            ..Default::default()
+        });
+
+        // Render 10 ms blocks, returning per-block (left, right) RMS
+        let block = 480;
+        let mut left = vec![0.0; block];
+        let mut right = vec![0.0; block];
+        let mut render = |blocks: usize| -> Vec<(f64, f64)> {
+            let rms = |x: &[f64]| (x.iter().map(|s| s * s).sum::<f64>() / x.len() as f64).sqrt();
+            (0..blocks)
+                .map(|_| {
+                    playback.process(&mut left, &mut right);
+                    (rms(&left), rms(&right))
+                })
+                .collect()
+        };
+
+        engine.transition_to(base, 0.0);
+        let (full, silent) = *render(20).last().unwrap();
+        assert!(full > 0.1);
+        assert!(silent < 1e-6);
+
+        // 200 ms crossfade = 20 blocks where both beds are audible
+        engine.transition_to(free_spins, 200.0);
+        let fade = render(30);
+        let overlap = fade
+            .iter()
+            .filter(|(l, r)| *l > 0.01 * full && *r > 0.01 * full)
+            .count();
+        assert!((20..=21).contains(&overlap), "overlap {} blocks", overlap);
+
+        // Equal power: summed energy stays at the single-bed level throughout
+        for (l, r) in &fade {
+            let energy = (l / full).powi(2) + (r / full).powi(2);
+            assert!((energy - 1.0).abs() < 0.05, "energy {}", energy);
+        }
+        let (l, r) = *fade.last().unwrap();
+        assert!(l < 1e-3 * full);
+        assert!((r / full - 1.0).abs() < 0.02);
+    }
 }
@@ -450,6 +450,28 @@ impl Default for EventIndex {
 // DISK JOB (Prefetch Request)
 // ═══════════════════════════════════════════════════════════════════════════
 
+/// Scheduling class of a disk job — urgent jobs are always served before normal ones
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DiskJobClass {
+    /// Prefetch — ring buffer between low and high water
+    Normal,
+    /// Ring buffer at or below low water, about to underflow
+    Urgent,
+}
+
+impl DiskJobClass {
+    /// Classify by ring buffer fill (None = at high water, nothing to read)
+    pub fn for_available(available_read: usize) -> Option<Self> {
+        if available_read <= LOW_WATER_FRAMES {
+            Some(Self::Urgent)
+        } else if available_read < HIGH_WATER_FRAMES {
+            Some(Self::Normal)
+        } else {
+            None
+        }
+    }
+}
+
 /// Disk read job for prefetch scheduler
 #[derive(Debug, Clone)]
 pub struct DiskJob {
@@ -461,7 +483,9 @@ pub struct DiskJob {
     pub src_frame: i64,
     /// Number of frames to read
     pub frames: usize,
-    /// Priority (higher = more urgent)
+    /// Scheduling class (urgent before normal)
+    pub class: DiskJobClass,
+    /// Priority within class (higher = more urgent)
     pub priority: i32,
 }
 
@@ -479,6 +503,12 @@ impl DiskJob {
         // Urgency dominates, then need, distance is least important
         urgency * 1000 + need * 10 - distance / 64
     }
+
+    /// Ordering key for the reader pool: class first, then priority
+    #[inline]
+    pub fn schedule_key(&self) -> (DiskJobClass, i32) {
+        (self.class, self.priority)
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════
@@ -548,6 +578,66 @@ impl Default for AssetCatalog {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// DISK SOURCE (Raw Frame Reads)
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Backend that reads interleaved f32 frames for an asset
+pub trait DiskSource: Send + Sync {
+    /// Read up to `frames` frames starting at `src_frame` into `output`
+    /// Returns frames read, or None on I/O failure
+    fn read_frames(
+        &self,
+        asset: &AssetInfo,
+        src_frame: i64,
+        frames: usize,
+        output: &mut [f32],
+    ) -> Option<usize>;
+}
+
+/// Default disk source — raw f32 PCM from the asset file
+pub struct FileDiskSource;
+
+impl DiskSource for FileDiskSource {
+    fn read_frames(
+        &self,
+        asset: &AssetInfo,
+        src_frame: i64,
+        frames: usize,
+        output: &mut [f32],
+    ) -> Option<usize> {
+        // Open file and seek
+        let file = match File::open(&asset.path) {
+            Ok(f) => f,
+            Err(e) => {
+                log::error!("Failed to open file {}: {}", asset.path, e);
+                return None;
+            }
+        };
+
+        let mut reader = BufReader::new(file);
+
+        // Calculate byte position
+        let frame_size = asset.channels as u64 * asset.bytes_per_sample as u64;
+        let byte_offset = asset.data_offset + (src_frame as u64 * frame_size);
+
+        reader.seek(SeekFrom::Start(byte_offset)).ok()?;
+
+        let bytes_to_read = frames * asset.channels as usize * asset.bytes_per_sample as usize;
+
+        let mut byte_buffer = vec![0u8; bytes_to_read];
+        reader.read_exact(&mut byte_buffer).ok()?;
+
+        // Convert to f32 (assuming file is already f32)
+        // For WAV files, this would need proper decoding
+        for (i, chunk) in byte_buffer.as_chunks::<4>().0.iter().enumerate() {
+            output[i] = f32::from_le_bytes(*chunk);
+        }
+
+        Some(frames)
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // DISK READER THREAD POOL
 // ═══════════════════════════════════════════════════════════════════════════
@@ -562,7 +652,7 @@ pub enum DiskCommand {
 
 /// Disk reader thread pool for background I/O
 pub struct DiskReaderPool {
-    /// Job queue (priority queue would be better, but Vec works for MVP)
+    /// Job queue — workers pop the highest (class, priority) job
     job_queue: Arc<Mutex<Vec<DiskJob>>>,
     /// Worker thread handles
     workers: Vec<JoinHandle<()>>,
@@ -576,6 +666,16 @@ impl DiskReaderPool {
         num_workers: usize,
         assets: Arc<AssetCatalog>,
         streams: Arc<RwLock<HashMap<u32, Arc<StreamRT>>>>,
+    ) -> Self {
+        Self::with_source(num_workers, assets, streams, Arc::new(FileDiskSource))
+    }
+
+    /// Create new disk reader pool reading through a custom disk source
+    pub fn with_source(
+        num_workers: usize,
+        assets: Arc<AssetCatalog>,
+        streams: Arc<RwLock<HashMap<u32, Arc<StreamRT>>>>,
+        source: Arc<dyn DiskSource>,
     ) -> Self {
         let job_queue = Arc::new(Mutex::new(Vec::new()));
         let shutdown = Arc::new(AtomicBool::new(false));
@@ -586,11 +686,12 @@ impl DiskReaderPool {
             let flag = Arc::clone(&shutdown);
             let assets = Arc::clone(&assets);
             let streams = Arc::clone(&streams);
+            let source = Arc::clone(&source);
 
             match thread::Builder::new()
                 .name(format!("disk-reader-{}", i))
                 .spawn(move || {
-                    Self::worker_loop(queue, flag, assets, streams);
+                    Self::worker_loop(queue, flag, assets, streams, source);
                 }) {
                 Ok(handle) => workers.push(handle),
                 Err(e) => {
@@ -616,6 +717,7 @@ impl DiskReaderPool {
         shutdown: Arc<AtomicBool>,
         assets: Arc<AssetCatalog>,
         streams: Arc<RwLock<HashMap<u32, Arc<StreamRT>>>>,
+        source: Arc<dyn DiskSource>,
     ) {
         let mut read_buffer = vec![0.0f32; DISK_READ_CHUNK_FRAMES * 2]; // stereo
 
@@ -624,7 +726,7 @@ impl DiskReaderPool {
                 break;
             }
 
-            // Get highest priority job
+            // Get highest priority job (urgent class first)
             let job = {
                 let mut jobs = queue.lock();
                 if jobs.is_empty() {
@@ -634,7 +736,7 @@ impl DiskReaderPool {
                     let max_idx = jobs
                         .iter()
                         .enumerate()
-                        .max_by_key(|(_, j)| j.priority)
+                        .max_by_key(|(_, j)| j.schedule_key())
                         .map(|(i, _)| i);
 
                     max_idx.map(|idx| jobs.remove(idx))
@@ -643,7 +745,7 @@ impl DiskReaderPool {
 
             match job {
                 Some(job) => {
-                    Self::process_job(&job, &assets, &streams, &mut read_buffer);
+                    Self::process_job(&job, &assets, &streams, source.as_ref(), &mut read_buffer);
                 }
                 None => {
                     // No work, sleep briefly
@@ -658,6 +760,7 @@ impl DiskReaderPool {
         job: &DiskJob,
         assets: &AssetCatalog,
         streams: &RwLock<HashMap<u32, Arc<StreamRT>>>,
+        source: &dyn DiskSource,
         read_buffer: &mut [f32],
     ) {
         // Get asset info
@@ -672,43 +775,16 @@ impl DiskReaderPool {
             None => return,
         };
 
-        // Open file and seek
-        let file = match File::open(&asset.path) {
-            Ok(f) => f,
-            Err(e) => {
-                log::error!("Failed to open file {}: {}", asset.path, e);
-                return;
-            }
-        };
-
-        let mut reader = BufReader::new(file);
-
-        // Calculate byte position
-        let frame_size = asset.channels as u64 * asset.bytes_per_sample as u64;
-        let byte_offset = asset.data_offset + (job.src_frame as u64 * frame_size);
-
-        if reader.seek(SeekFrom::Start(byte_offset)).is_err() {
-            return;
-        }
-
         // Read frames
         let frames_to_read = job.frames.min(DISK_READ_CHUNK_FRAMES);
-        let bytes_to_read =
-            frames_to_read * asset.channels as usize * asset.bytes_per_sample as usize;
-
-        let mut byte_buffer = vec![0u8; bytes_to_read];
-        if reader.read_exact(&mut byte_buffer).is_err() {
-            return;
-        }
-
-        // Convert to f32 (assuming file is already f32)
-        // For WAV files, this would need proper decoding
-        for (i, chunk) in byte_buffer.chunks_exact(4).enumerate() {
-            read_buffer[i] = f32::from_le_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]);
-        }
+        let frames_read =
+            match source.read_frames(&asset, job.src_frame, frames_to_read, read_buffer) {
+                Some(n) => n,
+                None => return,
+            };
 
         // Write to ring buffer
-        let written = stream.ring_buffer.write(read_buffer, frames_to_read);
+        let written = stream.ring_buffer.write(read_buffer, frames_read);
 
         // Update read position
         let old_pos = stream.src_read_frame.load(Ordering::Relaxed);
@@ -908,8 +984,8 @@ impl StreamingEngine {
 
             let available = stream.ring_buffer.available_read();
 
-            // Need more data?
-            if available < HIGH_WATER_FRAMES {
+            // Need more data? Low water → urgent, otherwise prefetch
+            if let Some(class) = DiskJobClass::for_available(available) {
                 let need_frames = (HIGH_WATER_FRAMES - available).min(DISK_READ_CHUNK_FRAMES);
                 let src_frame = stream.src_read_frame.load(Ordering::Relaxed);
 
@@ -921,13 +997,14 @@ impl StreamingEngine {
                     asset_id: stream.asset_id,
                     src_frame,
                     frames: need_frames,
+                    class,
                     priority,
                 });
             }
         }
 
-        // Sort by priority and submit
-        jobs.sort_by_key(|b| std::cmp::Reverse(b.priority));
+        // Sort by class + priority and submit
+        jobs.sort_by_key(|b| std::cmp::Reverse(b.schedule_key()));
 
         if let Some(ref reader) = self.disk_reader {
             reader.submit_batch(jobs);
@@ -1383,4 +1460,91 @@ mod tests {
         assert!(urgent > normal);
         assert!(normal > future);
     }
+
+    /// Slow disk that records the order in which streams are served
+    struct SlowDisk {
+        served: Mutex<Vec<i64>>,
+    }
+
+    impl DiskSource for SlowDisk {
+        fn read_frames(
+            &self,
+            _asset: &AssetInfo,
+            src_frame: i64,
+            frames: usize,
+            output: &mut [f32],
+        ) -> Option<usize> {
+            thread::sleep(std::time::Duration::from_millis(5));
+            output[..frames * 2].fill(0.0);
+            self.served.lock().push(src_frame);
+            Some(frames)
+        }
+    }
+
+    #[test]
+    fn test_disk_job_class() {
+        assert_eq!(DiskJobClass::for_available(0), Some(DiskJobClass::Urgent));
+        assert_eq!(
+            DiskJobClass::for_available(LOW_WATER_FRAMES),
+            Some(DiskJobClass::Urgent)
+        );
+        assert_eq!(
+            DiskJobClass::for_available(12000),
+            Some(DiskJobClass::Normal)
+        );
+        assert_eq!(DiskJobClass::for_available(HIGH_WATER_FRAMES), None);
+    }
+
+    #[test]
+    fn test_urgent_job_served_before_prefetch() {
+        let assets = Arc::new(AssetCatalog::new());
+        let asset_id = assets.register(AssetInfo {
+            path: String::new(),
+            total_frames: 1_000_000,
+            sample_rate: 48000,
+            channels: 2,
+            data_offset: 0,
+            bytes_per_sample: 4,
+        });
+
+        // Stream 1: nearly full buffer, distant prefetch. Stream 2: at low water.
+        let streams = Arc::new(RwLock::new(HashMap::new()));
+        for id in [1, 2] {
+            let stream = StreamRT::new(id, id, asset_id, 0, 1_000_000, 0, 1.0, 2);
+            streams.write().insert(id, Arc::new(stream));
+        }
+
+        let job = |stream_id: u32, src_frame: i64, available: usize| DiskJob {
+            stream_id,
+            asset_id,
+            src_frame,
+            frames: 256,
+            class: DiskJobClass::for_available(available).unwrap(),
+            priority: DiskJob::calculate_priority(available, 0, 0),
+        };
+
+        // Prefetch jobs queued ahead of the urgent one (src_frame tags the job)
+        let mut jobs: Vec<DiskJob> = (0..4).map(|i| job(1, i, 20000)).collect();
+        jobs.push(job(2, 100, LOW_WATER_FRAMES / 2));
+
+        let disk = Arc::new(SlowDisk {
+            served: Mutex::new(Vec::new()),
+        });
+        let mut pool = DiskReaderPool::with_source(1, assets, Arc::clone(&streams), disk.clone());
+        pool.submit_batch(jobs);
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(5);
+        while disk.served.lock().len() < 5 && std::time::Instant::now() < deadline {
+            thread::sleep(std::time::Duration::from_millis(1));
+        }
+        pool.shutdown();
+
+        let served = disk.served.lock().clone();
+        assert_eq!(served.len(), 5);
+        assert_eq!(
+            served[0], 100,
+            "urgent job must be served first: {:?}",
+            served
+        );
+    }
 }
@@ -555,6 +555,19 @@ impl From<u32> for OutputBus {
     }
 }
 
+impl OutputBus {
+    /// Bus for a voice's engine bus ID (one-shots, scheduled and spatial voices)
+    ///
+    /// Voices never play straight into master: 0 and unknown IDs route
+    /// through Sfx.
+    pub fn from_engine_id(bus_id: u32) -> Self {
+        match Self::from(bus_id) {
+            Self::Master => Self::Sfx,
+            bus => bus,
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // TRACK
 // ═══════════════════════════════════════════════════════════════════════════
@@ -592,6 +605,9 @@ pub struct TrackSendSlot {
 /// Maximum number of sends per track
 pub const MAX_TRACK_SENDS: usize = 8;
 
+/// Input trim range (±dB)
+pub const MAX_INPUT_TRIM_DB: f64 = 24.0;
+
 /// Audio track with clips
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Track {
@@ -632,6 +648,12 @@ pub struct Track {
     /// Phase invert (polarity flip)
     #[serde(default)]
     pub phase_inverted: bool,
+    /// Input trim in dB (±MAX_INPUT_TRIM_DB), applied before the insert chain
+    #[serde(default)]
+    pub input_trim_db: f64,
+    /// Sum stereo input to mono (L+R)/2 on both channels
+    #[serde(default)]
+    pub mono_sum: bool,
     /// Instrument plugin ID (for loading on project open)
     #[serde(default)]
     pub instrument_plugin_id: Option<String>,
@@ -687,6 +709,8 @@ impl Track {
             input_bus: None,
             monitor_mode: MonitorMode::Auto,
             phase_inverted: false,
+            input_trim_db: 0.0,
+            mono_sum: false,
             track_type: TrackType::Audio,
             instrument_plugin_id: None,
             output_channel_map: Vec::new(),
@@ -699,6 +723,28 @@ impl Track {
         self.channels >= 2
     }
 
+    /// Apply the input section in place: trim, polarity, then mono sum.
+    /// Runs on the raw clip signal, before inserts, pan and fader.
+    pub fn process_input(&self, left: &mut [f64], right: &mut [f64]) {
+        let trim_db = self.input_trim_db.clamp(-MAX_INPUT_TRIM_DB, MAX_INPUT_TRIM_DB);
+        let trim = 10.0_f64.powf(trim_db / 20.0);
+        let gain = if self.phase_inverted { -trim } else { trim };
+
+        if self.mono_sum {
+            let half = gain * 0.5;
+            for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+                let mono = (*l + *r) * half;
+                *l = mono;
+                *r = mono;
+            }
+        } else if gain != 1.0 {
+            for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+                *l *= gain;
+                *r *= gain;
+            }
+        }
+    }
+
     /// Get output bus for a specific plugin output channel pair.
     /// Returns the mapped bus or falls back to track's default output_bus.
     #[inline]
@@ -788,6 +834,8 @@ impl Track {
             input_bus: None,
             monitor_mode: MonitorMode::Auto,
             phase_inverted: false,
+            input_trim_db: 0.0,
+            mono_sum: false,
             track_type: TrackType::Audio,
             instrument_plugin_id: None,
             output_channel_map: Vec::new(),
@@ -1558,6 +1606,14 @@ pub struct Clip {
     pub fade_in: f64,
     pub fade_out: f64,
 
+    /// Fade-in shape (rendered during playback)
+    #[serde(default = "default_fade_curve")]
+    pub fade_in_curve: CrossfadeCurve,
+
+    /// Fade-out shape (rendered during playback)
+    #[serde(default = "default_fade_curve")]
+    pub fade_out_curve: CrossfadeCurve,
+
     // Gain and state
     pub gain: f64, // 0.0 to 2.0 (linear)
     pub muted: bool,
@@ -1655,6 +1711,11 @@ fn default_iteration_gain() -> f64 {
     1.0
 }
 
+/// Clips saved before fade curves were stored were rendered with quadratic fades
+fn default_fade_curve() -> CrossfadeCurve {
+    CrossfadeCurve::Quadratic
+}
+
 /// MIDI clip entry for instrument tracks — wraps rf_core::MidiClip with timeline position
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MidiClipEntry {
@@ -1699,6 +1760,8 @@ impl Clip {
             source_duration: duration,
             fade_in: 0.0,
             fade_out: 0.0,
+            fade_in_curve: CrossfadeCurve::Linear,
+            fade_out_curve: CrossfadeCurve::Linear,
             gain: 1.0,
             muted: false,
             selected: false,
@@ -1890,6 +1953,46 @@ impl Clip {
         self.gain * vol_env
     }
 
+    /// Clip gain in dB
+    #[inline]
+    pub fn gain_db(&self) -> f64 {
+        if self.gain <= 0.0 {
+            f64::NEG_INFINITY
+        } else {
+            20.0 * self.gain.log10()
+        }
+    }
+
+    /// Set clip gain from dB (clamped to -inf..+12 dB)
+    pub fn set_gain_db(&mut self, db: f64) {
+        self.gain = if db.is_finite() {
+            10.0_f64.powf(db.min(12.0) / 20.0)
+        } else {
+            0.0
+        };
+    }
+
+    /// Fade envelope at clip-relative sample offset (fade-in × fade-out).
+    /// Returns 1.0 outside both fade regions.
+    #[inline]
+    pub fn fade_gain_at(&self, clip_offset_samples: u64, sample_rate: f64) -> f64 {
+        let pos = clip_offset_samples as f64;
+        let mut fade = 1.0;
+
+        let fade_in_samples = self.fade_in * sample_rate;
+        if fade_in_samples > 0.0 && pos < fade_in_samples {
+            fade = self.fade_in_curve.evaluate((pos / fade_in_samples) as f32) as f64;
+        }
+
+        let fade_out_samples = self.fade_out * sample_rate;
+        let remaining = self.duration * sample_rate - pos;
+        if fade_out_samples > 0.0 && remaining < fade_out_samples {
+            fade *= self.fade_out_curve.evaluate((remaining / fade_out_samples) as f32) as f64;
+        }
+
+        fade
+    }
+
     /// Get pan offset at clip-relative sample offset.
     /// Returns pan envelope value or 0.0 (center).
     #[inline]
@@ -2034,6 +2137,8 @@ pub enum CrossfadeCurve {
     Logarithmic,
     /// Exponential (slow attack, fast release)
     Exponential,
+    /// Quadratic t² (the fixed clip fade shape before per-clip curves)
+    Quadratic,
     /// Custom curve defined by control points
     /// Vec of (position, value) where position and value are 0.0-1.0
     Custom(Vec<(f32, f32)>),
@@ -2065,6 +2170,7 @@ impl CrossfadeCurve {
                 // (10^t - 1) / 9
                 (10.0_f32.powf(t) - 1.0) / 9.0
             }
+            CrossfadeCurve::Quadratic => t * t,
             CrossfadeCurve::Custom(points) => Self::evaluate_custom(points, t),
         }
     }
@@ -3298,6 +3404,8 @@ impl TrackManager {
         new_clip.source_duration = original.source_duration;
         new_clip.fade_in = original.fade_in;
         new_clip.fade_out = original.fade_out;
+        new_clip.fade_in_curve = original.fade_in_curve.clone();
+        new_clip.fade_out_curve = original.fade_out_curve.clone();
         new_clip.gain = original.gain;
         new_clip.color = original.color;
 
@@ -5785,6 +5893,62 @@ mod tests {
         assert_eq!(track.output_bus, OutputBus::Master);
     }
 
+    #[test]
+    fn test_output_bus_from_engine_id() {
+        assert_eq!(OutputBus::from_engine_id(0), OutputBus::Sfx);
+        assert_eq!(OutputBus::from_engine_id(1), OutputBus::Music);
+        assert_eq!(OutputBus::from_engine_id(2), OutputBus::Sfx);
+        assert_eq!(OutputBus::from_engine_id(3), OutputBus::Voice);
+        assert_eq!(OutputBus::from_engine_id(4), OutputBus::Ambience);
+        assert_eq!(OutputBus::from_engine_id(5), OutputBus::Aux);
+        assert_eq!(OutputBus::from_engine_id(99), OutputBus::Sfx);
+    }
+
+    #[test]
+    fn test_input_polarity_invert_and_trim() {
+        let mut track = Track::new("Input", 0xFF0000FF, OutputBus::Master);
+        let mut left = vec![0.5, -0.25, 1.0, 0.0];
+        let mut right = vec![-0.5, 0.75, -1.0, 0.1];
+
+        // Neutral input section passes through
+        let (orig_l, orig_r) = (left.clone(), right.clone());
+        track.process_input(&mut left, &mut right);
+        assert_eq!(left, orig_l);
+        assert_eq!(right, orig_r);
+
+        track.phase_inverted = true;
+        track.process_input(&mut left, &mut right);
+        for i in 0..4 {
+            assert_eq!(left[i], -orig_l[i]);
+            assert_eq!(right[i], -orig_r[i]);
+        }
+
+        // -6.02 dB trim halves, polarity still flipped back
+        track.input_trim_db = -20.0 * 2.0_f64.log10();
+        track.process_input(&mut left, &mut right);
+        for i in 0..4 {
+            assert!((left[i] - orig_l[i] * 0.5).abs() < 1e-12);
+            assert!((right[i] - orig_r[i] * 0.5).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_input_mono_sum_hard_panned() {
+        let mut track = Track::new("Input", 0xFF0000FF, OutputBus::Master);
+        track.mono_sum = true;
+
+        // Stereo clip with everything in the left channel
+        let signal: Vec<f64> = (0..64).map(|i| (i as f64 * 0.3).sin()).collect();
+        let mut left = signal.clone();
+        let mut right = vec![0.0; 64];
+        track.process_input(&mut left, &mut right);
+
+        assert_eq!(left, right);
+        for (out, src) in left.iter().zip(&signal) {
+            assert!((out - src * 0.5).abs() < 1e-12);
+        }
+    }
+
     #[test]
     fn test_create_and_move_clip() {
         let manager = TrackManager::new();
@@ -5851,6 +6015,7 @@ mod tests {
             CrossfadeCurve::SCurve,
             CrossfadeCurve::Logarithmic,
             CrossfadeCurve::Exponential,
+            CrossfadeCurve::Quadratic,
         ];
 
         for curve in curves {
@@ -5981,6 +6146,22 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_legacy_clip_loads_quadratic_fades() {
+        let mut clip = Clip::new(TrackId(1), "Legacy", "legacy.wav", 0.0, 1.0);
+        clip.fade_in = 0.1;
+        let mut value = serde_json::to_value(&clip).unwrap();
+        let fields = value.as_object_mut().unwrap();
+        fields.remove("fade_in_curve");
+        fields.remove("fade_out_curve");
+
+        let legacy: Clip = serde_json::from_value(value).unwrap();
+        assert_eq!(legacy.fade_in_curve, CrossfadeCurve::Quadratic);
+        assert_eq!(legacy.fade_out_curve, CrossfadeCurve::Quadratic);
+        // Halfway through the 100ms fade-in: (0.5)² as before
+        assert!((legacy.fade_gain_at(2400, 48000.0) - 0.25).abs() < 1e-6);
+    }
+
     #[test]
     fn test_asymmetric_crossfade_creation() {
         let manager = TrackManager::new();
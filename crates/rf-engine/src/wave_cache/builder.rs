@@ -288,7 +288,7 @@ impl WaveCacheBuilder {
 
             for chunk in channel_samples.chunks(samples_per_tile) {
                 let (min, max) = self.find_min_max(chunk);
-                level.tiles[ch_idx].push(TileData::new(min, max));
+                level.tiles[ch_idx].push(TileData::new(min, max, rms(chunk)));
             }
         }
     }
@@ -379,7 +379,7 @@ pub fn build_from_samples(
 
             for chunk in ch_samples.chunks(samples_per_tile) {
                 let (min, max) = find_min_max_simple(chunk);
-                wfc.mip_levels[level_idx].tiles[ch_idx].push(TileData::new(min, max));
+                wfc.mip_levels[level_idx].tiles[ch_idx].push(TileData::new(min, max, rms(chunk)));
             }
         }
     }
@@ -411,6 +411,16 @@ fn find_min_max_simple(samples: &[f32]) -> (f32, f32) {
     (min, max)
 }
 
+/// RMS of a chunk (accumulated in f64)
+fn rms(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+
+    let sum_sq: f64 = samples.iter().map(|&s| (s as f64) * (s as f64)).sum();
+    (sum_sq / samples.len() as f64).sqrt() as f32
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // TESTS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -428,6 +438,30 @@ mod tests {
         assert!((max - 0.9).abs() < 0.0001);
     }
 
+    #[test]
+    fn test_sine_rms_and_peak_tiles() {
+        // Full-scale 1 kHz sine, 1 second
+        let samples: Vec<f32> = (0..48000)
+            .map(|i| (2.0 * std::f32::consts::PI * 1000.0 * i as f32 / 48000.0).sin())
+            .collect();
+
+        let dir = tempfile::tempdir().unwrap();
+        let wfc = build_from_samples(&samples, 1, 48000, &dir.path().join("sine.wfc")).unwrap();
+
+        for level in &wfc.mip_levels {
+            // Skip the partial last tile
+            let full_tiles = samples.len() / level.samples_per_tile;
+            for tile in &level.tiles[0][..full_tiles] {
+                assert!((tile.rms - 0.707).abs() < 0.01, "rms {}", tile.rms);
+                assert!(tile.max > 0.99 && tile.min < -0.99);
+            }
+        }
+
+        // RMS survives the round trip through disk
+        let loaded = WfcFile::load(&dir.path().join("sine.wfc")).unwrap();
+        assert!((loaded.mip_levels[4].tiles[0][3].rms - 0.707).abs() < 0.01);
+    }
+
     #[test]
     fn test_build_state_conversion() {
         assert_eq!(BuildState::from(0), BuildState::Idle);
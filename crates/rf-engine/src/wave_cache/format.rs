@@ -6,7 +6,7 @@
 //! | Header (64 bytes)                      |
 //! +----------------------------------------+
 //! | Mip Level 0 (finest - 256 samples)     |
-//! |   - Tiles: [min, max] per tile         |
+//! |   - Tiles: [min, max, rms] per tile    |
 //! +----------------------------------------+
 //! | Mip Level 1 (512 samples)              |
 //! +----------------------------------------+
@@ -16,7 +16,9 @@
 //! +----------------------------------------+
 //! ```
 //!
-//! Each mip level stores (min, max) pairs as f32 for each channel.
+//! Each mip level stores (min, max, rms) triples as f32 for each channel.
+//! Version 1 files stored (min, max) pairs only; they are still readable,
+//! with RMS estimated from the peak range until the cache is rebuilt.
 
 use std::fs::File;
 use std::io::{BufReader, BufWriter, Read, Write};
@@ -34,7 +36,10 @@ use super::WaveCacheError;
 pub const WFC_MAGIC: [u8; 4] = *b"WFC1";
 
 /// Current format version
-pub const WFC_VERSION: u16 = 1;
+pub const WFC_VERSION: u16 = 2;
+
+/// Oldest format version that can still be read (min/max tiles, no RMS)
+pub const WFC_MIN_VERSION: u16 = 1;
 
 /// Number of mip levels (LOD)
 pub const NUM_MIP_LEVELS: usize = 8;
@@ -78,10 +83,8 @@ pub struct WfcHeader {
     pub duration_secs: f64,
     /// Number of tiles at base (finest) mip level
     pub num_base_tiles: u32,
-    /// Offset to mip level 0 data
+    /// Offset to each mip level's data
     pub mip_offsets: [u32; NUM_MIP_LEVELS],
-    /// Reserved padding to 64 bytes
-    pub _reserved: [u8; 4],
 }
 
 impl WfcHeader {
@@ -99,7 +102,6 @@ impl WfcHeader {
             duration_secs,
             num_base_tiles,
             mip_offsets: [0; NUM_MIP_LEVELS],
-            _reserved: [0; 4],
         }
     }
 
@@ -109,6 +111,24 @@ impl WfcHeader {
         (self.total_frames as usize).div_ceil(samples_per_tile)
     }
 
+    /// Bytes per tile for this file's format version
+    pub fn tile_bytes(&self) -> usize {
+        if self.version < 2 { 8 } else { TileData::BYTES }
+    }
+
+    /// Bytes between the 64-byte header and the first tile
+    ///
+    /// Version 1 wrote 4 reserved bytes past the header without counting
+    /// them in the mip offsets.
+    pub fn data_padding(&self) -> usize {
+        if self.version < 2 { 4 } else { 0 }
+    }
+
+    /// File position of a mip level's tile data
+    pub fn level_offset(&self, level: usize) -> usize {
+        self.mip_offsets[level] as usize + self.data_padding()
+    }
+
     /// Validate header
     pub fn validate(&self) -> Result<(), WaveCacheError> {
         if self.magic != WFC_MAGIC {
@@ -116,7 +136,7 @@ impl WfcHeader {
                 "Invalid magic number".to_string(),
             ));
         }
-        if self.version != WFC_VERSION {
+        if !(WFC_MIN_VERSION..=WFC_VERSION).contains(&self.version) {
             return Err(WaveCacheError::InvalidFormat(format!(
                 "Unsupported version: {}",
                 self.version
@@ -148,8 +168,6 @@ impl WfcHeader {
             bytes.extend_from_slice(&offset.to_le_bytes());
         }
 
-        bytes.extend_from_slice(&self._reserved);
-
         bytes
     }
 
@@ -187,9 +205,6 @@ impl WfcHeader {
             ]);
         }
 
-        let mut _reserved = [0u8; 4];
-        _reserved.copy_from_slice(&bytes[60..64]);
-
         let header = Self {
             magic,
             version,
@@ -200,7 +215,6 @@ impl WfcHeader {
             duration_secs,
             num_base_tiles,
             mip_offsets,
-            _reserved,
         };
 
         header.validate()?;
@@ -247,8 +261,7 @@ impl MipLevel {
     pub fn byte_size(&self) -> usize {
         let tiles_per_channel = self.tile_count();
         let channels = self.tiles.len();
-        // Each tile is 8 bytes (min: f32, max: f32)
-        channels * tiles_per_channel * 8
+        channels * tiles_per_channel * TileData::BYTES
     }
 }
 
@@ -263,11 +276,16 @@ pub struct TileData {
     pub min: f32,
     /// Maximum sample value in tile
     pub max: f32,
+    /// RMS (root mean square) energy in tile
+    pub rms: f32,
 }
 
 impl TileData {
-    pub fn new(min: f32, max: f32) -> Self {
-        Self { min, max }
+    /// Serialized size (min, max, rms as f32)
+    pub const BYTES: usize = 12;
+
+    pub fn new(min: f32, max: f32, rms: f32) -> Self {
+        Self { min, max, rms }
     }
 
     /// Get amplitude (range)
@@ -275,27 +293,50 @@ impl TileData {
         self.max - self.min
     }
 
-    /// Merge two tiles (take min of mins, max of maxes)
+    /// Merge two tiles of equal length (min of mins, max of maxes, combined RMS)
     pub fn merge(&self, other: &TileData) -> TileData {
         TileData {
             min: self.min.min(other.min),
             max: self.max.max(other.max),
+            rms: ((self.rms * self.rms + other.rms * other.rms) / 2.0).sqrt(),
         }
     }
 
     /// Convert to bytes (little-endian)
-    pub fn to_bytes(&self) -> [u8; 8] {
-        let mut bytes = [0u8; 8];
+    pub fn to_bytes(&self) -> [u8; Self::BYTES] {
+        let mut bytes = [0u8; Self::BYTES];
         bytes[0..4].copy_from_slice(&self.min.to_le_bytes());
         bytes[4..8].copy_from_slice(&self.max.to_le_bytes());
+        bytes[8..12].copy_from_slice(&self.rms.to_le_bytes());
         bytes
     }
 
     /// Convert from bytes (little-endian)
-    pub fn from_bytes(bytes: &[u8; 8]) -> Self {
+    pub fn from_bytes(bytes: &[u8; Self::BYTES]) -> Self {
         Self {
             min: f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
             max: f32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]),
+            rms: f32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]),
+        }
+    }
+
+    /// Convert from a version 1 (min, max) tile
+    ///
+    /// Version 1 stored no RMS, so it is estimated as that of a sine
+    /// spanning the tile's peak range.
+    pub fn from_v1_bytes(bytes: &[u8; 8]) -> Self {
+        let min = f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let max = f32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+        let peak = max.abs().max(min.abs());
+        Self::new(min, max, peak * std::f32::consts::FRAC_1_SQRT_2)
+    }
+
+    /// Parse a tile in the layout of the given format version
+    pub fn from_versioned_bytes(bytes: &[u8], version: u16) -> Option<Self> {
+        if version < 2 {
+            Some(Self::from_v1_bytes(bytes.get(..8)?.try_into().ok()?))
+        } else {
+            Some(Self::from_bytes(bytes.get(..Self::BYTES)?.try_into().ok()?))
         }
     }
 }
@@ -337,8 +378,9 @@ impl WfcFile {
             current_offset += level.byte_size() as u32;
         }
 
-        // Write header with correct offsets
+        // Write header with correct offsets (always in the current layout)
         let mut header = self.header;
+        header.version = WFC_VERSION;
         header.mip_offsets = mip_offsets;
         writer
             .write_all(&header.to_bytes())
@@ -373,8 +415,14 @@ impl WfcFile {
             .read_exact(&mut header_bytes)
             .map_err(|e| WaveCacheError::IoError(e.to_string()))?;
 
-        let header = WfcHeader::from_bytes(&header_bytes)?;
+        let mut header = WfcHeader::from_bytes(&header_bytes)?;
         let channels = header.channels as usize;
+        let tile_bytes = header.tile_bytes();
+
+        let mut padding = vec![0u8; header.data_padding()];
+        reader
+            .read_exact(&mut padding)
+            .map_err(|e| WaveCacheError::IoError(e.to_string()))?;
 
         // Read mip levels
         let mip_levels: [MipLevel; NUM_MIP_LEVELS] = std::array::from_fn(|level_idx| {
@@ -385,9 +433,11 @@ impl WfcFile {
             for ch in 0..channels {
                 level.tiles[ch] = Vec::with_capacity(num_tiles);
                 for _ in 0..num_tiles {
-                    let mut tile_bytes = [0u8; 8];
-                    if reader.read_exact(&mut tile_bytes).is_ok() {
-                        level.tiles[ch].push(TileData::from_bytes(&tile_bytes));
+                    let mut bytes = [0u8; TileData::BYTES];
+                    if reader.read_exact(&mut bytes[..tile_bytes]).is_ok()
+                        && let Some(tile) = TileData::from_versioned_bytes(&bytes, header.version)
+                    {
+                        level.tiles[ch].push(tile);
                     }
                 }
             }
@@ -395,6 +445,9 @@ impl WfcFile {
             level
         });
 
+        // Tiles are now held in the current layout
+        header.version = WFC_VERSION;
+
         Ok(Self { header, mip_levels })
     }
 
@@ -478,19 +531,16 @@ impl WfcFileMmap {
             return None;
         }
 
-        let offset = self.header.mip_offsets[level] as usize;
+        let offset = self.header.level_offset(level);
         let tiles_per_channel = num_tiles;
+        let tile_bytes = self.header.tile_bytes();
 
-        // Calculate byte offset: offset + (channel * tiles_per_channel + tile_idx) * 8
-        let tile_offset = offset + (channel * tiles_per_channel + tile_idx) * 8;
-
-        if tile_offset + 8 > self.mmap.len() {
-            return None;
-        }
+        // Calculate byte offset: offset + (channel * tiles_per_channel + tile_idx) * tile_bytes
+        let tile_offset = offset + (channel * tiles_per_channel + tile_idx) * tile_bytes;
 
-        let bytes: [u8; 8] = self.mmap[tile_offset..tile_offset + 8].try_into().ok()?;
+        let bytes = self.mmap.get(tile_offset..tile_offset + tile_bytes)?;
 
-        Some(TileData::from_bytes(&bytes))
+        TileData::from_versioned_bytes(bytes, self.header.version)
     }
 
     /// Get tiles for a range (optimized batch read)
@@ -514,20 +564,21 @@ impl WfcFileMmap {
             return Vec::new();
         }
 
-        let offset = self.header.mip_offsets[level] as usize;
+        let offset = self.header.level_offset(level);
         let tiles_per_channel = num_tiles;
+        let tile_bytes = self.header.tile_bytes();
 
         let mut result = Vec::with_capacity(end - start);
 
         for tile_idx in start..end {
-            let tile_offset = offset + (channel * tiles_per_channel + tile_idx) * 8;
+            let tile_offset = offset + (channel * tiles_per_channel + tile_idx) * tile_bytes;
 
-            if tile_offset + 8 > self.mmap.len() {
+            let Some(bytes) = self.mmap.get(tile_offset..tile_offset + tile_bytes) else {
                 break;
-            }
+            };
 
-            if let Ok(bytes) = self.mmap[tile_offset..tile_offset + 8].try_into() {
-                result.push(TileData::from_bytes(&bytes));
+            if let Some(tile) = TileData::from_versioned_bytes(bytes, self.header.version) {
+                result.push(tile);
             }
         }
 
@@ -572,6 +623,7 @@ mod tests {
     fn test_header_serialization() {
         let header = WfcHeader::new(2, 48000, 480000);
         let bytes = header.to_bytes();
+        assert_eq!(bytes.len(), 64);
         let parsed = WfcHeader::from_bytes(&bytes).unwrap();
 
         assert_eq!(parsed.magic, WFC_MAGIC);
@@ -583,12 +635,50 @@ mod tests {
 
     #[test]
     fn test_tile_serialization() {
-        let tile = TileData::new(-0.5, 0.8);
+        let tile = TileData::new(-0.5, 0.8, 0.4);
         let bytes = tile.to_bytes();
         let parsed = TileData::from_bytes(&bytes);
 
         assert!((parsed.min - (-0.5)).abs() < 0.0001);
         assert!((parsed.max - 0.8).abs() < 0.0001);
+        assert!((parsed.rms - 0.4).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_v1_file_migration() {
+        // Version 1 layout: mono, 256 frames, one (min, max) tile per level,
+        // 4 reserved bytes after the header
+        let mut header = WfcHeader::new(1, 48000, 256);
+        header.version = 1;
+        for (level, offset) in header.mip_offsets.iter_mut().enumerate() {
+            *offset = (64 + level * 8) as u32;
+        }
+        let mut bytes = header.to_bytes();
+        bytes.extend_from_slice(&[0; 4]);
+        for _ in 0..NUM_MIP_LEVELS {
+            bytes.extend_from_slice(&(-0.5f32).to_le_bytes());
+            bytes.extend_from_slice(&1.0f32.to_le_bytes());
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("v1.wfc");
+        std::fs::write(&path, &bytes).unwrap();
+
+        let mmap = WfcFileMmap::open(&path).unwrap();
+        assert_eq!(mmap.header.version, 1);
+        let tile = mmap.get_tile(3, 0, 0).unwrap();
+        assert_eq!((tile.min, tile.max), (-0.5, 1.0));
+        assert!((tile.rms - std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-6);
+
+        // Loading upgrades in memory; saving rewrites in the current layout
+        let wfc = WfcFile::load(&path).unwrap();
+        assert_eq!(wfc.header.version, WFC_VERSION);
+        assert_eq!(wfc.mip_levels[7].tile_count(), 1);
+        wfc.save(&path).unwrap();
+        let reopened = WfcFileMmap::open(&path).unwrap();
+        assert_eq!(reopened.header.version, WFC_VERSION);
+        let tile = reopened.get_tile(7, 0, 0).unwrap();
+        assert_eq!((tile.min, tile.max), (-0.5, 1.0));
     }
 
     #[test]
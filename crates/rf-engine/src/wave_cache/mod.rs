@@ -17,7 +17,7 @@ mod query;
 pub use builder::{BuildProgress, BuildState, WaveCacheBuilder, build_from_samples};
 pub use format::{
     BASE_TILE_SAMPLES, MIP_TILE_SAMPLES, MipLevel, NUM_MIP_LEVELS, TileData, WFC_MAGIC,
-    WFC_VERSION, WfcFile, WfcFileMmap, WfcHeader,
+    WFC_MIN_VERSION, WFC_VERSION, WfcFile, WfcFileMmap, WfcHeader,
 };
 pub use query::{CachedTile, TileRequest, TileResponse, WaveCacheQuery, tiles_to_flat_array};
 
@@ -294,6 +294,21 @@ impl WaveCacheManager {
 
         // Check if .wfc file exists on disk
         let cache_path = self.cache_path_for(audio_path);
+
+        // Older caches lack per-tile RMS: rebuild them from the source audio
+        if let Ok(old) = WfcFileMmap::open(&cache_path)
+            && old.header.version < WFC_VERSION
+        {
+            log::info!(
+                "[WaveCache] Rebuilding {} (cache format v{} -> v{})",
+                audio_path,
+                old.header.version,
+                WFC_VERSION
+            );
+            drop(old);
+            let _ = std::fs::remove_file(&cache_path);
+        }
+
         if cache_path.exists() {
             // P3.4: Check file size to decide between mmap and full load
             let file_size = std::fs::metadata(&cache_path).map(|m| m.len()).unwrap_or(0);
@@ -443,6 +458,7 @@ impl WaveCacheManager {
                     frame_offset: ((start_tile + i) * samples_per_tile) as u64,
                     min: td.min,
                     max: td.max,
+                    rms: td.rms,
                 })
                 .collect();
             channel_tiles.push(tiles);
@@ -51,6 +51,8 @@ pub struct CachedTile {
     pub min: f32,
     /// Max peak value
     pub max: f32,
+    /// RMS energy
+    pub rms: f32,
 }
 
 impl CachedTile {
@@ -107,6 +109,7 @@ impl<'a> WaveCacheQuery<'a> {
                         frame_offset: (tile_idx * samples_per_tile) as u64,
                         min: tile_data.min,
                         max: tile_data.max,
+                        rms: tile_data.rms,
                     });
                 }
             }
@@ -147,6 +150,7 @@ impl<'a> WaveCacheQuery<'a> {
                     frame_offset: (tile_idx * samples_per_tile) as u64,
                     min: td.min,
                     max: td.max,
+                    rms: td.rms,
                 })
             })
             .collect();
@@ -176,11 +180,15 @@ impl<'a> WaveCacheQuery<'a> {
                 // Merge all channels
                 let mut min = f32::MAX;
                 let mut max = f32::MIN;
+                let mut sum_sq = 0.0f32;
+                let mut count = 0;
 
                 for ch in 0..num_channels {
                     if let Some(tile) = level_data.tiles.get(ch).and_then(|t| t.get(tile_idx)) {
                         min = min.min(tile.min);
                         max = max.max(tile.max);
+                        sum_sq += tile.rms * tile.rms;
+                        count += 1;
                     }
                 }
 
@@ -190,6 +198,7 @@ impl<'a> WaveCacheQuery<'a> {
                         frame_offset: (tile_idx * samples_per_tile) as u64,
                         min,
                         max,
+                        rms: (sum_sq / count as f32).sqrt(),
                     })
                 } else {
                     None
@@ -210,16 +219,20 @@ impl<'a> WaveCacheQuery<'a> {
         // Merge all channels
         let mut min = f32::MAX;
         let mut max = f32::MIN;
+        let mut sum_sq = 0.0f32;
+        let mut count = 0;
 
         for ch_tiles in &level.tiles {
             if let Some(tile) = ch_tiles.get(tile_idx) {
                 min = min.min(tile.min);
                 max = max.max(tile.max);
+                sum_sq += tile.rms * tile.rms;
+                count += 1;
             }
         }
 
         if min <= max {
-            Some(TileData::new(min, max))
+            Some(TileData::new(min, max, (sum_sq / count as f32).sqrt()))
         } else {
             None
         }
@@ -343,7 +356,7 @@ mod tests {
                 wfc.mip_levels[level_idx].tiles[ch] = (0..num_tiles)
                     .map(|i| {
                         let t = i as f32 / num_tiles as f32;
-                        TileData::new(-0.5 * t, 0.5 * t)
+                        TileData::new(-0.5 * t, 0.5 * t, 0.35 * t)
                     })
                     .collect();
             }
@@ -379,12 +392,14 @@ mod tests {
                 frame_offset: 0,
                 min: -0.5,
                 max: 0.5,
+                rms: 0.35,
             },
             CachedTile {
                 tile_index: 1,
                 frame_offset: 256,
                 min: -0.3,
                 max: 0.3,
+                rms: 0.2,
             },
         ];
 
@@ -104,6 +104,7 @@ pub use state::{
     RtpcDefinition,
     RtpcInterpolation,
     RtpcTargetParameter,
+    ScheduledStinger,
     SequenceContainer,
     SequenceContainerState,
     SequenceEndBehavior,
@@ -264,6 +264,23 @@ impl EventManagerHandle {
         self.shared.rtpc_definitions.write().insert(rtpc.id, rtpc);
     }
 
+    /// Get RTPC definition by name
+    pub fn get_rtpc_by_name(&self, name: &str) -> Option<RtpcDefinition> {
+        self.shared
+            .rtpc_definitions
+            .read()
+            .values()
+            .find(|d| d.name == name)
+            .cloned()
+    }
+
+    /// Resolve state group and state names to (group_id, state_id)
+    pub fn get_state_ids(&self, group: &str, state: &str) -> Option<(u32, u32)> {
+        let groups = self.shared.state_groups.read();
+        let group = groups.values().find(|g| g.name == group)?;
+        Some((group.id, group.state_id(state)?))
+    }
+
     // ═══════════════════════════════════════════════════════════════════════════
     // COMMAND POSTING (called from UI/game thread)
     // ═══════════════════════════════════════════════════════════════════════════
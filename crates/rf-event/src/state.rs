@@ -1677,6 +1677,9 @@ pub struct Stinger {
 }
 
 impl Stinger {
+    /// Smallest custom grid (a 16th note); zero, negative or NaN grids snap to it
+    pub const MIN_CUSTOM_GRID_BEATS: f32 = 0.25;
+
     /// Create new stinger
     pub fn new(id: u32, name: impl Into<String>, sound_id: u32) -> Self {
         Self {
@@ -1702,7 +1705,7 @@ impl Stinger {
 
     /// Set custom grid
     pub fn with_custom_grid(mut self, beats: f32) -> Self {
-        self.custom_grid_beats = beats.max(0.25);
+        self.custom_grid_beats = beats.max(Self::MIN_CUSTOM_GRID_BEATS);
         self.sync_point = MusicSyncPoint::CustomGrid;
         self
     }
@@ -1881,6 +1884,32 @@ pub struct MusicSystem {
     pub volume: f32,
     /// Music bus ID
     pub music_bus_id: u32,
+    /// Playback position within the current segment (frames)
+    #[serde(default)]
+    pub position_frames: u64,
+    /// Sample rate used to convert musical time to frames
+    #[serde(default = "default_music_sample_rate")]
+    pub sample_rate: u32,
+    /// Stingers waiting for their sync point
+    #[serde(default)]
+    pub pending_stingers: Vec<ScheduledStinger>,
+}
+
+fn default_music_sample_rate() -> u32 {
+    48000
+}
+
+/// Stinger scheduled against the current segment's timeline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledStinger {
+    /// Stinger ID
+    pub stinger_id: u32,
+    /// Sound/event ID to play
+    pub sound_id: u32,
+    /// Start position within the segment (frames)
+    pub start_frame: u64,
+    /// Frames from the position at which it was posted
+    pub offset_frames: u64,
 }
 
 impl Default for MusicSystem {
@@ -1899,6 +1928,9 @@ impl MusicSystem {
             next_segment_id: None,
             volume: 1.0,
             music_bus_id: 0,
+            position_frames: 0,
+            sample_rate: default_music_sample_rate(),
+            pending_stingers: Vec::new(),
         }
     }
 
@@ -1945,6 +1977,83 @@ impl MusicSystem {
             self.next_segment_id = Some(segment_id);
         }
     }
+
+    /// Set playback position within the current segment (frames)
+    pub fn set_position_frames(&mut self, frames: u64) {
+        self.position_frames = frames;
+    }
+
+    /// Schedule a stinger at the next sync point of the current segment
+    ///
+    /// `Beat`, `Bar` and `CustomGrid` snap to the segment's musical grid,
+    /// `Marker` to the next `MarkerType::Sync` marker and `SegmentEnd` to
+    /// the segment's last bar. A missing sync marker falls back to the
+    /// segment end; with no segment playing the stinger starts immediately.
+    /// Returns `None` if the stinger does not exist.
+    pub fn post_stinger(
+        &mut self,
+        stinger_id: u32,
+        sync: MusicSyncPoint,
+    ) -> Option<ScheduledStinger> {
+        let stinger = self.get_stinger(stinger_id)?;
+        let sample_rate = self.sample_rate.max(1) as f64;
+        let position_secs = self.position_frames as f64 / sample_rate;
+
+        let start_secs = match self.current_segment_id.and_then(|id| self.get_segment(id)) {
+            None => position_secs,
+            Some(segment) => {
+                let now = position_secs as f32;
+                let segment_end = segment.bars_to_secs(segment.duration_bars as f32);
+                let secs = match sync {
+                    MusicSyncPoint::Immediate => now,
+                    MusicSyncPoint::Beat => segment.next_beat_time(now),
+                    MusicSyncPoint::Bar => segment.next_bar_time(now),
+                    MusicSyncPoint::CustomGrid => {
+                        // The field is public (and deserialized), so clamp here as well
+                        let grid_beats = stinger
+                            .custom_grid_beats
+                            .max(Stinger::MIN_CUSTOM_GRID_BEATS);
+                        let grid_secs = grid_beats * 60.0 / segment.tempo;
+                        (now / grid_secs).ceil() * grid_secs
+                    }
+                    MusicSyncPoint::Marker => {
+                        let now_bars = segment.secs_to_bars(now);
+                        segment
+                            .markers
+                            .iter()
+                            .find(|m| {
+                                m.marker_type == MarkerType::Sync && m.position_bars >= now_bars
+                            })
+                            .map_or(segment_end, |m| segment.bars_to_secs(m.position_bars))
+                    }
+                    MusicSyncPoint::SegmentEnd => segment_end,
+                };
+                secs as f64
+            }
+        };
+
+        let start_frame = ((start_secs * sample_rate).round() as u64).max(self.position_frames);
+        let scheduled = ScheduledStinger {
+            stinger_id,
+            sound_id: stinger.sound_id,
+            start_frame,
+            offset_frames: start_frame - self.position_frames,
+        };
+        self.pending_stingers.push(scheduled);
+        Some(scheduled)
+    }
+
+    /// Advance playback by `frames` and return stingers that start in that span
+    pub fn advance(&mut self, frames: u64) -> Vec<ScheduledStinger> {
+        let end = self.position_frames + frames;
+        let (due, pending): (Vec<_>, Vec<_>) = self
+            .pending_stingers
+            .drain(..)
+            .partition(|s| s.start_frame < end);
+        self.pending_stingers = pending;
+        self.position_frames = end;
+        due
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -2224,4 +2333,68 @@ mod tests {
         assert_eq!(curve.evaluate(0.5), 0.5);
         assert_eq!(curve.evaluate(1.0), 1.0);
     }
+
+    #[test]
+    fn test_stinger_next_bar() {
+        let mut music = MusicSystem::new();
+        // 120 BPM, 4/4 → one bar = 2 s = 96000 frames at 48 kHz
+        music.add_segment(MusicSegment::new(1, "Base", 100).with_duration(8));
+        music.add_stinger(Stinger::new(7, "BigWin", 200));
+        music.set_current_segment(1);
+        music.set_position_frames(120_000); // 1.25 bars in
+
+        let scheduled = music.post_stinger(7, MusicSyncPoint::Bar).unwrap();
+        assert_eq!(scheduled.sound_id, 200);
+        assert_eq!(scheduled.start_frame, 192_000);
+        assert_eq!(scheduled.offset_frames, 72_000);
+
+        // Not due within the first block, due once the bar line is crossed
+        assert!(music.advance(48_000).is_empty());
+        let due = music.advance(48_000);
+        assert_eq!(due, vec![scheduled]);
+        assert!(music.pending_stingers.is_empty());
+    }
+
+    #[test]
+    fn test_stinger_sync_marker() {
+        let mut music = MusicSystem::new();
+        let mut segment = MusicSegment::new(1, "Base", 100).with_duration(8);
+        segment.add_marker("Exit", 2.0, MarkerType::Exit);
+        segment.add_marker("Hit", 3.5, MarkerType::Sync);
+        music.add_segment(segment);
+        music.add_stinger(Stinger::new(7, "BigWin", 200));
+        music.set_current_segment(1);
+        music.set_position_frames(48_000);
+
+        let scheduled = music.post_stinger(7, MusicSyncPoint::Marker).unwrap();
+        assert_eq!(scheduled.start_frame, 336_000); // 3.5 bars * 96000
+
+        // Past the last sync marker → segment end
+        music.set_position_frames(400_000);
+        let scheduled = music.post_stinger(7, MusicSyncPoint::Marker).unwrap();
+        assert_eq!(scheduled.start_frame, 768_000);
+
+        assert!(music.post_stinger(99, MusicSyncPoint::Bar).is_none());
+    }
+
+    #[test]
+    fn test_stinger_custom_grid_clamped() {
+        let mut music = MusicSystem::new();
+        music.add_segment(MusicSegment::new(1, "Base", 100).with_duration(8));
+        music.set_current_segment(1);
+        music.set_position_frames(121_000);
+
+        // 0.25 beats at 120 BPM = 6000 frames
+        for beats in [0.0, -2.0, f32::NAN] {
+            let mut stinger = Stinger::new(7, "Hit", 200);
+            stinger.custom_grid_beats = beats;
+            music.remove_stinger(7);
+            music.add_stinger(stinger);
+            let scheduled = music.post_stinger(7, MusicSyncPoint::CustomGrid).unwrap();
+            assert_eq!(scheduled.start_frame, 126_000);
+        }
+
+        let stinger = Stinger::new(8, "Hit", 200).with_custom_grid(-1.0);
+        assert_eq!(stinger.custom_grid_beats, Stinger::MIN_CUSTOM_GRID_BEATS);
+    }
 }
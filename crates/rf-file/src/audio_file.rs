@@ -296,9 +296,24 @@ impl AudioData {
 
 /// Read WAV file using hound
 pub fn read_wav<P: AsRef<Path>>(path: P) -> FileResult<AudioData> {
-    let reader = hound::WavReader::open(path.as_ref())?;
+    let path = path.as_ref();
+    let file_size = std::fs::metadata(path)?.len();
+    let reader = hound::WavReader::open(path)?;
     let spec = reader.spec();
 
+    if spec.sample_format == hound::SampleFormat::Int && spec.bits_per_sample > 32 {
+        return Err(FileError::UnsupportedFormat(format!(
+            "{}-bit integer WAV",
+            spec.bits_per_sample
+        )));
+    }
+
+    // The data chunk size comes from the header and may be corrupt: never
+    // read (or preallocate) more samples than the file can actually hold,
+    // and stop at the first unreadable sample (truncated file).
+    let bytes_per_sample = spec.bits_per_sample.div_ceil(8).max(1) as u64;
+    let num_samples = (reader.len() as u64).min(file_size / bytes_per_sample) as usize;
+
     let num_channels = spec.channels as usize;
     let sample_rate = spec.sample_rate;
     let bit_depth = match (spec.bits_per_sample, spec.sample_format) {
@@ -314,13 +329,17 @@ pub fn read_wav<P: AsRef<Path>>(path: P) -> FileResult<AudioData> {
     let samples: Vec<Sample> = match spec.sample_format {
         hound::SampleFormat::Float => reader
             .into_samples::<f32>()
-            .map(|s| s.unwrap_or(0.0) as f64)
+            .take(num_samples)
+            .map_while(Result::ok)
+            .map(|s| s as f64)
             .collect(),
         hound::SampleFormat::Int => {
-            let max_value = (1 << (spec.bits_per_sample - 1)) as f64;
+            let max_value = (1u64 << (spec.bits_per_sample - 1)) as f64;
             reader
                 .into_samples::<i32>()
-                .map(|s| s.unwrap_or(0) as f64 / max_value)
+                .take(num_samples)
+                .map_while(Result::ok)
+                .map(|s| s as f64 / max_value)
                 .collect()
         }
     };
@@ -329,7 +348,7 @@ pub fn read_wav<P: AsRef<Path>>(path: P) -> FileResult<AudioData> {
     let num_frames = samples.len() / num_channels;
     let mut channels = vec![vec![0.0; num_frames]; num_channels];
 
-    for (i, chunk) in samples.chunks(num_channels).enumerate() {
+    for (i, chunk) in samples.chunks_exact(num_channels).enumerate() {
         for (ch, &sample) in chunk.iter().enumerate() {
             channels[ch][i] = sample;
         }
@@ -805,4 +824,22 @@ mod tests {
         let mono = data.to_mono();
         assert_eq!(mono, vec![0.5, 0.5]);
     }
+
+    #[test]
+    fn test_read_wav_malformed_headers() {
+        use rf_fuzz::FuzzConfig;
+        use rf_fuzz::audio_fuzz::fuzz_wav_loader;
+
+        let config = FuzzConfig::minimal()
+            .with_seed(0x52494646)
+            .with_iterations(2000);
+        let result = fuzz_wav_loader(&config, |path| {
+            read_wav(path).map(|data| data.channels.iter().map(Vec::len).sum::<usize>())
+        });
+        assert!(
+            result.passed,
+            "read_wav fuzz failed: {:?}",
+            result.failure_details
+        );
+    }
 }
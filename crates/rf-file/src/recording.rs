@@ -5,6 +5,7 @@
 //! - Punch in/out recording
 //! - Pre-roll buffer
 //! - Multi-take management
+//! - Multi-channel tracking (one file per input, per-channel gain and clip)
 //! - Automatic file naming
 //! - Recording safeguards (disk space, buffer overflow)
 
@@ -122,6 +123,50 @@ pub struct RecordingStats {
     pub write_errors: u32,
 }
 
+/// One input channel of a multi-channel recording
+#[derive(Debug, Clone)]
+pub struct ChannelRecordConfig {
+    /// Input channel index within the interleaved input frame
+    pub input: usize,
+    /// File name suffix (e.g., "Kick" -> "Recording_..._001_Kick.wav")
+    pub name: String,
+    /// Input gain in dB, applied before writing and metering
+    pub gain_db: f32,
+}
+
+impl ChannelRecordConfig {
+    pub fn new(input: usize, name: impl Into<String>) -> Self {
+        Self {
+            input,
+            name: name.into(),
+            gain_db: 0.0,
+        }
+    }
+
+    pub fn with_gain_db(mut self, gain_db: f32) -> Self {
+        self.gain_db = gain_db;
+        self
+    }
+}
+
+/// Per-channel input meter for the UI
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ChannelMeter {
+    /// Peak level since start (linear, post-gain)
+    pub peak: f32,
+    /// Sticky clip indicator (cleared with `clear_clip`)
+    pub clipped: bool,
+    /// Blocks that contained clipped samples
+    pub clip_count: u32,
+}
+
+/// Routing for one recorded channel (resolved from `ChannelRecordConfig`)
+#[derive(Debug, Clone, Copy)]
+struct ChannelRoute {
+    input: usize,
+    gain: f32,
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // PRE-ROLL BUFFER
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -199,8 +244,12 @@ struct DiskWriter {
 
 impl DiskWriter {
     fn new(path: PathBuf, config: &RecordingConfig) -> FileResult<Self> {
+        Self::with_channels(path, config, config.num_channels)
+    }
+
+    fn with_channels(path: PathBuf, config: &RecordingConfig, channels: u16) -> FileResult<Self> {
         let spec = hound::WavSpec {
-            channels: config.num_channels,
+            channels,
             sample_rate: config.sample_rate,
             bits_per_sample: config.bit_depth.bits() as u16,
             sample_format: match config.bit_depth {
@@ -383,6 +432,15 @@ pub struct AudioRecorder {
     /// Disk writer (on background thread)
     disk_writer: Mutex<Option<DiskWriter>>,
 
+    /// Per-channel writers for multi-channel recording (empty otherwise)
+    channel_writers: Mutex<Vec<DiskWriter>>,
+
+    /// Per-channel routing and gain (empty when not multi-channel)
+    channel_routes: RwLock<Vec<ChannelRoute>>,
+
+    /// Per-channel meters
+    channel_meters: RwLock<Vec<ChannelMeter>>,
+
     /// Current recording file path
     current_file: RwLock<Option<PathBuf>>,
 
@@ -414,6 +472,9 @@ impl AudioRecorder {
             pre_roll: Mutex::new(pre_roll),
             pending_samples: Mutex::new(VecDeque::with_capacity(256)),
             disk_writer: Mutex::new(None),
+            channel_writers: Mutex::new(Vec::new()),
+            channel_routes: RwLock::new(Vec::new()),
+            channel_meters: RwLock::new(Vec::new()),
             current_file: RwLock::new(None),
             take_counter: AtomicU64::new(1),
             is_processing: AtomicBool::new(false),
@@ -523,6 +584,87 @@ impl AudioRecorder {
         Ok(file_path)
     }
 
+    /// Start recording several inputs, one mono file per channel
+    ///
+    /// Incoming blocks passed to `process` are interleaved frames of
+    /// `config.num_channels` inputs; each entry of `channels` picks one input,
+    /// applies its gain and streams it to its own file. Per-channel peak and
+    /// clip state is available from `channel_meters`. Returns the file paths
+    /// in the order of `channels`.
+    pub fn start_multi(&self, channels: &[ChannelRecordConfig]) -> FileResult<Vec<PathBuf>> {
+        let mut state = self.state.write();
+
+        if *state != RecordingState::Armed && *state != RecordingState::Stopped {
+            return Err(FileError::WriteError(
+                "Invalid state for recording".to_string(),
+            ));
+        }
+
+        let config = self.config.read();
+
+        if channels.is_empty() {
+            return Err(FileError::RecordingError(
+                "No channels to record".to_string(),
+            ));
+        }
+        if let Some(bad) = channels
+            .iter()
+            .find(|c| c.input >= config.num_channels as usize)
+        {
+            return Err(FileError::RecordingError(format!(
+                "Input {} out of range ({} inputs)",
+                bad.input, config.num_channels
+            )));
+        }
+
+        let base_path = self.generate_file_path(&config)?;
+        let stem = base_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let mut writers = Vec::with_capacity(channels.len());
+        let mut paths = Vec::with_capacity(channels.len());
+        for channel in channels {
+            let path = base_path.with_file_name(format!("{}_{}.wav", stem, channel.name));
+            writers.push(DiskWriter::with_channels(path.clone(), &config, 1)?);
+            paths.push(path);
+        }
+
+        let routes: Vec<ChannelRoute> = channels
+            .iter()
+            .map(|c| ChannelRoute {
+                input: c.input,
+                gain: 10.0_f32.powf(c.gain_db / 20.0),
+            })
+            .collect();
+
+        // Write pre-roll through the same routing
+        if config.capture_pre_roll && *state == RecordingState::Armed {
+            let pre_roll_data = self.pre_roll.lock().read_all();
+            let routed = route_channels(&routes, &pre_roll_data, config.num_channels as usize);
+            write_deinterleaved(&mut writers, &routed)?;
+        }
+
+        *self.stats.write() = RecordingStats::default();
+        *self.channel_meters.write() = vec![ChannelMeter::default(); channels.len()];
+        *self.channel_routes.write() = routes;
+        *self.channel_writers.lock() = writers;
+
+        *self.current_file.write() = paths.first().cloned();
+        *state = RecordingState::Recording;
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_millis() as u64)
+            .unwrap_or(0);
+        self.start_time.store(now, Ordering::SeqCst);
+
+        log::info!("Multi-channel recording started: {} channels", paths.len());
+
+        Ok(paths)
+    }
+
     /// Stop recording
     pub fn stop(&self) -> FileResult<Option<PathBuf>> {
         let mut state = self.state.write();
@@ -535,12 +677,20 @@ impl AudioRecorder {
         self.flush_pending()?;
 
         // Finalize disk writer
-        let path = if let Some(writer) = self.disk_writer.lock().take() {
+        let mut path = if let Some(writer) = self.disk_writer.lock().take() {
             Some(writer.finalize()?)
         } else {
             None
         };
 
+        // Finalize multi-channel writers
+        self.channel_routes.write().clear();
+        let channel_writers = std::mem::take(&mut *self.channel_writers.lock());
+        for writer in channel_writers {
+            let channel_path = writer.finalize()?;
+            path.get_or_insert(channel_path);
+        }
+
         *state = RecordingState::Stopped;
         *self.current_file.write() = None;
 
@@ -607,11 +757,20 @@ impl AudioRecorder {
                     return;
                 }
 
-                // Queue samples for disk writing
-                self.queue_samples(samples);
-
-                // Update peak level
-                self.update_peak(samples);
+                let routes = self.channel_routes.read();
+                if routes.is_empty() {
+                    // Queue samples for disk writing
+                    self.queue_samples(samples);
+
+                    // Update peak level
+                    self.update_peak(samples);
+                } else {
+                    let num_inputs = self.config.read().num_channels as usize;
+                    let routed = route_channels(&routes, samples, num_inputs);
+                    self.update_channel_meters(&routed, routes.len());
+                    self.update_peak(&routed);
+                    self.queue_block(routed);
+                }
             }
             RecordingState::Paused | RecordingState::Stopped => {
                 // Do nothing
@@ -621,11 +780,15 @@ impl AudioRecorder {
 
     /// Queue samples for background writing
     fn queue_samples(&self, samples: &[f32]) {
+        self.queue_block(samples.to_vec());
+    }
+
+    fn queue_block(&self, block: Vec<f32>) {
         let mut pending = self.pending_samples.lock();
 
         // Limit queue size to prevent memory explosion
         if pending.len() < 1024 {
-            pending.push_back(samples.to_vec());
+            pending.push_back(block);
 
             // Update buffer usage stat
             let mut stats = self.stats.write();
@@ -646,6 +809,33 @@ impl AudioRecorder {
         }
 
         let result = (|| {
+            let mut channel_writers = self.channel_writers.lock();
+            if !channel_writers.is_empty() {
+                loop {
+                    let data = {
+                        let mut pending = self.pending_samples.lock();
+                        pending.pop_front()
+                    };
+                    let Some(data) = data else {
+                        break;
+                    };
+
+                    write_deinterleaved(&mut channel_writers, &data)?;
+
+                    let config = self.config.read();
+                    let mut stats = self.stats.write();
+                    stats.samples_recorded += (data.len() / channel_writers.len()) as u64;
+                    stats.duration_secs = stats.samples_recorded as f64 / config.sample_rate as f64;
+                    stats.bytes_written = channel_writers.iter().map(|w| w.bytes_written).sum();
+                }
+
+                for writer in channel_writers.iter_mut() {
+                    writer.flush()?;
+                }
+                return Ok(());
+            }
+            drop(channel_writers);
+
             let mut writer_guard = self.disk_writer.lock();
 
             if let Some(writer) = writer_guard.as_mut() {
@@ -700,6 +890,36 @@ impl AudioRecorder {
         }
     }
 
+    /// Update per-channel meters from routed (post-gain) frames
+    fn update_channel_meters(&self, routed: &[f32], num_channels: usize) {
+        let mut meters = self.channel_meters.write();
+        for (ch, meter) in meters.iter_mut().enumerate().take(num_channels) {
+            let peak = routed
+                .iter()
+                .skip(ch)
+                .step_by(num_channels)
+                .fold(0.0f32, |a, s| a.max(s.abs()));
+
+            meter.peak = meter.peak.max(peak);
+            if peak > 1.0 {
+                meter.clipped = true;
+                meter.clip_count += 1;
+            }
+        }
+    }
+
+    /// Per-channel meters of the current multi-channel recording
+    pub fn channel_meters(&self) -> Vec<ChannelMeter> {
+        self.channel_meters.read().clone()
+    }
+
+    /// Clear the sticky clip indicator of one channel
+    pub fn clear_clip(&self, channel: usize) {
+        if let Some(meter) = self.channel_meters.write().get_mut(channel) {
+            meter.clipped = false;
+        }
+    }
+
     /// Generate unique file path
     ///
     /// # File Naming Format
@@ -779,6 +999,32 @@ impl Default for AudioRecorder {
     }
 }
 
+/// Pick and scale routed inputs from interleaved frames
+///
+/// Output is interleaved with one slot per route.
+fn route_channels(routes: &[ChannelRoute], samples: &[f32], num_inputs: usize) -> Vec<f32> {
+    let frames = samples.len() / num_inputs.max(1);
+    let mut routed = Vec::with_capacity(frames * routes.len());
+    for frame in samples.chunks_exact(num_inputs.max(1)) {
+        routed.extend(routes.iter().map(|r| frame[r.input] * r.gain));
+    }
+    routed
+}
+
+/// Write interleaved routed frames, one channel per writer
+fn write_deinterleaved(writers: &mut [DiskWriter], routed: &[f32]) -> FileResult<()> {
+    let num_channels = writers.len();
+    let frames = routed.len() / num_channels.max(1);
+    let mut channel = Vec::with_capacity(frames);
+
+    for (ch, writer) in writers.iter_mut().enumerate() {
+        channel.clear();
+        channel.extend(routed.iter().skip(ch).step_by(num_channels));
+        writer.write_samples(&channel)?;
+    }
+    Ok(())
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // MULTI-TAKE MANAGER
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -1031,6 +1277,86 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_recorder_multi_channel_tones() {
+        let temp = tempdir().unwrap();
+        let sample_rate = 48000;
+        let config = RecordingConfig {
+            output_dir: temp.path().to_path_buf(),
+            num_channels: 4,
+            sample_rate,
+            capture_pre_roll: false,
+            ..Default::default()
+        };
+        let recorder = AudioRecorder::new(config);
+
+        let tones = [220.0f32, 440.0, 880.0, 1760.0];
+        let channels = [
+            ChannelRecordConfig::new(0, "A"),
+            ChannelRecordConfig::new(1, "B").with_gain_db(-6.0),
+            ChannelRecordConfig::new(2, "C"),
+            ChannelRecordConfig::new(3, "D").with_gain_db(12.0),
+        ];
+        let paths = recorder.start_multi(&channels).unwrap();
+        assert_eq!(paths.len(), 4);
+
+        // One second in 480-frame blocks, flushed like a disk thread would
+        let block = 480;
+        let total = sample_rate as usize;
+        for start in (0..total).step_by(block) {
+            let samples: Vec<f32> = (start..start + block)
+                .flat_map(|n| {
+                    let t = n as f32 / sample_rate as f32;
+                    tones.map(|f| 0.5 * (2.0 * std::f32::consts::PI * f * t).sin())
+                })
+                .collect();
+            recorder.process(&samples, start as u64);
+            if (start / block) % 10 == 9 {
+                recorder.flush_pending().unwrap();
+            }
+        }
+        recorder.stop().unwrap();
+        assert_eq!(recorder.stats().write_errors, 0);
+        assert_eq!(recorder.stats().samples_recorded, total as u64);
+
+        // Meters: gain applied, clip only on the boosted channel
+        let meters = recorder.channel_meters();
+        assert!((meters[0].peak - 0.5).abs() < 0.01);
+        assert!((meters[1].peak - 0.25).abs() < 0.01);
+        assert!(!meters[2].clipped);
+        assert!(meters[3].clipped);
+
+        let magnitude = |data: &[f32], freq: f32| {
+            let w = 2.0 * std::f32::consts::PI * freq / sample_rate as f32;
+            let (re, im) = data
+                .iter()
+                .enumerate()
+                .fold((0.0f32, 0.0f32), |(re, im), (n, &x)| {
+                    (re + x * (w * n as f32).cos(), im - x * (w * n as f32).sin())
+                });
+            (re * re + im * im).sqrt() * 2.0 / data.len() as f32
+        };
+
+        for (ch, path) in paths.iter().enumerate() {
+            let mut reader = hound::WavReader::open(path).unwrap();
+            assert_eq!(reader.spec().channels, 1);
+            let data: Vec<f32> = reader
+                .samples::<i32>()
+                .map(|s| s.unwrap() as f32 / 8388607.0)
+                .collect();
+            assert_eq!(data.len(), total);
+
+            for (other, &freq) in tones.iter().enumerate() {
+                let mag = magnitude(&data, freq);
+                if other == ch {
+                    assert!(mag > 0.2, "channel {} missing its tone: {}", ch, mag);
+                } else {
+                    assert!(mag < 0.05, "channel {} leaks {} Hz: {}", ch, freq, mag);
+                }
+            }
+        }
+    }
+
     #[test]
     fn test_punch_region() {
         let punch = PunchRegion {
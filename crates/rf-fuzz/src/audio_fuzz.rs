@@ -7,6 +7,10 @@ use crate::config::FuzzConfig;
 use crate::generators::InputGenerator;
 use crate::harness::{FuzzResult, FuzzRunner};
 use crate::report::FuzzReport;
+use std::cell::Cell;
+use std::panic::RefUnwindSafe;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 // ============================================================================
 // WAV format constants
@@ -571,6 +575,217 @@ impl FlacHeaderGenerator {
     }
 }
 
+// ============================================================================
+// Complete RIFF/WAV file generator (for real file loaders)
+// ============================================================================
+
+/// Generates complete WAV files (header + payload) with chunk-level damage.
+///
+/// Unlike `WavHeaderGenerator`, the output is meant to be written to disk and
+/// handed to a real loader, so the payload and the chunk layout matter:
+/// truncated chunks, absurd sizes, and data lengths that disagree with the
+/// header are the interesting cases.
+pub struct RiffFileGenerator;
+
+impl RiffFileGenerator {
+    /// Generate a well-formed PCM WAV file with a deterministic payload.
+    pub fn valid_file(
+        sample_rate: u32,
+        channels: u16,
+        bits_per_sample: u16,
+        frames: u32,
+    ) -> Vec<u8> {
+        let block_align = channels as u32 * (bits_per_sample as u32 / 8);
+        let data_size = frames * block_align;
+        let mut file =
+            WavHeaderGenerator::valid_header(sample_rate, channels, bits_per_sample, data_size);
+        file.extend((0..data_size).map(|i| (i % 251) as u8));
+        file
+    }
+
+    /// Generate a complete WAV file with a random kind of damage.
+    pub fn fuzzed_file(rng: &mut InputGenerator) -> Vec<u8> {
+        match rng.u32() % 11 {
+            0 => Self::fuzzed_header_with_payload(rng),
+            1 => Self::truncated_data_chunk(rng),
+            2 => Self::absurd_data_size(rng),
+            3 => Self::mismatched_riff_size(rng),
+            4 => Self::odd_chunk_without_padding(rng),
+            5 => Self::absurd_unknown_chunk(rng),
+            6 => Self::data_before_fmt(rng),
+            7 => Self::duplicate_fmt(rng),
+            8 => Self::unaligned_data_length(rng),
+            9 => Self::wide_pcm(rng),
+            _ => Self::flipped_bytes(rng),
+        }
+    }
+
+    /// Build a chunk: id, declared size, body (pad byte added for odd bodies).
+    fn chunk(id: &[u8; 4], declared_size: u32, body: &[u8]) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(8 + body.len() + 1);
+        buf.extend_from_slice(id);
+        buf.extend_from_slice(&declared_size.to_le_bytes());
+        buf.extend_from_slice(body);
+        if body.len() % 2 == 1 {
+            buf.push(0);
+        }
+        buf
+    }
+
+    /// PCM fmt chunk body.
+    fn fmt_body(sample_rate: u32, channels: u16, bits_per_sample: u16) -> Vec<u8> {
+        let block_align = channels.wrapping_mul(bits_per_sample / 8);
+        let byte_rate = sample_rate.wrapping_mul(block_align as u32);
+        let mut body = Vec::with_capacity(16);
+        body.extend_from_slice(&WAV_FORMAT_PCM.to_le_bytes());
+        body.extend_from_slice(&channels.to_le_bytes());
+        body.extend_from_slice(&sample_rate.to_le_bytes());
+        body.extend_from_slice(&byte_rate.to_le_bytes());
+        body.extend_from_slice(&block_align.to_le_bytes());
+        body.extend_from_slice(&bits_per_sample.to_le_bytes());
+        body
+    }
+
+    /// Wrap chunks in a RIFF/WAVE container with a correct RIFF size.
+    fn riff(chunks: &[u8]) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(12 + chunks.len());
+        buf.extend_from_slice(RIFF_MAGIC);
+        buf.extend_from_slice(&(4 + chunks.len() as u32).to_le_bytes());
+        buf.extend_from_slice(WAVE_MAGIC);
+        buf.extend_from_slice(chunks);
+        buf
+    }
+
+    /// Corrupted header (see `WavHeaderGenerator`) followed by real payload bytes.
+    fn fuzzed_header_with_payload(rng: &mut InputGenerator) -> Vec<u8> {
+        let mut file = WavHeaderGenerator::fuzzed_header(rng);
+        let payload = rng.usize(512);
+        file.extend(rng.bytes(payload));
+        file
+    }
+
+    /// Data chunk declares more bytes than the file contains.
+    fn truncated_data_chunk(rng: &mut InputGenerator) -> Vec<u8> {
+        let full = Self::valid_file(44100, 2, 16, 256);
+        let cut = 44 + rng.usize(full.len() - 45);
+        full[..cut].to_vec()
+    }
+
+    /// Data chunk declares a gigantic size backed by a few bytes.
+    fn absurd_data_size(rng: &mut InputGenerator) -> Vec<u8> {
+        let mut file = Self::valid_file(48000, 2, 16, 8);
+        let bad_size: u32 = match rng.u32() % 4 {
+            0 => u32::MAX,
+            1 => u32::MAX - 1,
+            2 => 0x8000_0000,
+            _ => rng.u32() | 0x1000_0000,
+        };
+        file[40..44].copy_from_slice(&bad_size.to_le_bytes());
+        file
+    }
+
+    /// RIFF size disagrees with the real file length.
+    fn mismatched_riff_size(rng: &mut InputGenerator) -> Vec<u8> {
+        let mut file = Self::valid_file(44100, 1, 16, 64);
+        let real = file.len() as u32 - 8;
+        let bad_size = match rng.u32() % 4 {
+            0 => 4,
+            1 => real / 2,
+            2 => real.wrapping_add(1_000_000),
+            _ => u32::MAX,
+        };
+        file[4..8].copy_from_slice(&bad_size.to_le_bytes());
+        file
+    }
+
+    /// Odd-sized unknown chunk whose pad byte is missing, shifting every later chunk.
+    fn odd_chunk_without_padding(rng: &mut InputGenerator) -> Vec<u8> {
+        let odd_len = (rng.usize(32) * 2 + 1) as u32;
+        let mut chunks = Vec::new();
+        chunks.extend_from_slice(b"junk");
+        chunks.extend_from_slice(&odd_len.to_le_bytes());
+        let mut body = rng.bytes(odd_len as usize);
+        body.resize(odd_len as usize, 0);
+        chunks.extend(body);
+        chunks.extend(Self::chunk(FMT_CHUNK_ID, 16, &Self::fmt_body(44100, 2, 16)));
+        chunks.extend(Self::chunk(DATA_CHUNK_ID, 64, &[0u8; 64]));
+        Self::riff(&chunks)
+    }
+
+    /// Unknown chunk with an absurd size before fmt (loaders must not read or skip blindly).
+    fn absurd_unknown_chunk(rng: &mut InputGenerator) -> Vec<u8> {
+        let id: &[u8; 4] = match rng.u32() % 3 {
+            0 => b"LIST",
+            1 => b"bext",
+            _ => b"JUNK",
+        };
+        let bad_size = match rng.u32() % 3 {
+            0 => u32::MAX,
+            1 => 0x7FFF_FFFF,
+            _ => rng.u32(),
+        };
+        let mut chunks = Self::chunk(id, bad_size, &[0u8; 8]);
+        chunks.extend(Self::chunk(FMT_CHUNK_ID, 16, &Self::fmt_body(44100, 2, 16)));
+        chunks.extend(Self::chunk(DATA_CHUNK_ID, 16, &[0u8; 16]));
+        Self::riff(&chunks)
+    }
+
+    /// Data chunk appears before the fmt chunk.
+    fn data_before_fmt(rng: &mut InputGenerator) -> Vec<u8> {
+        let data_len = rng.usize(128) as u32 & !3;
+        let mut chunks = Self::chunk(DATA_CHUNK_ID, data_len, &vec![0u8; data_len as usize]);
+        chunks.extend(Self::chunk(FMT_CHUNK_ID, 16, &Self::fmt_body(44100, 2, 16)));
+        Self::riff(&chunks)
+    }
+
+    /// Two fmt chunks with conflicting parameters.
+    fn duplicate_fmt(rng: &mut InputGenerator) -> Vec<u8> {
+        let channels = (rng.u32() % 9) as u16;
+        let bits = [0u16, 8, 16, 24, 32][rng.usize(4)];
+        let mut chunks = Self::chunk(FMT_CHUNK_ID, 16, &Self::fmt_body(44100, 2, 16));
+        chunks.extend(Self::chunk(
+            FMT_CHUNK_ID,
+            16,
+            &Self::fmt_body(rng.u32(), channels, bits),
+        ));
+        chunks.extend(Self::chunk(DATA_CHUNK_ID, 64, &[0u8; 64]));
+        Self::riff(&chunks)
+    }
+
+    /// Data length that is not a multiple of the block alignment.
+    fn unaligned_data_length(rng: &mut InputGenerator) -> Vec<u8> {
+        let channels = 1 + (rng.u32() % 8) as u16;
+        let bits = [8u16, 16, 24, 32][rng.usize(3)];
+        let block_align = (channels * bits / 8) as usize;
+        let data_len =
+            block_align * (1 + rng.usize(16)) + 1 + rng.usize(block_align.saturating_sub(2));
+        let mut chunks = Self::chunk(FMT_CHUNK_ID, 16, &Self::fmt_body(44100, channels, bits));
+        chunks.extend(Self::chunk(
+            DATA_CHUNK_ID,
+            data_len as u32,
+            &vec![0x55; data_len],
+        ));
+        Self::riff(&chunks)
+    }
+
+    /// Integer PCM wider than 32 bits (byte-aligned, so headers look legal).
+    fn wide_pcm(rng: &mut InputGenerator) -> Vec<u8> {
+        let bits = [40u16, 48, 56, 64][rng.usize(3)];
+        let channels = 1 + (rng.u32() % 2) as u16;
+        Self::valid_file(44100, channels, bits, 1 + rng.usize(16) as u32)
+    }
+
+    /// Valid file with random bytes flipped anywhere (header included).
+    fn flipped_bytes(rng: &mut InputGenerator) -> Vec<u8> {
+        let mut file = Self::valid_file(44100, 2, 24, 32);
+        let flips = 1 + rng.usize(8);
+        for _ in 0..flips {
+            let pos = rng.usize(file.len() - 1);
+            file[pos] ^= 1 << (rng.u32() % 8);
+        }
+        file
+    }
+}
 // ============================================================================
 // Completely random / garbage audio data generators
 // ============================================================================
@@ -1006,6 +1221,111 @@ pub fn parse_flac_header_safe(data: &[u8]) -> FlacParseResult {
     }
 }
 
+// ============================================================================
+// File loader fuzzing
+// ============================================================================
+
+/// Known-bad WAV files that crashed or exhausted memory in real loaders.
+///
+/// Always replayed first by `fuzz_wav_loader`, before any random input.
+pub fn wav_regression_seeds() -> Vec<(&'static str, Vec<u8>)> {
+    // 64-bit integer PCM: legal-looking header, overflowed the sample scale shift
+    let pcm_64bit = RiffFileGenerator::valid_file(44100, 2, 64, 4);
+
+    // Data chunk claims ~4 GiB (block-aligned, so the header itself is accepted)
+    // backed by 16 bytes: preallocated / zero-filled gigabytes
+    let mut huge_data = RiffFileGenerator::valid_file(44100, 2, 16, 4);
+    huge_data[40..44].copy_from_slice(&0xFFFF_FFFCu32.to_le_bytes());
+
+    // Data chunk declares 1024 bytes, file ends after 10
+    let truncated = RiffFileGenerator::valid_file(44100, 2, 16, 256)[..54].to_vec();
+
+    vec![
+        ("pcm_64bit_int", pcm_64bit),
+        ("data_size_4gib", huge_data),
+        ("truncated_data_chunk", truncated),
+    ]
+}
+
+/// Outcome of handing a fuzzed file to a loader.
+#[derive(Debug, Clone)]
+pub enum LoaderOutcome {
+    /// Loader returned samples (total across channels)
+    Loaded { samples: usize },
+    /// Loader rejected the file with an error
+    Rejected(String),
+}
+
+/// Temp file that is removed even if the loader panics.
+struct TempAudioFile(PathBuf);
+
+impl TempAudioFile {
+    fn write(data: &[u8], extension: &str) -> std::io::Result<Self> {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+        let name = format!(
+            "rf-fuzz-{}-{}.{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed),
+            extension
+        );
+        let path = std::env::temp_dir().join(name);
+        std::fs::write(&path, data)?;
+        Ok(Self(path))
+    }
+}
+
+impl Drop for TempAudioFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// Fuzz a real WAV file loader (e.g. `rf_file::read_wav`).
+///
+/// Every input is written to a temp file and passed to `loader`, which
+/// returns the number of decoded samples. The loader must never panic, and
+/// a successful load must not produce more samples than the file has bytes
+/// (i.e. it must not trust header sizes). Regression seeds run first; the
+/// rest is reproducible from `config.seed`.
+pub fn fuzz_wav_loader<F, E>(config: &FuzzConfig, loader: F) -> FuzzResult
+where
+    F: Fn(&Path) -> Result<usize, E> + RefUnwindSafe,
+    E: std::fmt::Display,
+{
+    let seeds = wav_regression_seeds();
+    let next_seed = Cell::new(0);
+    let runner = FuzzRunner::new(config.clone());
+
+    runner.fuzz_with_validation(
+        |rng| {
+            let i = next_seed.get();
+            if i < seeds.len() {
+                next_seed.set(i + 1);
+                seeds[i].1.clone()
+            } else {
+                RiffFileGenerator::fuzzed_file(rng)
+            }
+        },
+        |data| {
+            let file = match TempAudioFile::write(&data, "wav") {
+                Ok(file) => file,
+                Err(e) => return LoaderOutcome::Rejected(format!("temp file: {}", e)),
+            };
+            match loader(&file.0) {
+                Ok(samples) => LoaderOutcome::Loaded { samples },
+                Err(e) => LoaderOutcome::Rejected(e.to_string()),
+            }
+        },
+        |input, outcome| match outcome {
+            LoaderOutcome::Loaded { samples } if *samples > input.len() => Err(format!(
+                "Decoded {} samples from a {}-byte file",
+                samples,
+                input.len()
+            )),
+            _ => Ok(()),
+        },
+    )
+}
 // ============================================================================
 // Tests
 // ============================================================================
@@ -1311,6 +1631,56 @@ mod tests {
         assert!(matches!(flac_result, FlacParseResult::Invalid(_)));
     }
 
+    #[test]
+    fn test_riff_file_generator_determinism() {
+        let mut gen1 = InputGenerator::new(Some(7), 4096);
+        let mut gen2 = InputGenerator::new(Some(7), 4096);
+
+        for _ in 0..50 {
+            assert_eq!(
+                RiffFileGenerator::fuzzed_file(&mut gen1),
+                RiffFileGenerator::fuzzed_file(&mut gen2)
+            );
+        }
+    }
+
+    #[test]
+    fn test_fuzz_wav_loader_catches_unbounded_loader() {
+        // A loader that trusts the data chunk size, like a naive reader would
+        let trusting = |path: &Path| -> Result<usize, String> {
+            let data = std::fs::read(path).map_err(|e| e.to_string())?;
+            match parse_wav_header_safe(&data) {
+                WavParseResult::Valid { data_size, .. } => Ok(data_size as usize),
+                WavParseResult::Invalid(e) => Err(e),
+            }
+        };
+
+        let config = FuzzConfig::minimal().with_seed(42).with_iterations(200);
+        let result = fuzz_wav_loader(&config, trusting);
+        assert!(!result.passed);
+        assert_eq!(result.panics, 0);
+        // data_size_4gib is the second regression seed
+        assert_eq!(result.failure_details[0].iteration, 1);
+    }
+
+    #[test]
+    fn test_fuzz_wav_loader_bounded_loader_passes() {
+        let bounded = |path: &Path| -> Result<usize, String> {
+            let data = std::fs::read(path).map_err(|e| e.to_string())?;
+            match parse_wav_header_safe(&data) {
+                WavParseResult::Valid { data_size, .. } => {
+                    Ok((data_size as usize).min(data.len().saturating_sub(44)))
+                }
+                WavParseResult::Invalid(e) => Err(e),
+            }
+        };
+
+        let config = FuzzConfig::minimal().with_seed(42).with_iterations(300);
+        let result = fuzz_wav_loader(&config, bounded);
+        assert!(result.passed, "{:?}", result.failure_details);
+        assert_eq!(result.iterations, 300);
+    }
+
     #[test]
     fn test_generator_determinism() {
         let mut gen1 = InputGenerator::new(Some(999), 4096);
@@ -3,7 +3,18 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::adapter::EngineAdapter;
+use serde_json::Value;
+
+use crate::adapter::{EngineAdapter, IngestLayer};
+use crate::wizard::{FieldPurpose, analyze_structure, detect_events, detect_fields};
+
+/// Minimum share of named-event occurrences that must map to a stage before
+/// Layer 1 is recommended (same bar as the wizard's `determine_layer`).
+const DIRECT_EVENT_MIN_COVERAGE: f64 = 0.7;
+
+/// Upper bound on rule-based confidence — heuristic reconstruction is never
+/// as trustworthy as explicit events or state diffs.
+const RULE_BASED_MAX_CONFIDENCE: f64 = 0.5;
 
 /// Central registry for engine adapters
 #[derive(Default)]
@@ -107,12 +118,124 @@ impl AdapterRegistry {
     }
 }
 
+/// Raw output captured from an engine, used to pick an ingest layer
+#[derive(Debug, Clone, Default)]
+pub struct EngineSample {
+    /// Individual records (events or state snapshots), in capture order
+    pub records: Vec<Value>,
+}
+
+impl EngineSample {
+    /// Create sample from records
+    pub fn new(records: Vec<Value>) -> Self {
+        Self { records }
+    }
+
+    /// Create sample from a JSON document (top-level arrays are flattened)
+    pub fn from_json(json: Value) -> Self {
+        match json {
+            Value::Array(records) => Self { records },
+            record => Self {
+                records: vec![record],
+            },
+        }
+    }
+}
+
+/// Recommended ingest layer for an engine sample
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LayerRecommendation {
+    /// Most capable layer that fits the sample
+    pub layer: IngestLayer,
+    /// Confidence score (0.0 - 1.0)
+    pub confidence: f64,
+}
+
+impl AdapterRegistry {
+    /// Recommend the most capable ingest layer for a raw engine sample.
+    ///
+    /// Checked in order of capability:
+    ///
+    /// 1. **Direct Event** — at least 70% of named-event occurrences map to a
+    ///    stage, either through the built-in name table or because a
+    ///    registered adapter parses them. Confidence is that coverage.
+    /// 2. **Snapshot Diff** — two or more records carrying a phase field plus
+    ///    balance or reel state. Confidence is the share of
+    ///    phase/balance/reels/win fields present.
+    /// 3. **Rule-Based** — fallback for generic signals. Confidence is the
+    ///    share of object records, capped at 0.5.
+    pub fn recommend_layer(&self, sample: &EngineSample) -> LayerRecommendation {
+        let structure = analyze_structure(&sample.records);
+        let events = detect_events(&sample.records, &structure);
+        let fields = detect_fields(&sample.records, &structure);
+
+        let total_events: usize = events.iter().map(|e| e.sample_count).sum();
+        if total_events > 0 {
+            let named_events: usize = events
+                .iter()
+                .filter(|e| {
+                    e.suggested_stage.is_some()
+                        || e.sample_payload
+                            .as_ref()
+                            .is_some_and(|payload| self.parses_event(payload))
+                })
+                .map(|e| e.sample_count)
+                .sum();
+            let coverage = named_events as f64 / total_events as f64;
+            if coverage >= DIRECT_EVENT_MIN_COVERAGE {
+                return LayerRecommendation {
+                    layer: IngestLayer::DirectEvent,
+                    confidence: coverage,
+                };
+            }
+        }
+
+        let has_field =
+            |purpose: FieldPurpose| fields.iter().any(|f| f.suggested_purpose == Some(purpose));
+        let state_fields = [
+            FieldPurpose::Phase,
+            FieldPurpose::Balance,
+            FieldPurpose::ReelSymbols,
+            FieldPurpose::Win,
+        ];
+        if sample.records.len() >= 2
+            && has_field(FieldPurpose::Phase)
+            && (has_field(FieldPurpose::Balance) || has_field(FieldPurpose::ReelSymbols))
+        {
+            let present = state_fields.iter().filter(|p| has_field(**p)).count();
+            return LayerRecommendation {
+                layer: IngestLayer::SnapshotDiff,
+                confidence: present as f64 / state_fields.len() as f64,
+            };
+        }
+
+        let confidence = if sample.records.is_empty() {
+            0.0
+        } else {
+            let objects = sample.records.iter().filter(|r| r.is_object()).count();
+            RULE_BASED_MAX_CONFIDENCE * objects as f64 / sample.records.len() as f64
+        };
+        LayerRecommendation {
+            layer: IngestLayer::RuleBased,
+            confidence,
+        }
+    }
+
+    /// Check if any registered adapter maps this event to a stage
+    fn parses_event(&self, event: &Value) -> bool {
+        self.adapters
+            .values()
+            .any(|a| matches!(a.parse_event(event), Ok(Some(_))))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::adapter::{AdapterError, IngestLayer};
     use crate::config::AdapterConfig;
     use rf_stage::{StageEvent, StageTrace};
+    use serde_json::json;
 
     // Mock adapter for testing
     struct MockAdapter {
@@ -166,4 +289,82 @@ mod tests {
         assert!(retrieved.is_some());
         assert_eq!(retrieved.unwrap().adapter_id(), "mock-1");
     }
+
+    #[test]
+    fn test_recommend_direct_event() {
+        let registry = AdapterRegistry::new();
+        let sample = EngineSample::from_json(json!([
+            { "type": "spin_start", "timestamp": 0 },
+            { "type": "reel_stop", "timestamp": 400, "reel": 0 },
+            { "type": "reel_stop", "timestamp": 600, "reel": 1 },
+            { "type": "win", "timestamp": 900, "win_amount": 5.0 },
+            { "type": "spin_end", "timestamp": 1200 }
+        ]));
+
+        let rec = registry.recommend_layer(&sample);
+        assert_eq!(rec.layer, IngestLayer::DirectEvent);
+        assert_eq!(rec.confidence, 1.0);
+    }
+
+    #[test]
+    fn test_recommend_direct_event_via_registered_adapter() {
+        let sample = EngineSample::from_json(json!([
+            { "type": "XQ_GO" },
+            { "type": "XQ_STOP" }
+        ]));
+
+        let registry = AdapterRegistry::new();
+        assert_ne!(
+            registry.recommend_layer(&sample).layer,
+            IngestLayer::DirectEvent
+        );
+
+        let mut config = AdapterConfig::new("xq", "XQ", "XQ Engine");
+        config.map_event("XQ_GO", "UiSpinPress");
+        config.map_event("XQ_STOP", "SpinEnd");
+        let mut registry = AdapterRegistry::new();
+        registry.register_config(config);
+
+        let rec = registry.recommend_layer(&sample);
+        assert_eq!(rec.layer, IngestLayer::DirectEvent);
+        assert_eq!(rec.confidence, 1.0);
+    }
+
+    #[test]
+    fn test_recommend_snapshot_diff() {
+        let registry = AdapterRegistry::new();
+        let sample = EngineSample::new(vec![
+            json!({ "phase": "idle", "balance": 100.0, "reels": [[1, 2, 3]] }),
+            json!({ "phase": "spinning", "balance": 99.0, "reels": [[1, 2, 3]] }),
+            json!({ "phase": "result", "balance": 104.0, "reels": [[4, 4, 4]] }),
+        ]);
+
+        let rec = registry.recommend_layer(&sample);
+        assert_eq!(rec.layer, IngestLayer::SnapshotDiff);
+        assert_eq!(rec.confidence, 0.75);
+
+        // A single snapshot has nothing to diff against
+        let single = EngineSample::new(vec![sample.records[0].clone()]);
+        assert_eq!(
+            registry.recommend_layer(&single).layer,
+            IngestLayer::RuleBased
+        );
+    }
+
+    #[test]
+    fn test_recommend_rule_based() {
+        let registry = AdapterRegistry::new();
+        let sample = EngineSample::new(vec![
+            json!({ "signal": 1, "value": 0.25 }),
+            json!({ "signal": 2, "value": 0.75 }),
+        ]);
+
+        let rec = registry.recommend_layer(&sample);
+        assert_eq!(rec.layer, IngestLayer::RuleBased);
+        assert_eq!(rec.confidence, 0.5);
+
+        let empty = registry.recommend_layer(&EngineSample::default());
+        assert_eq!(empty.layer, IngestLayer::RuleBased);
+        assert_eq!(empty.confidence, 0.0);
+    }
 }
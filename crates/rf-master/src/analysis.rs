@@ -3,10 +3,12 @@
 //! Features:
 //! - Genre detection using spectral features
 //! - Dynamics analysis (crest factor, LRA)
+//! - Time-resolved dynamics report (crest over time, PLR, loudness histogram)
 //! - Spectral balance analysis
 //! - Stereo field analysis
 //! - Problem detection (clipping, DC offset, phase issues)
 
+use crate::loudness::LufsMeter;
 use crate::{DynamicsProfile, Genre, LoudnessMeasurement, ReferenceProfile, StereoProfile};
 use realfft::{RealFftPlanner, RealToComplex};
 use rustfft::num_complex::Complex;
@@ -352,13 +354,7 @@ impl MasteringAnalyzer {
             .map(|(l, r)| (l + r) * 0.5)
             .collect();
 
-        let rms = self.calculate_rms(&mono);
-        let peak = mono.iter().map(|s| s.abs()).fold(0.0f32, f32::max);
-        let crest_factor = if rms > 1e-10 {
-            20.0 * (peak / rms).log10()
-        } else {
-            0.0
-        };
+        let report = dynamics_report(&mono, self.sample_rate);
 
         let dynamic_range = self.calculate_dynamic_range(&mono);
         let lra = self.calculate_lra(&mono);
@@ -367,10 +363,11 @@ impl MasteringAnalyzer {
         let band_dynamics = self.calculate_band_dynamics(&mono);
 
         DynamicsProfile {
-            crest_factor,
+            crest_factor: report.crest_factor,
             dynamic_range,
             lra,
             band_dynamics,
+            plr: report.plr,
         }
     }
 
@@ -636,6 +633,18 @@ impl MasteringAnalyzer {
         }
     }
 
+    /// Measure average spectral slope (dB/octave)
+    pub fn spectral_slope(&self, audio_l: &[f32], audio_r: &[f32]) -> f32 {
+        let mono: Vec<f32> = audio_l
+            .iter()
+            .zip(audio_r.iter())
+            .map(|(l, r)| (l + r) * 0.5)
+            .collect();
+
+        let spectrum = self.compute_average_spectrum(&mono);
+        crate::eq::spectral_slope(&spectrum, self.sample_rate)
+    }
+
     /// Create reference profile from audio
     pub fn create_reference_profile(
         &self,
@@ -664,6 +673,134 @@ impl MasteringAnalyzer {
     }
 }
 
+/// Crest-factor window length (seconds)
+const CREST_WINDOW_SECONDS: f32 = 1.0;
+
+/// Short-term loudness window and hop (seconds)
+const SHORT_TERM_SECONDS: f32 = 3.0;
+const SHORT_TERM_HOP_SECONDS: f32 = 1.0;
+
+/// Loudness histogram range (LUFS) and bin width (LU)
+const HISTOGRAM_FLOOR_LUFS: f32 = -60.0;
+const HISTOGRAM_BIN_LU: f32 = 1.0;
+const HISTOGRAM_BINS: usize = 60;
+
+/// Time-resolved dynamics of a master
+#[derive(Debug, Clone)]
+pub struct DynamicsReport {
+    /// Crest factor of the whole signal (dB)
+    pub crest_factor: f32,
+    /// Crest factor per window (dB), one entry per `window_seconds`
+    pub window_crest: Vec<f32>,
+    /// Crest window length (seconds)
+    pub window_seconds: f32,
+    /// Sample peak (dBFS)
+    pub peak_db: f32,
+    /// Integrated loudness (LUFS, BS.1770 gated)
+    pub integrated_lufs: f32,
+    /// Peak-to-loudness ratio (dB)
+    pub plr: f32,
+    /// Short-term (3 s) loudness per 1 s hop (LUFS)
+    pub short_term_lufs: Vec<f32>,
+    /// Short-term loudness histogram, 1 LU bins from -60 LUFS
+    pub loudness_histogram: Vec<u32>,
+}
+
+impl DynamicsReport {
+    /// Lower edge of histogram bin `index` (LUFS)
+    pub fn histogram_bin_lufs(index: usize) -> f32 {
+        HISTOGRAM_FLOOR_LUFS + index as f32 * HISTOGRAM_BIN_LU
+    }
+
+    /// Median of the per-window crest factors (dB)
+    pub fn median_window_crest(&self) -> f32 {
+        if self.window_crest.is_empty() {
+            return self.crest_factor;
+        }
+        let mut sorted = self.window_crest.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        sorted[sorted.len() / 2]
+    }
+}
+
+/// Analyze how dynamic (or how squashed) a mono master is
+pub fn dynamics_report(samples: &[f32], sample_rate: u32) -> DynamicsReport {
+    let crest_window = ((sample_rate as f32 * CREST_WINDOW_SECONDS) as usize).max(1);
+    let window_crest = samples
+        .chunks(crest_window)
+        .filter(|chunk| chunk.len() == crest_window || chunk.len() == samples.len())
+        .filter_map(|chunk| {
+            let (peak, mean_square) = peak_and_mean_square(chunk);
+            (mean_square > 1e-12).then(|| 20.0 * peak.log10() - 10.0 * mean_square.log10())
+        })
+        .collect();
+
+    let (peak, mean_square) = peak_and_mean_square(samples);
+    let peak_db = level_db(peak * peak);
+    let crest_factor = if mean_square > 1e-12 {
+        peak_db - level_db(mean_square)
+    } else {
+        0.0
+    };
+
+    // K-weighted, gated loudness; the short-term value is read after every hop
+    // once the first 3 s window is full
+    let mut meter = LufsMeter::new(sample_rate);
+    let short_len = (sample_rate as f32 * SHORT_TERM_SECONDS) as usize;
+    let short_hop = ((sample_rate as f32 * SHORT_TERM_HOP_SECONDS) as usize).max(1);
+    let mut short_term_lufs = Vec::new();
+    let mut processed = 0;
+    for hop in samples.chunks(short_hop) {
+        meter.process(hop, hop);
+        processed += hop.len();
+        if hop.len() == short_hop && processed >= short_len {
+            short_term_lufs.push(meter.short_term());
+        }
+    }
+    let integrated_lufs = meter.integrated();
+    if short_term_lufs.is_empty() {
+        short_term_lufs.push(integrated_lufs);
+    }
+
+    let mut loudness_histogram = vec![0u32; HISTOGRAM_BINS];
+    for &lufs in &short_term_lufs {
+        if lufs >= HISTOGRAM_FLOOR_LUFS {
+            let bin = ((lufs - HISTOGRAM_FLOOR_LUFS) / HISTOGRAM_BIN_LU) as usize;
+            loudness_histogram[bin.min(HISTOGRAM_BINS - 1)] += 1;
+        }
+    }
+
+    DynamicsReport {
+        crest_factor,
+        window_crest,
+        window_seconds: CREST_WINDOW_SECONDS,
+        peak_db,
+        integrated_lufs,
+        plr: (peak_db - integrated_lufs).max(0.0),
+        short_term_lufs,
+        loudness_histogram,
+    }
+}
+
+fn peak_and_mean_square(samples: &[f32]) -> (f32, f32) {
+    if samples.is_empty() {
+        return (0.0, 0.0);
+    }
+    let (peak, sum) = samples.iter().fold((0.0f32, 0.0f64), |(peak, sum), &s| {
+        (peak.max(s.abs()), sum + (s as f64) * (s as f64))
+    });
+    (peak, (sum / samples.len() as f64) as f32)
+}
+
+/// Power (mean square or squared peak) to dB, floored at -70
+fn level_db(power: f32) -> f32 {
+    if power > 1e-7 {
+        10.0 * power.log10()
+    } else {
+        -70.0
+    }
+}
+
 /// Spectral features for genre detection
 #[derive(Debug, Clone)]
 struct SpectralFeatures {
@@ -729,6 +866,64 @@ mod tests {
         assert!(dynamics.crest_factor < 10.0); // Sine wave is ~3 dB crest factor
     }
 
+    #[test]
+    fn test_dynamics_report_limited_vs_dynamic() {
+        let sample_rate = 48000;
+        // 10 s of decaying 110 Hz hits, one every half second
+        let dynamic: Vec<f32> = (0..sample_rate as usize * 10)
+            .map(|i| {
+                let t = i as f32 / sample_rate as f32;
+                let envelope = (-(t % 0.5) * 12.0).exp();
+                (2.0 * std::f32::consts::PI * 110.0 * t).sin() * envelope * 0.9
+            })
+            .collect();
+        // Same material driven hard into a clipper
+        let limited: Vec<f32> = dynamic.iter().map(|s| (s * 20.0).tanh() * 0.9).collect();
+
+        let dynamic_report = dynamics_report(&dynamic, sample_rate);
+        let limited_report = dynamics_report(&limited, sample_rate);
+
+        assert_eq!(dynamic_report.window_crest.len(), 10);
+        assert!(limited_report.median_window_crest() < 4.0);
+        assert!(dynamic_report.median_window_crest() > limited_report.median_window_crest() + 4.0);
+        assert!(dynamic_report.plr > limited_report.plr + 4.0);
+        assert_eq!(
+            limited_report.loudness_histogram.iter().sum::<u32>() as usize,
+            limited_report.short_term_lufs.len()
+        );
+
+        let profile = MasteringAnalyzer::new(sample_rate).analyze_dynamics(&limited, &limited);
+        assert!((profile.plr - limited_report.plr).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_dynamics_report_matches_lufs_meter() {
+        let sample_rate = 48000;
+        // 5 s of 1 kHz at -12 dBFS followed by 5 s of near-silence
+        let samples: Vec<f32> = (0..sample_rate as usize * 10)
+            .map(|i| {
+                let level = if i < sample_rate as usize * 5 {
+                    0.25
+                } else {
+                    0.0001
+                };
+                (2.0 * std::f32::consts::PI * 1000.0 * i as f32 / sample_rate as f32).sin() * level
+            })
+            .collect();
+        let report = dynamics_report(&samples, sample_rate);
+
+        let mut meter = LufsMeter::new(sample_rate);
+        meter.process(&samples, &samples);
+        assert_eq!(report.integrated_lufs, meter.integrated());
+        // The gate drops the quiet half instead of averaging it in
+        assert!((report.integrated_lufs - (-15.7)).abs() < 1.0);
+
+        // 3 s windows at 1 s hops over 10 s
+        assert_eq!(report.short_term_lufs.len(), 8);
+        assert!((report.short_term_lufs[0] - report.integrated_lufs).abs() < 0.5);
+        assert!(report.short_term_lufs[7] < -50.0);
+    }
+
     #[test]
     fn test_loudness_measurement() {
         let analyzer = MasteringAnalyzer::new(48000);
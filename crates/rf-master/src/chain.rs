@@ -3,16 +3,17 @@
 //! Combines all mastering components into unified processor
 
 use crate::{
+    Genre, LoudnessMeasurement, LoudnessTarget, MasterConfig, MasteringPreset, MasteringResult,
+    ReferenceProfile,
     analysis::MasteringAnalyzer,
+    dither::{DitherStage, DitherType},
     dynamics::{MasteringCompressor, MultibandDynamics, MultibandDynamicsConfig},
-    eq::{LinearPhaseEq, MasterEqConfig, TiltEq},
+    eq::{LinearPhaseEq, MasterEqConfig, SpectralBalance, TiltEq},
     error::{MasterError, MasterResult},
     limiter::{LimiterConfig, TruePeakLimiter},
     loudness::{LoudnessNormalizer, LufsMeter},
     reference::ReferenceMatcher,
     stereo::{StereoConfig, StereoEnhancer},
-    Genre, LoudnessMeasurement, LoudnessTarget, MasterConfig, MasteringPreset, MasteringResult,
-    ReferenceProfile,
 };
 
 /// Complete mastering engine
@@ -25,6 +26,12 @@ pub struct MasteringEngine {
     analyzer: MasteringAnalyzer,
     /// Pre-EQ (tilt)
     pre_eq: TiltEq,
+    /// User tilt (dB/octave)
+    user_tilt: f32,
+    /// Spectral balance corrector
+    balance: SpectralBalance,
+    /// Measured input slope (dB/octave)
+    input_slope: Option<f32>,
     /// Main EQ
     main_eq: LinearPhaseEq,
     /// Multiband dynamics
@@ -35,10 +42,14 @@ pub struct MasteringEngine {
     stereo: StereoEnhancer,
     /// Limiter
     limiter: TruePeakLimiter,
+    /// Final dither/quantization (None = float output)
+    dither: Option<DitherStage>,
     /// Input meter
     input_meter: LufsMeter,
     /// Output meter
     output_meter: LufsMeter,
+    /// Running input loudness for the live normalization preview
+    preview_meter: LufsMeter,
     /// Loudness normalizer
     normalizer: LoudnessNormalizer,
     /// Reference matcher
@@ -51,6 +62,9 @@ pub struct MasteringEngine {
     analysis_done: bool,
 }
 
+/// Gated 100ms blocks before `preview_gain` reports an estimate
+const PREVIEW_MIN_BLOCKS: usize = 4;
+
 impl MasteringEngine {
     /// Create new mastering engine
     pub fn new(sample_rate: u32) -> Self {
@@ -100,8 +114,13 @@ impl MasteringEngine {
         };
         let limiter = TruePeakLimiter::new(limiter_config);
 
+        let dither = config
+            .dither
+            .then(|| DitherStage::new(config.target_bits, config.dither_type));
+
         let input_meter = LufsMeter::new(sample_rate);
         let output_meter = LufsMeter::new(sample_rate);
+        let preview_meter = LufsMeter::new(sample_rate);
 
         let normalizer = LoudnessNormalizer::new(sample_rate, config.loudness.clone());
 
@@ -112,13 +131,18 @@ impl MasteringEngine {
             sample_rate,
             analyzer,
             pre_eq,
+            user_tilt: 0.0,
+            balance: SpectralBalance::default(),
+            input_slope: None,
             main_eq,
             multiband,
             bus_comp,
             stereo,
             limiter,
+            dither,
             input_meter,
             output_meter,
+            preview_meter,
             normalizer,
             matcher,
             detected_genre: Genre::Unknown,
@@ -131,6 +155,7 @@ impl MasteringEngine {
     pub fn set_preset(&mut self, preset: MasteringPreset) {
         self.config.preset = preset;
         self.config.loudness = LoudnessTarget::from_preset(preset);
+        self.normalizer.set_target(self.config.loudness.clone());
 
         // Update limiter ceiling
         self.limiter.set_ceiling(preset.true_peak_limit());
@@ -140,6 +165,36 @@ impl MasteringEngine {
     pub fn set_loudness_target(&mut self, target: LoudnessTarget) {
         self.config.loudness = target.clone();
         self.limiter.set_ceiling(target.true_peak);
+        self.normalizer.set_target(target);
+    }
+
+    /// Configure final dither stage
+    ///
+    /// When enabled, output is dithered and quantized to `target_bits` after
+    /// limiting.
+    pub fn set_dither(&mut self, enabled: bool, dither_type: DitherType, target_bits: u32) {
+        self.config.dither = enabled;
+        self.config.dither_type = dither_type;
+        self.config.target_bits = target_bits;
+        self.dither = enabled.then(|| DitherStage::new(target_bits, dither_type));
+    }
+
+    /// Applied dither stage (None = float output)
+    pub fn dither(&self) -> Option<&DitherStage> {
+        self.dither.as_ref()
+    }
+
+    /// Set tilt (dB/octave around the pivot, positive = brighter)
+    ///
+    /// Spectral balance correction (if enabled) is added on top.
+    pub fn set_tilt(&mut self, db_per_octave: f32) {
+        self.user_tilt = db_per_octave;
+        self.update_tilt();
+    }
+
+    /// Get effective tilt (dB/octave)
+    pub fn tilt(&self) -> f32 {
+        self.pre_eq.tilt()
     }
 
     /// Set reference track
@@ -161,6 +216,9 @@ impl MasteringEngine {
             self.detected_genre = self.config.genre;
         }
 
+        // Measure spectral slope for balance correction
+        self.input_slope = Some(self.analyzer.spectral_slope(left, right));
+
         // Measure input loudness
         self.input_meter.process(left, right);
 
@@ -191,7 +249,7 @@ impl MasteringEngine {
         let genre = self.detected_genre;
 
         // Apply tilt based on genre
-        self.pre_eq.set_tilt(genre.spectral_tilt());
+        self.update_tilt();
 
         // Adjust compression based on genre
         let ratio = genre.compression_ratio();
@@ -201,6 +259,22 @@ impl MasteringEngine {
         self.stereo.set_width(genre.stereo_width());
     }
 
+    /// User tilt plus spectral balance correction toward reference or genre target
+    fn update_tilt(&mut self) {
+        let correction = match self.input_slope {
+            Some(measured) if self.config.spectral_shape => {
+                let target = match self.matcher.reference() {
+                    Some(profile) => SpectralBalance::reference_target(profile, self.sample_rate),
+                    None => SpectralBalance::genre_target(self.detected_genre),
+                };
+                self.balance.correction(measured, target)
+            }
+            _ => 0.0,
+        };
+
+        self.pre_eq.set_tilt(self.user_tilt + correction);
+    }
+
     /// Process stereo sample (real-time)
     pub fn process_sample(&mut self, left: f32, right: f32) -> (f32, f32) {
         if !self.active {
@@ -219,7 +293,11 @@ impl MasteringEngine {
         // Limiting
         let (l, r) = self.limiter.process_sample(l, r);
 
-        (l, r)
+        // Dither to target bit depth (always last)
+        match self.dither.as_mut() {
+            Some(dither) => dither.process(l, r),
+            None => (l, r),
+        }
     }
 
     /// Process buffer (offline or block-based)
@@ -237,6 +315,9 @@ impl MasteringEngine {
             });
         }
 
+        // Running loudness of what has played so far
+        self.preview_meter.process(input_l, input_r);
+
         // Process sample by sample for now
         // Full implementation would use block processing with overlap
         for i in 0..input_l.len() {
@@ -291,11 +372,19 @@ impl MasteringEngine {
 
         let chain_summary = vec![
             format!("Genre: {:?}", self.detected_genre),
-            format!("Tilt EQ: {:.1} dB", self.detected_genre.spectral_tilt()),
+            format!("Tilt EQ: {:+.2} dB/oct", self.pre_eq.tilt()),
             format!("Width: {:.0}%", self.detected_genre.stereo_width() * 100.0),
             format!("Gain: {:.1} dB", applied_gain),
             format!("Peak reduction: {:.1} dB", peak_reduction),
             format!("Ceiling: {:.1} dBTP", self.config.loudness.true_peak),
+            match &self.dither {
+                Some(dither) => format!(
+                    "Dither: {} ({}-bit)",
+                    dither.dither_type().name(),
+                    dither.target_bits()
+                ),
+                None => "Dither: off".to_string(),
+            },
         ];
 
         // Check for warnings
@@ -350,6 +439,19 @@ impl MasteringEngine {
         score.clamp(0.0, 100.0)
     }
 
+    /// Live estimate of the normalization gain (dB)
+    ///
+    /// Measured from the input played through `process` so far, with the
+    /// same target and true-peak limit as the offline two-pass analysis, so
+    /// it converges to `MasteringResult::applied_gain` as more of the track
+    /// plays. Returns 0 dB until 400ms of gated audio has been measured.
+    pub fn preview_gain(&self) -> f32 {
+        if self.preview_meter.gated_blocks() < PREVIEW_MIN_BLOCKS {
+            return 0.0;
+        }
+        LoudnessNormalizer::gain_db_for(&self.preview_meter, &self.config.loudness)
+    }
+
     /// Get total latency
     pub fn latency(&self) -> usize {
         self.main_eq.latency() + self.limiter.latency()
@@ -363,9 +465,14 @@ impl MasteringEngine {
         self.bus_comp.reset();
         self.stereo.reset();
         self.limiter.reset();
+        if let Some(dither) = self.dither.as_mut() {
+            dither.reset();
+        }
         self.input_meter.reset();
         self.output_meter.reset();
+        self.preview_meter.reset();
         self.normalizer.reset();
+        self.input_slope = None;
         self.analysis_done = false;
     }
 
@@ -443,6 +550,105 @@ mod tests {
         assert_eq!(engine.genre(), Genre::Unknown);
     }
 
+    #[test]
+    fn test_set_tilt() {
+        let mut engine = MasteringEngine::new(48000);
+        engine.set_tilt(1.0);
+        assert_eq!(engine.tilt(), 1.0);
+
+        // Correction stays within the corrector's cap of the user tilt
+        let noise: Vec<f32> = (0..48000u32)
+            .map(|i| ((i.wrapping_mul(2654435761) >> 8) as f32 / 8388608.0) - 1.0)
+            .collect();
+        engine.analyze(&noise, &noise);
+        engine.finalize_analysis();
+        assert!((engine.tilt() - 1.0).abs() <= 1.0);
+        assert_ne!(engine.tilt(), 1.0);
+    }
+
+    #[test]
+    fn test_dither_final_stage() {
+        let mut engine = MasteringEngine::new(48000);
+        engine.set_dither(true, DitherType::EWeighted, 16);
+
+        let audio: Vec<f32> = (0..48000)
+            .map(|i| (2.0 * std::f32::consts::PI * 440.0 * i as f32 / 48000.0).sin() * 0.1)
+            .collect();
+        let result = engine.process_offline(&audio, &audio).unwrap();
+
+        // Every output sample lies on the 16-bit grid
+        let output = result.audio.unwrap();
+        assert!(
+            output
+                .iter()
+                .all(|&s| ((s as f64 * 32768.0) - (s as f64 * 32768.0).round()).abs() < 1e-6)
+        );
+        assert!(
+            result
+                .chain_summary
+                .iter()
+                .any(|line| line == "Dither: TPDF + E-weighted shaping (16-bit)")
+        );
+
+        engine.set_dither(false, DitherType::Tpdf, 24);
+        let result = engine.process_offline(&audio, &audio).unwrap();
+        assert!(
+            result
+                .chain_summary
+                .iter()
+                .any(|line| line == "Dither: off")
+        );
+    }
+
+    #[test]
+    fn test_preview_gain_converges_to_offline() {
+        // Quiet intro, then a louder body with slow level movement
+        let audio: Vec<f32> = (0..480000)
+            .map(|i| {
+                let t = i as f32 / 48000.0;
+                let level = if t < 2.0 {
+                    0.03
+                } else {
+                    0.2 + 0.1 * (t * 0.7).sin()
+                };
+                (2.0 * std::f32::consts::PI * 440.0 * t).sin() * level
+            })
+            .collect();
+
+        let mut offline = MasteringEngine::new(48000);
+        let offline_gain = offline
+            .process_offline(&audio, &audio)
+            .unwrap()
+            .applied_gain;
+
+        let mut engine = MasteringEngine::new(48000);
+        assert_eq!(engine.preview_gain(), 0.0);
+
+        let mut out_l = vec![0.0; 512];
+        let mut out_r = vec![0.0; 512];
+        let mut early_error = None;
+        for (n, chunk) in audio.chunks(512).enumerate() {
+            let len = chunk.len();
+            engine
+                .process(chunk, chunk, &mut out_l[..len], &mut out_r[..len])
+                .unwrap();
+
+            // Estimate from the intro alone is far off
+            if n * 512 >= 96000 && early_error.is_none() {
+                early_error = Some((engine.preview_gain() - offline_gain).abs());
+            }
+        }
+
+        let final_error = (engine.preview_gain() - offline_gain).abs();
+        assert!(early_error.unwrap() > 3.0, "early error {:?}", early_error);
+        assert!(
+            final_error < 0.1,
+            "preview {} vs offline {}",
+            engine.preview_gain(),
+            offline_gain
+        );
+    }
+
     #[test]
     fn test_latency() {
         let engine = MasteringEngine::new(48000);
@@ -0,0 +1,173 @@
+//! Dithering for bit-depth reduction
+//!
+//! Thin stereo wrapper around `rf_dsp::signal_integrity::Dither`:
+//! - TPDF dither (triangular, 2 LSB peak-to-peak)
+//! - Error-feedback noise shaping (E-weighted, high-order)
+//! - Final-stage quantization to the target bit depth
+
+use rf_dsp::signal_integrity::{Dither, DitherType as NoiseType, NoiseShapeType};
+use serde::{Deserialize, Serialize};
+
+/// Dither / noise-shaping curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DitherType {
+    /// Plain rounding, no dither (truncation distortion stays correlated)
+    None,
+    /// Flat TPDF dither
+    #[default]
+    Tpdf,
+    /// TPDF with 5-tap E-weighted noise shaping (Lipshitz)
+    EWeighted,
+    /// TPDF with 9-tap high-order E-weighted noise shaping (Lipshitz)
+    HighOrder,
+}
+
+impl DitherType {
+    /// Matching rf-dsp noise and shaping settings
+    fn dsp_settings(&self) -> (NoiseType, NoiseShapeType) {
+        match self {
+            DitherType::None => (NoiseType::None, NoiseShapeType::None),
+            DitherType::Tpdf => (NoiseType::Tpdf, NoiseShapeType::None),
+            DitherType::EWeighted => (NoiseType::Tpdf, NoiseShapeType::EWeighted),
+            DitherType::HighOrder => (NoiseType::Tpdf, NoiseShapeType::HighOrder),
+        }
+    }
+
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            DitherType::None => "None",
+            DitherType::Tpdf => "TPDF",
+            DitherType::EWeighted => "TPDF + E-weighted shaping",
+            DitherType::HighOrder => "TPDF + high-order shaping",
+        }
+    }
+}
+
+/// Right-channel noise seed, decorrelated from the left
+const RIGHT_SEED: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// Final dither and quantization stage
+#[derive(Debug, Clone)]
+pub struct DitherStage {
+    dither_type: DitherType,
+    left: Dither,
+    right: Dither,
+}
+
+impl DitherStage {
+    /// Create stage quantizing to `target_bits` (clamped to 8-24)
+    pub fn new(target_bits: u32, dither_type: DitherType) -> Self {
+        let target_bits = target_bits.clamp(8, 24);
+        let (noise, shape) = dither_type.dsp_settings();
+        Self {
+            dither_type,
+            left: Dither::new(target_bits, noise, shape),
+            right: Dither::new(target_bits, noise, shape).with_seed(RIGHT_SEED),
+        }
+    }
+
+    /// Applied dither type
+    pub fn dither_type(&self) -> DitherType {
+        self.dither_type
+    }
+
+    /// Target bit depth
+    pub fn target_bits(&self) -> u32 {
+        self.left.target_bits()
+    }
+
+    /// Dither and quantize one stereo sample
+    pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
+        let l = self.left.process(left as f64);
+        let r = self.right.process(right as f64);
+        (l as f32, r as f32)
+    }
+
+    /// Dither and quantize a stereo buffer in place
+    pub fn process_buffer(&mut self, left: &mut [f32], right: &mut [f32]) {
+        for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+            (*l, *r) = self.process(*l, *r);
+        }
+    }
+
+    /// Reset error-feedback state
+    pub fn reset(&mut self) {
+        self.left.reset();
+        self.right.reset();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Error energy at the first harmonics of `freq` (Goertzel)
+    fn harmonic_error(error: &[f64], freq: f64, sample_rate: f64) -> f64 {
+        (2..=7)
+            .map(|h| {
+                let w = 2.0 * std::f64::consts::PI * freq * h as f64 / sample_rate;
+                let (re, im) = error
+                    .iter()
+                    .enumerate()
+                    .fold((0.0, 0.0), |(re, im), (n, &e)| {
+                        (re + e * (w * n as f64).cos(), im - e * (w * n as f64).sin())
+                    });
+                (re * re + im * im) / (error.len() as f64).powi(2)
+            })
+            .sum()
+    }
+
+    #[test]
+    fn test_output_on_grid() {
+        let mut stage = DitherStage::new(16, DitherType::HighOrder);
+        for i in 0..1000 {
+            let x = (i as f32 * 0.01).sin() * 0.9;
+            let (l, _) = stage.process(x, x);
+            let steps = l as f64 * 32768.0;
+            assert!((steps - steps.round()).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_dither_removes_truncation_harmonics() {
+        let sample_rate = 48000.0;
+        let freq = 1000.0;
+
+        // One-second 1 kHz fade from -70 to -90 dBFS (a few LSB at 16-bit)
+        let n = 48000;
+        let input: Vec<f64> = (0..n)
+            .map(|i| {
+                let db = -70.0 - 20.0 * i as f64 / n as f64;
+                10.0_f64.powf(db / 20.0)
+                    * (2.0 * std::f64::consts::PI * freq * i as f64 / sample_rate).sin()
+            })
+            .collect();
+
+        let correlated = |dither_type| {
+            let mut stage = DitherStage::new(16, dither_type);
+            let error: Vec<f64> = input
+                .iter()
+                .map(|&x| stage.process(x as f32, x as f32).0 as f64 - x as f32 as f64)
+                .collect();
+            harmonic_error(&error, freq, sample_rate)
+        };
+
+        let rounded = correlated(DitherType::None);
+        assert!(rounded > 0.0);
+        for dither_type in [
+            DitherType::Tpdf,
+            DitherType::EWeighted,
+            DitherType::HighOrder,
+        ] {
+            let dithered = correlated(dither_type);
+            assert!(
+                dithered < rounded * 0.1,
+                "{:?}: harmonic error {:e} vs undithered {:e}",
+                dither_type,
+                dithered,
+                rounded
+            );
+        }
+    }
+}
@@ -3,11 +3,12 @@
 //! Features:
 //! - Linear phase mastering EQ
 //! - Reference matching EQ
-//! - Tilt EQ
+//! - Tilt EQ (dB/octave around a pivot)
+//! - Spectral balance correction
 //! - Spectral smoothing
 
 use crate::error::{MasterError, MasterResult};
-use crate::ReferenceProfile;
+use crate::{Genre, ReferenceProfile};
 use realfft::{RealFftPlanner, RealToComplex};
 use rustfft::num_complex::Complex;
 use std::sync::Arc;
@@ -83,95 +84,243 @@ impl Default for EqBand {
     }
 }
 
-/// Tilt EQ - simple spectral tilt control
+/// Lowest tilt section center (Hz)
+const TILT_MIN_FREQ: f64 = 10.0;
+
+/// Maximum tilt (dB per octave)
+const MAX_TILT_DB_PER_OCTAVE: f32 = 6.0;
+
+/// First-order high shelf, one per octave of the tilt cascade
+#[derive(Debug, Clone)]
+struct TiltSection {
+    b0: f64,
+    b1: f64,
+    a1: f64,
+    z_l: f64,
+    z_r: f64,
+}
+
+impl TiltSection {
+    /// High shelf with unity DC gain, `gain_db` above `freq` (bilinear, prewarped)
+    fn new(freq: f64, gain_db: f64, sample_rate: f64) -> Self {
+        let k = (std::f64::consts::PI * freq / sample_rate).tan();
+        let sqrt_g = 10.0f64.powf(gain_db / 40.0);
+        let a0 = 1.0 / sqrt_g + k;
+        Self {
+            b0: (sqrt_g + k) / a0,
+            b1: (k - sqrt_g) / a0,
+            a1: (k - 1.0 / sqrt_g) / a0,
+            z_l: 0.0,
+            z_r: 0.0,
+        }
+    }
+
+    /// Magnitude response at normalized angular frequency
+    fn magnitude(&self, omega: f64) -> f64 {
+        let z1 = Complex::from_polar(1.0, -omega);
+        ((z1 * self.b1 + self.b0) / (z1 * self.a1 + 1.0)).norm()
+    }
+
+    #[inline]
+    fn process(&mut self, left: f64, right: f64) -> (f64, f64) {
+        let out_l = self.b0 * left + self.z_l;
+        self.z_l = self.b1 * left - self.a1 * out_l;
+        let out_r = self.b0 * right + self.z_r;
+        self.z_r = self.b1 * right - self.a1 * out_r;
+        (out_l, out_r)
+    }
+}
+
+/// Tilt EQ - constant dB/octave slope around a pivot frequency
+///
+/// Cascade of octave-spaced first-order shelves; each contributes one
+/// octave's worth of slope, and the cascade is normalized to 0 dB at the pivot.
 pub struct TiltEq {
-    /// Tilt amount (dB, positive = treble boost)
-    tilt_db: f32,
-    /// Center frequency
-    center_freq: f32,
+    /// Tilt amount (dB per octave, positive = treble up / bass down)
+    tilt_db_per_octave: f32,
+    /// Pivot frequency (unity gain)
+    pivot_freq: f32,
     /// Sample rate
     sample_rate: u32,
-    /// Filter states
-    lp_state_l: f64,
-    lp_state_r: f64,
-    hp_state_l: f64,
-    hp_state_r: f64,
-    /// Coefficients
-    lp_coeff: f64,
-    hp_coeff: f64,
-    /// Gains
-    low_gain: f32,
-    high_gain: f32,
+    /// Shelf cascade
+    sections: Vec<TiltSection>,
+    /// Gain that puts the pivot at 0 dB
+    makeup: f64,
 }
 
 impl TiltEq {
     /// Create new tilt EQ
     pub fn new(sample_rate: u32) -> Self {
         let mut eq = Self {
-            tilt_db: 0.0,
-            center_freq: 1000.0,
+            tilt_db_per_octave: 0.0,
+            pivot_freq: 1000.0,
             sample_rate,
-            lp_state_l: 0.0,
-            lp_state_r: 0.0,
-            hp_state_l: 0.0,
-            hp_state_r: 0.0,
-            lp_coeff: 0.0,
-            hp_coeff: 0.0,
-            low_gain: 1.0,
-            high_gain: 1.0,
+            sections: Vec::new(),
+            makeup: 1.0,
         };
         eq.update_coefficients();
         eq
     }
 
-    /// Set tilt amount
-    pub fn set_tilt(&mut self, db: f32) {
-        self.tilt_db = db;
+    /// Set tilt (dB per octave around the pivot)
+    pub fn set_tilt(&mut self, db_per_octave: f32) {
+        self.tilt_db_per_octave =
+            db_per_octave.clamp(-MAX_TILT_DB_PER_OCTAVE, MAX_TILT_DB_PER_OCTAVE);
         self.update_coefficients();
     }
 
-    /// Set center frequency
-    pub fn set_center(&mut self, freq: f32) {
-        self.center_freq = freq;
+    /// Get tilt (dB per octave)
+    pub fn tilt(&self) -> f32 {
+        self.tilt_db_per_octave
+    }
+
+    /// Set pivot frequency
+    pub fn set_pivot(&mut self, freq: f32) {
+        self.pivot_freq = freq.clamp(20.0, self.sample_rate as f32 * 0.4);
         self.update_coefficients();
     }
 
+    /// Get pivot frequency
+    pub fn pivot(&self) -> f32 {
+        self.pivot_freq
+    }
+
     fn update_coefficients(&mut self) {
-        let omega = 2.0 * std::f64::consts::PI * self.center_freq as f64 / self.sample_rate as f64;
-        self.lp_coeff = omega / (omega + 1.0);
-        self.hp_coeff = 1.0 / (omega + 1.0);
+        self.sections.clear();
+        self.makeup = 1.0;
+        if self.tilt_db_per_octave == 0.0 {
+            return;
+        }
+
+        let sr = self.sample_rate as f64;
+        let pivot = self.pivot_freq as f64;
+        let max_freq = sr * 0.45;
+
+        // Shelf centers at half-octave offsets from the pivot, one per octave
+        let lowest = (TILT_MIN_FREQ / pivot).log2().floor() as i32;
+        let highest = (max_freq / pivot).log2().ceil() as i32;
+        for k in lowest..highest {
+            let freq = pivot * 2.0f64.powf(k as f64 + 0.5);
+            if (TILT_MIN_FREQ..max_freq).contains(&freq) {
+                self.sections
+                    .push(TiltSection::new(freq, self.tilt_db_per_octave as f64, sr));
+            }
+        }
 
-        // Half the tilt to each side
-        self.low_gain = 10.0f32.powf(-self.tilt_db / 40.0);
-        self.high_gain = 10.0f32.powf(self.tilt_db / 40.0);
+        let omega = 2.0 * std::f64::consts::PI * pivot / sr;
+        let pivot_gain: f64 = self.sections.iter().map(|s| s.magnitude(omega)).product();
+        self.makeup = 1.0 / pivot_gain.max(1e-12);
+    }
+
+    /// Response at given frequency (dB)
+    pub fn response_db(&self, freq: f32) -> f32 {
+        let omega = 2.0 * std::f64::consts::PI * freq as f64 / self.sample_rate as f64;
+        let mag: f64 = self.sections.iter().map(|s| s.magnitude(omega)).product();
+        (20.0 * (mag * self.makeup).log10()) as f32
     }
 
     /// Process stereo sample
     pub fn process(&mut self, left: f32, right: f32) -> (f32, f32) {
-        // Lowpass for low frequencies
-        self.lp_state_l += self.lp_coeff * (left as f64 - self.lp_state_l);
-        self.lp_state_r += self.lp_coeff * (right as f64 - self.lp_state_r);
-
-        // Highpass for high frequencies
-        self.hp_state_l += self.lp_coeff * (left as f64 - self.hp_state_l);
-        self.hp_state_r += self.lp_coeff * (right as f64 - self.hp_state_r);
-
-        let hp_l = left as f64 - self.hp_state_l;
-        let hp_r = right as f64 - self.hp_state_r;
+        if self.sections.is_empty() {
+            return (left, right);
+        }
 
-        // Combine with gains
-        let out_l = self.lp_state_l * self.low_gain as f64 + hp_l * self.high_gain as f64;
-        let out_r = self.lp_state_r * self.low_gain as f64 + hp_r * self.high_gain as f64;
+        let mut l = left as f64 * self.makeup;
+        let mut r = right as f64 * self.makeup;
+        for section in &mut self.sections {
+            (l, r) = section.process(l, r);
+        }
 
-        (out_l as f32, out_r as f32)
+        (l as f32, r as f32)
     }
 
     /// Reset state
     pub fn reset(&mut self) {
-        self.lp_state_l = 0.0;
-        self.lp_state_r = 0.0;
-        self.hp_state_l = 0.0;
-        self.hp_state_r = 0.0;
+        for section in &mut self.sections {
+            section.z_l = 0.0;
+            section.z_r = 0.0;
+        }
+    }
+}
+
+/// Typical mastered-music spectral slope (dB/octave, per-bin magnitude)
+const REFERENCE_SLOPE_DB_PER_OCTAVE: f32 = -4.5;
+
+/// Estimate spectral slope (dB/octave) of a magnitude spectrum
+///
+/// Least-squares fit of bin level against log2(frequency), 50 Hz - 16 kHz.
+/// Bins are weighted by 1/f so every octave counts equally.
+pub fn spectral_slope(spectrum: &[f32], sample_rate: u32) -> f32 {
+    if spectrum.len() < 2 {
+        return 0.0;
+    }
+
+    let bin_width = sample_rate as f64 / (2.0 * (spectrum.len() - 1) as f64);
+    let (mut sw, mut sx, mut sy, mut sxx, mut sxy) = (0.0, 0.0, 0.0, 0.0, 0.0);
+
+    for (i, &mag) in spectrum.iter().enumerate().skip(1) {
+        let freq = i as f64 * bin_width;
+        if !(50.0..=16000.0).contains(&freq) || mag <= 1e-10 {
+            continue;
+        }
+        let w = 1.0 / freq;
+        let x = freq.log2();
+        let y = 20.0 * (mag as f64).log10();
+        sw += w;
+        sx += w * x;
+        sy += w * y;
+        sxx += w * x * x;
+        sxy += w * x * y;
+    }
+
+    let denom = sw * sxx - sx * sx;
+    if sw == 0.0 || denom.abs() < 1e-12 {
+        return 0.0;
+    }
+    ((sw * sxy - sx * sy) / denom) as f32
+}
+
+/// Spectral balance corrector - derives a tilt that nudges the
+/// measured slope toward a genre or reference target
+#[derive(Debug, Clone)]
+pub struct SpectralBalance {
+    /// Fraction of the slope difference to correct (0-1)
+    amount: f32,
+    /// Largest correction applied (dB/octave)
+    max_correction: f32,
+}
+
+impl Default for SpectralBalance {
+    fn default() -> Self {
+        Self {
+            amount: 0.5,
+            max_correction: 1.0,
+        }
+    }
+}
+
+impl SpectralBalance {
+    /// Create corrector
+    pub fn new(amount: f32, max_correction: f32) -> Self {
+        Self {
+            amount: amount.clamp(0.0, 1.0),
+            max_correction: max_correction.abs().min(MAX_TILT_DB_PER_OCTAVE),
+        }
+    }
+
+    /// Target slope for a genre (bass-heavy genres sit steeper)
+    pub fn genre_target(genre: Genre) -> f32 {
+        REFERENCE_SLOPE_DB_PER_OCTAVE - genre.spectral_tilt() * 0.25
+    }
+
+    /// Target slope of a reference track
+    pub fn reference_target(profile: &ReferenceProfile, sample_rate: u32) -> f32 {
+        spectral_slope(&profile.spectrum, sample_rate)
+    }
+
+    /// Tilt (dB/octave) that moves `measured` toward `target`
+    pub fn correction(&self, measured: f32, target: f32) -> f32 {
+        ((target - measured) * self.amount).clamp(-self.max_correction, self.max_correction)
     }
 }
 
@@ -519,6 +668,54 @@ mod tests {
         assert!(r.is_finite());
     }
 
+    /// Steady-state gain (dB) of a sine through the tilt EQ
+    fn sine_gain_db(tilt: &mut TiltEq, freq: f32) -> f32 {
+        tilt.reset();
+        let mut in_energy = 0.0f64;
+        let mut out_energy = 0.0f64;
+        for i in 0..48000 {
+            let x = (2.0 * std::f32::consts::PI * freq * i as f32 / 48000.0).sin() * 0.5;
+            let (y, _) = tilt.process(x, x);
+            if i >= 24000 {
+                in_energy += (x * x) as f64;
+                out_energy += (y * y) as f64;
+            }
+        }
+        (10.0 * (out_energy / in_energy).log10()) as f32
+    }
+
+    #[test]
+    fn test_tilt_db_per_octave_symmetric() {
+        let mut tilt = TiltEq::new(48000);
+        tilt.set_pivot(1000.0);
+        tilt.set_tilt(1.0);
+
+        let pivot = sine_gain_db(&mut tilt, 1000.0);
+        let high = sine_gain_db(&mut tilt, 4000.0);
+        let low = sine_gain_db(&mut tilt, 250.0);
+
+        assert!(pivot.abs() < 0.1, "pivot should be unity: {}", pivot);
+        assert!((high - 2.0).abs() < 0.3, "+2 oct gain: {}", high);
+        assert!((low + 2.0).abs() < 0.3, "-2 oct gain: {}", low);
+        assert!((high + low).abs() < 0.2, "asymmetric: {} / {}", high, low);
+        assert!((tilt.response_db(4000.0) - high).abs() < 0.1);
+    }
+
+    #[test]
+    fn test_spectral_balance_correction() {
+        // Spectrum falling 6 dB/octave (magnitude ∝ 1/f)
+        let spectrum: Vec<f32> = (0..2049).map(|i| 1.0 / (i.max(1) as f32)).collect();
+        let slope = spectral_slope(&spectrum, 48000);
+        assert!((slope + 6.02).abs() < 0.1, "slope: {}", slope);
+
+        // Too dark for a -4.5 dB/oct target → nudge treble up, capped
+        let balance = SpectralBalance::default();
+        let target = SpectralBalance::genre_target(Genre::Jazz);
+        let correction = balance.correction(slope, target);
+        assert!(correction > 0.0 && correction <= 1.0, "{}", correction);
+        assert_eq!(balance.correction(target, target), 0.0);
+    }
+
     #[test]
     fn test_linear_phase_eq() {
         let config = MasterEqConfig::default();
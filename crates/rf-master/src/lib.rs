@@ -9,6 +9,7 @@
 //! - **Dynamic Control**: Adaptive multiband dynamics with genre profiles
 //! - **Stereo Enhancement**: Width optimization and mono compatibility
 //! - **True Peak Limiting**: ISP-safe limiting with 8x oversampling
+//! - **Dithering**: TPDF with optional noise shaping on bit-depth reduction
 //! - **Reference Matching**: Match spectral/dynamic profile of reference tracks
 //!
 //! ## Usage
@@ -32,6 +33,7 @@
 
 pub mod analysis;
 pub mod chain;
+pub mod dither;
 pub mod dynamics;
 pub mod eq;
 pub mod limiter;
@@ -42,6 +44,7 @@ pub mod stereo;
 mod error;
 
 pub use chain::MasteringEngine;
+pub use dither::{DitherStage, DitherType};
 pub use error::{MasterError, MasterResult};
 
 use serde::{Deserialize, Serialize};
@@ -279,6 +282,9 @@ pub struct MasterConfig {
     pub dither: bool,
     /// Target bit depth
     pub target_bits: u32,
+    /// Dither / noise-shaping curve
+    #[serde(default)]
+    pub dither_type: DitherType,
 }
 
 impl Default for MasterConfig {
@@ -297,6 +303,7 @@ impl Default for MasterConfig {
             limiter_lookahead_ms: 5.0,
             dither: true,
             target_bits: 24,
+            dither_type: DitherType::Tpdf,
         }
     }
 }
@@ -327,6 +334,9 @@ pub struct DynamicsProfile {
     pub lra: f32,
     /// Multiband dynamics
     pub band_dynamics: Vec<f32>,
+    /// Peak-to-loudness ratio (dB)
+    #[serde(default)]
+    pub plr: f32,
 }
 
 /// Stereo profile from analysis
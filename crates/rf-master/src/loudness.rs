@@ -109,6 +109,10 @@ pub struct LufsMeter {
     buffer_pos: usize,
     /// Samples per 100ms block
     block_size: usize,
+    /// K-weighted power of the block in progress
+    block_power: f64,
+    /// Samples in the block in progress
+    samples_in_block: usize,
     /// Current momentary
     momentary_lufs: f64,
     /// Current short-term
@@ -135,6 +139,8 @@ impl LufsMeter {
             integrated_power: Vec::with_capacity(10000),
             buffer_pos: 0,
             block_size,
+            block_power: 0.0,
+            samples_in_block: 0,
             momentary_lufs: -70.0,
             short_term_lufs: -70.0,
             integrated_lufs: -70.0,
@@ -143,10 +149,10 @@ impl LufsMeter {
     }
 
     /// Process samples and update measurements
+    ///
+    /// A partial 100ms block carries over to the next call, so any buffer
+    /// size measures the same.
     pub fn process(&mut self, left: &[f32], right: &[f32]) {
-        let mut block_power = 0.0f64;
-        let mut samples_in_block = 0usize;
-
         for i in 0..left.len().min(right.len()) {
             // Track true peak (simplified - should use oversampling)
             let peak = left[i].abs().max(right[i].abs());
@@ -159,15 +165,15 @@ impl LufsMeter {
             let filtered_r = self.filter_r.process(right[i] as f64);
 
             // Mean square
-            block_power += filtered_l * filtered_l + filtered_r * filtered_r;
-            samples_in_block += 1;
+            self.block_power += filtered_l * filtered_l + filtered_r * filtered_r;
+            self.samples_in_block += 1;
 
             // Process block
-            if samples_in_block >= self.block_size {
-                let mean_power = block_power / (2.0 * samples_in_block as f64);
+            if self.samples_in_block >= self.block_size {
+                let mean_power = self.block_power / (2.0 * self.samples_in_block as f64);
                 self.process_block(mean_power);
-                block_power = 0.0;
-                samples_in_block = 0;
+                self.block_power = 0.0;
+                self.samples_in_block = 0;
             }
         }
     }
@@ -249,6 +255,11 @@ impl LufsMeter {
         self.integrated_lufs as f32
     }
 
+    /// Number of 100ms blocks above the absolute gate so far
+    pub fn gated_blocks(&self) -> usize {
+        self.integrated_power.len()
+    }
+
     /// Get max true peak (dBTP)
     pub fn true_peak(&self) -> f32 {
         if self.max_true_peak > 1e-10 {
@@ -266,6 +277,8 @@ impl LufsMeter {
         self.short_term_buffer.fill(0.0);
         self.integrated_power.clear();
         self.buffer_pos = 0;
+        self.block_power = 0.0;
+        self.samples_in_block = 0;
         self.momentary_lufs = -70.0;
         self.short_term_lufs = -70.0;
         self.integrated_lufs = -70.0;
@@ -307,23 +320,31 @@ impl LoudnessNormalizer {
         self.meter.process(left, right);
     }
 
+    /// Set target loudness (takes effect on the next `finalize`)
+    pub fn set_target(&mut self, target: LoudnessTarget) {
+        self.target = target;
+    }
+
     /// Finalize analysis and calculate gain
     pub fn finalize(&mut self) {
-        let current_lufs = self.meter.integrated();
-        let target_lufs = self.target.integrated_lufs;
-
-        // Calculate required gain
-        let gain_db = target_lufs - current_lufs;
-
-        // Limit gain adjustment to avoid clipping
-        let max_headroom = -self.meter.true_peak();
-        let safe_gain_db = gain_db.min(max_headroom - (-self.target.true_peak));
+        let safe_gain_db = Self::gain_db_for(&self.meter, &self.target);
 
         self.gain = 10.0f32.powf(safe_gain_db / 20.0);
         self.smoothed_gain = self.gain as f64;
         self.analyzed = true;
     }
 
+    /// Gain (dB) that brings the measured audio to `target`, limited so the
+    /// measured peak stays under the true-peak ceiling
+    pub fn gain_db_for(meter: &LufsMeter, target: &LoudnessTarget) -> f32 {
+        // Calculate required gain
+        let gain_db = target.integrated_lufs - meter.integrated();
+
+        // Limit gain adjustment to avoid clipping
+        let max_headroom = -meter.true_peak();
+        gain_db.min(max_headroom - (-target.true_peak))
+    }
+
     /// Get calculated gain (dB)
     pub fn gain_db(&self) -> f32 {
         20.0 * self.gain.log10()
@@ -479,6 +500,25 @@ mod tests {
         assert!(normalizer.gain_db() > 0.0);
     }
 
+    #[test]
+    fn test_lufs_meter_block_size_independent() {
+        let sine: Vec<f32> = (0..96000)
+            .map(|i| (2.0 * std::f32::consts::PI * 1000.0 * i as f32 / 48000.0).sin() * 0.5)
+            .collect();
+
+        let mut whole = LufsMeter::new(48000);
+        whole.process(&sine, &sine);
+
+        // Buffers smaller than a 100ms block still complete blocks
+        let mut chunked = LufsMeter::new(48000);
+        for chunk in sine.chunks(512) {
+            chunked.process(chunk, chunk);
+        }
+
+        assert_eq!(chunked.gated_blocks(), whole.gated_blocks());
+        assert!((chunked.integrated() - whole.integrated()).abs() < 1e-4);
+    }
+
     #[test]
     fn test_lra_calculator() {
         let mut lra = LraCalculator::new(48000);
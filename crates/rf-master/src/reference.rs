@@ -347,6 +347,7 @@ impl GenreReference {
                 dynamic_range: 6.0,
                 lra: 5.0,
                 band_dynamics: vec![-10.0, -8.0, -6.0, -8.0],
+                plr: 8.0,
             },
             stereo: StereoProfile {
                 correlation: 0.6,
@@ -374,6 +375,7 @@ impl GenreReference {
                 dynamic_range: 8.0,
                 lra: 7.0,
                 band_dynamics: vec![-12.0, -10.0, -8.0, -10.0],
+                plr: 10.0,
             },
             stereo: StereoProfile {
                 correlation: 0.7,
@@ -401,6 +403,7 @@ impl GenreReference {
                 dynamic_range: 25.0,
                 lra: 18.0,
                 band_dynamics: vec![-20.0, -18.0, -16.0, -18.0],
+                plr: 20.0,
             },
             stereo: StereoProfile {
                 correlation: 0.5,
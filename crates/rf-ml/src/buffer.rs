@@ -4,6 +4,8 @@
 
 use std::collections::VecDeque;
 
+use crate::error::{MlError, MlResult};
+
 /// Single audio frame for processing
 #[derive(Debug, Clone)]
 pub struct AudioFrame {
@@ -330,6 +332,176 @@ impl OverlapAddBuffer {
     }
 }
 
+/// Window used by `OverlapAddFramer` for both analysis and synthesis
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FramerWindow {
+    /// Periodic Hann
+    #[default]
+    Hann,
+    /// Periodic sqrt-Hann (w² is Hann)
+    SqrtHann,
+    /// No windowing
+    Rectangular,
+}
+
+impl FramerWindow {
+    /// Window coefficients for a frame of `size` samples
+    pub fn coefficients(&self, size: usize) -> Vec<f32> {
+        (0..size)
+            .map(|i| {
+                let phase = std::f32::consts::PI * i as f32 / size as f32;
+                match self {
+                    FramerWindow::Hann => phase.sin().powi(2),
+                    FramerWindow::SqrtHann => phase.sin(),
+                    FramerWindow::Rectangular => 1.0,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Windowed framing with overlap-add reconstruction
+///
+/// Input is split into windowed frames of `frame_size` every `hop` samples.
+/// Processed frames are windowed again, overlap-added, and divided by the
+/// summed squared window, so an identity process reconstructs the input
+/// exactly, delayed by `latency_samples()`.
+pub struct OverlapAddFramer {
+    /// Frame size
+    frame_size: usize,
+    /// Hop size
+    hop: usize,
+    /// Analysis/synthesis window
+    window: Vec<f32>,
+    /// 1 / Σ w² for each position within a hop
+    norm: Vec<f32>,
+    /// Pending input
+    input: VecDeque<f32>,
+    /// Overlap-add accumulator (one frame)
+    accum: Vec<f32>,
+    /// Reconstructed output ready to read
+    output: VecDeque<f32>,
+}
+
+impl OverlapAddFramer {
+    /// Create framer, rejecting window/hop combinations that can't reconstruct
+    pub fn new(frame_size: usize, hop: usize, window: FramerWindow) -> MlResult<Self> {
+        if frame_size == 0 || hop == 0 || hop > frame_size {
+            return Err(MlError::ProcessingFailed(format!(
+                "invalid framing: frame size {}, hop {}",
+                frame_size, hop
+            )));
+        }
+
+        let window = window.coefficients(frame_size);
+        let mut norm = Vec::with_capacity(hop);
+        for j in 0..hop {
+            let sum: f32 = window[j..].iter().step_by(hop).map(|w| w * w).sum();
+            if sum < 1e-6 {
+                return Err(MlError::ProcessingFailed(format!(
+                    "window does not cover position {} at hop {}",
+                    j, hop
+                )));
+            }
+            norm.push(1.0 / sum);
+        }
+
+        let mut framer = Self {
+            frame_size,
+            hop,
+            window,
+            norm,
+            input: VecDeque::with_capacity(frame_size * 2),
+            accum: vec![0.0; frame_size],
+            output: VecDeque::with_capacity(frame_size * 2),
+        };
+        framer.reset();
+        Ok(framer)
+    }
+
+    /// Push input samples (read the completed frames with `next_frame`)
+    pub fn push(&mut self, samples: &[f32]) {
+        self.input.extend(samples);
+    }
+
+    /// Write the next complete windowed frame into `frame`
+    ///
+    /// Returns false, leaving `frame` untouched, while less than a frame of
+    /// input is pending.
+    pub fn next_frame(&mut self, frame: &mut [f32]) -> bool {
+        assert_eq!(frame.len(), self.frame_size);
+        if self.input.len() < self.frame_size {
+            return false;
+        }
+
+        for ((out, &s), &w) in frame.iter_mut().zip(&self.input).zip(&self.window) {
+            *out = s * w;
+        }
+        self.input.drain(..self.hop);
+        true
+    }
+
+    /// Overlap-add a processed frame (frames must be added in push order)
+    pub fn add_frame(&mut self, frame: &[f32]) {
+        assert_eq!(frame.len(), self.frame_size);
+
+        for ((acc, &s), &w) in self.accum.iter_mut().zip(frame).zip(&self.window) {
+            *acc += s * w;
+        }
+
+        // The first hop is now final: no later frame overlaps it
+        let finished = self.accum[..self.hop]
+            .iter()
+            .zip(&self.norm)
+            .map(|(&s, &n)| s * n);
+        self.output.extend(finished);
+
+        self.accum.copy_within(self.hop.., 0);
+        let tail = self.frame_size - self.hop;
+        self.accum[tail..].fill(0.0);
+    }
+
+    /// Read reconstructed samples, returning how many were written
+    pub fn pop_output(&mut self, output: &mut [f32]) -> usize {
+        let count = output.len().min(self.output.len());
+        for (out, sample) in output.iter_mut().zip(self.output.drain(..count)) {
+            *out = sample;
+        }
+        count
+    }
+
+    /// Reconstructed samples ready to read
+    pub fn available(&self) -> usize {
+        self.output.len()
+    }
+
+    /// Delay between input and reconstructed output
+    pub fn latency_samples(&self) -> usize {
+        self.frame_size - self.hop
+    }
+
+    /// Frame size
+    pub fn frame_size(&self) -> usize {
+        self.frame_size
+    }
+
+    /// Hop size
+    pub fn hop(&self) -> usize {
+        self.hop
+    }
+
+    /// Reset state
+    pub fn reset(&mut self) {
+        self.input.clear();
+        self.output.clear();
+        self.accum.fill(0.0);
+
+        // Prime so the first real sample is covered by a full set of frames
+        self.input
+            .extend(std::iter::repeat_n(0.0, self.latency_samples()));
+    }
+}
+
 /// STFT analysis buffer
 pub struct StftBuffer {
     /// Frame buffer for input
@@ -464,4 +636,36 @@ mod tests {
         // Should have hop_size samples available after second frame
         assert!(buffer.available() >= 128);
     }
+
+    #[test]
+    fn test_overlap_add_framer_reconstruction() {
+        let mut framer = OverlapAddFramer::new(512, 256, FramerWindow::Hann).unwrap();
+        let latency = framer.latency_samples();
+
+        let input: Vec<f32> = (0..8000)
+            .map(|i| (i as f32 * 0.013).sin() * 0.7 + (i as f32 * 0.41).cos() * 0.2)
+            .collect();
+
+        // Odd chunk sizes exercise partial frames
+        let mut output = Vec::new();
+        let mut scratch = vec![0.0; 1024];
+        let mut frame = vec![0.0; 512];
+        for chunk in input.chunks(173) {
+            framer.push(chunk);
+            while framer.next_frame(&mut frame) {
+                framer.add_frame(&frame);
+            }
+            let n = framer.pop_output(&mut scratch);
+            output.extend_from_slice(&scratch[..n]);
+        }
+
+        assert!(output.len() > latency + 7000);
+        assert!(output[..latency].iter().all(|s| s.abs() < 1e-6));
+        for (i, (&out, &x)) in output[latency..].iter().zip(&input).enumerate() {
+            assert!((out - x).abs() < 1e-5, "sample {}: {} vs {}", i, out, x);
+        }
+
+        // Hann at hop = frame leaves gaps that can't be reconstructed
+        assert!(OverlapAddFramer::new(512, 512, FramerWindow::Hann).is_err());
+    }
 }
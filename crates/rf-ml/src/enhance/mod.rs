@@ -15,14 +15,30 @@
 //! // Process frame-by-frame (5ms latency)
 //! let enhanced = enhancer.process_frame(&noisy_frame)?;
 //! ```
+//!
+//! ## Streaming
+//!
+//! ```rust,ignore
+//! use rf_ml::InferenceConfig;
+//! use rf_ml::enhance::StreamingEnhancer;
+//!
+//! // Fails with `MlError::ModelNotFound` if the aTENNuate model isn't installed;
+//! // `StreamingEnhancer::state_space()` runs the built-in noise tracker instead
+//! let mut enhancer = StreamingEnhancer::new(InferenceConfig::default())?;
+//!
+//! // Any block size — buffered internally to 256-sample frames
+//! enhancer.process(&input, &mut output)?;
+//! ```
 
 mod attenuate;
 mod config;
 mod frcrn;
+mod streaming;
 
 pub use attenuate::ATENNuate;
 pub use config::{EnhanceConfig, EnhanceMode};
 pub use frcrn::FRCRN;
+pub use streaming::StreamingEnhancer;
 
 use crate::buffer::AudioFrame;
 use crate::error::MlResult;
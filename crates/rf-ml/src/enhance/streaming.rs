@@ -0,0 +1,738 @@
+//! Streaming aTENNuate speech enhancer
+//!
+//! Block-size agnostic wrapper around the aTENNuate SSM model:
+//! - Input is buffered into 256-sample frames (50% overlap, sqrt-Hann)
+//! - Fixed latency of one frame (5.3ms @ 48kHz)
+//! - Host rates other than 48kHz are resampled to the model rate internally
+//! - Without the ONNX model, `state_space()` runs a recursive noise tracker
+//!   instead (opt-in; `new` reports a missing model as an error)
+
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::Arc;
+
+use ndarray::Array2;
+use num_complex::Complex32;
+use realfft::{ComplexToReal, RealFftPlanner, RealToComplex};
+
+use crate::buffer::{FramerWindow, OverlapAddFramer};
+use crate::error::{MlError, MlResult};
+use crate::inference::{InferenceConfig, InferenceEngine};
+use crate::{frame_sizes, models, sample_rates};
+
+/// Frame length in samples (model rate)
+const FRAME: usize = frame_sizes::ATENNUATE;
+
+/// Hop between frames (50% overlap)
+const HOP: usize = FRAME / 2;
+
+/// Number of spectral bins per frame
+const BINS: usize = FRAME / 2 + 1;
+
+/// Power smoothing for the noise tracker
+const POWER_SMOOTH: f32 = 0.7;
+
+/// Noise floor rise per frame (~3 dB/s at 48kHz / 128 hop)
+const NOISE_RISE: f32 = 1.0018;
+
+/// Bias compensation for minimum-tracked noise power
+const NOISE_BIAS: f32 = 1.5;
+
+/// Decision-directed a-priori SNR smoothing
+const DD_ALPHA: f32 = 0.96;
+
+/// Lowest mask gain (-24 dB) at full strength
+const MIN_GAIN: f32 = 0.063;
+
+/// Fixed-ratio linear interpolator for host ↔ model rate conversion
+struct LinearResampler {
+    /// Input samples advanced per output sample
+    step: f64,
+    /// Fractional read position between `prev` and the next input
+    pos: f64,
+    /// Previous input sample
+    prev: f32,
+}
+
+impl LinearResampler {
+    fn new(from_rate: u32, to_rate: u32) -> Self {
+        Self {
+            step: from_rate as f64 / to_rate as f64,
+            pos: 0.0,
+            prev: 0.0,
+        }
+    }
+
+    /// Push one input sample, appending all outputs it completes
+    fn push(&mut self, sample: f32, out: &mut VecDeque<f32>) {
+        while self.pos < 1.0 {
+            let t = self.pos as f32;
+            out.push_back(self.prev + (sample - self.prev) * t);
+            self.pos += self.step;
+        }
+        self.pos -= 1.0;
+        self.prev = sample;
+    }
+
+    fn reset(&mut self) {
+        self.pos = 0.0;
+        self.prev = 0.0;
+    }
+}
+
+/// Host-rate conversion state (only present when host rate ≠ 48kHz)
+struct RateBridge {
+    host_rate: u32,
+    to_model: LinearResampler,
+    to_host: LinearResampler,
+    model_in: VecDeque<f32>,
+    model_out: Vec<f32>,
+    host_out: VecDeque<f32>,
+}
+
+impl RateBridge {
+    /// Samples pre-queued on the host side to absorb interpolator jitter
+    const PRIME: usize = 2;
+
+    fn new(host_rate: u32) -> Self {
+        let mut bridge = Self {
+            host_rate,
+            to_model: LinearResampler::new(host_rate, sample_rates::ATENNUATE),
+            to_host: LinearResampler::new(sample_rates::ATENNUATE, host_rate),
+            model_in: VecDeque::with_capacity(FRAME * 2),
+            model_out: Vec::with_capacity(FRAME * 2),
+            host_out: VecDeque::with_capacity(FRAME * 2),
+        };
+        bridge.reset();
+        bridge
+    }
+
+    fn reset(&mut self) {
+        self.to_model.reset();
+        self.to_host.reset();
+        self.model_in.clear();
+        self.host_out.clear();
+        self.host_out.extend(std::iter::repeat_n(0.0, Self::PRIME));
+    }
+}
+
+/// Streaming speech enhancer (aTENNuate SSM)
+pub struct StreamingEnhancer {
+    /// SSM model (None = built-in state-space noise tracker)
+    model: Option<InferenceEngine>,
+
+    /// Forward FFT
+    fft_forward: Arc<dyn RealToComplex<f32>>,
+
+    /// Inverse FFT
+    fft_inverse: Arc<dyn ComplexToReal<f32>>,
+
+    /// sqrt-Hann framing and overlap-add
+    framer: OverlapAddFramer,
+
+    /// Smoothed power per bin
+    power: Vec<f32>,
+
+    /// Tracked noise power per bin
+    noise: Vec<f32>,
+
+    /// Previous frame gain per bin (decision-directed SNR)
+    prev_gain: Vec<f32>,
+
+    /// Previous a-posteriori SNR per bin
+    prev_snr: Vec<f32>,
+
+    /// Noise tracker initialised
+    primed: bool,
+
+    /// Host-rate conversion (None at 48kHz)
+    bridge: Option<RateBridge>,
+
+    /// Enhancement strength (0.0 - 1.0)
+    strength: f32,
+
+    /// Windowed input frame from the framer
+    frame_in: Vec<f32>,
+
+    /// Enhanced frame scratch buffer
+    frame_scratch: Vec<f32>,
+
+    /// Spectrum scratch buffer
+    spectrum_scratch: Vec<Complex32>,
+
+    /// Mask scratch buffer
+    mask: Vec<f32>,
+
+    /// FFT scratch buffer
+    fft_scratch: Vec<Complex32>,
+
+    /// IFFT scratch buffer
+    ifft_scratch: Vec<Complex32>,
+}
+
+impl StreamingEnhancer {
+    /// Create enhancer, loading the aTENNuate model from the registry path
+    ///
+    /// Fails with `MlError::ModelNotFound` when the model isn't installed;
+    /// use `state_space()` to run without it.
+    pub fn new(config: InferenceConfig) -> MlResult<Self> {
+        Self::with_model(models::ATENNUATE, config)
+    }
+
+    /// Create enhancer from an explicit model path
+    pub fn with_model<P: AsRef<Path>>(model_path: P, config: InferenceConfig) -> MlResult<Self> {
+        let model = InferenceEngine::new(model_path, config)?;
+        Self::with_engine(Some(model))
+    }
+
+    /// Create enhancer running the built-in state-space noise tracker
+    /// (recursive minimum tracking + Wiener mask) instead of the model
+    pub fn state_space() -> MlResult<Self> {
+        Self::with_engine(None)
+    }
+
+    fn with_engine(model: Option<InferenceEngine>) -> MlResult<Self> {
+        let mut planner = RealFftPlanner::new();
+        let fft_forward = planner.plan_fft_forward(FRAME);
+        let fft_inverse = planner.plan_fft_inverse(FRAME);
+
+        let fft_scratch_len = fft_forward.get_scratch_len();
+        let ifft_scratch_len = fft_inverse.get_scratch_len();
+
+        let mut enhancer = Self {
+            model,
+            fft_forward,
+            fft_inverse,
+            framer: OverlapAddFramer::new(FRAME, HOP, FramerWindow::SqrtHann)?,
+            power: vec![0.0; BINS],
+            noise: vec![0.0; BINS],
+            prev_gain: vec![1.0; BINS],
+            prev_snr: vec![1.0; BINS],
+            primed: false,
+            bridge: None,
+            strength: 0.8,
+            frame_in: vec![0.0; FRAME],
+            frame_scratch: vec![0.0; FRAME],
+            spectrum_scratch: vec![Complex32::new(0.0, 0.0); BINS],
+            mask: vec![1.0; BINS],
+            fft_scratch: vec![Complex32::new(0.0, 0.0); fft_scratch_len],
+            ifft_scratch: vec![Complex32::new(0.0, 0.0); ifft_scratch_len],
+        };
+        enhancer.reset();
+        Ok(enhancer)
+    }
+
+    /// Set host sample rate (model always runs at 48kHz)
+    pub fn set_sample_rate(&mut self, sample_rate: u32) -> MlResult<()> {
+        if sample_rate == 0 {
+            return Err(MlError::InvalidSampleRate {
+                expected: sample_rates::ATENNUATE,
+                got: sample_rate,
+            });
+        }
+        self.bridge =
+            (sample_rate != sample_rates::ATENNUATE).then(|| RateBridge::new(sample_rate));
+        self.reset();
+        Ok(())
+    }
+
+    /// Host sample rate
+    pub fn sample_rate(&self) -> u32 {
+        self.bridge
+            .as_ref()
+            .map_or(sample_rates::ATENNUATE, |b| b.host_rate)
+    }
+
+    /// Process mono samples (any block size, output delayed by `latency_samples`)
+    pub fn process(&mut self, input: &[f32], output: &mut [f32]) -> MlResult<()> {
+        if output.len() < input.len() {
+            return Err(MlError::BufferTooSmall {
+                needed: input.len(),
+                got: output.len(),
+            });
+        }
+
+        let Some(mut bridge) = self.bridge.take() else {
+            return self.process_model_rate(input, &mut output[..input.len()]);
+        };
+
+        let result = (|| {
+            for &x in input {
+                bridge.to_model.push(x, &mut bridge.model_in);
+            }
+            let model_in = bridge.model_in.make_contiguous();
+            bridge.model_out.resize(model_in.len(), 0.0);
+            self.process_model_rate(model_in, &mut bridge.model_out)?;
+            bridge.model_in.clear();
+            for &y in &bridge.model_out {
+                bridge.to_host.push(y, &mut bridge.host_out);
+            }
+            Ok(())
+        })();
+
+        for y in output.iter_mut().take(input.len()) {
+            *y = bridge.host_out.pop_front().unwrap_or(0.0);
+        }
+        self.bridge = Some(bridge);
+        result
+    }
+
+    /// Process model-rate samples, writing exactly as many delayed outputs
+    fn process_model_rate(&mut self, input: &[f32], output: &mut [f32]) -> MlResult<()> {
+        self.framer.push(input);
+        while self.framer.next_frame(&mut self.frame_in) {
+            self.process_frame()?;
+            self.framer.add_frame(&self.frame_scratch);
+        }
+
+        // The hop primed in `reset` keeps a full block of output ready
+        let written = self.framer.pop_output(output);
+        debug_assert_eq!(written, output.len());
+        Ok(())
+    }
+
+    /// Enhance the windowed 256-sample `frame_in` into `frame_scratch`
+    fn process_frame(&mut self) -> MlResult<()> {
+        self.fft_forward
+            .process_with_scratch(
+                &mut self.frame_in,
+                &mut self.spectrum_scratch,
+                &mut self.fft_scratch,
+            )
+            .map_err(|e| MlError::ProcessingFailed(format!("FFT failed: {}", e)))?;
+
+        if self.model.is_some() {
+            self.model_mask()?;
+        } else {
+            self.state_space_mask();
+        }
+
+        // Blend with dry according to strength
+        for (c, &g) in self.spectrum_scratch.iter_mut().zip(self.mask.iter()) {
+            *c *= 1.0 - self.strength * (1.0 - g);
+        }
+
+        self.fft_inverse
+            .process_with_scratch(
+                &mut self.spectrum_scratch,
+                &mut self.frame_scratch,
+                &mut self.ifft_scratch,
+            )
+            .map_err(|e| MlError::ProcessingFailed(format!("IFFT failed: {}", e)))?;
+
+        let norm = 1.0 / FRAME as f32;
+        for s in &mut self.frame_scratch {
+            *s *= norm;
+        }
+
+        Ok(())
+    }
+
+    /// Run SSM model on log magnitude → sigmoid mask
+    fn model_mask(&mut self) -> MlResult<()> {
+        let Some(model) = &self.model else {
+            return Ok(());
+        };
+
+        let mut input = Array2::<f32>::zeros((1, BINS));
+        for (i, c) in self.spectrum_scratch.iter().enumerate() {
+            input[[0, i]] = c.norm().ln().max(-10.0);
+        }
+
+        let output = model.run_array2(&input)?;
+        if output.shape() != [1, BINS] {
+            return Err(MlError::InvalidOutputShape {
+                expected: format!("[1, {BINS}] mask"),
+                got: format!("{:?}", output.shape()),
+            });
+        }
+
+        for (m, &logit) in self.mask.iter_mut().zip(output.iter()) {
+            *m = 1.0 / (1.0 + (-logit).exp());
+        }
+        Ok(())
+    }
+
+    /// Recursive noise tracking + decision-directed Wiener mask
+    fn state_space_mask(&mut self) {
+        for (i, c) in self.spectrum_scratch.iter().enumerate() {
+            let p = c.norm_sqr();
+
+            if !self.primed {
+                self.power[i] = p;
+                self.noise[i] = p;
+            }
+
+            // State update: smoothed power, minimum-tracked noise floor
+            self.power[i] = POWER_SMOOTH * self.power[i] + (1.0 - POWER_SMOOTH) * p;
+            self.noise[i] = if self.power[i] < self.noise[i] {
+                self.power[i]
+            } else {
+                self.noise[i] * NOISE_RISE
+            };
+
+            let noise = (self.noise[i] * NOISE_BIAS).max(1e-12);
+            let snr_post = p / noise;
+            let snr_prio = DD_ALPHA * self.prev_gain[i] * self.prev_gain[i] * self.prev_snr[i]
+                + (1.0 - DD_ALPHA) * (snr_post - 1.0).max(0.0);
+
+            let gain = (snr_prio / (1.0 + snr_prio)).max(MIN_GAIN);
+            self.mask[i] = gain;
+            self.prev_gain[i] = gain;
+            self.prev_snr[i] = snr_post;
+        }
+        self.primed = true;
+    }
+
+    /// Reset streaming and model state
+    pub fn reset(&mut self) {
+        self.framer.reset();
+        // One extra hop of silence so every block can be answered in full
+        self.framer.push(&[0.0; HOP]);
+        while self.framer.next_frame(&mut self.frame_in) {
+            self.framer.add_frame(&self.frame_in);
+        }
+
+        self.power.fill(0.0);
+        self.noise.fill(0.0);
+        self.prev_gain.fill(1.0);
+        self.prev_snr.fill(1.0);
+        self.primed = false;
+        if let Some(bridge) = &mut self.bridge {
+            bridge.reset();
+        }
+    }
+
+    /// Latency in host-rate samples
+    pub fn latency_samples(&self) -> usize {
+        // Framer delay plus the primed hop: one frame at the model rate
+        let model_latency = self.framer.latency_samples() + HOP;
+        match &self.bridge {
+            Some(b) => {
+                let scaled = model_latency as u64 * b.host_rate as u64;
+                scaled.div_ceil(sample_rates::ATENNUATE as u64) as usize + RateBridge::PRIME
+            }
+            None => model_latency,
+        }
+    }
+
+    /// Latency in milliseconds
+    pub fn latency_ms(&self) -> f64 {
+        self.latency_samples() as f64 / self.sample_rate() as f64 * 1000.0
+    }
+
+    /// Set enhancement strength (0.0 - 1.0)
+    pub fn set_strength(&mut self, strength: f32) {
+        self.strength = strength.clamp(0.0, 1.0);
+    }
+
+    /// Current strength
+    pub fn strength(&self) -> f32 {
+        self.strength
+    }
+
+    /// True when the ONNX model is loaded (false = state-space fallback)
+    pub fn has_model(&self) -> bool {
+        self.model.is_some()
+    }
+
+    /// Check if GPU accelerated
+    pub fn is_gpu_accelerated(&self) -> bool {
+        self.model.as_ref().is_some_and(|m| m.is_gpu_accelerated())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SR: f32 = 48000.0;
+
+    /// Deterministic white noise
+    fn noise(len: usize, amp: f32) -> Vec<f32> {
+        let mut state = 0x1234_5678u32;
+        (0..len)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                (state as f32 / u32::MAX as f32 * 2.0 - 1.0) * amp
+            })
+            .collect()
+    }
+
+    /// Voiced "speech": 150 Hz harmonic stack, gated into syllables
+    fn speech(len: usize) -> (Vec<f32>, Vec<bool>) {
+        let lead = (0.25 * SR) as usize;
+        let syllable = (0.2 * SR) as usize;
+        let gap = (0.15 * SR) as usize;
+        let mut voiced = vec![false; len];
+        let mut pos = lead;
+        while pos < len {
+            let end = (pos + syllable).min(len);
+            voiced[pos..end].fill(true);
+            pos = end + gap;
+        }
+
+        let signal = (0..len)
+            .map(|n| {
+                if !voiced[n] {
+                    return 0.0;
+                }
+                let t = n as f32 / SR;
+                (1..=20)
+                    .map(|h| {
+                        (2.0 * std::f32::consts::PI * 150.0 * h as f32 * t).sin() * 0.1 / h as f32
+                    })
+                    .sum()
+            })
+            .collect();
+        (signal, voiced)
+    }
+
+    /// Goertzel power summed over the speech harmonics
+    fn harmonic_energy(x: &[f32]) -> f32 {
+        (1..=20)
+            .map(|h| {
+                let w = 2.0 * std::f32::consts::PI * 150.0 * h as f32 / SR;
+                let coeff = 2.0 * w.cos();
+                let (mut s1, mut s2) = (0.0f32, 0.0f32);
+                for &v in x {
+                    let s0 = v + coeff * s1 - s2;
+                    s2 = s1;
+                    s1 = s0;
+                }
+                s1 * s1 + s2 * s2 - coeff * s1 * s2
+            })
+            .sum()
+    }
+
+    fn energy(x: &[f32]) -> f32 {
+        x.iter().map(|v| v * v).sum::<f32>()
+    }
+
+    /// Protobuf length-delimited field
+    fn pb_bytes(field: u32, payload: &[u8]) -> Vec<u8> {
+        let mut out = pb_varint(field << 3 | 2);
+        out.extend(pb_varint(payload.len() as u32));
+        out.extend_from_slice(payload);
+        out
+    }
+
+    /// Protobuf varint field
+    fn pb_int(field: u32, value: u32) -> Vec<u8> {
+        let mut out = pb_varint(field << 3);
+        out.extend(pb_varint(value));
+        out
+    }
+
+    fn pb_varint(mut value: u32) -> Vec<u8> {
+        let mut out = Vec::new();
+        while value >= 0x80 {
+            out.push(value as u8 | 0x80);
+            value >>= 7;
+        }
+        out.push(value as u8);
+        out
+    }
+
+    /// ONNX model passing the [1, BINS] log magnitude through as mask logits
+    fn write_identity_model() -> std::path::PathBuf {
+        let value_info = |name: &str| {
+            let dims = [
+                pb_bytes(1, &pb_int(1, 1)),
+                pb_bytes(1, &pb_int(1, BINS as u32)),
+            ]
+            .concat();
+            let tensor = [pb_int(1, 1), pb_bytes(2, &dims)].concat();
+            [
+                pb_bytes(1, name.as_bytes()),
+                pb_bytes(2, &pb_bytes(1, &tensor)),
+            ]
+            .concat()
+        };
+        let node = [
+            pb_bytes(1, b"log_mag"),
+            pb_bytes(2, b"mask_logits"),
+            pb_bytes(4, b"Identity"),
+        ]
+        .concat();
+        let graph = [
+            pb_bytes(1, &node),
+            pb_bytes(2, b"identity_mask"),
+            pb_bytes(11, &value_info("log_mag")),
+            pb_bytes(12, &value_info("mask_logits")),
+        ]
+        .concat();
+        let model = [
+            pb_int(1, 7),
+            pb_bytes(7, &graph),
+            pb_bytes(8, &pb_int(2, 13)),
+        ]
+        .concat();
+
+        let path =
+            std::env::temp_dir().join(format!("rf_ml_identity_mask_{}.onnx", std::process::id()));
+        std::fs::write(&path, model).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_missing_model_is_an_error() {
+        let result =
+            StreamingEnhancer::with_model("models/missing.onnx", InferenceConfig::default());
+        assert!(matches!(result, Err(MlError::ModelNotFound { .. })));
+
+        let fallback = StreamingEnhancer::state_space().unwrap();
+        assert!(!fallback.has_model());
+    }
+
+    #[test]
+    fn test_model_mask_is_applied() {
+        let path = write_identity_model();
+        let mut enhancer =
+            StreamingEnhancer::with_model(&path, InferenceConfig::default()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert!(enhancer.has_model());
+        enhancer.set_strength(1.0);
+
+        // sigmoid(ln|X|) = |X| / (1 + |X|): loud bins pass, quiet bins vanish.
+        // A steady tone is kept (the state-space tracker would learn it as noise),
+        // low-level hiss is pushed down.
+        let len = (2.0 * SR) as usize;
+        let tone: Vec<f32> = (0..len)
+            .map(|n| (2.0 * std::f32::consts::PI * 1500.0 * n as f32 / SR).sin() * 0.5)
+            .collect();
+        let hiss = noise(len, 0.001);
+
+        let run = |enhancer: &mut StreamingEnhancer, input: &[f32]| {
+            enhancer.reset();
+            let mut output = vec![0.0; input.len()];
+            for (i, o) in input.chunks(FRAME).zip(output.chunks_mut(FRAME)) {
+                enhancer.process(i, o).unwrap();
+            }
+            output
+        };
+
+        let d = enhancer.latency_samples();
+        let tone_out = run(&mut enhancer, &tone);
+        let tone_db =
+            10.0 * (energy(&tone_out[len / 2 + d..]) / energy(&tone[len / 2..len - d])).log10();
+        assert!(tone_db > -1.0, "tone lost {tone_db:.1} dB");
+
+        let hiss_out = run(&mut enhancer, &hiss);
+        let hiss_db =
+            10.0 * (energy(&hiss_out[len / 2 + d..]) / energy(&hiss[len / 2..len - d])).log10();
+        assert!(hiss_db < -20.0, "hiss only dropped {hiss_db:.1} dB");
+    }
+
+    #[test]
+    fn test_latency_is_one_frame() {
+        let enhancer = StreamingEnhancer::state_space().unwrap();
+        assert_eq!(enhancer.latency_samples(), 256);
+        assert!((enhancer.latency_ms() - 5.33).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_unity_strength_zero_is_delayed_passthrough() {
+        let mut enhancer = StreamingEnhancer::state_space().unwrap();
+        enhancer.set_strength(0.0);
+
+        let input = noise(4096, 0.5);
+        let mut output = vec![0.0; input.len()];
+        // Odd block sizes exercise the frame buffering
+        for (i, o) in input.chunks(77).zip(output.chunks_mut(77)) {
+            enhancer.process(i, o).unwrap();
+        }
+
+        let d = enhancer.latency_samples();
+        for n in FRAME..input.len() - d {
+            assert!((output[n + d] - input[n]).abs() < 1e-4, "sample {n}");
+        }
+    }
+
+    #[test]
+    fn test_noisy_speech_noise_floor_drops_speech_retained() {
+        let len = (2.0 * SR) as usize;
+        let (clean, voiced) = speech(len);
+        let hiss = noise(len, 0.03);
+        let noisy: Vec<f32> = clean.iter().zip(&hiss).map(|(s, n)| s + n).collect();
+
+        let mut enhancer = StreamingEnhancer::state_space().unwrap();
+        enhancer.set_strength(1.0);
+        let mut output = vec![0.0; len];
+        for (i, o) in noisy.chunks(FRAME).zip(output.chunks_mut(FRAME)) {
+            enhancer.process(i, o).unwrap();
+        }
+
+        // Re-align output with input
+        let d = enhancer.latency_samples();
+        let aligned = &output[d..];
+
+        // Compare the second half, after the noise tracker has settled
+        let start = len / 2;
+        let end = len - d;
+        let (mut in_gap, mut out_gap) = (Vec::new(), Vec::new());
+        let (mut in_voice, mut out_voice) = (Vec::new(), Vec::new());
+        for n in start..end {
+            // Skip syllable edges (mask transitions)
+            let edge =
+                (n.saturating_sub(FRAME)..(n + FRAME).min(len)).any(|m| voiced[m] != voiced[n]);
+            if edge {
+                continue;
+            }
+            if voiced[n] {
+                in_voice.push(noisy[n]);
+                out_voice.push(aligned[n]);
+            } else {
+                in_gap.push(noisy[n]);
+                out_gap.push(aligned[n]);
+            }
+        }
+
+        let floor_drop_db = 10.0 * (energy(&out_gap) / energy(&in_gap)).log10();
+        assert!(
+            floor_drop_db < -10.0,
+            "noise floor only dropped {floor_drop_db:.1} dB"
+        );
+
+        let speech_db = 10.0 * (harmonic_energy(&out_voice) / harmonic_energy(&in_voice)).log10();
+        assert!(speech_db > -2.0, "speech band lost {speech_db:.1} dB");
+    }
+
+    #[test]
+    fn test_host_rate_resampling() {
+        let mut enhancer = StreamingEnhancer::state_space().unwrap();
+        enhancer.set_sample_rate(44100).unwrap();
+        enhancer.set_strength(0.0);
+        assert_eq!(enhancer.sample_rate(), 44100);
+        assert!(enhancer.latency_ms() < 6.0);
+
+        // 1 kHz tone survives the 44.1k → 48k → 44.1k round trip
+        let input: Vec<f32> = (0..8820)
+            .map(|n| (2.0 * std::f32::consts::PI * 1000.0 * n as f32 / 44100.0).sin() * 0.5)
+            .collect();
+        let mut output = vec![0.0; input.len()];
+        for (i, o) in input.chunks(441).zip(output.chunks_mut(441)) {
+            enhancer.process(i, o).unwrap();
+        }
+
+        let tail = &output[4410..];
+        let peak = tail.iter().fold(0.0f32, |m, v| m.max(v.abs()));
+        assert!((peak - 0.5).abs() < 0.02, "peak {peak}");
+    }
+
+    #[test]
+    fn test_reset_clears_output() {
+        let mut enhancer = StreamingEnhancer::state_space().unwrap();
+        let input = noise(1024, 0.5);
+        let mut output = vec![0.0; 1024];
+        enhancer.process(&input, &mut output).unwrap();
+
+        enhancer.reset();
+        let silence = vec![0.0; 512];
+        let mut out = vec![1.0; 512];
+        enhancer.process(&silence, &mut out).unwrap();
+        assert!(out.iter().all(|&v| v == 0.0));
+    }
+}
@@ -42,7 +42,7 @@ mod buffer;
 mod error;
 mod inference;
 
-pub use buffer::{AudioFrame, FrameBuffer};
+pub use buffer::{AudioFrame, FrameBuffer, FramerWindow, OverlapAddFramer};
 pub use error::{MlError, MlResult};
 pub use inference::{ExecutionProvider, InferenceConfig, InferenceEngine};
 
@@ -68,6 +68,9 @@ pub mod models {
 
     /// Multi-pitch estimator
     pub const PITCH_ESTIMATOR: &str = "models/pitch_estimator.onnx";
+
+    /// Spectral-transfer EQ matching model
+    pub const SPECTRAL_TRANSFER: &str = "models/spectral_transfer.onnx";
 }
 
 /// Sample rates commonly used by ML models
@@ -80,6 +83,9 @@ pub mod sample_rates {
 
     /// aTENNuate sample rate
     pub const ATENNUATE: u32 = 48000;
+
+    /// Spectral-transfer EQ matching sample rate
+    pub const SPECTRAL_TRANSFER: u32 = 48000;
 }
 
 /// Frame sizes for streaming processing
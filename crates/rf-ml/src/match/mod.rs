@@ -18,15 +18,20 @@
 //!
 //! // Get EQ curve to match target to reference
 //! let eq_curve = matcher.compute_match(&target_audio)?;
+//!
+//! // Or one-shot: (freq, gain_db) transfer curve, neural model or DSP fallback
+//! let curve = rf_ml::r#match::match_eq(&source, &reference, &InferenceConfig::default())?;
 //! ```
 
 mod config;
 mod curve;
 mod spectral;
+mod transfer;
 
 pub use config::{MatchConfig, MatchMode, MatchWeighting};
 pub use curve::{EqCurve, FrequencyBand};
 pub use spectral::SpectralMatcher;
+pub use transfer::{TRANSFER_BANDS, match_eq};
 
 use crate::error::MlResult;
 
@@ -149,7 +149,7 @@ impl SpectralMatcher {
     }
 
     /// Compute averaged spectrum from audio
-    fn compute_spectrum(
+    pub(super) fn compute_spectrum(
         &self,
         audio: &[f32],
         channels: usize,
@@ -276,7 +276,7 @@ impl SpectralMatcher {
     }
 
     /// Convert frequency bins to EQ bands (log-spaced)
-    fn bins_to_bands(&self, diff: &[f32], freq_resolution: f32) -> Vec<FrequencyBand> {
+    pub(super) fn bins_to_bands(&self, diff: &[f32], freq_resolution: f32) -> Vec<FrequencyBand> {
         let num_bands = self.config.num_bands;
         let min_freq = self.config.min_freq;
         let max_freq = self.config.max_freq;
@@ -6,6 +6,7 @@
 //! - MP3, OGG Vorbis, AAC (lossy)
 
 use crate::error::{OfflineError, OfflineResult};
+use crate::metadata::{Metadata, REPLAYGAIN_REFERENCE_LUFS};
 use crate::pipeline::AudioBuffer;
 
 use std::fs::File;
@@ -15,7 +16,7 @@ use symphonia::core::audio::{AudioBufferRef, Signal};
 use symphonia::core::codecs::DecoderOptions;
 use symphonia::core::formats::FormatOptions;
 use symphonia::core::io::MediaSourceStream;
-use symphonia::core::meta::MetadataOptions;
+use symphonia::core::meta::{MetadataOptions, StandardTagKey, Tag};
 use symphonia::core::probe::Hint;
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -28,6 +29,43 @@ pub struct AudioDecoder;
 impl AudioDecoder {
     /// Decode audio file to AudioBuffer
     pub fn decode(path: &Path) -> OfflineResult<AudioBuffer> {
+        Self::decode_with(path, false)
+    }
+
+    /// Decode files back to back into one continuous buffer (album bounce)
+    ///
+    /// Encoder delay and padding are stripped from every file so track joins
+    /// are sample-accurate. All files must share sample rate and channel count.
+    pub fn decode_playlist(paths: &[&Path]) -> OfflineResult<AudioBuffer> {
+        let (first, rest) = paths
+            .split_first()
+            .ok_or_else(|| OfflineError::InvalidConfig("Empty playlist".to_string()))?;
+
+        let mut output = Self::decode_with(first, true)?;
+        for path in rest {
+            let track = Self::decode_with(path, true)?;
+            if track.channels != output.channels {
+                return Err(OfflineError::ChannelMismatch {
+                    expected: output.channels,
+                    actual: track.channels,
+                });
+            }
+            if track.sample_rate != output.sample_rate {
+                return Err(OfflineError::InvalidConfig(format!(
+                    "Sample rate mismatch in playlist: {} is {} Hz, expected {} Hz",
+                    path.display(),
+                    track.sample_rate,
+                    output.sample_rate
+                )));
+            }
+            output.samples.extend_from_slice(&track.samples);
+        }
+
+        Ok(output)
+    }
+
+    /// Decode, optionally stripping encoder delay/padding
+    fn decode_with(path: &Path, gapless: bool) -> OfflineResult<AudioBuffer> {
         // Create media source stream
         let file = File::open(path)
             .map_err(|e| OfflineError::ReadError(format!("Failed to open file: {}", e)))?;
@@ -45,7 +83,10 @@ impl AudioDecoder {
             .format(
                 &hint,
                 mss,
-                &FormatOptions::default(),
+                &FormatOptions {
+                    enable_gapless: gapless,
+                    ..Default::default()
+                },
                 &MetadataOptions::default(),
             )
             .map_err(|e| OfflineError::ReadError(format!("Failed to probe format: {}", e)))?;
@@ -73,6 +114,8 @@ impl AudioDecoder {
 
         // Decode all packets
         let mut samples: Vec<f64> = Vec::new();
+        // Whether the demuxer trimmed packets itself (MP3 LAME/Xing, Ogg granules)
+        let mut demuxer_trimmed = false;
 
         loop {
             match format.next_packet() {
@@ -80,6 +123,7 @@ impl AudioDecoder {
                     if packet.track_id() != track_id {
                         continue;
                     }
+                    demuxer_trimmed |= packet.trim_start() > 0 || packet.trim_end() > 0;
 
                     match decoder.decode(&packet) {
                         Ok(decoded) => {
@@ -106,6 +150,16 @@ impl AudioDecoder {
             }
         }
 
+        // Otherwise strip priming/padding signalled in the codec parameters
+        if gapless && !demuxer_trimmed {
+            let total = samples.len();
+            let delay = (codec_params.delay.unwrap_or(0) as usize * channels).min(total);
+            let padding =
+                (codec_params.padding.unwrap_or(0) as usize * channels).min(total - delay);
+            samples.truncate(total - padding);
+            samples.drain(..delay);
+        }
+
         Ok(AudioBuffer {
             samples,
             channels,
@@ -287,6 +341,89 @@ impl AudioDecoder {
         })
     }
 
+    /// Read title/artist/loudness tags without decoding audio
+    ///
+    /// Container tags (Vorbis comments, RIFF INFO) take precedence over
+    /// tags found ahead of the stream (ID3v2).
+    pub fn read_metadata(path: &Path) -> OfflineResult<Metadata> {
+        let file = File::open(path)
+            .map_err(|e| OfflineError::ReadError(format!("Failed to open file: {}", e)))?;
+
+        let mss = MediaSourceStream::new(Box::new(file), Default::default());
+
+        let mut hint = Hint::new();
+        if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+            hint.with_extension(ext);
+        }
+
+        let mut probed = symphonia::default::get_probe()
+            .format(
+                &hint,
+                mss,
+                &FormatOptions::default(),
+                &MetadataOptions::default(),
+            )
+            .map_err(|e| OfflineError::ReadError(format!("Failed to probe format: {}", e)))?;
+
+        let mut metadata = Metadata::new();
+        if let Some(revision) = probed.metadata.get().as_ref().and_then(|m| m.current()) {
+            Self::apply_tags(revision.tags(), &mut metadata);
+        }
+        if let Some(revision) = probed.format.metadata().current() {
+            Self::apply_tags(revision.tags(), &mut metadata);
+        }
+
+        Ok(metadata)
+    }
+
+    /// Map symphonia standard tags onto metadata fields
+    fn apply_tags(tags: &[Tag], metadata: &mut Metadata) {
+        for tag in tags {
+            let Some(key) = tag.std_key else {
+                continue;
+            };
+            let value = tag.value.to_string();
+
+            match key {
+                StandardTagKey::TrackTitle => metadata.title = Some(value),
+                StandardTagKey::Artist => metadata.artist = Some(value),
+                StandardTagKey::Album => metadata.album = Some(value),
+                StandardTagKey::Genre => metadata.genre = Some(value),
+                StandardTagKey::Date => metadata.date = Some(value),
+                StandardTagKey::Comment => metadata.comment = Some(value),
+                StandardTagKey::TrackNumber => {
+                    // "3" or "3/12"
+                    metadata.track_number =
+                        value.split('/').next().and_then(|n| n.trim().parse().ok());
+                }
+                StandardTagKey::ReplayGainTrackGain => {
+                    if let Some(gain) = Self::parse_db(&value) {
+                        metadata.loudness_lufs = Some(REPLAYGAIN_REFERENCE_LUFS - gain);
+                    }
+                }
+                StandardTagKey::ReplayGainTrackPeak => {
+                    if let Ok(peak) = value.trim().parse::<f64>()
+                        && peak > 0.0
+                    {
+                        metadata.true_peak_dbtp = Some(20.0 * peak.log10());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Parse "-4.00 dB" style values
+    fn parse_db(value: &str) -> Option<f64> {
+        value
+            .trim()
+            .trim_end_matches("dB")
+            .trim_end_matches("db")
+            .trim()
+            .parse()
+            .ok()
+    }
+
     /// Get list of supported formats
     pub fn supported_formats() -> &'static [&'static str] {
         &["wav", "flac", "mp3", "ogg", "aac", "m4a", "aiff"]
@@ -348,4 +485,60 @@ mod tests {
 
         assert_eq!(info.duration_str(), "1:05.500");
     }
+
+    #[test]
+    fn test_decode_playlist_joins_without_gap() {
+        let sample_rate = 44100;
+        let freq = 441.0;
+        let frames = 10_000; // Not a whole number of cycles
+        let dir = std::env::temp_dir();
+        let paths: Vec<std::path::PathBuf> = (0..2)
+            .map(|n| {
+                dir.join(format!(
+                    "rf_offline_playlist_{}_{}.wav",
+                    std::process::id(),
+                    n
+                ))
+            })
+            .collect();
+
+        // Second file continues the phase of the first
+        for (n, path) in paths.iter().enumerate() {
+            let spec = hound::WavSpec {
+                channels: 2,
+                sample_rate,
+                bits_per_sample: 32,
+                sample_format: hound::SampleFormat::Float,
+            };
+            let mut writer = hound::WavWriter::create(path, spec).unwrap();
+            for i in 0..frames {
+                let t = (n * frames + i) as f64 / sample_rate as f64;
+                let x = (2.0 * std::f64::consts::PI * freq * t).sin() as f32 * 0.5;
+                writer.write_sample(x).unwrap();
+                writer.write_sample(x).unwrap();
+            }
+            writer.finalize().unwrap();
+        }
+
+        let refs: Vec<&Path> = paths.iter().map(|p| p.as_path()).collect();
+        let result = AudioDecoder::decode_playlist(&refs);
+        for path in &paths {
+            let _ = std::fs::remove_file(path);
+        }
+        let buffer = result.unwrap();
+
+        assert_eq!(buffer.channels, 2);
+        assert_eq!(buffer.samples.len(), 2 * frames * 2);
+
+        // No step larger than the sine's own slope anywhere, including the join
+        let max_step = 0.5 * 2.0 * std::f64::consts::PI * freq / sample_rate as f64;
+        let left: Vec<f64> = buffer.samples.iter().step_by(2).copied().collect();
+        for (i, pair) in left.windows(2).enumerate() {
+            assert!(
+                (pair[1] - pair[0]).abs() <= max_step * 1.001,
+                "discontinuity at frame {}",
+                i + 1
+            );
+        }
+    }
 }
@@ -8,6 +8,9 @@
 //! - OGG (via vorbis-encoder) — native libvorbis, no external dependencies!
 //! - Opus (via audiopus + ogg) — native libopus, no external dependencies!
 //! - AAC (via FFmpeg fallback when available)
+//!
+//! Tags (see [`Metadata`]) are written as ID3v2 for MP3, Vorbis comments for
+//! FLAC/OGG/Opus and bext/iXML chunks for WAV.
 
 use crate::config::DitheringMode;
 use crate::error::{OfflineError, OfflineResult};
@@ -15,6 +18,7 @@ use crate::formats::{
     AacConfig, AiffConfig, FlacConfig, Mp3Bitrate, Mp3Config, OggConfig, OpusConfig, OutputFormat,
     WavConfig,
 };
+use crate::metadata::{self, Metadata};
 use crate::pipeline::AudioBuffer;
 
 use std::io::Cursor;
@@ -29,6 +33,18 @@ pub trait AudioEncoder {
     /// Encode audio buffer to bytes
     fn encode(&self, buffer: &AudioBuffer) -> OfflineResult<Vec<u8>>;
 
+    /// Encode audio buffer with embedded tags
+    ///
+    /// Formats without tag support ignore the metadata.
+    fn encode_with_metadata(
+        &self,
+        buffer: &AudioBuffer,
+        metadata: &Metadata,
+    ) -> OfflineResult<Vec<u8>> {
+        let _ = metadata;
+        self.encode(buffer)
+    }
+
     /// Get file extension
     fn extension(&self) -> &'static str;
 }
@@ -136,6 +152,14 @@ impl AudioEncoder for WavEncoder {
         Ok(output)
     }
 
+    fn encode_with_metadata(
+        &self,
+        buffer: &AudioBuffer,
+        metadata: &Metadata,
+    ) -> OfflineResult<Vec<u8>> {
+        metadata::wav_with_tags(self.encode(buffer)?, metadata)
+    }
+
     fn extension(&self) -> &'static str {
         "wav"
     }
@@ -221,6 +245,18 @@ impl AudioEncoder for FlacEncoder {
         Ok(output)
     }
 
+    fn encode_with_metadata(
+        &self,
+        buffer: &AudioBuffer,
+        metadata: &Metadata,
+    ) -> OfflineResult<Vec<u8>> {
+        let flac = self.encode(buffer)?;
+        if metadata.is_empty() {
+            return Ok(flac);
+        }
+        metadata::flac_with_tags(flac, metadata)
+    }
+
     fn extension(&self) -> &'static str {
         "flac"
     }
@@ -522,6 +558,16 @@ impl AudioEncoder for LameMp3Encoder {
         Ok(mp3_output)
     }
 
+    fn encode_with_metadata(
+        &self,
+        buffer: &AudioBuffer,
+        metadata: &Metadata,
+    ) -> OfflineResult<Vec<u8>> {
+        let mut mp3 = metadata::id3v2_tag(metadata);
+        mp3.extend(self.encode(buffer)?);
+        Ok(mp3)
+    }
+
     fn extension(&self) -> &'static str {
         "mp3"
     }
@@ -606,6 +652,16 @@ impl AudioEncoder for FfmpegMp3Encoder {
         Ok(mp3_data)
     }
 
+    fn encode_with_metadata(
+        &self,
+        buffer: &AudioBuffer,
+        metadata: &Metadata,
+    ) -> OfflineResult<Vec<u8>> {
+        let mut mp3 = metadata::id3v2_tag(metadata);
+        mp3.extend(self.encode(buffer)?);
+        Ok(mp3)
+    }
+
     fn extension(&self) -> &'static str {
         "mp3"
     }
@@ -674,6 +730,18 @@ impl AudioEncoder for NativeOggEncoder {
         Ok(ogg_data)
     }
 
+    fn encode_with_metadata(
+        &self,
+        buffer: &AudioBuffer,
+        metadata: &Metadata,
+    ) -> OfflineResult<Vec<u8>> {
+        let ogg = self.encode(buffer)?;
+        if metadata.is_empty() {
+            return Ok(ogg);
+        }
+        metadata::ogg_vorbis_with_tags(ogg, metadata)
+    }
+
     fn extension(&self) -> &'static str {
         "ogg"
     }
@@ -739,6 +807,25 @@ impl NativeOpusEncoder {
 
 impl AudioEncoder for NativeOpusEncoder {
     fn encode(&self, buffer: &AudioBuffer) -> OfflineResult<Vec<u8>> {
+        self.encode_ogg(buffer, Self::create_opus_tags())
+    }
+
+    fn encode_with_metadata(
+        &self,
+        buffer: &AudioBuffer,
+        metadata: &Metadata,
+    ) -> OfflineResult<Vec<u8>> {
+        self.encode_ogg(buffer, metadata::opus_tags(metadata))
+    }
+
+    fn extension(&self) -> &'static str {
+        "opus"
+    }
+}
+
+impl NativeOpusEncoder {
+    /// Encode to an OGG Opus stream with the given OpusTags packet
+    fn encode_ogg(&self, buffer: &AudioBuffer, opus_tags: Vec<u8>) -> OfflineResult<Vec<u8>> {
         use audiopus::coder::Encoder as OpusEnc;
         use audiopus::{Application, Channels, SampleRate};
         use ogg::writing::PacketWriter;
@@ -804,7 +891,6 @@ impl AudioEncoder for NativeOpusEncoder {
             })?;
 
         // Write Opus comment header (OpusTags)
-        let opus_tags = Self::create_opus_tags();
         packet_writer
             .write_packet(
                 opus_tags,
@@ -877,12 +963,6 @@ impl AudioEncoder for NativeOpusEncoder {
         Ok(ogg_data)
     }
 
-    fn extension(&self) -> &'static str {
-        "opus"
-    }
-}
-
-impl NativeOpusEncoder {
     /// Create OpusHead identification header
     /// RFC 7845 Section 5.1
     fn create_opus_head(channels: u8, sample_rate: u32) -> Vec<u8> {
@@ -991,6 +1071,18 @@ impl AudioEncoder for FfmpegOggEncoder {
         Ok(ogg_data)
     }
 
+    fn encode_with_metadata(
+        &self,
+        buffer: &AudioBuffer,
+        metadata: &Metadata,
+    ) -> OfflineResult<Vec<u8>> {
+        let ogg = self.encode(buffer)?;
+        if metadata.is_empty() {
+            return Ok(ogg);
+        }
+        metadata::ogg_vorbis_with_tags(ogg, metadata)
+    }
+
     fn extension(&self) -> &'static str {
         "ogg"
     }
@@ -1241,6 +1333,85 @@ mod tests {
         assert_eq!(&data[0..4], b"RIFF");
     }
 
+    #[test]
+    fn test_flac_metadata_round_trip() {
+        let buffer = AudioBuffer {
+            samples: (0..8820).map(|i| (i as f64 * 0.05).sin() * 0.5).collect(),
+            channels: 2,
+            sample_rate: 44100,
+        };
+        let metadata = Metadata::new()
+            .with_title("Big Win")
+            .with_artist("VanVinkl")
+            .with_loudness(-14.0, -1.0);
+
+        let encoder = FlacEncoder::new(FlacConfig::default());
+        let data = encoder.encode_with_metadata(&buffer, &metadata).unwrap();
+        assert_eq!(&data[0..4], b"fLaC");
+
+        let path = std::env::temp_dir().join(format!("rf_offline_tags_{}.flac", std::process::id()));
+        std::fs::write(&path, &data).unwrap();
+        let reloaded = crate::decoder::AudioDecoder::read_metadata(&path);
+        let decoded = crate::decoder::AudioDecoder::decode(&path);
+        let _ = std::fs::remove_file(&path);
+
+        let reloaded = reloaded.unwrap();
+        assert_eq!(reloaded.title.as_deref(), Some("Big Win"));
+        assert_eq!(reloaded.artist.as_deref(), Some("VanVinkl"));
+        assert!(reloaded.album.is_none());
+        assert!((reloaded.loudness_lufs.unwrap() - (-14.0)).abs() < 0.01);
+
+        // Tags must not disturb the audio frames
+        assert_eq!(decoded.unwrap().samples.len(), buffer.samples.len());
+    }
+
+    #[test]
+    fn test_ogg_metadata_round_trip() {
+        let buffer = AudioBuffer {
+            samples: vec![0.25; 8820],
+            channels: 2,
+            sample_rate: 44100,
+        };
+        let metadata = Metadata::new().with_title("Free Spins").with_artist("VanVinkl");
+
+        let encoder = NativeOggEncoder::new(OggConfig { quality: 5.0 });
+        let data = encoder.encode_with_metadata(&buffer, &metadata).unwrap();
+
+        let path = std::env::temp_dir().join(format!("rf_offline_tags_{}.ogg", std::process::id()));
+        std::fs::write(&path, &data).unwrap();
+        let reloaded = crate::decoder::AudioDecoder::read_metadata(&path);
+        let decoded = crate::decoder::AudioDecoder::decode(&path);
+        let _ = std::fs::remove_file(&path);
+
+        let reloaded = reloaded.unwrap();
+        assert_eq!(reloaded.title.as_deref(), Some("Free Spins"));
+        assert_eq!(reloaded.artist.as_deref(), Some("VanVinkl"));
+        assert!(!decoded.unwrap().samples.is_empty());
+    }
+
+    #[test]
+    fn test_wav_metadata_chunks() {
+        let buffer = AudioBuffer {
+            samples: vec![0.5, -0.5, 0.25, -0.25],
+            channels: 2,
+            sample_rate: 44100,
+        };
+        let encoder = WavEncoder::new(WavConfig::default());
+
+        // Dither makes sample bytes differ run to run; compare layout only
+        let plain = encoder.encode(&buffer).unwrap();
+        let untagged = encoder.encode_with_metadata(&buffer, &Metadata::new()).unwrap();
+        assert_eq!(plain.len(), untagged.len());
+
+        let tagged = encoder
+            .encode_with_metadata(&buffer, &Metadata::new().with_title("Reel Stop"))
+            .unwrap();
+        assert!(tagged.windows(4).any(|w| w == b"bext"));
+        assert!(tagged.windows(4).any(|w| w == b"iXML"));
+        let riff_size = u32::from_le_bytes([tagged[4], tagged[5], tagged[6], tagged[7]]);
+        assert_eq!(riff_size as usize, tagged.len() - 8);
+    }
+
     #[test]
     fn test_available_encoders() {
         let encoders = available_encoders();
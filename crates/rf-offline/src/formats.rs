@@ -53,6 +53,40 @@ impl OutputFormat {
         matches!(self, Self::Wav(_) | Self::Aiff(_) | Self::Flac(_))
     }
 
+    /// Estimated encoded size in bytes
+    ///
+    /// Exact (plus header) for WAV/AIFF; FLAC assumes a typical 60% ratio and
+    /// lossy formats use their nominal bitrate. Metadata tags are not counted.
+    pub fn estimated_size(&self, frames: u64, channels: usize, sample_rate: u32) -> u64 {
+        let pcm = |bit_depth: u8| frames * channels as u64 * u64::from(bit_depth).div_ceil(8);
+        let at_kbps = |kbps: f64| {
+            let seconds = frames as f64 / sample_rate.max(1) as f64;
+            (kbps * 1000.0 / 8.0 * seconds) as u64
+        };
+
+        match self {
+            Self::Wav(config) => 44 + pcm(config.bit_depth),
+            Self::Aiff(config) => 54 + pcm(config.bit_depth),
+            Self::Flac(config) => pcm(config.bit_depth) * 3 / 5,
+            Self::Mp3(config) => at_kbps(match config.bitrate {
+                Mp3Bitrate::Cbr(kbps) | Mp3Bitrate::Abr(kbps) => kbps as f64,
+                Mp3Bitrate::Vbr(quality) => MP3_VBR_KBPS[quality.min(9) as usize],
+            }),
+            Self::Ogg(config) => {
+                // Nominal bitrate per quality step, starting at q -1
+                let pos = (config.quality.clamp(-1.0, 10.0) + 1.0) as f64;
+                let idx = (pos as usize).min(OGG_QUALITY_KBPS.len() - 2);
+                let frac = pos - idx as f64;
+                at_kbps(
+                    OGG_QUALITY_KBPS[idx]
+                        + frac * (OGG_QUALITY_KBPS[idx + 1] - OGG_QUALITY_KBPS[idx]),
+                )
+            }
+            Self::Opus(config) => at_kbps(config.bitrate as f64),
+            Self::Aac(config) => at_kbps(config.bitrate as f64),
+        }
+    }
+
     /// Create WAV 16-bit format
     pub fn wav_16() -> Self {
         Self::Wav(WavConfig {
@@ -174,6 +208,16 @@ impl OutputFormat {
     }
 }
 
+/// Average MP3 bitrate (kbps) for VBR quality 0-9 (LAME -V presets)
+const MP3_VBR_KBPS: [f64; 10] = [
+    245.0, 225.0, 190.0, 175.0, 165.0, 130.0, 115.0, 100.0, 85.0, 65.0,
+];
+
+/// Nominal Vorbis bitrate (kbps) for quality -1 to 10 (stereo 44.1kHz)
+const OGG_QUALITY_KBPS: [f64; 12] = [
+    45.0, 64.0, 80.0, 96.0, 112.0, 128.0, 160.0, 192.0, 224.0, 256.0, 320.0, 500.0,
+];
+
 /// WAV configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WavConfig {
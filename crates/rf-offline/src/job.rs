@@ -6,8 +6,11 @@ use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
+use parking_lot::Mutex;
+use rf_dsp::StereoProcessor;
+
 use super::config::SrcQuality;
-use super::processors::ProcessorChain;
+use super::processors::{DspProcessor, ProcessorChain};
 use super::{NormalizationMode, OfflineError, OfflineResult, OutputFormat};
 
 /// Unique job identifier
@@ -52,9 +55,9 @@ pub struct OfflineJob {
     /// Normalization mode
     pub normalization: Option<NormalizationMode>,
 
-    /// Processing chain (effects)
+    /// Processing chain (effects); replaces the pipeline's chain when set
     #[serde(skip)]
-    pub processors: Option<ProcessorChain>,
+    pub processors: Option<Mutex<ProcessorChain>>,
 
     /// Time range (start, end) in samples (None = entire file)
     pub range: Option<(u64, u64)>,
@@ -95,6 +98,16 @@ impl OfflineJob {
         JobBuilder::new()
     }
 
+    /// Append an rf-dsp processor to the job's chain
+    ///
+    /// Processors run in the order added, in place of the pipeline's chain.
+    pub fn add_processor(&mut self, processor: Box<dyn StereoProcessor>) {
+        self.processors
+            .get_or_insert_with(Default::default)
+            .get_mut()
+            .push(Box::new(DspProcessor::new(processor)));
+    }
+
     /// Validate job configuration
     pub fn validate(&self) -> OfflineResult<()> {
         if !self.input_path.exists() {
@@ -254,7 +267,7 @@ impl JobBuilder {
             sample_rate: self.sample_rate,
             src_quality: self.src_quality,
             normalization: self.normalization,
-            processors: self.processors,
+            processors: self.processors.map(Mutex::new),
             range: self.range,
             fade_in: self.fade_in,
             fade_out: self.fade_out,
@@ -355,6 +368,55 @@ impl JobProgress {
     }
 }
 
+/// Dry-run plan for a job: what would be rendered, without rendering it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JobPlan {
+    /// Job ID
+    pub job_id: JobId,
+    /// Input file path
+    pub input_path: PathBuf,
+    /// Output file path
+    pub output_path: PathBuf,
+    /// Probed input format (e.g. "WAV")
+    pub input_format: String,
+    /// Input sample rate
+    pub input_sample_rate: u32,
+    /// Input channel count
+    pub input_channels: usize,
+    /// Output sample rate
+    pub output_sample_rate: u32,
+    /// Output channel count
+    pub output_channels: usize,
+    /// Estimated output length (frames)
+    pub output_frames: u64,
+    /// Estimated output duration (seconds)
+    pub output_duration: f64,
+    /// Estimated output file size in bytes
+    pub estimated_size: u64,
+    /// Processing stages in execution order
+    pub chain: Vec<String>,
+    /// Why the job would fail (None = ready to run)
+    pub error: Option<String>,
+}
+
+impl JobPlan {
+    /// Plan for a job that cannot run
+    pub fn failure(job: &OfflineJob, error: String) -> Self {
+        Self {
+            job_id: job.id,
+            input_path: job.input_path.clone(),
+            output_path: job.output_path.clone(),
+            error: Some(error),
+            ..Default::default()
+        }
+    }
+
+    /// Whether the job passed the dry run
+    pub fn is_ready(&self) -> bool {
+        self.error.is_none()
+    }
+}
+
 /// Job completion result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct JobResult {
@@ -48,6 +48,7 @@ mod encoder;
 mod error;
 mod formats;
 mod job;
+mod metadata;
 mod normalize;
 mod pipeline;
 mod processors;
@@ -59,6 +60,7 @@ pub use encoder::*;
 pub use error::*;
 pub use formats::*;
 pub use job::*;
+pub use metadata::*;
 pub use normalize::*;
 pub use pipeline::*;
 pub use processors::*;
@@ -0,0 +1,522 @@
+//! Tag and broadcast metadata for encoded output
+//!
+//! Writes:
+//! - ID3v2.4 (MP3)
+//! - Vorbis comments (FLAC, OGG Vorbis, Opus)
+//! - bext (EBU Tech 3285 v2) and iXML chunks (WAV)
+//!
+//! Fields left as `None` are omitted from the output — never written empty.
+
+use std::io::Cursor;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{OfflineError, OfflineResult};
+
+/// ReplayGain 2.0 reference loudness (LUFS)
+pub const REPLAYGAIN_REFERENCE_LUFS: f64 = -18.0;
+
+/// Opus R128 reference loudness (LUFS, RFC 7845)
+pub const R128_REFERENCE_LUFS: f64 = -23.0;
+
+/// Vendor string written into Vorbis comment headers
+const VENDOR: &str = "FluxForge rf-offline";
+
+/// Descriptive and loudness metadata embedded at encode time
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    /// Track title
+    pub title: Option<String>,
+    /// Artist
+    pub artist: Option<String>,
+    /// Album / project
+    pub album: Option<String>,
+    /// Genre
+    pub genre: Option<String>,
+    /// Date (ISO 8601, e.g. "2026-10-16")
+    pub date: Option<String>,
+    /// Track number
+    pub track_number: Option<u32>,
+    /// Free-form comment / description
+    pub comment: Option<String>,
+    /// Broadcast originator (bext), falls back to artist
+    pub originator: Option<String>,
+    /// Integrated loudness (LUFS)
+    pub loudness_lufs: Option<f64>,
+    /// Maximum true peak (dBTP)
+    pub true_peak_dbtp: Option<f64>,
+}
+
+impl Metadata {
+    /// Create empty metadata
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set title (builder pattern)
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set artist (builder pattern)
+    pub fn with_artist(mut self, artist: impl Into<String>) -> Self {
+        self.artist = Some(artist.into());
+        self
+    }
+
+    /// Set album (builder pattern)
+    pub fn with_album(mut self, album: impl Into<String>) -> Self {
+        self.album = Some(album.into());
+        self
+    }
+
+    /// Set measured loudness (builder pattern)
+    pub fn with_loudness(mut self, loudness_lufs: f64, true_peak_dbtp: f64) -> Self {
+        self.loudness_lufs = loudness_lufs.is_finite().then_some(loudness_lufs);
+        self.true_peak_dbtp = true_peak_dbtp.is_finite().then_some(true_peak_dbtp);
+        self
+    }
+
+    /// Check if no field is set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// ReplayGain 2.0 track gain in dB (relative to -18 LUFS)
+    pub fn replaygain_track_gain(&self) -> Option<f64> {
+        self.loudness_lufs.map(|lufs| REPLAYGAIN_REFERENCE_LUFS - lufs)
+    }
+
+    /// ReplayGain track peak (linear, from true peak)
+    pub fn replaygain_track_peak(&self) -> Option<f64> {
+        self.true_peak_dbtp.map(|db| 10.0_f64.powf(db / 20.0))
+    }
+
+    /// Present text fields as (Vorbis comment key, value), empty strings skipped
+    fn text_fields(&self) -> Vec<(&'static str, String)> {
+        let mut fields = Vec::new();
+        let mut push = |key: &'static str, value: Option<String>| {
+            if let Some(value) = value.filter(|v| !v.is_empty()) {
+                fields.push((key, value));
+            }
+        };
+
+        push("TITLE", self.title.clone());
+        push("ARTIST", self.artist.clone());
+        push("ALBUM", self.album.clone());
+        push("GENRE", self.genre.clone());
+        push("DATE", self.date.clone());
+        push("TRACKNUMBER", self.track_number.map(|n| n.to_string()));
+        push("COMMENT", self.comment.clone());
+        fields
+    }
+
+    /// ReplayGain fields as (key, value)
+    fn replaygain_fields(&self) -> Vec<(&'static str, String)> {
+        let mut fields = Vec::new();
+        if let Some(gain) = self.replaygain_track_gain() {
+            fields.push(("REPLAYGAIN_TRACK_GAIN", format!("{:+.2} dB", gain)));
+        }
+        if let Some(peak) = self.replaygain_track_peak() {
+            fields.push(("REPLAYGAIN_TRACK_PEAK", format!("{:.6}", peak)));
+        }
+        fields
+    }
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// VORBIS COMMENTS (FLAC / OGG / OPUS)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Vorbis comment body: vendor + user comments (no packet framing)
+fn vorbis_comment_body(fields: &[(&str, String)]) -> Vec<u8> {
+    let mut body = Vec::new();
+    body.extend_from_slice(&(VENDOR.len() as u32).to_le_bytes());
+    body.extend_from_slice(VENDOR.as_bytes());
+    body.extend_from_slice(&(fields.len() as u32).to_le_bytes());
+    for (key, value) in fields {
+        let comment = format!("{}={}", key, value);
+        body.extend_from_slice(&(comment.len() as u32).to_le_bytes());
+        body.extend_from_slice(comment.as_bytes());
+    }
+    body
+}
+
+/// Vorbis comments with ReplayGain (FLAC, OGG Vorbis)
+fn replaygain_comments(metadata: &Metadata) -> Vec<u8> {
+    let mut fields = metadata.text_fields();
+    fields.extend(metadata.replaygain_fields());
+    vorbis_comment_body(&fields)
+}
+
+/// OpusTags packet (RFC 7845 §5.2) with R128 track gain
+pub(crate) fn opus_tags(metadata: &Metadata) -> Vec<u8> {
+    let mut fields = metadata.text_fields();
+    if let Some(lufs) = metadata.loudness_lufs {
+        // Q7.8 fixed point, relative to -23 LUFS
+        let gain = ((R128_REFERENCE_LUFS - lufs) * 256.0).round() as i16;
+        fields.push(("R128_TRACK_GAIN", gain.to_string()));
+    }
+
+    let mut tags = b"OpusTags".to_vec();
+    tags.extend(vorbis_comment_body(&fields));
+    tags
+}
+
+/// Insert a VORBIS_COMMENT block into an encoded FLAC stream
+///
+/// Any comment block written by libFLAC is replaced; other metadata blocks
+/// and audio frames are passed through untouched.
+pub(crate) fn flac_with_tags(flac: Vec<u8>, metadata: &Metadata) -> OfflineResult<Vec<u8>> {
+    const STREAMINFO: u8 = 0;
+    const VORBIS_COMMENT: u8 = 4;
+
+    if flac.len() < 4 || &flac[..4] != b"fLaC" {
+        return Err(OfflineError::EncodingError("Not a FLAC stream".into()));
+    }
+
+    // Collect metadata blocks as (type, body)
+    let mut blocks: Vec<(u8, &[u8])> = Vec::new();
+    let mut pos = 4;
+    loop {
+        let header = flac
+            .get(pos..pos + 4)
+            .ok_or_else(|| OfflineError::EncodingError("Truncated FLAC metadata".into()))?;
+        let last = header[0] & 0x80 != 0;
+        let block_type = header[0] & 0x7F;
+        let len = u32::from_be_bytes([0, header[1], header[2], header[3]]) as usize;
+        let body = flac
+            .get(pos + 4..pos + 4 + len)
+            .ok_or_else(|| OfflineError::EncodingError("Truncated FLAC metadata".into()))?;
+        if block_type != VORBIS_COMMENT {
+            blocks.push((block_type, body));
+        }
+        pos += 4 + len;
+        if last {
+            break;
+        }
+    }
+
+    let comments = replaygain_comments(metadata);
+    let insert_at = blocks
+        .iter()
+        .position(|(t, _)| *t == STREAMINFO)
+        .map_or(0, |i| i + 1);
+    blocks.insert(insert_at, (VORBIS_COMMENT, &comments));
+
+    let mut out = Vec::with_capacity(flac.len() + comments.len());
+    out.extend_from_slice(b"fLaC");
+    for (i, (block_type, body)) in blocks.iter().enumerate() {
+        let last_flag = if i == blocks.len() - 1 { 0x80 } else { 0 };
+        out.push(last_flag | block_type);
+        out.extend_from_slice(&(body.len() as u32).to_be_bytes()[1..]);
+        out.extend_from_slice(body);
+    }
+    out.extend_from_slice(&flac[pos..]);
+    Ok(out)
+}
+
+/// Replace the comment header (second packet) of an OGG Vorbis stream
+///
+/// Pages are re-muxed with the original packet/page boundaries and
+/// granule positions, so only the header page checksums change.
+pub(crate) fn ogg_vorbis_with_tags(ogg: Vec<u8>, metadata: &Metadata) -> OfflineResult<Vec<u8>> {
+    use ogg::writing::PacketWriteEndInfo;
+    use ogg::{PacketReader, PacketWriter};
+
+    let mut comment_packet = vec![0x03];
+    comment_packet.extend_from_slice(b"vorbis");
+    comment_packet.extend(replaygain_comments(metadata));
+    comment_packet.push(0x01); // framing bit
+
+    let mut reader = PacketReader::new(Cursor::new(ogg));
+    let mut out = Vec::new();
+    let mut writer = PacketWriter::new(&mut out);
+    let mut index = 0usize;
+
+    while let Some(packet) = reader
+        .read_packet()
+        .map_err(|e| OfflineError::EncodingError(format!("OGG read failed: {:?}", e)))?
+    {
+        let end_info = if packet.last_in_stream() {
+            PacketWriteEndInfo::EndStream
+        } else if packet.last_in_page() {
+            PacketWriteEndInfo::EndPage
+        } else {
+            PacketWriteEndInfo::NormalPacket
+        };
+        let serial = packet.stream_serial();
+        let absgp = packet.absgp_page();
+        let data = if index == 1 && packet.data.starts_with(b"\x03vorbis") {
+            std::mem::take(&mut comment_packet)
+        } else {
+            packet.data
+        };
+
+        writer
+            .write_packet(data, serial, end_info, absgp)
+            .map_err(|e| OfflineError::EncodingError(format!("OGG write failed: {:?}", e)))?;
+        index += 1;
+    }
+
+    drop(writer);
+    Ok(out)
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// ID3v2.4 (MP3)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Encode a 28-bit syncsafe integer
+fn syncsafe(value: u32) -> [u8; 4] {
+    [
+        ((value >> 21) & 0x7F) as u8,
+        ((value >> 14) & 0x7F) as u8,
+        ((value >> 7) & 0x7F) as u8,
+        (value & 0x7F) as u8,
+    ]
+}
+
+/// Build an ID3v2.4 tag (empty Vec when there is nothing to write)
+pub(crate) fn id3v2_tag(metadata: &Metadata) -> Vec<u8> {
+    const UTF8: u8 = 0x03;
+
+    let mut frames = Vec::new();
+    let mut push_frame = |id: &[u8; 4], body: Vec<u8>| {
+        frames.extend_from_slice(id);
+        frames.extend_from_slice(&syncsafe(body.len() as u32));
+        frames.extend_from_slice(&[0, 0]); // flags
+        frames.extend(body);
+    };
+
+    for (key, value) in metadata.text_fields() {
+        let id = match key {
+            "TITLE" => b"TIT2",
+            "ARTIST" => b"TPE1",
+            "ALBUM" => b"TALB",
+            "GENRE" => b"TCON",
+            "DATE" => b"TDRC",
+            "TRACKNUMBER" => b"TRCK",
+            "COMMENT" => {
+                // COMM: encoding, language, empty description, text
+                let mut body = vec![UTF8];
+                body.extend_from_slice(b"eng");
+                body.push(0);
+                body.extend_from_slice(value.as_bytes());
+                push_frame(b"COMM", body);
+                continue;
+            }
+            _ => continue,
+        };
+        let mut body = vec![UTF8];
+        body.extend_from_slice(value.as_bytes());
+        push_frame(id, body);
+    }
+
+    for (key, value) in metadata.replaygain_fields() {
+        // TXXX: encoding, description, value
+        let mut body = vec![UTF8];
+        body.extend_from_slice(key.as_bytes());
+        body.push(0);
+        body.extend_from_slice(value.as_bytes());
+        push_frame(b"TXXX", body);
+    }
+
+    if frames.is_empty() {
+        return frames;
+    }
+
+    let mut tag = Vec::with_capacity(10 + frames.len());
+    tag.extend_from_slice(b"ID3");
+    tag.extend_from_slice(&[4, 0, 0]); // v2.4.0, no flags
+    tag.extend_from_slice(&syncsafe(frames.len() as u32));
+    tag.extend(frames);
+    tag
+}
+
+// ═══════════════════════════════════════════════════════════════════════════════
+// BEXT / iXML (WAV)
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// Fixed-width, null-padded ASCII field
+fn ascii_field(out: &mut Vec<u8>, value: Option<&str>, width: usize) {
+    let start = out.len();
+    if let Some(value) = value {
+        out.extend(
+            value
+                .chars()
+                .map(|c| if c.is_ascii() { c as u8 } else { b'?' })
+                .take(width),
+        );
+    }
+    out.resize(start + width, 0);
+}
+
+/// Loudness field in 1/100 dB (0x7FFF = not set)
+fn loudness_field(value: Option<f64>) -> i16 {
+    value
+        .map(|v| (v * 100.0).round().clamp(i16::MIN as f64, (i16::MAX - 1) as f64) as i16)
+        .unwrap_or(0x7FFF)
+}
+
+/// Broadcast Audio Extension chunk body (EBU Tech 3285 v2)
+fn bext_chunk(metadata: &Metadata) -> Option<Vec<u8>> {
+    let description = metadata.comment.as_deref().or(metadata.title.as_deref());
+    let originator = metadata.originator.as_deref().or(metadata.artist.as_deref());
+    // OriginationDate must be "yyyy-mm-dd"
+    let date = metadata
+        .date
+        .as_deref()
+        .filter(|d| d.len() >= 10 && d.as_bytes()[4] == b'-' && d.as_bytes()[7] == b'-')
+        .map(|d| &d[..10]);
+
+    if description.is_none()
+        && originator.is_none()
+        && date.is_none()
+        && metadata.loudness_lufs.is_none()
+        && metadata.true_peak_dbtp.is_none()
+    {
+        return None;
+    }
+
+    let mut bext = Vec::with_capacity(602);
+    ascii_field(&mut bext, description, 256);
+    ascii_field(&mut bext, originator, 32);
+    ascii_field(&mut bext, None, 32); // OriginatorReference
+    ascii_field(&mut bext, date, 10);
+    ascii_field(&mut bext, None, 8); // OriginationTime
+    bext.extend_from_slice(&0u64.to_le_bytes()); // TimeReference
+    bext.extend_from_slice(&2u16.to_le_bytes()); // Version
+    bext.extend_from_slice(&[0; 64]); // UMID
+    bext.extend_from_slice(&loudness_field(metadata.loudness_lufs).to_le_bytes());
+    bext.extend_from_slice(&0x7FFFi16.to_le_bytes()); // LoudnessRange
+    bext.extend_from_slice(&loudness_field(metadata.true_peak_dbtp).to_le_bytes());
+    bext.extend_from_slice(&0x7FFFi16.to_le_bytes()); // MaxMomentaryLoudness
+    bext.extend_from_slice(&0x7FFFi16.to_le_bytes()); // MaxShortTermLoudness
+    bext.extend_from_slice(&[0; 180]); // Reserved
+    Some(bext)
+}
+
+/// Escape XML text content
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// iXML chunk body
+fn ixml_chunk(metadata: &Metadata) -> Option<Vec<u8>> {
+    let mut fields = metadata.text_fields();
+    fields.extend(metadata.replaygain_fields());
+    if fields.is_empty() {
+        return None;
+    }
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<BWFXML>\n");
+    xml.push_str("  <IXML_VERSION>2.10</IXML_VERSION>\n");
+    if let Some(album) = metadata.album.as_deref().filter(|a| !a.is_empty()) {
+        xml.push_str(&format!("  <PROJECT>{}</PROJECT>\n", xml_escape(album)));
+    }
+    if let Some(comment) = metadata.comment.as_deref().filter(|c| !c.is_empty()) {
+        xml.push_str(&format!("  <NOTE>{}</NOTE>\n", xml_escape(comment)));
+    }
+    xml.push_str("  <USER>");
+    for (key, value) in &fields {
+        xml.push_str(&format!("{}={}&#10;", key, xml_escape(value)));
+    }
+    xml.push_str("</USER>\n</BWFXML>\n");
+    Some(xml.into_bytes())
+}
+
+/// Insert bext and iXML chunks ahead of the `data` chunk of a RIFF/WAVE file
+pub(crate) fn wav_with_tags(wav: Vec<u8>, metadata: &Metadata) -> OfflineResult<Vec<u8>> {
+    if wav.len() < 12 || &wav[..4] != b"RIFF" || &wav[8..12] != b"WAVE" {
+        return Err(OfflineError::EncodingError("Not a RIFF/WAVE stream".into()));
+    }
+
+    let mut chunks = Vec::new();
+    for (id, body) in [(b"bext", bext_chunk(metadata)), (b"iXML", ixml_chunk(metadata))] {
+        if let Some(body) = body {
+            chunks.extend_from_slice(id);
+            chunks.extend_from_slice(&(body.len() as u32).to_le_bytes());
+            chunks.extend_from_slice(&body);
+            if body.len() % 2 == 1 {
+                chunks.push(0); // RIFF pad byte
+            }
+        }
+    }
+    if chunks.is_empty() {
+        return Ok(wav);
+    }
+
+    // Locate the data chunk
+    let mut pos = 12;
+    while pos + 8 <= wav.len() && &wav[pos..pos + 4] != b"data" {
+        let len = u32::from_le_bytes([wav[pos + 4], wav[pos + 5], wav[pos + 6], wav[pos + 7]]);
+        pos += 8 + len as usize + (len as usize % 2);
+    }
+    if pos + 8 > wav.len() {
+        return Err(OfflineError::EncodingError("WAV has no data chunk".into()));
+    }
+
+    let mut out = Vec::with_capacity(wav.len() + chunks.len());
+    out.extend_from_slice(&wav[..pos]);
+    out.extend_from_slice(&chunks);
+    out.extend_from_slice(&wav[pos..]);
+
+    let riff_size = u32::from_le_bytes([out[4], out[5], out[6], out[7]]) + chunks.len() as u32;
+    out[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample() -> Metadata {
+        Metadata::new()
+            .with_title("Big Win")
+            .with_artist("VanVinkl")
+            .with_loudness(-14.0, -1.0)
+    }
+
+    #[test]
+    fn test_missing_fields_omitted() {
+        let meta = Metadata::new().with_title("Only Title");
+        let fields = meta.text_fields();
+        assert_eq!(fields, vec![("TITLE", "Only Title".to_string())]);
+        assert!(meta.replaygain_fields().is_empty());
+        assert!(id3v2_tag(&Metadata::new()).is_empty());
+    }
+
+    #[test]
+    fn test_replaygain_from_lufs() {
+        let meta = sample();
+        assert_eq!(meta.replaygain_track_gain(), Some(-4.0));
+        let fields = meta.replaygain_fields();
+        assert_eq!(fields[0], ("REPLAYGAIN_TRACK_GAIN", "-4.00 dB".to_string()));
+    }
+
+    #[test]
+    fn test_id3v2_tag_layout() {
+        let tag = id3v2_tag(&sample());
+        assert_eq!(&tag[..5], b"ID3\x04\x00");
+        let size = tag[6..10].iter().fold(0u32, |acc, &b| (acc << 7) | b as u32);
+        assert_eq!(size as usize, tag.len() - 10);
+        assert!(tag.windows(4).any(|w| w == b"TIT2"));
+        assert!(tag.windows(4).any(|w| w == b"TXXX"));
+        assert!(!tag.windows(4).any(|w| w == b"TALB"));
+    }
+
+    #[test]
+    fn test_bext_layout() {
+        let bext = bext_chunk(&sample()).unwrap();
+        assert_eq!(bext.len(), 602);
+        // LoudnessValue at offset 412, MaxTruePeakLevel at 416
+        assert_eq!(i16::from_le_bytes([bext[412], bext[413]]), -1400);
+        assert_eq!(i16::from_le_bytes([bext[416], bext[417]]), -100);
+        assert!(bext_chunk(&Metadata::new()).is_none());
+    }
+}
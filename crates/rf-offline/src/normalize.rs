@@ -10,14 +10,23 @@ use serde::{Deserialize, Serialize};
 /// Normalization mode
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub enum NormalizationMode {
-    /// Peak normalization (dBFS target)
-    Peak { target_db: f64 },
+    /// Peak normalization (sample peak, dBFS target)
+    Peak {
+        #[serde(alias = "target_db")]
+        target_dbfs: f64,
+    },
 
     /// Loudness normalization (LUFS target) - EBU R128
     Lufs { target_lufs: f64 },
 
     /// True peak normalization (dBTP target)
-    TruePeak { target_db: f64 },
+    ///
+    /// Measured with the 4x oversampled detector, so inter-sample peaks
+    /// are accounted for and the encoded file won't clip on playback.
+    TruePeak {
+        #[serde(alias = "target_db")]
+        target_dbtp: f64,
+    },
 
     /// No normalization, but ensure no clipping
     NoClip,
@@ -25,14 +34,14 @@ pub enum NormalizationMode {
 
 impl Default for NormalizationMode {
     fn default() -> Self {
-        Self::Peak { target_db: -1.0 }
+        Self::Peak { target_dbfs: -1.0 }
     }
 }
 
 impl NormalizationMode {
     /// Create peak normalization at -1dBFS
     pub fn peak() -> Self {
-        Self::Peak { target_db: -1.0 }
+        Self::Peak { target_dbfs: -1.0 }
     }
 
     /// Create LUFS normalization at -14 LUFS (streaming standard)
@@ -47,7 +56,7 @@ impl NormalizationMode {
 
     /// Create true peak normalization at -1dBTP
     pub fn true_peak() -> Self {
-        Self::TruePeak { target_db: -1.0 }
+        Self::TruePeak { target_dbtp: -1.0 }
     }
 }
 
@@ -80,21 +89,27 @@ impl Normalizer {
     }
 
     /// Calculate gain to apply based on loudness info
+    ///
+    /// Returns unity gain when the measured level is silent (no finite dB).
     pub fn calculate_gain(&self, info: &LoudnessInfo) -> f64 {
         match self.mode {
-            NormalizationMode::Peak { target_db } => {
-                let current_peak_db = 20.0 * info.peak.log10();
-                let gain_db = target_db - current_peak_db;
-                db_to_linear(gain_db)
+            NormalizationMode::Peak { target_dbfs } => {
+                if info.peak <= 0.0 {
+                    return 1.0;
+                }
+                db_to_linear(target_dbfs - linear_to_db(info.peak))
             }
             NormalizationMode::Lufs { target_lufs } => {
-                let gain_db = target_lufs - info.integrated;
-                db_to_linear(gain_db)
+                if !info.integrated.is_finite() {
+                    return 1.0;
+                }
+                db_to_linear(target_lufs - info.integrated)
             }
-            NormalizationMode::TruePeak { target_db } => {
-                let current_tp_db = 20.0 * info.true_peak.log10();
-                let gain_db = target_db - current_tp_db;
-                db_to_linear(gain_db)
+            NormalizationMode::TruePeak { target_dbtp } => {
+                if info.true_peak <= 0.0 {
+                    return 1.0;
+                }
+                db_to_linear(target_dbtp - linear_to_db(info.true_peak))
             }
             NormalizationMode::NoClip => {
                 if info.peak > 1.0 {
@@ -121,7 +136,6 @@ fn db_to_linear(db: f64) -> f64 {
 }
 
 /// Convert linear gain to dB
-#[allow(dead_code)]
 fn linear_to_db(linear: f64) -> f64 {
     20.0 * linear.log10()
 }
@@ -318,16 +332,20 @@ impl TruePeakDetector {
     }
 
     /// Generate FIR interpolation filter coefficients
+    ///
+    /// Windowed sinc at the 4x rate, centred on tap 24 so phase 0 lands on
+    /// the original sample and phases 1-3 on the 0.75/0.5/0.25 positions.
+    /// Each polyphase branch (taps `4t + phase`) has unity DC gain.
     fn generate_fir_coeffs() -> [f64; 48] {
         let mut coeffs = [0.0; 48];
         let n = 48;
-        let m = n / 2;
+        let center = (n / 2) as f64;
 
         // Kaiser window parameters
         let beta = 4.5;
 
         for (i, coeff) in coeffs.iter_mut().enumerate().take(n) {
-            let x = (i as f64 - (n - 1) as f64 / 2.0) / 4.0;
+            let x = (i as f64 - center) / 4.0;
 
             // Sinc function
             let sinc = if x.abs() < 1e-10 {
@@ -337,11 +355,19 @@ impl TruePeakDetector {
             };
 
             // Kaiser window
-            let alpha = (i as f64 - (n - 1) as f64 / 2.0) / (m as f64);
+            let alpha = (i as f64 - center) / (center + 1.0);
             let window =
                 Self::bessel_i0(beta * (1.0 - alpha * alpha).sqrt()) / Self::bessel_i0(beta);
 
-            *coeff = sinc * window / 4.0; // Divide by 4 for interpolation
+            *coeff = sinc * window;
+        }
+
+        // Normalize each polyphase branch to unity gain
+        for phase in 0..4 {
+            let sum: f64 = (0..12).map(|tap| coeffs[tap * 4 + phase]).sum();
+            for tap in 0..12 {
+                coeffs[tap * 4 + phase] /= sum;
+            }
         }
 
         coeffs
@@ -376,7 +402,7 @@ impl TruePeakDetector {
         for phase in 0..4 {
             let mut sum = 0.0;
             for tap in 0..12 {
-                let coeff_idx = phase * 12 + tap;
+                let coeff_idx = tap * 4 + phase;
                 let sample_idx = (self.delay_idx + 12 - 1 - tap) % 12;
                 sum += self.delay_line[sample_idx] * self.coeffs[coeff_idx];
             }
@@ -735,6 +761,43 @@ mod tests {
         assert!(detected_peak >= sample_peak * 0.99);
     }
 
+    #[test]
+    fn test_true_peak_detector_intersample() {
+        let mut detector = TruePeakDetector::new();
+
+        // fs/4 sine at 45°: samples at ±0.707, true peak 1.0 between them.
+        // Skip the first filter lengths so the onset transient doesn't count.
+        let mut detected_peak = 0.0f64;
+        for n in 0..256 {
+            let s = (std::f64::consts::FRAC_PI_2 * n as f64 + std::f64::consts::FRAC_PI_4).sin();
+            let peak = detector.process(s);
+            if n >= 32 {
+                detected_peak = detected_peak.max(peak);
+            }
+        }
+
+        assert!(
+            (detected_peak - 1.0).abs() < 0.01,
+            "true peak {detected_peak} should reconstruct the inter-sample over"
+        );
+    }
+
+    #[test]
+    fn test_normalizer_silence_is_unity() {
+        let info = LoudnessInfo {
+            integrated: -f64::INFINITY,
+            ..Default::default()
+        };
+
+        for mode in [
+            NormalizationMode::peak(),
+            NormalizationMode::true_peak(),
+            NormalizationMode::streaming(),
+        ] {
+            assert_eq!(Normalizer::new(mode).calculate_gain(&info), 1.0);
+        }
+    }
+
     #[test]
     fn test_loudness_meter_silence() {
         let mut meter = LoudnessMeter::new(48000, 2);
@@ -782,7 +845,7 @@ mod tests {
             range: 5.0,
         };
 
-        let normalizer = Normalizer::new(NormalizationMode::Peak { target_db: -1.0 });
+        let normalizer = Normalizer::new(NormalizationMode::Peak { target_dbfs: -1.0 });
         let gain = normalizer.calculate_gain(&info);
 
         // Should boost by ~5 dB
@@ -12,17 +12,18 @@ use std::path::Path;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::config::OfflineConfig;
 use crate::decoder::AudioDecoder;
 use crate::encoder::create_encoder;
-use crate::error::OfflineResult;
+use crate::error::{OfflineError, OfflineResult};
 use crate::formats::OutputFormat;
-use crate::job::{JobResult, MonoDownmix, OfflineJob};
-use crate::normalize::{LoudnessMeter, NormalizationMode};
+use crate::job::{JobPlan, JobResult, MonoDownmix, OfflineJob};
+use crate::metadata::Metadata;
+use crate::normalize::{LoudnessInfo, LoudnessMeter, NormalizationMode, Normalizer};
 use crate::processors::{OfflineProcessor, ProcessorChain, SoftClipProcessor};
 
 use rf_dsp::dynamics::{TruePeakLimiter, LimiterStyle, LimiterLatencyProfile};
@@ -301,10 +302,15 @@ impl AudioBuffer {
 /// Main offline processing pipeline
 pub struct OfflinePipeline {
     config: OfflineConfig,
-    processors: ProcessorChain,
+    /// Default chain for jobs without their own (shared with `BatchProcessor`)
+    processors: Arc<Mutex<ProcessorChain>>,
     normalization: Option<NormalizationMode>,
     output_format: OutputFormat,
 
+    /// Tags embedded at encode time. Loudness fields left unset are
+    /// filled from the measured output.
+    metadata: Option<Metadata>,
+
     /// Post-normalization soft-clip ceiling in dB (e.g., -0.3).
     /// When set, applies polynomial soft-clipping after normalization
     /// to prevent hard clipping in encoders.
@@ -330,9 +336,10 @@ impl OfflinePipeline {
     pub fn new(config: OfflineConfig) -> Self {
         Self {
             config,
-            processors: ProcessorChain::new(),
+            processors: Arc::new(Mutex::new(ProcessorChain::new())),
             normalization: None,
             output_format: OutputFormat::wav_16(),
+            metadata: None,
             soft_clip_ceiling_db: None,
             use_true_peak_limiter: false,
             limiter_ceiling_db: -0.3,
@@ -346,13 +353,13 @@ impl OfflinePipeline {
 
     /// Set processor chain (builder pattern)
     pub fn with_processors(mut self, processors: ProcessorChain) -> Self {
-        self.processors = processors;
+        self.processors = Arc::new(Mutex::new(processors));
         self
     }
 
     /// Set processor chain (mutable reference, for per-job use from FFI)
     pub fn set_processors(&mut self, processors: ProcessorChain) {
-        self.processors = processors;
+        self.processors = Arc::new(Mutex::new(processors));
     }
 
     /// Set normalization mode (builder pattern)
@@ -398,6 +405,22 @@ impl OfflinePipeline {
         self.output_format = format;
     }
 
+    /// Set tag metadata (builder pattern)
+    pub fn with_metadata(mut self, metadata: Metadata) -> Self {
+        self.metadata = Some(metadata);
+        self
+    }
+
+    /// Set tag metadata (mutable reference, for FFI use)
+    pub fn set_metadata(&mut self, metadata: Metadata) {
+        self.metadata = Some(metadata);
+    }
+
+    /// Clear tag metadata
+    pub fn clear_metadata(&mut self) {
+        self.metadata = None;
+    }
+
     /// Cancel processing
     pub fn cancel(&self) {
         self.cancelled.store(true, Ordering::SeqCst);
@@ -469,6 +492,8 @@ impl OfflinePipeline {
     }
 
     /// Process a single job
+    ///
+    /// The job's own processor chain (if set) runs in place of the pipeline's.
     pub fn process_job(&mut self, job: &OfflineJob) -> OfflineResult<JobResult> {
         self.cancelled.store(false, Ordering::SeqCst);
         self.samples_processed.store(0, Ordering::Relaxed);
@@ -519,7 +544,8 @@ impl OfflinePipeline {
 
         // Step 3: Process through DSP chain
         self.set_state(PipelineState::Processing);
-        self.process_buffer(&mut buffer)?;
+        let chain = job.processors.as_ref().unwrap_or(&*self.processors);
+        self.process_buffer(&mut chain.lock(), &mut buffer, job.tail_samples as usize)?;
 
         if self.is_cancelled() {
             self.set_state(PipelineState::Cancelled);
@@ -582,9 +608,20 @@ impl OfflinePipeline {
             }
         }
 
+        // Measure integrated LUFS and true peak on final buffer
+        let mut meter = LoudnessMeter::new(buffer.sample_rate, buffer.channels);
+        meter.process(&buffer.samples);
+        let info = meter.get_info();
+        let loudness = info.integrated;
+        let true_peak_db = if info.true_peak > 0.0 {
+            20.0 * info.true_peak.log10()
+        } else {
+            -f64::INFINITY
+        };
+
         // Step 6: Encode
         self.set_state(PipelineState::Encoding);
-        let encoded = self.encode_buffer(&buffer)?;
+        let encoded = self.encode_buffer(&buffer, loudness, true_peak_db)?;
 
         // Step 7: Write
         self.set_state(PipelineState::Writing);
@@ -596,29 +633,137 @@ impl OfflinePipeline {
         let peak_db = buffer.peak_db();
         let output_size = encoded.len() as u64;
 
-        // Measure integrated LUFS on final buffer
-        let mut meter = LoudnessMeter::new(buffer.sample_rate, buffer.channels);
-        meter.process(&buffer.samples);
-        let loudness = meter.get_info().integrated;
-
         Ok(JobResult::success(
             job.id,
             job.output_path.clone(),
             output_size,
             self.start_time.unwrap_or_else(std::time::Instant::now).elapsed(),
             peak_db,
-            peak_db, // true_peak (same as peak for now)
+            true_peak_db,
             loudness,
         ))
     }
 
+    /// Dry run: describe what `process_job` would produce for `job`
+    ///
+    /// Only the input header is probed — nothing is decoded, processed or
+    /// written. The job's own chain is listed when set, as `process_job`
+    /// runs it in place of the pipeline's. Frame counts follow
+    /// `process_job` exactly; the file size is an estimate for FLAC and
+    /// lossy formats (see `OutputFormat::estimated_size`).
+    pub fn plan_job(&self, job: &OfflineJob) -> JobPlan {
+        if !job.input_path.exists() {
+            let error = OfflineError::InputNotFound(job.input_path.display().to_string());
+            return JobPlan::failure(job, error.to_string());
+        }
+        let info = match AudioDecoder::probe(&job.input_path) {
+            Ok(info) => info,
+            Err(e) => return JobPlan::failure(job, e.to_string()),
+        };
+
+        let mut chain = Vec::new();
+        let mut channels = info.channels.max(1);
+
+        // Range is applied to the interleaved buffer (see process_job)
+        let total = info.samples as u64 * channels as u64;
+        let mut samples = total;
+        if let Some((start, end)) = job.range {
+            let (start, end) = (start.min(total), end.min(total));
+            if start < end {
+                samples = end - start;
+                chain.push(format!("Trim {}..{}", start, end));
+            }
+        }
+        let mut frames = samples / channels as u64;
+
+        if let Some(method) = job.mono_downmix {
+            if channels > 1 {
+                channels = 1;
+                chain.push(format!("Mono downmix ({:?})", method));
+            }
+        }
+
+        let processors = job.processors.as_ref().unwrap_or(&*self.processors);
+        chain.extend(processors.lock().names().into_iter().map(str::to_string));
+        if job.tail_samples > 0 {
+            frames += job.tail_samples;
+            chain.push(format!("Tail +{} frames", job.tail_samples));
+        }
+
+        if let Some(fade_in) = job.fade_in.filter(|&n| n > 0 && n <= frames) {
+            chain.push(format!("Fade in {} frames", fade_in));
+        }
+        if let Some(fade_out) = job.fade_out.filter(|&n| n > 0 && n <= frames) {
+            chain.push(format!("Fade out {} frames", fade_out));
+        }
+        if let Some(mode) = &self.normalization {
+            chain.push(format!("Normalize {:?}", mode));
+        }
+        if self.use_true_peak_limiter {
+            chain.push(format!(
+                "True-peak limiter ({:.1} dB)",
+                self.limiter_ceiling_db
+            ));
+        }
+        if let Some(ceiling_db) = self.soft_clip_ceiling_db {
+            chain.push(format!("Soft clip ({:.1} dB)", ceiling_db));
+        }
+
+        let mut sample_rate = info.sample_rate;
+        if let Some(target_rate) = job.sample_rate.filter(|&rate| rate != sample_rate) {
+            frames = (frames as f64 * target_rate as f64 / sample_rate as f64).ceil() as u64;
+            chain.push(format!("Resample {} -> {} Hz", sample_rate, target_rate));
+            sample_rate = target_rate;
+        }
+        chain.push(format!(
+            "Encode {}",
+            self.output_format.extension().to_uppercase()
+        ));
+
+        JobPlan {
+            job_id: job.id,
+            input_path: job.input_path.clone(),
+            output_path: job.output_path.clone(),
+            input_format: info.format,
+            input_sample_rate: info.sample_rate,
+            input_channels: info.channels,
+            output_sample_rate: sample_rate,
+            output_channels: channels,
+            output_frames: frames,
+            output_duration: frames as f64 / sample_rate.max(1) as f64,
+            estimated_size: self
+                .output_format
+                .estimated_size(frames, channels, sample_rate),
+            chain,
+            error: None,
+        }
+    }
+
     /// Load audio from file (supports WAV, FLAC, MP3, OGG, AAC)
     fn load_audio(&self, path: &Path) -> OfflineResult<AudioBuffer> {
         AudioDecoder::decode(path)
     }
 
     /// Process buffer through DSP chain
-    fn process_buffer(&mut self, buffer: &mut AudioBuffer) -> OfflineResult<()> {
+    ///
+    /// Runs `latency + tail_frames` frames of silence after the audio to flush
+    /// the chain, then drops the leading latency so the output lines up with
+    /// the input and ends `tail_frames` later.
+    fn process_buffer(
+        &self,
+        processors: &mut ProcessorChain,
+        buffer: &mut AudioBuffer,
+        tail_frames: usize,
+    ) -> OfflineResult<()> {
+        let latency = processors.total_latency();
+        let frames = buffer.frames();
+        buffer
+            .samples
+            .resize((frames + latency + tail_frames) * buffer.channels, 0.0);
+        self.total_samples
+            .store(buffer.samples.len() as u64, Ordering::Relaxed);
+        processors.reset();
+
         // Align block size to channel count to avoid splitting frames mid-channel
         let block_size = if buffer.channels > 1 {
             (self.config.buffer_size / buffer.channels) * buffer.channels
@@ -634,33 +779,37 @@ impl OfflinePipeline {
                 return Ok(());
             }
 
-            self.processors.process_interleaved(chunk, buffer.sample_rate, buffer.channels);
+            processors.process_interleaved(chunk, buffer.sample_rate, buffer.channels);
 
             processed += chunk.len();
             self.samples_processed
                 .store(processed as u64, Ordering::Relaxed);
         }
 
+        buffer.samples.drain(..latency * buffer.channels);
+
         Ok(())
     }
 
     /// Normalize buffer
+    ///
+    /// Measures the whole buffer once, then applies a single static gain.
     fn normalize_buffer(
         &self,
         buffer: &mut AudioBuffer,
         mode: NormalizationMode,
     ) -> OfflineResult<()> {
-        match mode {
-            NormalizationMode::Peak { target_db } => {
-                let current_peak = buffer.peak_db();
-                if current_peak > -f64::INFINITY {
-                    let gain_db = target_db - current_peak;
-                    let gain_linear = 10.0_f64.powf(gain_db / 20.0);
-                    buffer.apply_gain(gain_linear);
-                }
+        let gain = match mode {
+            NormalizationMode::Peak { .. } | NormalizationMode::NoClip => {
+                // Sample peak only — no need to run the loudness meter
+                let info = LoudnessInfo {
+                    peak: buffer.peak(),
+                    ..Default::default()
+                };
+                Normalizer::new(mode).calculate_gain(&info)
             }
-            NormalizationMode::Lufs { target_lufs } => {
-                // Measure current loudness
+            NormalizationMode::Lufs { .. } | NormalizationMode::TruePeak { .. } => {
+                // LUFS and true peak (4x oversampled, ITU-R BS.1770-4)
                 let mut meter = LoudnessMeter::new(buffer.sample_rate, buffer.channels);
 
                 // Process in blocks
@@ -668,43 +817,12 @@ impl OfflinePipeline {
                     meter.process(chunk);
                 }
 
-                let info = meter.get_info();
-                if info.integrated > -f64::INFINITY {
-                    let gain_db = target_lufs - info.integrated;
-                    let gain_linear = 10.0_f64.powf(gain_db / 20.0);
-                    buffer.apply_gain(gain_linear);
-                }
+                Normalizer::new(mode).calculate_gain(&meter.get_info())
             }
-            NormalizationMode::TruePeak { target_db } => {
-                // True peak detection with 4x oversampling (ITU-R BS.1770-4)
-                let mut meter = LoudnessMeter::new(buffer.sample_rate, buffer.channels);
-
-                // Process in blocks for true peak measurement
-                for chunk in buffer.samples.chunks(4096) {
-                    meter.process(chunk);
-                }
-
-                let info = meter.get_info();
-                // true_peak is linear, convert to dB
-                let current_tp_db = if info.true_peak > 0.0 {
-                    20.0 * info.true_peak.log10()
-                } else {
-                    -f64::INFINITY
-                };
+        };
 
-                if current_tp_db > -f64::INFINITY {
-                    let gain_db = target_db - current_tp_db;
-                    let gain_linear = 10.0_f64.powf(gain_db / 20.0);
-                    buffer.apply_gain(gain_linear);
-                }
-            }
-            NormalizationMode::NoClip => {
-                // Just ensure no clipping
-                let peak = buffer.peak();
-                if peak > 1.0 {
-                    buffer.apply_gain(1.0 / peak);
-                }
-            }
+        if gain != 1.0 {
+            buffer.apply_gain(gain);
         }
         Ok(())
     }
@@ -814,9 +932,27 @@ impl OfflinePipeline {
     }
 
     /// Encode buffer to output format (supports WAV, FLAC, MP3, OGG, Opus, AAC)
-    fn encode_buffer(&self, buffer: &AudioBuffer) -> OfflineResult<Vec<u8>> {
+    ///
+    /// Tags are written only when metadata is set; its loudness fields default
+    /// to the measured values.
+    fn encode_buffer(
+        &self,
+        buffer: &AudioBuffer,
+        loudness: f64,
+        true_peak_db: f64,
+    ) -> OfflineResult<Vec<u8>> {
         let encoder = create_encoder(&self.output_format);
-        encoder.encode(buffer)
+
+        match &self.metadata {
+            Some(metadata) => {
+                let mut metadata = metadata.clone();
+                if metadata.loudness_lufs.is_none() && metadata.true_peak_dbtp.is_none() {
+                    metadata = metadata.with_loudness(loudness, true_peak_db);
+                }
+                encoder.encode_with_metadata(buffer, &metadata)
+            }
+            None => encoder.encode(buffer),
+        }
     }
 
     /// Apply TruePeakLimiter from rf-dsp (professional limiter with lookahead)
@@ -898,7 +1034,7 @@ impl OfflinePipeline {
 /// Batch processor for multiple jobs
 pub struct BatchProcessor {
     config: OfflineConfig,
-    processors: ProcessorChain,
+    processors: Arc<Mutex<ProcessorChain>>,
     normalization: Option<NormalizationMode>,
     output_format: OutputFormat,
     max_parallel: usize,
@@ -909,16 +1045,19 @@ impl BatchProcessor {
     pub fn new(config: OfflineConfig) -> Self {
         Self {
             config,
-            processors: ProcessorChain::new(),
+            processors: Arc::new(Mutex::new(ProcessorChain::new())),
             normalization: None,
             output_format: OutputFormat::wav_16(),
             max_parallel: rayon::current_num_threads(),
         }
     }
 
-    /// Set processor chain
+    /// Set processor chain for jobs without their own
+    ///
+    /// The chain is stateful, so jobs sharing it take turns in the processing
+    /// stage; decoding and encoding still run in parallel.
     pub fn with_processors(mut self, processors: ProcessorChain) -> Self {
-        self.processors = processors;
+        self.processors = Arc::new(Mutex::new(processors));
         self
     }
 
@@ -940,6 +1079,26 @@ impl BatchProcessor {
         self
     }
 
+    /// Dry run every job (see `OfflinePipeline::plan_job`)
+    ///
+    /// Validates inputs and reports estimated output length, size and the
+    /// processing chain without decoding or rendering anything.
+    pub fn dry_run(&self, jobs: &[OfflineJob]) -> Vec<JobPlan> {
+        let pipeline = self.job_pipeline();
+        jobs.iter().map(|job| pipeline.plan_job(job)).collect()
+    }
+
+    /// Pipeline configured the way `process_all` runs each job
+    fn job_pipeline(&self) -> OfflinePipeline {
+        let mut pipeline = OfflinePipeline::new(self.config.clone());
+        pipeline.processors = Arc::clone(&self.processors);
+
+        if let Some(ref mode) = self.normalization {
+            pipeline = pipeline.with_normalization(*mode);
+        }
+        pipeline.with_output_format(self.output_format.clone())
+    }
+
     /// Process all jobs in parallel
     pub fn process_all(&self, jobs: &[OfflineJob]) -> Vec<JobResult> {
         // Use rayon for parallel processing
@@ -952,15 +1111,7 @@ impl BatchProcessor {
         pool.install(|| {
             jobs.par_iter()
                 .map(|job| {
-                    let mut pipeline = OfflinePipeline::new(self.config.clone());
-
-                    // Note: ProcessorChain is not Clone, so we'd need to recreate it
-                    // For now, use default chain
-                    if let Some(ref mode) = self.normalization {
-                        pipeline = pipeline.with_normalization(*mode);
-                    }
-                    pipeline = pipeline.with_output_format(self.output_format.clone());
-
+                    let mut pipeline = self.job_pipeline();
                     match pipeline.process_job(job) {
                         Ok(result) => result,
                         Err(e) => {
@@ -976,6 +1127,7 @@ impl BatchProcessor {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::processors::GainProcessor;
 
     #[test]
     fn test_audio_buffer_mono_to_stereo() {
@@ -1018,6 +1170,234 @@ mod tests {
         assert!((buffer.peak() - 0.8).abs() < 0.001);
     }
 
+    #[test]
+    fn test_peak_normalization() {
+        let mut buffer = AudioBuffer {
+            samples: vec![0.25, -0.5, 0.1],
+            channels: 1,
+            sample_rate: 48000,
+        };
+
+        let pipeline = OfflinePipeline::new(OfflineConfig::default());
+        pipeline
+            .normalize_buffer(&mut buffer, NormalizationMode::Peak { target_dbfs: -6.0 })
+            .unwrap();
+
+        assert!((buffer.peak_db() - (-6.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_true_peak_normalization_intersample_overs() {
+        // fs/4 sine at 45°: sample peak sits 3 dB below the true peak.
+        // Faded in so the onset doesn't add its own reconstruction overshoot.
+        let amplitude = 0.9;
+        let samples: Vec<f64> = (0..48000)
+            .map(|n| {
+                let phase = std::f64::consts::FRAC_PI_2 * n as f64 + std::f64::consts::FRAC_PI_4;
+                let fade = (n as f64 / 480.0).min(1.0);
+                fade * amplitude * phase.sin()
+            })
+            .collect();
+        let mut buffer = AudioBuffer {
+            samples,
+            channels: 1,
+            sample_rate: 48000,
+        };
+
+        let pipeline = OfflinePipeline::new(OfflineConfig::default());
+        pipeline
+            .normalize_buffer(&mut buffer, NormalizationMode::TruePeak { target_dbtp: -1.0 })
+            .unwrap();
+
+        // Single static gain: the reconstructed peak is amplitude × gain
+        let gain = buffer.peak() / (amplitude * std::f64::consts::FRAC_1_SQRT_2);
+        let true_peak_dbtp = 20.0 * (amplitude * gain).log10();
+        assert!(
+            (true_peak_dbtp - (-1.0)).abs() < 0.1,
+            "true peak landed at {true_peak_dbtp:.2} dBTP"
+        );
+
+        // Sample-peak normalization would have overshot by ~3 dB
+        assert!(buffer.peak_db() < -3.5);
+    }
+
+    /// Plain stereo gain, standing in for any rf-dsp processor
+    struct TestGain(f64);
+
+    impl Processor for TestGain {
+        fn reset(&mut self) {}
+    }
+
+    impl StereoProcessor for TestGain {
+        fn process_sample(&mut self, left: f64, right: f64) -> (f64, f64) {
+            (left * self.0, right * self.0)
+        }
+    }
+
+    /// Write `frames` of a stereo 1 kHz sine at -12 dBFS (48 kHz, 32-bit float)
+    fn write_sine(path: &Path, frames: usize) {
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 48000,
+            bits_per_sample: 32,
+            sample_format: hound::SampleFormat::Float,
+        };
+        let mut writer = hound::WavWriter::create(path, spec).unwrap();
+        for n in 0..frames {
+            let x = 0.25 * (2.0 * std::f32::consts::PI * 1000.0 * n as f32 / 48000.0).sin();
+            writer.write_sample(x).unwrap();
+            writer.write_sample(x).unwrap();
+        }
+        writer.finalize().unwrap();
+    }
+
+    #[test]
+    fn test_job_dsp_chain_gain_then_limiter() {
+        let dir = std::env::temp_dir().join(format!("rf_offline_chain_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("in.wav");
+        let output = dir.join("out.wav");
+
+        let frames = 48000;
+        write_sine(&input, frames);
+
+        let mut job = OfflineJob::builder()
+            .input(&input)
+            .output(&output)
+            .tail(480)
+            .build()
+            .unwrap();
+
+        // +12 dB drives the sine to 0 dBFS, the limiter holds it at -3 dBFS
+        let mut limiter = TruePeakLimiter::new(48000.0);
+        limiter.set_ceiling(-3.0);
+        limiter.set_threshold(0.0);
+        let limiter_latency = Processor::latency(&limiter);
+        assert!(limiter_latency > 0);
+        job.add_processor(Box::new(TestGain(4.0)));
+        job.add_processor(Box::new(limiter));
+
+        let mut pipeline = OfflinePipeline::new(OfflineConfig::default())
+            .with_output_format(OutputFormat::wav_32f());
+        let result = pipeline.process_job(&job).unwrap();
+
+        let rendered = AudioDecoder::decode(&output).unwrap();
+        std::fs::remove_dir_all(&dir).ok();
+
+        // Latency removed, tail kept
+        assert_eq!(rendered.frames(), frames + 480);
+        assert!(rendered.samples[0].abs() < 1e-3);
+
+        // Steady state sits at the ceiling (skip the limiter's attack)
+        let steady = &rendered.samples[9600 * 2..frames * 2];
+        let peak_db = 20.0 * steady.iter().fold(0.0_f64, |m, x| m.max(x.abs())).log10();
+        assert!((peak_db - (-3.0)).abs() < 0.3, "peak {peak_db:.2} dBFS");
+        assert!(result.peak_level <= -2.7);
+    }
+
+    #[test]
+    fn test_batch_runs_and_plans_job_and_batch_chains() {
+        let dir = std::env::temp_dir().join(format!("rf_offline_batch_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("in.wav");
+        write_sine(&input, 4800);
+
+        // Batch chain +6 dB; the second job brings its own -6 dB chain
+        let batch = BatchProcessor::new(OfflineConfig::default())
+            .with_processors(ProcessorChain::new().add(GainProcessor::new(6.0)))
+            .with_output_format(OutputFormat::wav_32f())
+            .with_max_parallel(2);
+        let shared = OfflineJob::builder()
+            .input(&input)
+            .output(dir.join("shared.wav"))
+            .build()
+            .unwrap();
+        let mut own = OfflineJob::builder()
+            .input(&input)
+            .output(dir.join("own.wav"))
+            .build()
+            .unwrap();
+        own.add_processor(Box::new(TestGain(0.5)));
+        let jobs = [shared, own];
+
+        let plans = batch.dry_run(&jobs);
+        assert_eq!(plans[0].chain, ["Gain", "Encode WAV"]);
+        assert_eq!(plans[1].chain, ["DSP", "Encode WAV"]);
+
+        let results = batch.process_all(&jobs);
+        let peaks: Vec<f64> = jobs
+            .iter()
+            .map(|job| AudioDecoder::decode(&job.output_path).unwrap().peak_db())
+            .collect();
+        std::fs::remove_dir_all(&dir).ok();
+
+        assert!(results.iter().all(|r| r.error.is_none()), "{:?}", results);
+        assert!((peaks[0] - (-6.0)).abs() < 0.1, "batch chain {:.2}", peaks[0]);
+        assert!((peaks[1] - (-18.0)).abs() < 0.1, "job chain {:.2}", peaks[1]);
+    }
+
+    #[test]
+    fn test_dry_run_reports_missing_input_and_estimates() {
+        let dir = std::env::temp_dir().join(format!("rf_offline_dry_run_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("in.wav");
+
+        // 1 s stereo 16-bit at 44.1 kHz
+        let spec = hound::WavSpec {
+            channels: 2,
+            sample_rate: 44100,
+            bits_per_sample: 16,
+            sample_format: hound::SampleFormat::Int,
+        };
+        let mut writer = hound::WavWriter::create(&input, spec).unwrap();
+        for _ in 0..44100 * 2 {
+            writer.write_sample(0i16).unwrap();
+        }
+        writer.finalize().unwrap();
+
+        let valid = OfflineJob::builder()
+            .input(&input)
+            .output(dir.join("out.wav"))
+            .sample_rate(48000)
+            .fade_out(4800)
+            .build()
+            .unwrap();
+        let missing = OfflineJob::builder()
+            .input(dir.join("missing.wav"))
+            .output(dir.join("missing_out.wav"))
+            .build()
+            .unwrap();
+
+        let plans = BatchProcessor::new(OfflineConfig::default())
+            .with_normalization(NormalizationMode::streaming())
+            .with_output_format(OutputFormat::wav_24())
+            .dry_run(&[valid, missing]);
+        std::fs::remove_dir_all(&dir).ok();
+
+        let plan = &plans[0];
+        assert!(plan.is_ready(), "{:?}", plan.error);
+        assert_eq!(plan.input_sample_rate, 44100);
+        assert_eq!(plan.output_sample_rate, 48000);
+        assert_eq!(plan.output_channels, 2);
+        assert_eq!(plan.output_frames, 48000);
+        assert!((plan.output_duration - 1.0).abs() < 1e-9);
+        assert_eq!(plan.estimated_size, 44 + 48000 * 2 * 3);
+        assert_eq!(
+            plan.chain,
+            [
+                "Fade out 4800 frames",
+                "Normalize Lufs { target_lufs: -14.0 }",
+                "Resample 44100 -> 48000 Hz",
+                "Encode WAV",
+            ]
+        );
+
+        let plan = &plans[1];
+        assert!(!plan.is_ready());
+        assert!(plan.error.as_ref().unwrap().contains("not found"));
+        assert_eq!(plan.estimated_size, 0);
+    }
+
     #[test]
     fn test_audio_buffer_gain() {
         let mut buffer = AudioBuffer {
@@ -1,5 +1,6 @@
 //! Offline DSP processors
 
+use rf_dsp::StereoProcessor;
 use serde::{Deserialize, Serialize};
 
 /// Trait for offline processors
@@ -49,6 +50,11 @@ impl ProcessorChain {
         self
     }
 
+    /// Append a boxed processor
+    pub fn push(&mut self, processor: Box<dyn OfflineProcessor>) {
+        self.processors.push(processor);
+    }
+
     /// Process samples through all processors (mono-compatible)
     pub fn process(&mut self, samples: &mut [f64], sample_rate: u32) {
         for processor in &mut self.processors {
@@ -80,6 +86,11 @@ impl ProcessorChain {
     pub fn is_empty(&self) -> bool {
         self.processors.is_empty()
     }
+
+    /// Processor names in processing order
+    pub fn names(&self) -> Vec<&'static str> {
+        self.processors.iter().map(|p| p.name()).collect()
+    }
 }
 
 /// Processor configuration (serializable)
@@ -187,6 +198,67 @@ impl OfflineProcessor for GainProcessor {
     }
 }
 
+/// Runs any rf-dsp stereo processor on interleaved audio.
+///
+/// Mono is processed as dual mono (left output kept); with more than two
+/// channels only the first pair is processed. The wrapped processor must
+/// already be configured for the file's sample rate.
+pub struct DspProcessor {
+    inner: Box<dyn StereoProcessor>,
+    channels: usize,
+    left: Vec<f64>,
+    right: Vec<f64>,
+}
+
+impl DspProcessor {
+    pub fn new(inner: Box<dyn StereoProcessor>) -> Self {
+        Self {
+            inner,
+            channels: 2,
+            left: Vec::new(),
+            right: Vec::new(),
+        }
+    }
+}
+
+impl OfflineProcessor for DspProcessor {
+    fn process(&mut self, samples: &mut [f64], _sample_rate: u32) {
+        let ch = self.channels.max(1);
+
+        self.left.clear();
+        self.right.clear();
+        for frame in samples.chunks_exact(ch) {
+            self.left.push(frame[0]);
+            self.right.push(frame[ch.min(2) - 1]);
+        }
+
+        self.inner.process_block(&mut self.left, &mut self.right);
+
+        for (i, frame) in samples.chunks_exact_mut(ch).enumerate() {
+            frame[0] = self.left[i];
+            if ch > 1 {
+                frame[1] = self.right[i];
+            }
+        }
+    }
+
+    fn set_channels(&mut self, channels: usize) {
+        self.channels = channels;
+    }
+
+    fn reset(&mut self) {
+        self.inner.reset();
+    }
+
+    fn latency(&self) -> usize {
+        self.inner.latency()
+    }
+
+    fn name(&self) -> &'static str {
+        "DSP"
+    }
+}
+
 /// DC offset removal using high-pass filter.
 /// Coefficient is sample-rate adaptive: cutoff ≈ 5 Hz regardless of SR.
 /// Formula: coeff = 1 - (2π × cutoff_hz / sample_rate)
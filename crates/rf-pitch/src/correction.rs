@@ -29,16 +29,14 @@ pub struct CorrectionConfig {
     pub mode: CorrectionMode,
     /// Target scale (for Scale mode)
     pub scale: Option<Scale>,
-    /// Correction speed (0 = slow/natural, 1 = instant)
-    pub speed: f32,
+    /// Retune speed (ms to reach the target; 0 = instant/robotic, ~100+ = natural)
+    pub retune_speed_ms: f32,
     /// Correction amount (0 = none, 1 = full)
     pub amount: f32,
-    /// Preserve vibrato
+    /// Preserve vibrato (correct only the slow pitch center)
     pub preserve_vibrato: bool,
-    /// Vibrato depth threshold (cents)
-    pub vibrato_threshold: f32,
-    /// Note transition smoothing (seconds)
-    pub transition_time: f32,
+    /// Modulation faster than this rate (Hz) counts as vibrato
+    pub vibrato_rate_threshold: f32,
     /// Tolerance before correction kicks in (cents)
     pub tolerance: f32,
     /// Humanization amount (adds slight randomness)
@@ -50,11 +48,10 @@ impl Default for CorrectionConfig {
         Self {
             mode: CorrectionMode::Chromatic,
             scale: None,
-            speed: 0.5,
+            retune_speed_ms: 50.0,
             amount: 1.0,
             preserve_vibrato: true,
-            vibrato_threshold: 30.0,
-            transition_time: 0.05,
+            vibrato_rate_threshold: 3.0,
             tolerance: 10.0,
             humanize: 0.0,
         }
@@ -62,27 +59,29 @@ impl Default for CorrectionConfig {
 }
 
 /// Real-time pitch corrector
+///
+/// `process` is called once per pitch frame; `sample_rate` is the frame rate.
+/// The input is split into a slow pitch center (2-pole lowpass below the
+/// vibrato rate threshold) and faster modulation. Only the center is pulled
+/// toward the target; modulation is added back when vibrato is preserved.
 pub struct PitchCorrector {
     /// Configuration
     config: CorrectionConfig,
-    /// Sample rate
+    /// Sample rate (pitch frames per second)
     sample_rate: u32,
     /// Current target pitch (MIDI)
     target_pitch: f32,
-    /// Current smoothed pitch
-    smoothed_pitch: f32,
-    /// Vibrato detector state
-    vibrato_phase: f32,
-    /// Vibrato depth (cents)
+    /// Pitch center lowpass stages
+    center_stage1: f32,
+    center_stage2: f32,
+    /// Applied correction offset (semitones)
+    correction: f32,
+    /// Vibrato depth estimate (cents, peak)
     vibrato_depth: f32,
-    /// Vibrato rate (Hz)
-    vibrato_rate: f32,
-    /// Pitch history for vibrato detection
-    pitch_history: Vec<f32>,
-    /// History write index
-    history_idx: usize,
-    /// Correction envelope
-    correction_envelope: f32,
+    /// Frames processed (humanize phase)
+    frame_count: u64,
+    /// Started tracking
+    initialized: bool,
 }
 
 impl PitchCorrector {
@@ -92,13 +91,12 @@ impl PitchCorrector {
             config: CorrectionConfig::default(),
             sample_rate,
             target_pitch: 0.0,
-            smoothed_pitch: 0.0,
-            vibrato_phase: 0.0,
+            center_stage1: 0.0,
+            center_stage2: 0.0,
+            correction: 0.0,
             vibrato_depth: 0.0,
-            vibrato_rate: 0.0,
-            pitch_history: vec![0.0; 100],
-            history_idx: 0,
-            correction_envelope: 0.0,
+            frame_count: 0,
+            initialized: false,
         }
     }
 
@@ -118,68 +116,78 @@ impl PitchCorrector {
         self.config.mode = CorrectionMode::Scale;
     }
 
+    /// Set retune speed (ms)
+    pub fn set_retune_speed_ms(&mut self, ms: f32) {
+        self.config.retune_speed_ms = ms.max(0.0);
+    }
+
+    /// Enable/disable vibrato preservation
+    pub fn set_preserve_vibrato(&mut self, preserve: bool) {
+        self.config.preserve_vibrato = preserve;
+    }
+
+    /// Current vibrato depth estimate (cents)
+    pub fn vibrato_depth(&self) -> f32 {
+        self.vibrato_depth
+    }
+
+    /// Current target pitch (MIDI)
+    pub fn target_pitch(&self) -> f32 {
+        self.target_pitch
+    }
+
     /// Process pitch value and return corrected pitch
     pub fn process(&mut self, input_pitch: f32) -> f32 {
         if self.config.mode == CorrectionMode::Off {
             return input_pitch;
         }
 
-        // Initialize smoothed pitch on first call
-        if self.smoothed_pitch == 0.0 && input_pitch != 0.0 {
-            self.smoothed_pitch = input_pitch;
+        // Initialize center on first voiced frame
+        if !self.initialized && input_pitch != 0.0 {
+            self.center_stage1 = input_pitch;
+            self.center_stage2 = input_pitch;
             self.target_pitch = self.get_target_pitch(input_pitch);
-            self.pitch_history.fill(input_pitch);
+            self.initialized = true;
         }
-
-        // Update pitch history for vibrato detection
-        self.pitch_history[self.history_idx] = input_pitch;
-        self.history_idx = (self.history_idx + 1) % self.pitch_history.len();
-
-        // Detect vibrato (only if we have enough history)
-        let vibrato_component = if self.config.preserve_vibrato {
-            self.detect_vibrato(input_pitch)
+        self.frame_count += 1;
+
+        // Split into slow center + vibrato-rate modulation
+        let (base_pitch, vibrato_component) = if self.config.preserve_vibrato {
+            let center = self.track_center(input_pitch);
+            let modulation = input_pitch - center;
+            self.vibrato_depth += (modulation.abs() * 100.0 - self.vibrato_depth) * 0.01;
+            (center, modulation)
         } else {
-            0.0
+            (input_pitch, 0.0)
         };
 
         // Get target pitch (quantized)
-        let base_pitch = input_pitch - vibrato_component;
         let target = self.get_target_pitch(base_pitch);
+        self.target_pitch = target;
 
-        // Check if within tolerance
-        let error_cents = (target - base_pitch).abs() * 100.0; // semitones to cents approx
-        if error_cents < self.config.tolerance {
-            return input_pitch;
-        }
-
-        // Detect note change
-        if (self.target_pitch - target).abs() > 0.5 {
-            self.target_pitch = target;
-            self.correction_envelope = 0.0;
-        }
-
-        // Smooth target transitions
-        let transition_samples = (self.config.transition_time * self.sample_rate as f32).max(1.0);
-        let alpha = 1.0 / transition_samples;
-        self.smoothed_pitch += (target - self.smoothed_pitch) * alpha;
-
-        // Apply correction with speed control
-        let correction_alpha = self.config.speed; // Direct speed mapping
-        self.correction_envelope += (1.0 - self.correction_envelope) * correction_alpha;
-        self.correction_envelope = self.correction_envelope.min(1.0);
-
-        // Interpolate from input towards target
-        let corrected_base = base_pitch + (target - base_pitch) * self.correction_envelope;
+        // Desired offset, zero within tolerance
+        let error = target - base_pitch;
+        let desired = if error.abs() * 100.0 < self.config.tolerance {
+            0.0
+        } else {
+            error * self.config.amount
+        };
 
-        // Apply correction amount
-        let final_base = base_pitch + (corrected_base - base_pitch) * self.config.amount;
+        // Retune speed: one-pole glide toward the desired offset
+        let retune_frames = self.config.retune_speed_ms * 0.001 * self.sample_rate as f32;
+        let alpha = if retune_frames <= 1.0 {
+            1.0
+        } else {
+            1.0 - (-1.0 / retune_frames).exp()
+        };
+        self.correction += (desired - self.correction) * alpha;
 
         // Add back vibrato
-        let mut final_pitch = final_base + vibrato_component;
+        let mut final_pitch = base_pitch + self.correction + vibrato_component;
 
         // Humanization
         if self.config.humanize > 0.0 {
-            let noise = (self.history_idx as f32 * 0.1).sin() * 0.01;
+            let noise = (self.frame_count as f32 * 0.1).sin() * 0.01;
             final_pitch += noise * self.config.humanize;
         }
 
@@ -202,58 +210,24 @@ impl PitchCorrector {
         }
     }
 
-    /// Detect vibrato in pitch signal
-    fn detect_vibrato(&mut self, current_pitch: f32) -> f32 {
-        // Simple vibrato detection: find oscillation around mean
-        let mean: f32 = self.pitch_history.iter().sum::<f32>() / self.pitch_history.len() as f32;
-
-        // Deviation from mean (in semitones)
-        let deviation = current_pitch - mean;
-
-        // Check if deviation is within vibrato range
-        let deviation_cents = deviation.abs() * 100.0;
-        if deviation_cents > self.config.vibrato_threshold
-            && deviation_cents < self.config.vibrato_threshold * 4.0
-        {
-            // Estimate vibrato parameters from zero crossings
-            let mut zero_crossings = 0;
-            let mut prev = self.pitch_history[0] - mean;
-
-            for &p in &self.pitch_history[1..] {
-                let curr = p - mean;
-                if prev * curr < 0.0 {
-                    zero_crossings += 1;
-                }
-                prev = curr;
-            }
-
-            // Update vibrato rate estimate
-            let period_samples = if zero_crossings > 0 {
-                self.pitch_history.len() as f32 * 2.0 / zero_crossings as f32
-            } else {
-                0.0
-            };
-
-            if period_samples > 0.0 {
-                self.vibrato_rate = self.sample_rate as f32 / period_samples;
-                self.vibrato_depth = deviation_cents;
-
-                // Return the vibrato component
-                return deviation;
-            }
-        }
-
-        0.0
+    /// Track slow pitch center (2-pole lowpass at half the vibrato threshold)
+    fn track_center(&mut self, pitch: f32) -> f32 {
+        let cutoff = (self.config.vibrato_rate_threshold * 0.5).max(0.1);
+        let alpha = 1.0 - (-2.0 * std::f32::consts::PI * cutoff / self.sample_rate as f32).exp();
+        self.center_stage1 += (pitch - self.center_stage1) * alpha;
+        self.center_stage2 += (self.center_stage1 - self.center_stage2) * alpha;
+        self.center_stage2
     }
 
     /// Reset corrector state
     pub fn reset(&mut self) {
         self.target_pitch = 0.0;
-        self.smoothed_pitch = 0.0;
-        self.vibrato_phase = 0.0;
-        self.pitch_history.fill(0.0);
-        self.history_idx = 0;
-        self.correction_envelope = 0.0;
+        self.center_stage1 = 0.0;
+        self.center_stage2 = 0.0;
+        self.correction = 0.0;
+        self.vibrato_depth = 0.0;
+        self.frame_count = 0;
+        self.initialized = false;
     }
 }
 
@@ -442,7 +416,7 @@ mod tests {
         let mut corrector = PitchCorrector::new(48000);
         corrector.set_config(CorrectionConfig {
             mode: CorrectionMode::Chromatic,
-            speed: 1.0,
+            retune_speed_ms: 0.0,
             amount: 1.0,
             tolerance: 0.0,
             preserve_vibrato: false, // Disable vibrato detection for cleaner test
@@ -469,7 +443,7 @@ mod tests {
         corrector.set_config(CorrectionConfig {
             mode: CorrectionMode::Scale,
             scale: Some(Scale::major(0)),
-            speed: 1.0,
+            retune_speed_ms: 0.0,
             amount: 1.0,
             tolerance: 0.0,
             preserve_vibrato: false,
@@ -489,6 +463,52 @@ mod tests {
         );
     }
 
+    /// 5 Hz vibrato (±50 cents) around a sharp C4, pitch frames @ 1 kHz
+    fn run_vibrato(corrector: &mut PitchCorrector) -> (f32, f32) {
+        let rate = 1000.0;
+        let mut out = Vec::new();
+        for i in 0..3000 {
+            let t = i as f32 / rate;
+            let input = 60.3 + 0.5 * (2.0 * std::f32::consts::PI * 5.0 * t).sin();
+            let y = corrector.process(input);
+            if i >= 1000 {
+                out.push(y);
+            }
+        }
+        let mean = out.iter().sum::<f32>() / out.len() as f32;
+        let max = out.iter().cloned().fold(f32::MIN, f32::max);
+        let min = out.iter().cloned().fold(f32::MAX, f32::min);
+        (mean, (max - min) * 0.5)
+    }
+
+    #[test]
+    fn test_retune_preserves_vibrato() {
+        let config = CorrectionConfig {
+            mode: CorrectionMode::Scale,
+            scale: Some(Scale::major(0)),
+            retune_speed_ms: 20.0,
+            tolerance: 0.0,
+            preserve_vibrato: true,
+            ..Default::default()
+        };
+
+        let mut natural = PitchCorrector::new(1000);
+        natural.set_config(config.clone());
+        let (center, depth) = run_vibrato(&mut natural);
+        assert!((center - 60.0).abs() < 0.05, "center: {}", center);
+        assert!(depth > 0.4, "vibrato should survive: {}", depth);
+        assert!(natural.vibrato_depth() > 20.0);
+
+        // Robotic: instant retune without preservation flattens the vibrato
+        let mut robotic = PitchCorrector::new(1000);
+        robotic.set_config(config);
+        robotic.set_retune_speed_ms(0.0);
+        robotic.set_preserve_vibrato(false);
+        let (center, depth) = run_vibrato(&mut robotic);
+        assert!((center - 60.0).abs() < 0.05, "center: {}", center);
+        assert!(depth < 0.05, "vibrato should be flattened: {}", depth);
+    }
+
     #[test]
     fn test_note_corrector() {
         let config = PitchConfig::default();
@@ -175,12 +175,12 @@ impl Scale {
                 let dist_up = (note_in_octave - (semitone as f32 + 12.0)).abs();
                 if dist_up < min_dist {
                     min_dist = dist_up;
-                    best_note = semitone as f32;
+                    best_note = semitone as f32 + 12.0;
                 }
                 let dist_down = (note_in_octave - (semitone as f32 - 12.0)).abs();
                 if dist_down < min_dist {
                     min_dist = dist_down;
-                    best_note = semitone as f32;
+                    best_note = semitone as f32 - 12.0;
                 }
             }
         }
@@ -452,6 +452,9 @@ mod tests {
 
         // D should stay D
         assert_eq!(scale.quantize(62.0), 62.0);
+
+        // Just below C wraps up to the next octave's C
+        assert_eq!(scale.quantize(59.8), 60.0);
     }
 
     #[test]
@@ -211,6 +211,7 @@ void au_host_scan_plugins(void* user_data, AUHostScanCallback callback) {
 //   - au_render_set_param() → AudioUnitSetParameter() (audio-thread safe for most AUs)
 //   - au_render_get_latency() → kAudioUnitProperty_Latency
 //   - au_render_query_params() → enumerate real AU parameters via kAudioUnitProperty_ParameterList
+//   - au_render_query_param_tree() → parameter groups (AUv3 AUParameterTree / AUv2 clumps) + value strings
 //   - au_render_destroy() → AudioUnitUninitialize() + AudioComponentInstanceDispose()
 // ─────────────────────────────────────────────────────────────────────────────
 
@@ -591,6 +592,121 @@ void au_render_query_params(void* handle, void* user_data, AURenderParamCallback
     free(param_ids);
 }
 
+// Parameter tree callback type.
+// group_path and value_strings are joined with AU_TREE_SEPARATOR ("" = top level / continuous).
+typedef void (*AURenderParamTreeCallback)(
+    void*       user_data,
+    uint32_t    param_id,
+    const char* group_path,
+    const char* value_strings
+);
+
+// ASCII unit separator — cannot appear in display names
+#define AU_TREE_SEPARATOR @"\x1f"
+
+// Recursively report every parameter under an AUv3 group with its group path.
+static void _au_walk_param_group(AUParameterGroup* group, NSArray<NSString*>* path,
+                                 void* user_data, AURenderParamTreeCallback callback) {
+    for (AUParameterNode* node in group.children) {
+        if ([node isKindOfClass:[AUParameterGroup class]]) {
+            _au_walk_param_group((AUParameterGroup*)node,
+                                 [path arrayByAddingObject:node.displayName],
+                                 user_data, callback);
+        } else if ([node isKindOfClass:[AUParameter class]]) {
+            AUParameter* param = (AUParameter*)node;
+            NSString* joined_path = [path componentsJoinedByString:AU_TREE_SEPARATOR];
+            NSString* joined_values = param.valueStrings
+                ? [param.valueStrings componentsJoinedByString:AU_TREE_SEPARATOR]
+                : @"";
+            callback(user_data, (uint32_t)param.address,
+                     joined_path.UTF8String, joined_values.UTF8String);
+        }
+    }
+}
+
+// Enumerate the parameter hierarchy. Call from non-audio thread.
+// AUv3: walks AUParameterTree (nested groups) on a metadata-only AUAudioUnit instance.
+// AUv2: one level of clumps (kAudioUnitProperty_ParameterClumpName) + indexed value strings.
+void au_render_query_param_tree(void* handle, void* user_data, AURenderParamTreeCallback callback) {
+    if (!handle || !callback) return;
+    AURenderCtx* ctx = (AURenderCtx*)handle;
+
+    @autoreleasepool {
+        AudioComponent comp = AudioComponentInstanceGetComponent(ctx->au);
+        AudioComponentDescription desc;
+        if (comp && AudioComponentGetDescription(comp, &desc) == noErr
+            && (desc.componentFlags & kAudioComponentFlag_IsV3AudioUnit)) {
+            NSError* error = nil;
+            AUAudioUnit* unit = [[AUAudioUnit alloc] initWithComponentDescription:desc
+                                                                          options:kAudioComponentInstantiation_LoadInProcess
+                                                                            error:&error];
+            if (unit && unit.parameterTree) {
+                _au_walk_param_group(unit.parameterTree, @[], user_data, callback);
+                return;
+            }
+            NSLog(@"[au_render] AUv3 parameter tree unavailable (%@) — using clumps", error);
+        }
+
+        UInt32 list_size = 0;
+        OSStatus st = AudioUnitGetPropertyInfo(ctx->au, kAudioUnitProperty_ParameterList,
+                                               kAudioUnitScope_Global, 0, &list_size, NULL);
+        if (st != noErr || list_size == 0) return;
+
+        uint32_t param_count = list_size / sizeof(AudioUnitParameterID);
+        AudioUnitParameterID* param_ids = (AudioUnitParameterID*)malloc(list_size);
+        if (!param_ids) return;
+
+        st = AudioUnitGetProperty(ctx->au, kAudioUnitProperty_ParameterList,
+                                  kAudioUnitScope_Global, 0, param_ids, &list_size);
+        if (st != noErr) { free(param_ids); return; }
+
+        AudioUnitParameterInfo info;
+        for (uint32_t i = 0; i < param_count; i++) {
+            memset(&info, 0, sizeof(info));
+            UInt32 info_size = sizeof(AudioUnitParameterInfo);
+            st = AudioUnitGetProperty(ctx->au, kAudioUnitProperty_ParameterInfo,
+                                      kAudioUnitScope_Global, param_ids[i], &info, &info_size);
+            if (st != noErr) continue;
+            if ((info.flags & kAudioUnitParameterFlag_CFNameRelease) && info.cfNameString) {
+                CFRelease(info.cfNameString);
+            }
+
+            NSString* group_path = @"";
+            if (info.flags & kAudioUnitParameterFlag_HasClump) {
+                AudioUnitParameterNameInfo clump = {
+                    .inID = info.clumpID,
+                    .inDesiredLength = kAudioUnitParameterName_Full,
+                    .outName = NULL
+                };
+                UInt32 clump_size = sizeof(clump);
+                if (AudioUnitGetProperty(ctx->au, kAudioUnitProperty_ParameterClumpName,
+                                         kAudioUnitScope_Global, 0, &clump, &clump_size) == noErr
+                    && clump.outName) {
+                    group_path = [NSString stringWithString:(__bridge NSString*)clump.outName];
+                    CFRelease(clump.outName);
+                }
+            }
+
+            NSString* value_strings = @"";
+            if (info.unit == kAudioUnitParameterUnit_Indexed) {
+                CFArrayRef strings = NULL;
+                UInt32 strings_size = sizeof(strings);
+                if (AudioUnitGetProperty(ctx->au, kAudioUnitProperty_ParameterValueStrings,
+                                         kAudioUnitScope_Global, param_ids[i],
+                                         &strings, &strings_size) == noErr && strings) {
+                    value_strings = [(__bridge NSArray*)strings componentsJoinedByString:AU_TREE_SEPARATOR];
+                    CFRelease(strings);
+                }
+            }
+
+            callback(user_data, (uint32_t)param_ids[i],
+                     group_path.UTF8String, value_strings.UTF8String);
+        }
+
+        free(param_ids);
+    }
+}
+
 // Reset the AU render instance (clear internal state, keep setup).
 void au_render_reset(void* handle) {
     if (!handle) return;
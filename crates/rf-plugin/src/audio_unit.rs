@@ -73,6 +73,21 @@ unsafe extern "C" {
         ),
     );
 
+    /// Enumerate the AU parameter hierarchy — calls `callback` once per parameter
+    /// with its group path and (for indexed parameters) value strings, both
+    /// joined by `TREE_FIELD_SEPARATOR`. AUv3 units report nested
+    /// `AUParameterGroup`s; AUv2 units report one level of clumps.
+    fn au_render_query_param_tree(
+        handle: *mut c_void,
+        user_data: *mut c_void,
+        callback: unsafe extern "C" fn(
+            user_data: *mut c_void,
+            param_id: u32,
+            group_path: *const c_char,
+            value_strings: *const c_char,
+        ),
+    );
+
     /// Send a single MIDI event to the AU instance.
     /// Uses MusicDeviceMIDIEvent — audio-thread safe, zero allocations.
     /// Returns 0 on success, non-zero OSStatus on failure (harmless for non-MIDI AUs).
@@ -215,6 +230,156 @@ unsafe extern "C" fn param_query_callback(
     });
 }
 
+// ─────────────────────────────────────────────────────────────────────────────
+// Parameter tree
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Separator between group names / value strings in `au_render_query_param_tree`
+const TREE_FIELD_SEPARATOR: char = '\u{1f}';
+
+/// Node of the hierarchical AU parameter tree
+///
+/// The flat `parameter_info` index stays the source of truth for automation;
+/// the tree only adds the grouping the AU declares (AUv3 groups / AUv2 clumps).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParamNode {
+    /// Parameter group
+    Group {
+        name: String,
+        children: Vec<ParamNode>,
+    },
+    /// Leaf parameter
+    Parameter {
+        /// Position in the flat `parameter_info` index
+        index: usize,
+        /// AU parameter ID
+        id: u32,
+        name: String,
+        /// Display strings for indexed (discrete) parameters, empty if continuous
+        value_strings: Vec<String>,
+    },
+}
+
+impl ParamNode {
+    /// Group or parameter name
+    pub fn name(&self) -> &str {
+        match self {
+            ParamNode::Group { name, .. } | ParamNode::Parameter { name, .. } => name,
+        }
+    }
+
+    /// Child nodes (empty for parameters)
+    pub fn children(&self) -> &[ParamNode] {
+        match self {
+            ParamNode::Group { children, .. } => children,
+            ParamNode::Parameter { .. } => &[],
+        }
+    }
+
+    /// Get or create the direct child group `name`
+    fn group_mut(&mut self, name: &str) -> &mut ParamNode {
+        let ParamNode::Group { children, .. } = self else {
+            unreachable!("parameters have no child groups");
+        };
+        let pos = children
+            .iter()
+            .position(|c| matches!(c, ParamNode::Group { name: n, .. } if n == name))
+            .unwrap_or_else(|| {
+                children.push(ParamNode::Group {
+                    name: name.to_string(),
+                    children: Vec::new(),
+                });
+                children.len() - 1
+            });
+        &mut children[pos]
+    }
+}
+
+/// Where a parameter sits in the AU hierarchy, as reported by the AU
+#[derive(Debug, Clone, Default, PartialEq)]
+struct ParamPlacement {
+    /// Enclosing groups, outermost first (empty = top level)
+    group_path: Vec<String>,
+    /// Value strings for indexed parameters
+    value_strings: Vec<String>,
+}
+
+impl ParamPlacement {
+    /// Parse the separator-joined fields reported over FFI
+    fn from_fields(group_path: &str, value_strings: &str) -> Self {
+        let split = |s: &str| -> Vec<String> {
+            s.split(TREE_FIELD_SEPARATOR)
+                .filter(|part| !part.is_empty())
+                .map(str::to_string)
+                .collect()
+        };
+        Self {
+            group_path: split(group_path),
+            value_strings: split(value_strings),
+        }
+    }
+}
+
+/// Build the parameter tree in flat-index order
+///
+/// Indexed parameters get `steps` set from their value strings so hosts that
+/// only read the flat index still treat them as discrete.
+fn build_param_tree(
+    root_name: &str,
+    params: &mut [ParameterInfo],
+    placements: &HashMap<u32, ParamPlacement>,
+) -> ParamNode {
+    let mut root = ParamNode::Group {
+        name: root_name.to_string(),
+        children: Vec::new(),
+    };
+
+    for (index, param) in params.iter_mut().enumerate() {
+        let placement = placements.get(&param.id).cloned().unwrap_or_default();
+        if placement.value_strings.len() > 1 {
+            param.steps = placement.value_strings.len() as u32 - 1;
+        }
+
+        let mut node = &mut root;
+        for group in &placement.group_path {
+            node = node.group_mut(group);
+        }
+        if let ParamNode::Group { children, .. } = node {
+            children.push(ParamNode::Parameter {
+                index,
+                id: param.id,
+                name: param.name.clone(),
+                value_strings: placement.value_strings,
+            });
+        }
+    }
+
+    root
+}
+
+#[cfg(target_os = "macos")]
+unsafe extern "C" fn param_tree_callback(
+    user_data: *mut c_void,
+    param_id: u32,
+    group_path: *const c_char,
+    value_strings: *const c_char,
+) {
+    let placements = unsafe { &mut *(user_data as *mut HashMap<u32, ParamPlacement>) };
+    let to_str = |ptr: *const c_char| {
+        if ptr.is_null() {
+            String::new()
+        } else {
+            unsafe { CStr::from_ptr(ptr) }
+                .to_string_lossy()
+                .into_owned()
+        }
+    };
+    placements.insert(
+        param_id,
+        ParamPlacement::from_fields(&to_str(group_path), &to_str(value_strings)),
+    );
+}
+
 /// AudioUnit component type
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u32)]
@@ -482,6 +647,8 @@ pub struct AudioUnitInstance {
     latency: AtomicU64,
     /// Real AU parameters (populated in initialize())
     parameters: Vec<ParameterInfo>,
+    /// Hierarchical view of `parameters` (populated in initialize())
+    param_tree: ParamNode,
     /// Parameter values (normalized 0-1 for non-AU fallback path)
     param_values: Vec<f64>,
     /// Pending parameter changes (for flush on next process())
@@ -560,6 +727,10 @@ impl AudioUnitInstance {
         };
 
         Ok(Self {
+            param_tree: ParamNode::Group {
+                name: info.name.clone(),
+                children: Vec::new(),
+            },
             info,
             descriptor,
             active: AtomicBool::new(false),
@@ -580,6 +751,12 @@ impl AudioUnitInstance {
         &self.descriptor
     }
 
+    /// Hierarchical parameter tree (groups and nested parameters) for the UI.
+    /// Automation keeps using the flat `parameter_info` index referenced by each leaf.
+    pub fn parameter_tree(&self) -> ParamNode {
+        self.param_tree.clone()
+    }
+
     /// Flush pending parameter changes to the AU (called at block start).
     /// Uses try_lock() — on the audio thread we MUST NOT block.
     /// If the queue is contended, changes are deferred to the next block (~5ms worst case).
@@ -668,6 +845,18 @@ impl PluginInstance for AudioUnitInstance {
                     self.parameters = query.params;
                 }
 
+                // Query groups / value strings and build the hierarchical view
+                let mut placements: HashMap<u32, ParamPlacement> = HashMap::new();
+                unsafe {
+                    au_render_query_param_tree(
+                        handle,
+                        &mut placements as *mut HashMap<u32, ParamPlacement> as *mut c_void,
+                        param_tree_callback,
+                    );
+                }
+                self.param_tree =
+                    build_param_tree(&self.info.name, &mut self.parameters, &placements);
+
                 // Read initial latency
                 let latency = unsafe { au_render_get_latency(handle) };
                 self.latency.store(latency as u64, Ordering::SeqCst);
@@ -963,6 +1152,83 @@ mod tests {
         assert!(id.contains("aufx"));
     }
 
+    fn mock_param(id: u32, name: &str) -> ParameterInfo {
+        ParameterInfo {
+            id,
+            name: name.to_string(),
+            unit: String::new(),
+            min: 0.0,
+            max: 1.0,
+            default: 0.0,
+            normalized: 0.0,
+            steps: 0,
+            automatable: true,
+            read_only: false,
+        }
+    }
+
+    #[test]
+    fn test_param_tree_two_groups() {
+        let sep = TREE_FIELD_SEPARATOR.to_string();
+        let waves = ["Saw", "Square", "Sine"].join(&sep);
+        let envelope_path = ["Filter", "Envelope"].join(&sep);
+        let mut params = vec![
+            mock_param(10, "Wave"),
+            mock_param(11, "Detune"),
+            mock_param(20, "Cutoff"),
+            mock_param(21, "Attack"),
+            mock_param(30, "Master"),
+        ];
+        let placements: HashMap<u32, ParamPlacement> = [
+            (10, ParamPlacement::from_fields("Oscillator", &waves)),
+            (11, ParamPlacement::from_fields("Oscillator", "")),
+            (20, ParamPlacement::from_fields("Filter", "")),
+            (21, ParamPlacement::from_fields(&envelope_path, "")),
+        ]
+        .into_iter()
+        .collect();
+
+        let tree = build_param_tree("Synth", &mut params, &placements);
+        assert_eq!(tree.name(), "Synth");
+
+        let names: Vec<&str> = tree.children().iter().map(|c| c.name()).collect();
+        assert_eq!(names, ["Oscillator", "Filter", "Master"]);
+
+        let osc = &tree.children()[0];
+        assert_eq!(osc.children().len(), 2);
+        match &osc.children()[0] {
+            ParamNode::Parameter {
+                index,
+                id,
+                value_strings,
+                ..
+            } => {
+                assert_eq!((*index, *id), (0, 10));
+                assert_eq!(value_strings, &["Saw", "Square", "Sine"]);
+            }
+            other => panic!("expected parameter, got {:?}", other),
+        }
+
+        let filter = &tree.children()[1];
+        let filter_names: Vec<&str> = filter.children().iter().map(|c| c.name()).collect();
+        assert_eq!(filter_names, ["Cutoff", "Envelope"]);
+        let envelope = &filter.children()[1];
+        assert!(matches!(envelope, ParamNode::Group { .. }));
+        assert!(matches!(
+            envelope.children(),
+            [ParamNode::Parameter {
+                index: 3,
+                id: 21,
+                ..
+            }]
+        ));
+
+        // Flat index is preserved; indexed parameter becomes discrete
+        assert_eq!(params.len(), 5);
+        assert_eq!(params[0].steps, 2);
+        assert_eq!(params[1].steps, 0);
+    }
+
     #[cfg(target_os = "macos")]
     #[test]
     fn test_au_instance_creation() {
@@ -103,6 +103,7 @@ pub use ara2::{
     AraPlaybackRegionId, AraPlaybackTransformation, AraPluginExtension, AraPluginType,
     AraRegionSequenceId, AraTransformationFlags,
 };
+pub use rf_dsp::ProcessContext;
 pub use sandbox::{SandboxConfig, SandboxError, SandboxManager, SandboxedPlugin, SandboxedPluginAdapter};
 pub use scanner::{PluginCategory, PluginInfo, PluginScanner, PluginType};
 pub use vst3::Vst3Host;
@@ -290,51 +291,6 @@ pub struct ParameterInfo {
     pub read_only: bool,
 }
 
-/// Plugin processing context
-#[derive(Debug, Clone)]
-pub struct ProcessContext {
-    /// Sample rate in Hz
-    pub sample_rate: f64,
-    /// Maximum block size
-    pub max_block_size: usize,
-    /// Current tempo in BPM
-    pub tempo: f64,
-    /// Time signature numerator
-    pub time_sig_num: u32,
-    /// Time signature denominator
-    pub time_sig_denom: u32,
-    /// Current playback position in samples
-    pub position_samples: i64,
-    /// Is playing
-    pub is_playing: bool,
-    /// Is recording
-    pub is_recording: bool,
-    /// Is looping
-    pub is_looping: bool,
-    /// Loop start in samples
-    pub loop_start: i64,
-    /// Loop end in samples
-    pub loop_end: i64,
-}
-
-impl Default for ProcessContext {
-    fn default() -> Self {
-        Self {
-            sample_rate: 48000.0,
-            max_block_size: 512,
-            tempo: 120.0,
-            time_sig_num: 4,
-            time_sig_denom: 4,
-            position_samples: 0,
-            is_playing: false,
-            is_recording: false,
-            is_looping: false,
-            loop_start: 0,
-            loop_end: 0,
-        }
-    }
-}
-
 /// Plugin instance trait - common interface for all plugin formats
 pub trait PluginInstance: Send + Sync {
     /// Get plugin info
@@ -114,22 +114,33 @@ struct Lv2UridUnmap {
     unmap: unsafe extern "C" fn(handle: *mut c_void, urid: u32) -> *const c_char,
 }
 
-/// URID map callback — called by plugins to map URI → integer
-unsafe extern "C" fn urid_map_callback(_handle: *mut c_void, uri: *const c_char) -> u32 {
-    if uri.is_null() { return 0; }
-    let uri_str = unsafe { CStr::from_ptr(uri) }.to_string_lossy().to_string();
+/// Map URI → URID, allocating a new URID on first use
+fn map_uri(uri: &str) -> u32 {
     // BUG#32: parking_lot::Mutex — no poisoning possible
     let mut map = URID_MAP.lock();
-    if let Some(&id) = map.uri_to_id.get(&uri_str) {
+    if let Some(&id) = map.uri_to_id.get(uri) {
         return id;
     }
     // Allocate new URID
     let id = map.id_to_uri.len() as u32 + 1;
-    map.uri_to_id.insert(uri_str.clone(), id);
-    map.id_to_uri.push(uri_str);
+    map.uri_to_id.insert(uri.to_string(), id);
+    map.id_to_uri.push(uri.to_string());
     id
 }
 
+/// Map URID → URI (None for 0 or unknown URIDs)
+fn unmap_urid(urid: u32) -> Option<String> {
+    let map = URID_MAP.lock();
+    map.id_to_uri.get((urid as usize).checked_sub(1)?).cloned()
+}
+
+/// URID map callback — called by plugins to map URI → integer
+unsafe extern "C" fn urid_map_callback(_handle: *mut c_void, uri: *const c_char) -> u32 {
+    if uri.is_null() { return 0; }
+    let uri_str = unsafe { CStr::from_ptr(uri) }.to_string_lossy();
+    map_uri(&uri_str)
+}
+
 /// URID unmap callback — called by plugins to get URI from integer
 unsafe extern "C" fn urid_unmap_callback(_handle: *mut c_void, urid: u32) -> *const c_char {
     if urid == 0 { return std::ptr::null(); }
@@ -280,6 +291,147 @@ const LV2_URID_MAP_URI: &[u8] = b"http://lv2plug.in/ns/ext/urid#map\0";
 const LV2_URID_UNMAP_URI: &[u8] = b"http://lv2plug.in/ns/ext/urid#unmap\0";
 const LV2_STATE_INTERFACE_URI: &[u8] = b"http://lv2plug.in/ns/ext/state#interface\0";
 
+// ═══════════════════════════════════════════════════════════════════════════
+// LV2 STATE EXTENSION
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// LV2_State_Status values
+const LV2_STATE_SUCCESS: u32 = 0;
+const LV2_STATE_ERR_BAD_FLAGS: u32 = 3;
+const LV2_STATE_ERR_NO_PROPERTY: u32 = 5;
+
+/// LV2_State_Flags: value is plain old data / portable across hosts
+const LV2_STATE_IS_POD: u32 = 1;
+const LV2_STATE_IS_PORTABLE: u32 = 2;
+
+/// LV2_State_Store_Function — plugin hands one property to the host
+type Lv2StateStoreFn = unsafe extern "C" fn(
+    handle: *mut c_void,
+    key: u32,
+    value: *const c_void,
+    size: usize,
+    value_type: u32,
+    flags: u32,
+) -> u32;
+
+/// LV2_State_Retrieve_Function — plugin asks the host for one property
+type Lv2StateRetrieveFn = unsafe extern "C" fn(
+    handle: *mut c_void,
+    key: u32,
+    size: *mut usize,
+    value_type: *mut u32,
+    flags: *mut u32,
+) -> *const c_void;
+
+/// LV2_State_Interface — returned by extension_data(state#interface)
+#[repr(C)]
+struct Lv2StateInterface {
+    save: Option<
+        unsafe extern "C" fn(
+            instance: LV2Handle,
+            store: Lv2StateStoreFn,
+            handle: *mut c_void,
+            flags: u32,
+            features: *const *const Lv2Feature,
+        ) -> u32,
+    >,
+    restore: Option<
+        unsafe extern "C" fn(
+            instance: LV2Handle,
+            retrieve: Lv2StateRetrieveFn,
+            handle: *mut c_void,
+            flags: u32,
+            features: *const *const Lv2Feature,
+        ) -> u32,
+    >,
+}
+
+/// One saved state property. Keys and types are stored as URIs because
+/// URIDs are only valid within the process that mapped them.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+struct Lv2StateProperty {
+    key: String,
+    value_type: String,
+    flags: u32,
+    value: Vec<u8>,
+}
+
+/// Serialized plugin state (`get_state` / `set_state` payload)
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+struct Lv2SavedState {
+    /// Control port values in parameter order
+    port_values: Vec<f32>,
+    /// Properties stored through the State extension
+    #[serde(default)]
+    properties: Vec<Lv2StateProperty>,
+}
+
+/// Store callback — `handle` is a `Vec<Lv2StateProperty>`
+unsafe extern "C" fn state_store_callback(
+    handle: *mut c_void,
+    key: u32,
+    value: *const c_void,
+    size: usize,
+    value_type: u32,
+    flags: u32,
+) -> u32 {
+    // Only POD values can be serialized; anything else holds host-invalid pointers
+    if flags & LV2_STATE_IS_POD == 0 {
+        return LV2_STATE_ERR_BAD_FLAGS;
+    }
+    let (Some(key), Some(value_type)) = (unmap_urid(key), unmap_urid(value_type)) else {
+        return LV2_STATE_ERR_NO_PROPERTY;
+    };
+    let value = if value.is_null() || size == 0 {
+        Vec::new()
+    } else {
+        unsafe { std::slice::from_raw_parts(value as *const u8, size) }.to_vec()
+    };
+    let properties = unsafe { &mut *(handle as *mut Vec<Lv2StateProperty>) };
+    properties.retain(|p| p.key != key);
+    properties.push(Lv2StateProperty {
+        key,
+        value_type,
+        flags,
+        value,
+    });
+    LV2_STATE_SUCCESS
+}
+
+/// Property re-mapped to this process's URIDs for restore
+struct MappedStateProperty {
+    key: u32,
+    value_type: u32,
+    flags: u32,
+    value: Vec<u8>,
+}
+
+/// Retrieve callback — `handle` is a `Vec<MappedStateProperty>`
+unsafe extern "C" fn state_retrieve_callback(
+    handle: *mut c_void,
+    key: u32,
+    size: *mut usize,
+    value_type: *mut u32,
+    flags: *mut u32,
+) -> *const c_void {
+    let properties = unsafe { &*(handle as *const Vec<MappedStateProperty>) };
+    let Some(prop) = properties.iter().find(|p| p.key == key) else {
+        return std::ptr::null();
+    };
+    unsafe {
+        if !size.is_null() {
+            *size = prop.value.len();
+        }
+        if !value_type.is_null() {
+            *value_type = prop.value_type;
+        }
+        if !flags.is_null() {
+            *flags = prop.flags;
+        }
+    }
+    prop.value.as_ptr() as *const c_void
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // LV2 PORT TYPES
 // ═══════════════════════════════════════════════════════════════════════════
@@ -619,6 +771,152 @@ fn regex_lite_find(text: &str, pattern: &str) -> Option<String> {
     Some(capture_text[..end_idx].to_string())
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// LV2 PRESETS
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Bundled LV2 preset (pset:Preset)
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct PresetInfo {
+    /// Preset URI
+    pub uri: String,
+    /// Display name (rdfs:label, falls back to the URI)
+    pub label: String,
+    /// TTL file holding the preset's port values
+    pub path: PathBuf,
+}
+
+/// Split TTL into top-level statements (terminated by `.` outside IRIs,
+/// strings and `[ ]` blank nodes)
+fn ttl_statements(content: &str) -> Vec<&str> {
+    let mut statements = Vec::new();
+    let bytes = content.as_bytes();
+    let (mut start, mut depth) = (0, 0i32);
+    let (mut in_iri, mut in_str, mut in_comment) = (false, false, false);
+
+    for (i, &b) in bytes.iter().enumerate() {
+        match b {
+            b'\n' if in_comment => in_comment = false,
+            _ if in_comment => {}
+            b'"' if !in_iri => in_str = !in_str,
+            _ if in_str => {}
+            b'#' if !in_iri => in_comment = true,
+            b'<' => in_iri = true,
+            b'>' => in_iri = false,
+            _ if in_iri => {}
+            b'[' => depth += 1,
+            b']' => depth -= 1,
+            b'.' if depth == 0 && bytes.get(i + 1).is_none_or(|c| c.is_ascii_whitespace()) => {
+                let stmt = content[start..i].trim();
+                if !stmt.is_empty() {
+                    statements.push(stmt);
+                }
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    statements
+}
+
+/// Subject IRI of a statement (`<uri> ...`)
+fn ttl_subject(statement: &str) -> Option<&str> {
+    let rest = statement.strip_prefix('<')?;
+    Some(&rest[..rest.find('>')?])
+}
+
+/// First object following `predicate` (IRI, string or bare literal)
+fn ttl_object<'a>(statement: &'a str, predicate: &str) -> Option<&'a str> {
+    let rest = statement[statement.find(predicate)? + predicate.len()..].trim_start();
+    let (open, close) = match rest.chars().next()? {
+        '<' => ('<', '>'),
+        '"' => ('"', '"'),
+        _ => {
+            let end = rest
+                .find(|c: char| c.is_whitespace() || c == ';' || c == ',' || c == ']')
+                .unwrap_or(rest.len());
+            return Some(&rest[..end]);
+        }
+    };
+    let body = rest.strip_prefix(open)?;
+    Some(&body[..body.find(close)?])
+}
+
+fn ttl_is_preset(statement: &str) -> bool {
+    statement.contains("pset:Preset")
+        || statement.contains("<http://lv2plug.in/ns/ext/presets#Preset>")
+}
+
+/// Find presets declared in a bundle's manifest.ttl for `plugin_uri`
+fn scan_presets(bundle_path: &Path, plugin_uri: &str) -> Vec<PresetInfo> {
+    let Ok(manifest) = std::fs::read_to_string(bundle_path.join("manifest.ttl")) else {
+        return Vec::new();
+    };
+
+    let mut presets = Vec::new();
+    for stmt in ttl_statements(&manifest) {
+        if !ttl_is_preset(stmt) {
+            continue;
+        }
+        let Some(uri) = ttl_subject(stmt) else {
+            continue;
+        };
+        if let Some(applies_to) = ttl_object(stmt, "lv2:appliesTo")
+            && applies_to != plugin_uri
+        {
+            continue;
+        }
+
+        let path = ttl_object(stmt, "rdfs:seeAlso")
+            .map(|file| bundle_path.join(file))
+            .unwrap_or_else(|| bundle_path.join("manifest.ttl"));
+        // Label usually lives in the preset file, not the manifest
+        let label = ttl_object(stmt, "rdfs:label")
+            .map(str::to_string)
+            .or_else(|| {
+                let content = std::fs::read_to_string(&path).ok()?;
+                ttl_statements(&content)
+                    .into_iter()
+                    .find(|s| ttl_subject(s) == Some(uri))
+                    .and_then(|s| ttl_object(s, "rdfs:label"))
+                    .map(str::to_string)
+            })
+            .unwrap_or_else(|| uri.to_string());
+
+        presets.push(PresetInfo {
+            uri: uri.to_string(),
+            label,
+            path,
+        });
+    }
+    presets
+}
+
+/// Parse `lv2:port [ lv2:symbol "x" ; pset:value 0.5 ]` entries of a preset
+fn parse_preset_ports(content: &str, preset_uri: &str) -> Option<Vec<(String, f32)>> {
+    let stmt = ttl_statements(content)
+        .into_iter()
+        .find(|s| ttl_subject(s) == Some(preset_uri))?;
+
+    let mut ports = Vec::new();
+    let mut rest = stmt;
+    while let Some(open) = rest.find('[') {
+        let Some(close) = rest[open..].find(']') else {
+            break;
+        };
+        let node = &rest[open + 1..open + close];
+        if let (Some(symbol), Some(value)) = (
+            ttl_object(node, "lv2:symbol"),
+            ttl_object(node, "pset:value"),
+        ) && let Ok(value) = value.parse::<f32>()
+        {
+            ports.push((symbol.to_string(), value));
+        }
+        rest = &rest[open + close + 1..];
+    }
+    Some(ports)
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // LV2 HOST
 // ═══════════════════════════════════════════════════════════════════════════
@@ -887,6 +1185,10 @@ pub struct Lv2PluginInstance {
     sample_rate: f64,
     /// Bundle path CString for reinstantiation (BUG#33)
     _bundle_path_cstr: std::ffi::CString,
+    /// Bundle directory (preset discovery)
+    bundle_path: PathBuf,
+    /// State extension interface (null if unsupported)
+    state_interface: *const Lv2StateInterface,
     /// Pre-allocated Atom buffers for MIDI ports
     atom_input: Option<AtomBuffer>,
     atom_output: Option<AtomBuffer>,
@@ -977,6 +1279,16 @@ impl Lv2PluginInstance {
             ));
         }
 
+        Self::instantiate(desc, descriptor_ptr, Some(lib))
+    }
+
+    /// Instantiate a plugin from its C descriptor.
+    /// `library` keeps the owning dylib loaded for the instance lifetime.
+    fn instantiate(
+        desc: &Lv2Descriptor,
+        descriptor_ptr: *const Lv2PluginDescriptor,
+        library: Option<Arc<libloading::Library>>,
+    ) -> PluginResult<Self> {
         let descriptor_ref = unsafe { &*descriptor_ptr };
         let plugin_uri = unsafe { cstr_to_string(descriptor_ref.uri) };
 
@@ -1071,9 +1383,15 @@ impl Lv2PluginInstance {
         let sequence_urid = unsafe { urid_map_callback(std::ptr::null_mut(), c"http://lv2plug.in/ns/ext/atom#Sequence".as_ptr() as *const c_char) };
         let midi_event_urid = unsafe { urid_map_callback(std::ptr::null_mut(), c"http://lv2plug.in/ns/ext/midi#MidiEvent".as_ptr() as *const c_char) };
 
+        // State extension (optional — plain port values are saved either way)
+        let state_interface = descriptor_ref
+            .extension_data
+            .map(|ext_data| unsafe { ext_data(LV2_STATE_INTERFACE_URI.as_ptr() as *const c_char) })
+            .unwrap_or(std::ptr::null()) as *const Lv2StateInterface;
+
         Ok(Self {
             info,
-            _library: Some(lib),
+            _library: library,
             handle,
             descriptor: descriptor_ptr,
             ports: Vec::new(),
@@ -1084,6 +1402,8 @@ impl Lv2PluginInstance {
             instantiated_sample_rate: 48000.0,
             sample_rate: 48000.0,
             _bundle_path_cstr: bundle_path_cstr,  // BUG#33: kept for potential reinstantiation
+            bundle_path: desc.bundle_path.clone(),
+            state_interface,
             _urid_map: urid_map,
             _urid_unmap: urid_unmap,
             _feature_uris: feature_uris,
@@ -1171,6 +1491,117 @@ impl Lv2PluginInstance {
         }
     }
 
+    /// Presets bundled with this plugin (declared in its manifest.ttl)
+    pub fn list_presets(&self) -> Vec<PresetInfo> {
+        scan_presets(&self.bundle_path, &self.info.id)
+    }
+
+    /// Apply a bundled preset's port values by preset URI.
+    /// Ports are matched by lv2:symbol; unknown symbols are skipped.
+    pub fn load_preset(&mut self, uri: &str) -> PluginResult<()> {
+        let preset = self
+            .list_presets()
+            .into_iter()
+            .find(|p| p.uri == uri)
+            .ok_or_else(|| PluginError::NotFound(format!("LV2 preset {}", uri)))?;
+        let content = std::fs::read_to_string(&preset.path)?;
+        let values = parse_preset_ports(&content, uri).ok_or_else(|| {
+            PluginError::LoadFailed(format!("preset {} not found in {:?}", uri, preset.path))
+        })?;
+
+        for (symbol, value) in values {
+            let param = self
+                .ports
+                .iter()
+                .filter(|p| matches!(p.port_type, Lv2PortType::ControlInput))
+                .position(|p| p.symbol == symbol);
+            match param {
+                Some(id) => self.set_parameter(id as u32, value as f64)?,
+                None => log::debug!("LV2 preset {}: unknown port '{}'", uri, symbol),
+            }
+        }
+        Ok(())
+    }
+
+    /// Null-terminated feature list (same features the plugin was instantiated with)
+    fn feature_ptrs(&self) -> Vec<*const Lv2Feature> {
+        let mut features: Vec<*const Lv2Feature> = self
+            ._feature_structs
+            .iter()
+            .map(|f| f.as_ref() as *const Lv2Feature)
+            .collect();
+        features.push(std::ptr::null());
+        features
+    }
+
+    /// Collect properties through LV2_State_Interface::save
+    fn save_state_properties(&self) -> PluginResult<Vec<Lv2StateProperty>> {
+        let mut properties: Vec<Lv2StateProperty> = Vec::new();
+        if self.state_interface.is_null() || self.handle.is_null() {
+            return Ok(properties);
+        }
+        let Some(save) = (unsafe { (*self.state_interface).save }) else {
+            return Ok(properties);
+        };
+
+        let features = self.feature_ptrs();
+        let status = unsafe {
+            save(
+                self.handle,
+                state_store_callback,
+                &mut properties as *mut Vec<Lv2StateProperty> as *mut c_void,
+                LV2_STATE_IS_POD | LV2_STATE_IS_PORTABLE,
+                features.as_ptr(),
+            )
+        };
+        if status != LV2_STATE_SUCCESS {
+            return Err(PluginError::ProcessingError(format!(
+                "LV2 state save failed (status {})",
+                status
+            )));
+        }
+        Ok(properties)
+    }
+
+    /// Hand properties back through LV2_State_Interface::restore
+    fn restore_state_properties(&mut self, properties: &[Lv2StateProperty]) -> PluginResult<()> {
+        if self.state_interface.is_null() || self.handle.is_null() {
+            return Ok(());
+        }
+        let Some(restore) = (unsafe { (*self.state_interface).restore }) else {
+            return Ok(());
+        };
+
+        // Re-map URIs to this process's URIDs
+        let mapped: Vec<MappedStateProperty> = properties
+            .iter()
+            .map(|p| MappedStateProperty {
+                key: map_uri(&p.key),
+                value_type: map_uri(&p.value_type),
+                flags: p.flags,
+                value: p.value.clone(),
+            })
+            .collect();
+
+        let features = self.feature_ptrs();
+        let status = unsafe {
+            restore(
+                self.handle,
+                state_retrieve_callback,
+                &mapped as *const Vec<MappedStateProperty> as *mut c_void,
+                LV2_STATE_IS_POD | LV2_STATE_IS_PORTABLE,
+                features.as_ptr(),
+            )
+        };
+        if status != LV2_STATE_SUCCESS {
+            return Err(PluginError::ProcessingError(format!(
+                "LV2 state restore failed (status {})",
+                status
+            )));
+        }
+        Ok(())
+    }
+
     /// Send all current port values to the UI via port_event.
     /// Called after open_editor to sync UI display with actual plugin state.
     fn notify_ui_all_ports(&self) {
@@ -1450,11 +1881,46 @@ impl PluginInstance for Lv2PluginInstance {
     }
 
     fn get_state(&self) -> PluginResult<Vec<u8>> {
-        Ok(Vec::new()) // TODO: LV2 state extension
+        let state = Lv2SavedState {
+            port_values: self.port_values.clone(),
+            properties: self.save_state_properties()?,
+        };
+        serde_json::to_vec(&state).map_err(|e| PluginError::ProcessingError(e.to_string()))
     }
 
-    fn set_state(&mut self, _state: &[u8]) -> PluginResult<()> {
-        Ok(())
+    fn set_state(&mut self, state: &[u8]) -> PluginResult<()> {
+        if state.is_empty() {
+            return Ok(());
+        }
+        let state: Lv2SavedState = serde_json::from_slice(state)
+            .map_err(|e| PluginError::ProcessingError(format!("invalid LV2 state: {}", e)))?;
+
+        for (id, value) in state
+            .port_values
+            .iter()
+            .enumerate()
+            .take(self.port_values.len())
+        {
+            self.set_parameter(id as u32, *value as f64)?;
+        }
+        self.restore_state_properties(&state.properties)
+    }
+
+    fn preset_count(&self) -> usize {
+        self.list_presets().len()
+    }
+
+    fn preset_name(&self, index: usize) -> Option<String> {
+        self.list_presets().into_iter().nth(index).map(|p| p.label)
+    }
+
+    fn load_preset(&mut self, index: usize) -> PluginResult<()> {
+        let preset = self
+            .list_presets()
+            .into_iter()
+            .nth(index)
+            .ok_or_else(|| PluginError::NotFound(format!("LV2 preset #{}", index)))?;
+        Lv2PluginInstance::load_preset(self, &preset.uri)
     }
 
     fn latency(&self) -> usize {
@@ -1740,6 +2206,236 @@ mod tests {
         );
     }
 
+    // ── Mock plugin with State extension ──────────────────────────────────
+
+    const MOCK_URI: &str = "urn:rf:mock";
+
+    struct MockPlugin {
+        map: *const Lv2UridMap,
+        blob: Vec<u8>,
+    }
+
+    unsafe fn mock_urid(plugin: &MockPlugin, uri: &CStr) -> u32 {
+        unsafe { ((*plugin.map).map)((*plugin.map).handle, uri.as_ptr()) }
+    }
+
+    unsafe extern "C" fn mock_instantiate(
+        _descriptor: *const Lv2PluginDescriptor,
+        _sample_rate: f64,
+        _bundle_path: *const c_char,
+        features: *const *const Lv2Feature,
+    ) -> LV2Handle {
+        let mut map = std::ptr::null();
+        let mut i = 0;
+        unsafe {
+            while !(*features.add(i)).is_null() {
+                let feature = &**features.add(i);
+                if CStr::from_ptr(feature.uri).to_bytes_with_nul() == LV2_URID_MAP_URI {
+                    map = feature.data as *const Lv2UridMap;
+                }
+                i += 1;
+            }
+        }
+        Box::into_raw(Box::new(MockPlugin {
+            map,
+            blob: Vec::new(),
+        })) as LV2Handle
+    }
+
+    unsafe extern "C" fn mock_cleanup(instance: LV2Handle) {
+        drop(unsafe { Box::from_raw(instance as *mut MockPlugin) });
+    }
+
+    unsafe extern "C" fn mock_save(
+        instance: LV2Handle,
+        store: Lv2StateStoreFn,
+        handle: *mut c_void,
+        _flags: u32,
+        _features: *const *const Lv2Feature,
+    ) -> u32 {
+        let plugin = unsafe { &*(instance as *const MockPlugin) };
+        unsafe {
+            store(
+                handle,
+                mock_urid(plugin, c"urn:rf:mock#blob"),
+                plugin.blob.as_ptr() as *const c_void,
+                plugin.blob.len(),
+                mock_urid(plugin, c"http://lv2plug.in/ns/ext/atom#Chunk"),
+                LV2_STATE_IS_POD | LV2_STATE_IS_PORTABLE,
+            )
+        }
+    }
+
+    unsafe extern "C" fn mock_restore(
+        instance: LV2Handle,
+        retrieve: Lv2StateRetrieveFn,
+        handle: *mut c_void,
+        _flags: u32,
+        _features: *const *const Lv2Feature,
+    ) -> u32 {
+        let plugin = unsafe { &mut *(instance as *mut MockPlugin) };
+        let (mut size, mut value_type, mut flags) = (0usize, 0u32, 0u32);
+        let key = unsafe { mock_urid(plugin, c"urn:rf:mock#blob") };
+        let value = unsafe { retrieve(handle, key, &mut size, &mut value_type, &mut flags) };
+        if value.is_null()
+            || value_type != unsafe { mock_urid(plugin, c"http://lv2plug.in/ns/ext/atom#Chunk") }
+        {
+            return LV2_STATE_ERR_NO_PROPERTY;
+        }
+        plugin.blob = unsafe { std::slice::from_raw_parts(value as *const u8, size) }.to_vec();
+        LV2_STATE_SUCCESS
+    }
+
+    static MOCK_STATE_INTERFACE: Lv2StateInterface = Lv2StateInterface {
+        save: Some(mock_save),
+        restore: Some(mock_restore),
+    };
+
+    unsafe extern "C" fn mock_extension_data(uri: *const c_char) -> *const c_void {
+        if unsafe { CStr::from_ptr(uri) }.to_bytes_with_nul() == LV2_STATE_INTERFACE_URI {
+            &MOCK_STATE_INTERFACE as *const Lv2StateInterface as *const c_void
+        } else {
+            std::ptr::null()
+        }
+    }
+
+    /// Mock instance with a single "gain" control port
+    fn mock_instance(bundle_path: &Path) -> Lv2PluginInstance {
+        let descriptor = Box::leak(Box::new(Lv2PluginDescriptor {
+            uri: c"urn:rf:mock".as_ptr(),
+            instantiate: Some(mock_instantiate),
+            connect_port: None,
+            activate: None,
+            run: None,
+            deactivate: None,
+            cleanup: Some(mock_cleanup),
+            extension_data: Some(mock_extension_data),
+        }));
+        let desc = Lv2Descriptor {
+            uri: MOCK_URI.into(),
+            name: "Mock".into(),
+            author: String::new(),
+            license: String::new(),
+            plugin_class: Lv2Class::Plugin,
+            required_features: Vec::new(),
+            optional_features: Vec::new(),
+            bundle_path: bundle_path.to_path_buf(),
+            binary_name: String::new(),
+            ui_bundle_path: None,
+            ui_binary_name: None,
+            ui_type_uri: None,
+            ui_uri: None,
+        };
+
+        let mut instance = Lv2PluginInstance::instantiate(&desc, descriptor, None).unwrap();
+        instance.ports = vec![Lv2Port {
+            index: 4,
+            symbol: "gain".into(),
+            name: "Gain".into(),
+            port_type: Lv2PortType::ControlInput,
+            default_value: 1.0,
+            min_value: 0.0,
+            max_value: 2.0,
+            is_logarithmic: false,
+            is_integer: false,
+            is_toggled: false,
+        }];
+        instance.port_values = vec![1.0];
+        instance
+    }
+
+    fn mock_blob(instance: &mut Lv2PluginInstance) -> &mut Vec<u8> {
+        unsafe { &mut (*(instance.handle as *mut MockPlugin)).blob }
+    }
+
+    #[test]
+    fn test_state_save_restore_roundtrip() {
+        let mut source = mock_instance(Path::new("/nonexistent"));
+        source.set_parameter(0, 0.25).unwrap();
+        *mock_blob(&mut source) = b"sample: kick.wav".to_vec();
+        let state = source.get_state().unwrap();
+
+        // Keys travel as URIs, not process-local URIDs
+        let saved: Lv2SavedState = serde_json::from_slice(&state).unwrap();
+        assert_eq!(saved.properties.len(), 1);
+        assert_eq!(saved.properties[0].key, "urn:rf:mock#blob");
+        assert_eq!(
+            saved.properties[0].value_type,
+            "http://lv2plug.in/ns/ext/atom#Chunk"
+        );
+
+        let mut target = mock_instance(Path::new("/nonexistent"));
+        assert_eq!(target.get_parameter(0), Some(1.0));
+        assert!(mock_blob(&mut target).is_empty());
+
+        target.set_state(&state).unwrap();
+        assert_eq!(target.get_parameter(0), Some(0.25));
+        assert_eq!(mock_blob(&mut target).as_slice(), b"sample: kick.wav");
+    }
+
+    #[test]
+    fn test_bundled_presets() {
+        let bundle =
+            std::env::temp_dir().join(format!("rf_lv2_presets_{}.lv2", std::process::id()));
+        std::fs::create_dir_all(bundle.join("presets")).unwrap();
+        std::fs::write(
+            bundle.join("manifest.ttl"),
+            r#"
+            @prefix lv2: <http://lv2plug.in/ns/lv2core#> .
+            @prefix pset: <http://lv2plug.in/ns/ext/presets#> .
+            @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+            <urn:rf:mock> a lv2:Plugin ; lv2:binary <mock.so> .
+
+            <urn:rf:mock#quiet> a pset:Preset ;
+                lv2:appliesTo <urn:rf:mock> ;
+                rdfs:seeAlso <presets/quiet.ttl> .
+
+            <urn:rf:other#preset> a pset:Preset ;
+                lv2:appliesTo <urn:rf:other> ;
+                rdfs:seeAlso <presets/other.ttl> .
+            "#,
+        )
+        .unwrap();
+        std::fs::write(
+            bundle.join("presets/quiet.ttl"),
+            r#"
+            @prefix lv2: <http://lv2plug.in/ns/lv2core#> .
+            @prefix pset: <http://lv2plug.in/ns/ext/presets#> .
+            @prefix rdfs: <http://www.w3.org/2000/01/rdf-schema#> .
+
+            <urn:rf:mock#quiet> a pset:Preset ;
+                rdfs:label "Quiet" ;
+                lv2:port [
+                    lv2:symbol "gain" ;
+                    pset:value 0.125
+                ] , [
+                    lv2:symbol "missing" ;
+                    pset:value 3.0
+                ] .
+            "#,
+        )
+        .unwrap();
+
+        let mut instance = mock_instance(&bundle);
+        let presets = instance.list_presets();
+        assert_eq!(
+            presets,
+            vec![PresetInfo {
+                uri: "urn:rf:mock#quiet".into(),
+                label: "Quiet".into(),
+                path: bundle.join("presets/quiet.ttl"),
+            }]
+        );
+        assert_eq!(instance.preset_name(0).as_deref(), Some("Quiet"));
+
+        instance.load_preset("urn:rf:mock#quiet").unwrap();
+        assert_eq!(instance.get_parameter(0), Some(0.125));
+        assert!(instance.load_preset("urn:rf:mock#nope").is_err());
+
+        std::fs::remove_dir_all(&bundle).ok();
+    }
+
     #[test]
     fn test_ttl_parse_binary() {
         let ttl = r#"
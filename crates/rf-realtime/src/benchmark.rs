@@ -12,6 +12,8 @@ use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::time::{Duration, Instant};
 
+use crate::simd::SimdLevel;
+
 /// Performance target levels
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PerformanceTarget {
@@ -422,6 +424,156 @@ impl MemoryTracker {
     }
 }
 
+/// SIMD comparison benchmark settings
+#[derive(Debug, Clone)]
+pub struct SimdBenchConfig {
+    /// Samples per process call
+    pub block_size: usize,
+    /// Number of blocks to time
+    pub num_blocks: usize,
+    /// Maximum allowed deviation from the scalar output
+    pub epsilon: f64,
+}
+
+impl Default for SimdBenchConfig {
+    fn default() -> Self {
+        Self {
+            block_size: 512,
+            num_blocks: 256,
+            epsilon: 1e-9,
+        }
+    }
+}
+
+/// Result for a single SIMD level
+#[derive(Debug, Clone)]
+pub struct SimdBenchResult {
+    pub level: SimdLevel,
+    /// Processing cost (nanoseconds per sample)
+    pub ns_per_sample: f64,
+    /// Maximum absolute deviation from the scalar output
+    pub max_deviation: f64,
+}
+
+/// Per-level timings and output parity
+#[derive(Debug, Clone)]
+pub struct SimdBenchReport {
+    /// Results in `SimdLevel::available()` order (scalar first)
+    pub results: Vec<SimdBenchResult>,
+    pub epsilon: f64,
+}
+
+impl SimdBenchReport {
+    /// All levels match the scalar output within epsilon
+    pub fn parity_ok(&self) -> bool {
+        self.results.iter().all(|r| r.max_deviation <= self.epsilon)
+    }
+
+    /// Get result for a level
+    pub fn result(&self, level: SimdLevel) -> Option<&SimdBenchResult> {
+        self.results.iter().find(|r| r.level == level)
+    }
+
+    /// Speedup of a level relative to scalar
+    pub fn speedup(&self, level: SimdLevel) -> Option<f64> {
+        let scalar = self.result(SimdLevel::Scalar)?.ns_per_sample;
+        let level = self.result(level)?.ns_per_sample;
+        (level > 0.0).then(|| scalar / level)
+    }
+
+    /// Human-readable timing table
+    pub fn summary(&self) -> String {
+        self.results
+            .iter()
+            .map(|r| {
+                format!(
+                    "{:<8} {:>8.2} ns/sample  x{:.2}  max dev {:.3e}",
+                    r.level.name(),
+                    r.ns_per_sample,
+                    self.speedup(r.level).unwrap_or(0.0),
+                    r.max_deviation
+                )
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Benchmark a processor at every SIMD level the CPU supports.
+///
+/// `processor_factory` builds a fresh processor forced to the given level; the
+/// processor works in place on one block. Every level gets the same input and
+/// the scalar output is the reference; check `parity_ok()` on the report.
+pub fn bench_across_simd<F, P>(processor_factory: F, config: &SimdBenchConfig) -> SimdBenchReport
+where
+    F: Fn(SimdLevel) -> P,
+    P: FnMut(&mut [f64]),
+{
+    bench_simd_levels(&SimdLevel::available(), processor_factory, config)
+}
+
+/// Benchmark a processor at the given levels, using the first level's output as reference.
+pub fn bench_simd_levels<F, P>(
+    levels: &[SimdLevel],
+    processor_factory: F,
+    config: &SimdBenchConfig,
+) -> SimdBenchReport
+where
+    F: Fn(SimdLevel) -> P,
+    P: FnMut(&mut [f64]),
+{
+    let total = config.block_size * config.num_blocks;
+
+    // Deterministic white noise (LCG) so every level sees identical input
+    let mut seed = 0x2545_f491_4f6c_dd1du64;
+    let input: Vec<f64> = (0..total)
+        .map(|_| {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+        })
+        .collect();
+
+    let mut reference: Option<Vec<f64>> = None;
+    let mut results = Vec::new();
+
+    for &level in levels {
+        let mut processor = processor_factory(level);
+        let mut output = input.clone();
+
+        let start = Instant::now();
+        for block in output.chunks_mut(config.block_size) {
+            processor(block);
+        }
+        let elapsed = start.elapsed();
+
+        let max_deviation = match &reference {
+            Some(reference) => reference
+                .iter()
+                .zip(&output)
+                .map(|(a, b)| (a - b).abs())
+                .fold(0.0, f64::max),
+            None => 0.0,
+        };
+
+        results.push(SimdBenchResult {
+            level,
+            ns_per_sample: elapsed.as_nanos() as f64 / total.max(1) as f64,
+            max_deviation,
+        });
+
+        if reference.is_none() {
+            reference = Some(output);
+        }
+    }
+
+    SimdBenchReport {
+        results,
+        epsilon: config.epsilon,
+    }
+}
+
 /// Global profiler instance
 static GLOBAL_PROFILER: std::sync::OnceLock<Profiler> = std::sync::OnceLock::new();
 
@@ -483,6 +635,68 @@ mod tests {
         assert_eq!(tracker.peak_bytes(), 1500); // Peak unchanged
     }
 
+    #[test]
+    fn test_bench_biquad_across_simd() {
+        use crate::simd::SimdBiquadBank;
+        use rf_dsp::biquad::BiquadCoeffs;
+
+        // 11 lowpass filters summed (odd count exercises the scalar tail)
+        let factory = |level: SimdLevel| {
+            let num_filters = 11;
+            let mut bank = SimdBiquadBank::with_level(num_filters, level);
+            for i in 0..num_filters {
+                let c = BiquadCoeffs::lowpass(200.0 * (i + 1) as f64, 0.707, 48000.0);
+                bank.set_coefficients(i, c.b0, c.b1, c.b2, c.a1, c.a2);
+            }
+            let mut outputs = vec![0.0; num_filters];
+            move |block: &mut [f64]| {
+                for sample in block.iter_mut() {
+                    bank.process_parallel_into(*sample, &mut outputs);
+                    *sample = outputs.iter().sum();
+                }
+            }
+        };
+
+        let config = SimdBenchConfig {
+            num_blocks: 16,
+            ..Default::default()
+        };
+        let report = bench_across_simd(factory, &config);
+
+        assert_eq!(report.results.len(), SimdLevel::available().len());
+        assert!(report.parity_ok());
+        assert!(report.results.iter().all(|r| r.ns_per_sample > 0.0));
+    }
+
+    #[test]
+    fn test_bench_detects_mismatch() {
+        // The "SSE" kernel has a wrong gain -> parity must fail against scalar
+        let factory = |level: SimdLevel| {
+            let gain = if level == SimdLevel::Scalar {
+                0.5
+            } else {
+                0.5 + 1e-3
+            };
+            move |block: &mut [f64]| block.iter_mut().for_each(|s| *s *= gain)
+        };
+        let report = bench_simd_levels(
+            &[SimdLevel::Scalar, SimdLevel::Sse42],
+            factory,
+            &SimdBenchConfig::default(),
+        );
+
+        assert!(!report.parity_ok());
+        assert_eq!(report.result(SimdLevel::Scalar).unwrap().max_deviation, 0.0);
+        // Input is white noise in [-1, 1), so the worst error approaches 1e-3
+        let deviation = report.result(SimdLevel::Sse42).unwrap().max_deviation;
+        assert!(
+            deviation > 0.9e-3 && deviation <= 1e-3,
+            "deviation {}",
+            deviation
+        );
+        assert!(report.summary().contains("SSE4.2"));
+    }
+
     #[test]
     fn test_performance_report() {
         let profiler = Profiler::new();
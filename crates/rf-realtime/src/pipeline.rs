@@ -5,6 +5,7 @@
 //! - Lookahead compensation manager
 //! - Plugin Delay Compensation (PDC)
 //! - Per-path latency reporting
+//! - Block size changes at a block boundary, without restarting the stream
 
 use portable_atomic::{AtomicU32, Ordering};
 use std::collections::HashMap;
@@ -76,6 +77,16 @@ impl LatencySlot {
         output
     }
 
+    /// Delay a block in place
+    pub fn process_block(&mut self, block: &mut [f64]) {
+        if self.delay_samples == 0 {
+            return;
+        }
+        for sample in block.iter_mut() {
+            *sample = self.process(*sample);
+        }
+    }
+
     pub fn reset(&mut self) {
         self.buffer.fill(0.0);
         self.write_pos = 0;
@@ -211,6 +222,13 @@ impl PdcManager {
         }
     }
 
+    /// Process compensation for a whole block of a path
+    pub fn process_compensation_block(&mut self, path_id: u32, block: &mut [f64]) {
+        if let Some(path) = self.paths.get_mut(&path_id) {
+            path.compensation.process_block(block);
+        }
+    }
+
     /// Get path info
     pub fn get_path(&self, id: u32) -> Option<&ProcessingPath> {
         self.paths.get(&id)
@@ -235,6 +253,103 @@ impl PdcManager {
     }
 }
 
+/// Largest block size a pipeline path can buffer
+pub const MAX_PIPELINE_BLOCK_SIZE: usize = 8192;
+
+/// Block buffering for one path
+///
+/// Input is collected into blocks of `block_size`, compensated a block at a
+/// time and written to a history ring that the output reads `block_size`
+/// samples behind. Storage is allocated for `MAX_PIPELINE_BLOCK_SIZE` up front,
+/// so a size change never allocates or frees on the audio thread.
+#[derive(Debug, Clone)]
+struct BlockBuffer {
+    block_size: usize,
+    /// Size to switch to at the next block boundary
+    pending_block_size: Option<usize>,
+    /// Block being collected
+    input: Vec<f64>,
+    fill: usize,
+    /// Processed samples (ring)
+    history: Vec<f64>,
+    write_pos: usize,
+    /// Read delay before the last size change, crossfaded out
+    fade_from: usize,
+    fade_pos: usize,
+    fade_len: usize,
+}
+
+impl BlockBuffer {
+    fn new(block_size: usize) -> Self {
+        Self {
+            block_size,
+            pending_block_size: None,
+            input: vec![0.0; MAX_PIPELINE_BLOCK_SIZE],
+            fill: 0,
+            history: vec![0.0; MAX_PIPELINE_BLOCK_SIZE * 2],
+            write_pos: 0,
+            fade_from: 0,
+            fade_pos: 0,
+            fade_len: 0,
+        }
+    }
+
+    /// Processed sample `offset` samples into the current block, read `delay` behind
+    #[inline]
+    fn read(&self, offset: usize, delay: usize) -> f64 {
+        let len = self.history.len();
+        self.history[(self.write_pos + len + offset - delay) % len]
+    }
+
+    fn process(&mut self, pdc: &mut PdcManager, path_id: u32, input: &[f64], output: &mut [f64]) {
+        let mut done = 0;
+        while done < input.len() {
+            // Block boundary: switch size, fading from the old read delay to the new one
+            if self.fill == 0
+                && let Some(block_size) = self.pending_block_size.take()
+            {
+                self.fade_from = self.block_size;
+                self.fade_pos = 0;
+                self.fade_len = block_size.min(self.block_size);
+                self.block_size = block_size;
+            }
+
+            let n = (self.block_size - self.fill).min(input.len() - done);
+            for (k, out) in output[done..done + n].iter_mut().enumerate() {
+                let offset = self.fill + k;
+                let mut sample = self.read(offset, self.block_size);
+                if self.fade_pos < self.fade_len {
+                    self.fade_pos += 1;
+                    let t = self.fade_pos as f64 / self.fade_len as f64;
+                    sample = sample * t + self.read(offset, self.fade_from) * (1.0 - t);
+                }
+                *out = sample;
+            }
+            self.input[self.fill..self.fill + n].copy_from_slice(&input[done..done + n]);
+            self.fill += n;
+            done += n;
+
+            if self.fill == self.block_size {
+                let block = &mut self.input[..self.block_size];
+                pdc.process_compensation_block(path_id, block);
+                for &sample in block.iter() {
+                    self.history[self.write_pos] = sample;
+                    self.write_pos = (self.write_pos + 1) % self.history.len();
+                }
+                self.fill = 0;
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.history.fill(0.0);
+        self.write_pos = 0;
+        self.fill = 0;
+        self.fade_pos = 0;
+        self.fade_len = 0;
+    }
+}
+
 /// Zero-latency processing pipeline
 pub struct ZeroLatencyPipeline {
     /// PDC manager
@@ -247,16 +362,68 @@ pub struct ZeroLatencyPipeline {
     block_size: usize,
     /// Sample rate
     sample_rate: f64,
+    /// Block buffering state per path
+    blocks: HashMap<u32, BlockBuffer>,
+    /// Requested block size, handed to the paths at the next `process_block`
+    pending_block_size: Option<usize>,
 }
 
 impl ZeroLatencyPipeline {
     pub fn new(sample_rate: f64, block_size: usize) -> Self {
+        let block_size = block_size.clamp(1, MAX_PIPELINE_BLOCK_SIZE);
         Self {
             pdc: PdcManager::new(sample_rate, PipelineMode::ZeroLatency),
             direct_path: None,
             lookahead_paths: Vec::new(),
             block_size,
             sample_rate,
+            blocks: HashMap::new(),
+            pending_block_size: None,
+        }
+    }
+
+    /// Current block size (a pending change is not in effect yet)
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Request a new block size without restarting the stream
+    ///
+    /// Each path switches at its next block boundary, so the block in flight
+    /// finishes at the old size, and crossfades from the old latency to the new
+    /// one. Returns the resulting latency change.
+    pub fn set_block_size(&mut self, new_block_size: usize) -> BlockSizeChange {
+        let new_block_size = new_block_size.clamp(1, MAX_PIPELINE_BLOCK_SIZE);
+        let pdc_latency = self.total_latency();
+        let change = BlockSizeChange {
+            old_block_size: self.block_size,
+            new_block_size,
+            old_latency_samples: pdc_latency + self.block_size as u32,
+            new_latency_samples: pdc_latency + new_block_size as u32,
+            sample_rate: self.sample_rate,
+        };
+
+        self.pending_block_size = (new_block_size != self.block_size).then_some(new_block_size);
+        change
+    }
+
+    /// Process a host buffer through a path in blocks of `block_size`
+    ///
+    /// Adds `block_size` samples of buffering on top of the path's PDC delay.
+    pub fn process_block(&mut self, path_id: u32, input: &[f64], output: &mut [f64]) {
+        if let Some(block_size) = self.pending_block_size.take() {
+            self.block_size = block_size;
+            for blocks in self.blocks.values_mut() {
+                blocks.pending_block_size = Some(block_size);
+            }
+        }
+
+        let len = input.len().min(output.len());
+        match self.blocks.get_mut(&path_id) {
+            Some(blocks) => {
+                blocks.process(&mut self.pdc, path_id, &input[..len], &mut output[..len])
+            }
+            None => output[..len].copy_from_slice(&input[..len]),
         }
     }
 
@@ -264,6 +431,7 @@ impl ZeroLatencyPipeline {
     pub fn create_direct_path(&mut self, name: &str) -> u32 {
         let id = 0;
         self.pdc.add_path(id, name.to_string());
+        self.blocks.insert(id, BlockBuffer::new(self.block_size));
         self.direct_path = Some(id);
         id
     }
@@ -272,6 +440,7 @@ impl ZeroLatencyPipeline {
     pub fn create_lookahead_path(&mut self, name: &str) -> u32 {
         let id = (self.lookahead_paths.len() + 1) as u32;
         self.pdc.add_path(id, name.to_string());
+        self.blocks.insert(id, BlockBuffer::new(self.block_size));
         self.lookahead_paths.push(id);
         id
     }
@@ -333,12 +502,16 @@ impl ZeroLatencyPipeline {
             num_paths: self.pdc.paths().count(),
             direct_path_active: self.direct_path.is_some(),
             lookahead_paths: self.lookahead_paths.len(),
+            block_size: self.block_size,
         }
     }
 
     /// Reset pipeline
     pub fn reset(&mut self) {
         self.pdc.reset();
+        for blocks in self.blocks.values_mut() {
+            blocks.reset();
+        }
     }
 }
 
@@ -350,6 +523,31 @@ pub struct PipelineInfo {
     pub num_paths: usize,
     pub direct_path_active: bool,
     pub lookahead_paths: usize,
+    pub block_size: usize,
+}
+
+/// Result of a block size change request
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlockSizeChange {
+    pub old_block_size: usize,
+    pub new_block_size: usize,
+    /// Block buffering plus PDC latency before the change
+    pub old_latency_samples: u32,
+    /// Block buffering plus PDC latency after the change
+    pub new_latency_samples: u32,
+    pub sample_rate: f64,
+}
+
+impl BlockSizeChange {
+    /// Latency change in samples (negative = lower latency)
+    pub fn latency_delta_samples(&self) -> i64 {
+        self.new_latency_samples as i64 - self.old_latency_samples as i64
+    }
+
+    /// Latency change in milliseconds
+    pub fn latency_delta_ms(&self) -> f64 {
+        self.latency_delta_samples() as f64 / self.sample_rate * 1000.0
+    }
 }
 
 #[cfg(test)]
@@ -454,4 +652,56 @@ mod tests {
         pipeline.process_direct(&input, &mut output);
         assert_eq!(output, input);
     }
+
+    #[test]
+    fn test_block_size_change_is_continuous() {
+        let sample_rate = 48000.0;
+        let freq = 1100.0;
+        let host_block = 512;
+        let input: Vec<f64> = (0..host_block * 8)
+            .map(|i| (2.0 * std::f64::consts::PI * freq * i as f64 / sample_rate).sin())
+            .collect();
+
+        let mut pipeline = ZeroLatencyPipeline::new(sample_rate, 256);
+        let direct = pipeline.create_direct_path("Direct");
+        let lookahead = pipeline.create_lookahead_path("Lookahead");
+        pipeline.add_processor(lookahead, "Limiter", 48);
+
+        let mut output = vec![0.0; input.len()];
+        for (n, (inp, out)) in input
+            .chunks(host_block)
+            .zip(output.chunks_mut(host_block))
+            .enumerate()
+        {
+            if n == 4 {
+                let change = pipeline.set_block_size(64);
+                assert_eq!(change.old_latency_samples, 48 + 256);
+                assert_eq!(change.new_latency_samples, 48 + 64);
+                assert_eq!(change.latency_delta_samples(), -192);
+                assert!((change.latency_delta_ms() + 4.0).abs() < 1e-9);
+                // Not in effect until the next block boundary
+                assert_eq!(pipeline.block_size(), 256);
+            }
+            pipeline.process_block(direct, inp, out);
+        }
+        assert_eq!(pipeline.block_size(), 64);
+        assert_eq!(pipeline.info().block_size, 64);
+
+        // Direct path: PDC plus block buffering, crossfaded over one new block
+        let switch = host_block * 4;
+        let delayed = |i: usize, delay: usize| if i >= delay { input[i - delay] } else { 0.0 };
+        for (i, &sample) in output.iter().enumerate() {
+            let delay = if i < switch { 48 + 256 } else { 48 + 64 };
+            if !(switch..switch + 64).contains(&i) {
+                assert!((sample - delayed(i, delay)).abs() < 1e-12, "sample {}", i);
+            }
+        }
+
+        // No jump through the switch beyond the sine's slope and the fade step
+        let max_step = 2.0 * std::f64::consts::PI * freq / sample_rate + 2.0 / 64.0;
+        for w in output[switch - 64..switch + 128].windows(2) {
+            let step = (w[1] - w[0]).abs();
+            assert!(step <= max_step, "step {}", step);
+        }
+    }
 }
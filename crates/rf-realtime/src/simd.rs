@@ -20,6 +20,8 @@ pub enum SimdLevel {
     Avx2,
     /// AVX-512 (512-bit, 8 doubles)
     Avx512,
+    /// ARM NEON (128-bit, 2 doubles)
+    Neon,
 }
 
 impl SimdLevel {
@@ -40,7 +42,7 @@ impl SimdLevel {
     #[cfg(target_arch = "aarch64")]
     pub fn detect() -> Self {
         // ARM NEON is always available on aarch64
-        Self::Avx2 // Map NEON to AVX2-like capability
+        Self::Neon
     }
 
     #[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
@@ -48,11 +50,62 @@ impl SimdLevel {
         Self::Scalar
     }
 
+    /// Is this level supported by the running CPU
+    pub fn is_available(&self) -> bool {
+        match self {
+            Self::Scalar => true,
+            #[cfg(target_arch = "x86_64")]
+            Self::Sse42 => is_x86_feature_detected!("sse4.2"),
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx2 => is_x86_feature_detected!("avx2"),
+            #[cfg(target_arch = "x86_64")]
+            Self::Avx512 => is_x86_feature_detected!("avx512f"),
+            #[cfg(target_arch = "aarch64")]
+            Self::Neon => true,
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    /// All levels supported by the running CPU (scalar first)
+    pub fn available() -> Vec<Self> {
+        [
+            Self::Scalar,
+            Self::Sse42,
+            Self::Avx2,
+            Self::Avx512,
+            Self::Neon,
+        ]
+        .into_iter()
+        .filter(|level| level.is_available())
+        .collect()
+    }
+
+    /// Requested level if supported, otherwise scalar
+    pub fn or_scalar(self) -> Self {
+        if self.is_available() {
+            self
+        } else {
+            Self::Scalar
+        }
+    }
+
+    /// Display name
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Scalar => "Scalar",
+            Self::Sse42 => "SSE4.2",
+            Self::Avx2 => "AVX2",
+            Self::Avx512 => "AVX-512",
+            Self::Neon => "NEON",
+        }
+    }
+
     /// Get vector width in doubles
     pub fn vector_width(&self) -> usize {
         match self {
             Self::Scalar => 1,
-            Self::Sse42 => 2,
+            Self::Sse42 | Self::Neon => 2,
             Self::Avx2 => 4,
             Self::Avx512 => 8,
         }
@@ -125,6 +178,13 @@ impl SimdGain {
         }
     }
 
+    /// Force a SIMD level (falls back to scalar if unsupported)
+    pub fn with_level(level: SimdLevel) -> Self {
+        Self {
+            level: level.or_scalar(),
+        }
+    }
+
     /// Process gain with best available SIMD
     #[cfg(target_arch = "x86_64")]
     pub fn process(&self, buffer: &mut [f64], gain: f64) {
@@ -132,7 +192,7 @@ impl SimdGain {
             SimdLevel::Avx512 => unsafe { self.process_avx512_impl(buffer, gain) },
             SimdLevel::Avx2 => unsafe { self.process_avx2_impl(buffer, gain) },
             SimdLevel::Sse42 => unsafe { self.process_sse42_impl(buffer, gain) },
-            SimdLevel::Scalar => self.process_scalar(buffer, gain),
+            SimdLevel::Scalar | SimdLevel::Neon => self.process_scalar(buffer, gain),
         }
     }
 
@@ -230,6 +290,13 @@ impl SimdMixer {
         }
     }
 
+    /// Force a SIMD level (falls back to scalar if unsupported)
+    pub fn with_level(level: SimdLevel) -> Self {
+        Self {
+            level: level.or_scalar(),
+        }
+    }
+
     /// Mix two buffers: output = a * gain_a + b * gain_b
     #[cfg(target_arch = "x86_64")]
     pub fn mix(&self, a: &[f64], b: &[f64], output: &mut [f64], gain_a: f64, gain_b: f64) {
@@ -237,7 +304,7 @@ impl SimdMixer {
             SimdLevel::Avx512 => unsafe { self.mix_avx512_impl(a, b, output, gain_a, gain_b) },
             SimdLevel::Avx2 => unsafe { self.mix_avx2_impl(a, b, output, gain_a, gain_b) },
             SimdLevel::Sse42 => unsafe { self.mix_sse42_impl(a, b, output, gain_a, gain_b) },
-            SimdLevel::Scalar => self.mix_scalar(a, b, output, gain_a, gain_b),
+            SimdLevel::Scalar | SimdLevel::Neon => self.mix_scalar(a, b, output, gain_a, gain_b),
         }
     }
 
@@ -371,8 +438,13 @@ pub struct SimdBiquadBank {
 
 impl SimdBiquadBank {
     pub fn new(num_filters: usize) -> Self {
+        Self::with_level(num_filters, SimdLevel::detect())
+    }
+
+    /// Force a SIMD level (falls back to scalar if unsupported)
+    pub fn with_level(num_filters: usize, level: SimdLevel) -> Self {
         Self {
-            level: SimdLevel::detect(),
+            level: level.or_scalar(),
             coeffs: vec![[1.0, 0.0, 0.0, 0.0, 0.0]; num_filters],
             states: vec![[0.0, 0.0]; num_filters],
         }
@@ -388,21 +460,33 @@ impl SimdBiquadBank {
     /// Process all filters on a single sample (parallel processing)
     pub fn process_parallel(&mut self, input: f64) -> Vec<f64> {
         let mut outputs = vec![0.0; self.coeffs.len()];
-
-        match self.level {
-            SimdLevel::Avx2 | SimdLevel::Avx512 => {
-                self.process_parallel_simd(input, &mut outputs);
-            }
-            _ => {
-                self.process_parallel_scalar(input, &mut outputs);
-            }
-        }
-
+        self.process_parallel_into(input, &mut outputs);
         outputs
     }
 
-    fn process_parallel_scalar(&mut self, input: f64, outputs: &mut [f64]) {
-        for (i, (coeffs, state)) in self.coeffs.iter().zip(self.states.iter_mut()).enumerate() {
+    /// Process all filters on a single sample into `outputs` (no allocation)
+    pub fn process_parallel_into(&mut self, input: f64, outputs: &mut [f64]) {
+        #[cfg(target_arch = "x86_64")]
+        let done = match self.level {
+            SimdLevel::Avx512 => unsafe { self.process_parallel_avx512(input, outputs) },
+            SimdLevel::Avx2 => unsafe { self.process_parallel_avx2(input, outputs) },
+            SimdLevel::Sse42 => unsafe { self.process_parallel_sse42(input, outputs) },
+            SimdLevel::Scalar | SimdLevel::Neon => 0,
+        };
+        #[cfg(not(target_arch = "x86_64"))]
+        let done = 0;
+
+        self.process_parallel_scalar(input, outputs, done);
+    }
+
+    /// Scalar path for filters `start..`
+    fn process_parallel_scalar(&mut self, input: f64, outputs: &mut [f64], start: usize) {
+        let filters = self
+            .coeffs
+            .iter()
+            .zip(self.states.iter_mut())
+            .zip(outputs.iter_mut());
+        for ((coeffs, state), out) in filters.skip(start) {
             let [b0, b1, b2, a1, a2] = *coeffs;
             let [z1, z2] = *state;
 
@@ -410,13 +494,140 @@ impl SimdBiquadBank {
             state[0] = b1 * input - a1 * output + z2;
             state[1] = b2 * input - a2 * output;
 
-            outputs[i] = output;
+            *out = output;
+        }
+    }
+
+    /// Coefficient `k` of `W` consecutive filters starting at `base`
+    #[inline(always)]
+    fn coeff_lanes<const W: usize>(&self, base: usize, k: usize) -> [f64; W] {
+        std::array::from_fn(|lane| self.coeffs[base + lane][k])
+    }
+
+    /// State `k` of `W` consecutive filters starting at `base`
+    #[inline(always)]
+    fn state_lanes<const W: usize>(&self, base: usize, k: usize) -> [f64; W] {
+        std::array::from_fn(|lane| self.states[base + lane][k])
+    }
+
+    /// Store new states for `W` consecutive filters starting at `base`
+    #[inline(always)]
+    fn store_state_lanes<const W: usize>(&mut self, base: usize, z1: [f64; W], z2: [f64; W]) {
+        for lane in 0..W {
+            self.states[base + lane] = [z1[lane], z2[lane]];
         }
     }
 
-    fn process_parallel_simd(&mut self, input: f64, outputs: &mut [f64]) {
-        // Fall back to scalar for now - full SIMD biquad is complex
-        self.process_parallel_scalar(input, outputs);
+    /// SSE4.2: 2 filters per step. Returns number of filters processed.
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "sse4.2")]
+    unsafe fn process_parallel_sse42(&mut self, input: f64, outputs: &mut [f64]) -> usize {
+        unsafe {
+            let x = _mm_set1_pd(input);
+            let chunks = self.coeffs.len().min(outputs.len()) / 2;
+
+            for c in 0..chunks {
+                let base = c * 2;
+                let load = |lanes: [f64; 2]| _mm_loadu_pd(lanes.as_ptr());
+                let b0 = load(self.coeff_lanes(base, 0));
+                let b1 = load(self.coeff_lanes(base, 1));
+                let b2 = load(self.coeff_lanes(base, 2));
+                let a1 = load(self.coeff_lanes(base, 3));
+                let a2 = load(self.coeff_lanes(base, 4));
+                let z1 = load(self.state_lanes(base, 0));
+                let z2 = load(self.state_lanes(base, 1));
+
+                // Same operation order as the scalar path (bit-identical output)
+                let y = _mm_add_pd(_mm_mul_pd(b0, x), z1);
+                let s1 = _mm_add_pd(_mm_sub_pd(_mm_mul_pd(b1, x), _mm_mul_pd(a1, y)), z2);
+                let s2 = _mm_sub_pd(_mm_mul_pd(b2, x), _mm_mul_pd(a2, y));
+
+                let mut new_z1 = [0.0; 2];
+                let mut new_z2 = [0.0; 2];
+                _mm_storeu_pd(outputs.as_mut_ptr().add(base), y);
+                _mm_storeu_pd(new_z1.as_mut_ptr(), s1);
+                _mm_storeu_pd(new_z2.as_mut_ptr(), s2);
+                self.store_state_lanes(base, new_z1, new_z2);
+            }
+
+            chunks * 2
+        }
+    }
+
+    /// AVX2: 4 filters per step. Returns number of filters processed.
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx2")]
+    unsafe fn process_parallel_avx2(&mut self, input: f64, outputs: &mut [f64]) -> usize {
+        unsafe {
+            let x = _mm256_set1_pd(input);
+            let chunks = self.coeffs.len().min(outputs.len()) / 4;
+
+            for c in 0..chunks {
+                let base = c * 4;
+                let load = |lanes: [f64; 4]| _mm256_loadu_pd(lanes.as_ptr());
+                let b0 = load(self.coeff_lanes(base, 0));
+                let b1 = load(self.coeff_lanes(base, 1));
+                let b2 = load(self.coeff_lanes(base, 2));
+                let a1 = load(self.coeff_lanes(base, 3));
+                let a2 = load(self.coeff_lanes(base, 4));
+                let z1 = load(self.state_lanes(base, 0));
+                let z2 = load(self.state_lanes(base, 1));
+
+                let y = _mm256_add_pd(_mm256_mul_pd(b0, x), z1);
+                let s1 = _mm256_add_pd(
+                    _mm256_sub_pd(_mm256_mul_pd(b1, x), _mm256_mul_pd(a1, y)),
+                    z2,
+                );
+                let s2 = _mm256_sub_pd(_mm256_mul_pd(b2, x), _mm256_mul_pd(a2, y));
+
+                let mut new_z1 = [0.0; 4];
+                let mut new_z2 = [0.0; 4];
+                _mm256_storeu_pd(outputs.as_mut_ptr().add(base), y);
+                _mm256_storeu_pd(new_z1.as_mut_ptr(), s1);
+                _mm256_storeu_pd(new_z2.as_mut_ptr(), s2);
+                self.store_state_lanes(base, new_z1, new_z2);
+            }
+
+            chunks * 4
+        }
+    }
+
+    /// AVX-512: 8 filters per step. Returns number of filters processed.
+    #[cfg(target_arch = "x86_64")]
+    #[target_feature(enable = "avx512f")]
+    unsafe fn process_parallel_avx512(&mut self, input: f64, outputs: &mut [f64]) -> usize {
+        unsafe {
+            let x = _mm512_set1_pd(input);
+            let chunks = self.coeffs.len().min(outputs.len()) / 8;
+
+            for c in 0..chunks {
+                let base = c * 8;
+                let load = |lanes: [f64; 8]| _mm512_loadu_pd(lanes.as_ptr());
+                let b0 = load(self.coeff_lanes(base, 0));
+                let b1 = load(self.coeff_lanes(base, 1));
+                let b2 = load(self.coeff_lanes(base, 2));
+                let a1 = load(self.coeff_lanes(base, 3));
+                let a2 = load(self.coeff_lanes(base, 4));
+                let z1 = load(self.state_lanes(base, 0));
+                let z2 = load(self.state_lanes(base, 1));
+
+                let y = _mm512_add_pd(_mm512_mul_pd(b0, x), z1);
+                let s1 = _mm512_add_pd(
+                    _mm512_sub_pd(_mm512_mul_pd(b1, x), _mm512_mul_pd(a1, y)),
+                    z2,
+                );
+                let s2 = _mm512_sub_pd(_mm512_mul_pd(b2, x), _mm512_mul_pd(a2, y));
+
+                let mut new_z1 = [0.0; 8];
+                let mut new_z2 = [0.0; 8];
+                _mm512_storeu_pd(outputs.as_mut_ptr().add(base), y);
+                _mm512_storeu_pd(new_z1.as_mut_ptr(), s1);
+                _mm512_storeu_pd(new_z2.as_mut_ptr(), s2);
+                self.store_state_lanes(base, new_z1, new_z2);
+            }
+
+            chunks * 8
+        }
     }
 
     /// Process a buffer through a single filter
@@ -457,6 +668,13 @@ impl SimdPeakDetector {
         }
     }
 
+    /// Force a SIMD level (falls back to scalar if unsupported)
+    pub fn with_level(level: SimdLevel) -> Self {
+        Self {
+            level: level.or_scalar(),
+        }
+    }
+
     /// Find peak (absolute maximum) in buffer
     #[cfg(target_arch = "x86_64")]
     pub fn find_peak(&self, buffer: &[f64]) -> f64 {
@@ -646,6 +864,14 @@ mod tests {
         assert_eq!(outputs, vec![1.0, 1.0, 1.0, 1.0]);
     }
 
+    #[test]
+    fn test_simd_level_available() {
+        let levels = SimdLevel::available();
+        assert_eq!(levels[0], SimdLevel::Scalar);
+        assert!(levels.contains(&SimdLevel::detect()));
+        assert!(levels.iter().all(|l| l.is_available()));
+    }
+
     #[test]
     fn test_simd_peak_detector() {
         let detector = SimdPeakDetector::new();
@@ -9,9 +9,10 @@
 
 use crate::error::{RestoreError, RestoreResult};
 use crate::{RestoreConfig, Restorer};
+use serde::{Deserialize, Serialize};
 
 /// Declick configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeclickConfig {
     /// Base configuration
     pub base: RestoreConfig,
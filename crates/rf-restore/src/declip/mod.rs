@@ -3,13 +3,16 @@
 //! Advanced declipping using:
 //! - Spline interpolation for hard clips
 //! - Spectral reconstruction
-//! - Psychoacoustic masking
+//! - Psychoacoustic masking (optional: repairs whose change would be masked
+//!   by the surrounding signal are skipped)
 
 use crate::error::{RestoreError, RestoreResult};
+use crate::masking::{self, MaskingModel};
 use crate::{RestoreConfig, Restorer};
+use serde::{Deserialize, Serialize};
 
 /// Clipping detection mode
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ClipDetectionMode {
     /// Hard clipping (flat tops)
     Hard,
@@ -20,7 +23,7 @@ pub enum ClipDetectionMode {
 }
 
 /// Declipping configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeclipConfig {
     /// Base configuration
     pub base: RestoreConfig,
@@ -28,6 +31,10 @@ pub struct DeclipConfig {
     pub threshold: f32,
     /// Margin for reconstruction (samples)
     pub margin_samples: usize,
+    /// Unclipped samples used on each side to estimate the edge slope
+    pub context_samples: usize,
+    /// Maximum reconstructed peak above the clip level (dB)
+    pub headroom_db: f32,
     /// Detection mode
     pub mode: ClipDetectionMode,
     /// Quality (iterations)
@@ -42,6 +49,8 @@ impl Default for DeclipConfig {
             base: RestoreConfig::default(),
             threshold: 0.99,
             margin_samples: 4,
+            context_samples: 6,
+            headroom_db: 6.0,
             mode: ClipDetectionMode::Auto,
             quality: 3,
             preserve_transients: true,
@@ -57,8 +66,21 @@ pub struct Declip {
     buffer: Vec<f32>,
     /// Clip regions [(start, end)]
     clip_regions: Vec<(usize, usize)>,
+    /// Masking model (when masking-aware processing is enabled)
+    masking: Option<MaskingModel>,
+    /// Repair change (repaired minus clipped) over the analysis window
+    masking_change: Vec<f32>,
+    /// Magnitude spectrum of the surrounding signal
+    masking_context_spectrum: Vec<f32>,
+    /// Magnitude spectrum of the repair change
+    masking_change_spectrum: Vec<f32>,
+    /// Masking threshold per bin
+    masking_threshold: Vec<f32>,
 }
 
+/// FFT size for masking analysis around a clip region
+const MASKING_FFT_SIZE: usize = 1024;
+
 impl Declip {
     /// Create new declipping processor
     pub fn new(config: DeclipConfig) -> Self {
@@ -66,11 +88,30 @@ impl Declip {
             config,
             buffer: Vec::new(),
             clip_regions: Vec::new(),
+            masking: None,
+            masking_change: Vec::new(),
+            masking_context_spectrum: Vec::new(),
+            masking_change_spectrum: Vec::new(),
+            masking_threshold: Vec::new(),
         }
+        .with_masking()
+    }
+
+    /// Build the masking model if the config enables it
+    fn with_masking(mut self) -> Self {
+        let aggressiveness = self.config.base.masking_aggressiveness;
+        self.set_masking_aggressiveness(aggressiveness);
+        self
     }
 
     /// Detect clipped regions
     fn detect_clips(&mut self, audio: &[f32]) {
+        self.detect_clip_regions(audio, false);
+    }
+
+    /// Detect clipped regions; `flat_only` skips samples that are not part of a
+    /// flat top (so reconstructed peaks above the threshold are left alone)
+    fn detect_clip_regions(&mut self, audio: &[f32], flat_only: bool) {
         self.clip_regions.clear();
 
         let threshold = self.config.threshold;
@@ -80,7 +121,9 @@ impl Declip {
         let mut clip_start = 0;
 
         for (i, &sample) in audio.iter().enumerate() {
-            let is_clipped = sample.abs() >= threshold;
+            let is_flat = |j: usize| audio.get(j).is_some_and(|&s| (s - sample).abs() < 1e-6);
+            let is_clipped = sample.abs() >= threshold
+                && (!flat_only || is_flat(i + 1) || (i > 0 && is_flat(i - 1)));
 
             if is_clipped && !in_clip {
                 // Start of clip region
@@ -127,45 +170,37 @@ impl Declip {
         self.clip_regions = merged;
     }
 
-    /// Reconstruct clipped region using cubic spline
+    /// Reconstruct clipped region using a clamped cubic (Hermite) spline
+    ///
+    /// The spline runs from the last unclipped sample before the region to the
+    /// first one after it, matching value and first derivative at both ends.
+    /// Edge slopes come from a least-squares quadratic over `context_samples`.
     fn reconstruct_spline(&self, audio: &[f32], start: usize, end: usize) -> Vec<f32> {
-        let margin = self.config.margin_samples;
+        let context = self.config.context_samples.max(2);
         let len = end - start;
 
-        if len < 4 {
-            return audio[start..end].to_vec();
-        }
-
-        // Get boundary points
-        let left_points: Vec<f32> = audio[start.saturating_sub(margin * 2)..start].to_vec();
-        let right_points: Vec<f32> = audio[end..(end + margin * 2).min(audio.len())].to_vec();
-
-        if left_points.is_empty() || right_points.is_empty() {
+        if len < 4 || start < context || end + context > audio.len() {
             return audio[start..end].to_vec();
         }
 
-        // Cubic Hermite spline interpolation
-        let mut result = vec![0.0f32; len];
+        // Left edge: last unclipped sample and its slope
+        let left = &audio[start - context..start];
+        let p0 = left[context - 1];
+        let slope0 = edge_slope(left.iter().copied());
 
-        // Estimate derivatives at boundaries
-        let left_deriv = if left_points.len() >= 2 {
-            left_points[left_points.len() - 1] - left_points[left_points.len() - 2]
-        } else {
-            0.0
-        };
-        let right_deriv = if right_points.len() >= 2 {
-            right_points[1] - right_points[0]
-        } else {
-            0.0
-        };
+        // Right edge: fit runs backward in time, so negate
+        let right = &audio[end..end + context];
+        let p1 = right[0];
+        let slope1 = -edge_slope(right.iter().rev().copied());
 
-        let p0 = *left_points.last().unwrap_or(&0.0);
-        let p1 = *right_points.first().unwrap_or(&0.0);
-        let m0 = left_deriv * len as f32;
-        let m1 = right_deriv * len as f32;
+        // Spline spans start-1 ..= end, i.e. len + 1 sample steps
+        let span = (len + 1) as f32;
+        let m0 = slope0 * span;
+        let m1 = slope1 * span;
 
-        for i in 0..len {
-            let t = i as f32 / len as f32;
+        let mut result = vec![0.0f32; len];
+        for (i, out) in result.iter_mut().enumerate() {
+            let t = (i + 1) as f32 / span;
             let t2 = t * t;
             let t3 = t2 * t;
 
@@ -175,12 +210,73 @@ impl Declip {
             let h01 = -2.0 * t3 + 3.0 * t2;
             let h11 = t3 - t2;
 
-            result[i] = h00 * p0 + h10 * m0 + h01 * p1 + h11 * m1;
+            *out = h00 * p0 + h10 * m0 + h01 * p1 + h11 * m1;
         }
 
         result
     }
 
+    /// Clip level of a region and its peak cap (clip level plus headroom)
+    fn peak_cap(&self, audio: &[f32], start: usize, end: usize) -> (f32, f32) {
+        let clip_level = audio[start..end]
+            .iter()
+            .fold(0.0f32, |peak, s| peak.max(s.abs()))
+            .max(self.config.threshold);
+        let cap = clip_level * 10.0f32.powf(self.config.headroom_db.max(0.0) / 20.0);
+        (clip_level, cap)
+    }
+
+    /// Limit a reconstructed sample: untouched up to the clip level, then
+    /// saturating smoothly toward (never past) the cap
+    fn cap_peak(&self, sample: f32, clip_level: f32, cap: f32) -> f32 {
+        let x = sample.abs();
+        if x <= clip_level {
+            return sample;
+        }
+        let range = cap - clip_level;
+        if range <= f32::EPSILON {
+            return sample.signum() * clip_level;
+        }
+        sample.signum() * (clip_level + range * ((x - clip_level) / range).tanh())
+    }
+
+    /// Would replacing `audio[start..]` with `repaired` be inaudible?
+    ///
+    /// Compares the spectrum of the change (repaired minus clipped) with the
+    /// masking threshold of the surrounding signal, over one analysis window
+    /// centered on the region. Always false when masking is disabled.
+    fn repair_is_masked(&mut self, audio: &[f32], start: usize, repaired: &[f32]) -> bool {
+        let aggressiveness = self.config.base.masking_aggressiveness.unwrap_or(0.0);
+        let Some(model) = self.masking.as_mut() else {
+            return false;
+        };
+
+        let window = model.fft_size().min(audio.len());
+        let center = start + repaired.len() / 2;
+        let window_start = center.saturating_sub(window / 2).min(audio.len() - window);
+        let context = &audio[window_start..window_start + window];
+
+        let change = &mut self.masking_change[..window];
+        change.fill(0.0);
+        for (i, &sample) in repaired.iter().enumerate() {
+            if let Some(slot) = (start + i)
+                .checked_sub(window_start)
+                .and_then(|j| change.get_mut(j))
+            {
+                *slot = sample - audio[start + i];
+            }
+        }
+
+        model.magnitudes(context, &mut self.masking_context_spectrum);
+        model.magnitudes(change, &mut self.masking_change_spectrum);
+        model.threshold(&self.masking_context_spectrum, &mut self.masking_threshold);
+
+        self.masking_change_spectrum
+            .iter()
+            .zip(&self.masking_threshold)
+            .all(|(&level, &thr)| masking::is_masked(level, thr, aggressiveness))
+    }
+
     /// Apply soft limiting to reconstructed peaks
     fn soft_limit(&self, sample: f32, limit: f32) -> f32 {
         if sample.abs() <= limit {
@@ -210,34 +306,52 @@ impl Restorer for Declip {
         output.copy_from_slice(input);
 
         // Reconstruct each clip region
-        for &(start, end) in &self.clip_regions {
-            let reconstructed = self.reconstruct_spline(input, start, end);
+        let regions = std::mem::take(&mut self.clip_regions);
+        for &(start, end) in &regions {
+            let mut repaired = self.reconstruct_spline(input, start, end);
+            let (clip_level, cap) = self.peak_cap(input, start, end);
+
+            // Limit peaks to the headroom cap
+            for sample in repaired.iter_mut() {
+                *sample = self.cap_peak(*sample, clip_level, cap);
+            }
+            if self.repair_is_masked(input, start, &repaired) {
+                continue;
+            }
 
-            // Apply reconstruction with soft limiting
-            for (i, &sample) in reconstructed.iter().enumerate() {
+            for (i, &sample) in repaired.iter().enumerate() {
                 let idx = start + i;
                 if idx < output.len() {
-                    // Soft limit to prevent new clipping
-                    output[idx] = self.soft_limit(sample, 0.999);
+                    output[idx] = sample;
                 }
             }
         }
+        self.clip_regions = regions;
 
         // Apply quality iterations (spectral refinement)
         for _ in 0..self.config.quality.saturating_sub(1) {
-            // Re-detect any remaining clips
-            self.detect_clips(output);
+            // Re-detect flat tops that survived (e.g. regions too close to the edges)
+            self.detect_clip_regions(output, true);
 
             // Apply smaller corrections
-            for &(start, end) in &self.clip_regions {
-                let reconstructed = self.reconstruct_spline(output, start, end);
-                for (i, &sample) in reconstructed.iter().enumerate() {
+            let regions = std::mem::take(&mut self.clip_regions);
+            for &(start, end) in &regions {
+                let mut repaired = self.reconstruct_spline(output, start, end);
+                let (clip_level, cap) = self.peak_cap(output, start, end);
+                for sample in repaired.iter_mut() {
+                    *sample = self.cap_peak(*sample, clip_level, cap);
+                }
+                if self.repair_is_masked(output, start, &repaired) {
+                    continue;
+                }
+                for (i, &sample) in repaired.iter().enumerate() {
                     let idx = start + i;
                     if idx < output.len() {
-                        output[idx] = self.soft_limit(sample, 0.999);
+                        output[idx] = sample;
                     }
                 }
             }
+            self.clip_regions = regions;
         }
 
         Ok(())
@@ -255,6 +369,63 @@ impl Restorer for Declip {
     fn name(&self) -> &str {
         "Declip"
     }
+
+    fn set_masking_aggressiveness(&mut self, aggressiveness: Option<f32>) {
+        self.config.base.masking_aggressiveness = aggressiveness.map(|a| a.clamp(0.0, 1.0));
+        self.masking = aggressiveness
+            .map(|_| MaskingModel::new(MASKING_FFT_SIZE, self.config.base.sample_rate));
+        let (window, bins) = match &self.masking {
+            Some(model) => (model.fft_size(), model.bins()),
+            None => (0, 0),
+        };
+        self.masking_change = vec![0.0; window];
+        self.masking_context_spectrum = vec![0.0; bins];
+        self.masking_change_spectrum = vec![0.0; bins];
+        self.masking_threshold = vec![0.0; bins];
+    }
+}
+
+/// Slope (per sample) at the last of `points`, from a least-squares quadratic fit
+fn edge_slope(points: impl ExactSizeIterator<Item = f32>) -> f32 {
+    let n = points.len();
+    let samples: Vec<(f64, f64)> = points
+        .enumerate()
+        .map(|(i, y)| (i as f64 - (n - 1) as f64, y as f64))
+        .collect();
+
+    if n < 3 {
+        return match samples.as_slice() {
+            [a, b] => (b.1 - a.1) as f32,
+            _ => 0.0,
+        };
+    }
+
+    // Normal equations for y = a + b·x + c·x², x = 0 at the edge sample
+    let (mut s1, mut s2, mut s3, mut s4) = (0.0, 0.0, 0.0, 0.0);
+    let (mut sy, mut sxy, mut sx2y) = (0.0, 0.0, 0.0);
+    for &(x, y) in &samples {
+        s1 += x;
+        s2 += x * x;
+        s3 += x * x * x;
+        s4 += x * x * x * x;
+        sy += y;
+        sxy += x * y;
+        sx2y += x * x * y;
+    }
+    let s0 = n as f64;
+
+    let det = |m: [[f64; 3]; 3]| {
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    };
+    let d = det([[s0, s1, s2], [s1, s2, s3], [s2, s3, s4]]);
+    if d.abs() < 1e-12 {
+        return 0.0;
+    }
+    // Cramer's rule for b (derivative at x = 0)
+    let db = det([[s0, sy, s2], [s1, sxy, s3], [s2, sx2y, s4]]);
+    (db / d) as f32
 }
 
 /// Statistics about declipping
@@ -340,6 +511,127 @@ mod tests {
         assert!(diff > 0.0, "Declipping should modify clipped regions");
     }
 
+    #[test]
+    fn test_declip_masking_skips_inaudible_repairs() {
+        // Sine clipped at 0.99 with broadband noise away from its peaks, so
+        // the repaired regions themselves stay clean
+        let clipped = |amplitude: f32| -> Vec<f32> {
+            let mut state = 0x9e37_79b9_7f4a_7c15u64;
+            (0..4096)
+                .map(|i| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    let sine = (i as f32 * 0.03).sin();
+                    let noise = ((state >> 40) as f32 / (1u64 << 24) as f32 - 0.5) * 0.16;
+                    let noise = if sine.abs() < 0.9 { noise } else { 0.0 };
+                    (sine * amplitude + noise).clamp(-0.99, 0.99)
+                })
+                .collect()
+        };
+        let run = |input: &[f32], masking: Option<f32>| -> f32 {
+            let mut declip = Declip::new(DeclipConfig::default());
+            declip.set_masking_aggressiveness(masking);
+            let mut output = vec![0.0f32; input.len()];
+            declip.process(input, &mut output).unwrap();
+            input.iter().zip(&output).map(|(a, b)| (a - b).abs()).sum()
+        };
+
+        // Barely clipped: the repair is masked by the program and skipped
+        let light = clipped(0.9905);
+        assert!(run(&light, None) > 0.0);
+        assert_eq!(run(&light, Some(0.0)), 0.0);
+
+        // Hard clipped: the distortion is audible, so it's still repaired
+        let heavy = clipped(1.6);
+        assert!(run(&heavy, Some(0.0)) > 0.0);
+    }
+
+    /// Total harmonic distortion (harmonics 2-10) of a sine at `cycles` per buffer
+    fn thd(signal: &[f32], cycles: usize) -> f64 {
+        let power = |bin: usize| {
+            let (mut re, mut im) = (0.0f64, 0.0f64);
+            for (n, &s) in signal.iter().enumerate() {
+                let phase = std::f64::consts::TAU * (bin * n) as f64 / signal.len() as f64;
+                re += s as f64 * phase.cos();
+                im -= s as f64 * phase.sin();
+            }
+            re * re + im * im
+        };
+        let harmonics: f64 = (2..=10).map(|h| power(cycles * h)).sum();
+        (harmonics / power(cycles)).sqrt()
+    }
+
+    #[test]
+    fn test_declip_slope_continuity_and_thd() {
+        let mut declip = Declip::new(DeclipConfig::default());
+
+        // 50 cycles of a 96-sample-period sine at 1.25, hard clipped at 1.0
+        let period = 96;
+        let n = period * 50;
+        let clean: Vec<f32> = (0..n)
+            .map(|i| (std::f32::consts::TAU * i as f32 / period as f32).sin() * 1.25)
+            .collect();
+        let input: Vec<f32> = clean.iter().map(|s| s.clamp(-1.0, 1.0)).collect();
+
+        let mut output = vec![0.0f32; n];
+        declip.process(&input, &mut output).unwrap();
+
+        // Slope is continuous across every region edge
+        declip.detect_clips(&input);
+        let regions = declip.clip_regions.clone();
+        assert!(!regions.is_empty());
+        let max_step = (std::f32::consts::TAU / period as f32) * 1.25;
+        for &(start, end) in &regions {
+            if start < 2 || end + 1 >= n {
+                continue;
+            }
+            for edge in [start, end] {
+                let before = output[edge - 1] - output[edge - 2];
+                let after = output[edge] - output[edge - 1];
+                assert!(
+                    (after - before).abs() < max_step * 0.2,
+                    "Slope jump at {}: {} -> {}",
+                    edge,
+                    before,
+                    after
+                );
+            }
+        }
+
+        // Peaks restored above the clip level, capped by headroom
+        let peak = output.iter().fold(0.0f32, |p, s| p.max(s.abs()));
+        assert!(peak > 1.1 && peak < 2.0, "Reconstructed peak: {}", peak);
+
+        let thd_in = thd(&input, 50);
+        let thd_out = thd(&output, 50);
+        assert!(
+            thd_out < thd_in * 0.5,
+            "THD not reduced: {} -> {}",
+            thd_in,
+            thd_out
+        );
+    }
+
+    #[test]
+    fn test_declip_headroom_cap() {
+        let config = DeclipConfig {
+            headroom_db: 0.0,
+            ..Default::default()
+        };
+        let mut declip = Declip::new(config);
+
+        let input: Vec<f32> = (0..960)
+            .map(|i| ((std::f32::consts::TAU * i as f32 / 96.0).sin() * 1.5).clamp(-1.0, 1.0))
+            .collect();
+        let mut output = vec![0.0f32; 960];
+        declip.process(&input, &mut output).unwrap();
+
+        // Zero headroom: reconstruction never exceeds the clip level
+        let peak = output.iter().fold(0.0f32, |p, s| p.max(s.abs()));
+        assert!(peak <= 1.0 + 1e-6, "Peak exceeds cap: {}", peak);
+    }
+
     #[test]
     fn test_soft_limit() {
         let config = DeclipConfig::default();
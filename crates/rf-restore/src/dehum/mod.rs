@@ -5,12 +5,15 @@
 //! - Remove up to 16 harmonics
 //! - Adaptive notch filters
 //! - Phase-locked tracking
+//! - Optional masking-aware mode: harmonics masked by the program are left in
 
 use crate::error::{RestoreError, RestoreResult};
+use crate::masking::{self, MaskingModel};
 use crate::{RestoreConfig, Restorer};
+use serde::{Deserialize, Serialize};
 
 /// Dehum configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DehumConfig {
     /// Base configuration
     pub base: RestoreConfig,
@@ -121,8 +124,25 @@ pub struct Dehum {
     detection_buffer: Vec<f32>,
     /// Detection position
     detection_pos: usize,
+    /// Masking model (when masking-aware processing is enabled)
+    masking: Option<MaskingModel>,
+    /// Recent input for masking analysis
+    masking_history: Vec<f32>,
+    /// Magnitude spectrum of `masking_history`
+    masking_spectrum: Vec<f32>,
+    /// Spectrum with the hum harmonics removed (the masker)
+    masker_spectrum: Vec<f32>,
+    /// Masking threshold per bin
+    masking_threshold: Vec<f32>,
+    /// Per-notch wet amount (0 = bypassed because masked, 1 = active)
+    notch_mix: Vec<f32>,
+    /// Per-notch wet amount to reach by the end of the current block
+    notch_target: Vec<f32>,
 }
 
+/// FFT size for masking analysis (~6 Hz bins at 48 kHz, resolves 50 Hz harmonics)
+const MASKING_FFT_SIZE: usize = 8192;
+
 impl Dehum {
     /// Create new dehum processor
     pub fn new(config: DehumConfig, sample_rate: u32) -> Self {
@@ -141,7 +161,22 @@ impl Dehum {
             detected_freq: freq,
             detection_buffer: vec![0.0; sample_rate as usize], // 1 second buffer
             detection_pos: 0,
+            masking: None,
+            masking_history: Vec::new(),
+            masking_spectrum: Vec::new(),
+            masker_spectrum: Vec::new(),
+            masking_threshold: Vec::new(),
+            notch_mix: Vec::new(),
+            notch_target: Vec::new(),
         }
+        .with_masking()
+    }
+
+    /// Build the masking model if the config enables it
+    fn with_masking(mut self) -> Self {
+        let aggressiveness = self.config.base.masking_aggressiveness;
+        self.set_masking_aggressiveness(aggressiveness);
+        self
     }
 
     /// Create notch filter bank
@@ -211,6 +246,72 @@ impl Dehum {
         }
     }
 
+    /// Decide per harmonic whether its hum is audible over the recent input
+    ///
+    /// Sets the target wet amount for each notch: 0 when the harmonic is
+    /// masked by the rest of the spectrum, 1 otherwise.
+    fn update_masking_targets(&mut self, input: &[f32]) {
+        let Some(model) = self.masking.as_mut() else {
+            self.notch_target.fill(1.0);
+            return;
+        };
+        let aggressiveness = self.config.base.masking_aggressiveness.unwrap_or(0.0);
+
+        // Slide the analysis window
+        let len = self.masking_history.len();
+        let n = input.len().min(len);
+        self.masking_history.copy_within(n.., 0);
+        self.masking_history[len - n..].copy_from_slice(&input[input.len() - n..]);
+
+        model.magnitudes(&self.masking_history, &mut self.masking_spectrum);
+
+        // The hum can't mask itself: threshold from the spectrum without it
+        self.masker_spectrum.copy_from_slice(&self.masking_spectrum);
+        let bins = self.masker_spectrum.len();
+        for notch in &self.notches {
+            let center = model.bin_of(notch.freq, self.sample_rate);
+            let lo = center.saturating_sub(2);
+            let hi = (center + 2).min(bins - 1);
+            self.masker_spectrum[lo..=hi].fill(0.0);
+        }
+        model.threshold(&self.masker_spectrum, &mut self.masking_threshold);
+
+        for (target, notch) in self.notch_target.iter_mut().zip(&self.notches) {
+            let bin = model.bin_of(notch.freq, self.sample_rate);
+            let masked = masking::is_masked(
+                self.masking_spectrum[bin],
+                self.masking_threshold[bin],
+                aggressiveness,
+            );
+            *target = if masked { 0.0 } else { 1.0 };
+        }
+    }
+
+    /// Notch cascade with masked harmonics crossfaded out
+    ///
+    /// Every notch keeps running so its state is valid when it fades back
+    /// in; the wet amount ramps to its target across the block.
+    fn process_masked(&mut self, input: &[f32], output: &mut [f32]) -> RestoreResult<()> {
+        self.update_masking_targets(input);
+
+        let ramp = 1.0 / input.len().max(1) as f32;
+        for (i, &sample) in input.iter().enumerate() {
+            let t = (i + 1) as f32 * ramp;
+            let mut processed = sample as f64;
+
+            for (k, notch) in self.notches.iter_mut().enumerate() {
+                let mix = self.notch_mix[k] + (self.notch_target[k] - self.notch_mix[k]) * t;
+                let filtered = notch.process(processed);
+                processed += mix as f64 * (filtered - processed);
+            }
+
+            output[i] = processed as f32;
+        }
+        self.notch_mix.copy_from_slice(&self.notch_target);
+
+        Ok(())
+    }
+
     /// Get detected hum frequency
     pub fn detected_frequency(&self) -> f32 {
         self.detected_freq
@@ -232,6 +333,10 @@ impl Restorer for Dehum {
             self.update_filters(detected);
         }
 
+        if self.masking.is_some() {
+            return self.process_masked(input, output);
+        }
+
         // Apply notch filters in cascade
         for (i, &sample) in input.iter().enumerate() {
             let mut processed = sample as f64;
@@ -252,6 +357,8 @@ impl Restorer for Dehum {
         }
         self.detection_buffer.fill(0.0);
         self.detection_pos = 0;
+        self.masking_history.fill(0.0);
+        self.notch_mix.fill(1.0);
     }
 
     fn latency_samples(&self) -> usize {
@@ -261,6 +368,25 @@ impl Restorer for Dehum {
     fn name(&self) -> &str {
         "Dehum"
     }
+
+    fn set_masking_aggressiveness(&mut self, aggressiveness: Option<f32>) {
+        self.config.base.masking_aggressiveness = aggressiveness.map(|a| a.clamp(0.0, 1.0));
+        if aggressiveness.is_none() {
+            self.masking = None;
+            return;
+        }
+        if self.masking.is_none() {
+            let model = MaskingModel::new(MASKING_FFT_SIZE, self.sample_rate);
+            let bins = model.bins();
+            self.masking = Some(model);
+            self.masking_history = vec![0.0; MASKING_FFT_SIZE];
+            self.masking_spectrum = vec![0.0; bins];
+            self.masker_spectrum = vec![0.0; bins];
+            self.masking_threshold = vec![0.0; bins];
+            self.notch_mix = vec![1.0; self.notches.len()];
+            self.notch_target = vec![1.0; self.notches.len()];
+        }
+    }
 }
 
 #[cfg(test)]
@@ -318,4 +444,33 @@ mod tests {
 
         assert!(output_energy < input_energy * 0.5, "Hum should be reduced");
     }
+
+    #[test]
+    fn test_dehum_masking_keeps_audible_hum_notched() {
+        let config = DehumConfig {
+            frequency: 50.0,
+            harmonics: 4,
+            ..Default::default()
+        };
+        let mut dehum = Dehum::new(config, 48000);
+        dehum.set_masking_aggressiveness(Some(0.5));
+
+        // Hum alone is never masked, so it must still be removed
+        let hum: Vec<f32> = (0..48000)
+            .map(|i| (2.0 * std::f32::consts::PI * 50.0 * i as f32 / 48000.0).sin() * 0.5)
+            .collect();
+        let mut output = vec![0.0f32; hum.len()];
+        for (block_in, block_out) in hum.chunks(512).zip(output.chunks_mut(512)) {
+            dehum.process(block_in, block_out).unwrap();
+        }
+
+        let tail = 24000..;
+        let input_energy: f32 = hum[tail.clone()].iter().map(|s| s * s).sum();
+        let output_energy: f32 = output[tail].iter().map(|s| s * s).sum();
+        assert!(
+            output_energy < input_energy * 0.01,
+            "{}",
+            output_energy / input_energy
+        );
+    }
 }
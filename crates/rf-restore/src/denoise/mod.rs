@@ -9,13 +9,15 @@
 //! - Musical noise suppression
 
 use crate::error::{RestoreError, RestoreResult};
+use crate::masking::{self, MaskingModel};
 use crate::{RestoreConfig, Restorer};
 use realfft::{RealFftPlanner, RealToComplex};
 use rustfft::num_complex::Complex;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 /// Denoise configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DenoiseConfig {
     /// Base configuration
     pub base: RestoreConfig,
@@ -151,6 +153,10 @@ pub struct Denoise {
     is_learning: bool,
     /// Reduction gain linear
     reduction_gain: f32,
+    /// Masking model (when masking-aware processing is enabled)
+    masking: Option<MaskingModel>,
+    /// Per-bin masking threshold of the current frame
+    masking_threshold: Vec<f32>,
 }
 
 impl Denoise {
@@ -191,7 +197,17 @@ impl Denoise {
             output_pos: 0,
             is_learning: false,
             reduction_gain,
+            masking: None,
+            masking_threshold: vec![0.0; bins],
         }
+        .with_masking()
+    }
+
+    /// Build the masking model if the config enables it
+    fn with_masking(mut self) -> Self {
+        let aggressiveness = self.config.base.masking_aggressiveness;
+        self.set_masking_aggressiveness(aggressiveness);
+        self
     }
 
     /// Start learning noise profile
@@ -269,8 +285,30 @@ impl Denoise {
             / (self.sample_rate as f32 * self.config.smoothing_time))
             .exp();
 
+        // Noise masked by the signal is inaudible: leave those bins alone
+        let masking_aggressiveness = self.config.base.masking_aggressiveness.unwrap_or(0.0);
+        let masking_active = if let Some(model) = self.masking.as_mut() {
+            model.threshold(magnitudes, &mut self.masking_threshold);
+            true
+        } else {
+            false
+        };
+
         for (i, spectrum_bin) in self.spectrum.iter_mut().enumerate() {
             let input_mag = magnitudes[i];
+            if masking_active
+                && masking::is_masked(
+                    self.noise_profile.magnitude[i],
+                    self.masking_threshold[i],
+                    masking_aggressiveness,
+                )
+            {
+                let smoothed_gain = alpha * self.prev_gains[i] + (1.0 - alpha);
+                self.prev_gains[i] = smoothed_gain;
+                *spectrum_bin *= smoothed_gain;
+                continue;
+            }
+
             let noise_mag = self.noise_profile.magnitude[i] * self.reduction_gain;
             let _noise_var = self.noise_profile.variance[i];
 
@@ -428,6 +466,23 @@ impl Restorer for Denoise {
     fn name(&self) -> &str {
         "Denoise"
     }
+
+    fn analyze_offline(&mut self, input: &[f32]) -> RestoreResult<()> {
+        // Whole-file noise profile from the quietest frames
+        self.estimate_noise_auto(input);
+        self.reset();
+        Ok(())
+    }
+
+    fn set_masking_aggressiveness(&mut self, aggressiveness: Option<f32>) {
+        self.config.base.masking_aggressiveness = aggressiveness.map(|a| a.clamp(0.0, 1.0));
+        self.masking =
+            aggressiveness.map(|_| MaskingModel::new(self.config.fft_size, self.sample_rate));
+    }
+
+    fn apply_noise_profile(&mut self, profile: &NoiseProfile) {
+        self.set_noise_profile(profile.clone());
+    }
 }
 
 /// Voice-optimized denoiser with enhanced speech preservation
@@ -495,6 +550,10 @@ impl Restorer for VoiceDenoise {
     fn name(&self) -> &str {
         "VoiceDenoise"
     }
+
+    fn set_masking_aggressiveness(&mut self, aggressiveness: Option<f32>) {
+        self.denoise.set_masking_aggressiveness(aggressiveness);
+    }
 }
 
 #[cfg(test)]
@@ -589,6 +648,68 @@ mod tests {
         assert_eq!(voice_denoise.name(), "VoiceDenoise");
     }
 
+    #[test]
+    fn test_masked_noise_left_untouched() {
+        let sr = 48000;
+        let config = DenoiseConfig {
+            reduction_db: 0.0,
+            ..Default::default()
+        };
+
+        // Low-level white noise (~-50 dBFS)
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let mut noise = |len: usize| -> Vec<f32> {
+            (0..len)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    ((state >> 40) as f32 / (1u64 << 24) as f32 - 0.5) * 0.01
+                })
+                .collect()
+        };
+        let learn = noise(sr as usize / 2);
+
+        // Loud 1 kHz tone over the same noise
+        let input: Vec<f32> = noise(sr as usize)
+            .iter()
+            .enumerate()
+            .map(|(i, n)| {
+                n + 0.5 * (2.0 * std::f32::consts::PI * 1000.0 * i as f32 / sr as f32).sin()
+            })
+            .collect();
+
+        // Steady-state per-bin gains
+        let run = |masking: Option<f32>| -> Vec<f32> {
+            let mut denoise = Denoise::new(config.clone(), sr);
+            denoise.set_masking_aggressiveness(masking);
+            let mut scratch = vec![0.0f32; learn.len()];
+            denoise.start_learning();
+            denoise.process(&learn, &mut scratch).unwrap();
+            denoise.stop_learning();
+            denoise.reset();
+
+            let mut output = vec![0.0f32; input.len()];
+            denoise.process(&input, &mut output).unwrap();
+            denoise.prev_gains
+        };
+        let mean = |gains: &[f32], lo: f32, hi: f32| {
+            let bin = |freq: f32| (freq * 2048.0 / sr as f32).round() as usize;
+            let range = &gains[bin(lo)..=bin(hi)];
+            range.iter().sum::<f32>() / range.len() as f32
+        };
+
+        // Noise just above the tone is masked: untouched. Far above: reduced.
+        let masked = run(Some(0.0));
+        assert!(mean(&masked, 1250.0, 1500.0) > 0.95, "{:?}", masked);
+        assert!(mean(&masked, 6000.0, 10000.0) < 0.25, "{:?}", masked);
+
+        // Without masking both ranges are reduced
+        let unmasked = run(None);
+        assert!(mean(&unmasked, 1250.0, 1500.0) < 0.3, "{:?}", unmasked);
+        assert!(mean(&unmasked, 6000.0, 10000.0) < 0.25, "{:?}", unmasked);
+    }
+
     // Simple pseudo-random for testing
     fn rand_simple(seed: usize) -> f32 {
         let x = seed.wrapping_mul(1103515245).wrapping_add(12345);
@@ -11,10 +11,11 @@ use crate::error::{RestoreError, RestoreResult};
 use crate::{RestoreConfig, Restorer};
 use realfft::{RealFftPlanner, RealToComplex};
 use rustfft::num_complex::Complex;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 /// Dereverb configuration
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DereverbConfig {
     /// Base configuration
     pub base: RestoreConfig,
@@ -397,6 +398,16 @@ impl Restorer for Dereverb {
     fn name(&self) -> &str {
         "Dereverb"
     }
+
+    fn analyze_offline(&mut self, input: &[f32]) -> RestoreResult<()> {
+        // Seed the per-bin decay from the whole-file T60 instead of adapting from 0.95
+        let t60 = self.estimate_t60(input).max(0.05);
+        let hop_seconds = self.config.hop_size as f32 / self.sample_rate as f32;
+        let decay = 10.0_f32.powf(-3.0 * hop_seconds / t60).clamp(0.5, 0.999);
+        self.decay_rate.fill(decay);
+        self.reverb_profile.t60.fill(t60);
+        Ok(())
+    }
 }
 
 /// Weighted Prediction Error (WPE) dereverberation
@@ -37,11 +37,14 @@ pub mod declip;
 pub mod dehum;
 pub mod denoise;
 pub mod dereverb;
+pub mod masking;
 
 pub mod analysis;
 mod error;
+mod profile;
 
 pub use error::{RestoreError, RestoreResult};
+pub use profile::{RestoreModule, RestoreProfile};
 
 use serde::{Deserialize, Serialize};
 
@@ -56,6 +59,13 @@ pub struct RestoreConfig {
     pub overlap: f32,
     /// Quality level (0.0 = fast, 1.0 = best)
     pub quality: f32,
+    /// Psychoacoustic masking aggressiveness (0.0-1.0)
+    ///
+    /// `None` processes everything. With `Some`, modules leave components
+    /// that are masked by the signal untouched; higher values act further
+    /// below the masking threshold (see `masking::MAX_MASKING_MARGIN_DB`).
+    #[serde(default)]
+    pub masking_aggressiveness: Option<f32>,
 }
 
 impl Default for RestoreConfig {
@@ -65,6 +75,7 @@ impl Default for RestoreConfig {
             block_size: 2048,
             overlap: 0.75,
             quality: 0.8,
+            masking_aggressiveness: None,
         }
     }
 }
@@ -82,6 +93,26 @@ pub trait Restorer: Send + Sync {
 
     /// Get processing name
     fn name(&self) -> &str;
+
+    /// Analyze the full signal before offline processing
+    ///
+    /// Called by `RestorationPipeline::process_offline` after `reset` and before
+    /// the signal is processed. Modules that benefit from whole-file statistics
+    /// (noise profiles, reverb decay) override this; the default does nothing.
+    fn analyze_offline(&mut self, _input: &[f32]) -> RestoreResult<()> {
+        Ok(())
+    }
+
+    /// Enable (`Some(aggressiveness)`) or disable psychoacoustic masking
+    ///
+    /// Modules without a masking-aware mode ignore this.
+    fn set_masking_aggressiveness(&mut self, _aggressiveness: Option<f32>) {}
+
+    /// Apply a previously learned noise profile
+    ///
+    /// Learned state is not part of a `RestoreProfile`, so callers re-apply it
+    /// after building a pipeline. Modules without a noise profile ignore this.
+    fn apply_noise_profile(&mut self, _profile: &denoise::NoiseProfile) {}
 }
 
 /// Restoration analysis result
@@ -109,6 +140,8 @@ pub struct AnalysisResult {
 pub struct RestorationPipeline {
     /// Modules in processing order
     modules: Vec<Box<dyn Restorer>>,
+    /// Parameters each module was built from (`None` for opaque modules)
+    specs: Vec<Option<RestoreModule>>,
     /// Pipeline configuration
     config: RestoreConfig,
     /// Is active
@@ -120,14 +153,78 @@ impl RestorationPipeline {
     pub fn new(config: RestoreConfig) -> Self {
         Self {
             modules: Vec::new(),
+            specs: Vec::new(),
             config,
             active: true,
         }
     }
 
     /// Add restoration module
-    pub fn add_module(&mut self, module: Box<dyn Restorer>) {
+    pub fn add_module(&mut self, mut module: Box<dyn Restorer>) {
+        if self.config.masking_aggressiveness.is_some() {
+            module.set_masking_aggressiveness(self.config.masking_aggressiveness);
+        }
         self.modules.push(module);
+        self.specs.push(None);
+    }
+
+    /// Build a module from its parameters and add it, keeping it saveable
+    pub fn add_profile_module(&mut self, module: RestoreModule) {
+        let mut built = module.build(self.config.sample_rate);
+        if self.config.masking_aggressiveness.is_some() {
+            built.set_masking_aggressiveness(self.config.masking_aggressiveness);
+        }
+        self.modules.push(built);
+        self.specs.push(Some(module));
+    }
+
+    /// Build a pipeline from a saved profile
+    pub fn from_profile(profile: &RestoreProfile, config: RestoreConfig) -> Self {
+        let mut pipeline = Self::new(config);
+        for module in &profile.modules {
+            pipeline.add_profile_module(module.clone());
+        }
+        pipeline
+    }
+
+    /// Capture the module chain as an unnamed profile
+    ///
+    /// Fails if a module was added with `add_module`, since its parameters
+    /// are not known to the pipeline.
+    pub fn to_profile(&self) -> RestoreResult<RestoreProfile> {
+        let modules = self
+            .specs
+            .iter()
+            .zip(&self.modules)
+            .map(|(spec, module)| {
+                spec.clone().ok_or_else(|| {
+                    RestoreError::InvalidConfig(format!(
+                        "module '{}' was not built from parameters",
+                        module.name()
+                    ))
+                })
+            })
+            .collect::<RestoreResult<_>>()?;
+        Ok(RestoreProfile {
+            name: String::new(),
+            modules,
+        })
+    }
+
+    /// Set masking aggressiveness for all modules (`None` = process everything)
+    pub fn set_masking_aggressiveness(&mut self, aggressiveness: Option<f32>) {
+        let aggressiveness = aggressiveness.map(|a| a.clamp(0.0, 1.0));
+        self.config.masking_aggressiveness = aggressiveness;
+        for module in &mut self.modules {
+            module.set_masking_aggressiveness(aggressiveness);
+        }
+    }
+
+    /// Apply a learned noise profile to every module that uses one
+    pub fn apply_noise_profile(&mut self, profile: &denoise::NoiseProfile) {
+        for module in &mut self.modules {
+            module.apply_noise_profile(profile);
+        }
     }
 
     /// Set active state
@@ -163,6 +260,41 @@ impl RestorationPipeline {
         Ok(())
     }
 
+    /// Process a whole file offline (two-pass: analyze, then process)
+    ///
+    /// Each module first sees the entire signal via `Restorer::analyze_offline`,
+    /// then processes it with look-ahead: trailing silence flushes the module's
+    /// latency so the output is time-aligned with the input and the same length.
+    /// Modules are reset afterwards, ready for streaming `process`.
+    pub fn process_offline(&mut self, input: &[f32]) -> RestoreResult<Vec<f32>> {
+        if !self.active || self.modules.is_empty() {
+            return Ok(input.to_vec());
+        }
+
+        let block_size = self.config.block_size.max(1);
+        let mut signal = input.to_vec();
+
+        for module in &mut self.modules {
+            module.reset();
+            module.analyze_offline(&signal)?;
+
+            let latency = module.latency_samples();
+            signal.resize(input.len() + latency, 0.0);
+            let mut output = vec![0.0f32; signal.len()];
+            for (block_in, block_out) in
+                signal.chunks(block_size).zip(output.chunks_mut(block_size))
+            {
+                module.process(block_in, block_out)?;
+            }
+
+            output.drain(..latency);
+            signal = output;
+        }
+
+        self.reset();
+        Ok(signal)
+    }
+
     /// Get total latency
     pub fn total_latency(&self) -> usize {
         self.modules.iter().map(|m| m.latency_samples()).sum()
@@ -200,4 +332,112 @@ mod tests {
         // Should be passthrough with no modules
         assert_eq!(input, output);
     }
+
+    #[test]
+    fn test_profile_round_trip() {
+        let sample_rate = 48000;
+        let config = RestoreConfig::default();
+
+        let mut original = RestorationPipeline::new(config.clone());
+        original.add_profile_module(RestoreModule::Dehum(dehum::DehumConfig {
+            frequency: 50.0,
+            harmonics: 4,
+            ..Default::default()
+        }));
+        original.add_profile_module(RestoreModule::Denoise(denoise::DenoiseConfig {
+            reduction_db: 9.0,
+            ..Default::default()
+        }));
+
+        let mut profile = original.to_profile().unwrap();
+        profile.name = "hum + hiss".into();
+        let json = profile.to_json().unwrap();
+        let profile = RestoreProfile::from_json(&json).unwrap();
+        assert_eq!(profile.name, "hum + hiss");
+        assert_eq!(profile.modules.len(), 2);
+        let mut rebuilt = RestorationPipeline::from_profile(&profile, config);
+
+        let mut seed = 0x2468_ace1u32;
+        let input: Vec<f32> = (0..sample_rate as usize)
+            .map(|i| {
+                seed ^= seed << 13;
+                seed ^= seed >> 17;
+                seed ^= seed << 5;
+                let t = i as f32 / sample_rate as f32;
+                (2.0 * std::f32::consts::PI * 50.0 * t).sin() * 0.2
+                    + (seed as f32 / u32::MAX as f32 - 0.5) * 0.05
+            })
+            .collect();
+
+        let expected = original.process_offline(&input).unwrap();
+        let actual = rebuilt.process_offline(&input).unwrap();
+        assert_eq!(expected, actual);
+
+        // Opaque modules cannot be saved
+        original.add_module(Box::new(denoise::VoiceDenoise::new(sample_rate)));
+        assert!(original.to_profile().is_err());
+    }
+
+    #[test]
+    fn test_offline_denoise_beats_streaming() {
+        let sample_rate = 48000;
+        let len = sample_rate as usize * 2;
+
+        // 440 Hz tone from 0.5 s to 1.5 s over constant white noise
+        let mut seed = 0x1234_5678u32;
+        let clean: Vec<f32> = (0..len)
+            .map(|i| {
+                let t = i as f32 / sample_rate as f32;
+                if (0.5..1.5).contains(&t) {
+                    (2.0 * std::f32::consts::PI * 440.0 * t).sin() * 0.5
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        let noisy: Vec<f32> = clean
+            .iter()
+            .map(|&s| {
+                seed ^= seed << 13;
+                seed ^= seed >> 17;
+                seed ^= seed << 5;
+                s + (seed as f32 / u32::MAX as f32 - 0.5) * 0.1
+            })
+            .collect();
+
+        let new_pipeline = || {
+            let mut pipeline = RestorationPipeline::new(RestoreConfig::default());
+            pipeline.add_module(Box::new(denoise::Denoise::new(
+                denoise::DenoiseConfig {
+                    reduction_db: 6.0,
+                    ..Default::default()
+                },
+                sample_rate,
+            )));
+            pipeline
+        };
+
+        let mut streaming = vec![0.0f32; len];
+        let mut pipeline = new_pipeline();
+        for (block_in, block_out) in noisy.chunks(2048).zip(streaming.chunks_mut(2048)) {
+            pipeline.process(block_in, block_out).unwrap();
+        }
+
+        let offline = new_pipeline().process_offline(&noisy).unwrap();
+        assert_eq!(offline.len(), len);
+
+        // Residual noise in the sections where the clean signal is silent
+        let residual = |out: &[f32]| -> f32 {
+            let quiet = (len / 20..len / 5).chain(len * 4 / 5..len * 19 / 20);
+            quiet.map(|i| (out[i] - clean[i]).powi(2)).sum()
+        };
+        let streaming_residual = residual(&streaming);
+        let offline_residual = residual(&offline);
+        assert!(
+            offline_residual < streaming_residual * 0.5,
+            "offline residual {} vs streaming {}",
+            offline_residual,
+            streaming_residual
+        );
+    }
 }
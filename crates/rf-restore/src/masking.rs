@@ -0,0 +1,191 @@
+//! Psychoacoustic masking model shared by the restoration modules
+//!
+//! Estimates, per FFT bin, the level below which an added or removed
+//! component is inaudible next to the program material:
+//! - Bins grouped into critical bands (1 Bark each, Zwicker's Bark scale)
+//! - Band energies spread across neighbouring bands (Schroeder spreading)
+//! - Tone-masking-noise offset of `14.5 + band` dB (conservative)
+//!
+//! Modules compare the component they would remove (noise, hum, clipping
+//! distortion) against the threshold and leave it untouched when it is
+//! masked. `aggressiveness` (0.0-1.0) moves that decision point from the
+//! threshold itself down to `MAX_MASKING_MARGIN_DB` below it, so higher
+//! values still act on components that are only slightly masked.
+//!
+//! No absolute threshold of hearing is applied: playback level is unknown,
+//! so only masking by the signal itself counts.
+
+use realfft::{RealFftPlanner, RealToComplex};
+use rustfft::num_complex::Complex;
+use std::sync::Arc;
+
+/// How far below the masking threshold modules act at aggressiveness 1.0 (dB)
+pub const MAX_MASKING_MARGIN_DB: f32 = 24.0;
+
+/// Number of critical bands (0-24 Bark)
+const NUM_BANDS: usize = 25;
+
+/// Bark value of a frequency (Zwicker & Terhardt)
+pub fn bark(freq: f32) -> f32 {
+    13.0 * (0.00076 * freq).atan() + 3.5 * (freq / 7500.0).powi(2).atan()
+}
+
+/// Linear amplitude factor applied to the threshold for an aggressiveness
+pub fn masking_margin(aggressiveness: f32) -> f32 {
+    10.0_f32.powf(-aggressiveness.clamp(0.0, 1.0) * MAX_MASKING_MARGIN_DB / 20.0)
+}
+
+/// Is a component of magnitude `level` masked by `threshold` (same scale)?
+#[inline]
+pub fn is_masked(level: f32, threshold: f32, aggressiveness: f32) -> bool {
+    level <= threshold * masking_margin(aggressiveness)
+}
+
+/// Per-bin masking threshold estimator
+pub struct MaskingModel {
+    /// FFT size
+    fft_size: usize,
+    /// Forward FFT (for `magnitudes`)
+    fft: Arc<dyn RealToComplex<f32>>,
+    /// Hann analysis window
+    window: Vec<f32>,
+    /// FFT input scratch
+    scratch: Vec<f32>,
+    /// FFT output scratch
+    spectrum: Vec<Complex<f32>>,
+    /// Critical band of each bin
+    bin_band: Vec<usize>,
+    /// Bin count per band
+    band_bins: [usize; NUM_BANDS],
+    /// Spreading gain from band `b` (row) to band `j` (column), linear power
+    spreading: Vec<f32>,
+    /// Band energy scratch
+    band_energy: [f32; NUM_BANDS],
+}
+
+impl MaskingModel {
+    /// Create a model for spectra of `fft_size / 2 + 1` bins
+    pub fn new(fft_size: usize, sample_rate: u32) -> Self {
+        let bins = fft_size / 2 + 1;
+        let mut planner = RealFftPlanner::<f32>::new();
+
+        let window: Vec<f32> = (0..fft_size)
+            .map(|i| {
+                let phase = 2.0 * std::f32::consts::PI * i as f32 / fft_size as f32;
+                0.5 * (1.0 - phase.cos())
+            })
+            .collect();
+
+        let bin_hz = sample_rate as f32 / fft_size as f32;
+        let bin_band: Vec<usize> = (0..bins)
+            .map(|i| (bark(i as f32 * bin_hz) as usize).min(NUM_BANDS - 1))
+            .collect();
+        let mut band_bins = [0; NUM_BANDS];
+        for &band in &bin_band {
+            band_bins[band] += 1;
+        }
+
+        let mut spreading = vec![0.0; NUM_BANDS * NUM_BANDS];
+        for b in 0..NUM_BANDS {
+            for j in 0..NUM_BANDS {
+                let dz = j as f32 - b as f32 + 0.474;
+                let db = 15.81 + 7.5 * dz - 17.5 * (1.0 + dz * dz).sqrt();
+                spreading[b * NUM_BANDS + j] = 10.0_f32.powf(db / 10.0);
+            }
+        }
+
+        Self {
+            fft_size,
+            fft: planner.plan_fft_forward(fft_size),
+            window,
+            scratch: vec![0.0; fft_size],
+            spectrum: vec![Complex::new(0.0, 0.0); bins],
+            bin_band,
+            band_bins,
+            spreading,
+            band_energy: [0.0; NUM_BANDS],
+        }
+    }
+
+    /// FFT size
+    pub fn fft_size(&self) -> usize {
+        self.fft_size
+    }
+
+    /// Number of spectrum bins
+    pub fn bins(&self) -> usize {
+        self.bin_band.len()
+    }
+
+    /// Hann-windowed magnitude spectrum of `samples` (zero-padded or truncated
+    /// to the FFT size), on the same scale as the restoration STFTs
+    pub fn magnitudes(&mut self, samples: &[f32], out: &mut [f32]) {
+        let n = samples.len().min(self.fft_size);
+        self.scratch.fill(0.0);
+        for i in 0..n {
+            self.scratch[i] = samples[i] * self.window[i];
+        }
+        self.fft.process(&mut self.scratch, &mut self.spectrum).ok();
+        for (o, c) in out.iter_mut().zip(&self.spectrum) {
+            *o = c.norm();
+        }
+    }
+
+    /// Masking threshold per bin (magnitude) for a magnitude spectrum
+    pub fn threshold(&mut self, magnitudes: &[f32], out: &mut [f32]) {
+        self.band_energy = [0.0; NUM_BANDS];
+        for (&mag, &band) in magnitudes.iter().zip(&self.bin_band) {
+            self.band_energy[band] += mag * mag;
+        }
+
+        let mut band_threshold = [0.0f32; NUM_BANDS];
+        for (j, threshold) in band_threshold.iter_mut().enumerate() {
+            let spread: f32 = (0..NUM_BANDS)
+                .map(|b| self.band_energy[b] * self.spreading[b * NUM_BANDS + j])
+                .sum();
+            let offset_db = 14.5 + j as f32;
+            // Spread energy per bin, back to magnitude
+            *threshold = (spread * 10.0_f32.powf(-offset_db / 10.0)
+                / self.band_bins[j].max(1) as f32)
+                .sqrt();
+        }
+
+        for (o, &band) in out.iter_mut().zip(&self.bin_band) {
+            *o = band_threshold[band];
+        }
+    }
+
+    /// Bin index of a frequency
+    pub fn bin_of(&self, freq: f32, sample_rate: u32) -> usize {
+        ((freq * self.fft_size as f32 / sample_rate as f32).round() as usize).min(self.bins() - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_threshold_follows_masker() {
+        let sample_rate = 48000;
+        let fft_size = 2048;
+        let mut model = MaskingModel::new(fft_size, sample_rate);
+        let tone: Vec<f32> = (0..fft_size)
+            .map(|i| (2.0 * std::f32::consts::PI * 1000.0 * i as f32 / sample_rate as f32).sin())
+            .collect();
+
+        let mut mags = vec![0.0; model.bins()];
+        let mut threshold = vec![0.0; model.bins()];
+        model.magnitudes(&tone, &mut mags);
+        model.threshold(&mags, &mut threshold);
+
+        // Highest near the masker, far lower an octave+ away, below the tone itself
+        let tone_bin = model.bin_of(1000.0, sample_rate);
+        let far_bin = model.bin_of(8000.0, sample_rate);
+        assert!(threshold[tone_bin] < mags[tone_bin]);
+        assert!(threshold[tone_bin] > threshold[far_bin] * 1000.0);
+        let near = threshold[tone_bin];
+        assert!(is_masked(near * 0.5, near, 0.0));
+        assert!(!is_masked(near * 0.5, near, 1.0));
+    }
+}
@@ -0,0 +1,69 @@
+//! Batch restoration profiles
+//!
+//! A profile records the ordered module chain of a `RestorationPipeline` with
+//! each module's parameters, so a chain tuned on one file can be saved as JSON
+//! and re-applied to a batch. Only parameters are stored: learned state such
+//! as captured noise profiles is rebuilt by each run.
+
+use serde::{Deserialize, Serialize};
+
+use crate::Restorer;
+use crate::declick::{Declick, DeclickConfig, Decrackle};
+use crate::declip::{Declip, DeclipConfig};
+use crate::dehum::{Dehum, DehumConfig};
+use crate::denoise::{Denoise, DenoiseConfig, VoiceDenoise};
+use crate::dereverb::{Dereverb, DereverbConfig, WpeDereverb};
+use crate::error::{RestoreError, RestoreResult};
+
+/// A restoration module with its parameters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "module", content = "params")]
+pub enum RestoreModule {
+    Declick(DeclickConfig),
+    Decrackle,
+    Declip(DeclipConfig),
+    Dehum(DehumConfig),
+    Denoise(DenoiseConfig),
+    VoiceDenoise,
+    Dereverb(DereverbConfig),
+    WpeDereverb(DereverbConfig),
+}
+
+impl RestoreModule {
+    /// Instantiate the module
+    pub fn build(&self, sample_rate: u32) -> Box<dyn Restorer> {
+        match self {
+            RestoreModule::Declick(config) => Box::new(Declick::new(config.clone(), sample_rate)),
+            RestoreModule::Decrackle => Box::new(Decrackle::new(sample_rate)),
+            RestoreModule::Declip(config) => Box::new(Declip::new(config.clone())),
+            RestoreModule::Dehum(config) => Box::new(Dehum::new(config.clone(), sample_rate)),
+            RestoreModule::Denoise(config) => Box::new(Denoise::new(config.clone(), sample_rate)),
+            RestoreModule::VoiceDenoise => Box::new(VoiceDenoise::new(sample_rate)),
+            RestoreModule::Dereverb(config) => Box::new(Dereverb::new(config.clone(), sample_rate)),
+            RestoreModule::WpeDereverb(config) => {
+                Box::new(WpeDereverb::new(config.clone(), sample_rate))
+            }
+        }
+    }
+}
+
+/// Saved restoration chain
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RestoreProfile {
+    /// Profile name
+    pub name: String,
+    /// Modules in processing order
+    pub modules: Vec<RestoreModule>,
+}
+
+impl RestoreProfile {
+    /// Serialize to pretty-printed JSON
+    pub fn to_json(&self) -> RestoreResult<String> {
+        serde_json::to_string_pretty(self).map_err(|e| RestoreError::InvalidConfig(e.to_string()))
+    }
+
+    /// Parse from JSON
+    pub fn from_json(json: &str) -> RestoreResult<Self> {
+        serde_json::from_str(json).map_err(|e| RestoreError::InvalidConfig(e.to_string()))
+    }
+}
@@ -6,11 +6,13 @@
 //! - Batch operations
 //! - Macro recording
 //! - External control
+//! - Scheduled (recurring) execution driven by host ticks
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 
 use crossbeam_channel::{Receiver, Sender, bounded};
 use mlua::{HookTriggers, Lua, Table, UserData, UserDataMethods, VmState};
@@ -840,17 +842,24 @@ impl ScriptEngine {
         self.lua
             .load(&script.source)
             .set_name(&script.name)
-            .exec()?;
-
-        Ok(())
+            .exec()
+            .map_err(|e| self.exec_error(e))
     }
 
     /// Execute inline Lua code
     pub fn execute(&self, code: &str) -> ScriptResult<()> {
         // BUG#40: reset instruction counter before each execution
         self.instruction_count.store(0, Ordering::Relaxed);
-        self.lua.load(code).exec()?;
-        Ok(())
+        self.lua.load(code).exec().map_err(|e| self.exec_error(e))
+    }
+
+    /// `Timeout` if the instruction limit hook aborted the run, else the Lua error
+    fn exec_error(&self, e: mlua::Error) -> ScriptError {
+        if self.instruction_count.load(Ordering::Relaxed) >= MAX_SCRIPT_INSTRUCTIONS {
+            ScriptError::Timeout
+        } else {
+            ScriptError::LuaError(e)
+        }
     }
 
     /// Execute and return result
@@ -914,6 +923,14 @@ impl Default for ScriptEngine {
 
 // ============ Script Manager ============
 
+/// Recurring script registration
+struct ScheduledScript {
+    name: String,
+    interval: Duration,
+    /// Next due time (None until the first tick anchors the schedule)
+    next_due: Option<Duration>,
+}
+
 /// Manages script discovery and execution
 pub struct ScriptManager {
     engine: ScriptEngine,
@@ -921,6 +938,8 @@ pub struct ScriptManager {
     user_scripts_dir: Option<PathBuf>,
     /// Built-in scripts
     builtin_scripts: HashMap<String, &'static str>,
+    /// Recurring scripts, run from `tick()`
+    schedules: Vec<ScheduledScript>,
 }
 
 impl ScriptManager {
@@ -929,6 +948,7 @@ impl ScriptManager {
             engine: ScriptEngine::new()?,
             user_scripts_dir: None,
             builtin_scripts: HashMap::new(),
+            schedules: Vec::new(),
         };
 
         // Add built-in scripts
@@ -1029,6 +1049,86 @@ end
         self.engine.execute(code)
     }
 
+    /// Run a script every `interval`, driven by `tick()`
+    ///
+    /// The first run happens one interval after the next tick. Re-scheduling
+    /// a script replaces its interval.
+    pub fn schedule(&mut self, name: &str, interval: Duration) -> ScriptResult<()> {
+        if !self.engine.scripts.contains_key(name) && !self.builtin_scripts.contains_key(name) {
+            return Err(ScriptError::NotFound(name.into()));
+        }
+        if interval.is_zero() {
+            return Err(ScriptError::InvalidScript(format!(
+                "schedule interval for '{}' must be non-zero",
+                name
+            )));
+        }
+
+        self.unschedule(name);
+        self.schedules.push(ScheduledScript {
+            name: name.into(),
+            interval,
+            next_due: None,
+        });
+        Ok(())
+    }
+
+    /// Stop a recurring script; returns false if it was not scheduled
+    pub fn unschedule(&mut self, name: &str) -> bool {
+        let before = self.schedules.len();
+        self.schedules.retain(|s| s.name != name);
+        self.schedules.len() != before
+    }
+
+    /// Names of scheduled scripts
+    pub fn scheduled_scripts(&self) -> Vec<&str> {
+        self.schedules.iter().map(|s| s.name.as_str()).collect()
+    }
+
+    /// Run due scheduled scripts and return the actions they queued
+    ///
+    /// `now` is host time on any monotonic base. Cooperative: scripts run on
+    /// the caller's thread, each under the instruction limit. A script that
+    /// hits the limit is unscheduled; other failures are logged and retried
+    /// at the next interval. Missed intervals are not replayed.
+    /// Drains the action queue, so pending non-scheduled actions are returned too.
+    pub fn tick(&mut self, now: Duration) -> Vec<ScriptAction> {
+        let mut timed_out = Vec::new();
+
+        for index in 0..self.schedules.len() {
+            let schedule = &self.schedules[index];
+            let interval = schedule.interval;
+            let due = match schedule.next_due {
+                Some(due) if now >= due => due,
+                Some(_) => continue,
+                None => {
+                    self.schedules[index].next_due = Some(now + interval);
+                    continue;
+                }
+            };
+
+            // Keep the cadence, skipping intervals missed by slow ticks
+            let missed = (now - due).as_nanos() / interval.as_nanos();
+            self.schedules[index].next_due = Some(due + interval * (missed as u32 + 1));
+
+            let name = self.schedules[index].name.clone();
+            match self.execute(&name) {
+                Ok(()) => {}
+                Err(ScriptError::Timeout) => {
+                    log::warn!("[Script] '{}' exceeded execution limit, unscheduled", name);
+                    timed_out.push(name);
+                }
+                Err(e) => log::warn!("[Script] scheduled '{}' failed: {}", name, e),
+            }
+        }
+
+        for name in timed_out {
+            self.unschedule(&name);
+        }
+
+        self.engine.poll_actions()
+    }
+
     /// Get the engine
     pub fn engine(&self) -> &ScriptEngine {
         &self.engine
@@ -1101,6 +1201,68 @@ mod tests {
         matches!(&actions[1], ScriptAction::SetPlayhead(48000));
     }
 
+    #[test]
+    fn test_scheduled_script_fires_on_interval() {
+        let mut manager = ScriptManager::new().unwrap();
+        manager.engine().update_context(ScriptContext {
+            selected_clips: vec![7],
+            ..Default::default()
+        });
+        manager
+            .schedule("normalize_clips", Duration::from_millis(100))
+            .unwrap();
+        assert!(
+            manager
+                .schedule("missing", Duration::from_millis(100))
+                .is_err()
+        );
+
+        // 350 ms of 10 ms ticks: runs at 100, 200 and 300 ms
+        let mut runs = 0;
+        for ms in (0..=350).step_by(10) {
+            let actions = manager.tick(Duration::from_millis(ms));
+            runs += actions
+                .iter()
+                .filter(|a| {
+                    matches!(a, ScriptAction::Custom { name, data }
+                        if name == "normalize" && data == "7")
+                })
+                .count();
+        }
+        assert_eq!(runs, 3);
+
+        assert!(manager.unschedule("normalize_clips"));
+        assert!(manager.tick(Duration::from_millis(1000)).is_empty());
+    }
+
+    #[test]
+    fn test_scheduled_script_timeout_unschedules() {
+        let mut manager = ScriptManager::new().unwrap();
+        manager
+            .builtin_scripts
+            .insert("spin".into(), "while true do end");
+        manager.schedule("spin", Duration::from_millis(50)).unwrap();
+
+        manager.tick(Duration::ZERO);
+        manager.tick(Duration::from_millis(50));
+        assert!(manager.scheduled_scripts().is_empty());
+
+        // A script error that merely mentions the limit is retried, not unscheduled
+        manager
+            .builtin_scripts
+            .insert("fails".into(), "error('script execution limit exceeded')");
+        manager
+            .schedule("fails", Duration::from_millis(50))
+            .unwrap();
+        manager.tick(Duration::from_millis(100));
+        manager.tick(Duration::from_millis(150));
+        assert_eq!(manager.scheduled_scripts().len(), 1);
+        assert!(matches!(
+            manager.execute_code("while true do end"),
+            Err(ScriptError::Timeout)
+        ));
+    }
+
     #[test]
     fn test_script_context() {
         let engine = ScriptEngine::new().unwrap();
@@ -8,8 +8,8 @@ use rf_stage::StageEvent;
 
 use crate::features::{
     ActivationContext, CascadesChapter, FeatureCategory, FeatureChapter, FeatureRegistry,
-    FreeSpinsChapter, GambleChapter, HoldAndWinChapter, JackpotChapter, PickBonusChapter,
-    SpinContext,
+    FeatureState, FreeSpinsChapter, GambleChapter, HoldAndWinChapter, JackpotChapter,
+    PickBonusChapter, SpinContext,
 };
 use crate::model::{GameMode, GameModel};
 use crate::paytable::PayTable;
@@ -526,10 +526,15 @@ impl SlotEngineV2 {
             result.generate_stages_with_config(&mut self.timestamp_gen, &antic_config);
 
         // Add feature stages
-        for (_, feature) in self.features.iter() {
+        for (_, feature) in self.features.iter_mut() {
             if feature.is_active() {
                 let feature_stages = feature.generate_stages(&mut self.timestamp_gen);
                 stages.extend(feature_stages);
+
+                // Outro stages have been emitted: the feature is done
+                if feature.state() == FeatureState::Completing {
+                    feature.deactivate();
+                }
             }
         }
 
@@ -1335,6 +1340,7 @@ impl Default for SlotEngineV2 {
 mod tests {
     use super::*;
     use crate::features::FeatureId;
+    use rf_stage::Stage;
 
     #[test]
     fn test_engine_v2_creation() {
@@ -1380,6 +1386,46 @@ mod tests {
         assert!(!stages.is_empty());
     }
 
+    #[test]
+    fn test_engine_v2_losing_spin_emits_no_cascade_stages() {
+        let mut engine = SlotEngineV2::new();
+        engine.seed(777);
+
+        for _ in 0..3 {
+            let (result, stages) = engine.spin_forced_with_stages(ForcedOutcome::Lose);
+            assert_eq!(result.total_win, 0.0);
+            assert!(!engine.is_cascade_active());
+            assert!(!stages.iter().any(|e| matches!(
+                e.stage,
+                Stage::CascadeStart | Stage::CascadeStep { .. } | Stage::CascadeEnd { .. }
+            )));
+        }
+    }
+
+    #[test]
+    fn test_engine_v2_cascade_inactive_after_end_stage() {
+        let mut engine = SlotEngineV2::new();
+        engine.seed(777);
+
+        let (result, _) = engine.spin_forced_with_stages(ForcedOutcome::SmallWin);
+        assert!(result.total_win > 0.0);
+        assert!(engine.is_cascade_active());
+
+        let (_, stages) = engine.spin_forced_with_stages(ForcedOutcome::Lose);
+        let ends = stages
+            .iter()
+            .filter(|e| matches!(e.stage, Stage::CascadeEnd { .. }))
+            .count();
+        assert_eq!(ends, 1);
+        assert!(!engine.is_cascade_active());
+        assert!(engine.cascade_state().is_none());
+
+        // The ended sequence is not emitted again
+        let result = engine.spin_forced(ForcedOutcome::Lose);
+        let stages = engine.generate_stages(&result);
+        assert!(!stages.iter().any(|e| matches!(e.stage, Stage::CascadeEnd { .. })));
+    }
+
     #[test]
     fn test_engine_v2_feature_registry() {
         let model = GameModel::standard_5x3("Feature Test", "ftest");
@@ -1,4 +1,11 @@
 //! Cascades/Tumble Feature Chapter
+//!
+//! Each winning cascade emits a `CascadeStep` stage carrying the multiplier
+//! applied to that cascade, so audio can escalate per step. The first
+//! non-winning cascade (or reaching `max_depth`) moves the chapter into
+//! `Completing`, which emits a single `CascadeEnd`; the engine deactivates the
+//! chapter once that stage has been generated. A losing spin that never
+//! cascaded deactivates silently.
 
 use rf_stage::{Stage, StageEvent};
 use serde::{Deserialize, Serialize};
@@ -49,6 +56,10 @@ struct CascadeState {
     total_win: f64,
     current_multiplier: f64,
     peak_multiplier: f64,
+    /// Step index and applied multiplier of the latest winning cascade
+    last_step: Option<(u32, f64)>,
+    /// Sequence ended, `CascadeEnd` pending
+    completing: bool,
 }
 
 /// Cascades Feature Chapter
@@ -128,7 +139,9 @@ impl FeatureChapter for CascadesChapter {
     }
 
     fn state(&self) -> FeatureState {
-        if self.state.is_active {
+        if self.state.completing {
+            FeatureState::Completing
+        } else if self.state.is_active {
             FeatureState::Active
         } else {
             FeatureState::Inactive
@@ -170,8 +183,8 @@ impl FeatureChapter for CascadesChapter {
     }
 
     fn can_activate(&self, context: &ActivationContext) -> bool {
-        // Cascades activate on any win
-        !self.state.is_active && context.bet > 0.0
+        // Cascades activate on any win; a finished sequence restarts
+        (!self.state.is_active || self.state.completing) && context.bet > 0.0
     }
 
     fn activate(&mut self, _context: &ActivationContext) {
@@ -181,11 +194,14 @@ impl FeatureChapter for CascadesChapter {
             total_win: 0.0,
             current_multiplier: self.get_multiplier_for_step(0),
             peak_multiplier: 1.0,
+            last_step: None,
+            completing: false,
         };
     }
 
     fn deactivate(&mut self) {
         self.state.is_active = false;
+        self.state.completing = false;
     }
 
     fn reset(&mut self) {
@@ -201,6 +217,7 @@ impl FeatureChapter for CascadesChapter {
 
         // No win = cascade ends
         if base_win <= 0.0 {
+            self.state.last_step = None;
             return FeatureResult::complete(0.0)
                 .with_multiplier(self.state.peak_multiplier)
                 .with_data(
@@ -213,6 +230,8 @@ impl FeatureChapter for CascadesChapter {
         let multiplied_win = base_win * self.state.current_multiplier;
         self.state.total_win += multiplied_win;
 
+        self.state.last_step = Some((self.state.current_step, self.state.current_multiplier));
+
         // Track peak multiplier
         if self.state.current_multiplier > self.state.peak_multiplier {
             self.state.peak_multiplier = self.state.current_multiplier;
@@ -232,24 +251,37 @@ impl FeatureChapter for CascadesChapter {
     }
 
     fn post_spin(&mut self, _context: &SpinContext, result: &FeatureResult) {
-        if !result.continues() {
+        if result.continues() || !self.state.is_active {
+            return;
+        }
+        if self.state.current_step > 0 {
+            // Stay running until the end stage has been generated
+            self.state.completing = true;
+        } else {
+            // No cascade happened: nothing to end
             self.deactivate();
         }
     }
 
     fn generate_stages(&self, timing: &mut TimestampGenerator) -> Vec<StageEvent> {
-        if !self.state.is_active || self.state.current_step == 0 {
+        if !self.state.is_active {
             return Vec::new();
         }
 
-        let timestamp = timing.cascade_step();
-        vec![StageEvent::new(
-            Stage::CascadeStep {
-                step_index: self.state.current_step - 1,
-                multiplier: self.state.current_multiplier,
-            },
-            timestamp,
-        )]
+        let mut stages = Vec::new();
+        if let Some((step_index, multiplier)) = self.state.last_step {
+            stages.push(StageEvent::new(
+                Stage::CascadeStep {
+                    step_index,
+                    multiplier,
+                },
+                timing.cascade_step(),
+            ));
+        }
+        if self.state.completing {
+            stages.extend(self.generate_deactivation_stages(timing));
+        }
+        stages
     }
 
     fn generate_activation_stages(&self, timing: &mut TimestampGenerator) -> Vec<StageEvent> {
@@ -301,4 +333,55 @@ mod tests {
         assert!((chapter.get_multiplier_for_step(1) - 2.0).abs() < 0.001);
         assert!((chapter.get_multiplier_for_step(2) - 3.0).abs() < 0.001);
     }
+
+    #[test]
+    fn test_cascade_sequence_stages() {
+        let mut chapter = CascadesChapter::new();
+        let mut timing = TimestampGenerator::new(crate::timing::TimingConfig::default());
+        let mut stages = Vec::new();
+
+        chapter.activate(&ActivationContext::new(0, 1.0));
+        for win in [2.0, 1.0, 0.5, 0.0] {
+            let mut context = SpinContext::new(1.0);
+            context.accumulated_win = win;
+            let result = chapter.process_spin(&mut context);
+            chapter.post_spin(&context, &result);
+            stages.extend(chapter.generate_stages(&mut timing));
+        }
+
+        let steps: Vec<(u32, f64)> = stages
+            .iter()
+            .filter_map(|e| match e.stage {
+                Stage::CascadeStep {
+                    step_index,
+                    multiplier,
+                } => Some((step_index, multiplier)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(steps, vec![(0, 1.0), (1, 2.0), (2, 3.0)]);
+        assert_eq!(stages.len(), 4);
+        assert!(matches!(
+            stages[3].stage,
+            Stage::CascadeEnd { total_steps: 3, total_win } if (total_win - 5.5).abs() < 1e-9
+        ));
+        assert_eq!(chapter.state(), FeatureState::Completing);
+
+        // Next spin starts a fresh sequence
+        assert!(chapter.can_activate(&ActivationContext::new(0, 1.0)));
+    }
+
+    #[test]
+    fn test_losing_spin_ends_without_stages() {
+        let mut chapter = CascadesChapter::new();
+        let mut timing = TimestampGenerator::new(crate::timing::TimingConfig::default());
+
+        chapter.activate(&ActivationContext::new(0, 1.0));
+        let mut context = SpinContext::new(1.0);
+        let result = chapter.process_spin(&mut context);
+        chapter.post_spin(&context, &result);
+
+        assert_eq!(chapter.state(), FeatureState::Inactive);
+        assert!(chapter.generate_stages(&mut timing).is_empty());
+    }
 }
@@ -106,4 +106,6 @@ pub use parser::{
 };
 // P5 RegularWinConfig re-export (used by CalibrationResult)
 pub use model::RegularWinConfig;
-pub use scenario::{DemoScenario, LoopMode, ScenarioPlayback, ScriptedOutcome};
+pub use scenario::{
+    trace_to_json, DemoScenario, LoopMode, ScenarioPlayback, ScriptedOutcome, TraceEntry,
+};
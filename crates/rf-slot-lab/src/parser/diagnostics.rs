@@ -0,0 +1,174 @@
+//! GDD Diagnostics — collect every problem in a document in one pass
+//!
+//! Unlike `GddSchema::validate`, which stops at the first error, diagnostics
+//! gather all errors with a source line so a designer can fix them together.
+//! Both run the same rule checks, which report into a [`Violations`] sink.
+//! Lines are resolved by locating the offending key or value in the source text.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use super::{GddDocument, GddLimits, GddParseError, GddParser, GddSchema, validator};
+
+/// A single problem found in a GDD, with its source location
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GddDiagnostic {
+    /// Document path of the offending field (e.g. `math.symbol_weights.WILD2`)
+    pub path: String,
+    /// 1-based source line, if it could be located
+    pub line: Option<usize>,
+    /// Human-readable description
+    pub message: String,
+}
+
+impl GddDiagnostic {
+    fn new(path: impl Into<String>, line: Option<usize>, message: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            line,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for GddDiagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line {
+            Some(line) => write!(f, "{} at line {}", self.message, line),
+            None => write!(f, "{} ({})", self.message, self.path),
+        }
+    }
+}
+
+/// Diagnostic for a JSON syntax/shape error
+pub(crate) fn from_json_error(err: &serde_json::Error) -> GddDiagnostic {
+    let line = (err.line() > 0).then_some(err.line());
+    GddDiagnostic::new("", line, format!("invalid JSON: {}", err))
+}
+
+/// Diagnostic for a YAML syntax/shape error
+pub(crate) fn from_yaml_error(err: &serde_yml::Error) -> GddDiagnostic {
+    let line = err.location().map(|l| l.line());
+    GddDiagnostic::new("", line, format!("invalid YAML: {}", err))
+}
+
+/// A failed GDD rule, with the source keys leading to the offending field
+pub(crate) struct Violation {
+    path: String,
+    keys: Vec<String>,
+    error: GddParseError,
+}
+
+/// Sink the GDD rule checks report into
+///
+/// The fail-fast validators keep only the first violation; `collect` keeps them all.
+#[derive(Default)]
+pub(crate) struct Violations(Vec<Violation>);
+
+impl Violations {
+    /// Record a violation at `path`, located in the source by following `keys`
+    pub(crate) fn push(&mut self, path: impl Into<String>, keys: &[&str], error: GddParseError) {
+        self.0.push(Violation {
+            path: path.into(),
+            keys: keys.iter().map(|k| k.to_string()).collect(),
+            error,
+        });
+    }
+
+    /// Number of violations recorded
+    #[cfg(test)]
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The first violation as an error, or `Ok` if every rule passed
+    pub(crate) fn into_result(self) -> Result<(), GddParseError> {
+        match self.0.into_iter().next() {
+            Some(v) => Err(v.error),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Check a parsed document, returning every error found
+///
+/// Runs the parser limits, the schema rules and the `math.symbol_weights`
+/// consistency check. When several rules flag the same path only the first is kept.
+pub(crate) fn collect(doc: &GddDocument, limits: &GddLimits, source: &str) -> Vec<GddDiagnostic> {
+    let mut violations = Violations::default();
+    GddParser::with_limits(limits.clone()).check_limits(doc, &mut violations);
+
+    let schema = GddSchema {
+        max_reels: limits.max_reels.min(u8::MAX as usize) as u8,
+        max_rows: limits.max_rows.min(u8::MAX as usize) as u8,
+        max_name_length: limits.max_name_length,
+        ..GddSchema::default()
+    };
+    schema.check(doc, &mut violations);
+
+    for (name, message) in validator::symbol_weight_issues(doc) {
+        violations.push(
+            format!("math.symbol_weights.{}", name),
+            &["math", "symbol_weights", name],
+            GddParseError::ValidationError(message),
+        );
+    }
+
+    let mut seen = HashSet::new();
+    let mut out: Vec<GddDiagnostic> = violations
+        .0
+        .into_iter()
+        .filter(|v| seen.insert(v.path.clone()))
+        .map(|v| {
+            let keys: Vec<&str> = v.keys.iter().map(String::as_str).collect();
+            GddDiagnostic::new(v.path, locate(source, &keys), v.error.to_string())
+        })
+        .collect();
+
+    out.sort_by_key(|d| d.line.unwrap_or(usize::MAX));
+    out
+}
+
+/// Find the line of the last needle, searching each needle after the previous one
+fn locate(source: &str, needles: &[&str]) -> Option<usize> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut start = 0;
+    for needle in needles {
+        start += lines[start..]
+            .iter()
+            .position(|line| line_mentions(line, needle))?;
+    }
+    Some(start + 1)
+}
+
+/// Whether a JSON or YAML line contains `token` as a key or a scalar value
+fn line_mentions(line: &str, token: &str) -> bool {
+    let trimmed = line.trim().trim_start_matches("- ");
+    line.contains(&format!("\"{}\"", token))
+        || line.contains(&format!("'{}'", token))
+        || trimmed.starts_with(&format!("{}:", token))
+        || trimmed.ends_with(&format!(": {}", token))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate_nested_keys() {
+        let source = "game:\n  name: Test\ngrid:\n  reels: 5\n  rows: 3\n";
+        assert_eq!(locate(source, &["grid", "rows"]), Some(5));
+        assert_eq!(locate(source, &["game", "name"]), Some(2));
+        assert_eq!(locate(source, &["math"]), None);
+    }
+
+    #[test]
+    fn test_diagnostic_display() {
+        let located = GddDiagnostic::new("grid.reels", Some(4), "bad reels");
+        assert_eq!(located.to_string(), "bad reels at line 4");
+
+        let unlocated = GddDiagnostic::new("grid.reels", None, "bad reels");
+        assert_eq!(unlocated.to_string(), "bad reels (grid.reels)");
+    }
+}
@@ -13,13 +13,21 @@
 //! ```rust,ignore
 //! let parser = GddParser::new();
 //! let model = parser.parse_json(json_string)?;
+//!
+//! // Or report every problem at once, with line numbers
+//! match parser.parse(source) {
+//!     Err(GddParseError::Diagnostics(errors)) => errors.iter().for_each(|e| eprintln!("{e}")),
+//!     other => { /* ... */ }
+//! }
 //! ```
 
+pub mod diagnostics;
 pub mod schema;
 pub mod validator;
 pub mod par;
 pub mod par_plus;
 
+pub use diagnostics::GddDiagnostic;
 pub use schema::GddSchema;
 pub use validator::{ValidationReport, validate_constraints};
 pub use par::{
@@ -85,6 +93,26 @@ impl GddParser {
         Self { limits }
     }
 
+    /// Parse a JSON or YAML GDD, collecting every error instead of stopping at the first
+    ///
+    /// JSON is detected by a leading `{`; anything else is read as YAML. On failure
+    /// returns `GddParseError::Diagnostics` with each problem and its source line.
+    pub fn parse(&self, source: &str) -> Result<GameModel, GddParseError> {
+        let doc: GddDocument = if source.trim_start().starts_with('{') {
+            serde_json::from_str(source)
+                .map_err(|e| GddParseError::Diagnostics(vec![diagnostics::from_json_error(&e)]))?
+        } else {
+            serde_yml::from_str(source)
+                .map_err(|e| GddParseError::Diagnostics(vec![diagnostics::from_yaml_error(&e)]))?
+        };
+
+        let errors = diagnostics::collect(&doc, &self.limits, source);
+        if !errors.is_empty() {
+            return Err(GddParseError::Diagnostics(errors));
+        }
+        self.to_game_model(doc)
+    }
+
     /// Parse JSON GDD into GameModel
     pub fn parse_json(&self, json: &str) -> Result<GameModel, GddParseError> {
         let doc: GddDocument =
@@ -161,40 +189,79 @@ impl GddParser {
 
     /// Validate GDD document
     pub fn validate(&self, doc: &GddDocument) -> Result<(), GddParseError> {
+        let mut violations = diagnostics::Violations::default();
+        self.check_limits(doc, &mut violations);
+        violations.into_result()
+    }
+
+    /// Check the document against the parser limits, reporting every violation
+    pub(crate) fn check_limits(&self, doc: &GddDocument, out: &mut diagnostics::Violations) {
         // Check name length
         if doc.game.name.len() > self.limits.max_name_length {
-            return Err(GddParseError::ValidationError(format!(
-                "Game name too long: {} > {}",
-                doc.game.name.len(),
-                self.limits.max_name_length
-            )));
+            out.push(
+                "game.name",
+                &["game", "name"],
+                GddParseError::ValidationError(format!(
+                    "Game name too long: {} > {}",
+                    doc.game.name.len(),
+                    self.limits.max_name_length
+                )),
+            );
         }
 
         // Check symbol count
         if doc.symbols.len() > self.limits.max_symbols {
-            return Err(GddParseError::ValidationError(format!(
-                "Too many symbols: {} > {}",
-                doc.symbols.len(),
-                self.limits.max_symbols
-            )));
+            out.push(
+                "symbols",
+                &["symbols"],
+                GddParseError::ValidationError(format!(
+                    "Too many symbols: {} > {}",
+                    doc.symbols.len(),
+                    self.limits.max_symbols
+                )),
+            );
         }
 
         // Check grid
-        if doc.grid.reels > self.limits.max_reels as u8 {
-            return Err(GddParseError::ValidationError(format!(
-                "Too many reels: {} > {}",
-                doc.grid.reels, self.limits.max_reels
-            )));
+        if doc.grid.reels as usize > self.limits.max_reels {
+            out.push(
+                "grid.reels",
+                &["grid", "reels"],
+                GddParseError::ValidationError(format!(
+                    "Too many reels: {} > {}",
+                    doc.grid.reels, self.limits.max_reels
+                )),
+            );
         }
 
-        if doc.grid.rows > self.limits.max_rows as u8 {
-            return Err(GddParseError::ValidationError(format!(
-                "Too many rows: {} > {}",
-                doc.grid.rows, self.limits.max_rows
-            )));
+        if doc.grid.rows as usize > self.limits.max_rows {
+            out.push(
+                "grid.rows",
+                &["grid", "rows"],
+                GddParseError::ValidationError(format!(
+                    "Too many rows: {} > {}",
+                    doc.grid.rows, self.limits.max_rows
+                )),
+            );
         }
 
-        Ok(())
+        // Check pay values
+        for (i, sym) in doc.symbols.iter().enumerate() {
+            if let Some(pay) = sym
+                .pays
+                .iter()
+                .find(|p| !p.is_finite() || **p < 0.0 || **p > self.limits.max_pay_value)
+            {
+                out.push(
+                    format!("symbols[{}].pays", i),
+                    &["symbols", &sym.name, "pays"],
+                    GddParseError::ValidationError(format!(
+                        "Symbol '{}' has pay {} outside 0-{}",
+                        sym.name, pay, self.limits.max_pay_value
+                    )),
+                );
+            }
+        }
     }
 
     /// Convert GDD document to GameModel
@@ -468,6 +535,15 @@ pub enum GddParseError {
 
     #[error("Invalid value: {0}")]
     InvalidValue(String),
+
+    #[error("{}", format_diagnostics(.0))]
+    Diagnostics(Vec<GddDiagnostic>),
+}
+
+/// Join diagnostics into one message, one per line
+fn format_diagnostics(errors: &[GddDiagnostic]) -> String {
+    let lines: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    format!("{} error(s) in GDD:\n{}", errors.len(), lines.join("\n"))
 }
 
 /// Check if feature type is a built-in feature
@@ -541,6 +617,52 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_parse_reports_all_errors_with_lines() {
+        let json = r#"{
+    "game": { "name": "Broken", "id": "broken" },
+    "grid": { "reels": 5, "rows": 3 },
+    "symbols": [
+        { "id": 1, "name": "Zeus", "type": "regular", "pays": [0, 0, 5, 15, 50] },
+        {
+            "id": 2,
+            "name": "Wild",
+            "type": "wild",
+            "pays": [0, 0, 10, 20, 80, 200]
+        }
+    ],
+    "math": {
+        "target_rtp": 0.96,
+        "symbol_weights": {
+            "Zeus": [10, 10, 10, 10, 10],
+            "WILD2": [1, 1, 1, 1, 1]
+        }
+    }
+}"#;
+
+        let parser = GddParser::new();
+        let Err(GddParseError::Diagnostics(errors)) = parser.parse(json) else {
+            panic!("expected diagnostics");
+        };
+
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].path, "symbols[1].pays");
+        assert_eq!(errors[0].line, Some(10));
+        assert_eq!(
+            errors[0].to_string(),
+            "Validation error: symbols[1] 'Wild' has 6 pays but the grid has 5 reels at line 10"
+        );
+        assert_eq!(errors[1].path, "math.symbol_weights.WILD2");
+        assert_eq!(
+            errors[1].to_string(),
+            "Validation error: math.symbol_weights references unknown symbol 'WILD2' at line 17"
+        );
+
+        // A clean document still parses
+        let fixed = json.replace(", 200]", "]").replace("\"WILD2\"", "\"Wild\"");
+        assert!(parser.parse(&fixed).is_ok());
+    }
+
     #[test]
     fn test_parse_yaml_minimal() {
         let yaml = r#"
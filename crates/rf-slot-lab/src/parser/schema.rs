@@ -3,6 +3,7 @@
 //! Typed schema validation rules for GDD documents.
 //! Validates structure, field types, required fields, and value ranges.
 
+use super::diagnostics::Violations;
 use super::{GddDocument, GddParseError};
 
 /// Schema validation configuration.
@@ -105,32 +106,55 @@ impl Default for GddSchema {
 impl GddSchema {
     /// Validate a GDD document against this schema.
     pub fn validate(&self, doc: &GddDocument) -> Result<Vec<String>, GddParseError> {
+        let mut violations = Violations::default();
+        let warnings = self.check(doc, &mut violations);
+        violations.into_result().map(|()| warnings)
+    }
+
+    /// Check a document against this schema, reporting every error and returning the warnings.
+    pub(crate) fn check(&self, doc: &GddDocument, out: &mut Violations) -> Vec<String> {
         let mut warnings = Vec::new();
 
         // ── Required Fields ──
         if self.require_game_name && doc.game.name.is_empty() {
-            return Err(GddParseError::MissingField("game.name".into()));
+            out.push(
+                "game.name",
+                &["game"],
+                GddParseError::MissingField("game.name".into()),
+            );
         }
 
         if self.require_game_id && doc.game.id.is_empty() {
-            return Err(GddParseError::MissingField("game.id".into()));
+            out.push(
+                "game.id",
+                &["game"],
+                GddParseError::MissingField("game.id".into()),
+            );
         }
 
         // ── Name Length ──
         if doc.game.name.len() > self.max_name_length {
-            return Err(GddParseError::ValidationError(format!(
-                "game.name exceeds max length: {} > {}",
-                doc.game.name.len(),
-                self.max_name_length
-            )));
+            out.push(
+                "game.name",
+                &["game", "name"],
+                GddParseError::ValidationError(format!(
+                    "game.name exceeds max length: {} > {}",
+                    doc.game.name.len(),
+                    self.max_name_length
+                )),
+            );
         }
 
         if doc.game.id.len() > self.max_name_length {
-            return Err(GddParseError::ValidationError(format!(
-                "game.id exceeds max length: {} > {}",
-                doc.game.id.len(),
-                self.max_name_length
-            )));
+            out.push(
+                "game.id",
+                &["game", "id"],
+                GddParseError::ValidationError(format!(
+                    "game.id exceeds max length: {} > {}",
+                    doc.game.id.len(),
+                    self.max_name_length
+                )),
+            );
         }
 
         // ── Game ID Format ──
@@ -140,25 +164,37 @@ impl GddSchema {
             .chars()
             .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
         {
-            return Err(GddParseError::InvalidValue(format!(
-                "game.id must be alphanumeric with underscores/hyphens, got: {}",
-                doc.game.id
-            )));
+            out.push(
+                "game.id",
+                &["game", "id"],
+                GddParseError::InvalidValue(format!(
+                    "game.id must be alphanumeric with underscores/hyphens, got: {}",
+                    doc.game.id
+                )),
+            );
         }
 
         // ── Grid ──
         if doc.grid.reels < self.min_reels || doc.grid.reels > self.max_reels {
-            return Err(GddParseError::ValidationError(format!(
-                "grid.reels must be {}-{}, got: {}",
-                self.min_reels, self.max_reels, doc.grid.reels
-            )));
+            out.push(
+                "grid.reels",
+                &["grid", "reels"],
+                GddParseError::ValidationError(format!(
+                    "grid.reels must be {}-{}, got: {}",
+                    self.min_reels, self.max_reels, doc.grid.reels
+                )),
+            );
         }
 
         if doc.grid.rows < self.min_rows || doc.grid.rows > self.max_rows {
-            return Err(GddParseError::ValidationError(format!(
-                "grid.rows must be {}-{}, got: {}",
-                self.min_rows, self.max_rows, doc.grid.rows
-            )));
+            out.push(
+                "grid.rows",
+                &["grid", "rows"],
+                GddParseError::ValidationError(format!(
+                    "grid.rows must be {}-{}, got: {}",
+                    self.min_rows, self.max_rows, doc.grid.rows
+                )),
+            );
         }
 
         // ── Volatility ──
@@ -175,10 +211,14 @@ impl GddSchema {
         // ── RTP ──
         if let Some(rtp) = doc.game.target_rtp
             && (rtp < self.rtp_range.0 || rtp > self.rtp_range.1) {
-                return Err(GddParseError::InvalidValue(format!(
-                    "target_rtp must be {:.2}-{:.3}, got: {:.4}",
-                    self.rtp_range.0, self.rtp_range.1, rtp
-                )));
+                out.push(
+                    "game.target_rtp",
+                    &["game", "target_rtp"],
+                    GddParseError::InvalidValue(format!(
+                        "target_rtp must be {:.2}-{:.3}, got: {:.4}",
+                        self.rtp_range.0, self.rtp_range.1, rtp
+                    )),
+                );
             }
 
         // ── Win Mechanism ──
@@ -192,21 +232,31 @@ impl GddSchema {
 
         // ── Symbols ──
         if self.require_symbols && doc.symbols.is_empty() {
-            return Err(GddParseError::MissingField(
-                "symbols (at least one required)".into(),
-            ));
+            out.push(
+                "symbols",
+                &["symbols"],
+                GddParseError::MissingField("symbols (at least one required)".into()),
+            );
         }
 
         for (i, sym) in doc.symbols.iter().enumerate() {
             if sym.name.is_empty() {
-                return Err(GddParseError::MissingField(format!("symbols[{}].name", i)));
+                out.push(
+                    format!("symbols[{}].name", i),
+                    &["symbols"],
+                    GddParseError::MissingField(format!("symbols[{}].name", i)),
+                );
             }
 
             if sym.id > self.max_symbol_id {
-                return Err(GddParseError::InvalidValue(format!(
-                    "symbols[{}].id exceeds max: {} > {}",
-                    i, sym.id, self.max_symbol_id
-                )));
+                out.push(
+                    format!("symbols[{}].id", i),
+                    &["symbols", &sym.name],
+                    GddParseError::InvalidValue(format!(
+                        "symbols[{}].id exceeds max: {} > {}",
+                        i, sym.id, self.max_symbol_id
+                    )),
+                );
             }
 
             let type_lower = sym.symbol_type.to_lowercase();
@@ -216,16 +266,41 @@ impl GddSchema {
                     i, sym.name, sym.symbol_type, self.valid_symbol_types
                 ));
             }
+
+            // One pay per symbol count, so never more pays than reels
+            if doc.grid.reels > 0 && sym.pays.len() > doc.grid.reels as usize {
+                out.push(
+                    format!("symbols[{}].pays", i),
+                    &["symbols", &sym.name, "pays"],
+                    GddParseError::ValidationError(format!(
+                        "symbols[{}] '{}' has {} pays but the grid has {} reels",
+                        i,
+                        sym.name,
+                        sym.pays.len(),
+                        doc.grid.reels
+                    )),
+                );
+            }
         }
 
-        // ── Check for duplicate symbol IDs ──
+        // ── Check for duplicate symbol IDs and names ──
         let mut seen_ids = std::collections::HashSet::new();
-        for sym in &doc.symbols {
+        let mut seen_names = std::collections::HashSet::new();
+        for (i, sym) in doc.symbols.iter().enumerate() {
             if !seen_ids.insert(sym.id) {
-                return Err(GddParseError::ValidationError(format!(
-                    "Duplicate symbol ID: {}",
-                    sym.id
-                )));
+                out.push(
+                    format!("symbols[{}].id", i),
+                    &["symbols", &sym.name],
+                    GddParseError::ValidationError(format!("Duplicate symbol ID: {}", sym.id)),
+                );
+            }
+
+            if !seen_names.insert(sym.name.as_str()) {
+                out.push(
+                    format!("symbols[{}].name", i),
+                    &["symbols", &sym.name],
+                    GddParseError::ValidationError(format!("Duplicate symbol name: {}", sym.name)),
+                );
             }
         }
 
@@ -255,23 +330,31 @@ impl GddSchema {
 
             if let (Some(min), Some(max)) = (tier.min_ratio, tier.max_ratio)
                 && min >= max {
-                    return Err(GddParseError::InvalidValue(format!(
-                        "win_tiers[{}] '{}': min_ratio ({}) must be less than max_ratio ({})",
-                        i, tier.name, min, max
-                    )));
+                    out.push(
+                        format!("win_tiers[{}]", i),
+                        &["win_tiers", &tier.name],
+                        GddParseError::InvalidValue(format!(
+                            "win_tiers[{}] '{}': min_ratio ({}) must be less than max_ratio ({})",
+                            i, tier.name, min, max
+                        )),
+                    );
                 }
         }
 
         // ── Math Model ──
         if let Some(ref math) = doc.math
             && (math.target_rtp < self.rtp_range.0 || math.target_rtp > self.rtp_range.1) {
-                return Err(GddParseError::InvalidValue(format!(
-                    "math.target_rtp must be {:.2}-{:.3}, got: {:.4}",
-                    self.rtp_range.0, self.rtp_range.1, math.target_rtp
-                )));
+                out.push(
+                    "math.target_rtp",
+                    &["math", "target_rtp"],
+                    GddParseError::InvalidValue(format!(
+                        "math.target_rtp must be {:.2}-{:.3}, got: {:.4}",
+                        self.rtp_range.0, self.rtp_range.1, math.target_rtp
+                    )),
+                );
             }
 
-        Ok(warnings)
+        warnings
     }
 }
 
@@ -349,6 +432,24 @@ mod tests {
         assert!(schema.validate(&doc).is_err());
     }
 
+    #[test]
+    fn test_check_reports_every_error() {
+        let schema = GddSchema::default();
+        let mut doc = minimal_doc();
+        doc.grid.reels = 0;
+        doc.game.target_rtp = Some(1.5);
+
+        let mut violations = Violations::default();
+        schema.check(&doc, &mut violations);
+        assert_eq!(violations.len(), 2);
+
+        // validate still fails on the first one
+        assert!(matches!(
+            schema.validate(&doc),
+            Err(GddParseError::ValidationError(msg)) if msg.starts_with("grid.reels")
+        ));
+    }
+
     #[test]
     fn test_unknown_volatility_warning() {
         let schema = GddSchema::default();
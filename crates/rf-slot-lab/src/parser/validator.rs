@@ -261,27 +261,50 @@ fn validate_math_consistency(doc: &GddDocument, report: &mut ValidationReport) {
             }
         }
 
+        for (_, message) in symbol_weight_issues(doc) {
+            report.add_warning(message);
+        }
+    }
+}
+
+/// `math.symbol_weights` entries naming unknown symbols or with the wrong reel count,
+/// as (symbol name, message) pairs sorted by name
+pub(crate) fn symbol_weight_issues(doc: &GddDocument) -> Vec<(&str, String)> {
+    let Some(ref math) = doc.math else {
+        return Vec::new();
+    };
+
+    let mut weights: Vec<_> = math.symbol_weights.iter().collect();
+    weights.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut issues = Vec::new();
+    for (sym_name, weights) in weights {
         // Check symbol weights reference existing symbols
-        for (sym_name, weights) in &math.symbol_weights {
-            let found = doc.symbols.iter().any(|s| &s.name == sym_name);
-            if !found && !doc.symbols.is_empty() {
-                report.add_warning(format!(
+        let found = doc.symbols.iter().any(|s| &s.name == sym_name);
+        if !found && !doc.symbols.is_empty() {
+            issues.push((
+                sym_name.as_str(),
+                format!(
                     "math.symbol_weights references unknown symbol '{}'",
                     sym_name
-                ));
-            }
+                ),
+            ));
+        }
 
-            // Weight count should match reel count
-            if weights.len() != doc.grid.reels as usize {
-                report.add_warning(format!(
+        // Weight count should match reel count
+        if weights.len() != doc.grid.reels as usize {
+            issues.push((
+                sym_name.as_str(),
+                format!(
                     "Symbol '{}' has {} weights but grid has {} reels",
                     sym_name,
                     weights.len(),
                     doc.grid.reels
-                ));
-            }
+                ),
+            ));
         }
     }
+    issues
 }
 
 // ─── FluxMacro Requirements ─────────────────────────────────────────────────
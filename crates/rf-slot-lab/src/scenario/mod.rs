@@ -23,7 +23,12 @@ mod presets;
 // Placeholder types for now
 use serde::{Deserialize, Serialize};
 
+use rf_stage::StageEvent;
+
+use crate::engine::SyntheticSlotEngine;
 use crate::model::GameModel;
+use crate::spin::ForcedOutcome;
+use crate::timing::TimingProfile;
 
 /// Errors produced when validating a scenario against a game model.
 ///
@@ -360,6 +365,106 @@ impl DemoScenario {
 
         Ok(())
     }
+
+    /// Render one pass over the sequence into an absolute STAGE timeline.
+    ///
+    /// One [`SyntheticSlotEngine`], seeded once with [`TRACE_SEED`], plays
+    /// the whole sequence, so the same scenario and profile always yield
+    /// the same trace — audio tests can pin it as a fixture instead of
+    /// driving the live engine. Spins are laid end to end: a spin starts
+    /// `delay_before_ms` after the previous spin's last stage, and every
+    /// event is stamped in seconds from the start of the scenario.
+    ///
+    /// `loop_mode` is ignored (a `Forever` scenario has no finite trace).
+    /// Outcomes without a synthetic-engine equivalent — `TriggerHoldAndWin`
+    /// and `SpecificGrid` — render as a losing spin so the timeline keeps
+    /// one spin per scripted step.
+    pub fn render_trace(&self, timing_profile: TimingProfile) -> Vec<(f64, StageEvent)> {
+        let mut engine = SyntheticSlotEngine::new();
+        engine.seed(TRACE_SEED);
+        engine.set_timing(timing_profile);
+
+        let mut trace = Vec::new();
+        let mut cursor_ms = 0.0;
+        for spin in &self.sequence {
+            let spin_start_ms = cursor_ms + spin.delay_before_ms.unwrap_or(0.0).max(0.0);
+            let (outcome, ratio) = spin
+                .outcome
+                .to_forced()
+                .unwrap_or((ForcedOutcome::Lose, None));
+            let result = match ratio {
+                Some(ratio) => engine.spin_forced_with_multiplier(outcome, ratio),
+                None => engine.spin_forced(outcome),
+            };
+
+            let mut spin_end_ms = spin_start_ms;
+            for event in engine.generate_stages(&result) {
+                let at_ms = spin_start_ms + event.timestamp_ms;
+                spin_end_ms = spin_end_ms.max(at_ms);
+                trace.push((at_ms / 1000.0, event));
+            }
+            cursor_ms = spin_end_ms;
+        }
+        trace
+    }
+}
+
+/// RNG seed used by [`DemoScenario::render_trace`].
+pub const TRACE_SEED: u64 = 0x5EED_7ACE;
+
+/// One entry of a serialized scenario trace.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceEntry {
+    /// Seconds from scenario start
+    pub time_s: f64,
+    /// Stage event (its own `timestamp_ms` stays relative to spin start)
+    pub event: StageEvent,
+}
+
+/// Serialize a rendered trace as a pretty-printed JSON array of
+/// `{ "time_s": .., "event": {..} }` objects.
+pub fn trace_to_json(trace: &[(f64, StageEvent)]) -> serde_json::Result<String> {
+    let entries: Vec<TraceEntry> = trace
+        .iter()
+        .map(|(time_s, event)| TraceEntry {
+            time_s: *time_s,
+            event: event.clone(),
+        })
+        .collect();
+    serde_json::to_string_pretty(&entries)
+}
+
+impl ScriptedOutcome {
+    /// Engine outcome (and exact win ratio, if scripted) for this step.
+    ///
+    /// Returns `None` for outcomes the synthetic engine cannot force
+    /// (`TriggerHoldAndWin`, `SpecificGrid`). Unknown jackpot tiers map
+    /// to Mini.
+    pub fn to_forced(&self) -> Option<(ForcedOutcome, Option<f64>)> {
+        let forced = match self {
+            Self::Lose => (ForcedOutcome::Lose, None),
+            Self::SmallWin { ratio } => (ForcedOutcome::SmallWin, Some(*ratio)),
+            Self::MediumWin { ratio } => (ForcedOutcome::MediumWin, Some(*ratio)),
+            Self::BigWin { ratio } => (ForcedOutcome::BigWin, Some(*ratio)),
+            Self::MegaWin { ratio } => (ForcedOutcome::MegaWin, Some(*ratio)),
+            Self::EpicWin { ratio } => (ForcedOutcome::EpicWin, Some(*ratio)),
+            Self::UltraWin { ratio } => (ForcedOutcome::UltraWin, Some(*ratio)),
+            Self::TriggerFreeSpins { .. } => (ForcedOutcome::FreeSpins, None),
+            Self::TriggerJackpot { tier } => {
+                let outcome = match tier.to_ascii_lowercase().as_str() {
+                    "grand" => ForcedOutcome::JackpotGrand,
+                    "major" => ForcedOutcome::JackpotMajor,
+                    "minor" => ForcedOutcome::JackpotMinor,
+                    _ => ForcedOutcome::JackpotMini,
+                };
+                (outcome, None)
+            }
+            Self::NearMiss { .. } => (ForcedOutcome::NearMiss, None),
+            Self::CascadeChain { .. } => (ForcedOutcome::Cascade, None),
+            Self::TriggerHoldAndWin | Self::SpecificGrid { .. } => return None,
+        };
+        Some(forced)
+    }
 }
 
 /// Helper: validate that a scripted win ratio is a finite, non-negative number.
@@ -918,4 +1023,55 @@ mod tests {
         scenario.add_spin(specific_grid_outcome(grid));
         assert!(scenario.validate_against(&model).is_ok());
     }
+
+    #[test]
+    fn test_render_trace_ordered_stages() {
+        let mut scenario = DemoScenario::new("trace", "Trace");
+        scenario.add_spin(ScriptedOutcome::Lose);
+        scenario.add_spin(ScriptedOutcome::SmallWin { ratio: 2.0 });
+        scenario.sequence[1].delay_before_ms = Some(500.0);
+
+        let trace = scenario.render_trace(TimingProfile::Normal);
+        let names: Vec<&str> = trace.iter().map(|(_, e)| e.stage.type_name()).collect();
+
+        // Two full spins, each bracketed by spin press .. spin end
+        let presses: Vec<usize> = (0..names.len())
+            .filter(|&i| names[i] == "ui_spin_press")
+            .collect();
+        let ends: Vec<usize> = (0..names.len())
+            .filter(|&i| names[i] == "spin_end")
+            .collect();
+        assert_eq!(presses.len(), 2, "stages: {names:?}");
+        assert_eq!(ends.len(), 2, "stages: {names:?}");
+        assert_eq!(presses[0], 0);
+        assert_eq!(*ends.last().unwrap(), names.len() - 1);
+        assert!(ends[0] < presses[1]);
+
+        // Only the second spin presents a win
+        let first_spin = &names[..=ends[0]];
+        let second_spin = &names[presses[1]..];
+        assert!(!first_spin.contains(&"win_present"));
+        assert!(second_spin.contains(&"win_present"));
+
+        // Absolute times never go backwards, and the delay is honoured
+        assert!(trace.windows(2).all(|w| w[0].0 <= w[1].0));
+        let gap = trace[presses[1]].0 - trace[ends[0]].0;
+        assert!((gap - 0.5).abs() < 1e-9, "gap {gap}");
+    }
+
+    #[test]
+    fn test_render_trace_deterministic_and_json() {
+        let mut scenario = DemoScenario::new("trace", "Trace");
+        scenario.add_spin(ScriptedOutcome::BigWin { ratio: 20.0 });
+        scenario.add_spin(ScriptedOutcome::TriggerHoldAndWin);
+
+        let a = trace_to_json(&scenario.render_trace(TimingProfile::Turbo)).unwrap();
+        let b = trace_to_json(&scenario.render_trace(TimingProfile::Turbo)).unwrap();
+        assert_eq!(a, b);
+
+        let entries: Vec<TraceEntry> = serde_json::from_str(&a).unwrap();
+        assert!(!entries.is_empty());
+        assert_eq!(entries[0].time_s, 0.0);
+        assert_eq!(entries[0].event.stage.type_name(), "ui_spin_press");
+    }
 }
@@ -5,6 +5,14 @@
 //! - Personalized rendering
 //! - Loudness and DRC metadata
 //! - Interactivity support
+//! - Object group rendering (dominant objects + bed)
+
+mod scene;
+
+pub use scene::{
+    BED_ELEMENT_ID, ObjectRendering, SceneConfig, SceneDecomposition, SceneDescription,
+    SceneObjectMetadata, SceneRenderer,
+};
 
 use crate::position::Position3D;
 use serde::{Deserialize, Serialize};
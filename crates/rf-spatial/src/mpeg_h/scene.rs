@@ -0,0 +1,416 @@
+//! Scene-based object group rendering
+//!
+//! Decomposes a group of objects into a few dominant discrete objects plus a
+//! channel bed holding the rest, renders both to the target layout, and exports
+//! the scene description (per-object gain/position) for transmission.
+
+use super::{AudioElement, ElementType, MpegHScene};
+use crate::error::{SpatialError, SpatialResult};
+use crate::position::Position3D;
+use crate::{AudioObject, SpeakerLayout};
+use serde::{Deserialize, Serialize};
+
+/// Element ID reserved for the channel bed in exported scenes
+pub const BED_ELEMENT_ID: u32 = 0;
+
+/// Scene renderer configuration
+#[derive(Debug, Clone)]
+pub struct SceneConfig {
+    /// Target speaker layout
+    pub layout: SpeakerLayout,
+    /// Objects kept discrete; the rest are folded into the bed
+    pub max_dominant_objects: usize,
+}
+
+impl Default for SceneConfig {
+    fn default() -> Self {
+        Self {
+            layout: SpeakerLayout::atmos_7_1_4(),
+            max_dominant_objects: 8,
+        }
+    }
+}
+
+/// How an object is carried in the scene
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObjectRendering {
+    /// Transmitted as a discrete object with its own metadata
+    Discrete,
+    /// Pre-rendered into the channel bed
+    Bed,
+}
+
+/// Per-object metadata in the scene description
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SceneObjectMetadata {
+    /// Object ID
+    pub id: u32,
+    /// Object name
+    pub name: String,
+    /// Gain (dB)
+    pub gain_db: f32,
+    /// Static position
+    pub position: Position3D,
+    /// Discrete or bed
+    pub rendering: ObjectRendering,
+}
+
+/// Exportable scene description
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SceneDescription {
+    /// Target layout name
+    pub layout: String,
+    /// Target channel count (including LFE)
+    pub channels: usize,
+    /// Objects in descending dominance
+    pub objects: Vec<SceneObjectMetadata>,
+}
+
+impl SceneDescription {
+    /// Serialize to JSON
+    pub fn to_json(&self) -> SpatialResult<String> {
+        serde_json::to_string_pretty(self).map_err(|e| SpatialError::ProcessingError(e.to_string()))
+    }
+
+    /// Convert to MPEG-H scene: one channel element for the bed, one object
+    /// element per discrete object
+    pub fn to_mpeg_h_scene(&self, scene_id: u32) -> MpegHScene {
+        let mut scene = MpegHScene::new(scene_id);
+        scene.description = format!("Object group ({})", self.layout);
+
+        scene.add_element(AudioElement {
+            id: BED_ELEMENT_ID,
+            element_type: ElementType::Channel,
+            name: format!("Bed {}", self.layout),
+            allow_gain_change: false,
+            ..Default::default()
+        });
+
+        for obj in self
+            .objects
+            .iter()
+            .filter(|o| o.rendering == ObjectRendering::Discrete)
+        {
+            scene.add_element(AudioElement {
+                id: obj.id,
+                element_type: ElementType::Object,
+                name: obj.name.clone(),
+                default_gain_db: obj.gain_db,
+                position: Some(obj.position),
+                ..Default::default()
+            });
+        }
+
+        scene
+    }
+}
+
+/// Dominant-object-plus-bed split (object IDs)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SceneDecomposition {
+    /// Discrete objects, most dominant first
+    pub dominant: Vec<u32>,
+    /// Objects folded into the bed
+    pub bed: Vec<u32>,
+}
+
+/// Renders object groups as MPEG-H scenes (static positions)
+pub struct SceneRenderer {
+    /// Configuration
+    config: SceneConfig,
+    /// Ear-level speakers: (channel, azimuth)
+    ear_layer: Vec<(usize, f32)>,
+    /// Height speakers: (channel, azimuth)
+    height_layer: Vec<(usize, f32)>,
+    /// Height layer elevation (degrees)
+    height_elevation: f32,
+}
+
+impl SceneRenderer {
+    /// Create new scene renderer
+    pub fn new(config: SceneConfig) -> Self {
+        let mut ear_layer = Vec::new();
+        let mut height_layer = Vec::new();
+        let mut height_elevation = 0.0f32;
+
+        for speaker in config.layout.speakers.iter().filter(|s| !s.is_lfe) {
+            let sph = speaker.position.to_spherical();
+            if sph.elevation > 10.0 {
+                height_layer.push((speaker.channel, sph.azimuth));
+                height_elevation = height_elevation.max(sph.elevation);
+            } else {
+                ear_layer.push((speaker.channel, sph.azimuth));
+            }
+        }
+
+        ear_layer.sort_by(|a, b| a.1.total_cmp(&b.1));
+        height_layer.sort_by(|a, b| a.1.total_cmp(&b.1));
+
+        Self {
+            config,
+            ear_layer,
+            height_layer,
+            height_elevation,
+        }
+    }
+
+    /// Target layout
+    pub fn layout(&self) -> &SpeakerLayout {
+        &self.config.layout
+    }
+
+    /// Speaker gains (per output channel) for a static position
+    ///
+    /// Pairwise VBAP within the ear and height layers, crossfaded by elevation.
+    pub fn speaker_gains(&self, position: &Position3D) -> Vec<f32> {
+        let mut gains = vec![0.0f32; self.config.layout.total_channels()];
+        let sph = position.to_spherical();
+
+        let height_mix = if self.height_layer.is_empty() || self.height_elevation <= 0.0 {
+            0.0
+        } else {
+            (sph.elevation / self.height_elevation).clamp(0.0, 1.0)
+        };
+        let angle = height_mix * std::f32::consts::FRAC_PI_2;
+
+        for (layer, weight) in [
+            (&self.ear_layer, angle.cos()),
+            (&self.height_layer, angle.sin()),
+        ] {
+            if weight <= 1e-6 {
+                continue;
+            }
+            for (channel, g) in pan_layer(layer, sph.azimuth) {
+                gains[channel] += g * weight;
+            }
+        }
+
+        gains
+    }
+
+    /// Split objects into dominant discrete objects and bed objects
+    ///
+    /// Dominance = object energy × gain². Ties keep input order.
+    pub fn decompose(&self, objects: &[AudioObject]) -> SceneDecomposition {
+        let mut ranked: Vec<(u32, f32)> = objects
+            .iter()
+            .map(|o| {
+                let energy: f32 = o.audio.iter().map(|s| s * s).sum();
+                (o.id, energy * o.gain * o.gain)
+            })
+            .collect();
+        ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+        let split = self.config.max_dominant_objects.min(ranked.len());
+        SceneDecomposition {
+            dominant: ranked[..split].iter().map(|(id, _)| *id).collect(),
+            bed: ranked[split..].iter().map(|(id, _)| *id).collect(),
+        }
+    }
+
+    /// Render bed objects only (the channel bed to transmit)
+    pub fn render_bed(
+        &self,
+        objects: &[AudioObject],
+        decomposition: &SceneDecomposition,
+        output: &mut [Vec<f32>],
+    ) -> SpatialResult<()> {
+        self.check_output(output)?;
+        for ch in output.iter_mut() {
+            ch.fill(0.0);
+        }
+        for obj in objects.iter().filter(|o| decomposition.bed.contains(&o.id)) {
+            self.mix_object(obj, output);
+        }
+        Ok(())
+    }
+
+    /// Render the full scene (bed + discrete objects) to the target layout
+    pub fn render(&self, objects: &[AudioObject], output: &mut [Vec<f32>]) -> SpatialResult<()> {
+        let decomposition = self.decompose(objects);
+        self.render_bed(objects, &decomposition, output)?;
+        for obj in objects
+            .iter()
+            .filter(|o| decomposition.dominant.contains(&o.id))
+        {
+            self.mix_object(obj, output);
+        }
+        Ok(())
+    }
+
+    /// Export the scene description for a group of objects
+    pub fn describe(&self, objects: &[AudioObject]) -> SceneDescription {
+        let decomposition = self.decompose(objects);
+        let metadata = |id: u32, rendering: ObjectRendering| {
+            objects
+                .iter()
+                .find(|o| o.id == id)
+                .map(|o| SceneObjectMetadata {
+                    id: o.id,
+                    name: o.name.clone(),
+                    gain_db: 20.0 * o.gain.max(1e-5).log10(),
+                    position: o.position,
+                    rendering,
+                })
+        };
+
+        let objects = decomposition
+            .dominant
+            .iter()
+            .filter_map(|&id| metadata(id, ObjectRendering::Discrete))
+            .chain(
+                decomposition
+                    .bed
+                    .iter()
+                    .filter_map(|&id| metadata(id, ObjectRendering::Bed)),
+            )
+            .collect();
+
+        SceneDescription {
+            layout: self.config.layout.name.clone(),
+            channels: self.config.layout.total_channels(),
+            objects,
+        }
+    }
+
+    fn check_output(&self, output: &[Vec<f32>]) -> SpatialResult<()> {
+        let expected = self.config.layout.total_channels();
+        if output.len() != expected {
+            return Err(SpatialError::InvalidChannelCount {
+                expected,
+                got: output.len(),
+            });
+        }
+        Ok(())
+    }
+
+    fn mix_object(&self, obj: &AudioObject, output: &mut [Vec<f32>]) {
+        let gains = self.speaker_gains(&obj.position);
+        for (ch, &g) in gains.iter().enumerate() {
+            let g = g * obj.gain;
+            if g == 0.0 {
+                continue;
+            }
+            for (out, &s) in output[ch].iter_mut().zip(obj.audio.iter()) {
+                *out += s * g;
+            }
+        }
+    }
+}
+
+/// Pairwise 2D VBAP within one layer (speakers sorted by azimuth)
+fn pan_layer(layer: &[(usize, f32)], azimuth: f32) -> Vec<(usize, f32)> {
+    match layer.len() {
+        0 => return Vec::new(),
+        1 => return vec![(layer[0].0, 1.0)],
+        _ => {}
+    }
+
+    // Adjacent pair enclosing the azimuth (wrapping around the back)
+    let n = layer.len();
+    let (a, b) = (0..n)
+        .map(|i| (layer[i], layer[(i + 1) % n]))
+        .find(|(a, b)| {
+            let span = (b.1 - a.1).rem_euclid(360.0);
+            let offset = (azimuth - a.1).rem_euclid(360.0);
+            offset <= span
+        })
+        .unwrap_or((layer[n - 1], layer[0]));
+
+    // Solve p = g_a·l_a + g_b·l_b in the horizontal plane
+    let dir = |az: f32| {
+        let r = az.to_radians();
+        (r.sin(), r.cos())
+    };
+    let (px, py) = dir(azimuth);
+    let (ax, ay) = dir(a.1);
+    let (bx, by) = dir(b.1);
+    let det = ax * by - bx * ay;
+    if det.abs() < 1e-6 {
+        return vec![(a.0, 1.0)];
+    }
+
+    let ga = ((px * by - bx * py) / det).max(0.0);
+    let gb = ((ax * py - px * ay) / det).max(0.0);
+    let norm = (ga * ga + gb * gb).sqrt().max(1e-9);
+
+    vec![(a.0, ga / norm), (b.0, gb / norm)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn object(id: u32, position: Position3D, level: f32) -> AudioObject {
+        AudioObject {
+            id,
+            name: format!("Obj {}", id),
+            position,
+            audio: vec![level; 64],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_scene_renders_to_7_1_4() {
+        let renderer = SceneRenderer::new(SceneConfig {
+            max_dominant_objects: 2,
+            ..Default::default()
+        });
+
+        let objects: Vec<AudioObject> = (0..5)
+            .map(|i| {
+                let az = -90.0 + i as f32 * 45.0;
+                object(
+                    i + 1,
+                    Position3D::from_spherical(az, 0.0, 1.0),
+                    0.1 * (i + 1) as f32,
+                )
+            })
+            .collect();
+
+        let mut output = vec![vec![0.0f32; 64]; 12];
+        renderer.render(&objects, &mut output).unwrap();
+        assert_eq!(output.len(), renderer.layout().total_channels());
+        assert!(output.iter().flatten().all(|s| s.is_finite()));
+
+        let mut wrong = vec![vec![0.0f32; 64]; 8];
+        assert!(renderer.render(&objects, &mut wrong).is_err());
+
+        // Loudest two stay discrete, metadata preserved
+        let desc = renderer.describe(&objects);
+        assert_eq!(desc.channels, 12);
+        assert_eq!(desc.objects[0].id, 5);
+        assert_eq!(desc.objects[1].id, 4);
+        assert_eq!(desc.objects[2].rendering, ObjectRendering::Bed);
+        assert!(desc.to_json().unwrap().contains("\"Discrete\""));
+
+        let scene = desc.to_mpeg_h_scene(1);
+        assert_eq!(scene.elements.len(), 3); // bed + 2 objects
+        assert_eq!(
+            scene.get_element(5).unwrap().position,
+            Some(objects[4].position)
+        );
+    }
+
+    #[test]
+    fn test_centered_object_to_center_speaker() {
+        let renderer = SceneRenderer::new(SceneConfig::default());
+        let objects = vec![object(1, Position3D::new(0.0, 1.0, 0.0), 0.5)];
+
+        let mut output = vec![vec![0.0f32; 64]; 12];
+        renderer.render(&objects, &mut output).unwrap();
+
+        let center = renderer
+            .layout()
+            .speakers
+            .iter()
+            .find(|s| s.label == "C")
+            .unwrap()
+            .channel;
+        for (ch, samples) in output.iter().enumerate() {
+            let expected = if ch == center { 0.5 } else { 0.0 };
+            assert!((samples[10] - expected).abs() < 1e-5, "channel {}", ch);
+        }
+    }
+}
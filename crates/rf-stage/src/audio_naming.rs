@@ -373,6 +373,7 @@ pub fn resolve_audio_assets(stage: &Stage) -> Vec<AudioAssetBinding> {
         Stage::Custom { name, .. } => {
             vec![AudioAssetBinding::sfx(&format!("custom_{}", name.to_lowercase()))]
         }
+        Stage::Unknown => vec![],
 
         // ═══════════════════════════════════════════════════════════════
         // FEATURE TRANSITIONS & SUMMARY
@@ -505,6 +505,9 @@ pub enum Stage {
         #[serde(default)]
         id: u32,
     },
+
+    /// Engine event that could not be classified with enough confidence
+    Unknown,
 }
 
 impl Stage {
@@ -576,7 +579,8 @@ impl Stage {
             | Stage::SymbolUpgrade { .. }  // P0.15
             | Stage::MysteryReveal { .. }  // P0.16
             | Stage::MultiplierApply { .. } // P0.17
-            | Stage::Custom { .. } => StageCategory::Special,
+            | Stage::Custom { .. }
+            | Stage::Unknown => StageCategory::Special,
         }
     }
 
@@ -640,6 +644,7 @@ impl Stage {
             Stage::MysteryReveal { .. } => "mystery_reveal", // P0.16
             Stage::MultiplierApply { .. } => "multiplier_apply", // P0.17
             Stage::Custom { .. } => "custom",
+            Stage::Unknown => "unknown",
         }
     }
 
@@ -2,9 +2,16 @@
 //!
 //! These enums classify various game elements like big win tiers,
 //! feature types, jackpot levels, etc.
+//!
+//! [`Taxonomy`] maps arbitrary engine event names to canonical stages using
+//! string similarity plus a learned alias table.
+
+use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
 
+use crate::stage::Stage;
+
 /// Big win tier classification
 ///
 /// Standard tiers based on win-to-bet ratio:
@@ -231,6 +238,193 @@ pub enum BonusChoiceType {
     Custom(u32),
 }
 
+/// Fuzzy classifier from engine event names to canonical stages
+///
+/// Names are normalized (camelCase and separators split, lowercased, common
+/// synonyms folded) and compared against canonical stage names and learned
+/// aliases. Exact alias hits score 1.0; fuzzy matches are capped below that.
+#[derive(Debug, Clone)]
+pub struct Taxonomy {
+    /// Normalized event name → stage
+    aliases: HashMap<String, Stage>,
+    /// Below this confidence, `classify` returns `Stage::Unknown`
+    min_confidence: f64,
+}
+
+/// Default confidence threshold for a fuzzy match
+const DEFAULT_MIN_CONFIDENCE: f64 = 0.6;
+
+/// Fuzzy matches never report full confidence
+const FUZZY_CONFIDENCE_CAP: f64 = 0.95;
+
+impl Taxonomy {
+    /// Create a classifier with an empty alias table
+    pub fn new() -> Self {
+        Self {
+            aliases: HashMap::new(),
+            min_confidence: DEFAULT_MIN_CONFIDENCE,
+        }
+    }
+
+    /// Set the confidence threshold (0.0-1.0)
+    pub fn with_min_confidence(mut self, min_confidence: f64) -> Self {
+        self.min_confidence = min_confidence.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Teach the classifier that `name` means `stage`
+    pub fn add_alias(&mut self, name: &str, stage: Stage) {
+        self.aliases.insert(normalize_event_name(name), stage);
+    }
+
+    /// Number of learned aliases
+    pub fn alias_count(&self) -> usize {
+        self.aliases.len()
+    }
+
+    /// Suggest the most likely canonical stage for an engine event name
+    ///
+    /// Returns `Stage::Unknown` with the best score if no candidate reaches
+    /// the confidence threshold.
+    pub fn classify(&self, name: &str) -> (Stage, f64) {
+        let normalized = normalize_event_name(name);
+        if normalized.is_empty() {
+            return (Stage::Unknown, 0.0);
+        }
+
+        if let Some(stage) = self.aliases.get(&normalized) {
+            return (stage.clone(), 1.0);
+        }
+        let no_data = HashMap::new();
+        if let Some(stage) = Stage::from_type_name(&normalized, &no_data)
+            .filter(|s| !matches!(s, Stage::Custom { .. }))
+        {
+            return (stage, 1.0);
+        }
+
+        let canonical = Stage::all_type_names()
+            .iter()
+            .filter(|n| **n != "custom")
+            .filter_map(|n| Some((*n, Stage::from_type_name(n, &no_data)?)));
+        let learned = self.aliases.iter().map(|(k, v)| (k.as_str(), v.clone()));
+
+        let mut best: Option<(Stage, f64)> = None;
+        for (candidate, stage) in canonical.chain(learned) {
+            let score = similarity(&normalized, &normalize_event_name(candidate));
+            if best.as_ref().is_none_or(|(_, b)| score > *b) {
+                best = Some((stage, score));
+            }
+        }
+
+        match best {
+            Some((stage, score)) => {
+                let confidence = (score * FUZZY_CONFIDENCE_CAP).min(FUZZY_CONFIDENCE_CAP);
+                if confidence >= self.min_confidence {
+                    (stage, confidence)
+                } else {
+                    (Stage::Unknown, confidence)
+                }
+            }
+            None => (Stage::Unknown, 0.0),
+        }
+    }
+}
+
+impl Default for Taxonomy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Split camelCase and separators into lowercase `_`-joined tokens, folding synonyms
+fn normalize_event_name(name: &str) -> String {
+    let mut tokens: Vec<String> = Vec::new();
+    let mut current = String::new();
+    let mut prev_lower = false;
+
+    for c in name.chars() {
+        if !c.is_ascii_alphanumeric() {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+            prev_lower = false;
+            continue;
+        }
+        if c.is_ascii_uppercase() && prev_lower && !current.is_empty() {
+            tokens.push(std::mem::take(&mut current));
+        }
+        prev_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        current.push(c.to_ascii_lowercase());
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+
+    tokens
+        .iter()
+        .map(|t| canonical_token(t))
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// Fold common engine-side synonyms onto the words used by canonical stage names
+fn canonical_token(token: &str) -> &str {
+    match token {
+        "begin" | "began" | "started" | "starting" | "init" => "start",
+        "ended" | "finish" | "finished" | "finishing" => "end",
+        "stopped" | "stopping" => "stop",
+        "pressed" | "click" | "clicked" | "tap" | "tapped" => "press",
+        "reels" => "reel",
+        "wins" => "win",
+        "fs" | "freespin" | "freespins" => "feature",
+        other => other,
+    }
+}
+
+/// Similarity in 0.0-1.0: mean of token overlap (Jaccard) and edit-distance ratio
+fn similarity(a: &str, b: &str) -> f64 {
+    if a == b {
+        return 1.0;
+    }
+    let tokens_a: Vec<&str> = a.split('_').collect();
+    let tokens_b: Vec<&str> = b.split('_').collect();
+    let shared = tokens_a.iter().filter(|t| tokens_b.contains(t)).count();
+    let union = tokens_a.len() + tokens_b.len() - shared;
+    let jaccard = if union == 0 {
+        0.0
+    } else {
+        shared as f64 / union as f64
+    };
+
+    let max_len = a.chars().count().max(b.chars().count());
+    let edit = if max_len == 0 {
+        0.0
+    } else {
+        1.0 - levenshtein(a, b) as f64 / max_len as f64
+    };
+
+    0.5 * (jaccard + edit)
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diag = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                diag
+            } else {
+                1 + diag.min(above).min(row[j])
+            };
+            diag = above;
+        }
+    }
+    row[b.len()]
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -258,4 +452,33 @@ mod tests {
         assert!(JackpotTier::Minor.level() < JackpotTier::Major.level());
         assert!(JackpotTier::Major.level() < JackpotTier::Grand.level());
     }
+
+    #[test]
+    fn test_classify_spin_start_variants() {
+        let taxonomy = Taxonomy::new();
+        for name in ["spinStart", "spin_begin", "SPIN_START"] {
+            let (stage, confidence) = taxonomy.classify(name);
+            assert_eq!(stage, Stage::UiSpinPress, "{name}");
+            assert!(confidence >= 0.9, "{name}: confidence {confidence}");
+        }
+    }
+
+    #[test]
+    fn test_classify_aliases_and_unknown() {
+        let mut taxonomy = Taxonomy::new();
+        let (stage, confidence) = taxonomy.classify("xyzzy");
+        assert_eq!(stage, Stage::Unknown);
+        assert!(confidence < 0.6);
+
+        taxonomy.add_alias("onTumbleBegin", Stage::CascadeStart);
+        assert_eq!(
+            taxonomy.classify("on_tumble_begin"),
+            (Stage::CascadeStart, 1.0)
+        );
+
+        // Learned aliases also take part in fuzzy matching
+        let (stage, confidence) = taxonomy.classify("onTumbleBeginFx");
+        assert_eq!(stage, Stage::CascadeStart);
+        assert!(confidence < 1.0);
+    }
 }
@@ -1002,6 +1002,7 @@ mod tests {
             regions: Vec::new(),
             automation: Vec::new(),
             instrument_plugin_id: None,
+            instrument_state_ref: None,
             output_channel_map: Vec::new(),
         };
 
@@ -5,11 +5,110 @@
 //! - Named snapshots
 //! - Branching history support
 //! - History export/import
+//! - Delta-compressed state: entries store a structural diff against the
+//!   previous state, with a full keyframe every `keyframe_interval` states
 
 use std::collections::VecDeque;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+// ============ State Delta ============
+
+/// Structural difference between two serialized (JSON) states
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum StateDelta {
+    /// No change
+    Unchanged,
+    /// Replace the value wholesale
+    Replace(Value),
+    /// Per-key changes to an object
+    Object {
+        changed: Vec<(String, StateDelta)>,
+        removed: Vec<String>,
+    },
+    /// Per-index changes to an array, resized to `len` first
+    Array {
+        len: usize,
+        changed: Vec<(usize, StateDelta)>,
+    },
+}
+
+impl StateDelta {
+    /// Diff `old` -> `new`
+    pub fn diff(old: &Value, new: &Value) -> Self {
+        match (old, new) {
+            _ if old == new => StateDelta::Unchanged,
+            (Value::Object(old_map), Value::Object(new_map)) => {
+                let changed = new_map
+                    .iter()
+                    .filter_map(|(key, new_value)| match old_map.get(key) {
+                        Some(old_value) if old_value == new_value => None,
+                        Some(old_value) => Some((key.clone(), Self::diff(old_value, new_value))),
+                        None => Some((key.clone(), StateDelta::Replace(new_value.clone()))),
+                    })
+                    .collect();
+                let removed = old_map
+                    .keys()
+                    .filter(|key| !new_map.contains_key(*key))
+                    .cloned()
+                    .collect();
+                StateDelta::Object { changed, removed }
+            }
+            (Value::Array(old_items), Value::Array(new_items)) => {
+                let changed = new_items
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(i, new_item)| match old_items.get(i) {
+                        Some(old_item) if old_item == new_item => None,
+                        Some(old_item) => Some((i, Self::diff(old_item, new_item))),
+                        None => Some((i, StateDelta::Replace(new_item.clone()))),
+                    })
+                    .collect();
+                StateDelta::Array {
+                    len: new_items.len(),
+                    changed,
+                }
+            }
+            _ => StateDelta::Replace(new.clone()),
+        }
+    }
+
+    /// Apply to `value` (the `old` this delta was computed from)
+    pub fn apply(&self, value: &mut Value) {
+        match self {
+            StateDelta::Unchanged => {}
+            StateDelta::Replace(new) => *value = new.clone(),
+            StateDelta::Object { changed, removed } => {
+                if !value.is_object() {
+                    *value = Value::Object(Default::default());
+                }
+                if let Value::Object(map) = value {
+                    for key in removed {
+                        map.remove(key);
+                    }
+                    for (key, delta) in changed {
+                        delta.apply(map.entry(key.clone()).or_insert(Value::Null));
+                    }
+                }
+            }
+            StateDelta::Array { len, changed } => {
+                if !value.is_array() {
+                    *value = Value::Array(Vec::new());
+                }
+                if let Value::Array(items) = value {
+                    items.resize(*len, Value::Null);
+                    for (i, delta) in changed {
+                        if let Some(item) = items.get_mut(*i) {
+                            delta.apply(item);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
 
 // ============ History Entry ============
 
@@ -64,8 +163,11 @@ pub struct HistoryEntry {
     pub parent_id: Option<HistoryId>,
     /// Whether this is the current state
     pub is_current: bool,
-    /// Associated state data (serialized)
+    /// Associated state data (serialized); set on keyframes
     pub state_data: Option<Vec<u8>>,
+    /// Serialized `StateDelta` against the previous entry with state
+    #[serde(default)]
+    pub state_delta: Option<Vec<u8>>,
 }
 
 impl HistoryEntry {
@@ -79,6 +181,7 @@ impl HistoryEntry {
             parent_id: None,
             is_current: false,
             state_data: None,
+            state_delta: None,
         }
     }
 
@@ -92,6 +195,7 @@ impl HistoryEntry {
             parent_id: None,
             is_current: false,
             state_data: None,
+            state_delta: None,
         }
     }
 
@@ -107,12 +211,24 @@ impl HistoryEntry {
         self
     }
 
+    /// Full state of a keyframe entry (delta entries need
+    /// `HistoryBrowser::entry_state`)
     pub fn get_state<T: for<'de> Deserialize<'de>>(&self) -> Option<T> {
         self.state_data
             .as_ref()
             .and_then(|data| serde_json::from_slice(data).ok())
     }
 
+    /// Whether this entry carries state (keyframe or delta)
+    pub fn has_state(&self) -> bool {
+        self.state_data.is_some() || self.state_delta.is_some()
+    }
+
+    /// Bytes held by the stored state
+    pub fn state_bytes(&self) -> usize {
+        self.state_data.as_ref().map_or(0, Vec::len) + self.state_delta.as_ref().map_or(0, Vec::len)
+    }
+
     pub fn age_seconds(&self) -> u64 {
         current_timestamp().saturating_sub(self.timestamp) / 1000
     }
@@ -145,6 +261,9 @@ pub const MAX_HISTORY_ENTRIES: usize = 1000;
 /// Maximum snapshots
 pub const MAX_SNAPSHOTS: usize = 50;
 
+/// Default number of states between full keyframes
+pub const DEFAULT_KEYFRAME_INTERVAL: usize = 20;
+
 /// History browser with timeline view support
 pub struct HistoryBrowser {
     /// All history entries (linear timeline)
@@ -155,6 +274,8 @@ pub struct HistoryBrowser {
     current_index: usize,
     /// Maximum entries to keep
     max_entries: usize,
+    /// States between full keyframes (1 = every state is a keyframe)
+    keyframe_interval: usize,
     /// Auto-snapshot interval (seconds, 0 = disabled)
     auto_snapshot_interval: u64,
     /// Last auto-snapshot time
@@ -170,6 +291,7 @@ impl HistoryBrowser {
             snapshots: Vec::with_capacity(MAX_SNAPSHOTS),
             current_index: 0,
             max_entries: MAX_HISTORY_ENTRIES,
+            keyframe_interval: DEFAULT_KEYFRAME_INTERVAL,
             auto_snapshot_interval: 0,
             last_auto_snapshot: current_timestamp(),
             on_change: None,
@@ -182,6 +304,16 @@ impl HistoryBrowser {
         self.trim_history();
     }
 
+    /// Store a full keyframe every `interval` states (applies to new entries)
+    pub fn set_keyframe_interval(&mut self, interval: usize) {
+        self.keyframe_interval = interval.max(1);
+    }
+
+    /// States between full keyframes
+    pub fn keyframe_interval(&self) -> usize {
+        self.keyframe_interval
+    }
+
     /// Enable auto-snapshots at interval (seconds)
     pub fn set_auto_snapshot_interval(&mut self, seconds: u64) {
         self.auto_snapshot_interval = seconds;
@@ -202,6 +334,8 @@ impl HistoryBrowser {
             entry.parent_id = Some(current.id);
         }
 
+        self.encode_state(&mut entry);
+
         entry.is_current = true;
 
         // Mark previous as not current
@@ -224,8 +358,8 @@ impl HistoryBrowser {
         let mut snapshot = HistoryEntry::snapshot(name);
 
         // Copy state from current entry
-        if let Some(current) = self.current_entry() {
-            snapshot.state_data = current.state_data.clone();
+        if self.current_index > 0 {
+            snapshot.state_data = self.state_bytes_at(self.current_index - 1);
         }
 
         let id = snapshot.id;
@@ -350,6 +484,94 @@ impl HistoryBrowser {
         }
     }
 
+    /// Reconstructed state of the current entry
+    pub fn current_state<T: for<'de> Deserialize<'de>>(&self) -> Option<T> {
+        let index = self.current_index.checked_sub(1)?;
+        serde_json::from_value(self.state_value_at(index)?).ok()
+    }
+
+    /// Reconstructed state of any entry
+    pub fn entry_state<T: for<'de> Deserialize<'de>>(&self, id: HistoryId) -> Option<T> {
+        let index = self.entries.iter().position(|e| e.id == id)?;
+        serde_json::from_value(self.state_value_at(index)?).ok()
+    }
+
+    /// Bytes held by entry state (keyframes + deltas)
+    pub fn memory_usage(&self) -> HistoryMemory {
+        let mut memory = HistoryMemory::default();
+        for entry in &self.entries {
+            if let Some(data) = &entry.state_data {
+                memory.keyframes += 1;
+                memory.keyframe_bytes += data.len();
+            } else if let Some(delta) = &entry.state_delta {
+                memory.deltas += 1;
+                memory.delta_bytes += delta.len();
+            }
+        }
+        memory
+    }
+
+    /// Replace a full `state_data` with a delta against the previous state,
+    /// unless a keyframe is due
+    fn encode_state(&mut self, entry: &mut HistoryEntry) {
+        entry.state_delta = None;
+        let Some(data) = &entry.state_data else {
+            return;
+        };
+        let Some(prev_index) = self.entries.iter().rposition(HistoryEntry::has_state) else {
+            return;
+        };
+
+        // States since (and including) the last keyframe
+        let since_keyframe = self
+            .entries
+            .iter()
+            .rev()
+            .filter(|e| e.has_state())
+            .position(|e| e.state_data.is_some())
+            .map_or(usize::MAX, |n| n + 1);
+        if since_keyframe >= self.keyframe_interval {
+            return;
+        }
+
+        let (Some(prev), Ok(new)) = (
+            self.state_value_at(prev_index),
+            serde_json::from_slice::<Value>(data),
+        ) else {
+            return;
+        };
+        if let Ok(delta) = serde_json::to_vec(&StateDelta::diff(&prev, &new)) {
+            entry.state_data = None;
+            entry.state_delta = Some(delta);
+        }
+    }
+
+    /// Rebuild the state at `index` from the nearest keyframe at or before it
+    fn state_value_at(&self, index: usize) -> Option<Value> {
+        if !self.entries.get(index)?.has_state() {
+            return None;
+        }
+        let keyframe = self
+            .entries
+            .range(..=index)
+            .rposition(|e| e.state_data.is_some())?;
+        let mut value: Value =
+            serde_json::from_slice(self.entries[keyframe].state_data.as_ref()?).ok()?;
+        for entry in self.entries.range(keyframe + 1..=index) {
+            if let Some(delta) = &entry.state_delta {
+                serde_json::from_slice::<StateDelta>(delta)
+                    .ok()?
+                    .apply(&mut value);
+            }
+        }
+        Some(value)
+    }
+
+    /// Reconstructed state at `index`, serialized
+    fn state_bytes_at(&self, index: usize) -> Option<Vec<u8>> {
+        serde_json::to_vec(&self.state_value_at(index)?).ok()
+    }
+
     /// Get all entries
     pub fn entries(&self) -> impl Iterator<Item = &HistoryEntry> {
         self.entries.iter()
@@ -422,8 +644,8 @@ impl HistoryBrowser {
                 HistoryEntry::snapshot(format!("Auto-snapshot {}", self.snapshots.len() + 1));
             snapshot.entry_type = HistoryEntryType::AutoSnapshot;
 
-            if let Some(current) = self.entries.back() {
-                snapshot.state_data = current.state_data.clone();
+            if !self.entries.is_empty() {
+                snapshot.state_data = self.state_bytes_at(self.entries.len() - 1);
             }
 
             self.snapshots.push(snapshot);
@@ -451,6 +673,19 @@ impl HistoryBrowser {
     /// Trim history to max size
     fn trim_history(&mut self) {
         while self.entries.len() > self.max_entries {
+            // Deltas following the dropped keyframe need a new base
+            if let Some(next) = self
+                .entries
+                .iter()
+                .skip(1)
+                .position(HistoryEntry::has_state)
+                && self.entries[next + 1].state_data.is_none()
+            {
+                let state = self.state_bytes_at(next + 1);
+                let entry = &mut self.entries[next + 1];
+                entry.state_data = state;
+                entry.state_delta = None;
+            }
             self.entries.pop_front();
             if self.current_index > 0 {
                 self.current_index -= 1;
@@ -467,6 +702,7 @@ impl HistoryBrowser {
             can_undo: self.can_go_back(),
             can_redo: self.can_go_forward(),
             current_action: self.current_entry().map(|e| e.name.clone()),
+            memory_bytes: self.memory_usage().total_bytes(),
         }
     }
 }
@@ -486,6 +722,22 @@ pub struct HistorySummary {
     pub can_undo: bool,
     pub can_redo: bool,
     pub current_action: Option<String>,
+    pub memory_bytes: usize,
+}
+
+/// State memory held by the history timeline
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HistoryMemory {
+    pub keyframes: usize,
+    pub keyframe_bytes: usize,
+    pub deltas: usize,
+    pub delta_bytes: usize,
+}
+
+impl HistoryMemory {
+    pub fn total_bytes(&self) -> usize {
+        self.keyframe_bytes + self.delta_bytes
+    }
 }
 
 // ============ Tests ============
@@ -551,4 +803,101 @@ mod tests {
         // Should have truncated forward history
         assert_eq!(history.len(), 3);
     }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct TestTrack {
+        name: String,
+        volume: f64,
+        muted: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct TestProject {
+        name: String,
+        tracks: Vec<TestTrack>,
+    }
+
+    #[test]
+    fn test_state_delta_roundtrip() {
+        let old = serde_json::json!({"a": 1, "b": [1, 2, 3], "c": {"d": true}, "gone": 0});
+        let new = serde_json::json!({"a": 2, "b": [1, 5], "c": {"d": true, "e": "x"}});
+        let delta = StateDelta::diff(&old, &new);
+        let mut value = old.clone();
+        delta.apply(&mut value);
+        assert_eq!(value, new);
+        assert_eq!(StateDelta::diff(&new, &new), StateDelta::Unchanged);
+    }
+
+    #[test]
+    fn test_delta_history_undo_redo() {
+        let mut project = TestProject {
+            name: "Session".to_string(),
+            tracks: (0..64)
+                .map(|i| TestTrack {
+                    name: format!("Track {}", i),
+                    volume: 1.0,
+                    muted: false,
+                })
+                .collect(),
+        };
+
+        let mut history = HistoryBrowser::new();
+        history.set_keyframe_interval(10);
+        let mut states = Vec::new();
+        let mut full_bytes = 0;
+        for edit in 0..100 {
+            let count = project.tracks.len();
+            let track = &mut project.tracks[(edit * 7) % count];
+            if edit % 3 == 0 {
+                track.muted = !track.muted;
+            } else {
+                track.volume = edit as f64 / 100.0;
+            }
+            if edit == 50 {
+                project.tracks.pop();
+            }
+            full_bytes += serde_json::to_vec(&project).unwrap().len();
+            history.push(HistoryEntry::action(format!("Edit {}", edit)).with_state(&project));
+            states.push(project.clone());
+        }
+
+        let memory = history.memory_usage();
+        assert_eq!(memory.keyframes, 10);
+        assert_eq!(memory.deltas, 90);
+        assert!(memory.total_bytes() * 5 < full_bytes);
+
+        // Undo all the way back, then redo to the end
+        assert_eq!(
+            history.current_state::<TestProject>().as_ref(),
+            states.last()
+        );
+        for expected in states.iter().rev().skip(1) {
+            history.go_back().unwrap();
+            assert_eq!(
+                history.current_state::<TestProject>().as_ref(),
+                Some(expected)
+            );
+        }
+        for expected in states.iter().skip(1) {
+            history.go_forward().unwrap();
+            assert_eq!(
+                history.current_state::<TestProject>().as_ref(),
+                Some(expected)
+            );
+        }
+
+        // Trimming re-bases the oldest deltas onto a keyframe
+        history.set_max_entries(45);
+        assert_eq!(history.len(), 45);
+        assert!(history.entries().next().unwrap().state_data.is_some());
+        let first = history.entries().next().unwrap().id;
+        assert_eq!(
+            history.entry_state::<TestProject>(first).as_ref(),
+            Some(&states[55])
+        );
+        assert_eq!(
+            history.current_state::<TestProject>().as_ref(),
+            states.last()
+        );
+    }
 }
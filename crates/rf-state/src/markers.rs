@@ -7,12 +7,14 @@
 //! - Cue points (for video)
 //! - Tempo/time signature markers
 //! - Memory locations (Cubase-style)
+//! - Musical (bar/beat) or absolute (timecode) anchoring
 //!
 //! ## Marker Types
 //! - Position: Single point in time
 //! - Cycle: Range with start/end
 //! - Arranger: Sections for song arrangement
 
+use rf_core::{GridValue, MusicalPosition, TempoMap};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -52,6 +54,61 @@ pub enum MarkerType {
     Cue,
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// MARKER ANCHOR
+// ═══════════════════════════════════════════════════════════════════════════════
+
+/// What a marker position is locked to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MarkerAnchor {
+    /// Fixed timeline position in samples (timecode) — ignores tempo changes
+    Absolute(u64),
+    /// Bar/beat position — follows tempo changes
+    Musical(MusicalPosition),
+}
+
+impl MarkerAnchor {
+    /// Musical anchor on a beat (0-indexed bar and beat)
+    pub fn musical(bar: u32, beat: u8) -> Self {
+        Self::Musical(MusicalPosition::new(bar, beat, 0))
+    }
+
+    /// Musical anchor at a sample position
+    pub fn musical_at(samples: u64, tempo_map: &TempoMap) -> Self {
+        Self::Musical(tempo_map.ticks_to_position(tempo_map.samples_to_ticks(samples)))
+    }
+
+    /// Resolve to a sample position
+    pub fn resolve(&self, tempo_map: &TempoMap) -> u64 {
+        match self {
+            Self::Absolute(samples) => *samples,
+            Self::Musical(pos) => tempo_map.ticks_to_samples(tempo_map.position_to_ticks(pos)),
+        }
+    }
+
+    /// Position in ticks
+    pub fn ticks(&self, tempo_map: &TempoMap) -> u64 {
+        match self {
+            Self::Absolute(samples) => tempo_map.samples_to_ticks(*samples),
+            Self::Musical(pos) => tempo_map.position_to_ticks(pos),
+        }
+    }
+
+    /// Snap to the nearest grid line, keeping the anchor kind
+    pub fn snap_to_grid(&self, grid: GridValue, tempo_map: &TempoMap) -> Self {
+        let ticks = tempo_map.snap_to_grid(self.ticks(tempo_map), grid);
+        self.with_ticks(ticks, tempo_map)
+    }
+
+    /// Same anchor kind at another tick position
+    fn with_ticks(&self, ticks: u64, tempo_map: &TempoMap) -> Self {
+        match self {
+            Self::Absolute(_) => Self::Absolute(tempo_map.ticks_to_samples(ticks)),
+            Self::Musical(_) => Self::Musical(tempo_map.ticks_to_position(ticks)),
+        }
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // MARKER
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -77,6 +134,12 @@ pub struct Marker {
     pub shortcut: Option<u8>,
     /// Is locked (prevent movement)
     pub locked: bool,
+    /// Start anchor; `None` = plain sample position
+    #[serde(default)]
+    pub anchor: Option<MarkerAnchor>,
+    /// End anchor (for cycle/arranger markers)
+    #[serde(default)]
+    pub end_anchor: Option<MarkerAnchor>,
 }
 
 impl Marker {
@@ -92,6 +155,8 @@ impl Marker {
             description: String::new(),
             shortcut: None,
             locked: false,
+            anchor: None,
+            end_anchor: None,
         }
     }
 
@@ -107,6 +172,8 @@ impl Marker {
             description: String::new(),
             shortcut: None,
             locked: false,
+            anchor: None,
+            end_anchor: None,
         }
     }
 
@@ -122,6 +189,8 @@ impl Marker {
             description: String::new(),
             shortcut: None,
             locked: false,
+            anchor: None,
+            end_anchor: None,
         }
     }
 
@@ -137,6 +206,8 @@ impl Marker {
             description: String::new(),
             shortcut: None,
             locked: false,
+            anchor: None,
+            end_anchor: None,
         }
     }
 
@@ -152,9 +223,60 @@ impl Marker {
             description: String::new(),
             shortcut: None,
             locked: false,
+            anchor: None,
+            end_anchor: None,
         }
     }
 
+    /// Create anchored position marker
+    pub fn anchored(name: &str, anchor: MarkerAnchor, tempo_map: &TempoMap) -> Self {
+        let mut marker = Self::position(name, anchor.resolve(tempo_map));
+        marker.anchor = Some(anchor);
+        marker
+    }
+
+    /// Create anchored region (cycle marker) for loop/export ranges
+    pub fn region(
+        name: &str,
+        start: MarkerAnchor,
+        end: MarkerAnchor,
+        tempo_map: &TempoMap,
+    ) -> Self {
+        let mut marker = Self::cycle(name, start.resolve(tempo_map), end.resolve(tempo_map));
+        marker.anchor = Some(start);
+        marker.end_anchor = Some(end);
+        marker
+    }
+
+    /// Recompute sample positions from anchors (call after tempo map edits)
+    pub fn resolve(&mut self, tempo_map: &TempoMap) {
+        if let Some(anchor) = self.anchor {
+            self.position = anchor.resolve(tempo_map);
+        }
+        if let Some(end) = self.end_anchor {
+            self.end_position = Some(end.resolve(tempo_map));
+        }
+    }
+
+    /// Move to a new anchor; regions keep their length in the anchor's timebase
+    pub fn place(&mut self, anchor: MarkerAnchor, tempo_map: &TempoMap) {
+        if self.locked {
+            return;
+        }
+
+        if let Some(end_position) = self.end_position {
+            let start = self.anchor.unwrap_or(MarkerAnchor::Absolute(self.position));
+            let end = self
+                .end_anchor
+                .unwrap_or(MarkerAnchor::Absolute(end_position));
+            let length = end.ticks(tempo_map).saturating_sub(start.ticks(tempo_map));
+            let new_end = anchor.ticks(tempo_map) + length;
+            self.end_anchor = Some(anchor.with_ticks(new_end, tempo_map));
+        }
+        self.anchor = Some(anchor);
+        self.resolve(tempo_map);
+    }
+
     /// Get length (for cycle/arranger)
     pub fn length(&self) -> Option<u64> {
         self.end_position
@@ -169,7 +291,7 @@ impl Marker {
         }
     }
 
-    /// Move marker
+    /// Move marker by samples (anchors become absolute; use `place` to stay musical)
     pub fn move_to(&mut self, new_position: u64) {
         if self.locked {
             return;
@@ -180,6 +302,13 @@ impl Marker {
             self.end_position = Some(new_position + length);
         }
         self.position = new_position;
+
+        if self.anchor.is_some() {
+            self.anchor = Some(MarkerAnchor::Absolute(self.position));
+        }
+        if self.end_anchor.is_some() {
+            self.end_anchor = self.end_position.map(MarkerAnchor::Absolute);
+        }
     }
 
     /// Resize (for cycle/arranger)
@@ -466,6 +595,13 @@ impl MarkerTrack {
     pub fn delete_by_type(&mut self, marker_type: MarkerType) {
         self.markers.retain(|_, m| m.marker_type != marker_type);
     }
+
+    /// Re-resolve anchored markers after a tempo map change
+    pub fn resolve_anchors(&mut self, tempo_map: &TempoMap) {
+        for marker in self.markers.values_mut() {
+            marker.resolve(tempo_map);
+        }
+    }
 }
 
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -520,6 +656,44 @@ mod tests {
         assert_eq!(prev.map(|m| m.id), Some(m2));
     }
 
+    #[test]
+    fn test_anchors_follow_tempo() {
+        let mut tempo_map = TempoMap::new(48000);
+        let mut track = MarkerTrack::new();
+
+        // Bar 3 (0-indexed 2) at 120 BPM 4/4 = 8 beats = 4 s
+        let musical = track.add(Marker::anchored(
+            "Drop",
+            MarkerAnchor::musical(2, 0),
+            &tempo_map,
+        ));
+        let absolute = track.add(Marker::anchored(
+            "Hit",
+            MarkerAnchor::Absolute(192000),
+            &tempo_map,
+        ));
+        let region = track.add(Marker::region(
+            "Loop",
+            MarkerAnchor::musical(1, 0),
+            MarkerAnchor::musical(2, 0),
+            &tempo_map,
+        ));
+        assert_eq!(track.get(musical).unwrap().position, 192000);
+
+        tempo_map.set_tempo(0, 60.0);
+        track.resolve_anchors(&tempo_map);
+
+        assert_eq!(track.get(musical).unwrap().position, 384000);
+        assert_eq!(track.get(absolute).unwrap().position, 192000);
+        let region = track.get(region).unwrap();
+        assert_eq!(region.position, 192000);
+        assert_eq!(region.end_position, Some(384000));
+
+        // Snap while placing: 100000 samples -> nearest beat at 60 BPM
+        let snapped = MarkerAnchor::Absolute(100000).snap_to_grid(GridValue::Quarter, &tempo_map);
+        assert_eq!(snapped, MarkerAnchor::Absolute(96000));
+    }
+
     #[test]
     fn test_arranger_chain() {
         let mut chain = ArrangerChain::new();
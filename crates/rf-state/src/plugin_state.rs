@@ -8,7 +8,9 @@
 //! Documentation: .claude/architecture/PLUGIN_STATE_SYSTEM.md
 
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
 
 // ═══════════════════════════════════════════════════════════════════════════
 // CONSTANTS
@@ -374,6 +376,187 @@ impl PluginStateStorage {
     }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════
+// SIDECAR STATE STORE
+// ═══════════════════════════════════════════════════════════════════════════
+
+/// Suffix of the sidecar directory next to a project file
+/// (`Song.rfproj` -> `Song_plugin_states/`)
+pub const PLUGIN_STATE_DIR_SUFFIX: &str = "_plugin_states";
+
+/// Extension of sidecar state files
+pub const PLUGIN_STATE_EXTENSION: &str = "ffblob";
+
+/// Content hash of a stored plugin state (hex SHA-256)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PluginStateHash(String);
+
+impl PluginStateHash {
+    /// Hash raw state bytes
+    pub fn of(bytes: &[u8]) -> Self {
+        let digest = Sha256::digest(bytes);
+        Self(digest.iter().map(|b| format!("{:02x}", b)).collect())
+    }
+
+    /// Parse a hex hash (64 lowercase hex characters)
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let valid = hex.len() == 64
+            && hex
+                .bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b));
+        valid.then(|| Self(hex.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl std::fmt::Display for PluginStateHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Content-addressed sidecar storage for large plugin states
+///
+/// Each distinct state is written once as `<hash>.ffblob`; instances with
+/// identical state share the file and the project only keeps the hash.
+#[derive(Debug, Clone)]
+pub struct PluginStateStore {
+    dir: PathBuf,
+}
+
+impl PluginStateStore {
+    /// Store rooted at an explicit directory
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Sidecar store belonging to a project file
+    pub fn for_project(project_path: &Path) -> Self {
+        let stem = project_path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or("project");
+        let parent = project_path.parent().unwrap_or_else(|| Path::new("."));
+        Self::new(parent.join(format!("{}{}", stem, PLUGIN_STATE_DIR_SUFFIX)))
+    }
+
+    /// Store directory
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path_for(&self, hash: &PluginStateHash) -> PathBuf {
+        self.dir
+            .join(format!("{}.{}", hash.as_str(), PLUGIN_STATE_EXTENSION))
+    }
+
+    /// Store state bytes, returning their hash (no-op if already stored)
+    pub fn store(&self, bytes: &[u8]) -> std::io::Result<PluginStateHash> {
+        let hash = PluginStateHash::of(bytes);
+        let path = self.path_for(&hash);
+        if path.exists() {
+            return Ok(hash);
+        }
+
+        std::fs::create_dir_all(&self.dir)?;
+        // Write then rename so a crash never leaves a truncated blob under a valid hash
+        let tmp = path.with_extension("tmp");
+        std::fs::write(&tmp, bytes)?;
+        std::fs::rename(&tmp, &path)?;
+        Ok(hash)
+    }
+
+    /// Load state bytes by hash (verified against the hash)
+    pub fn load(&self, hash: &PluginStateHash) -> std::io::Result<Vec<u8>> {
+        let bytes = std::fs::read(self.path_for(hash))?;
+        if PluginStateHash::of(&bytes) != *hash {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Plugin state {} is corrupted", hash),
+            ));
+        }
+        Ok(bytes)
+    }
+
+    /// Check if a state is stored
+    pub fn contains(&self, hash: &PluginStateHash) -> bool {
+        self.path_for(hash).exists()
+    }
+
+    /// All hashes currently stored
+    pub fn list(&self) -> std::io::Result<Vec<PluginStateHash>> {
+        let entries = match std::fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+
+        let mut hashes = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().and_then(|e| e.to_str()) != Some(PLUGIN_STATE_EXTENSION) {
+                continue;
+            }
+            if let Some(hash) = path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .and_then(PluginStateHash::from_hex)
+            {
+                hashes.push(hash);
+            }
+        }
+        hashes.sort();
+        Ok(hashes)
+    }
+
+    /// Copy the given states into another store, returning how many were copied
+    ///
+    /// States the destination already holds are skipped.
+    pub fn copy_to<'a>(
+        &self,
+        dest: &PluginStateStore,
+        hashes: impl IntoIterator<Item = &'a PluginStateHash>,
+    ) -> std::io::Result<usize> {
+        if dest.dir == self.dir {
+            return Ok(0);
+        }
+        let mut copied = 0;
+        for hash in hashes {
+            if dest.contains(hash) {
+                continue;
+            }
+            std::fs::create_dir_all(&dest.dir)?;
+            let path = dest.path_for(hash);
+            let tmp = path.with_extension("tmp");
+            std::fs::copy(self.path_for(hash), &tmp)?;
+            std::fs::rename(&tmp, &path)?;
+            copied += 1;
+        }
+        Ok(copied)
+    }
+
+    /// Delete every stored state not in `referenced`, returning how many were removed
+    pub fn collect_garbage<'a>(
+        &self,
+        referenced: impl IntoIterator<Item = &'a PluginStateHash>,
+    ) -> std::io::Result<usize> {
+        let referenced: std::collections::HashSet<&PluginStateHash> =
+            referenced.into_iter().collect();
+        let mut removed = 0;
+        for hash in self.list()? {
+            if !referenced.contains(&hash) {
+                std::fs::remove_file(self.path_for(&hash))?;
+                removed += 1;
+            }
+        }
+        Ok(removed)
+    }
+}
+
 // ═══════════════════════════════════════════════════════════════════════════
 // HELPER FUNCTIONS
 // ═══════════════════════════════════════════════════════════════════════════
@@ -472,4 +655,52 @@ mod tests {
         let track_states = storage.get_track_states(1);
         assert_eq!(track_states.len(), 1);
     }
+
+    #[test]
+    fn test_state_store_dedup_and_gc() {
+        let dir = std::env::temp_dir().join("rf_plugin_state_store_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        let store = PluginStateStore::new(&dir);
+
+        // Two sampler instances with identical state share one sidecar file
+        let sampler_state = vec![0xAB; 256 * 1024];
+        let a = store.store(&sampler_state).unwrap();
+        let b = store.store(&sampler_state).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(store.list().unwrap(), vec![a.clone()]);
+        assert_eq!(store.load(&a).unwrap(), sampler_state);
+
+        let other = store.store(&[1, 2, 3]).unwrap();
+        assert_ne!(other, a);
+        assert_eq!(store.list().unwrap().len(), 2);
+
+        // Only `a` still referenced
+        assert_eq!(store.collect_garbage([&a]).unwrap(), 1);
+        assert!(store.contains(&a));
+        assert!(!store.contains(&other));
+
+        // Copying skips states the destination already has
+        let copy = PluginStateStore::new(dir.join("copy"));
+        assert_eq!(store.copy_to(&copy, [&a]).unwrap(), 1);
+        assert_eq!(store.copy_to(&copy, [&a]).unwrap(), 0);
+        assert_eq!(copy.load(&a).unwrap(), sampler_state);
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_state_store_rejects_corrupt_blob() {
+        let dir = std::env::temp_dir().join("rf_plugin_state_corrupt_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        let store = PluginStateStore::new(&dir);
+
+        let hash = store.store(b"state").unwrap();
+        std::fs::write(store.path_for(&hash), b"tampered").unwrap();
+        assert!(store.load(&hash).is_err());
+
+        assert!(PluginStateHash::from_hex("not-a-hash").is_none());
+        assert_eq!(PluginStateHash::from_hex(hash.as_str()), Some(hash));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
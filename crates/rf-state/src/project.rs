@@ -129,6 +129,9 @@ pub struct InsertState {
     pub mix: f64,
     pub parameters: HashMap<u32, f64>,
     pub preset_name: Option<String>,
+    /// Plugin state stored in the sidecar store
+    #[serde(default)]
+    pub state_ref: Option<PluginStateHash>,
 }
 
 /// Send state
@@ -202,6 +205,9 @@ pub struct TrackState {
     /// Instrument plugin ID (for Instrument tracks)
     #[serde(default)]
     pub instrument_plugin_id: Option<String>,
+    /// Instrument plugin state stored in the sidecar store
+    #[serde(default)]
+    pub instrument_state_ref: Option<PluginStateHash>,
     /// Per-channel output bus routing for multi-output plugins.
     /// Each entry is a bus name string (e.g., "Master", "Music", "SFX").
     /// Index = stereo channel pair. Empty = single bus routing.
@@ -281,8 +287,26 @@ pub struct AutomationPointState {
     pub tension: f64,
 }
 
+// ============ MIDI Learn ============
+
+/// MIDI CC → insert parameter mapping
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MidiCcMappingState {
+    pub channel: u8,
+    pub cc: u8,
+    pub track_id: u64,
+    pub slot_index: u32,
+    pub param_index: u32,
+    pub min: f64,
+    pub max: f64,
+    /// 0 = linear, 1 = logarithmic, 2 = exponential
+    pub curve_type: u8,
+    pub smoothing_ms: f64,
+}
+
 // Marker types moved to markers.rs
 use crate::markers::MarkerTrack;
+use crate::plugin_state::{PluginStateHash, PluginStateStore};
 
 // ============ Complete Project ============
 
@@ -311,6 +335,9 @@ pub struct Project {
     pub loop_start: u64,
     /// Loop end
     pub loop_end: u64,
+    /// MIDI learn CC mappings
+    #[serde(default)]
+    pub midi_mappings: Vec<MidiCcMappingState>,
 }
 
 impl Default for Project {
@@ -336,6 +363,7 @@ impl Default for Project {
             loop_enabled: false,
             loop_start: 0,
             loop_end: 0,
+            midi_mappings: Vec::new(),
         }
     }
 }
@@ -381,10 +409,56 @@ impl Project {
         }
 
         match format {
-            ProjectFormat::Json => self.save_json(path),
-            ProjectFormat::Binary => self.save_binary(path),
-            ProjectFormat::Compressed => self.save_compressed(path),
+            ProjectFormat::Json => self.save_json(path)?,
+            ProjectFormat::Binary => self.save_binary(path)?,
+            ProjectFormat::Compressed => self.save_compressed(path)?,
         }
+
+        // Drop sidecar plugin states no longer referenced by the saved project
+        let refs = self.plugin_state_refs();
+        let removed = PluginStateStore::for_project(path).collect_garbage(refs)?;
+        if removed > 0 {
+            log::debug!("Removed {} unreferenced plugin state(s)", removed);
+        }
+        Ok(())
+    }
+
+    /// Save under a new path ("Save As"), bringing the sidecar plugin states along
+    ///
+    /// States referenced by the project are copied from `previous_path`'s store
+    /// before saving, so the garbage collection in `save` keeps them.
+    pub fn save_as(
+        &self,
+        previous_path: &Path,
+        path: &Path,
+        format: ProjectFormat,
+    ) -> Result<(), ProjectError> {
+        let copied = PluginStateStore::for_project(previous_path).copy_to(
+            &PluginStateStore::for_project(path),
+            self.plugin_state_refs(),
+        )?;
+        if copied > 0 {
+            log::debug!(
+                "Copied {} plugin state(s) to the new project location",
+                copied
+            );
+        }
+        self.save(path, format)
+    }
+
+    /// Sidecar plugin states referenced by this project
+    pub fn plugin_state_refs(&self) -> Vec<&PluginStateHash> {
+        let inserts = self
+            .buses
+            .iter()
+            .flat_map(|bus| &bus.inserts)
+            .chain(&self.master.inserts)
+            .filter_map(|insert| insert.state_ref.as_ref());
+        let instruments = self
+            .tracks
+            .iter()
+            .filter_map(|track| track.instrument_state_ref.as_ref());
+        inserts.chain(instruments).collect()
     }
 
     fn save_json(&self, path: &Path) -> Result<(), ProjectError> {
@@ -402,13 +476,12 @@ impl Project {
         // Write version
         file.write_all(&PROJECT_VERSION.to_le_bytes())?;
 
-        // Write checksum
-        let checksum = self.checksum();
-        file.write_all(&checksum.to_le_bytes())?;
-
-        // Write JSON payload
+        // Write checksum (of the payload bytes, so it can be verified before parsing)
         let json = self.to_json()?;
         let json_bytes = json.as_bytes();
+        file.write_all(&crc32_hash(json_bytes).to_le_bytes())?;
+
+        // Write JSON payload
         file.write_all(&(json_bytes.len() as u64).to_le_bytes())?;
         file.write_all(json_bytes)?;
 
@@ -460,10 +533,10 @@ impl Project {
 
     fn load_json(path: &Path) -> Result<Self, ProjectError> {
         let json = std::fs::read_to_string(path)?;
-        let project = Self::from_json(&json)?;
+        let project = ProjectLoader::load_str(&json)?;
 
-        // Validate and migrate
-        Self::validate_and_migrate(project)
+        // Validate (migration already happened in the loader)
+        Self::validate(project)
     }
 
     fn load_binary(path: &Path) -> Result<Self, ProjectError> {
@@ -507,21 +580,23 @@ impl Project {
         // Read JSON
         let mut json_bytes = vec![0u8; json_len];
         file.read_exact(&mut json_bytes)?;
+
+        // Verify checksum on the stored bytes, before any migration rewrites them.
+        // Older files checksummed the compact re-serialization of the project.
+        let computed_checksum = crc32_hash(&json_bytes);
         let json = String::from_utf8(json_bytes)
             .map_err(|_| ProjectError::Invalid("Invalid UTF-8 in project".to_string()))?;
-
-        let mut project: Project = serde_json::from_str(&json)?;
-
-        // Verify checksum
-        let computed_checksum = project.checksum();
-        if stored_checksum != computed_checksum {
-            log::warn!(
+        if stored_checksum != computed_checksum
+            && Self::from_json(&json).map(|p| p.checksum()).ok() != Some(stored_checksum)
+        {
+            return Err(ProjectError::Invalid(format!(
                 "Checksum mismatch: stored={}, computed={}",
-                stored_checksum,
-                computed_checksum
-            );
+                stored_checksum, computed_checksum
+            )));
         }
 
+        let mut project = ProjectLoader::load_str(&json)?;
+
         // Read embedded assets
         let mut asset_count_bytes = [0u8; 4];
         if file.read_exact(&mut asset_count_bytes).is_ok() {
@@ -550,7 +625,7 @@ impl Project {
             }
         }
 
-        Self::validate_and_migrate(project)
+        Self::validate(project)
     }
 
     /// Maximum allowed number of tracks (prevents DoS)
@@ -562,8 +637,8 @@ impl Project {
     /// Maximum allowed embedded assets
     const MAX_EMBEDDED_ASSETS: usize = 1000;
 
-    /// Validate and migrate project
-    fn validate_and_migrate(mut project: Project) -> Result<Project, ProjectError> {
+    /// Validate project size limits
+    fn validate(project: Project) -> Result<Project, ProjectError> {
         // Security: Validate project size limits to prevent DoS
         if project.tracks.len() > Self::MAX_TRACKS {
             return Err(ProjectError::Invalid(format!(
@@ -600,11 +675,6 @@ impl Project {
             )));
         }
 
-        // Migrate from older versions
-        if project.meta.version < PROJECT_VERSION {
-            project = migrate_project(project)?;
-        }
-
         Ok(project)
     }
 
@@ -684,21 +754,81 @@ impl ProjectFormat {
 
 // ============ Migration ============
 
-/// Migrate project from older version
-fn migrate_project(mut project: Project) -> Result<Project, ProjectError> {
-    // V1 -> V2 migration
-    if project.meta.version == 1 {
-        log::info!("Migrating project from v1 to v2");
-
-        // V2 added tempo, time signature, loop
-        // These fields have defaults, so nothing special needed
+/// A single format migration, upgrading a deserialized project one version
+pub type ProjectMigration = fn(&mut serde_json::Value) -> Result<(), ProjectError>;
+
+/// Ordered migration chain: `PROJECT_MIGRATIONS[i]` upgrades v`i+1` to v`i+2`
+const PROJECT_MIGRATIONS: [ProjectMigration; PROJECT_VERSION as usize - 1] = [migrate_v1_to_v2];
+
+/// Loads project JSON of any supported format version
+///
+/// The JSON is first read as an untyped value, its `meta.version` is
+/// inspected (missing means v1), and every migration from that version up
+/// to [`PROJECT_VERSION`] is applied in order before the value is
+/// deserialized into a [`Project`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ProjectLoader;
+
+impl ProjectLoader {
+    /// Load a project from a JSON string, migrating it to the current version
+    pub fn load_str(json: &str) -> Result<Project, ProjectError> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let value = Self::migrate(value)?;
+        Ok(serde_json::from_value(value)?)
+    }
 
-        project.meta.version = 2;
+    /// Format version stored in a project value (missing means v1)
+    pub fn file_version(value: &serde_json::Value) -> Result<u32, ProjectError> {
+        match value.get("meta").and_then(|meta| meta.get("version")) {
+            None | Some(serde_json::Value::Null) => Ok(1),
+            Some(version) => version
+                .as_u64()
+                .and_then(|v| u32::try_from(v).ok())
+                .filter(|&v| v >= 1)
+                .ok_or_else(|| {
+                    ProjectError::Invalid(format!("Invalid project version: {version}"))
+                }),
+        }
     }
 
-    // Future migrations go here...
+    /// Apply every migration from the value's version up to [`PROJECT_VERSION`]
+    pub fn migrate(mut value: serde_json::Value) -> Result<serde_json::Value, ProjectError> {
+        let version = Self::file_version(&value)?;
+        if version > PROJECT_VERSION {
+            return Err(ProjectError::FutureVersion(version));
+        }
+
+        for (from, migration) in
+            (version..PROJECT_VERSION).zip(&PROJECT_MIGRATIONS[version as usize - 1..])
+        {
+            log::info!("Migrating project from v{} to v{}", from, from + 1);
+            migration(&mut value)?;
+            let meta = value
+                .get_mut("meta")
+                .and_then(|meta| meta.as_object_mut())
+                .ok_or_else(|| ProjectError::Invalid("Project has no metadata".to_string()))?;
+            meta.insert("version".to_string(), (from + 1).into());
+        }
+
+        Ok(value)
+    }
+}
 
-    Ok(project)
+/// V1 -> V2: tempo, time signature, playhead and loop were added
+fn migrate_v1_to_v2(value: &mut serde_json::Value) -> Result<(), ProjectError> {
+    let project = value
+        .as_object_mut()
+        .ok_or_else(|| ProjectError::Invalid("Project is not a JSON object".to_string()))?;
+
+    project.entry("tempo").or_insert(120.0.into());
+    project.entry("time_sig_num").or_insert(4.into());
+    project.entry("time_sig_denom").or_insert(4.into());
+    project.entry("playhead").or_insert(0.into());
+    project.entry("loop_enabled").or_insert(false.into());
+    project.entry("loop_start").or_insert(0.into());
+    project.entry("loop_end").or_insert(0.into());
+
+    Ok(())
 }
 
 // ============ Errors ============
@@ -811,4 +941,142 @@ mod tests {
         assert_eq!(project.tempo, 120.0);
         assert_eq!(project.time_sig_num, 4);
     }
+
+    #[test]
+    fn test_loader_migrates_v1() {
+        // v1 files had no version field and no tempo/time signature/loop
+        let mut value = serde_json::to_value(Project::new("Old Project")).unwrap();
+        value["meta"].as_object_mut().unwrap().remove("version");
+        for field in [
+            "tempo",
+            "time_sig_num",
+            "time_sig_denom",
+            "playhead",
+            "loop_enabled",
+            "loop_start",
+            "loop_end",
+        ] {
+            value.as_object_mut().unwrap().remove(field);
+        }
+        let json = value.to_string();
+        assert!(Project::from_json(&json).is_err());
+
+        let project = ProjectLoader::load_str(&json).unwrap();
+        assert_eq!(project.meta.version, PROJECT_VERSION);
+        assert_eq!(project.meta.name, "Old Project");
+        assert_eq!(project.tempo, 120.0);
+        assert_eq!(project.time_sig_num, 4);
+        assert_eq!(project.time_sig_denom, 4);
+        assert!(!project.loop_enabled);
+    }
+
+    #[test]
+    fn test_loader_current_version_untouched() {
+        let mut project = Project::new("Current");
+        project.tempo = 96.0;
+        let loaded = ProjectLoader::load_str(&project.to_json().unwrap()).unwrap();
+        assert_eq!(loaded.meta.version, PROJECT_VERSION);
+        assert_eq!(loaded.tempo, 96.0);
+    }
+
+    #[test]
+    fn test_loader_rejects_future_and_invalid_versions() {
+        let mut value = serde_json::to_value(Project::new("Future")).unwrap();
+        value["meta"]["version"] = serde_json::json!(PROJECT_VERSION + 1);
+        assert!(matches!(
+            ProjectLoader::load_str(&value.to_string()),
+            Err(ProjectError::FutureVersion(v)) if v == PROJECT_VERSION + 1
+        ));
+
+        value["meta"]["version"] = serde_json::json!("two");
+        assert!(matches!(
+            ProjectLoader::load_str(&value.to_string()),
+            Err(ProjectError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_binary_checksum_verified_before_migration() {
+        let dir = std::env::temp_dir().join("rf_project_binary_checksum_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+
+        // Round trip
+        let path = dir.join("Song.rfp");
+        Project::new("Song")
+            .save(&path, ProjectFormat::Binary)
+            .unwrap();
+        assert_eq!(Project::load(&path).unwrap().meta.name, "Song");
+
+        // A v1 payload is checksummed as stored, then migrated
+        let mut value = serde_json::to_value(Project::new("Old")).unwrap();
+        value["meta"].as_object_mut().unwrap().remove("version");
+        value.as_object_mut().unwrap().remove("tempo");
+        let json = value.to_string();
+        let write = |path: &Path, json: &[u8], checksum: u32| {
+            let mut bytes = MAGIC_BYTES.to_vec();
+            bytes.extend_from_slice(&1u32.to_le_bytes());
+            bytes.extend_from_slice(&checksum.to_le_bytes());
+            bytes.extend_from_slice(&(json.len() as u64).to_le_bytes());
+            bytes.extend_from_slice(json);
+            bytes.extend_from_slice(&0u32.to_le_bytes());
+            std::fs::write(path, bytes).unwrap();
+        };
+        let old = dir.join("Old.rfp");
+        write(&old, json.as_bytes(), crc32_hash(json.as_bytes()));
+        let loaded = Project::load(&old).unwrap();
+        assert_eq!(loaded.meta.version, PROJECT_VERSION);
+        assert_eq!(loaded.tempo, 120.0);
+
+        // Corrupted payload is rejected
+        let corrupt = json.replace("\"Old\"", "\"Odd\"");
+        write(&old, corrupt.as_bytes(), crc32_hash(json.as_bytes()));
+        assert!(matches!(Project::load(&old), Err(ProjectError::Invalid(_))));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_save_collects_unreferenced_plugin_states() {
+        let dir = std::env::temp_dir().join("rf_project_plugin_state_test");
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("Song.rfproj");
+        let store = PluginStateStore::for_project(&path);
+
+        let sampler = store.store(&[7u8; 4096]).unwrap();
+        let stale = store.store(b"removed instance").unwrap();
+
+        // Two sampler inserts with identical state reference one sidecar file
+        let mut project = Project::new("Song");
+        for slot in 0..2 {
+            project.master.inserts.push(InsertState {
+                slot,
+                plugin_id: "com.example.sampler".to_string(),
+                bypassed: false,
+                mix: 1.0,
+                parameters: HashMap::new(),
+                preset_name: None,
+                state_ref: Some(sampler.clone()),
+            });
+        }
+        project.save(&path, ProjectFormat::Json).unwrap();
+
+        assert_eq!(store.list().unwrap(), vec![sampler.clone()]);
+        assert!(!store.contains(&stale));
+
+        let loaded = Project::load(&path).unwrap();
+        assert_eq!(loaded.plugin_state_refs(), vec![&sampler, &sampler]);
+
+        // Save As brings the states along and leaves the original project intact
+        let copy_path = dir.join("copy").join("Song v2.rfproj");
+        loaded
+            .save_as(&path, &copy_path, ProjectFormat::Json)
+            .unwrap();
+        let copy_store = PluginStateStore::for_project(&copy_path);
+        assert_eq!(copy_store.list().unwrap(), vec![sampler.clone()]);
+        assert_eq!(copy_store.load(&sampler).unwrap(), vec![7u8; 4096]);
+        assert!(store.contains(&sampler));
+
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
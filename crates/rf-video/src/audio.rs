@@ -0,0 +1,184 @@
+//! Embedded audio extraction
+//!
+//! Decodes a video's audio stream with Symphonia (independent of the video
+//! backend) and converts it to the session rate with sinc resampling.
+
+use std::fs::File;
+use std::path::Path;
+
+use rubato::{
+    Resampler, SincFixedIn, SincInterpolationParameters, SincInterpolationType, WindowFunction,
+};
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::{CODEC_TYPE_NULL, DecoderOptions};
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::meta::MetadataOptions;
+use symphonia::core::probe::Hint;
+
+use crate::{VideoError, VideoResult};
+
+/// Decoded audio stream (interleaved)
+#[derive(Debug, Clone)]
+pub(crate) struct DecodedAudio {
+    pub sample_rate: u32,
+    pub channels: usize,
+    pub samples: Vec<f32>,
+}
+
+impl DecodedAudio {
+    /// Number of frames
+    pub fn frames(&self) -> usize {
+        self.samples.len() / self.channels.max(1)
+    }
+}
+
+/// Decode the first audio stream of a media file
+pub(crate) fn decode_audio(path: &Path) -> VideoResult<DecodedAudio> {
+    let file = File::open(path).map_err(|e| VideoError::OpenFailed(e.to_string()))?;
+    let mss = MediaSourceStream::new(Box::new(file), Default::default());
+
+    let mut hint = Hint::new();
+    if let Some(ext) = path.extension().and_then(|e| e.to_str()) {
+        hint.with_extension(ext);
+    }
+
+    let probed = symphonia::default::get_probe()
+        .format(
+            &hint,
+            mss,
+            &FormatOptions::default(),
+            &MetadataOptions::default(),
+        )
+        .map_err(|e| VideoError::OpenFailed(e.to_string()))?;
+    let mut format = probed.format;
+
+    let track = format
+        .tracks()
+        .iter()
+        .find(|t| t.codec_params.codec != CODEC_TYPE_NULL)
+        .ok_or(VideoError::NoAudioStream)?;
+    let track_id = track.id;
+    let codec_params = track.codec_params.clone();
+
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&codec_params, &DecoderOptions::default())
+        .map_err(|e| VideoError::UnsupportedCodec(e.to_string()))?;
+
+    let mut sample_rate = codec_params.sample_rate.unwrap_or(48000);
+    let mut channels = codec_params.channels.map(|c| c.count()).unwrap_or(2);
+    let mut samples = Vec::new();
+    let mut buffer: Option<SampleBuffer<f32>> = None;
+
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            Err(SymphoniaError::IoError(ref e))
+                if e.kind() == std::io::ErrorKind::UnexpectedEof =>
+            {
+                break;
+            }
+            Err(e) => return Err(VideoError::DecodeFailed(e.to_string())),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = match decoder.decode(&packet) {
+            Ok(decoded) => decoded,
+            // Skip corrupt packets
+            Err(SymphoniaError::DecodeError(_)) => continue,
+            Err(e) => return Err(VideoError::DecodeFailed(e.to_string())),
+        };
+
+        let spec = *decoded.spec();
+        sample_rate = spec.rate;
+        channels = spec.channels.count();
+        let needed = decoded.capacity() * channels;
+        if buffer.as_ref().is_none_or(|b| b.capacity() < needed) {
+            buffer = Some(SampleBuffer::new(decoded.capacity() as u64, spec));
+        }
+        if let Some(buffer) = buffer.as_mut() {
+            buffer.copy_interleaved_ref(decoded);
+            samples.extend_from_slice(buffer.samples());
+        }
+    }
+
+    Ok(DecodedAudio {
+        sample_rate,
+        channels,
+        samples,
+    })
+}
+
+/// Sinc-resample to `target_rate`, compensating the filter delay so the
+/// output stays time-aligned with the input
+pub(crate) fn resample(audio: DecodedAudio, target_rate: u32) -> VideoResult<DecodedAudio> {
+    if audio.sample_rate == target_rate || audio.samples.is_empty() {
+        return Ok(DecodedAudio {
+            sample_rate: target_rate,
+            ..audio
+        });
+    }
+
+    let channels = audio.channels.max(1);
+    let frames = audio.frames();
+    let ratio = target_rate as f64 / audio.sample_rate as f64;
+    let expected = (frames as f64 * ratio).round() as usize;
+    let chunk_size = 1024;
+
+    let params = SincInterpolationParameters {
+        sinc_len: 128,
+        f_cutoff: 0.95,
+        interpolation: SincInterpolationType::Cubic,
+        oversampling_factor: 128,
+        window: WindowFunction::BlackmanHarris2,
+    };
+    let map_err = |e: String| VideoError::DecodeFailed(format!("resample: {}", e));
+    let mut resampler = SincFixedIn::<f32>::new(ratio, 1.0, params, chunk_size, channels)
+        .map_err(|e| map_err(e.to_string()))?;
+    let delay = resampler.output_delay();
+
+    // De-interleave
+    let input: Vec<Vec<f32>> = (0..channels)
+        .map(|ch| {
+            audio
+                .samples
+                .iter()
+                .skip(ch)
+                .step_by(channels)
+                .copied()
+                .collect()
+        })
+        .collect();
+
+    let mut output: Vec<Vec<f32>> = vec![Vec::with_capacity(expected + delay); channels];
+    let mut pos = 0;
+    while output[0].len() < expected + delay {
+        let end = (pos + chunk_size).min(frames);
+        let chunk: Vec<&[f32]> = input.iter().map(|ch| &ch[pos.min(end)..end]).collect();
+        let result = if end - pos.min(end) == chunk_size {
+            resampler.process(&chunk, None)
+        } else {
+            // Partial input at the end, then zeros to flush the filter
+            resampler.process_partial(Some(&chunk), None)
+        }
+        .map_err(|e| map_err(e.to_string()))?;
+        for (out, data) in output.iter_mut().zip(result) {
+            out.extend_from_slice(&data);
+        }
+        pos = end;
+    }
+
+    let mut samples = Vec::with_capacity(expected * channels);
+    for frame in delay..delay + expected {
+        samples.extend(output.iter().map(|ch| ch[frame]));
+    }
+
+    Ok(DecodedAudio {
+        sample_rate: target_rate,
+        channels,
+        samples,
+    })
+}
@@ -10,6 +10,7 @@ use std::fs::File;
 use std::io::BufReader;
 use std::path::Path;
 
+use crate::audio::{self, DecodedAudio};
 use crate::timecode::FrameRate;
 use crate::{VideoError, VideoInfo, VideoResult};
 
@@ -46,6 +47,97 @@ impl PixelFormat {
     }
 }
 
+// ============ Decode Options ============
+
+/// Hardware decode backend
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HwAccel {
+    /// Software decode only
+    #[default]
+    None,
+    /// Best available backend for this platform
+    Auto,
+    /// Apple VideoToolbox (macOS)
+    VideoToolbox,
+    /// NVIDIA NVDEC (CUDA)
+    Nvdec,
+    /// VA-API (Linux, Intel/AMD)
+    Vaapi,
+    /// Direct3D 11 (Windows)
+    D3d11va,
+    /// Intel Quick Sync
+    Qsv,
+}
+
+impl HwAccel {
+    /// Concrete backends to try, in order
+    pub fn candidates(self) -> Vec<HwAccel> {
+        match self {
+            HwAccel::None => Vec::new(),
+            HwAccel::Auto => {
+                if cfg!(target_os = "macos") {
+                    vec![HwAccel::VideoToolbox]
+                } else if cfg!(target_os = "windows") {
+                    vec![HwAccel::Nvdec, HwAccel::D3d11va, HwAccel::Qsv]
+                } else if cfg!(target_os = "linux") {
+                    vec![HwAccel::Nvdec, HwAccel::Vaapi, HwAccel::Qsv]
+                } else {
+                    Vec::new()
+                }
+            }
+            accel => vec![accel],
+        }
+    }
+
+    /// Backend name
+    pub fn name(&self) -> &'static str {
+        match self {
+            HwAccel::None => "none",
+            HwAccel::Auto => "auto",
+            HwAccel::VideoToolbox => "videotoolbox",
+            HwAccel::Nvdec => "cuda",
+            HwAccel::Vaapi => "vaapi",
+            HwAccel::D3d11va => "d3d11va",
+            HwAccel::Qsv => "qsv",
+        }
+    }
+}
+
+/// Decoder open options
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DecodeOptions {
+    /// Requested hardware acceleration (falls back to software)
+    pub hw_accel: HwAccel,
+}
+
+/// Active decode path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodePath {
+    Software,
+    Hardware(HwAccel),
+}
+
+impl DecodePath {
+    /// Is hardware decode active
+    pub fn is_hardware(&self) -> bool {
+        matches!(self, DecodePath::Hardware(_))
+    }
+}
+
+/// Try each hardware backend in order, falling back to software
+pub(crate) fn open_with_fallback<T>(
+    hw_accel: HwAccel,
+    mut open_hardware: impl FnMut(HwAccel) -> VideoResult<T>,
+    open_software: impl FnOnce() -> VideoResult<T>,
+) -> VideoResult<(T, DecodePath)> {
+    for accel in hw_accel.candidates() {
+        if let Ok(decoder) = open_hardware(accel) {
+            return Ok((decoder, DecodePath::Hardware(accel)));
+        }
+    }
+    Ok((open_software()?, DecodePath::Software))
+}
+
 // ============ Video Frame ============
 
 /// Decoded video frame
@@ -179,21 +271,38 @@ pub struct VideoDecoder {
     info: VideoInfo,
     #[cfg(not(feature = "ffmpeg"))]
     current_frame: u64,
+    /// Embedded audio, decoded and resampled on first request
+    audio: Option<DecodedAudio>,
 }
 
 impl VideoDecoder {
     pub fn open(path: &Path) -> VideoResult<Self> {
+        Self::open_with(path, DecodeOptions::default())
+    }
+
+    /// Open with decode options. Hardware decode falls back to software on failure.
+    pub fn open_with(path: &Path, options: DecodeOptions) -> VideoResult<Self> {
         #[cfg(feature = "ffmpeg")]
         {
-            let inner = ffmpeg_backend::FfmpegDecoder::open(path)?;
-            Ok(Self { inner })
+            let inner = ffmpeg_backend::FfmpegDecoder::open_with(path, options)?;
+            Ok(Self { inner, audio: None })
         }
         #[cfg(not(feature = "ffmpeg"))]
         {
+            // No codec backend — nothing to accelerate
+            let _ = options;
             Self::open_mp4_fallback(path)
         }
     }
 
+    /// Active decode path (hardware or software)
+    pub fn decode_path(&self) -> DecodePath {
+        #[cfg(feature = "ffmpeg")]
+        { self.inner.decode_path() }
+        #[cfg(not(feature = "ffmpeg"))]
+        { DecodePath::Software }
+    }
+
     pub fn info(&self) -> &VideoInfo {
         #[cfg(feature = "ffmpeg")]
         { self.inner.info() }
@@ -235,6 +344,57 @@ impl VideoDecoder {
         { self.current_frame }
     }
 
+    /// Decode embedded audio for a timeline range
+    ///
+    /// `start_sample` is a timeline position at `target_sr`; the stream starts
+    /// at the video's start timecode (or 0 without one). Returns `len` frames,
+    /// interleaved at the stream's channel count and silence-padded outside it.
+    pub fn decode_audio_range(
+        &mut self,
+        start_sample: u64,
+        len: usize,
+        target_sr: u32,
+    ) -> VideoResult<Vec<f32>> {
+        if !self.info().has_audio {
+            return Err(VideoError::NoAudioStream);
+        }
+        if target_sr == 0 {
+            return Err(VideoError::DecodeFailed("target sample rate is 0".into()));
+        }
+        if self
+            .audio
+            .as_ref()
+            .is_none_or(|a| a.sample_rate != target_sr)
+        {
+            let decoded = audio::decode_audio(&self.info().path)?;
+            self.audio = Some(audio::resample(decoded, target_sr)?);
+        }
+
+        let info = self.info();
+        let offset = info
+            .start_timecode
+            .map(|tc| tc.to_samples(target_sr))
+            .unwrap_or(0);
+        let Some(audio) = self.audio.as_ref() else {
+            return Err(VideoError::NoAudioStream);
+        };
+
+        let channels = audio.channels;
+        let mut output = vec![0.0; len * channels];
+
+        // Overlap of the request with the stream, in stream frames
+        let first = start_sample as i64 - offset as i64;
+        let from = first.max(0);
+        let to = (first + len as i64).min(audio.frames() as i64);
+        if from < to {
+            let dest = ((from - first) as usize) * channels;
+            let src = &audio.samples[from as usize * channels..to as usize * channels];
+            output[dest..dest + src.len()].copy_from_slice(src);
+        }
+
+        Ok(output)
+    }
+
     /// Pure Rust MP4 fallback (metadata only, placeholder frames)
     #[cfg(not(feature = "ffmpeg"))]
     fn open_mp4_fallback(path: &Path) -> VideoResult<Self> {
@@ -337,6 +497,7 @@ impl VideoDecoder {
         Ok(Self {
             info,
             current_frame: 0,
+            audio: None,
         })
     }
 }
@@ -360,7 +521,10 @@ fn fps_to_frame_rate(fps: f64) -> FrameRate {
     } else if (fps - 60.0).abs() < 0.01 {
         FrameRate::Fps60
     } else {
-        FrameRate::Custom(fps)
+        FrameRate::Custom {
+            num: (fps * 1000.0).round() as u32,
+            den: 1000,
+        }
     }
 }
 
@@ -370,13 +534,21 @@ fn fps_to_frame_rate(fps: f64) -> FrameRate {
 pub mod ffmpeg_backend {
     use super::*;
 
+    use ffmpeg_next::ffi;
+    use ffmpeg_next::format::Pixel;
+
     /// FFmpeg-based video decoder with full codec support
     pub struct FfmpegDecoder {
         info: VideoInfo,
         input: ffmpeg_next::format::context::Input,
         stream_index: usize,
         decoder: ffmpeg_next::codec::decoder::Video,
-        scaler: ffmpeg_next::software::scaling::Context,
+        /// RGB24 converter, rebuilt when the source format changes
+        scaler: Option<ffmpeg_next::software::scaling::Context>,
+        scaler_format: Pixel,
+        /// Hardware surface format (frames in it need a transfer to system memory)
+        hw_format: Option<Pixel>,
+        decode_path: DecodePath,
         current_frame: u64,
         time_base: ffmpeg_next::Rational,
     }
@@ -389,6 +561,10 @@ pub mod ffmpeg_backend {
 
     impl FfmpegDecoder {
         pub fn open(path: &Path) -> VideoResult<Self> {
+            Self::open_with(path, DecodeOptions::default())
+        }
+
+        pub fn open_with(path: &Path, options: DecodeOptions) -> VideoResult<Self> {
             ffmpeg_next::init().map_err(|e| VideoError::FfmpegError(e.to_string()))?;
 
             let input = ffmpeg_next::format::input(path)
@@ -403,29 +579,19 @@ pub mod ffmpeg_backend {
             let time_base = stream.time_base();
 
             let codec_params = stream.parameters();
-            let codec = ffmpeg_next::codec::Context::from_parameters(codec_params)
-                .map_err(|e| VideoError::FfmpegError(e.to_string()))?;
-
-            let decoder = codec
-                .decoder()
-                .video()
-                .map_err(|e| VideoError::FfmpegError(e.to_string()))?;
+            let ((decoder, hw_format), decode_path) = super::open_with_fallback(
+                options.hw_accel,
+                |accel| {
+                    open_hardware(codec_params.clone(), accel)
+                        .map(|(decoder, format)| (decoder, Some(format)))
+                },
+                || open_software(codec_params.clone()).map(|decoder| (decoder, None)),
+            )?;
 
             let width = decoder.width();
             let height = decoder.height();
             let src_format = decoder.format();
 
-            let scaler = ffmpeg_next::software::scaling::Context::get(
-                src_format,
-                width,
-                height,
-                ffmpeg_next::format::Pixel::RGB24,
-                width,
-                height,
-                ffmpeg_next::software::scaling::Flags::BILINEAR,
-            )
-            .map_err(|e| VideoError::FfmpegError(e.to_string()))?;
-
             let frame_rate = stream.rate();
             let fps = frame_rate.0 as f64 / frame_rate.1 as f64;
 
@@ -472,7 +638,10 @@ pub mod ffmpeg_backend {
                 input,
                 stream_index,
                 decoder,
-                scaler,
+                scaler: None,
+                scaler_format: Pixel::None,
+                hw_format,
+                decode_path,
                 current_frame: 0,
                 time_base,
             })
@@ -482,8 +651,12 @@ pub mod ffmpeg_backend {
             &self.info
         }
 
+        pub fn decode_path(&self) -> DecodePath {
+            self.decode_path
+        }
+
         pub fn seek_to_frame(&mut self, frame: u64) -> VideoResult<()> {
-            let fps = self.info.frame_rate.as_f64();
+            let fps = self.info.frame_rate.fps();
             let time_secs = frame as f64 / fps;
             let timestamp = (time_secs * ffmpeg_next::ffi::AV_TIME_BASE as f64) as i64;
 
@@ -502,6 +675,7 @@ pub mod ffmpeg_backend {
             }
 
             let mut decoded = ffmpeg_next::util::frame::Video::empty();
+            let mut sw_frame = ffmpeg_next::util::frame::Video::empty();
             let mut rgb_frame = ffmpeg_next::util::frame::Video::empty();
 
             for (stream, packet) in self.input.packets() {
@@ -509,14 +683,63 @@ pub mod ffmpeg_backend {
                     continue;
                 }
 
-                self.decoder
-                    .send_packet(&packet)
-                    .map_err(|e| VideoError::DecodeFailed(e.to_string()))?;
+                if let Err(e) = self.decoder.send_packet(&packet) {
+                    if !self.decode_path.is_hardware() {
+                        return Err(VideoError::DecodeFailed(e.to_string()));
+                    }
+                    // Hardware decoder rejected the stream — continue in software
+                    self.decoder = open_software(stream.parameters())?;
+                    self.hw_format = None;
+                    self.decode_path = DecodePath::Software;
+                    self.decoder
+                        .send_packet(&packet)
+                        .map_err(|e| VideoError::DecodeFailed(e.to_string()))?;
+                }
 
                 if self.decoder.receive_frame(&mut decoded).is_ok() {
-                    self.scaler
-                        .run(&decoded, &mut rgb_frame)
-                        .map_err(|e| VideoError::DecodeFailed(e.to_string()))?;
+                    // Hardware surfaces must be downloaded (usually NV12/P010) before scaling
+                    let source = if Some(decoded.format()) == self.hw_format {
+                        // SAFETY: both frames are valid, sw_frame is unallocated and
+                        // filled by FFmpeg in the surface's transfer format.
+                        let ret = unsafe {
+                            ffi::av_hwframe_transfer_data(
+                                sw_frame.as_mut_ptr(),
+                                decoded.as_ptr(),
+                                0,
+                            )
+                        };
+                        if ret < 0 {
+                            return Err(VideoError::DecodeFailed(format!(
+                                "hardware frame transfer failed ({})",
+                                ret
+                            )));
+                        }
+                        &sw_frame
+                    } else {
+                        &decoded
+                    };
+
+                    if self.scaler.is_none() || source.format() != self.scaler_format {
+                        self.scaler = Some(
+                            ffmpeg_next::software::scaling::Context::get(
+                                source.format(),
+                                source.width(),
+                                source.height(),
+                                Pixel::RGB24,
+                                source.width(),
+                                source.height(),
+                                ffmpeg_next::software::scaling::Flags::BILINEAR,
+                            )
+                            .map_err(|e| VideoError::FfmpegError(e.to_string()))?,
+                        );
+                        self.scaler_format = source.format();
+                    }
+
+                    if let Some(scaler) = self.scaler.as_mut() {
+                        scaler
+                            .run(source, &mut rgb_frame)
+                            .map_err(|e| VideoError::DecodeFailed(e.to_string()))?;
+                    }
 
                     let video_frame = VideoFrame {
                         frame_number: self.current_frame,
@@ -544,4 +767,283 @@ pub mod ffmpeg_backend {
             self.info.duration_frames
         }
     }
+
+    /// Open software decoder
+    fn open_software(
+        params: ffmpeg_next::codec::Parameters,
+    ) -> VideoResult<ffmpeg_next::codec::decoder::Video> {
+        let codec = ffmpeg_next::codec::Context::from_parameters(params)
+            .map_err(|e| VideoError::FfmpegError(e.to_string()))?;
+
+        codec
+            .decoder()
+            .video()
+            .map_err(|e| VideoError::FfmpegError(e.to_string()))
+    }
+
+    /// Open hardware decoder, returning it with its surface pixel format
+    fn open_hardware(
+        params: ffmpeg_next::codec::Parameters,
+        accel: HwAccel,
+    ) -> VideoResult<(ffmpeg_next::codec::decoder::Video, Pixel)> {
+        let unavailable = || VideoError::UnsupportedCodec(format!("{} decode", accel.name()));
+        let device_type = device_type(accel).ok_or_else(unavailable)?;
+
+        let mut context = ffmpeg_next::codec::Context::from_parameters(params)
+            .map_err(|e| VideoError::FfmpegError(e.to_string()))?;
+        let codec = ffmpeg_next::codec::decoder::find(context.id()).ok_or_else(unavailable)?;
+
+        // SAFETY: codec is a valid registered decoder; the device reference is
+        // handed to the codec context, which releases it on close.
+        let hw_format = unsafe {
+            let hw_format = hw_pixel_format(codec.as_ptr(), device_type).ok_or_else(unavailable)?;
+
+            let mut device: *mut ffi::AVBufferRef = std::ptr::null_mut();
+            let ret = ffi::av_hwdevice_ctx_create(
+                &mut device,
+                device_type,
+                std::ptr::null(),
+                std::ptr::null_mut(),
+                0,
+            );
+            if ret < 0 || device.is_null() {
+                return Err(VideoError::FfmpegError(format!(
+                    "{} device init failed ({})",
+                    accel.name(),
+                    ret
+                )));
+            }
+            (*context.as_mut_ptr()).hw_device_ctx = device;
+            hw_format
+        };
+
+        let decoder = context
+            .decoder()
+            .open_as(codec)
+            .and_then(|opened| opened.video())
+            .map_err(|e| VideoError::FfmpegError(e.to_string()))?;
+
+        Ok((decoder, Pixel::from(hw_format)))
+    }
+
+    /// FFmpeg device type for a concrete backend
+    fn device_type(accel: HwAccel) -> Option<ffi::AVHWDeviceType> {
+        match accel {
+            HwAccel::VideoToolbox => Some(ffi::AVHWDeviceType::AV_HWDEVICE_TYPE_VIDEOTOOLBOX),
+            HwAccel::Nvdec => Some(ffi::AVHWDeviceType::AV_HWDEVICE_TYPE_CUDA),
+            HwAccel::Vaapi => Some(ffi::AVHWDeviceType::AV_HWDEVICE_TYPE_VAAPI),
+            HwAccel::D3d11va => Some(ffi::AVHWDeviceType::AV_HWDEVICE_TYPE_D3D11VA),
+            HwAccel::Qsv => Some(ffi::AVHWDeviceType::AV_HWDEVICE_TYPE_QSV),
+            HwAccel::None | HwAccel::Auto => None,
+        }
+    }
+
+    /// Surface pixel format the codec uses with a device type, if supported
+    unsafe fn hw_pixel_format(
+        codec: *const ffi::AVCodec,
+        device_type: ffi::AVHWDeviceType,
+    ) -> Option<ffi::AVPixelFormat> {
+        let mut index = 0;
+        loop {
+            // SAFETY: caller guarantees codec is valid; configs are static.
+            let config = unsafe { ffi::avcodec_get_hw_config(codec, index) };
+            if config.is_null() {
+                return None;
+            }
+            let config = unsafe { &*config };
+            let device_ctx = ffi::AV_CODEC_HW_CONFIG_METHOD_HW_DEVICE_CTX as i32;
+            if config.methods & device_ctx != 0 && config.device_type == device_type {
+                return Some(config.pix_fmt);
+            }
+            index += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Write a 1 s MP4: 25 fps AVC video plus (optionally) silent 48 kHz AAC
+    fn write_fixture(path: &Path, with_audio: bool) {
+        use mp4::{
+            AacConfig, AudioObjectType, AvcConfig, ChannelConfig, MediaConfig, Mp4Config,
+            Mp4Sample, Mp4Writer, SampleFreqIndex, TrackConfig, TrackType,
+        };
+
+        let config = Mp4Config {
+            major_brand: str::parse("isom").unwrap(),
+            minor_version: 512,
+            compatible_brands: vec![str::parse("isom").unwrap(), str::parse("mp41").unwrap()],
+            timescale: 1000,
+        };
+        let file = std::fs::File::create(path).unwrap();
+        let mut writer = Mp4Writer::write_start(std::io::BufWriter::new(file), &config).unwrap();
+
+        writer
+            .add_track(&TrackConfig {
+                track_type: TrackType::Video,
+                timescale: 25,
+                language: "und".into(),
+                media_conf: MediaConfig::AvcConfig(AvcConfig {
+                    width: 64,
+                    height: 64,
+                    seq_param_set: vec![0x67, 0x42, 0xc0, 0x0a, 0xd9, 0x0f, 0x84, 0x00],
+                    pic_param_set: vec![0x68, 0xce, 0x3c, 0x80],
+                }),
+            })
+            .unwrap();
+        for i in 0..25 {
+            let sample = Mp4Sample {
+                start_time: i,
+                duration: 1,
+                rendering_offset: 0,
+                is_sync: true,
+                bytes: mp4::Bytes::from_static(&[0, 0, 0, 1, 0x65]),
+            };
+            writer.write_sample(1, &sample).unwrap();
+        }
+
+        if with_audio {
+            writer
+                .add_track(&TrackConfig {
+                    track_type: TrackType::Audio,
+                    timescale: 48000,
+                    language: "und".into(),
+                    media_conf: MediaConfig::AacConfig(AacConfig {
+                        bitrate: 0,
+                        profile: AudioObjectType::AacLowComplexity,
+                        freq_index: SampleFreqIndex::Freq48000,
+                        chan_conf: ChannelConfig::Mono,
+                    }),
+                })
+                .unwrap();
+            // Silent AAC-LC mono frame, 1024 samples each
+            for i in 0..47 {
+                let sample = Mp4Sample {
+                    start_time: i * 1024,
+                    duration: 1024,
+                    rendering_offset: 0,
+                    is_sync: true,
+                    bytes: mp4::Bytes::from_static(&[0x21, 0x10, 0x04, 0x60, 0x8c, 0x1c]),
+                };
+                writer.write_sample(2, &sample).unwrap();
+            }
+        }
+
+        writer.write_end().unwrap();
+        drop(writer);
+
+        // The mp4 writer emits SLConfigDescriptor predefined = 0; real encoders
+        // (and Symphonia) use 2 ("MP4"). The descriptor closes the esds box.
+        if with_audio {
+            let mut bytes = std::fs::read(path).unwrap();
+            let esds = bytes.windows(4).position(|w| w == b"esds").unwrap() - 4;
+            let size = u32::from_be_bytes(bytes[esds..esds + 4].try_into().unwrap()) as usize;
+            assert_eq!(bytes[esds + size - 3..esds + size], [0x06, 0x00, 0x00]);
+            bytes[esds + size - 1] = 0x02;
+            std::fs::write(path, bytes).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_decode_audio_range_length() {
+        let dir = tempfile::tempdir().unwrap();
+
+        let with_audio = dir.path().join("with_audio.mp4");
+        write_fixture(&with_audio, true);
+        let mut decoder = VideoDecoder::open(&with_audio).unwrap();
+        assert!(decoder.info().has_audio);
+
+        // Same rate, inside the stream
+        let audio = decoder.decode_audio_range(1000, 4800, 48000).unwrap();
+        let channels = decoder.audio.as_ref().unwrap().channels;
+        assert_eq!(audio.len(), 4800 * channels);
+
+        // Resampled to 44.1 kHz, running past the end of the stream
+        let audio = decoder.decode_audio_range(40000, 8820, 44100).unwrap();
+        assert_eq!(audio.len(), 8820 * channels);
+        assert!(audio.iter().all(|s| s.abs() < 1e-3));
+
+        let silent = dir.path().join("no_audio.mp4");
+        write_fixture(&silent, false);
+        let mut decoder = VideoDecoder::open(&silent).unwrap();
+        assert!(matches!(
+            decoder.decode_audio_range(0, 480, 48000),
+            Err(VideoError::NoAudioStream)
+        ));
+    }
+
+    #[cfg(not(feature = "ffmpeg"))]
+    #[test]
+    fn test_decode_audio_range_aligned_to_start_timecode() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("with_audio.mp4");
+        write_fixture(&path, true);
+        let mut decoder = VideoDecoder::open(&path).unwrap();
+
+        // Stream starts at 00:00:00:05 (25 fps) = 0.2 s = sample 9600 of the session
+        decoder.info.start_timecode =
+            Some(crate::Timecode::from_frames(5, decoder.info.frame_rate));
+        assert_eq!(decoder.info.frame_rate, FrameRate::Fps25);
+        // Non-silent stereo stream: left = frame index, right = -frame index
+        let samples = (0..48000).flat_map(|i| [i as f32, -(i as f32)]).collect();
+        decoder.audio = Some(DecodedAudio {
+            sample_rate: 48000,
+            channels: 2,
+            samples,
+        });
+
+        // Straddling the start: silence before the timecode, stream frame 0 at 9600
+        let audio = decoder.decode_audio_range(9000, 1200, 48000).unwrap();
+        assert!(audio[..600 * 2].iter().all(|&s| s == 0.0));
+        assert_eq!(&audio[600 * 2..602 * 2], &[0.0, -0.0, 1.0, -1.0]);
+        assert_eq!(audio[1199 * 2], 599.0);
+
+        // Inside the stream, session sample 20000 is stream frame 10400
+        let audio = decoder.decode_audio_range(20000, 480, 48000).unwrap();
+        assert!(audio.iter().any(|&s| s != 0.0));
+        assert_eq!(audio[0], 10400.0);
+        assert_eq!(audio[479 * 2 + 1], -10879.0);
+    }
+
+    #[test]
+    fn test_hw_accel_candidates() {
+        assert!(HwAccel::None.candidates().is_empty());
+        assert_eq!(HwAccel::Vaapi.candidates(), vec![HwAccel::Vaapi]);
+        assert!(!HwAccel::Auto.candidates().contains(&HwAccel::Auto));
+        assert_eq!(DecodeOptions::default().hw_accel, HwAccel::None);
+    }
+
+    #[test]
+    fn test_open_with_fallback() {
+        // Explicit backend succeeds
+        let (value, path) = open_with_fallback(HwAccel::Nvdec, |_| Ok("hw"), || Ok("sw")).unwrap();
+        assert_eq!(value, "hw");
+        assert_eq!(path, DecodePath::Hardware(HwAccel::Nvdec));
+
+        // Hardware fails -> software
+        let (value, path) = open_with_fallback(
+            HwAccel::VideoToolbox,
+            |_| Err(VideoError::UnsupportedCodec("no device".into())),
+            || Ok("sw"),
+        )
+        .unwrap();
+        assert_eq!(value, "sw");
+        assert!(!path.is_hardware());
+
+        // Software only never touches hardware
+        let mut tried = false;
+        let (_, path) = open_with_fallback(
+            HwAccel::None,
+            |_| {
+                tried = true;
+                Ok("hw")
+            },
+            || Ok("sw"),
+        )
+        .unwrap();
+        assert!(!tried);
+        assert_eq!(path, DecodePath::Software);
+    }
 }
@@ -21,15 +21,16 @@ use thiserror::Error;
 
 use rf_core::SampleRate;
 
+mod audio;
 pub mod decoder;
 pub mod frame_cache;
 pub mod thumbnail;
 pub mod timecode;
 
-pub use decoder::{PixelFormat, VideoDecoder, VideoFrame};
+pub use decoder::{DecodeOptions, DecodePath, HwAccel, PixelFormat, VideoDecoder, VideoFrame};
 pub use frame_cache::{CacheConfig, FrameCache};
 pub use thumbnail::{ThumbnailGenerator, ThumbnailStrip};
-pub use timecode::{FrameRate, Timecode, TimecodeFormat};
+pub use timecode::{FrameRate, Timecode, TimecodeRange};
 
 // ============ Error Types ============
 
@@ -145,7 +146,7 @@ impl VideoClip {
 
         let clip_offset_samples = position_samples - self.timeline_start;
         let clip_offset_secs = clip_offset_samples as f64 / sample_rate.as_f64();
-        let frame_offset = (clip_offset_secs * self.source.frame_rate.as_f64()) as u64;
+        let frame_offset = (clip_offset_secs * self.source.frame_rate.fps()) as u64;
 
         Some(self.source_in + frame_offset)
     }
@@ -245,7 +246,11 @@ impl VideoPlayer {
     /// Seek to timecode
     pub fn seek_to_timecode(&mut self, tc: &Timecode) -> VideoResult<()> {
         if let Some(ref info) = self.info {
-            let frame = tc.to_frame_number(&info.frame_rate);
+            let frame = if tc.rate == info.frame_rate {
+                tc.to_frames()
+            } else {
+                (tc.to_seconds() * info.frame_rate.fps()).round() as u64
+            };
             self.seek_to_frame(frame)
         } else {
             Err(VideoError::SeekFailed("No video loaded".into()))
@@ -256,7 +261,7 @@ impl VideoPlayer {
     pub fn seek_to_sample(&mut self, sample: u64) -> VideoResult<()> {
         if let Some(ref info) = self.info {
             let time_secs = sample as f64 / self.sample_rate.as_f64();
-            let frame = (time_secs * info.frame_rate.as_f64()) as u64;
+            let frame = (time_secs * info.frame_rate.fps()) as u64;
             self.seek_to_frame(frame)
         } else {
             Err(VideoError::SeekFailed("No video loaded".into()))
@@ -330,7 +335,7 @@ impl VideoPlayer {
     pub fn current_timecode(&self) -> Option<Timecode> {
         self.info
             .as_ref()
-            .map(|info| Timecode::from_frame_number(self.current_frame, &info.frame_rate))
+            .map(|info| Timecode::from_frames(self.current_frame, info.frame_rate))
     }
 
     /// Get playback state
@@ -598,7 +603,7 @@ mod tests {
 
     #[test]
     fn test_timecode_parsing() {
-        let tc = Timecode::parse("01:02:03:04", TimecodeFormat::NonDropFrame).unwrap();
+        let tc = Timecode::parse("01:02:03:04", FrameRate::Fps30).unwrap();
         assert_eq!(tc.hours, 1);
         assert_eq!(tc.minutes, 2);
         assert_eq!(tc.seconds, 3);
@@ -608,9 +613,9 @@ mod tests {
     #[test]
     fn test_frame_rate() {
         let fr = FrameRate::Fps24;
-        assert!((fr.as_f64() - 24.0).abs() < 0.001);
+        assert!((fr.fps() - 24.0).abs() < 0.001);
 
         let fr = FrameRate::Fps23_976;
-        assert!((fr.as_f64() - 23.976).abs() < 0.001);
+        assert!((fr.fps() - 23.976).abs() < 0.001);
     }
 }
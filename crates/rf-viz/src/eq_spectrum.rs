@@ -43,6 +43,10 @@ pub struct EqSpectrumConfig {
     pub show_piano_roll: bool,
     /// Show peak hold
     pub show_peak_hold: bool,
+    /// Show dynamic EQ overlay (live gain movement + thresholds)
+    pub show_dynamic_overlay: bool,
+    /// Dynamic EQ overlay color
+    pub dynamic_color: [f32; 4],
     /// Spectrum fill opacity
     pub fill_opacity: f32,
     /// Spectrum line width
@@ -65,6 +69,8 @@ impl Default for EqSpectrumConfig {
             show_grid: true,
             show_piano_roll: false,
             show_peak_hold: true,
+            show_dynamic_overlay: true,
+            dynamic_color: [0.4, 1.0, 0.6, 0.35], // Green
             fill_opacity: 0.3,
             line_width: 2.0,
         }
@@ -94,6 +100,12 @@ pub struct BandHandle {
     pub hovered: bool,
     /// Handle color
     pub color: [f32; 4],
+    /// Dynamic mode enabled
+    pub dynamic_enabled: bool,
+    /// Dynamic threshold (dB)
+    pub dynamic_threshold_db: f32,
+    /// Current dynamic gain offset fed by the engine (dB, negative = cut)
+    pub dynamic_offset_db: f32,
 }
 
 /// Collision zone (frequency masking)
@@ -120,6 +132,9 @@ pub struct EqSpectrumData {
     pub peaks: Vec<f32>,
     /// EQ curve (dB values)
     pub eq_curve: Vec<f32>,
+    /// Active (static + dynamic) EQ curve in dB, same layout as `eq_curve`.
+    /// Empty = derive from per-band `dynamic_offset_db`.
+    pub dynamic_curve: Vec<f32>,
     /// Band handles
     pub bands: Vec<BandHandle>,
     /// Collision zones
@@ -134,6 +149,7 @@ impl Default for EqSpectrumData {
             spectrum: vec![0.0; 256],
             peaks: vec![0.0; 256],
             eq_curve: vec![0.0; 256],
+            dynamic_curve: Vec::new(),
             bands: Vec::new(),
             collisions: Vec::new(),
             sample_rate: 48000.0,
@@ -141,6 +157,48 @@ impl Default for EqSpectrumData {
     }
 }
 
+impl EqSpectrumData {
+    /// Active EQ response (static curve + live dynamic movement).
+    ///
+    /// Uses `dynamic_curve` when the engine provides one, otherwise adds each
+    /// band's `dynamic_offset_db` to `eq_curve` with a bell-shaped weighting
+    /// around the band frequency.
+    pub fn active_curve(&self, config: &EqSpectrumConfig) -> Vec<f32> {
+        if self.dynamic_curve.len() == self.eq_curve.len() {
+            return self.dynamic_curve.clone();
+        }
+
+        let num_points = self.eq_curve.len();
+        let dynamic_bands: Vec<&BandHandle> = self
+            .bands
+            .iter()
+            .filter(|b| b.enabled && b.dynamic_enabled && b.dynamic_offset_db != 0.0)
+            .collect();
+
+        if dynamic_bands.is_empty() || num_points < 2 {
+            return self.eq_curve.clone();
+        }
+
+        self.eq_curve
+            .iter()
+            .enumerate()
+            .map(|(i, &db)| {
+                let t = i as f32 / (num_points - 1) as f32;
+                let freq = x_to_frequency(t, config.min_freq, config.max_freq);
+                let offset: f32 = dynamic_bands
+                    .iter()
+                    .map(|b| {
+                        let ratio = freq / b.frequency.max(1.0);
+                        let detune = b.q.max(0.1) * (ratio - 1.0 / ratio);
+                        b.dynamic_offset_db / (1.0 + detune * detune)
+                    })
+                    .sum();
+                db + offset
+            })
+            .collect()
+    }
+}
+
 // ============================================================================
 // VERTICES
 // ============================================================================
@@ -581,6 +639,138 @@ pub fn generate_collision_zones(
     (vertices, indices)
 }
 
+/// Generate dynamic EQ overlay (shaded region between static and active response)
+///
+/// Empty when the overlay is disabled or no band is currently moving.
+pub fn generate_dynamic_overlay(
+    data: &EqSpectrumData,
+    config: &EqSpectrumConfig,
+    width: f32,
+    height: f32,
+) -> (Vec<SpectrumVertex>, Vec<u32>) {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    let num_points = data.eq_curve.len();
+    if !config.show_dynamic_overlay || num_points < 2 {
+        return (vertices, indices);
+    }
+
+    let active = data.active_curve(config);
+    let moving = active
+        .iter()
+        .zip(&data.eq_curve)
+        .any(|(a, s)| (a - s).abs() > 0.01);
+    if !moving {
+        return (vertices, indices);
+    }
+
+    let db_range = config.max_db - config.min_db;
+    let mut edge_color = config.dynamic_color;
+    edge_color[3] = (edge_color[3] * 2.0).min(1.0);
+
+    for i in 0..num_points {
+        let t = i as f32 / (num_points - 1) as f32;
+        let x = t * width;
+
+        let static_db = data.eq_curve[i].clamp(config.min_db, config.max_db);
+        let active_db = active[i].clamp(config.min_db, config.max_db);
+        let static_y = height * (1.0 - (static_db - config.min_db) / db_range);
+        let active_y = height * (1.0 - (active_db - config.min_db) / db_range);
+
+        // Active edge is brighter, static edge fades into the fill
+        vertices.push(SpectrumVertex::new(x, active_y, t, 0.0, edge_color));
+        vertices.push(SpectrumVertex::new(
+            x,
+            static_y,
+            t,
+            1.0,
+            config.dynamic_color,
+        ));
+
+        if i > 0 {
+            let base = (i as u32 - 1) * 2;
+            indices.push(base);
+            indices.push(base + 1);
+            indices.push(base + 2);
+            indices.push(base + 1);
+            indices.push(base + 3);
+            indices.push(base + 2);
+        }
+    }
+
+    (vertices, indices)
+}
+
+/// Generate dynamic threshold markers (horizontal tick at each dynamic band)
+pub fn generate_threshold_markers(
+    data: &EqSpectrumData,
+    config: &EqSpectrumConfig,
+    width: f32,
+    height: f32,
+) -> (Vec<SpectrumVertex>, Vec<u32>) {
+    let mut vertices = Vec::new();
+    let mut indices = Vec::new();
+
+    if !config.show_dynamic_overlay {
+        return (vertices, indices);
+    }
+
+    let log_min = config.min_freq.log10();
+    let log_max = config.max_freq.log10();
+    let db_range = config.max_db - config.min_db;
+    let half_len = 12.0;
+    let half_thickness = 1.0;
+    let mut color = config.dynamic_color;
+    color[3] = 1.0;
+
+    for band in &data.bands {
+        if !band.enabled || !band.dynamic_enabled {
+            continue;
+        }
+
+        let x = width * (band.frequency.log10() - log_min) / (log_max - log_min);
+        let db = band
+            .dynamic_threshold_db
+            .clamp(config.min_db, config.max_db);
+        let y = height * (1.0 - (db - config.min_db) / db_range);
+
+        let base = vertices.len() as u32;
+        vertices.push(SpectrumVertex::new(
+            x - half_len,
+            y - half_thickness,
+            0.0,
+            0.0,
+            color,
+        ));
+        vertices.push(SpectrumVertex::new(
+            x + half_len,
+            y - half_thickness,
+            1.0,
+            0.0,
+            color,
+        ));
+        vertices.push(SpectrumVertex::new(
+            x + half_len,
+            y + half_thickness,
+            1.0,
+            1.0,
+            color,
+        ));
+        vertices.push(SpectrumVertex::new(
+            x - half_len,
+            y + half_thickness,
+            0.0,
+            1.0,
+            color,
+        ));
+
+        indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+
+    (vertices, indices)
+}
+
 // ============================================================================
 // FREQUENCY HELPERS
 // ============================================================================
@@ -659,6 +849,62 @@ mod tests {
         assert!(!indices.is_empty());
     }
 
+    fn dynamic_band(offset_db: f32) -> BandHandle {
+        BandHandle {
+            index: 0,
+            frequency: 1000.0,
+            gain_db: 0.0,
+            q: 1.0,
+            enabled: true,
+            selected: false,
+            hovered: false,
+            color: [1.0; 4],
+            dynamic_enabled: true,
+            dynamic_threshold_db: -24.0,
+            dynamic_offset_db: offset_db,
+        }
+    }
+
+    #[test]
+    fn test_dynamic_overlay_empty_without_movement() {
+        let data = EqSpectrumData {
+            bands: vec![dynamic_band(0.0)],
+            ..Default::default()
+        };
+        let config = EqSpectrumConfig::default();
+
+        let (vertices, indices) = generate_dynamic_overlay(&data, &config, 800.0, 400.0);
+        assert!(vertices.is_empty());
+        assert!(indices.is_empty());
+
+        // Threshold marker is still shown for an idle dynamic band
+        let (markers, _) = generate_threshold_markers(&data, &config, 800.0, 400.0);
+        assert_eq!(markers.len(), 4);
+    }
+
+    #[test]
+    fn test_dynamic_overlay_follows_gain_reduction() {
+        let data = EqSpectrumData {
+            bands: vec![dynamic_band(-6.0)],
+            ..Default::default()
+        };
+        let config = EqSpectrumConfig::default();
+
+        let active = data.active_curve(&config);
+        let center = (frequency_to_x(1000.0, config.min_freq, config.max_freq) * 255.0).round();
+        assert!((active[center as usize] + 6.0).abs() < 0.1);
+        assert!(active[0].abs() < 0.5);
+
+        let (vertices, indices) = generate_dynamic_overlay(&data, &config, 800.0, 400.0);
+        assert_eq!(vertices.len(), 256 * 2);
+        assert_eq!(indices.len(), 255 * 6);
+
+        // Cut: active edge sits below (larger y) the static edge
+        for pair in vertices.chunks(2) {
+            assert!(pair[0].position[1] >= pair[1].position[1] - 1e-3);
+        }
+    }
+
     #[test]
     fn test_grid_generation() {
         let config = EqSpectrumConfig::default();
@@ -20,6 +20,9 @@ pub const MAX_GPU_BANDS: usize = 64;
 /// Maximum audio buffer size for GPU processing
 pub const MAX_BUFFER_SIZE: usize = 65536;
 
+/// Maximum number of channels tracked by batch processing (filter state slots)
+pub const MAX_BATCH_CHANNELS: usize = 256;
+
 // ============================================================================
 // GPU Buffer Types (must match WGSL structs)
 // ============================================================================
@@ -353,6 +356,17 @@ impl GpuDynamicBand {
     }
 }
 
+/// Batch dispatch configuration (one workgroup per channel)
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable, Default)]
+pub struct GpuBatchConfig {
+    pub num_channels: u32,
+    pub frames_per_channel: u32,
+    pub num_filters: u32,
+    /// Index of the first channel in this dispatch (for filter state lookup)
+    pub channel_offset: u32,
+}
+
 // ============================================================================
 // GPU Filter Processor
 // ============================================================================
@@ -365,6 +379,7 @@ pub struct GpuFilterProcessor {
     biquad_pipeline: wgpu::ComputePipeline,
     saturation_pipeline: wgpu::ComputePipeline,
     stereo_pipeline: wgpu::ComputePipeline,
+    batch_pipeline: wgpu::ComputePipeline,
 
     // Buffers
     input_buffer: wgpu::Buffer,
@@ -381,10 +396,15 @@ pub struct GpuFilterProcessor {
     stereo_config_buffer: wgpu::Buffer,
     saturation_config_buffer: wgpu::Buffer,
 
+    // Batch buffers (per-channel filter states)
+    batch_states_buffer: wgpu::Buffer,
+    batch_config_buffer: wgpu::Buffer,
+
     // Bind groups
     biquad_bind_group: wgpu::BindGroup,
     saturation_bind_group: wgpu::BindGroup,
     stereo_bind_group: wgpu::BindGroup,
+    batch_bind_group: wgpu::BindGroup,
 
     // Staging buffers for CPU readback
     staging_buffer: wgpu::Buffer,
@@ -411,6 +431,13 @@ impl GpuFilterProcessor {
             source: wgpu::ShaderSource::Wgsl(shader_source.into()),
         });
 
+        // Batch kernel lives in its own module (group 0 only)
+        let batch_shader_source = include_str!("../../../shaders/gpu_filter_batch.wgsl");
+        let batch_shader = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("GPU Filter Batch Shader"),
+            source: wgpu::ShaderSource::Wgsl(batch_shader_source.into()),
+        });
+
         // Create buffers
         let input_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("GPU Filter Input"),
@@ -492,6 +519,22 @@ impl GpuFilterProcessor {
             mapped_at_creation: false,
         });
 
+        // Batch buffers
+        let batch_states_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("GPU Batch Filter States"),
+            size: (MAX_BATCH_CHANNELS * MAX_GPU_BANDS * std::mem::size_of::<GpuFilterState>())
+                as u64,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let batch_config_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("GPU Batch Config"),
+            size: std::mem::size_of::<GpuBatchConfig>() as u64,
+            usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         // Staging buffers
         let staging_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("GPU Filter Staging"),
@@ -581,7 +624,7 @@ impl GpuFilterProcessor {
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: Some("Stereo Bind Group Layout"),
                 entries: &[
-                    // Stereo config (binding 0 in group 5)
+                    // Stereo config (binding 0 in group 1)
                     wgpu::BindGroupLayoutEntry {
                         binding: 0,
                         visibility: wgpu::ShaderStages::COMPUTE,
@@ -650,6 +693,68 @@ impl GpuFilterProcessor {
                 ],
             });
 
+        let batch_bind_group_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("Batch Bind Group Layout"),
+                entries: &[
+                    // Packed input (channel-major)
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    // Packed output
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    // Filter params
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 2,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    // Per-channel filter states
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 3,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    // Batch config
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 4,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                ],
+            });
+
         // Create pipelines
         let biquad_pipeline_layout =
             device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
@@ -667,23 +772,24 @@ impl GpuFilterProcessor {
             cache: None,
         });
 
+        let stereo_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Stereo Pipeline Layout"),
+                bind_group_layouts: &[&biquad_bind_group_layout, &stereo_bind_group_layout],
+                immediate_size: 0,
+            });
+
+        // Saturation config lives in the stereo group (binding 5)
         let saturation_pipeline =
             device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
                 label: Some("Saturation Pipeline"),
-                layout: Some(&biquad_pipeline_layout),
+                layout: Some(&stereo_pipeline_layout),
                 module: &shader,
                 entry_point: Some("saturate"),
                 compilation_options: Default::default(),
                 cache: None,
             });
 
-        let stereo_pipeline_layout =
-            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
-                label: Some("Stereo Pipeline Layout"),
-                bind_group_layouts: &[&biquad_bind_group_layout, &stereo_bind_group_layout],
-                immediate_size: 0,
-            });
-
         let stereo_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
             label: Some("Stereo Process Pipeline"),
             layout: Some(&stereo_pipeline_layout),
@@ -693,6 +799,22 @@ impl GpuFilterProcessor {
             cache: None,
         });
 
+        let batch_pipeline_layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("Batch Pipeline Layout"),
+                bind_group_layouts: &[&batch_bind_group_layout],
+                immediate_size: 0,
+            });
+
+        let batch_pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("Biquad Batch Pipeline"),
+            layout: Some(&batch_pipeline_layout),
+            module: &batch_shader,
+            entry_point: Some("biquad_filter_batch"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
         // Create bind groups
         let biquad_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
             label: Some("Biquad Bind Group"),
@@ -779,11 +901,39 @@ impl GpuFilterProcessor {
             ],
         });
 
+        let batch_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("Batch Bind Group"),
+            layout: &batch_bind_group_layout,
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: input_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: output_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: filter_params_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 3,
+                    resource: batch_states_buffer.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 4,
+                    resource: batch_config_buffer.as_entire_binding(),
+                },
+            ],
+        });
+
         Ok(Self {
             ctx,
             biquad_pipeline,
             saturation_pipeline,
             stereo_pipeline,
+            batch_pipeline,
             input_buffer,
             output_buffer,
             filter_params_buffer,
@@ -795,9 +945,12 @@ impl GpuFilterProcessor {
             output_right_buffer,
             stereo_config_buffer,
             saturation_config_buffer,
+            batch_states_buffer,
+            batch_config_buffer,
             biquad_bind_group,
             saturation_bind_group,
             stereo_bind_group,
+            batch_bind_group,
             staging_buffer,
             staging_left_buffer,
             staging_right_buffer,
@@ -831,6 +984,13 @@ impl GpuFilterProcessor {
         self.ctx
             .queue
             .write_buffer(&self.filter_states_buffer, 0, bytemuck::cast_slice(&states));
+
+        let batch_states = vec![GpuFilterState::default(); MAX_BATCH_CHANNELS * MAX_GPU_BANDS];
+        self.ctx.queue.write_buffer(
+            &self.batch_states_buffer,
+            0,
+            bytemuck::cast_slice(&batch_states),
+        );
     }
 
     /// Process mono audio through filter chain
@@ -1070,6 +1230,7 @@ impl GpuFilterProcessor {
             });
             pass.set_pipeline(&self.saturation_pipeline);
             pass.set_bind_group(0, &self.saturation_bind_group, &[]);
+            pass.set_bind_group(1, &self.stereo_bind_group, &[]);
             pass.dispatch_workgroups((num_samples as u32).div_ceil(64), 1, 1);
         }
 
@@ -1107,6 +1268,141 @@ impl GpuFilterProcessor {
 
         Ok(result)
     }
+
+    /// Process many equal-length channels through the same filter chain.
+    ///
+    /// Channels are packed channel-major into one buffer and filtered with a
+    /// workgroup per channel, so a whole session runs in a single dispatch
+    /// (more only if the packed size exceeds the GPU buffer). Each channel
+    /// keeps its own filter state across calls, indexed by its position in
+    /// `channels`. Output is written back in place.
+    pub async fn process_batch(
+        &mut self,
+        channels: &mut [&mut [f32]],
+        params: &[GpuFilterParams],
+    ) -> VizResult<()> {
+        let Some(frames) = channels.first().map(|c| c.len()) else {
+            return Ok(());
+        };
+        if channels.iter().any(|c| c.len() != frames) {
+            return Err(VizError::Buffer(
+                "batch channels must have equal length".to_string(),
+            ));
+        }
+        if channels.len() > MAX_BATCH_CHANNELS {
+            return Err(VizError::Buffer(format!(
+                "batch of {} channels exceeds {MAX_BATCH_CHANNELS}",
+                channels.len()
+            )));
+        }
+        let capacity = self.max_samples.min(MAX_BUFFER_SIZE);
+        if frames == 0 {
+            return Ok(());
+        }
+        if frames > capacity {
+            return Err(VizError::Buffer(format!(
+                "batch channel length {frames} exceeds buffer size {capacity}"
+            )));
+        }
+
+        let num_filters = params.len().min(MAX_GPU_BANDS);
+        self.set_filters(params);
+
+        let channels_per_dispatch = capacity / frames;
+        let mut channel_offset = 0;
+
+        for group in channels.chunks_mut(channels_per_dispatch) {
+            let packed = pack_channels(group, frames);
+            let num_samples = packed.len();
+
+            self.ctx
+                .queue
+                .write_buffer(&self.input_buffer, 0, bytemuck::cast_slice(&packed));
+
+            let config = GpuBatchConfig {
+                num_channels: group.len() as u32,
+                frames_per_channel: frames as u32,
+                num_filters: num_filters as u32,
+                channel_offset: channel_offset as u32,
+            };
+            self.ctx
+                .queue
+                .write_buffer(&self.batch_config_buffer, 0, bytemuck::bytes_of(&config));
+
+            // Dispatch compute
+            let mut encoder =
+                self.ctx
+                    .device
+                    .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                        label: Some("GPU Batch Encoder"),
+                    });
+
+            {
+                let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                    label: Some("Biquad Batch Pass"),
+                    timestamp_writes: None,
+                });
+                pass.set_pipeline(&self.batch_pipeline);
+                pass.set_bind_group(0, &self.batch_bind_group, &[]);
+                pass.dispatch_workgroups(group.len() as u32, 1, 1);
+            }
+
+            // Copy output to staging
+            encoder.copy_buffer_to_buffer(
+                &self.output_buffer,
+                0,
+                &self.staging_buffer,
+                0,
+                (num_samples * std::mem::size_of::<f32>()) as u64,
+            );
+
+            self.ctx.queue.submit(std::iter::once(encoder.finish()));
+
+            // Read back result
+            let buffer_slice = self.staging_buffer.slice(..);
+            let (sender, receiver) = flume::bounded(1);
+            buffer_slice.map_async(wgpu::MapMode::Read, move |result| {
+                let _ = sender.send(result);
+            });
+
+            self.ctx.device.poll(wgpu::PollType::Wait { submission_index: None, timeout: None }).ok();
+
+            receiver
+                .recv_async()
+                .await
+                .map_err(|e| VizError::Render(e.to_string()))?
+                .map_err(|e| VizError::Render(e.to_string()))?;
+
+            let data = buffer_slice.get_mapped_range();
+            unpack_channels(
+                bytemuck::cast_slice(&data[..num_samples * std::mem::size_of::<f32>()]),
+                group,
+                frames,
+            );
+            drop(data);
+            self.staging_buffer.unmap();
+
+            channel_offset += group.len();
+        }
+
+        Ok(())
+    }
+}
+
+/// Pack equal-length channels into one channel-major buffer
+fn pack_channels(channels: &[&mut [f32]], frames: usize) -> Vec<f32> {
+    let mut packed = Vec::with_capacity(channels.len() * frames);
+    for channel in channels {
+        packed.extend_from_slice(&channel[..frames]);
+    }
+    packed
+}
+
+/// Scatter a channel-major buffer back into the channels
+fn unpack_channels(packed: &[f32], channels: &mut [&mut [f32]], frames: usize) {
+    for (channel, chunk) in channels.iter_mut().zip(packed.chunks_exact(frames)) {
+        channel[..frames].copy_from_slice(chunk);
+    }
 }
 
 // ============================================================================
@@ -1185,3 +1481,133 @@ impl GpuEqBuilder {
         self.filters.is_empty()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// |H(e^jw)| of a biquad, evaluated from its transfer function
+    fn magnitude(p: &GpuFilterParams, freq: f64, sample_rate: f64) -> f64 {
+        let w = 2.0 * std::f64::consts::PI * freq / sample_rate;
+        let (c1, s1, c2, s2) = (w.cos(), w.sin(), (2.0 * w).cos(), (2.0 * w).sin());
+        let (b0, b1, b2) = (p.b0 as f64, p.b1 as f64, p.b2 as f64);
+        let (a1, a2) = (p.a1 as f64, p.a2 as f64);
+        let num = (b0 + b1 * c1 + b2 * c2).hypot(b1 * s1 + b2 * s2);
+        let den = (1.0 + a1 * c1 + a2 * c2).hypot(a1 * s1 + a2 * s2);
+        num / den
+    }
+
+    fn assert_db(p: &GpuFilterParams, freq: f64, expected_db: f64, tolerance_db: f64) {
+        let db = 20.0 * magnitude(p, freq, 48000.0).log10();
+        assert!(
+            (db - expected_db).abs() < tolerance_db,
+            "{freq} Hz: {db:.3} dB, expected {expected_db} dB"
+        );
+    }
+
+    #[test]
+    fn test_eq_builder_analytic_response() {
+        let mut eq = GpuEqBuilder::new(48000.0);
+        eq.add_highpass(80.0, 0.707)
+            .add_peak(1000.0, 6.0, 1.0)
+            .add_high_shelf(8000.0, -3.0, 0.707);
+        let params = eq.build();
+        let (highpass, peak, shelf) = (&params[0], &params[1], &params[2]);
+
+        // Butterworth highpass: -3 dB at the cutoff, 12 dB/octave below it
+        assert_db(highpass, 80.0, -3.01, 0.05);
+        assert_db(highpass, 20.0, -24.1, 0.5);
+        assert_db(highpass, 10000.0, 0.0, 0.01);
+
+        // Peak: full gain at the centre, flat far away
+        assert_db(peak, 1000.0, 6.0, 0.01);
+        assert_db(peak, 20.0, 0.0, 0.05);
+        assert_db(peak, 20000.0, 0.0, 0.1);
+
+        // High shelf: half gain at the corner, full gain above, flat below
+        assert_db(shelf, 8000.0, -1.5, 0.05);
+        assert_db(shelf, 23000.0, -3.0, 0.05);
+        assert_db(shelf, 100.0, 0.0, 0.01);
+    }
+
+    #[test]
+    fn test_batch_matches_per_channel() {
+        let Ok(ctx) = GpuContext::new_blocking() else {
+            eprintln!("no GPU adapter, skipping batch parity test");
+            return;
+        };
+        // 4 channels per dispatch: 8 channels take two dispatches
+        let frames = 256;
+        let mut gpu = GpuFilterProcessor::new_blocking(Arc::new(ctx), 4 * frames, 48000.0).unwrap();
+
+        let mut eq = GpuEqBuilder::new(48000.0);
+        eq.add_highpass(80.0, 0.707)
+            .add_peak(1000.0, 6.0, 1.0)
+            .add_high_shelf(8000.0, -3.0, 0.707);
+        let params = eq.build();
+
+        // Two consecutive blocks per channel, so filter state carries over
+        let blocks: Vec<Vec<Vec<f32>>> = (0..2)
+            .map(|block| {
+                (0..8)
+                    .map(|ch| {
+                        (0..frames)
+                            .map(|i| (((block * frames + i) * (ch + 3)) as f32 * 0.05).sin() * 0.5)
+                            .collect()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Per channel: one channel at a time through both blocks
+        let mut expected = blocks.clone();
+        for ch in 0..8 {
+            gpu.reset_states();
+            for block in expected.iter_mut() {
+                pollster::block_on(gpu.process_batch(&mut [&mut block[ch][..]], &params)).unwrap();
+            }
+        }
+
+        // Batched: all channels per block
+        let mut batched = blocks.clone();
+        gpu.reset_states();
+        for block in batched.iter_mut() {
+            let mut channels: Vec<&mut [f32]> =
+                block.iter_mut().map(|c| c.as_mut_slice()).collect();
+            pollster::block_on(gpu.process_batch(&mut channels, &params)).unwrap();
+        }
+
+        assert_ne!(batched, blocks, "filters left the audio untouched");
+        for (block, (got, want)) in batched.iter().zip(&expected).enumerate() {
+            for (ch, (a, b)) in got.iter().zip(want).enumerate() {
+                let max_diff = a
+                    .iter()
+                    .zip(b)
+                    .map(|(x, y)| (x - y).abs())
+                    .fold(0.0, f32::max);
+                assert!(
+                    max_diff < 1e-6,
+                    "block {block} channel {ch}: diff {max_diff}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_pack_unpack_round_trip() {
+        let frames = 4;
+        let mut buffers: Vec<Vec<f32>> = (0..3)
+            .map(|ch| (0..frames).map(|i| (ch * 10 + i) as f32).collect())
+            .collect();
+        let expected = buffers.clone();
+        let mut channels: Vec<&mut [f32]> = buffers.iter_mut().map(|b| b.as_mut_slice()).collect();
+
+        // Channel-major: all of channel 0, then channel 1, ...
+        let packed = pack_channels(&channels, frames);
+        assert_eq!(&packed[frames..2 * frames], &[10.0, 11.0, 12.0, 13.0]);
+
+        channels.iter_mut().for_each(|c| c.fill(0.0));
+        unpack_channels(&packed, &mut channels, frames);
+        assert_eq!(buffers, expected);
+    }
+}
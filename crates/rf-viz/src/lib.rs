@@ -25,12 +25,13 @@ pub use common::{GpuContext, VizError, VizResult};
 pub use eq_spectrum::{
     BandHandle, CollisionZone, EqSpectrumConfig, EqSpectrumData, SpectrumVertex, db_to_y,
     frequency_to_x, generate_band_handles, generate_collision_zones, generate_curve_mesh,
-    generate_grid, generate_piano_roll, generate_spectrum_mesh, x_to_frequency, y_to_db,
+    generate_dynamic_overlay, generate_grid, generate_piano_roll, generate_spectrum_mesh,
+    generate_threshold_markers, x_to_frequency, y_to_db,
 };
 pub use gpu_filter::{
-    GpuDynamicBand, GpuEqBuilder, GpuFilterParams, GpuFilterProcessor, GpuFilterState,
-    GpuProcessConfig, GpuSaturationConfig, GpuStereoConfig, MAX_BUFFER_SIZE, MAX_GPU_BANDS,
-    SaturationMode,
+    GpuBatchConfig, GpuDynamicBand, GpuEqBuilder, GpuFilterParams, GpuFilterProcessor,
+    GpuFilterState, GpuProcessConfig, GpuSaturationConfig, GpuStereoConfig, MAX_BATCH_CHANNELS,
+    MAX_BUFFER_SIZE, MAX_GPU_BANDS, SaturationMode,
 };
 pub use plugin_browser::{
     BrowserLayout, BrowserVertex, BrowserViewMode, PluginBrowserConfig, PluginBrowserItem,
@@ -38,8 +39,8 @@ pub use plugin_browser::{
     format_color, status_color,
 };
 pub use plugin_chain::{
-    ChainLayout, ChainSlotState, ChainVertex, PluginChainConfig, PluginChainState, cpu_color,
-    latency_color, slot_color,
+    ChainIntent, ChainLayout, ChainSlotState, ChainVertex, PluginChainConfig, PluginChainState,
+    SlotStats, cpu_color, latency_color, slot_color,
 };
 pub use spectrogram::{
     ColorMap, DisplayMode, FrequencyScale, SpectrogramConfig, SpectrogramData, SpectrogramFrame,
@@ -5,6 +5,7 @@
 //! - Bypass/Solo controls
 //! - Wet/Dry mix knobs
 //! - Latency indicators
+//! - Live CPU/latency load per slot
 //! - PDC visualization
 
 use serde::{Deserialize, Serialize};
@@ -66,8 +67,9 @@ pub struct ChainSlotState {
     pub latency_samples: u32,
     /// PDC compensation in samples
     pub pdc_compensation: u32,
-    /// CPU usage percentage
-    pub cpu_usage: f32,
+    /// CPU usage percentage of the block budget
+    #[serde(alias = "cpu_usage")]
+    pub cpu_percent: f32,
     /// Is editor open
     pub editor_open: bool,
     /// Has pending parameter changes
@@ -87,11 +89,53 @@ impl ChainSlotState {
             mix: 1.0,
             latency_samples: 0,
             pdc_compensation: 0,
-            cpu_usage: 0.0,
+            cpu_percent: 0.0,
             editor_open: false,
             has_changes: false,
         }
     }
+
+    /// Update live load from engine stats
+    ///
+    /// `block_time_us` is the real-time budget of one audio block
+    /// (`block_size / sample_rate * 1e6`).
+    pub fn apply_stats(&mut self, stats: &SlotStats, block_time_us: f64) {
+        self.cpu_percent = if block_time_us > 0.0 {
+            (stats.avg_time_us as f64 / block_time_us * 100.0) as f32
+        } else {
+            0.0
+        };
+        self.latency_samples = stats.latency_samples;
+    }
+
+    /// CPU meter color for the current load
+    pub fn cpu_color(&self) -> [f32; 4] {
+        cpu_color(self.cpu_percent)
+    }
+
+    /// Latency indicator color for the current latency
+    pub fn latency_color(&self, sample_rate: u32) -> [f32; 4] {
+        latency_color(self.latency_samples, sample_rate)
+    }
+}
+
+/// Live per-slot processing stats
+///
+/// Snapshot of the engine's `NodeStats` for one insert: `avg_time_us` is
+/// `NodeStats::average_us()`, `latency_samples` the plugin's reported latency.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct SlotStats {
+    /// Average processing time per block (microseconds)
+    pub avg_time_us: u64,
+    /// Reported latency in samples
+    pub latency_samples: u32,
+}
+
+/// Intent emitted by chain interactions, handled by the engine
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChainIntent {
+    /// Move slot `from` to insertion point `to` (see `PluginChainState::move_slot`)
+    Reorder { from: usize, to: usize },
 }
 
 /// Plugin chain UI state
@@ -194,10 +238,54 @@ impl PluginChainState {
             .slots
             .iter()
             .filter(|s| !s.bypassed)
-            .map(|s| s.cpu_usage)
+            .map(|s| s.cpu_percent)
             .sum();
     }
 
+    /// Feed live stats, one entry per slot in chain order
+    pub fn apply_stats(&mut self, stats: &[SlotStats], block_time_us: f64) {
+        for (slot, stats) in self.slots.iter_mut().zip(stats) {
+            slot.apply_stats(stats, block_time_us);
+        }
+        self.recalculate_totals();
+    }
+
+    /// Start dragging a slot
+    pub fn begin_drag(&mut self, index: usize) {
+        if index < self.slots.len() {
+            self.dragging = Some(index);
+            self.drop_target = None;
+        }
+    }
+
+    /// Update the drop target while dragging
+    pub fn update_drag(&mut self, drop_target: Option<usize>) {
+        if self.dragging.is_some() {
+            self.drop_target = drop_target;
+        }
+    }
+
+    /// Cancel the current drag without reordering
+    pub fn cancel_drag(&mut self) {
+        self.dragging = None;
+        self.drop_target = None;
+    }
+
+    /// Finish the drag, returning a reorder intent if the slot would move
+    ///
+    /// The chain itself is not changed; apply the intent with `move_slot`
+    /// once the engine has accepted it.
+    pub fn end_drag(&mut self) -> Option<ChainIntent> {
+        let from = self.dragging.take()?;
+        let to = self.drop_target.take()?;
+
+        // Dropping right before or after itself is a no-op
+        if to == from || to == from + 1 || to > self.slots.len() {
+            return None;
+        }
+        Some(ChainIntent::Reorder { from, to })
+    }
+
     /// Check if any slot is soloed
     pub fn has_solo(&self) -> bool {
         self.slots.iter().any(|s| s.soloed)
@@ -616,4 +704,49 @@ mod tests {
         state.toggle_bypass(0);
         assert!(state.slots[0].bypassed);
     }
+
+    #[test]
+    fn test_drag_emits_reorder() {
+        let mut state = PluginChainState::new();
+        for i in 0..3 {
+            state.add_slot(ChainSlotState::new(i, "p", "P"));
+        }
+
+        state.begin_drag(0);
+        state.update_drag(Some(1));
+        assert_eq!(state.end_drag(), None);
+
+        state.begin_drag(0);
+        state.update_drag(Some(3));
+        let intent = state.end_drag();
+        assert_eq!(intent, Some(ChainIntent::Reorder { from: 0, to: 3 }));
+        assert!(state.dragging.is_none() && state.drop_target.is_none());
+    }
+
+    #[test]
+    fn test_apply_stats() {
+        let mut state = PluginChainState::new();
+        state.add_slot(ChainSlotState::new(0, "a", "A"));
+        state.add_slot(ChainSlotState::new(1, "b", "B"));
+
+        // 512 samples at 48 kHz
+        let block_time_us = 512.0 / 48000.0 * 1e6;
+        let stats = [
+            SlotStats {
+                avg_time_us: 533,
+                latency_samples: 0,
+            },
+            SlotStats {
+                avg_time_us: 8533,
+                latency_samples: 2048,
+            },
+        ];
+        state.apply_stats(&stats, block_time_us);
+
+        assert!((state.slots[0].cpu_percent - 5.0).abs() < 0.1);
+        assert!((state.slots[1].cpu_percent - 80.0).abs() < 0.1);
+        assert_eq!(state.total_latency, 2048);
+        assert_eq!(state.slots[0].cpu_color(), cpu_color(5.0));
+        assert_eq!(state.slots[1].latency_color(48000), [1.0, 0.25, 0.38, 1.0]);
+    }
 }
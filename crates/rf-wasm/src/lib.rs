@@ -5,10 +5,16 @@
 // ============================================================================
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::rc::Rc;
 use std::sync::atomic::{AtomicU32, Ordering};
 use wasm_bindgen::prelude::*;
-use web_sys::{AudioContext, GainNode};
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{
+    AudioBuffer, AudioBufferSourceNode, AudioContext, AudioScheduledSourceNode, GainNode, Response,
+};
 
 // ============================================================================
 // INITIALIZATION
@@ -66,6 +72,18 @@ pub enum PlaybackState {
     FadingOut = 3,
 }
 
+/// Why a voice finished (passed to the voice-complete callback)
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VoiceEndReason {
+    /// All source nodes played to the end
+    Completed = 0,
+    /// Stopped by `stop_voice`/`stop_event`/`stop_all`/`dispose`
+    Stopped = 1,
+    /// Taken by voice stealing
+    Stolen = 2,
+}
+
 /// Audio event layer
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct AudioLayer {
@@ -97,6 +115,41 @@ struct VoiceInstance {
     state: PlaybackState,
     volume: f32,
     priority: u8,
+    /// One source per layer with a decoded buffer
+    sources: Vec<AudioBufferSourceNode>,
+    /// Per-layer gain (volume × layer volume), ramped down on stop
+    gains: Vec<GainNode>,
+}
+
+impl VoiceInstance {
+    /// Fade out and stop the voice's source nodes; their `onended` reports `reason`.
+    /// A voice without sources completes immediately (returned to the caller).
+    fn stop_nodes(
+        &self,
+        now: f64,
+        fade_ms: u32,
+        reason: VoiceEndReason,
+        lifecycle: &SharedLifecycle,
+    ) -> Option<Completion> {
+        if self.sources.is_empty() {
+            return lifecycle.borrow_mut().complete(self.id, Some(reason));
+        }
+        if let Some(live) = lifecycle.borrow_mut().live.get_mut(&self.id) {
+            live.reason = reason;
+        }
+
+        let end = now + fade_ms as f64 / 1000.0;
+        for gain in &self.gains {
+            let param = gain.gain();
+            let _ = param.cancel_scheduled_values(now);
+            let _ = param.set_value_at_time(param.value(), now);
+            let _ = param.linear_ramp_to_value_at_time(0.0, end);
+        }
+        for source in &self.sources {
+            let _ = AudioScheduledSourceNode::stop_with_when(source, end);
+        }
+        None
+    }
 }
 
 /// RTPC definition
@@ -116,6 +169,128 @@ pub struct StateGroupDef {
     pub default_state: String,
 }
 
+/// Engine configuration snapshot (event definitions are loaded separately)
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EngineState {
+    pub bus_volumes: BTreeMap<u8, f32>,
+    pub bus_mutes: BTreeMap<u8, bool>,
+    pub rtpc_defs: Vec<RtpcDef>,
+    pub rtpc_values: BTreeMap<String, f32>,
+    pub states: BTreeMap<String, String>,
+    pub max_voices: u32,
+    pub max_voices_per_event: u32,
+    pub steal_mode: VoiceStealMode,
+}
+
+/// Audio file that failed to preload
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PreloadFailure {
+    pub audio_path: String,
+    pub error: String,
+}
+
+/// Result of `preload_all_audio`
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct PreloadReport {
+    /// Unique paths requested (already-cached paths included)
+    pub total: u32,
+    /// Paths decoded and cached
+    pub loaded: u32,
+    pub failed: Vec<PreloadFailure>,
+}
+
+/// Decoded audio, keyed by `audio_path`
+type AudioCache = Rc<RefCell<HashMap<String, AudioBuffer>>>;
+
+// ============================================================================
+// VOICE LIFECYCLE
+// ============================================================================
+
+/// Voice callbacks and completion bookkeeping, shared with `onended` handlers
+type SharedLifecycle = Rc<RefCell<VoiceLifecycle>>;
+
+/// Voice that has not completed yet
+#[derive(Clone, Debug)]
+struct LiveVoice {
+    event_id: String,
+    /// Source nodes that have not fired `onended`
+    pending_sources: u32,
+    /// Reported on completion (set by stop/steal before the nodes end)
+    reason: VoiceEndReason,
+}
+
+#[derive(Default)]
+struct VoiceLifecycle {
+    on_start: Option<js_sys::Function>,
+    on_complete: Option<js_sys::Function>,
+    live: HashMap<u32, LiveVoice>,
+    /// Completed voice ids, reaped (and recycled) by `cleanup_voices`
+    ended: Vec<u32>,
+}
+
+impl VoiceLifecycle {
+    fn start(&mut self, voice_id: u32, event_id: &str, sources: u32) {
+        self.live.insert(
+            voice_id,
+            LiveVoice {
+                event_id: event_id.to_string(),
+                pending_sources: sources,
+                reason: VoiceEndReason::Completed,
+            },
+        );
+    }
+
+    /// Count one finished source node; the voice completes with its last one
+    fn source_ended(&mut self, voice_id: u32) -> Option<Completion> {
+        let live = self.live.get_mut(&voice_id)?;
+        live.pending_sources = live.pending_sources.saturating_sub(1);
+        if live.pending_sources > 0 {
+            return None;
+        }
+        self.complete(voice_id, None)
+    }
+
+    /// Retire a live voice (once only); `reason` overrides the stored one
+    fn complete(&mut self, voice_id: u32, reason: Option<VoiceEndReason>) -> Option<Completion> {
+        let live = self.live.remove(&voice_id)?;
+        self.ended.push(voice_id);
+        Some(Completion {
+            callback: self.on_complete.clone(),
+            voice_id,
+            event_id: live.event_id,
+            reason: reason.unwrap_or(live.reason),
+        })
+    }
+}
+
+/// Voice-complete notification, delivered after the lifecycle borrow is released
+struct Completion {
+    callback: Option<js_sys::Function>,
+    voice_id: u32,
+    event_id: String,
+    reason: VoiceEndReason,
+}
+
+impl Completion {
+    /// Call `callback(voice_id, event_id, reason)`
+    fn notify(self) {
+        if let Some(callback) = self.callback {
+            let _ = callback.call3(
+                &JsValue::NULL,
+                &self.voice_id.into(),
+                &JsValue::from_str(&self.event_id),
+                &(self.reason as u32).into(),
+            );
+        }
+    }
+
+    /// Notify from a microtask, so a callback raised inside an engine call
+    /// can call back into the engine (e.g. play the next event)
+    fn notify_deferred(self) {
+        wasm_bindgen_futures::spawn_local(async move { self.notify() });
+    }
+}
+
 // ============================================================================
 // VOICE HANDLE (JS-visible)
 // ============================================================================
@@ -157,6 +332,8 @@ pub struct FluxForgeAudio {
     master_gain: Option<GainNode>,
     bus_gains: HashMap<u8, GainNode>,
     events: HashMap<String, AudioEvent>,
+    audio_buffers: AudioCache,
+    lifecycle: SharedLifecycle,
     stage_map: HashMap<String, String>,
     voices: Vec<VoiceInstance>,
     free_voice_ids: Vec<u32>,
@@ -182,6 +359,8 @@ impl FluxForgeAudio {
             master_gain: None,
             bus_gains: HashMap::new(),
             events: HashMap::new(),
+            audio_buffers: AudioCache::default(),
+            lifecycle: SharedLifecycle::default(),
             stage_map: HashMap::new(),
             voices: Vec::with_capacity(32),
             free_voice_ids: Vec::with_capacity(32),
@@ -294,6 +473,90 @@ impl FluxForgeAudio {
         Ok(count)
     }
 
+    // ════════════════════════════════════════════════════════════════════════
+    // AUDIO LOADING
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Fetch and decode every audio file referenced by loaded events
+    ///
+    /// `progress` is called as `progress(loaded, total)` after each file.
+    /// Paths shared by several events are fetched once; already-cached paths
+    /// count as loaded. A failing file is recorded in the report instead of
+    /// rejecting, so the promise resolves with a `PreloadReport` and only
+    /// rejects if the audio context is not initialized.
+    #[wasm_bindgen]
+    pub fn preload_all_audio(&self, progress: js_sys::Function) -> js_sys::Promise {
+        let context = self.context.clone();
+        let cache = Rc::clone(&self.audio_buffers);
+        let paths = self.unique_audio_paths();
+
+        wasm_bindgen_futures::future_to_promise(async move {
+            let context =
+                context.ok_or_else(|| JsValue::from_str("Audio context not initialized"))?;
+            let window = web_sys::window().ok_or_else(|| JsValue::from_str("No window"))?;
+
+            let mut report = PreloadReport {
+                total: paths.len() as u32,
+                ..Default::default()
+            };
+            let mut done = 0u32;
+
+            for path in paths {
+                if !cache.borrow().contains_key(&path) {
+                    match fetch_and_decode(&window, &context, &path).await {
+                        Ok(buffer) => {
+                            cache.borrow_mut().insert(path, buffer);
+                        }
+                        Err(e) => {
+                            let error = e.as_string().unwrap_or_else(|| format!("{:?}", e));
+                            log::warn!("[FluxForge WASM] Failed to load {}: {}", path, error);
+                            report.failed.push(PreloadFailure {
+                                audio_path: path,
+                                error,
+                            });
+                        }
+                    }
+                }
+
+                done += 1;
+                let _ = progress.call2(&JsValue::NULL, &done.into(), &report.total.into());
+            }
+
+            report.loaded = report.total - report.failed.len() as u32;
+            log::info!(
+                "[FluxForge WASM] Preloaded {}/{} audio files",
+                report.loaded,
+                report.total
+            );
+            serde_wasm_bindgen::to_value(&report).map_err(JsValue::from)
+        })
+    }
+
+    /// Check if an audio file is decoded and cached
+    #[wasm_bindgen]
+    pub fn is_audio_loaded(&self, audio_path: &str) -> bool {
+        self.audio_buffers.borrow().contains_key(audio_path)
+    }
+
+    /// Get number of cached audio buffers
+    #[wasm_bindgen]
+    pub fn get_loaded_audio_count(&self) -> u32 {
+        self.audio_buffers.borrow().len() as u32
+    }
+
+    /// Sorted, deduplicated `audio_path`s of all loaded event layers
+    fn unique_audio_paths(&self) -> Vec<String> {
+        self.events
+            .values()
+            .flat_map(|event| &event.layers)
+            .map(|layer| layer.audio_path.as_str())
+            .filter(|path| !path.is_empty())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .map(str::to_string)
+            .collect()
+    }
+
     // ════════════════════════════════════════════════════════════════════════
     // EVENT PLAYBACK
     // ════════════════════════════════════════════════════════════════════════
@@ -315,6 +578,49 @@ impl FluxForgeAudio {
         let context = self.context.as_ref()?;
         let now = context.current_time();
 
+        // Start one source per layer whose audio is decoded (see preload_all_audio)
+        let mut sources = Vec::with_capacity(event.layers.len());
+        let mut gains = Vec::with_capacity(event.layers.len());
+        for layer in &event.layers {
+            let Some(buffer) = self.audio_buffers.borrow().get(&layer.audio_path).cloned() else {
+                continue;
+            };
+            match self.start_layer(voice_id, layer, &buffer, volume, now) {
+                Ok((source, gain)) => {
+                    sources.push(source);
+                    gains.push(gain);
+                }
+                Err(e) => log::warn!(
+                    "[FluxForge WASM] Failed to start {}: {:?}",
+                    layer.audio_path,
+                    e
+                ),
+            }
+        }
+
+        let on_start = {
+            let mut lifecycle = self.lifecycle.borrow_mut();
+            lifecycle.start(voice_id, event_id, sources.len() as u32);
+            lifecycle.on_start.clone()
+        };
+        if let Some(callback) = on_start {
+            let event_id = event_id.to_string();
+            wasm_bindgen_futures::spawn_local(async move {
+                let _ = callback.call2(
+                    &JsValue::NULL,
+                    &voice_id.into(),
+                    &JsValue::from_str(&event_id),
+                );
+            });
+        }
+
+        // Nothing to play: complete now so sequencing does not stall
+        if sources.is_empty() {
+            if let Some(completion) = self.lifecycle.borrow_mut().complete(voice_id, None) {
+                completion.notify_deferred();
+            }
+        }
+
         self.voices.push(VoiceInstance {
             id: voice_id,
             event_id: event_id.to_string(),
@@ -322,10 +628,10 @@ impl FluxForgeAudio {
             state: PlaybackState::Playing,
             volume,
             priority: event.priority,
+            sources,
+            gains,
         });
 
-        // Note: Actual audio playback would use AudioBufferSourceNode
-        // This requires loading audio files which is handled separately
         log::debug!(
             "[FluxForge WASM] Playing event: {} (voice {})",
             event_id,
@@ -355,33 +661,85 @@ impl FluxForgeAudio {
 
     /// Stop an event
     #[wasm_bindgen]
-    pub fn stop_event(&mut self, event_id: &str, _fade_time_ms: u32) {
-        for voice in &mut self.voices {
-            if voice.event_id == event_id && voice.state == PlaybackState::Playing {
-                voice.state = PlaybackState::FadingOut;
-                // Note: Actual fade would be applied to GainNode
-            }
-        }
+    pub fn stop_event(&mut self, event_id: &str, fade_time_ms: u32) {
+        self.fade_out_voices(|v| v.event_id == event_id, fade_time_ms);
     }
 
     /// Stop a specific voice
     #[wasm_bindgen]
-    pub fn stop_voice(&mut self, voice_id: u32, _fade_time_ms: u32) {
-        if let Some(voice) = self.voices.iter_mut().find(|v| v.id == voice_id) {
-            voice.state = PlaybackState::FadingOut;
-        }
+    pub fn stop_voice(&mut self, voice_id: u32, fade_time_ms: u32) {
+        self.fade_out_voices(|v| v.id == voice_id, fade_time_ms);
     }
 
     /// Stop all sounds
     #[wasm_bindgen]
-    pub fn stop_all(&mut self, _fade_time_ms: u32) {
+    pub fn stop_all(&mut self, fade_time_ms: u32) {
+        self.fade_out_voices(|_| true, fade_time_ms);
+    }
+
+    /// Fade out matching playing voices; completion reports `Stopped`
+    fn fade_out_voices(&mut self, matches: impl Fn(&VoiceInstance) -> bool, fade_ms: u32) {
+        let now = self.get_current_time();
         for voice in &mut self.voices {
-            if voice.state == PlaybackState::Playing {
+            if voice.state == PlaybackState::Playing && matches(voice) {
                 voice.state = PlaybackState::FadingOut;
+                if let Some(completion) =
+                    voice.stop_nodes(now, fade_ms, VoiceEndReason::Stopped, &self.lifecycle)
+                {
+                    completion.notify_deferred();
+                }
             }
         }
     }
 
+    /// Create source → gain → panner → bus for one layer and schedule it
+    fn start_layer(
+        &self,
+        voice_id: u32,
+        layer: &AudioLayer,
+        buffer: &AudioBuffer,
+        volume: f32,
+        now: f64,
+    ) -> Result<(AudioBufferSourceNode, GainNode), JsValue> {
+        let context = self
+            .context
+            .as_ref()
+            .ok_or_else(|| JsValue::from_str("Audio context not initialized"))?;
+        let bus_gain = self
+            .bus_gains
+            .get(&(layer.bus as u8))
+            .ok_or_else(|| JsValue::from_str("Bus not initialized"))?;
+
+        let source = context.create_buffer_source()?;
+        source.set_buffer(Some(buffer));
+        source.set_loop(layer.loop_enabled);
+
+        let gain = context.create_gain()?;
+        gain.gain().set_value(volume * layer.volume);
+        let panner = context.create_stereo_panner()?;
+        panner.pan().set_value(layer.pan.clamp(-1.0, 1.0));
+
+        source.connect_with_audio_node(&gain)?;
+        gain.connect_with_audio_node(&panner)?;
+        panner.connect_with_audio_node(bus_gain)?;
+
+        // Fires on natural end and after stop(); the last layer completes the voice
+        let lifecycle = Rc::clone(&self.lifecycle);
+        let onended = Closure::once_into_js(move || {
+            let completion = lifecycle.borrow_mut().source_ended(voice_id);
+            if let Some(completion) = completion {
+                completion.notify();
+            }
+        });
+        AudioScheduledSourceNode::set_onended(&source, Some(onended.unchecked_ref()));
+
+        source.start_with_when_and_grain_offset(
+            now + layer.delay_ms as f64 / 1000.0,
+            layer.offset_ms as f64 / 1000.0,
+        )?;
+        Ok((source, gain))
+    }
+
     // ════════════════════════════════════════════════════════════════════════
     // BUS CONTROL
     // ════════════════════════════════════════════════════════════════════════
@@ -484,11 +842,34 @@ impl FluxForgeAudio {
         self.state_groups.get(group).cloned()
     }
 
+    // ════════════════════════════════════════════════════════════════════════
+    // VOICE CALLBACKS
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Register `callback(voice_id, event_id)`, called when a voice starts
+    /// (pass `undefined` to clear)
+    #[wasm_bindgen]
+    pub fn set_on_voice_start(&mut self, callback: Option<js_sys::Function>) {
+        self.lifecycle.borrow_mut().on_start = callback;
+    }
+
+    /// Register `callback(voice_id, event_id, reason)`, called once per voice
+    /// when its last source ends. `reason` is a `VoiceEndReason`: stopped and
+    /// stolen voices report when their fade finishes; a voice with no decoded
+    /// audio completes right after it starts. Pass `undefined` to clear.
+    #[wasm_bindgen]
+    pub fn set_on_voice_complete(&mut self, callback: Option<js_sys::Function>) {
+        self.lifecycle.borrow_mut().on_complete = callback;
+    }
+
     // ════════════════════════════════════════════════════════════════════════
     // VOICE MANAGEMENT
     // ════════════════════════════════════════════════════════════════════════
 
     fn acquire_voice(&mut self, event_id: &str, _priority: u8) -> Option<u32> {
+        // Finished voices must not count towards the limits
+        self.cleanup_voices();
+
         // Count voices for this event
         let event_voice_count = self
             .voices
@@ -526,8 +907,7 @@ impl FluxForgeAudio {
             .min_by(|(_, a), (_, b)| a.start_time.partial_cmp(&b.start_time).unwrap_or(std::cmp::Ordering::Equal))
             .map(|(i, _)| i)
         {
-            let voice = self.voices.remove(idx);
-            self.free_voice_ids.push(voice.id);
+            self.steal_voice(idx);
         }
     }
 
@@ -558,26 +938,29 @@ impl FluxForgeAudio {
         };
 
         if let Some(idx) = idx {
-            let voice = self.voices.remove(idx);
-            self.free_voice_ids.push(voice.id);
+            self.steal_voice(idx);
+        }
+    }
+
+    /// Cut a voice immediately; its id is recycled once its nodes have ended
+    fn steal_voice(&mut self, idx: usize) {
+        let voice = self.voices.remove(idx);
+        let now = self.get_current_time();
+        if let Some(completion) = voice.stop_nodes(now, 0, VoiceEndReason::Stolen, &self.lifecycle)
+        {
+            completion.notify_deferred();
         }
     }
 
     /// Cleanup finished voices (call periodically)
+    ///
+    /// Removes voices whose sources have all ended and recycles their ids.
     #[wasm_bindgen]
     pub fn cleanup_voices(&mut self) {
-        let mut to_remove = Vec::new();
-
-        for (idx, voice) in self.voices.iter().enumerate() {
-            if voice.state == PlaybackState::Stopped || voice.state == PlaybackState::FadingOut {
-                to_remove.push(idx);
-            }
-        }
-
-        // Remove in reverse order to maintain indices
-        for idx in to_remove.into_iter().rev() {
-            let voice = self.voices.remove(idx);
-            self.free_voice_ids.push(voice.id);
+        let ended = std::mem::take(&mut self.lifecycle.borrow_mut().ended);
+        for id in ended {
+            self.voices.retain(|v| v.id != id);
+            self.free_voice_ids.push(id);
         }
     }
 
@@ -652,12 +1035,113 @@ impl FluxForgeAudio {
         self.steal_mode = mode;
     }
 
+    // ════════════════════════════════════════════════════════════════════════
+    // STATE PERSISTENCE
+    // ════════════════════════════════════════════════════════════════════════
+
+    /// Snapshot bus, RTPC, state and voice configuration as JSON
+    #[wasm_bindgen]
+    pub fn export_state(&self) -> String {
+        let mut rtpc_defs: Vec<RtpcDef> = self.rtpc_defs.values().cloned().collect();
+        rtpc_defs.sort_by(|a, b| a.name.cmp(&b.name));
+
+        let state = EngineState {
+            bus_volumes: self.bus_volumes.iter().map(|(&k, &v)| (k, v)).collect(),
+            bus_mutes: self.bus_mutes.iter().map(|(&k, &v)| (k, v)).collect(),
+            rtpc_defs,
+            rtpc_values: self.rtpc_values.clone().into_iter().collect(),
+            states: self.state_groups.clone().into_iter().collect(),
+            max_voices: self.max_voices,
+            max_voices_per_event: self.max_voices_per_event,
+            steal_mode: self.steal_mode,
+        };
+
+        // Plain maps/structs of primitives — serialization cannot fail
+        serde_json::to_string(&state).unwrap_or_default()
+    }
+
+    /// Restore configuration from `export_state` JSON and re-apply bus gains
+    #[wasm_bindgen]
+    pub fn import_state(&mut self, json: &str) -> Result<(), JsValue> {
+        let state: EngineState = serde_json::from_str(json)
+            .map_err(|e| JsValue::from_str(&format!("JSON parse error: {}", e)))?;
+
+        self.bus_volumes = state
+            .bus_volumes
+            .into_iter()
+            .map(|(bus, volume)| (bus, volume.clamp(0.0, 2.0)))
+            .collect();
+        self.bus_mutes = state.bus_mutes.into_iter().collect();
+        self.rtpc_defs = state
+            .rtpc_defs
+            .into_iter()
+            .map(|def| (def.name.clone(), def))
+            .collect();
+        self.rtpc_values = state
+            .rtpc_values
+            .into_iter()
+            .map(|(name, value)| {
+                let value = match self.rtpc_defs.get(&name) {
+                    Some(def) => value.clamp(def.min, def.max),
+                    None => value,
+                };
+                (name, value)
+            })
+            .collect();
+        self.state_groups = state.states.into_iter().collect();
+        self.max_voices = state.max_voices;
+        self.max_voices_per_event = state.max_voices_per_event;
+        self.steal_mode = state.steal_mode;
+
+        self.apply_bus_gains();
+
+        log::info!("[FluxForge WASM] State restored");
+        Ok(())
+    }
+
+    /// Push stored bus volumes/mutes to the live GainNodes
+    fn apply_bus_gains(&self) {
+        let master_id = AudioBus::Master as u8;
+        let volume = |bus_id: u8| *self.bus_volumes.get(&bus_id).unwrap_or(&1.0);
+        let muted = |bus_id: u8| *self.bus_mutes.get(&bus_id).unwrap_or(&false);
+
+        // Master volume lives on the master GainNode (see set_master_volume)
+        if let Some(gain) = &self.master_gain {
+            gain.gain().set_value(volume(master_id));
+        }
+
+        for (&bus_id, gain) in &self.bus_gains {
+            let value = if muted(bus_id) {
+                0.0
+            } else if bus_id == master_id {
+                1.0
+            } else {
+                volume(bus_id)
+            };
+            gain.gain().set_value(value);
+        }
+    }
+
     /// Dispose and cleanup
     #[wasm_bindgen]
     pub fn dispose(&mut self) {
         self.stop_all(0);
+
+        // The closed context may never fire `onended`; report stragglers now
+        let completions: Vec<Completion> = {
+            let mut lifecycle = self.lifecycle.borrow_mut();
+            let ids: Vec<u32> = lifecycle.live.keys().copied().collect();
+            ids.into_iter()
+                .filter_map(|id| lifecycle.complete(id, Some(VoiceEndReason::Stopped)))
+                .collect()
+        };
+        for completion in completions {
+            completion.notify_deferred();
+        }
+        self.cleanup_voices();
         self.voices.clear();
         self.events.clear();
+        self.audio_buffers.borrow_mut().clear();
         self.stage_map.clear();
         self.rtpc_values.clear();
         self.rtpc_defs.clear();
@@ -677,6 +1161,25 @@ impl FluxForgeAudio {
     }
 }
 
+/// Fetch one audio file and decode it with the context's decoder
+async fn fetch_and_decode(
+    window: &web_sys::Window,
+    context: &AudioContext,
+    path: &str,
+) -> Result<AudioBuffer, JsValue> {
+    let response: Response = JsFuture::from(window.fetch_with_str(path))
+        .await?
+        .dyn_into()?;
+    if !response.ok() {
+        return Err(JsValue::from_str(&format!("HTTP {}", response.status())));
+    }
+
+    let data: js_sys::ArrayBuffer = JsFuture::from(response.array_buffer()?).await?.dyn_into()?;
+    JsFuture::from(context.decode_audio_data(&data)?)
+        .await?
+        .dyn_into()
+}
+
 // ============================================================================
 // UTILITY EXPORTS
 // ============================================================================
@@ -975,6 +1478,37 @@ mod tests {
         assert_eq!(audio.get_state("musicState").unwrap(), "idle");
     }
 
+    #[test]
+    fn test_export_import_state_roundtrip() {
+        let mut audio = FluxForgeAudio::new();
+        let json = r#"[{"name": "winAmount", "min": 0.0, "max": 100.0, "default": 50.0}]"#;
+        audio.load_rtpc_json(json).unwrap();
+        audio.set_rtpc("winAmount", 75.0);
+        audio.set_bus_volume(AudioBus::Music, 0.4);
+        audio.set_bus_volume(AudioBus::Reels, 1.5);
+        audio.set_bus_mute(AudioBus::Sfx, true);
+        audio.set_state("gamePhase", "freeSpins");
+        audio.set_max_voices(48);
+        audio.set_voice_steal_mode(VoiceStealMode::Quietest);
+
+        let snapshot = audio.export_state();
+
+        let mut restored = FluxForgeAudio::new();
+        restored.import_state(&snapshot).unwrap();
+
+        assert!((restored.get_bus_volume(AudioBus::Music) - 0.4).abs() < 1e-6);
+        assert!((restored.get_bus_volume(AudioBus::Reels) - 1.5).abs() < 1e-6);
+        assert!(restored.is_bus_muted(AudioBus::Sfx));
+        assert!((restored.get_rtpc("winAmount") - 75.0).abs() < 1e-6);
+        assert!((restored.get_rtpc_normalized("winAmount") - 0.75).abs() < 1e-6);
+        assert_eq!(restored.get_state("gamePhase").unwrap(), "freeSpins");
+        assert_eq!(restored.max_voices, 48);
+        assert_eq!(restored.steal_mode, VoiceStealMode::Quietest);
+
+        // Stable output: a restored engine exports the same snapshot
+        assert_eq!(restored.export_state(), snapshot);
+    }
+
     #[test]
     fn test_dispose() {
         let mut audio = FluxForgeAudio::new();
@@ -1021,6 +1555,39 @@ mod tests {
         assert_eq!(audio.get_event_count(), 1);
     }
 
+    #[test]
+    fn test_unique_audio_paths_dedupes_across_events() {
+        let mut audio = FluxForgeAudio::new();
+
+        let layer = |path: &str| {
+            format!(
+                r#"{{"audio_path": "{}", "volume": 1.0, "pan": 0.0, "delay_ms": 0,
+                    "offset_ms": 0, "bus": "Sfx", "loop_enabled": false}}"#,
+                path
+            )
+        };
+        let json = format!(
+            r#"[
+                {{"id": "spin", "name": "Spin", "stages": [], "priority": 50,
+                  "layers": [{}, {}]}},
+                {{"id": "stop", "name": "Stop", "stages": [], "priority": 50,
+                  "layers": [{}, {}]}}
+            ]"#,
+            layer("sfx/spin.wav"),
+            layer("sfx/whoosh.wav"),
+            layer("sfx/whoosh.wav"),
+            layer("sfx/stop.wav")
+        );
+        audio.load_events_json(&json).unwrap();
+
+        assert_eq!(
+            audio.unique_audio_paths(),
+            vec!["sfx/spin.wav", "sfx/stop.wav", "sfx/whoosh.wav"]
+        );
+        assert!(!audio.is_audio_loaded("sfx/spin.wav"));
+        assert_eq!(audio.get_loaded_audio_count(), 0);
+    }
+
     // test_load_events_invalid_json is skipped on non-wasm32 targets because
     // the error path uses JsValue::from_str() which panics outside WASM.
     // This test would pass correctly on wasm32 targets with wasm-bindgen-test.
@@ -1033,6 +1600,47 @@ mod tests {
         assert_eq!(audio.get_active_voice_count(), 0);
     }
 
+    #[test]
+    fn test_voice_completes_after_last_source() {
+        let mut lifecycle = VoiceLifecycle::default();
+        lifecycle.start(7, "spin", 2);
+
+        assert!(lifecycle.source_ended(7).is_none());
+        let completion = lifecycle
+            .source_ended(7)
+            .expect("last source completes the voice");
+        assert_eq!(completion.voice_id, 7);
+        assert_eq!(completion.event_id, "spin");
+        assert_eq!(completion.reason, VoiceEndReason::Completed);
+        assert_eq!(lifecycle.ended, vec![7]);
+
+        // Late onended / repeated stop must not report twice
+        assert!(lifecycle.source_ended(7).is_none());
+        assert!(lifecycle
+            .complete(7, Some(VoiceEndReason::Stopped))
+            .is_none());
+        assert_eq!(lifecycle.ended, vec![7]);
+    }
+
+    #[test]
+    fn test_stolen_voice_reports_reason() {
+        let mut lifecycle = VoiceLifecycle::default();
+        lifecycle.start(3, "win", 1);
+
+        // steal_voice records the reason before the node's onended fires
+        lifecycle.live.get_mut(&3).unwrap().reason = VoiceEndReason::Stolen;
+        let completion = lifecycle.source_ended(3).unwrap();
+        assert_eq!(completion.reason, VoiceEndReason::Stolen);
+
+        // Stopping a voice without sources completes it immediately
+        lifecycle.start(4, "win", 0);
+        let completion = lifecycle
+            .complete(4, Some(VoiceEndReason::Stopped))
+            .unwrap();
+        assert_eq!(completion.reason, VoiceEndReason::Stopped);
+        assert!(lifecycle.live.is_empty());
+    }
+
     #[test]
     fn test_stop_all_without_init() {
         let mut audio = FluxForgeAudio::new();
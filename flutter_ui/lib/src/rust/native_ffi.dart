@@ -995,6 +995,8 @@ typedef ClickIsCountInActiveNative = Int32 Function();
 typedef ClickIsCountInActiveDart = int Function();
 typedef ClickGetCountInBeatNative = Int32 Function();
 typedef ClickGetCountInBeatDart = int Function();
+typedef ClickPollCountInEventsNative = Pointer<Utf8> Function();
+typedef ClickPollCountInEventsDart = Pointer<Utf8> Function();
 
 // Click track — tap tempo
 typedef ClickTapTempoNative = Double Function();
@@ -2876,6 +2878,7 @@ class NativeFFI {
   late final ClickStartCountInDart _clickStartCountIn;
   late final ClickIsCountInActiveDart _clickIsCountInActive;
   late final ClickGetCountInBeatDart _clickGetCountInBeat;
+  late final ClickPollCountInEventsDart _clickPollCountInEvents;
   // Click track — tap tempo
   late final ClickTapTempoDart _clickTapTempo;
   // Click track — audibility mode
@@ -3762,6 +3765,7 @@ class NativeFFI {
     _clickStartCountIn = _lib.lookupFunction<ClickStartCountInNative, ClickStartCountInDart>('click_start_count_in');
     _clickIsCountInActive = _lib.lookupFunction<ClickIsCountInActiveNative, ClickIsCountInActiveDart>('click_is_count_in_active');
     _clickGetCountInBeat = _lib.lookupFunction<ClickGetCountInBeatNative, ClickGetCountInBeatDart>('click_get_count_in_beat');
+    _clickPollCountInEvents = _lib.lookupFunction<ClickPollCountInEventsNative, ClickPollCountInEventsDart>('click_poll_count_in_events');
     // Click track — tap tempo
     _clickTapTempo = _lib.lookupFunction<ClickTapTempoNative, ClickTapTempoDart>('click_tap_tempo');
     // Click track — audibility mode
@@ -6754,6 +6758,17 @@ class NativeFFI {
     return _clickGetCountInBeat();
   }
 
+  /// Drain count-in events as a JSON array
+  /// (`{"type":"beat",...}` per click, `{"type":"record_start",...}` at the downbeat)
+  String? clickPollCountInEvents() {
+    if (!_loaded) return null;
+    final ptr = _clickPollCountInEvents();
+    if (ptr == nullptr) return null;
+    final json = ptr.toDartString();
+    _freeString(ptr);
+    return json;
+  }
+
   /// Tap tempo — call on each tap, returns calculated BPM
   double clickTapTempo() {
     if (!_loaded) return 0.0;
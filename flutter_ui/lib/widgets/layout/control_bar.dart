@@ -11,6 +11,7 @@
 /// - System meters
 
 import 'dart:async';
+import 'dart:convert';
 
 import 'package:flutter/material.dart';
 import 'package:provider/provider.dart';
@@ -3056,20 +3057,26 @@ class _CountInBeatIndicatorLiveState extends State<_CountInBeatIndicatorLive> {
     final ffi = NativeFFI.instance;
     if (!ffi.isLoaded) return;
 
-    final active = ffi.clickIsCountInActive();
-    final beat = ffi.clickGetCountInBeat();
-
-    if (active != _isActive || beat != _currentBeat) {
-      // Calculate total beats from count-in mode + beats per bar
-      if (active && !_isActive) {
-        final mode = ffi.clickGetCountIn(); // 0=Off, 1=1Bar, 2=2Bars, 3=4Bars
-        final beatsPerBar = ffi.clickGetBeatsPerBar();
-        final bars = mode == 1 ? 1 : mode == 2 ? 2 : mode == 3 ? 4 : 1;
-        _totalBeats = beatsPerBar * bars;
+    // Beat events carry the clicked beat and the count-in length
+    var beat = _currentBeat;
+    var total = _totalBeats;
+    final events = ffi.clickPollCountInEvents();
+    if (events != null) {
+      for (final event in jsonDecode(events) as List) {
+        if (event['type'] == 'beat') {
+          beat = event['index'] as int;
+          total = event['total'] as int;
+        }
       }
+    }
+    final active = ffi.clickIsCountInActive();
+    if (!active) beat = -1;
+
+    if (active != _isActive || beat != _currentBeat || total != _totalBeats) {
       setState(() {
         _isActive = active;
         _currentBeat = beat;
+        _totalBeats = total;
       });
     }
   }
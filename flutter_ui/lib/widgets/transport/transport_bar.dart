@@ -2,7 +2,8 @@
 ///
 /// Play, Stop, Record, Rewind, Forward with micro-interactions
 /// Time display (bars:beats, timecode, samples)
-/// Tempo, loop, metronome controls
+/// Tempo (tap to type), loop, metronome controls
+/// Loop and punch regions with draggable loop handles
 /// DSD/GPU status indicators
 
 import 'package:flutter/material.dart';
@@ -26,6 +27,11 @@ class TransportBar extends StatelessWidget {
   final double tempo;
   final TimeDisplayMode timeDisplayMode;
 
+  // Loop region
+  final double? loopStart; // seconds
+  final double? loopEnd;   // seconds
+  final double? regionSpan; // seconds across the region strip (null = fit)
+
   // Punch In/Out
   final PunchMode punchMode;
   final double? punchInTime;  // seconds
@@ -56,6 +62,7 @@ class TransportBar extends StatelessWidget {
   final VoidCallback? onRewind;
   final VoidCallback? onForward;
   final VoidCallback? onLoopToggle;
+  final void Function(double start, double end)? onLoopRegionChange;
   final VoidCallback? onMetronomeToggle;
   final VoidCallback? onTimeDisplayModeChange;
   final ValueChanged<double>? onTempoChange;
@@ -88,6 +95,10 @@ class TransportBar extends StatelessWidget {
     this.currentTime = 0,
     this.tempo = 120,
     this.timeDisplayMode = TimeDisplayMode.bars,
+    // Loop region
+    this.loopStart,
+    this.loopEnd,
+    this.regionSpan,
     // Punch defaults
     this.punchMode = PunchMode.off,
     this.punchInTime,
@@ -113,6 +124,7 @@ class TransportBar extends StatelessWidget {
     this.onRewind,
     this.onForward,
     this.onLoopToggle,
+    this.onLoopRegionChange,
     this.onMetronomeToggle,
     this.onTimeDisplayModeChange,
     this.onTempoChange,
@@ -164,130 +176,347 @@ class TransportBar extends StatelessWidget {
           bottom: BorderSide(color: FluxForgeTheme.borderSubtle),
         ),
       ),
-      child: Row(
+      child: Stack(
+        fit: StackFit.expand,
         children: [
-          // Transport controls
-          _TransportButtonGroup(
-            isPlaying: isPlaying,
-            isRecording: isRecording,
-            onPlay: onPlay,
-            onStop: onStop,
-            onRecord: onRecord,
-            onRewind: onRewind,
-            onForward: onForward,
-          ),
+          Row(
+            children: [
+              // Transport controls
+              _TransportButtonGroup(
+                isPlaying: isPlaying,
+                isRecording: isRecording,
+                onPlay: onPlay,
+                onStop: onStop,
+                onRecord: onRecord,
+                onRewind: onRewind,
+                onForward: onForward,
+              ),
 
-          const SizedBox(width: 24),
+              const SizedBox(width: 24),
 
-          // Time display
-          _TimeDisplay(
-            time: _formatTime(),
-            mode: timeDisplayMode,
-            onTap: onTimeDisplayModeChange,
-          ),
+              // Time display
+              _TimeDisplay(
+                time: _formatTime(),
+                mode: timeDisplayMode,
+                onTap: onTimeDisplayModeChange,
+              ),
 
-          const SizedBox(width: 24),
+              const SizedBox(width: 24),
 
-          // Tempo
-          _TempoControl(
-            tempo: tempo,
-            onChanged: onTempoChange,
-          ),
+              // Tempo
+              _TempoControl(
+                tempo: tempo,
+                onChanged: onTempoChange,
+              ),
 
-          const Spacer(),
+              const Spacer(),
 
-          // DSD Indicator (shows only when DSD content loaded)
-          DsdIndicator(
-            rate: dsdRate,
-            mode: dsdMode,
-            isDsdLoaded: isDsdLoaded,
-            onTap: onDsdTap,
-          ),
+              // DSD Indicator (shows only when DSD content loaded)
+              DsdIndicator(
+                rate: dsdRate,
+                mode: dsdMode,
+                isDsdLoaded: isDsdLoaded,
+                onTap: onDsdTap,
+              ),
 
-          const SizedBox(width: 8),
+              const SizedBox(width: 8),
 
-          // GPU Indicator (shows only when GPU processing active)
-          GpuIndicator(
-            mode: gpuMode,
-            utilization: gpuUtilization,
-            onTap: onGpuTap,
-          ),
+              // GPU Indicator (shows only when GPU processing active)
+              GpuIndicator(
+                mode: gpuMode,
+                utilization: gpuUtilization,
+                onTap: onGpuTap,
+              ),
 
-          const SizedBox(width: 16),
-
-          // Punch In/Out
-          _PunchControl(
-            mode: punchMode,
-            punchInTime: punchInTime,
-            punchOutTime: punchOutTime,
-            tempo: tempo,
-            onModeChange: onPunchModeChange,
-            onSetPunchIn: onSetPunchInAtCursor,
-            onSetPunchOut: onSetPunchOutAtCursor,
-          ),
+              const SizedBox(width: 16),
+
+              // Punch In/Out
+              _PunchControl(
+                mode: punchMode,
+                punchInTime: punchInTime,
+                punchOutTime: punchOutTime,
+                tempo: tempo,
+                onModeChange: onPunchModeChange,
+                onSetPunchIn: onSetPunchInAtCursor,
+                onSetPunchOut: onSetPunchOutAtCursor,
+              ),
 
-          const SizedBox(width: 8),
+              const SizedBox(width: 8),
 
-          // Pre-Roll
-          _ToggleButton(
-            icon: Icons.skip_previous_rounded,
-            label: 'PRE',
-            isActive: preRollEnabled,
-            onTap: onPreRollToggle,
-            activeColor: FluxForgeTheme.accentBlue,
-          ),
+              // Pre-Roll
+              _ToggleButton(
+                icon: Icons.skip_previous_rounded,
+                label: 'PRE',
+                isActive: preRollEnabled,
+                onTap: onPreRollToggle,
+                activeColor: FluxForgeTheme.accentBlue,
+              ),
+
+              const SizedBox(width: 4),
 
-          const SizedBox(width: 4),
+              // Count-In
+              _ToggleButton(
+                icon: Icons.timer_rounded,
+                label: 'COUNT',
+                isActive: countInEnabled,
+                onTap: onCountInToggle,
+                activeColor: FluxForgeTheme.accentOrange,
+              ),
 
-          // Count-In
-          _ToggleButton(
-            icon: Icons.timer_rounded,
-            label: 'COUNT',
-            isActive: countInEnabled,
-            onTap: onCountInToggle,
-            activeColor: FluxForgeTheme.accentOrange,
-          ),
+              const SizedBox(width: 8),
 
-          const SizedBox(width: 8),
+              // Loop & Metronome
+              _ToggleButton(
+                icon: Icons.repeat_rounded,
+                label: 'LOOP',
+                isActive: loopEnabled,
+                onTap: onLoopToggle,
+                activeColor: FluxForgeTheme.accentCyan,
+              ),
 
-          // Loop & Metronome
-          _ToggleButton(
-            icon: Icons.repeat_rounded,
-            label: 'LOOP',
-            isActive: loopEnabled,
-            onTap: onLoopToggle,
-            activeColor: FluxForgeTheme.accentCyan,
-          ),
+              const SizedBox(width: 8),
 
-          const SizedBox(width: 8),
-
-          Builder(builder: (ctx) {
-            return GestureDetector(
-              onSecondaryTapUp: (details) {
-                final box = ctx.findRenderObject() as RenderBox;
-                final pos = box.localToGlobal(Offset(box.size.width / 2, box.size.height));
-                showMetronomeSettings(
-                  ctx,
-                  anchor: pos,
-                  enabled: metronomeEnabled,
-                  onToggle: () => onMetronomeToggle?.call(),
+              Builder(builder: (ctx) {
+                return GestureDetector(
+                  onSecondaryTapUp: (details) {
+                    final box = ctx.findRenderObject() as RenderBox;
+                    final pos = box.localToGlobal(Offset(box.size.width / 2, box.size.height));
+                    showMetronomeSettings(
+                      ctx,
+                      anchor: pos,
+                      enabled: metronomeEnabled,
+                      onToggle: () => onMetronomeToggle?.call(),
+                    );
+                  },
+                  child: _ToggleButton(
+                    icon: Icons.music_note_rounded,
+                    label: 'CLICK',
+                    isActive: metronomeEnabled,
+                    onTap: onMetronomeToggle,
+                    activeColor: FluxForgeTheme.accentOrange,
+                  ),
                 );
-              },
-              child: _ToggleButton(
-                icon: Icons.music_note_rounded,
-                label: 'CLICK',
-                isActive: metronomeEnabled,
-                onTap: onMetronomeToggle,
-                activeColor: FluxForgeTheme.accentOrange,
-              ),
-            );
-          }),
+              }),
+            ],
+          ),
+
+          // Loop & punch regions along the bottom edge
+          Positioned(
+            left: 0,
+            right: 0,
+            bottom: 0,
+            height: _RegionStrip.height,
+            child: _RegionStrip(
+              loopStart: loopStart,
+              loopEnd: loopEnd,
+              loopEnabled: loopEnabled,
+              punchMode: punchMode,
+              punchInTime: punchInTime,
+              punchOutTime: punchOutTime,
+              currentTime: currentTime,
+              span: regionSpan,
+              onLoopRegionChange: onLoopRegionChange,
+            ),
+          ),
         ],
       ),
     );
   }
 }
 
+// ═══════════════════════════════════════════════════════════════════════════════
+// LOOP / PUNCH REGION STRIP
+// ═══════════════════════════════════════════════════════════════════════════════
+
+enum _LoopHandle { start, end }
+
+class _RegionStrip extends StatefulWidget {
+  static const double height = 6;
+
+  final double? loopStart;
+  final double? loopEnd;
+  final bool loopEnabled;
+  final PunchMode punchMode;
+  final double? punchInTime;
+  final double? punchOutTime;
+  final double currentTime;
+  final double? span;
+  final void Function(double start, double end)? onLoopRegionChange;
+
+  const _RegionStrip({
+    this.loopStart,
+    this.loopEnd,
+    required this.loopEnabled,
+    required this.punchMode,
+    this.punchInTime,
+    this.punchOutTime,
+    required this.currentTime,
+    this.span,
+    this.onLoopRegionChange,
+  });
+
+  @override
+  State<_RegionStrip> createState() => _RegionStripState();
+}
+
+class _RegionStripState extends State<_RegionStrip> {
+  static const double _handleHitWidth = 10;
+  static const double _minLoopLength = 0.05; // seconds
+
+  _LoopHandle? _dragging;
+  // Span is frozen while dragging so the handles don't rescale under the cursor
+  double _dragSpan = 1;
+
+  double get _fitSpan {
+    final end = [
+      widget.loopEnd ?? 0,
+      widget.punchOutTime ?? 0,
+      widget.currentTime,
+      1.0,
+    ].reduce((a, b) => a > b ? a : b);
+    return end * 1.25;
+  }
+
+  double get _span => _dragging != null ? _dragSpan : (widget.span ?? _fitSpan);
+
+  void _onDragStart(DragStartDetails details) {
+    final start = widget.loopStart;
+    final end = widget.loopEnd;
+    if (start == null || end == null || widget.onLoopRegionChange == null) return;
+
+    final width = context.size!.width;
+    final span = _span;
+    final x = details.localPosition.dx;
+    final startDist = (x - start / span * width).abs();
+    final endDist = (x - end / span * width).abs();
+    if (startDist > _handleHitWidth && endDist > _handleHitWidth) return;
+
+    setState(() {
+      _dragging = startDist <= endDist ? _LoopHandle.start : _LoopHandle.end;
+      _dragSpan = span;
+    });
+  }
+
+  void _onDragUpdate(DragUpdateDetails details) {
+    final handle = _dragging;
+    final start = widget.loopStart;
+    final end = widget.loopEnd;
+    if (handle == null || start == null || end == null) return;
+
+    final width = context.size!.width;
+    final time = (details.localPosition.dx / width * _dragSpan).clamp(0.0, _dragSpan);
+    switch (handle) {
+      case _LoopHandle.start:
+        final maxStart = (end - _minLoopLength).clamp(0.0, end);
+        widget.onLoopRegionChange?.call(time.clamp(0.0, maxStart), end);
+      case _LoopHandle.end:
+        final minEnd = start + _minLoopLength;
+        widget.onLoopRegionChange?.call(start, time < minEnd ? minEnd : time);
+    }
+  }
+
+  void _onDragEnd() {
+    if (_dragging != null) setState(() => _dragging = null);
+  }
+
+  @override
+  Widget build(BuildContext context) {
+    return GestureDetector(
+      behavior: HitTestBehavior.opaque,
+      onHorizontalDragStart: _onDragStart,
+      onHorizontalDragUpdate: _onDragUpdate,
+      onHorizontalDragEnd: (_) => _onDragEnd(),
+      onHorizontalDragCancel: _onDragEnd,
+      child: CustomPaint(
+        painter: _RegionStripPainter(
+          span: _span,
+          loopStart: widget.loopStart,
+          loopEnd: widget.loopEnd,
+          loopEnabled: widget.loopEnabled,
+          punchIn: widget.punchMode == PunchMode.off ? null : widget.punchInTime,
+          punchOut: widget.punchMode == PunchMode.off ? null : widget.punchOutTime,
+          currentTime: widget.currentTime,
+          activeHandle: _dragging,
+        ),
+      ),
+    );
+  }
+}
+
+class _RegionStripPainter extends CustomPainter {
+  final double span;
+  final double? loopStart;
+  final double? loopEnd;
+  final bool loopEnabled;
+  final double? punchIn;
+  final double? punchOut;
+  final double currentTime;
+  final _LoopHandle? activeHandle;
+
+  _RegionStripPainter({
+    required this.span,
+    this.loopStart,
+    this.loopEnd,
+    required this.loopEnabled,
+    this.punchIn,
+    this.punchOut,
+    required this.currentTime,
+    this.activeHandle,
+  });
+
+  @override
+  void paint(Canvas canvas, Size size) {
+    double x(double time) => (time / span * size.width).clamp(0.0, size.width);
+
+    // Punch range (upper half)
+    if (punchIn != null && punchOut != null) {
+      canvas.drawRect(
+        Rect.fromLTRB(x(punchIn!), 0, x(punchOut!), size.height / 2),
+        Paint()..color = FluxForgeTheme.accentRed.withValues(alpha: 0.5),
+      );
+    }
+
+    // Loop range (lower half) with a handle at each edge
+    if (loopStart != null && loopEnd != null) {
+      final color = loopEnabled ? FluxForgeTheme.accentCyan : FluxForgeTheme.textTertiary;
+      final left = x(loopStart!);
+      final right = x(loopEnd!);
+      canvas.drawRect(
+        Rect.fromLTRB(left, size.height / 2, right, size.height),
+        Paint()..color = color.withValues(alpha: loopEnabled ? 0.4 : 0.15),
+      );
+      for (final (edge, handle) in [(left, _LoopHandle.start), (right, _LoopHandle.end)]) {
+        final width = handle == activeHandle ? 4.0 : 2.0;
+        canvas.drawRect(
+          Rect.fromCenter(center: Offset(edge, size.height / 2), width: width, height: size.height),
+          Paint()..color = color,
+        );
+      }
+    }
+
+    // Playhead
+    final playhead = x(currentTime);
+    canvas.drawLine(
+      Offset(playhead, 0),
+      Offset(playhead, size.height),
+      Paint()
+        ..color = FluxForgeTheme.textPrimary
+        ..strokeWidth = 1,
+    );
+  }
+
+  @override
+  bool shouldRepaint(_RegionStripPainter oldDelegate) =>
+      span != oldDelegate.span ||
+      loopStart != oldDelegate.loopStart ||
+      loopEnd != oldDelegate.loopEnd ||
+      loopEnabled != oldDelegate.loopEnabled ||
+      punchIn != oldDelegate.punchIn ||
+      punchOut != oldDelegate.punchOut ||
+      currentTime != oldDelegate.currentTime ||
+      activeHandle != oldDelegate.activeHandle;
+}
+
 // ═══════════════════════════════════════════════════════════════════════════════
 // PUNCH CONTROL
 // ═══════════════════════════════════════════════════════════════════════════════
@@ -682,7 +911,8 @@ class _TimeDisplay extends StatelessWidget {
   }
 }
 
-class _TempoControl extends StatelessWidget {
+/// Tempo readout; tap to type a new value (Enter or focus loss commits)
+class _TempoControl extends StatefulWidget {
   final double tempo;
   final ValueChanged<double>? onChanged;
 
@@ -691,32 +921,103 @@ class _TempoControl extends StatelessWidget {
     this.onChanged,
   });
 
+  @override
+  State<_TempoControl> createState() => _TempoControlState();
+}
+
+class _TempoControlState extends State<_TempoControl> {
+  bool _isEditing = false;
+  late TextEditingController _editController;
+  late FocusNode _editFocus;
+
+  @override
+  void initState() {
+    super.initState();
+    _editController = TextEditingController();
+    _editFocus = FocusNode();
+    _editFocus.addListener(() {
+      if (!_editFocus.hasFocus && _isEditing) {
+        _commitEdit();
+      }
+    });
+  }
+
+  @override
+  void dispose() {
+    _editController.dispose();
+    _editFocus.dispose();
+    super.dispose();
+  }
+
+  void _startEdit() {
+    if (widget.onChanged == null) return;
+    _editController.text = widget.tempo.toStringAsFixed(1);
+    setState(() => _isEditing = true);
+    WidgetsBinding.instance.addPostFrameCallback((_) {
+      _editFocus.requestFocus();
+      _editController.selection = TextSelection(
+        baseOffset: 0,
+        extentOffset: _editController.text.length,
+      );
+    });
+  }
+
+  void _commitEdit() {
+    if (!_isEditing) return;
+    final bpm = double.tryParse(_editController.text.trim());
+    if (bpm != null) {
+      widget.onChanged?.call(bpm.clamp(20.0, 999.0));
+    }
+    setState(() => _isEditing = false);
+  }
+
   @override
   Widget build(BuildContext context) {
-    return Container(
-      padding: const EdgeInsets.symmetric(horizontal: 8, vertical: 4),
-      decoration: BoxDecoration(
-        color: FluxForgeTheme.bgMid,
-        borderRadius: BorderRadius.circular(6),
-        border: Border.all(color: FluxForgeTheme.borderSubtle),
-      ),
-      child: Row(
-        mainAxisSize: MainAxisSize.min,
-        children: [
-          Text('BPM', style: FluxForgeTheme.label),
-          const SizedBox(width: 8),
-          SizedBox(
-            width: 50,
-            child: Text(
-              tempo.toStringAsFixed(1),
-              style: FluxForgeTheme.mono.copyWith(
-                fontSize: 14,
-                color: FluxForgeTheme.accentOrange,
-              ),
-              textAlign: TextAlign.center,
-            ),
+    final style = FluxForgeTheme.mono.copyWith(
+      fontSize: 14,
+      color: FluxForgeTheme.accentOrange,
+    );
+
+    return GestureDetector(
+      onTap: _startEdit,
+      child: Container(
+        padding: const EdgeInsets.symmetric(horizontal: 8, vertical: 4),
+        decoration: BoxDecoration(
+          color: FluxForgeTheme.bgMid,
+          borderRadius: BorderRadius.circular(6),
+          border: Border.all(
+            color: _isEditing ? FluxForgeTheme.accentOrange : FluxForgeTheme.borderSubtle,
           ),
-        ],
+        ),
+        child: Row(
+          mainAxisSize: MainAxisSize.min,
+          children: [
+            Text('BPM', style: FluxForgeTheme.label),
+            const SizedBox(width: 8),
+            SizedBox(
+              width: 50,
+              child: _isEditing
+                  ? TextField(
+                      controller: _editController,
+                      focusNode: _editFocus,
+                      textAlign: TextAlign.center,
+                      style: style,
+                      decoration: const InputDecoration(
+                        isDense: true,
+                        contentPadding: EdgeInsets.zero,
+                        border: InputBorder.none,
+                      ),
+                      keyboardType: const TextInputType.numberWithOptions(decimal: true),
+                      onSubmitted: (_) => _commitEdit(),
+                    )
+                  : Text(
+                      widget.tempo.toStringAsFixed(1),
+                      style: style,
+                      textAlign: TextAlign.center,
+                    ),
+            ),
+          ],
+        ),
       ),
     );
   }
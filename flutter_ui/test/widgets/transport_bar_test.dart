@@ -0,0 +1,85 @@
+/// TransportBar Widget Tests
+///
+/// Covers:
+/// - Dragging a loop handle on the region strip
+/// - Typing a tempo after tapping the BPM readout
+
+import 'package:flutter/material.dart';
+import 'package:flutter_test/flutter_test.dart';
+import 'package:fluxforge_ui/widgets/transport/transport_bar.dart';
+
+void main() {
+  Future<void> pumpBar(WidgetTester tester, TransportBar bar) async {
+    tester.view.physicalSize = const Size(2000, 200);
+    tester.view.devicePixelRatio = 1.0;
+    addTearDown(tester.view.resetPhysicalSize);
+
+    await tester.pumpWidget(MaterialApp(
+      home: Scaffold(body: Align(alignment: Alignment.topLeft, child: bar)),
+    ));
+  }
+
+  group('TransportBar', () {
+    testWidgets('dragging the loop end handle reports the new region', (tester) async {
+      (double, double)? region;
+      await pumpBar(
+        tester,
+        TransportBar(
+          loopEnabled: true,
+          loopStart: 2,
+          loopEnd: 4,
+          regionSpan: 10,
+          onLoopRegionChange: (start, end) => region = (start, end),
+        ),
+      );
+
+      // Region strip spans the bar inside its 16 px horizontal padding
+      final bar = tester.getRect(find.byType(TransportBar));
+      final stripWidth = bar.width - 32;
+      final handle = Offset(bar.left + 16 + stripWidth * 0.4, bar.bottom - 3);
+      await tester.dragFrom(handle, Offset(stripWidth * 0.1, 0));
+      await tester.pump();
+
+      expect(region, isNotNull);
+      expect(region!.$1, 2);
+      expect(region!.$2, closeTo(5.0, 0.05));
+    });
+
+    testWidgets('dragging away from the handles leaves the loop alone', (tester) async {
+      var calls = 0;
+      await pumpBar(
+        tester,
+        TransportBar(
+          loopStart: 2,
+          loopEnd: 4,
+          regionSpan: 10,
+          onLoopRegionChange: (_, _) => calls++,
+        ),
+      );
+
+      final bar = tester.getRect(find.byType(TransportBar));
+      final stripWidth = bar.width - 32;
+      await tester.dragFrom(
+        Offset(bar.left + 16 + stripWidth * 0.8, bar.bottom - 3),
+        const Offset(100, 0),
+      );
+      await tester.pump();
+
+      expect(calls, 0);
+    });
+
+    testWidgets('tapping the tempo allows typing a new value', (tester) async {
+      double? tempo;
+      await pumpBar(tester, TransportBar(onTempoChange: (v) => tempo = v));
+
+      await tester.tap(find.text('120.0'));
+      await tester.pump();
+      await tester.enterText(find.byType(TextField), '128.5');
+      await tester.testTextInput.receiveAction(TextInputAction.done);
+      await tester.pump();
+
+      expect(tempo, 128.5);
+      expect(find.byType(TextField), findsNothing);
+    });
+  });
+}
@@ -160,7 +160,7 @@ struct MultibandConfig {
     crossover_freqs: array<f32, 7>,  // Up to 8 bands
 }
 
-@group(1) @binding(1) var<uniform> multiband_config: MultibandConfig;
+@group(1) @binding(1) var<storage, read> multiband_config: MultibandConfig;
 @group(1) @binding(2) var<storage, read_write> band_buffers: array<f32>;  // [band][sample]
 
 // Linkwitz-Riley crossover coefficients
@@ -483,10 +483,10 @@ struct ConvolutionConfig {
     num_partitions: u32,
 }
 
-@group(4) @binding(0) var<uniform> conv_config: ConvolutionConfig;
-@group(4) @binding(1) var<storage, read> ir_freq: array<vec2<f32>>;  // Complex IR spectrum
-@group(4) @binding(2) var<storage, read_write> input_freq: array<vec2<f32>>;  // Complex input spectrum
-@group(4) @binding(3) var<storage, read_write> output_accum: array<f32>;  // Overlap-add accumulator
+@group(3) @binding(2) var<uniform> conv_config: ConvolutionConfig;
+@group(3) @binding(3) var<storage, read> ir_freq: array<vec2<f32>>;  // Complex IR spectrum
+@group(3) @binding(4) var<storage, read_write> input_freq: array<vec2<f32>>;  // Complex input spectrum
+@group(3) @binding(5) var<storage, read_write> output_accum: array<f32>;  // Overlap-add accumulator
 
 // Complex multiplication
 fn cmul(a: vec2<f32>, b: vec2<f32>) -> vec2<f32> {
@@ -526,11 +526,11 @@ struct StereoConfig {
     pan: f32,
 }
 
-@group(5) @binding(0) var<uniform> stereo_config: StereoConfig;
-@group(5) @binding(1) var<storage, read> input_left: array<f32>;
-@group(5) @binding(2) var<storage, read> input_right: array<f32>;
-@group(5) @binding(3) var<storage, read_write> output_left: array<f32>;
-@group(5) @binding(4) var<storage, read_write> output_right: array<f32>;
+@group(1) @binding(0) var<uniform> stereo_config: StereoConfig;
+@group(1) @binding(1) var<storage, read> input_left: array<f32>;
+@group(1) @binding(2) var<storage, read> input_right: array<f32>;
+@group(1) @binding(3) var<storage, read_write> output_left: array<f32>;
+@group(1) @binding(4) var<storage, read_write> output_right: array<f32>;
 
 @compute @workgroup_size(64)
 fn stereo_process(
@@ -580,7 +580,7 @@ struct SaturationConfig {
     mode: u32,  // 0=soft, 1=hard, 2=tube, 3=tape
 }
 
-@group(5) @binding(5) var<uniform> saturation_config: SaturationConfig;
+@group(1) @binding(5) var<uniform> saturation_config: SaturationConfig;
 
 fn soft_clip(x: f32) -> f32 {
     return x / (1.0 + abs(x));
@@ -654,7 +654,7 @@ struct GainConfig {
     _pad: f32,
 }
 
-@group(5) @binding(6) var<storage, read_write> gain_config: GainConfig;
+@group(1) @binding(6) var<storage, read_write> gain_config: GainConfig;
 
 @compute @workgroup_size(64)
 fn apply_gain_smoothed(
@@ -667,11 +667,11 @@ fn apply_gain_smoothed(
     }
 
     // Smooth gain changes
-    let target = gain_config.gain_linear;
+    let target_gain = gain_config.gain_linear;
     var current = gain_config.current_gain;
 
     // One-pole smoother per sample
-    current = current + gain_config.smoothing_coeff * (target - current);
+    current = current + gain_config.smoothing_coeff * (target_gain - current);
 
     output_buffer[sample_idx] = input_buffer[sample_idx] * current;
 
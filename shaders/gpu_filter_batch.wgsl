@@ -0,0 +1,92 @@
+// GPU Filter Batch Processing - WGSL Compute Shader
+// Runs one biquad chain over many channels in a single dispatch.
+// Kept apart from gpu_filter.wgsl so the pipeline only binds group 0.
+
+// ============================================================================
+// Types & Bindings (FilterParams/FilterState match gpu_filter.wgsl)
+// ============================================================================
+
+struct FilterParams {
+    // Biquad coefficients
+    b0: f32,
+    b1: f32,
+    b2: f32,
+    a1: f32,
+    a2: f32,
+    // Padding for alignment
+    _pad0: f32,
+    _pad1: f32,
+    _pad2: f32,
+}
+
+struct FilterState {
+    z1: f32,
+    z2: f32,
+    _pad0: f32,
+    _pad1: f32,
+}
+
+// Channels are packed channel-major: channel c occupies
+// [c * frames_per_channel, (c + 1) * frames_per_channel).
+struct BatchConfig {
+    num_channels: u32,
+    frames_per_channel: u32,
+    num_filters: u32,
+    channel_offset: u32,
+}
+
+// Packed input audio (channel-major)
+@group(0) @binding(0) var<storage, read> input_buffer: array<f32>;
+// Packed output audio
+@group(0) @binding(1) var<storage, read_write> output_buffer: array<f32>;
+// Filter parameters (up to 64 bands, shared by all channels)
+@group(0) @binding(2) var<storage, read> filter_params: array<FilterParams, 64>;
+// Filter states (64 per channel slot)
+@group(0) @binding(3) var<storage, read_write> filter_states: array<FilterState>;
+// Batch configuration
+@group(0) @binding(4) var<uniform> batch_config: BatchConfig;
+
+// ============================================================================
+// Biquad Filter Processing (TDF-II)
+// ============================================================================
+
+fn process_biquad(input: f32, params: FilterParams, state: ptr<function, FilterState>) -> f32 {
+    let output = params.b0 * input + (*state).z1;
+    (*state).z1 = params.b1 * input - params.a1 * output + (*state).z2;
+    (*state).z2 = params.b2 * input - params.a2 * output;
+    return output;
+}
+
+// One workgroup per channel; the biquad recursion is serial per channel.
+@compute @workgroup_size(1)
+fn biquad_filter_batch(
+    @builtin(workgroup_id) wg_id: vec3<u32>
+) {
+    let channel = wg_id.x;
+
+    if channel >= batch_config.num_channels {
+        return;
+    }
+
+    let frames = batch_config.frames_per_channel;
+    let sample_base = channel * frames;
+    let state_base = (batch_config.channel_offset + channel) * 64u;
+    let num_filters = min(batch_config.num_filters, 64u);
+
+    var states: array<FilterState, 64>;
+    for (var i = 0u; i < num_filters; i++) {
+        states[i] = filter_states[state_base + i];
+    }
+
+    for (var s = 0u; s < frames; s++) {
+        var sample = input_buffer[sample_base + s];
+        for (var i = 0u; i < num_filters; i++) {
+            sample = process_biquad(sample, filter_params[i], &states[i]);
+        }
+        output_buffer[sample_base + s] = sample;
+    }
+
+    for (var i = 0u; i < num_filters; i++) {
+        filter_states[state_base + i] = states[i];
+    }
+}